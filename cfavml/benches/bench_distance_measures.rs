@@ -51,6 +51,16 @@ benchmark_distance_measure!(
     x1024 = f32_xconst_avx2_fma_cosine::<1024>,
     xany = f32_xany_avx2_fma_cosine,
 );
+benchmark_distance_measure!(
+    "f32_avx2_nofma_cosine_fused",
+    x1024 = f32_xany_avx2_nofma_cosine_fused,
+    xany = f32_xany_avx2_nofma_cosine_fused,
+);
+benchmark_distance_measure!(
+    "f32_avx2_fma_cosine_fused",
+    x1024 = f32_xany_avx2_fma_cosine_fused,
+    xany = f32_xany_avx2_fma_cosine_fused,
+);
 benchmark_distance_measure!(
     "f32_avx2_nofma_euclidean",
     x1024 = f32_xconst_avx2_nofma_euclidean::<1024>,
@@ -62,16 +72,19 @@ benchmark_distance_measure!(
     xany = f32_xany_avx2_fma_euclidean,
 );
 
+#[cfg(feature = "nightly")]
 benchmark_distance_measure!(
     "f32_avx512_fma_dot",
     x1024 = f32_xconst_avx512_fma_dot::<1024>,
     xany = f32_xany_avx512_fma_dot,
 );
+#[cfg(feature = "nightly")]
 benchmark_distance_measure!(
     "f32_avx512_fma_cosine",
     x1024 = f32_xconst_avx512_fma_cosine::<1024>,
     xany = f32_xany_avx512_fma_cosine,
 );
+#[cfg(feature = "nightly")]
 benchmark_distance_measure!(
     "f32_avx512_fma_euclidean",
     x1024 = f32_xconst_avx512_fma_euclidean::<1024>,
@@ -103,13 +116,26 @@ criterion_group!(
         benchmark_f32_avx2_fma_dot,
         benchmark_f32_avx2_nofma_cosine,
         benchmark_f32_avx2_fma_cosine,
+        benchmark_f32_avx2_nofma_cosine_fused,
+        benchmark_f32_avx2_fma_cosine_fused,
         benchmark_f32_avx2_nofma_euclidean,
         benchmark_f32_avx2_fma_euclidean,
-        benchmark_f32_avx512_fma_dot,
-        benchmark_f32_avx512_fma_cosine,
-        benchmark_f32_avx512_fma_euclidean,
         benchmark_f32_fallback_nofma_dot,
         benchmark_f32_fallback_nofma_cosine,
         benchmark_f32_fallback_nofma_euclidean,
 );
+#[cfg(feature = "nightly")]
+criterion_group!(
+    name = benches_avx512;
+    config = Criterion::default()
+        .measurement_time(Duration::from_secs(60));
+    targets =
+        benchmark_f32_avx512_fma_dot,
+        benchmark_f32_avx512_fma_cosine,
+        benchmark_f32_avx512_fma_euclidean,
+);
+
+#[cfg(not(feature = "nightly"))]
 criterion_main!(benches);
+#[cfg(feature = "nightly")]
+criterion_main!(benches, benches_avx512);
@@ -0,0 +1,147 @@
+use std::hint::black_box;
+
+use cfavml::bench_utils::get_sample_vectors;
+use cfavml::danger::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// Only the `repeat!` macro is used, sample data comes from `cfavml::bench_utils`.
+#[allow(unused)]
+mod utils;
+
+const SIZES: [usize; 5] = [56, 64, 512, 1301, 8192];
+
+macro_rules! benchmark_dot {
+    ($name:expr, $ty:ty, $func:expr) => {
+        paste::paste! {
+            fn [< benchmark_ $name >](c: &mut Criterion) {
+                let mut group = c.benchmark_group($name);
+                for size in SIZES {
+                    let (x, y) = get_sample_vectors::<$ty>(size);
+                    group.bench_function(format!("xany-{size}"), |b| {
+                        b.iter(|| repeat!(1000, $func, &x, &y));
+                    });
+                }
+                group.finish();
+            }
+        }
+    };
+}
+
+macro_rules! benchmark_xconst_dot {
+    ($name:expr, $xconst:ident, $xany:expr, $($dims:literal),+ $(,)?) => {
+        paste::paste! {
+            fn [< benchmark_ $name >](c: &mut Criterion) {
+                let mut group = c.benchmark_group($name);
+                $(
+                    let (x, y) = get_sample_vectors::<f32>($dims);
+                    group.bench_function(concat!("xconst-", $dims), |b| {
+                        b.iter(|| repeat!(1000, $xconst::<$dims>, &x, &y));
+                    });
+                    group.bench_function(concat!("xany-", $dims), |b| {
+                        b.iter(|| repeat!(1000, $xany, &x, &y));
+                    });
+                )+
+                group.finish();
+            }
+        }
+    };
+}
+
+macro_rules! benchmark_xconst_sum {
+    ($name:expr, $xconst:ident, $xany:expr, $($dims:literal),+ $(,)?) => {
+        paste::paste! {
+            fn [< benchmark_ $name >](c: &mut Criterion) {
+                let mut group = c.benchmark_group($name);
+                $(
+                    let (x, _) = get_sample_vectors::<f32>($dims);
+                    group.bench_function(concat!("xconst-", $dims), |b| {
+                        b.iter(|| repeat!(1000, $xconst::<$dims>, &x));
+                    });
+                    group.bench_function(concat!("xany-", $dims), |b| {
+                        b.iter(|| repeat!(1000, $xany, &x));
+                    });
+                )+
+                group.finish();
+            }
+        }
+    };
+}
+
+benchmark_dot!(
+    "f32_fallback_nofma_dot",
+    f32,
+    generic_xany_fallback_nofma_dot::<f32>
+);
+benchmark_dot!("f32_avx2_nofma_dot", f32, f32_xany_avx2_nofma_dot);
+benchmark_dot!("f32_avx2_fma_dot", f32, f32_xany_avx2_fma_dot);
+benchmark_dot!(
+    "f64_fallback_nofma_dot",
+    f64,
+    generic_xany_fallback_nofma_dot::<f64>
+);
+benchmark_dot!("f64_avx2_nofma_dot", f64, f64_xany_avx2_nofma_dot);
+benchmark_dot!("f64_avx2_fma_dot", f64, f64_xany_avx2_fma_dot);
+
+// Common embedding dimensions, i.e. BERT (768) and OpenAI `text-embedding-ada-002`
+// (1536), comparing the unrolled tail-free `xconst` kernels against `xany`.
+benchmark_xconst_dot!(
+    "f32_avx2_fma_dot_embedding_dims",
+    f32_xconst_avx2_fma_dot,
+    f32_xany_avx2_fma_dot,
+    128,
+    256,
+    768,
+    1536,
+);
+benchmark_xconst_sum!(
+    "f32_avx2_nofma_sum_embedding_dims",
+    f32_xconst_avx2_nofma_sum_horizontal,
+    f32_xany_avx2_nofma_sum_horizontal,
+    128,
+    256,
+    768,
+    1536,
+);
+
+#[cfg(feature = "nightly")]
+benchmark_dot!("f32_avx512_fma_dot", f32, f32_xany_avx512_fma_dot);
+#[cfg(feature = "nightly")]
+benchmark_dot!("f64_avx512_fma_dot", f64, f64_xany_avx512_fma_dot);
+#[cfg(feature = "nightly")]
+benchmark_xconst_dot!(
+    "f32_avx512_fma_dot_embedding_dims",
+    f32_xconst_avx512_fma_dot,
+    f32_xany_avx512_fma_dot,
+    128,
+    256,
+    768,
+    1536,
+);
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default();
+    targets =
+        benchmark_f32_fallback_nofma_dot,
+        benchmark_f32_avx2_nofma_dot,
+        benchmark_f32_avx2_fma_dot,
+        benchmark_f64_fallback_nofma_dot,
+        benchmark_f64_avx2_nofma_dot,
+        benchmark_f64_avx2_fma_dot,
+        benchmark_f32_avx2_fma_dot_embedding_dims,
+        benchmark_f32_avx2_nofma_sum_embedding_dims,
+);
+#[cfg(feature = "nightly")]
+criterion_group!(
+    name = benches_avx512;
+    config = Criterion::default();
+    targets =
+        benchmark_f32_avx512_fma_dot,
+        benchmark_f64_avx512_fma_dot,
+        benchmark_f32_avx512_fma_dot_embedding_dims,
+);
+
+#[cfg(not(feature = "nightly"))]
+criterion_main!(benches);
+#[cfg(feature = "nightly")]
+criterion_main!(benches, benches_avx512);
@@ -0,0 +1,59 @@
+use std::hint::black_box;
+
+use cfavml::bench_utils::get_sample_vectors;
+use cfavml::danger::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// A `4096 x 4096` output is 64MB, larger than the last level cache of most CPUs.
+const M: usize = 4096;
+const K: usize = 8;
+const N: usize = 4096;
+
+// A working set which fits in cache, read after each product to show how much
+// of it the output evicted.
+const WORKING_SET: usize = 256 * 1024;
+
+fn benchmark_f32_avx2_fma_gemm_streaming(c: &mut Criterion) {
+    let mut group = c.benchmark_group("f32_avx2_fma_gemm_streaming");
+    group.sample_size(10);
+
+    let (a, _) = get_sample_vectors::<f32>(M * K);
+    let (_, b) = get_sample_vectors::<f32>(K * N);
+    let (x, y) = get_sample_vectors::<f32>(WORKING_SET);
+
+    let mut buffer = vec![0.0; M * N + 8];
+    let offset = buffer.as_ptr().align_offset(32);
+    let out = &mut buffer[offset..offset + M * N];
+
+    group.bench_function(format!("storeu-{M}x{K}x{N}"), |bench| {
+        bench.iter(|| unsafe {
+            f32_xany_avx2_fma_gemm(black_box(&a), black_box(&b), out, M, K, N)
+        });
+    });
+    group.bench_function(format!("stream-{M}x{K}x{N}"), |bench| {
+        bench.iter(|| unsafe {
+            f32_xany_avx2_fma_gemm_streaming(black_box(&a), black_box(&b), out, M, K, N)
+        });
+    });
+    group.bench_function(format!("storeu-then-dot-{WORKING_SET}"), |bench| {
+        bench.iter(|| unsafe {
+            f32_xany_avx2_fma_gemm(black_box(&a), black_box(&b), out, M, K, N);
+            black_box(f32_xany_avx2_fma_dot(black_box(&x), black_box(&y)))
+        });
+    });
+    group.bench_function(format!("stream-then-dot-{WORKING_SET}"), |bench| {
+        bench.iter(|| unsafe {
+            f32_xany_avx2_fma_gemm_streaming(black_box(&a), black_box(&b), out, M, K, N);
+            black_box(f32_xany_avx2_fma_dot(black_box(&x), black_box(&y)))
+        });
+    });
+
+    group.finish();
+}
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default();
+    targets = benchmark_f32_avx2_fma_gemm_streaming,
+);
+criterion_main!(benches);
@@ -47,16 +47,19 @@ benchmark_metric!(
     xany = f32_xany_avx2_nofma_sum_horizontal,
 );
 
+#[cfg(feature = "nightly")]
 benchmark_metric!(
     "f32_avx512_nofma_min",
     x1024 = f32_xconst_avx512_nofma_min_horizontal::<1024>,
     xany = f32_xany_avx512_nofma_min_horizontal,
 );
+#[cfg(feature = "nightly")]
 benchmark_metric!(
     "f32_avx512_nofma_max",
     x1024 = f32_xconst_avx512_nofma_max_horizontal::<1024>,
     xany = f32_xany_avx512_nofma_max_horizontal,
 );
+#[cfg(feature = "nightly")]
 benchmark_metric!(
     "f32_avx512_nofma_sum",
     x1024 = f32_xconst_avx512_nofma_sum_horizontal::<1024>,
@@ -70,13 +73,13 @@ benchmark_metric!(
 );
 benchmark_metric!(
     "f32_fallback_nofma_max",
-    x1024 = f32_xany_fallback_nofma_max_horizontal,
-    xany = f32_xany_fallback_nofma_max_horizontal,
+    x1024 = generic_xany_fallback_nofma_max_horizontal,
+    xany = generic_xany_fallback_nofma_max_horizontal,
 );
 benchmark_metric!(
     "f32_fallback_nofma_sum",
-    x1024 = f32_xany_fallback_nofma_sum_horizontal,
-    xany = f32_xany_fallback_nofma_sum_horizontal,
+    x1024 = generic_xany_fallback_nofma_sum_horizontal,
+    xany = generic_xany_fallback_nofma_sum_horizontal,
 );
 
 criterion_group!(
@@ -87,11 +90,22 @@ criterion_group!(
         benchmark_f32_avx2_nofma_sum,
         benchmark_f32_avx2_nofma_max,
         benchmark_f32_avx2_nofma_min,
-        benchmark_f32_avx512_nofma_sum,
-        benchmark_f32_avx512_nofma_max,
-        benchmark_f32_avx512_nofma_min,
         benchmark_f32_fallback_nofma_sum,
         benchmark_f32_fallback_nofma_max,
         benchmark_f32_fallback_nofma_min,
 );
+#[cfg(feature = "nightly")]
+criterion_group!(
+    name = benches_avx512;
+    config = Criterion::default()
+        .measurement_time(Duration::from_secs(60));
+    targets =
+        benchmark_f32_avx512_nofma_sum,
+        benchmark_f32_avx512_nofma_max,
+        benchmark_f32_avx512_nofma_min,
+);
+
+#[cfg(not(feature = "nightly"))]
 criterion_main!(benches);
+#[cfg(feature = "nightly")]
+criterion_main!(benches, benches_avx512);
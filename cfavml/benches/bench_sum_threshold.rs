@@ -0,0 +1,60 @@
+use std::hint::black_box;
+
+use cfavml::bench_utils::get_sample_vectors;
+use cfavml::danger::*;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+// Only the `repeat!` macro is used, sample data comes from `cfavml::bench_utils`.
+#[allow(unused)]
+mod utils;
+
+// Lengths around the `SMALL_SUM_THRESHOLD` of the AVX512 sum routines, including some
+// which are not a multiple of the register width so the tail handling is measured.
+const F32_SIZES: [usize; 12] =
+    [64, 131, 256, 384, 512, 576, 640, 704, 768, 896, 1024, 2048];
+const F64_SIZES: [usize; 12] =
+    [32, 67, 128, 192, 256, 288, 320, 352, 384, 448, 512, 1024];
+
+macro_rules! benchmark_sum_paths {
+    ($name:expr, $ty:ty, $sizes:expr, $small:expr, $unrolled:expr) => {
+        paste::paste! {
+            fn [< benchmark_ $name >](c: &mut Criterion) {
+                let mut group = c.benchmark_group($name);
+                for size in $sizes {
+                    let (x, _) = get_sample_vectors::<$ty>(size);
+                    group.bench_function(format!("small-{size}"), |b| {
+                        b.iter(|| repeat!(1000, $small, &x));
+                    });
+                    group.bench_function(format!("unrolled-{size}"), |b| {
+                        b.iter(|| repeat!(1000, $unrolled, &x));
+                    });
+                }
+                group.finish();
+            }
+        }
+    };
+}
+
+benchmark_sum_paths!(
+    "f32_avx512_nofma_sum_threshold",
+    f32,
+    F32_SIZES,
+    f32_xany_avx512_nofma_sum_small,
+    f32_xany_avx512_nofma_sum_unrolled
+);
+benchmark_sum_paths!(
+    "f64_avx512_nofma_sum_threshold",
+    f64,
+    F64_SIZES,
+    f64_xany_avx512_nofma_sum_small,
+    f64_xany_avx512_nofma_sum_unrolled
+);
+
+criterion_group!(
+    name = benches;
+    config = Criterion::default();
+    targets =
+        benchmark_f32_avx512_nofma_sum_threshold,
+        benchmark_f64_avx512_nofma_sum_threshold,
+);
+criterion_main!(benches);
@@ -1,12 +1,7 @@
-use rand::{Rng, SeedableRng};
-use rand_chacha::ChaCha8Rng;
-
-const SEED: u64 = 2837564324875;
-
 #[macro_export]
 macro_rules! repeat {
     ($n:expr, $func:expr, $($x:expr $(,)?)*) => {{
-        #[allow(unused_unsafe)]
+        #[allow(unused_unsafe, clippy::macro_metavars_in_unsafe)]
         unsafe {
             for _ in 0..$n {
                 black_box($func($(black_box($x),)*));
@@ -17,28 +12,17 @@ macro_rules! repeat {
 
 #[cfg(not(feature = "benchmark-aligned"))]
 pub fn get_sample_vectors(size: usize) -> (Vec<f32>, Vec<f32>) {
-    let mut rng = ChaCha8Rng::seed_from_u64(SEED);
-
-    let mut x = Vec::new();
-    let mut y = Vec::new();
-    for _ in 0..size {
-        x.push(rng.gen());
-        y.push(rng.gen());
-    }
-
-    (x, y)
+    cfavml::bench_utils::get_sample_vectors(size)
 }
 
 #[cfg(feature = "benchmark-aligned")]
 pub fn get_sample_vectors(size: usize) -> (Vec<f32>, Vec<f32>) {
-    let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+    let (sample_x, sample_y) = cfavml::bench_utils::get_sample_vectors(size);
 
     let mut x = unsafe { aligned_vec(size) };
     let mut y = unsafe { aligned_vec(size) };
-    for _ in 0..size {
-        x.push(rng.gen());
-        y.push(rng.gen());
-    }
+    x.extend_from_slice(&sample_x);
+    y.extend_from_slice(&sample_y);
 
     (x, y)
 }
@@ -0,0 +1,295 @@
+//! Introspection of which set of routines the crate selects on the current CPU.
+//!
+//! This surfaces the result of the runtime feature detection so users can confirm
+//! which implementation is being used, i.e. when diagnosing performance issues,
+//! without having to instrument individual operations.
+
+use core::any::TypeId;
+use core::fmt;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+/// The implementation of the routines selected for the current CPU.
+pub enum Backend {
+    /// AVX512 routines, only available with the `nightly` feature enabled.
+    Avx512,
+    /// AVX2 routines making use of fused multiply-add instructions.
+    Avx2Fma,
+    /// AVX2 routines without fused multiply-add instructions.
+    Avx2,
+    /// The compiler optimized fallback routines.
+    Fallback,
+}
+
+impl Backend {
+    /// Returns the name of the backend.
+    pub const fn name(self) -> &'static str {
+        match self {
+            Backend::Avx512 => "avx512",
+            Backend::Avx2Fma => "avx2-fma",
+            Backend::Avx2 => "avx2",
+            Backend::Fallback => "fallback",
+        }
+    }
+}
+
+/// Calls the expression of the backend [detected_backend] selects on the current CPU.
+///
+/// When the target features the crate is compiled with guarantee the most capable
+/// backend available, see [static_backend], that arm is selected at compile time
+/// and no runtime detection takes place.
+///
+/// The arms of backends which are not compiled for the target are removed entirely,
+/// so they may name routines which do not exist on it.
+///
+/// A leading `verify` arm describes how the result is produced, `returned(op)`,
+/// `written(op, result)` or `inplace(op, x)`, with an optional `tolerance = ..`.
+/// With the `verify` feature enabled the `fallback` arm is then also run, writing to
+/// a copy of `result` or `x`, and the two results are checked against each other.
+macro_rules! dispatch_backend {
+    (
+        verify => returned($op:literal $(, tolerance = $tolerance:expr)?),
+        avx512 => $avx512:expr,
+        avx2_fma => $avx2_fma:expr,
+        avx2 => $avx2:expr,
+        fallback => $fallback:expr $(,)?
+    ) => {{
+        let result = $crate::backend::dispatch_backend!(
+            avx512 => $avx512,
+            avx2_fma => $avx2_fma,
+            avx2 => $avx2,
+            fallback => $fallback,
+        );
+        #[cfg(feature = "verify")]
+        $crate::verify::verify_returned(
+            $op,
+            $crate::verify::dispatched_backend(),
+            $crate::verify::verify_tolerance!($($tolerance)?),
+            result,
+            || $fallback,
+        );
+        result
+    }};
+    (
+        verify => written($op:literal, $result:ident $(, tolerance = $tolerance:expr)?),
+        avx512 => $avx512:expr,
+        avx2_fma => $avx2_fma:expr,
+        avx2 => $avx2:expr,
+        fallback => $fallback:expr $(,)?
+    ) => {{
+        $crate::backend::dispatch_backend!(
+            avx512 => $avx512,
+            avx2_fma => $avx2_fma,
+            avx2 => $avx2,
+            fallback => $fallback,
+        );
+        #[cfg(feature = "verify")]
+        $crate::verify::verify_written(
+            $op,
+            $crate::verify::dispatched_backend(),
+            $crate::verify::verify_tolerance!($($tolerance)?),
+            $result,
+            |$result| $fallback,
+        );
+    }};
+    (
+        verify => inplace($op:literal, $x:ident $(, tolerance = $tolerance:expr)?),
+        avx512 => $avx512:expr,
+        avx2_fma => $avx2_fma:expr,
+        avx2 => $avx2:expr,
+        fallback => $fallback:expr $(,)?
+    ) => {{
+        #[cfg(feature = "verify")]
+        let original = $x.to_vec();
+        $crate::backend::dispatch_backend!(
+            avx512 => $avx512,
+            avx2_fma => $avx2_fma,
+            avx2 => $avx2,
+            fallback => $fallback,
+        );
+        #[cfg(feature = "verify")]
+        $crate::verify::verify_inplace(
+            $op,
+            $crate::verify::dispatched_backend(),
+            $crate::verify::verify_tolerance!($($tolerance)?),
+            $x,
+            original,
+            |$x| $fallback,
+        );
+    }};
+    (
+        avx512 => $avx512:expr,
+        avx2_fma => $avx2_fma:expr,
+        avx2 => $avx2:expr,
+        fallback => $fallback:expr $(,)?
+    ) => {{
+        const STATIC_BACKEND: Option<$crate::backend::Backend> =
+            $crate::backend::static_backend();
+
+        match STATIC_BACKEND {
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            Some($crate::backend::Backend::Avx512) => $avx512,
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                not(feature = "nightly")
+            ))]
+            Some($crate::backend::Backend::Avx2Fma) => $avx2_fma,
+            _ => match $crate::backend::detected_backend() {
+                #[cfg(all(
+                    any(target_arch = "x86", target_arch = "x86_64"),
+                    feature = "nightly"
+                ))]
+                $crate::backend::Backend::Avx512 => $avx512,
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                $crate::backend::Backend::Avx2Fma => $avx2_fma,
+                #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+                $crate::backend::Backend::Avx2 => $avx2,
+                _ => $fallback,
+            },
+        }
+    }};
+}
+pub(crate) use dispatch_backend;
+
+impl fmt::Display for Backend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Returns the backend guaranteed to be available by the target features the crate
+/// was compiled with, or `None` if the build targets no optimized backend.
+///
+/// This is resolved entirely at compile time, so when it returns `Some` the routines
+/// of that backend can be called without any runtime detection. The CPU may still
+/// support a more capable backend, see [detected_backend].
+pub const fn static_backend() -> Option<Backend> {
+    if cfg!(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        feature = "nightly",
+        target_feature = "avx512f"
+    )) {
+        Some(Backend::Avx512)
+    } else if cfg!(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2",
+        target_feature = "fma"
+    )) {
+        Some(Backend::Avx2Fma)
+    } else if cfg!(all(
+        any(target_arch = "x86", target_arch = "x86_64"),
+        target_feature = "avx2"
+    )) {
+        Some(Backend::Avx2)
+    } else {
+        None
+    }
+}
+
+/// Returns the most capable backend the crate would select on the current CPU.
+///
+/// The CPU features are detected at runtime unless they are statically enabled
+/// when compiling, AVX512 is only reported when the crate is compiled with the
+/// `nightly` feature as the routines are not available otherwise.
+pub fn detected_backend() -> Backend {
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+    if std::arch::is_x86_feature_detected!("avx512f") {
+        return Backend::Avx512;
+    }
+
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    if std::arch::is_x86_feature_detected!("avx2") {
+        return if std::arch::is_x86_feature_detected!("fma") {
+            Backend::Avx2Fma
+        } else {
+            Backend::Avx2
+        };
+    }
+
+    Backend::Fallback
+}
+
+/// Returns the backend the crate would select for routines operating on `T`
+/// on the current CPU.
+///
+/// Only `f32` and `f64` have manually optimized routines across the operation set,
+/// `u8` and `i16` have AVX512 routines when the CPU supports the AVX512BW subset,
+/// all other types use the fallback routines.
+pub fn backend_for<T: 'static>() -> Backend {
+    let type_id = TypeId::of::<T>();
+    if type_id == TypeId::of::<f32>() || type_id == TypeId::of::<f64>() {
+        detected_backend()
+    } else if type_id == TypeId::of::<u8>() || type_id == TypeId::of::<i16>() {
+        narrow_int_backend()
+    } else {
+        Backend::Fallback
+    }
+}
+
+/// Returns the backend selected for the narrow `u8` and `i16` integer routines,
+/// which require AVX512BW rather than the AVX512F foundation alone.
+fn narrow_int_backend() -> Backend {
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+    if std::arch::is_x86_feature_detected!("avx512bw") {
+        return Backend::Avx512;
+    }
+
+    Backend::Fallback
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backend_for() {
+        assert_eq!(backend_for::<f32>(), detected_backend());
+        assert_eq!(backend_for::<f64>(), detected_backend());
+        assert_eq!(backend_for::<u32>(), Backend::Fallback);
+        assert!(matches!(
+            backend_for::<u8>(),
+            Backend::Avx512 | Backend::Fallback
+        ));
+    }
+
+    #[test]
+    fn test_static_backend_is_detected() {
+        match static_backend() {
+            Some(Backend::Avx512) => assert_eq!(detected_backend(), Backend::Avx512),
+            Some(Backend::Avx2Fma) => assert!(matches!(
+                detected_backend(),
+                Backend::Avx512 | Backend::Avx2Fma
+            )),
+            Some(Backend::Avx2) => assert_ne!(detected_backend(), Backend::Fallback),
+            Some(Backend::Fallback) => unreachable!(),
+            None => {},
+        }
+    }
+
+    #[cfg(target_feature = "avx2")]
+    #[test]
+    fn test_dispatch_static_backend() {
+        let dispatched = dispatch_backend!(
+            avx512 => Backend::Avx512,
+            avx2_fma => Backend::Avx2Fma,
+            avx2 => Backend::Avx2,
+            fallback => Backend::Fallback,
+        );
+
+        match static_backend() {
+            Some(Backend::Avx512) => assert_eq!(dispatched, Backend::Avx512),
+            Some(Backend::Avx2Fma) if cfg!(not(feature = "nightly")) => {
+                assert_eq!(dispatched, Backend::Avx2Fma)
+            },
+            _ => assert_eq!(dispatched, detected_backend()),
+        }
+    }
+
+    #[test]
+    fn test_backend_display() {
+        assert_eq!(Backend::Avx2Fma.to_string(), "avx2-fma");
+        assert_eq!(Backend::Fallback.to_string(), "fallback");
+    }
+}
@@ -0,0 +1,37 @@
+//! Utilities for benchmarking the routines provided by the crate.
+//!
+//! These are the same helpers used by the crate's own tests and benchmarks, so
+//! they can be used to reproduce results or benchmark other element types with
+//! identical, seeded, input data.
+
+use rand::distributions::{Distribution, Standard};
+use rand::{Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// The seed used to generate all sample data.
+///
+/// This value is fixed so generated vectors are identical across runs and platforms.
+pub const SEED: u64 = 34535345353;
+
+/// Generates two vectors of `size` random elements.
+///
+/// The vectors are generated from a [ChaCha8Rng] seeded with [SEED], meaning the
+/// output is deterministic for any given `T` and `size`.
+///
+/// For floats, values are within the range `[0, 1)`, other types follow the
+/// [Standard] distribution provided by `rand`.
+pub fn get_sample_vectors<T>(size: usize) -> (Vec<T>, Vec<T>)
+where
+    Standard: Distribution<T>,
+{
+    let mut rng = ChaCha8Rng::seed_from_u64(SEED);
+
+    let mut x = Vec::with_capacity(size);
+    let mut y = Vec::with_capacity(size);
+    for _ in 0..size {
+        x.push(rng.gen());
+        y.push(rng.gen());
+    }
+
+    (x, y)
+}
@@ -0,0 +1,45 @@
+use crate::math::TranscendentalMath;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// The activation function applied as the epilogue of a fused routine.
+pub enum Activation {
+    /// The value is returned as is.
+    None,
+    /// `max(x, 0)`
+    Relu,
+    /// `1 / (1 + e^-x)`
+    Sigmoid,
+    /// `tanh(x)`
+    Tanh,
+}
+
+impl Activation {
+    #[inline(always)]
+    /// Applies the activation function to the given value.
+    pub fn apply<T: Copy, M: TranscendentalMath<T>>(self, x: T) -> T {
+        match self {
+            Activation::None => x,
+            Activation::Relu => M::cmp_max(x, M::zero()),
+            Activation::Sigmoid => {
+                let exp = M::exp(M::sub(M::zero(), x));
+                M::div(M::one(), M::add(M::one(), exp))
+            },
+            Activation::Tanh => M::tanh(x),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::AutoMath;
+
+    #[test]
+    fn test_activation_apply() {
+        assert_eq!(Activation::None.apply::<f32, AutoMath>(-2.0), -2.0);
+        assert_eq!(Activation::Relu.apply::<f32, AutoMath>(-2.0), 0.0);
+        assert_eq!(Activation::Relu.apply::<f32, AutoMath>(2.0), 2.0);
+        assert_eq!(Activation::Sigmoid.apply::<f32, AutoMath>(0.0), 0.5);
+        assert_eq!(Activation::Tanh.apply::<f32, AutoMath>(0.0), 0.0);
+    }
+}
@@ -0,0 +1,445 @@
+//! Complex number routines operating on interleaved `f32` data.
+//!
+//! All routines within this file expect complex vectors to be laid out
+//! interleaved (array of structs), meaning a complex vector of `N` elements
+//! is a `[f32; N * 2]` slice in the form of `[re0, im0, re1, im1, ...]`.
+//!
+//! This matches the layout of `num_complex::Complex<f32>` and `[f32; 2]` pairs,
+//! so a `&[Complex<f32>]` can be cast to a `&[f32]` without any copying.
+use core::arch::x86_64::*;
+
+use crate::danger::{
+    f32_xany_avx2_nofma_add_vertical,
+    f32_xany_avx2_nofma_sub_vertical,
+    sum_avx2_ps,
+};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Adds each complex element of `y` to `x` element wise.
+///
+/// ```py
+/// D: int
+/// x: [c32; D]
+/// y: [c32; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] + y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length and laid out interleaved, otherwise this
+/// routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn c32_xany_avx2_nofma_add_vertical(x: &mut [f32], y: &[f32]) {
+    debug_assert_eq!(x.len() % 2, 0, "Complex vectors must be interleaved pairs");
+    f32_xany_avx2_nofma_add_vertical(x, y)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Subtracts each complex element of `y` from `x` element wise.
+///
+/// ```py
+/// D: int
+/// x: [c32; D]
+/// y: [c32; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] - y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length and laid out interleaved, otherwise this
+/// routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn c32_xany_avx2_nofma_sub_vertical(x: &mut [f32], y: &[f32]) {
+    debug_assert_eq!(x.len() % 2, 0, "Complex vectors must be interleaved pairs");
+    f32_xany_avx2_nofma_sub_vertical(x, y)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Multiplies each complex element of `x` by the complex elements of `y`
+/// element wise.
+///
+/// ```py
+/// D: int
+/// x: [c32; D]
+/// y: [c32; D]
+///
+/// for i in 0..D:
+///     x[i] = (x[i].re * y[i].re - x[i].im * y[i].im)
+///          + (x[i].re * y[i].im + x[i].im * y[i].re)j
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length and laid out interleaved, otherwise this
+/// routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn c32_xany_avx2_nofma_mul_vertical(x: &mut [f32], y: &[f32]) {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len() % 2, 0, "Complex vectors must be interleaved pairs");
+
+    let len = x.len();
+    let offset_from = len % 8;
+
+    let x_ptr = x.as_mut_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let a = _mm256_loadu_ps(x_ptr.add(i));
+        let b = _mm256_loadu_ps(y_ptr.add(i));
+
+        let (b_re, b_im, a_swapped) = split_complex_operands(a, b);
+        let cross = _mm256_mul_ps(a_swapped, b_im);
+        let result = _mm256_addsub_ps(_mm256_mul_ps(a, b_re), cross);
+        _mm256_storeu_ps(x_ptr.add(i), result);
+
+        i += 8;
+    }
+
+    while i < len {
+        complex_mul_scalar(x, y, i);
+        i += 2;
+    }
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Multiplies each complex element of `x` by the complex elements of `y`
+/// element wise.
+///
+/// ```py
+/// D: int
+/// x: [c32; D]
+/// y: [c32; D]
+///
+/// for i in 0..D:
+///     x[i] = (x[i].re * y[i].re - x[i].im * y[i].im)
+///          + (x[i].re * y[i].im + x[i].im * y[i].re)j
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length and laid out interleaved, otherwise this
+/// routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn c32_xany_avx2_fma_mul_vertical(x: &mut [f32], y: &[f32]) {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len() % 2, 0, "Complex vectors must be interleaved pairs");
+
+    let len = x.len();
+    let offset_from = len % 8;
+
+    let x_ptr = x.as_mut_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let a = _mm256_loadu_ps(x_ptr.add(i));
+        let b = _mm256_loadu_ps(y_ptr.add(i));
+
+        let (b_re, b_im, a_swapped) = split_complex_operands(a, b);
+        let cross = _mm256_mul_ps(a_swapped, b_im);
+        let result = _mm256_fmaddsub_ps(a, b_re, cross);
+        _mm256_storeu_ps(x_ptr.add(i), result);
+
+        i += 8;
+    }
+
+    while i < len {
+        complex_mul_scalar(x, y, i);
+        i += 2;
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the conjugated dot product of two complex vectors, returning
+/// the `(real, imaginary)` parts of the result.
+///
+/// ```py
+/// D: int
+/// total: c32
+/// x: [c32; D]
+/// y: [c32; D]
+///
+/// for i in 0..D:
+///     total = total + (conj(x[i]) * y[i])
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length and laid out interleaved, otherwise this
+/// routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn c32_xany_avx2_nofma_dot_conj(x: &[f32], y: &[f32]) -> (f32, f32) {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len() % 2, 0, "Complex vectors must be interleaved pairs");
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc_re1 = _mm256_setzero_ps();
+    let mut acc_re2 = _mm256_setzero_ps();
+    let mut acc_im1 = _mm256_setzero_ps();
+    let mut acc_im2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let a1 = _mm256_loadu_ps(x_ptr.add(i));
+        let a2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let b1 = _mm256_loadu_ps(y_ptr.add(i));
+        let b2 = _mm256_loadu_ps(y_ptr.add(i + 8));
+
+        let b1_swapped = _mm256_permute_ps::<0xB1>(b1);
+        let b2_swapped = _mm256_permute_ps::<0xB1>(b2);
+
+        acc_re1 = _mm256_add_ps(acc_re1, _mm256_mul_ps(a1, b1));
+        acc_re2 = _mm256_add_ps(acc_re2, _mm256_mul_ps(a2, b2));
+        acc_im1 = _mm256_add_ps(acc_im1, _mm256_mul_ps(a1, b1_swapped));
+        acc_im2 = _mm256_add_ps(acc_im2, _mm256_mul_ps(a2, b2_swapped));
+
+        i += 16;
+    }
+
+    if offset_from >= 8 {
+        let a = _mm256_loadu_ps(x_ptr.add(i));
+        let b = _mm256_loadu_ps(y_ptr.add(i));
+        let b_swapped = _mm256_permute_ps::<0xB1>(b);
+
+        acc_re1 = _mm256_add_ps(acc_re1, _mm256_mul_ps(a, b));
+        acc_im1 = _mm256_add_ps(acc_im1, _mm256_mul_ps(a, b_swapped));
+
+        i += 8;
+    }
+
+    let (mut re, mut im) = rollup_conj_acc(
+        _mm256_add_ps(acc_re1, acc_re2),
+        _mm256_add_ps(acc_im1, acc_im2),
+    );
+
+    while i < len {
+        let (tail_re, tail_im) = complex_dot_conj_scalar(x, y, i);
+        re += tail_re;
+        im += tail_im;
+
+        i += 2;
+    }
+
+    (re, im)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the conjugated dot product of two complex vectors, returning
+/// the `(real, imaginary)` parts of the result.
+///
+/// ```py
+/// D: int
+/// total: c32
+/// x: [c32; D]
+/// y: [c32; D]
+///
+/// for i in 0..D:
+///     total = total + (conj(x[i]) * y[i])
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length and laid out interleaved, otherwise this
+/// routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn c32_xany_avx2_fma_dot_conj(x: &[f32], y: &[f32]) -> (f32, f32) {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len() % 2, 0, "Complex vectors must be interleaved pairs");
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc_re1 = _mm256_setzero_ps();
+    let mut acc_re2 = _mm256_setzero_ps();
+    let mut acc_im1 = _mm256_setzero_ps();
+    let mut acc_im2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let a1 = _mm256_loadu_ps(x_ptr.add(i));
+        let a2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let b1 = _mm256_loadu_ps(y_ptr.add(i));
+        let b2 = _mm256_loadu_ps(y_ptr.add(i + 8));
+
+        let b1_swapped = _mm256_permute_ps::<0xB1>(b1);
+        let b2_swapped = _mm256_permute_ps::<0xB1>(b2);
+
+        acc_re1 = _mm256_fmadd_ps(a1, b1, acc_re1);
+        acc_re2 = _mm256_fmadd_ps(a2, b2, acc_re2);
+        acc_im1 = _mm256_fmadd_ps(a1, b1_swapped, acc_im1);
+        acc_im2 = _mm256_fmadd_ps(a2, b2_swapped, acc_im2);
+
+        i += 16;
+    }
+
+    if offset_from >= 8 {
+        let a = _mm256_loadu_ps(x_ptr.add(i));
+        let b = _mm256_loadu_ps(y_ptr.add(i));
+        let b_swapped = _mm256_permute_ps::<0xB1>(b);
+
+        acc_re1 = _mm256_fmadd_ps(a, b, acc_re1);
+        acc_im1 = _mm256_fmadd_ps(a, b_swapped, acc_im1);
+
+        i += 8;
+    }
+
+    let (mut re, mut im) = rollup_conj_acc(
+        _mm256_add_ps(acc_re1, acc_re2),
+        _mm256_add_ps(acc_im1, acc_im2),
+    );
+
+    while i < len {
+        let (tail_re, tail_im) = complex_dot_conj_scalar(x, y, i);
+        re += tail_re;
+        im += tail_im;
+
+        i += 2;
+    }
+
+    (re, im)
+}
+
+#[inline(always)]
+/// Splits the operands of a complex multiply into the broadcast real parts of `b`,
+/// the broadcast imaginary parts of `b` and `a` with its real and imaginary parts
+/// swapped.
+unsafe fn split_complex_operands(a: __m256, b: __m256) -> (__m256, __m256, __m256) {
+    let b_re = _mm256_moveldup_ps(b);
+    let b_im = _mm256_movehdup_ps(b);
+    let a_swapped = _mm256_permute_ps::<0xB1>(a);
+    (b_re, b_im, a_swapped)
+}
+
+#[inline(always)]
+/// Reduces the real and imaginary accumulators of the conjugated dot product.
+///
+/// The real accumulator holds `x.re * y.re` and `x.im * y.im` pairs which are
+/// simply summed, the imaginary accumulator holds `x.re * y.im` and `x.im * y.re`
+/// pairs, where the latter must be subtracted.
+unsafe fn rollup_conj_acc(acc_re: __m256, acc_im: __m256) -> (f32, f32) {
+    let sign_mask = _mm256_setr_ps(0.0, -0.0, 0.0, -0.0, 0.0, -0.0, 0.0, -0.0);
+    let acc_im = _mm256_xor_ps(acc_im, sign_mask);
+    (sum_avx2_ps(acc_re), sum_avx2_ps(acc_im))
+}
+
+#[inline(always)]
+unsafe fn complex_mul_scalar(x: &mut [f32], y: &[f32], i: usize) {
+    let a_re = *x.get_unchecked(i);
+    let a_im = *x.get_unchecked(i + 1);
+    let b_re = *y.get_unchecked(i);
+    let b_im = *y.get_unchecked(i + 1);
+
+    *x.get_unchecked_mut(i) = (a_re * b_re) - (a_im * b_im);
+    *x.get_unchecked_mut(i + 1) = (a_re * b_im) + (a_im * b_re);
+}
+
+#[inline(always)]
+unsafe fn complex_dot_conj_scalar(x: &[f32], y: &[f32], i: usize) -> (f32, f32) {
+    let a_re = *x.get_unchecked(i);
+    let a_im = *x.get_unchecked(i + 1);
+    let b_re = *y.get_unchecked(i);
+    let b_im = *y.get_unchecked(i + 1);
+
+    ((a_re * b_re) + (a_im * b_im), (a_re * b_im) - (a_im * b_re))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        assert_is_close_vector,
+        get_sample_vectors,
+        simple_complex_dot_conj,
+        simple_complex_mul,
+    };
+
+    #[test]
+    fn test_xany_nofma_add_vertical() {
+        let (mut x, y) = get_sample_vectors(262);
+        let expected = x
+            .iter()
+            .zip(y.iter())
+            .map(|(x, y)| x + y)
+            .collect::<Vec<f32>>();
+        unsafe { c32_xany_avx2_nofma_add_vertical(&mut x, &y) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_sub_vertical() {
+        let (mut x, y) = get_sample_vectors(262);
+        let expected = x
+            .iter()
+            .zip(y.iter())
+            .map(|(x, y)| x - y)
+            .collect::<Vec<f32>>();
+        unsafe { c32_xany_avx2_nofma_sub_vertical(&mut x, &y) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_mul_vertical() {
+        let (mut x, y) = get_sample_vectors(262);
+        let expected = simple_complex_mul(&x, &y);
+        unsafe { c32_xany_avx2_nofma_mul_vertical(&mut x, &y) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_xany_fma_mul_vertical() {
+        let (mut x, y) = get_sample_vectors(262);
+        let expected = simple_complex_mul(&x, &y);
+        unsafe { c32_xany_avx2_fma_mul_vertical(&mut x, &y) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_dot_conj() {
+        let (x, y) = get_sample_vectors(262);
+        let (expected_re, expected_im) = simple_complex_dot_conj(&x, &y);
+        let (re, im) = unsafe { c32_xany_avx2_nofma_dot_conj(&x, &y) };
+        assert_is_close(re, expected_re);
+        assert_is_close(im, expected_im);
+    }
+
+    #[test]
+    fn test_xany_fma_dot_conj() {
+        let (x, y) = get_sample_vectors(262);
+        let (expected_re, expected_im) = simple_complex_dot_conj(&x, &y);
+        let (re, im) = unsafe { c32_xany_avx2_fma_dot_conj(&x, &y) };
+        assert_is_close(re, expected_re);
+        assert_is_close(im, expected_im);
+    }
+}
@@ -0,0 +1,277 @@
+use core::arch::x86_64::*;
+
+use half::f16;
+
+use crate::danger::sum_avx2_ps;
+use crate::math::*;
+
+#[target_feature(enable = "avx2", enable = "f16c")]
+#[inline]
+/// Computes the dot product of two `f16` vectors, converting each element to `f32`
+/// as it is loaded and accumulating in `f32`.
+///
+/// ```py
+/// D: int
+/// total: f32
+/// x: [f16; D]
+/// y: [f16; D]
+///
+/// for i in 0..D:
+///     total = total + (f32(x[i]) * f32(y[i]))
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and F16C instructions are available, if this method is
+/// executed on systems without them, it will lead to an `ILLEGAL_INSTRUCTION` error.
+/// Callers should check `is_x86_feature_detected!("f16c")` and use
+/// [f16_xany_fallback_nofma_dot_f32](crate::danger::f16_xany_fallback_nofma_dot_f32)
+/// when it is not available.
+pub unsafe fn f16_xany_avx2_nofma_dot_f32(x: &[f16], y: &[f16]) -> f32 {
+    f16_xany_avx2_nofma_dot_plus_f32(x, y, 0.0)
+}
+
+#[target_feature(enable = "avx2", enable = "f16c")]
+#[inline]
+/// Computes `bias` plus the dot product of two `f16` vectors, converting each element
+/// to `f32` as it is loaded and accumulating in `f32`.
+///
+/// The accumulator is seeded with `bias` rather than adding it to the result,
+/// which saves a separate add when applying the bias of a linear layer.
+///
+/// ```py
+/// D: int
+/// total: f32 = bias
+/// x: [f16; D]
+/// y: [f16; D]
+///
+/// for i in 0..D:
+///     total = total + (f32(x[i]) * f32(y[i]))
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and F16C instructions are available, if this method is
+/// executed on systems without them, it will lead to an `ILLEGAL_INSTRUCTION` error.
+/// Callers should check `is_x86_feature_detected!("f16c")` and use
+/// [f16_xany_fallback_nofma_dot_f32](crate::danger::f16_xany_fallback_nofma_dot_f32)
+/// when it is not available.
+pub unsafe fn f16_xany_avx2_nofma_dot_plus_f32(x: &[f16], y: &[f16], bias: f32) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm256_zextps128_ps256(_mm_set_ss(bias));
+    let mut acc2 = _mm256_setzero_ps();
+    let mut acc3 = _mm256_setzero_ps();
+    let mut acc4 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = load_f16_x8(x_ptr.add(i));
+        let x2 = load_f16_x8(x_ptr.add(i + 8));
+        let x3 = load_f16_x8(x_ptr.add(i + 16));
+        let x4 = load_f16_x8(x_ptr.add(i + 24));
+        let y1 = load_f16_x8(y_ptr.add(i));
+        let y2 = load_f16_x8(y_ptr.add(i + 8));
+        let y3 = load_f16_x8(y_ptr.add(i + 16));
+        let y4 = load_f16_x8(y_ptr.add(i + 24));
+
+        acc1 = _mm256_add_ps(acc1, _mm256_mul_ps(x1, y1));
+        acc2 = _mm256_add_ps(acc2, _mm256_mul_ps(x2, y2));
+        acc3 = _mm256_add_ps(acc3, _mm256_mul_ps(x3, y3));
+        acc4 = _mm256_add_ps(acc4, _mm256_mul_ps(x4, y4));
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let x = load_f16_x8(x_ptr.add(i));
+        let y = load_f16_x8(y_ptr.add(i));
+        acc1 = _mm256_add_ps(acc1, _mm256_mul_ps(x, y));
+
+        i += 8;
+    }
+
+    acc1 = _mm256_add_ps(_mm256_add_ps(acc1, acc2), _mm256_add_ps(acc3, acc4));
+    let mut total = sum_avx2_ps(acc1);
+
+    while i < len {
+        let x = x.get_unchecked(i).to_f32();
+        let y = y.get_unchecked(i).to_f32();
+        total = AutoMath::add(total, AutoMath::mul(x, y));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[target_feature(enable = "avx2", enable = "f16c", enable = "fma")]
+#[inline]
+/// Computes the dot product of two `f16` vectors, converting each element to `f32`
+/// as it is loaded and accumulating in `f32`.
+///
+/// ```py
+/// D: int
+/// total: f32
+/// x: [f16; D]
+/// y: [f16; D]
+///
+/// for i in 0..D:
+///     total = total + (f32(x[i]) * f32(y[i]))
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2, FMA and F16C instructions are available, if this method is
+/// executed on systems without them, it will lead to an `ILLEGAL_INSTRUCTION` error.
+/// Callers should check `is_x86_feature_detected!("f16c")` and use
+/// [f16_xany_fallback_nofma_dot_f32](crate::danger::f16_xany_fallback_nofma_dot_f32)
+/// when it is not available.
+pub unsafe fn f16_xany_avx2_fma_dot_f32(x: &[f16], y: &[f16]) -> f32 {
+    f16_xany_avx2_fma_dot_plus_f32(x, y, 0.0)
+}
+
+#[target_feature(enable = "avx2", enable = "f16c", enable = "fma")]
+#[inline]
+/// Computes `bias` plus the dot product of two `f16` vectors, converting each element
+/// to `f32` as it is loaded and accumulating in `f32`.
+///
+/// The accumulator is seeded with `bias` rather than adding it to the result,
+/// which saves a separate add when applying the bias of a linear layer.
+///
+/// ```py
+/// D: int
+/// total: f32 = bias
+/// x: [f16; D]
+/// y: [f16; D]
+///
+/// for i in 0..D:
+///     total = total + (f32(x[i]) * f32(y[i]))
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2, FMA and F16C instructions are available, if this method is
+/// executed on systems without them, it will lead to an `ILLEGAL_INSTRUCTION` error.
+/// Callers should check `is_x86_feature_detected!("f16c")` and use
+/// [f16_xany_fallback_nofma_dot_f32](crate::danger::f16_xany_fallback_nofma_dot_f32)
+/// when it is not available.
+pub unsafe fn f16_xany_avx2_fma_dot_plus_f32(x: &[f16], y: &[f16], bias: f32) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm256_zextps128_ps256(_mm_set_ss(bias));
+    let mut acc2 = _mm256_setzero_ps();
+    let mut acc3 = _mm256_setzero_ps();
+    let mut acc4 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = load_f16_x8(x_ptr.add(i));
+        let x2 = load_f16_x8(x_ptr.add(i + 8));
+        let x3 = load_f16_x8(x_ptr.add(i + 16));
+        let x4 = load_f16_x8(x_ptr.add(i + 24));
+        let y1 = load_f16_x8(y_ptr.add(i));
+        let y2 = load_f16_x8(y_ptr.add(i + 8));
+        let y3 = load_f16_x8(y_ptr.add(i + 16));
+        let y4 = load_f16_x8(y_ptr.add(i + 24));
+
+        acc1 = _mm256_fmadd_ps(x1, y1, acc1);
+        acc2 = _mm256_fmadd_ps(x2, y2, acc2);
+        acc3 = _mm256_fmadd_ps(x3, y3, acc3);
+        acc4 = _mm256_fmadd_ps(x4, y4, acc4);
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let x = load_f16_x8(x_ptr.add(i));
+        let y = load_f16_x8(y_ptr.add(i));
+        acc1 = _mm256_fmadd_ps(x, y, acc1);
+
+        i += 8;
+    }
+
+    acc1 = _mm256_add_ps(_mm256_add_ps(acc1, acc2), _mm256_add_ps(acc3, acc4));
+    let mut total = sum_avx2_ps(acc1);
+
+    while i < len {
+        let x = x.get_unchecked(i).to_f32();
+        let y = y.get_unchecked(i).to_f32();
+        total = AutoMath::add(total, AutoMath::mul(x, y));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// Loads 8 `f16` values and converts them to `f32`.
+unsafe fn load_f16_x8(ptr: *const f16) -> __m256 {
+    _mm256_cvtph_ps(_mm_loadu_si128(ptr.cast()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    fn get_f16_sample_vectors(size: usize) -> (Vec<f16>, Vec<f16>, f32) {
+        let (x, y) = get_sample_vectors::<f32>(size);
+        let x = x.into_iter().map(f16::from_f32).collect::<Vec<_>>();
+        let y = y.into_iter().map(f16::from_f32).collect::<Vec<_>>();
+
+        let x_f32 = x.iter().map(|v| v.to_f32()).collect::<Vec<_>>();
+        let y_f32 = y.iter().map(|v| v.to_f32()).collect::<Vec<_>>();
+        let expected = simple_dot(&x_f32, &y_f32);
+
+        (x, y, expected)
+    }
+
+    #[test]
+    fn test_xany_nofma_dot_f32() {
+        let (x, y, expected) = get_f16_sample_vectors(131);
+        let dist = unsafe { f16_xany_avx2_nofma_dot_f32(&x, &y) };
+        assert_is_close(dist, expected);
+    }
+
+    #[test]
+    fn test_xany_fma_dot_f32() {
+        let (x, y, expected) = get_f16_sample_vectors(131);
+        let dist = unsafe { f16_xany_avx2_fma_dot_f32(&x, &y) };
+        assert_is_close(dist, expected);
+    }
+
+    #[test]
+    fn test_xany_dot_plus_f32() {
+        let (x, y, expected) = get_f16_sample_vectors(131);
+        let dist = unsafe { f16_xany_avx2_nofma_dot_plus_f32(&x, &y, 1.5) };
+        assert_is_close(dist, expected + 1.5);
+        let dist = unsafe { f16_xany_avx2_fma_dot_plus_f32(&x, &y, 1.5) };
+        assert_is_close(dist, expected + 1.5);
+    }
+}
@@ -0,0 +1,103 @@
+use half::f16;
+
+use crate::math::*;
+
+#[inline]
+/// Computes the dot product of two `f16` vectors, converting each element to `f32`
+/// and accumulating in `f32`.
+///
+/// ```py
+/// D: int
+/// total: f32
+/// x: [f16; D]
+/// y: [f16; D]
+///
+/// for i in 0..D:
+///     total = total + (f32(x[i]) * f32(y[i]))
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f16_xany_fallback_nofma_dot_f32(x: &[f16], y: &[f16]) -> f32 {
+    f16_xany_fallback_nofma_dot_plus_f32(x, y, 0.0)
+}
+
+#[inline]
+/// Computes `bias` plus the dot product of two `f16` vectors, converting each element
+/// to `f32` and accumulating in `f32`.
+///
+/// The accumulator is seeded with `bias` rather than adding it to the result,
+/// which saves a separate add when applying the bias of a linear layer.
+///
+/// ```py
+/// D: int
+/// total: f32 = bias
+/// x: [f16; D]
+/// y: [f16; D]
+///
+/// for i in 0..D:
+///     total = total + (f32(x[i]) * f32(y[i]))
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f16_xany_fallback_nofma_dot_plus_f32(
+    x: &[f16],
+    y: &[f16],
+    bias: f32,
+) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let mut total = bias;
+    for i in 0..x.len() {
+        let x = x.get_unchecked(i).to_f32();
+        let y = y.get_unchecked(i).to_f32();
+        total = AutoMath::add(total, AutoMath::mul(x, y));
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_xany_nofma_dot_f32() {
+        let (x, y) = get_sample_vectors::<f32>(514);
+        let x = x.into_iter().map(f16::from_f32).collect::<Vec<_>>();
+        let y = y.into_iter().map(f16::from_f32).collect::<Vec<_>>();
+
+        let x_f32 = x.iter().map(|v| v.to_f32()).collect::<Vec<_>>();
+        let y_f32 = y.iter().map(|v| v.to_f32()).collect::<Vec<_>>();
+
+        let dist = unsafe { f16_xany_fallback_nofma_dot_f32(&x, &y) };
+        assert_is_close(dist, simple_dot(&x_f32, &y_f32));
+    }
+
+    #[test]
+    fn test_xany_nofma_dot_plus_f32() {
+        let (x, y) = get_sample_vectors::<f32>(514);
+        let x = x.into_iter().map(f16::from_f32).collect::<Vec<_>>();
+        let y = y.into_iter().map(f16::from_f32).collect::<Vec<_>>();
+
+        let x_f32 = x.iter().map(|v| v.to_f32()).collect::<Vec<_>>();
+        let y_f32 = y.iter().map(|v| v.to_f32()).collect::<Vec<_>>();
+
+        let dist = unsafe { f16_xany_fallback_nofma_dot_plus_f32(&x, &y, 1.5) };
+        assert_is_close(dist, simple_dot(&x_f32, &y_f32) + 1.5);
+    }
+}
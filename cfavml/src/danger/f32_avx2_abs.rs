@@ -0,0 +1,114 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the absolute value of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = abs(x[i])
+/// ```
+///
+/// The sign bit is cleared, so `-0.0` becomes `0.0` and `NaN` inputs stay `NaN`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_abs(x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    f32_avx2_abs_to(x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the absolute value of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = abs(x[i])
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_abs_inplace(x: &mut [f32]) {
+    let x_ptr = x.as_mut_ptr();
+    f32_avx2_abs_to(x_ptr, x_ptr, x.len())
+}
+
+#[inline(always)]
+/// Computes the absolute value of the values read from `x_ptr` writing the output
+/// to the `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn f32_avx2_abs_to(x_ptr: *const f32, result: *mut f32, len: usize) {
+    let offset_from = len % 32;
+
+    // Clearing the sign bit, this also maps `-0.0` to `0.0` and keeps `NaN` a `NaN`.
+    let sign_mask = _mm256_set1_ps(-0.0);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let v1 = _mm256_loadu_ps(x_ptr.add(i));
+        let v2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let v3 = _mm256_loadu_ps(x_ptr.add(i + 16));
+        let v4 = _mm256_loadu_ps(x_ptr.add(i + 24));
+
+        _mm256_storeu_ps(result.add(i), _mm256_andnot_ps(sign_mask, v1));
+        _mm256_storeu_ps(result.add(i + 8), _mm256_andnot_ps(sign_mask, v2));
+        _mm256_storeu_ps(result.add(i + 16), _mm256_andnot_ps(sign_mask, v3));
+        _mm256_storeu_ps(result.add(i + 24), _mm256_andnot_ps(sign_mask, v4));
+
+        i += 32;
+    }
+
+    while i < len {
+        *result.add(i) = (*x_ptr.add(i)).abs();
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_abs() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let mut x = x.iter().zip(y).map(|(a, b)| a - b).collect::<Vec<_>>();
+        x[3] = -0.0;
+        x[4] = f32::NEG_INFINITY;
+
+        let expected = x.iter().map(|v| v.abs()).collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_nofma_abs(&x, &mut result) };
+        assert_eq!(result, expected);
+        assert_eq!(result[3].to_bits(), 0.0f32.to_bits());
+
+        unsafe { f32_xany_avx2_nofma_abs_inplace(&mut x) };
+        assert_eq!(x, expected);
+    }
+}
@@ -0,0 +1,108 @@
+use core::arch::x86_64::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the absolute difference `|x[i] - y[i]|` element wise, writing the result
+/// to `x`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = abs(x[i] - y[i])
+/// ```
+///
+/// `NaN` is propagated as with a regular subtraction.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_abs_diff_vertical(x: &mut [f32], y: &[f32]) {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_mut_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let x3 = _mm256_loadu_ps(x_ptr.add(i + 16));
+        let x4 = _mm256_loadu_ps(x_ptr.add(i + 24));
+
+        let y1 = _mm256_loadu_ps(y_ptr.add(i));
+        let y2 = _mm256_loadu_ps(y_ptr.add(i + 8));
+        let y3 = _mm256_loadu_ps(y_ptr.add(i + 16));
+        let y4 = _mm256_loadu_ps(y_ptr.add(i + 24));
+
+        _mm256_storeu_ps(x_ptr.add(i), abs_diff(x1, y1));
+        _mm256_storeu_ps(x_ptr.add(i + 8), abs_diff(x2, y2));
+        _mm256_storeu_ps(x_ptr.add(i + 16), abs_diff(x3, y3));
+        _mm256_storeu_ps(x_ptr.add(i + 24), abs_diff(x4, y4));
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+        let y = _mm256_loadu_ps(y_ptr.add(i));
+        _mm256_storeu_ps(x_ptr.add(i), abs_diff(x, y));
+
+        i += 8;
+    }
+
+    while i < len {
+        let x = x_ptr.add(i);
+        *x = (*x - *y_ptr.add(i)).abs();
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+unsafe fn abs_diff(x: __m256, y: __m256) -> __m256 {
+    // Clearing the sign bit of the difference.
+    _mm256_andnot_ps(_mm256_set1_ps(-0.0), _mm256_sub_ps(x, y))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_abs_diff_vertical() {
+        for len in [0, 3, 8, 33, 531] {
+            let (x, y) = get_sample_vectors::<f32>(len);
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(a, b)| (a - b).abs())
+                .collect::<Vec<_>>();
+
+            let mut result = x.clone();
+            unsafe { f32_xany_avx2_nofma_abs_diff_vertical(&mut result, &y) };
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_abs_diff_vertical_nan() {
+        let mut x = vec![1.0; 9];
+        let mut y = vec![3.0; 9];
+        x[2] = f32::NAN;
+        y[8] = f32::NAN;
+
+        unsafe { f32_xany_avx2_nofma_abs_diff_vertical(&mut x, &y) };
+        assert!(x[2].is_nan() && x[8].is_nan());
+        assert_eq!(x[0], 2.0);
+    }
+}
@@ -0,0 +1,418 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes `alpha * x + beta * y` element wise, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// alpha: f32
+/// beta: f32
+/// x: [f32; D]
+/// y: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = alpha * x[i] + beta * y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_axpby(
+    alpha: f32,
+    beta: f32,
+    x: &[f32],
+    y: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        ![x, y].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    f32_avx2_nofma_axpby_to(alpha, beta, x, y.as_ptr(), result.as_mut_ptr())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes `alpha * x + beta * y` element wise, writing the result back to `y`.
+///
+/// ```py
+/// D: int
+/// alpha: f32
+/// beta: f32
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     y[i] = alpha * x[i] + beta * y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_axpby_inplace(
+    alpha: f32,
+    beta: f32,
+    x: &[f32],
+    y: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), y.len());
+
+    let y_ptr = y.as_mut_ptr();
+    f32_avx2_nofma_axpby_to(alpha, beta, x, y_ptr, y_ptr)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the exponential moving average of `prev` and `new` element wise,
+/// writing the result back to `prev`.
+///
+/// ```py
+/// D: int
+/// alpha: f32
+/// prev: [f32; D]
+/// new: [f32; D]
+///
+/// for i in 0..D:
+///     prev[i] = alpha * new[i] + (1 - alpha) * prev[i]
+/// ```
+///
+/// This is [f32_xany_avx2_nofma_axpby_inplace] with the coefficients coupled, `alpha` is the weight given
+/// to the new observation.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_ema_inplace(
+    alpha: f32,
+    prev: &mut [f32],
+    new: &[f32],
+) {
+    f32_xany_avx2_nofma_axpby_inplace(alpha, 1.0 - alpha, new, prev)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes `alpha * x + beta * y` element wise, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// alpha: f32
+/// beta: f32
+/// x: [f32; D]
+/// y: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = alpha * x[i] + beta * y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_axpby(
+    alpha: f32,
+    beta: f32,
+    x: &[f32],
+    y: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        ![x, y].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    f32_avx2_fma_axpby_to(alpha, beta, x, y.as_ptr(), result.as_mut_ptr())
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes `alpha * x + beta * y` element wise, writing the result back to `y`.
+///
+/// ```py
+/// D: int
+/// alpha: f32
+/// beta: f32
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     y[i] = alpha * x[i] + beta * y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_axpby_inplace(
+    alpha: f32,
+    beta: f32,
+    x: &[f32],
+    y: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), y.len());
+
+    let y_ptr = y.as_mut_ptr();
+    f32_avx2_fma_axpby_to(alpha, beta, x, y_ptr, y_ptr)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the exponential moving average of `prev` and `new` element wise,
+/// writing the result back to `prev`.
+///
+/// ```py
+/// D: int
+/// alpha: f32
+/// prev: [f32; D]
+/// new: [f32; D]
+///
+/// for i in 0..D:
+///     prev[i] = alpha * new[i] + (1 - alpha) * prev[i]
+/// ```
+///
+/// This is [f32_xany_avx2_fma_axpby_inplace] with the coefficients coupled, `alpha` is the weight given
+/// to the new observation.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_ema_inplace(alpha: f32, prev: &mut [f32], new: &[f32]) {
+    f32_xany_avx2_fma_axpby_inplace(alpha, 1.0 - alpha, new, prev)
+}
+
+#[inline(always)]
+/// Computes `alpha * x + beta * y` writing the output to the `result` pointer.
+///
+/// `y` and `result` may point to the same memory.
+unsafe fn f32_avx2_nofma_axpby_to(
+    alpha: f32,
+    beta: f32,
+    x: &[f32],
+    y_ptr: *const f32,
+    result: *mut f32,
+) {
+    let len = x.len();
+    let offset_from = len % 32;
+    let x_ptr = x.as_ptr();
+
+    let alpha_reg = _mm256_set1_ps(alpha);
+    let beta_reg = _mm256_set1_ps(beta);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x0 = _mm256_loadu_ps(x_ptr.add(i));
+        let x1 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 16));
+        let x3 = _mm256_loadu_ps(x_ptr.add(i + 24));
+        let y0 = _mm256_loadu_ps(y_ptr.add(i));
+        let y1 = _mm256_loadu_ps(y_ptr.add(i + 8));
+        let y2 = _mm256_loadu_ps(y_ptr.add(i + 16));
+        let y3 = _mm256_loadu_ps(y_ptr.add(i + 24));
+
+        _mm256_storeu_ps(
+            result.add(i),
+            _mm256_add_ps(_mm256_mul_ps(alpha_reg, x0), _mm256_mul_ps(beta_reg, y0)),
+        );
+        _mm256_storeu_ps(
+            result.add(i + 8),
+            _mm256_add_ps(_mm256_mul_ps(alpha_reg, x1), _mm256_mul_ps(beta_reg, y1)),
+        );
+        _mm256_storeu_ps(
+            result.add(i + 16),
+            _mm256_add_ps(_mm256_mul_ps(alpha_reg, x2), _mm256_mul_ps(beta_reg, y2)),
+        );
+        _mm256_storeu_ps(
+            result.add(i + 24),
+            _mm256_add_ps(_mm256_mul_ps(alpha_reg, x3), _mm256_mul_ps(beta_reg, y3)),
+        );
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+        let y = _mm256_loadu_ps(y_ptr.add(i));
+        _mm256_storeu_ps(
+            result.add(i),
+            _mm256_add_ps(_mm256_mul_ps(alpha_reg, x), _mm256_mul_ps(beta_reg, y)),
+        );
+
+        i += 8;
+    }
+
+    while i < len {
+        let x = *x_ptr.add(i);
+        let y = *y_ptr.add(i);
+        *result.add(i) = AutoMath::add(AutoMath::mul(alpha, x), AutoMath::mul(beta, y));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Computes `alpha * x + beta * y` writing the output to the `result` pointer.
+///
+/// `y` and `result` may point to the same memory.
+unsafe fn f32_avx2_fma_axpby_to(
+    alpha: f32,
+    beta: f32,
+    x: &[f32],
+    y_ptr: *const f32,
+    result: *mut f32,
+) {
+    let len = x.len();
+    let offset_from = len % 32;
+    let x_ptr = x.as_ptr();
+
+    let alpha_reg = _mm256_set1_ps(alpha);
+    let beta_reg = _mm256_set1_ps(beta);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x0 = _mm256_loadu_ps(x_ptr.add(i));
+        let x1 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 16));
+        let x3 = _mm256_loadu_ps(x_ptr.add(i + 24));
+        let y0 = _mm256_loadu_ps(y_ptr.add(i));
+        let y1 = _mm256_loadu_ps(y_ptr.add(i + 8));
+        let y2 = _mm256_loadu_ps(y_ptr.add(i + 16));
+        let y3 = _mm256_loadu_ps(y_ptr.add(i + 24));
+
+        _mm256_storeu_ps(
+            result.add(i),
+            _mm256_fmadd_ps(alpha_reg, x0, _mm256_mul_ps(beta_reg, y0)),
+        );
+        _mm256_storeu_ps(
+            result.add(i + 8),
+            _mm256_fmadd_ps(alpha_reg, x1, _mm256_mul_ps(beta_reg, y1)),
+        );
+        _mm256_storeu_ps(
+            result.add(i + 16),
+            _mm256_fmadd_ps(alpha_reg, x2, _mm256_mul_ps(beta_reg, y2)),
+        );
+        _mm256_storeu_ps(
+            result.add(i + 24),
+            _mm256_fmadd_ps(alpha_reg, x3, _mm256_mul_ps(beta_reg, y3)),
+        );
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+        let y = _mm256_loadu_ps(y_ptr.add(i));
+        _mm256_storeu_ps(
+            result.add(i),
+            _mm256_fmadd_ps(alpha_reg, x, _mm256_mul_ps(beta_reg, y)),
+        );
+
+        i += 8;
+    }
+
+    while i < len {
+        let x = *x_ptr.add(i);
+        let y = *y_ptr.add(i);
+        *result.add(i) = AutoMath::add(AutoMath::mul(alpha, x), AutoMath::mul(beta, y));
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close_vector, get_sample_vectors};
+
+    #[test]
+    fn test_xany_nofma_axpby() {
+        let (x, mut y) = get_sample_vectors::<f32>(131);
+        let expected = x
+            .iter()
+            .zip(y.iter())
+            .map(|(x, y)| 0.5 * x + 1.5 * y)
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_nofma_axpby(0.5, 1.5, &x, &y, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        unsafe { f32_xany_avx2_nofma_axpby_inplace(0.5, 1.5, &x, &mut y) };
+        assert_is_close_vector(&y, &expected);
+    }
+
+    #[test]
+    fn test_xany_fma_axpby() {
+        let (x, mut y) = get_sample_vectors::<f32>(131);
+        let expected = x
+            .iter()
+            .zip(y.iter())
+            .map(|(x, y)| 0.5 * x + 1.5 * y)
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_fma_axpby(0.5, 1.5, &x, &y, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        unsafe { f32_xany_avx2_fma_axpby_inplace(0.5, 1.5, &x, &mut y) };
+        assert_is_close_vector(&y, &expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_ema_inplace() {
+        let (new, mut prev) = get_sample_vectors::<f32>(131);
+        let expected = new
+            .iter()
+            .zip(prev.iter())
+            .map(|(new, prev)| 0.25 * new + 0.75 * prev)
+            .collect::<Vec<f32>>();
+
+        unsafe { f32_xany_avx2_nofma_ema_inplace(0.25, &mut prev, &new) };
+        assert_is_close_vector(&prev, &expected);
+    }
+
+    #[test]
+    fn test_xany_fma_ema_inplace() {
+        let (new, mut prev) = get_sample_vectors::<f32>(131);
+        let expected = new
+            .iter()
+            .zip(prev.iter())
+            .map(|(new, prev)| 0.25 * new + 0.75 * prev)
+            .collect::<Vec<f32>>();
+
+        unsafe { f32_xany_avx2_fma_ema_inplace(0.25, &mut prev, &new) };
+        assert_is_close_vector(&prev, &expected);
+    }
+}
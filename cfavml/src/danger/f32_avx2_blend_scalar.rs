@@ -0,0 +1,463 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Blends each element of `x` towards the constant `c` by `t`, writing the result
+/// to `result`.
+///
+/// ```py
+/// D: int
+/// t: f32
+/// c: f32
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = x[i] * (1 - t) + c * t
+/// ```
+///
+/// A `t` of `0` leaves `x` unchanged and a `t` of `1` sets every element to `c`, i.e.
+/// fading an image towards black or shrinking weights towards zero.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_blend_scalar(
+    t: f32,
+    x: &[f32],
+    c: f32,
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    blend_scalar_to::<false>(t, x.as_ptr(), c, result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Blends each element of `x` towards the constant `c` by `t` in place.
+///
+/// ```py
+/// D: int
+/// t: f32
+/// c: f32
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] * (1 - t) + c * t
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_blend_scalar_inplace(t: f32, x: &mut [f32], c: f32) {
+    let x_ptr = x.as_mut_ptr();
+    blend_scalar_to::<false>(t, x_ptr, c, x_ptr, x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Blends each element of `x` towards the constant `c` by the matching element of
+/// `t`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// c: f32
+/// t: [f32; D]
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = x[i] * (1 - t[i]) + c * t[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_blend_scalar_vertical(
+    t: &[f32],
+    x: &[f32],
+    c: f32,
+    result: &mut [f32],
+) {
+    debug_assert_eq!(t.len(), x.len());
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        ![t, x].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    blend_scalar_vertical_to::<false>(t, x.as_ptr(), c, result.as_mut_ptr())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Blends each element of `x` towards the constant `c` by the matching element of
+/// `t` in place.
+///
+/// ```py
+/// D: int
+/// c: f32
+/// t: [f32; D]
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] * (1 - t[i]) + c * t[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_blend_scalar_vertical_inplace(
+    t: &[f32],
+    x: &mut [f32],
+    c: f32,
+) {
+    debug_assert_eq!(t.len(), x.len());
+
+    let x_ptr = x.as_mut_ptr();
+    blend_scalar_vertical_to::<false>(t, x_ptr, c, x_ptr)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Blends each element of `x` towards the constant `c` by `t`, writing the result
+/// to `result`.
+///
+/// ```py
+/// D: int
+/// t: f32
+/// c: f32
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = x[i] * (1 - t) + c * t
+/// ```
+///
+/// A `t` of `0` leaves `x` unchanged and a `t` of `1` sets every element to `c`, i.e.
+/// fading an image towards black or shrinking weights towards zero.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_blend_scalar(
+    t: f32,
+    x: &[f32],
+    c: f32,
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    blend_scalar_to::<true>(t, x.as_ptr(), c, result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Blends each element of `x` towards the constant `c` by `t` in place.
+///
+/// ```py
+/// D: int
+/// t: f32
+/// c: f32
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] * (1 - t) + c * t
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_blend_scalar_inplace(t: f32, x: &mut [f32], c: f32) {
+    let x_ptr = x.as_mut_ptr();
+    blend_scalar_to::<true>(t, x_ptr, c, x_ptr, x.len())
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Blends each element of `x` towards the constant `c` by the matching element of
+/// `t`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// c: f32
+/// t: [f32; D]
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = x[i] * (1 - t[i]) + c * t[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_blend_scalar_vertical(
+    t: &[f32],
+    x: &[f32],
+    c: f32,
+    result: &mut [f32],
+) {
+    debug_assert_eq!(t.len(), x.len());
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        ![t, x].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    blend_scalar_vertical_to::<true>(t, x.as_ptr(), c, result.as_mut_ptr())
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Blends each element of `x` towards the constant `c` by the matching element of
+/// `t` in place.
+///
+/// ```py
+/// D: int
+/// c: f32
+/// t: [f32; D]
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] * (1 - t[i]) + c * t[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_blend_scalar_vertical_inplace(
+    t: &[f32],
+    x: &mut [f32],
+    c: f32,
+) {
+    debug_assert_eq!(t.len(), x.len());
+
+    let x_ptr = x.as_mut_ptr();
+    blend_scalar_vertical_to::<true>(t, x_ptr, c, x_ptr)
+}
+
+#[inline(always)]
+/// Computes `a * b + c`, fused when `FMA` is set, in which case the caller must have
+/// the FMA target feature enabled.
+unsafe fn mul_add<const FMA: bool>(a: __m256, b: __m256, c: __m256) -> __m256 {
+    if FMA {
+        _mm256_fmadd_ps(a, b, c)
+    } else {
+        _mm256_add_ps(_mm256_mul_ps(a, b), c)
+    }
+}
+
+#[inline(always)]
+/// Computes `x * (1 - t) + c * t` writing the output to the `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn blend_scalar_to<const FMA: bool>(
+    t: f32,
+    x_ptr: *const f32,
+    c: f32,
+    result: *mut f32,
+    len: usize,
+) {
+    let offset_from = len % 32;
+
+    // Both coefficients are computed once, so `t` of `0` or `1` are exact.
+    let scale = AutoMath::sub(1.0, t);
+    let offset = AutoMath::mul(c, t);
+    let scale_reg = _mm256_set1_ps(scale);
+    let offset_reg = _mm256_set1_ps(offset);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x0 = _mm256_loadu_ps(x_ptr.add(i));
+        let x1 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 16));
+        let x3 = _mm256_loadu_ps(x_ptr.add(i + 24));
+
+        _mm256_storeu_ps(result.add(i), mul_add::<FMA>(x0, scale_reg, offset_reg));
+        _mm256_storeu_ps(result.add(i + 8), mul_add::<FMA>(x1, scale_reg, offset_reg));
+        _mm256_storeu_ps(
+            result.add(i + 16),
+            mul_add::<FMA>(x2, scale_reg, offset_reg),
+        );
+        _mm256_storeu_ps(
+            result.add(i + 24),
+            mul_add::<FMA>(x3, scale_reg, offset_reg),
+        );
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+        _mm256_storeu_ps(result.add(i), mul_add::<FMA>(x, scale_reg, offset_reg));
+
+        i += 8;
+    }
+
+    while i < len {
+        *result.add(i) = AutoMath::add(AutoMath::mul(*x_ptr.add(i), scale), offset);
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Computes `x * (1 - t) + c * t` with a per-element `t` writing the output to the
+/// `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn blend_scalar_vertical_to<const FMA: bool>(
+    t: &[f32],
+    x_ptr: *const f32,
+    c: f32,
+    result: *mut f32,
+) {
+    let len = t.len();
+    let offset_from = len % 8;
+    let t_ptr = t.as_ptr();
+
+    let one = _mm256_set1_ps(1.0);
+    let c_reg = _mm256_set1_ps(c);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let t = _mm256_loadu_ps(t_ptr.add(i));
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+
+        let r = mul_add::<FMA>(x, _mm256_sub_ps(one, t), _mm256_mul_ps(c_reg, t));
+        _mm256_storeu_ps(result.add(i), r);
+
+        i += 8;
+    }
+
+    while i < len {
+        let t = *t_ptr.add(i);
+        let scaled = AutoMath::mul(*x_ptr.add(i), AutoMath::sub(1.0, t));
+        *result.add(i) = AutoMath::add(scaled, AutoMath::mul(c, t));
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close_vector, get_sample_vectors};
+
+    #[test]
+    fn test_xany_nofma_blend_scalar() {
+        let (mut x, _) = get_sample_vectors::<f32>(131);
+        let expected = x
+            .iter()
+            .map(|v| v * 0.75 + 2.0 * 0.25)
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_nofma_blend_scalar(0.25, &x, 2.0, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        let original = x.clone();
+        unsafe { f32_xany_avx2_nofma_blend_scalar(0.0, &original, 2.0, &mut result) };
+        assert_eq!(result, original);
+        unsafe { f32_xany_avx2_nofma_blend_scalar(1.0, &original, 2.0, &mut result) };
+        assert!(result.iter().all(|v| *v == 2.0));
+
+        unsafe { f32_xany_avx2_nofma_blend_scalar_inplace(0.25, &mut x, 2.0) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_blend_scalar_vertical() {
+        let (mut x, t) = get_sample_vectors::<f32>(131);
+        let expected = x
+            .iter()
+            .zip(t.iter())
+            .map(|(x, t)| x * (1.0 - t) - 3.0 * t)
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_nofma_blend_scalar_vertical(&t, &x, -3.0, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        unsafe { f32_xany_avx2_nofma_blend_scalar_vertical_inplace(&t, &mut x, -3.0) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_xany_fma_blend_scalar() {
+        let (mut x, _) = get_sample_vectors::<f32>(131);
+        let expected = x
+            .iter()
+            .map(|v| v * 0.75 + 2.0 * 0.25)
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_fma_blend_scalar(0.25, &x, 2.0, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        let original = x.clone();
+        unsafe { f32_xany_avx2_fma_blend_scalar(0.0, &original, 2.0, &mut result) };
+        assert_eq!(result, original);
+        unsafe { f32_xany_avx2_fma_blend_scalar(1.0, &original, 2.0, &mut result) };
+        assert!(result.iter().all(|v| *v == 2.0));
+
+        unsafe { f32_xany_avx2_fma_blend_scalar_inplace(0.25, &mut x, 2.0) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_xany_fma_blend_scalar_vertical() {
+        let (mut x, t) = get_sample_vectors::<f32>(131);
+        let expected = x
+            .iter()
+            .zip(t.iter())
+            .map(|(x, t)| x * (1.0 - t) - 3.0 * t)
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_fma_blend_scalar_vertical(&t, &x, -3.0, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        unsafe { f32_xany_avx2_fma_blend_scalar_vertical_inplace(&t, &mut x, -3.0) };
+        assert_is_close_vector(&x, &expected);
+    }
+}
@@ -0,0 +1,324 @@
+use core::arch::x86_64::*;
+
+use crate::danger::RoundingMode;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Converts each `f32` element of `x` to `u8`, rounding in the given [RoundingMode]
+/// and saturating values outside of the `0..=255` range, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [u8; D]
+///
+/// for i in 0..D:
+///     result[i] = clamp(round(x[i], mode), 0, 255)
+/// ```
+///
+/// With [RoundingMode::NearestEven] values exactly half way between two integers are
+/// rounded to the nearest even integer, i.e. `0.5 -> 0` and `1.5 -> 2`. `NaN` values
+/// become `0`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_to_u8_saturating(
+    mode: RoundingMode,
+    x: &[f32],
+    result: &mut [u8],
+) {
+    debug_assert_eq!(x.len(), result.len());
+
+    match mode {
+        RoundingMode::NearestEven => saturating_to::<
+            { _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC },
+        >(mode, x, result),
+        RoundingMode::TowardZero => {
+            saturating_to::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(mode, x, result)
+        },
+        RoundingMode::TowardPositive => saturating_to::<
+            { _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC },
+        >(mode, x, result),
+        RoundingMode::TowardNegative => saturating_to::<
+            { _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC },
+        >(mode, x, result),
+    }
+}
+
+#[inline(always)]
+unsafe fn saturating_to<const ROUND: i32>(
+    mode: RoundingMode,
+    x: &[f32],
+    result: &mut [u8],
+) {
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let min = _mm256_setzero_ps();
+    let max = _mm256_set1_ps(255.0);
+    let order = _mm256_setr_epi32(0, 4, 1, 5, 2, 6, 3, 7);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = clamp_round_ps::<ROUND>(_mm256_loadu_ps(x_ptr.add(i)), min, max);
+        let x2 = clamp_round_ps::<ROUND>(_mm256_loadu_ps(x_ptr.add(i + 8)), min, max);
+        let x3 = clamp_round_ps::<ROUND>(_mm256_loadu_ps(x_ptr.add(i + 16)), min, max);
+        let x4 = clamp_round_ps::<ROUND>(_mm256_loadu_ps(x_ptr.add(i + 24)), min, max);
+
+        _mm256_storeu_si256(
+            result_ptr.add(i).cast(),
+            pack_u8_x32([x1, x2, x3, x4], order),
+        );
+
+        i += 32;
+    }
+
+    while i < len {
+        let x = *x.get_unchecked(i);
+        *result.get_unchecked_mut(i) = mode.round(x).clamp(0.0, 255.0) as u8;
+
+        i += 1;
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Applies the affine transform `x * scale + bias` to each `f32` element of `x` then
+/// converts it to `u8`, rounding in the given [RoundingMode] and saturating values
+/// outside of the `0..=255` range, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [u8; D]
+///
+/// for i in 0..D:
+///     result[i] = clamp(round(x[i] * scale + bias, mode), 0, 255)
+/// ```
+///
+/// This is the denormalization step at the output of image models, fusing the
+/// transform, rounding and saturating conversion into a single pass. Rounding and
+/// `NaN` handling match [f32_xany_avx2_nofma_to_u8_saturating].
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_to_u8_scale_clamp(
+    mode: RoundingMode,
+    scale: f32,
+    bias: f32,
+    x: &[f32],
+    result: &mut [u8],
+) {
+    debug_assert_eq!(x.len(), result.len());
+
+    match mode {
+        RoundingMode::NearestEven => scale_clamp_to::<
+            { _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC },
+        >(mode, scale, bias, x, result),
+        RoundingMode::TowardZero => scale_clamp_to::<
+            { _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC },
+        >(mode, scale, bias, x, result),
+        RoundingMode::TowardPositive => scale_clamp_to::<
+            { _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC },
+        >(mode, scale, bias, x, result),
+        RoundingMode::TowardNegative => scale_clamp_to::<
+            { _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC },
+        >(mode, scale, bias, x, result),
+    }
+}
+
+#[inline(always)]
+unsafe fn scale_clamp_to<const ROUND: i32>(
+    mode: RoundingMode,
+    scale: f32,
+    bias: f32,
+    x: &[f32],
+    result: &mut [u8],
+) {
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let scale_reg = _mm256_set1_ps(scale);
+    let bias_reg = _mm256_set1_ps(bias);
+    let min = _mm256_setzero_ps();
+    let max = _mm256_set1_ps(255.0);
+    let order = _mm256_setr_epi32(0, 4, 1, 5, 2, 6, 3, 7);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let [x1, x2, x3, x4] = [0, 8, 16, 24].map(|k| {
+            let x = _mm256_loadu_ps(x_ptr.add(i + k));
+            let x = _mm256_add_ps(_mm256_mul_ps(x, scale_reg), bias_reg);
+            clamp_round_ps::<ROUND>(x, min, max)
+        });
+
+        _mm256_storeu_si256(
+            result_ptr.add(i).cast(),
+            pack_u8_x32([x1, x2, x3, x4], order),
+        );
+
+        i += 32;
+    }
+
+    while i < len {
+        let x = *x.get_unchecked(i) * scale + bias;
+        *result.get_unchecked_mut(i) = mode.round(x).clamp(0.0, 255.0) as u8;
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Clamps the values to `min..=max` and rounds them to `i32` with the
+/// `_mm256_round_ps` control `ROUND`.
+///
+/// The clamp is applied before conversion as `cvtps` does not saturate, `NaN`
+/// values are replaced with `min` as `max_ps` returns the second operand
+/// when either operand is `NaN`. As the bounds are integers, rounding after the
+/// clamp gives the same result as rounding before it.
+unsafe fn clamp_round_ps<const ROUND: i32>(
+    x: __m256,
+    min: __m256,
+    max: __m256,
+) -> __m256i {
+    let clamped = _mm256_min_ps(_mm256_max_ps(x, min), max);
+    _mm256_cvttps_epi32(_mm256_round_ps::<ROUND>(clamped))
+}
+
+#[inline(always)]
+/// Packs 32 `i32` values already within `0..=255` into `u8`s.
+unsafe fn pack_u8_x32(x: [__m256i; 4], order: __m256i) -> __m256i {
+    // `packs` and `packus` operate on each 128-bit lane independently, so the
+    // 32-bit groups must be re-ordered to restore the element order.
+    let packed_i16_1 = _mm256_packs_epi32(x[0], x[1]);
+    let packed_i16_2 = _mm256_packs_epi32(x[2], x[3]);
+    let packed_u8 = _mm256_packus_epi16(packed_i16_1, packed_i16_2);
+    _mm256_permutevar8x32_epi32(packed_u8, order)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_to_u8_saturating() {
+        let mut x = vec![
+            f32::NEG_INFINITY,
+            -1.0,
+            -0.6,
+            -0.5,
+            -0.0,
+            0.0,
+            0.5,
+            1.5,
+            2.5,
+            127.49,
+            254.5,
+            255.0,
+            255.4,
+            255.5,
+            256.0,
+            1e10,
+            f32::INFINITY,
+            f32::NAN,
+        ];
+        x.extend((-400..400).map(|v| v as f32 * 0.75));
+
+        let expected = x
+            .iter()
+            .map(|v| v.round_ties_even().clamp(0.0, 255.0) as u8)
+            .collect::<Vec<_>>();
+
+        let mut result = vec![0; x.len()];
+        unsafe {
+            f32_xany_avx2_nofma_to_u8_saturating(
+                RoundingMode::NearestEven,
+                &x,
+                &mut result,
+            )
+        };
+        assert_eq!(result, expected);
+        assert_eq!(
+            &result[..18],
+            &[0, 0, 0, 0, 0, 0, 0, 2, 2, 127, 254, 255, 255, 255, 255, 255, 255, 0]
+        );
+    }
+
+    #[test]
+    fn test_xany_nofma_to_u8_scale_clamp() {
+        let mut x = vec![-1.0, -0.5, 0.0, 0.5, 0.999, 1.0, 1.5, f32::NAN];
+        x.extend((-400..400).map(|v| v as f32 / 300.0));
+
+        let expected = x
+            .iter()
+            .map(|v| (v * 127.5 + 127.5).round_ties_even().clamp(0.0, 255.0) as u8)
+            .collect::<Vec<_>>();
+
+        let mut result = vec![0; x.len()];
+        unsafe {
+            f32_xany_avx2_nofma_to_u8_scale_clamp(
+                RoundingMode::NearestEven,
+                127.5,
+                127.5,
+                &x,
+                &mut result,
+            )
+        };
+        assert_eq!(result, expected);
+        assert_eq!(&result[..8], &[0, 64, 128, 191, 255, 255, 255, 0]);
+    }
+
+    #[test]
+    fn test_xany_nofma_to_u8_rounding_modes() {
+        let mut x = vec![-0.5, 0.2, 0.5, 1.5, 2.7, 254.2, 254.9, 255.5, f32::NAN];
+        x.extend((-400..400).map(|v| v as f32 * 0.35));
+
+        for mode in [
+            RoundingMode::NearestEven,
+            RoundingMode::TowardZero,
+            RoundingMode::TowardPositive,
+            RoundingMode::TowardNegative,
+        ] {
+            let expected = x
+                .iter()
+                .map(|v| mode.round(*v).clamp(0.0, 255.0) as u8)
+                .collect::<Vec<_>>();
+
+            let mut result = vec![0; x.len()];
+            unsafe { f32_xany_avx2_nofma_to_u8_saturating(mode, &x, &mut result) };
+            assert_eq!(result, expected, "{mode:?}");
+
+            let mut result = vec![0; x.len()];
+            unsafe {
+                f32_xany_avx2_nofma_to_u8_scale_clamp(mode, 1.0, 0.0, &x, &mut result)
+            };
+            assert_eq!(result, expected, "{mode:?}");
+        }
+
+        let mut result = [0; 9];
+        unsafe {
+            f32_xany_avx2_nofma_to_u8_saturating(
+                RoundingMode::TowardPositive,
+                &x[..9],
+                &mut result,
+            )
+        };
+        assert_eq!(result, [0, 1, 1, 2, 3, 255, 255, 255, 0]);
+    }
+}
@@ -0,0 +1,288 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element of `x` to the bounds `lo` and `hi`, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// lo: f32
+/// hi: f32
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = min(max(x[i], lo), hi)
+/// ```
+///
+/// `NaN` elements of `x` are clamped to `lo`, if `lo` is greater than `hi` the
+/// element is set to `hi`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_clamp_value(
+    lo: f32,
+    hi: f32,
+    x: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    clamp_value_to(lo, hi, x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element of `x` to the bounds `lo` and `hi` in place.
+///
+/// ```py
+/// D: int
+/// lo: f32
+/// hi: f32
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = min(max(x[i], lo), hi)
+/// ```
+///
+/// `NaN` elements of `x` are clamped to `lo`, if `lo` is greater than `hi` the
+/// element is set to `hi`.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_clamp_value_inplace(lo: f32, hi: f32, x: &mut [f32]) {
+    let x_ptr = x.as_mut_ptr();
+    clamp_value_to(lo, hi, x_ptr, x_ptr, x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi`, writing the
+/// result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// lo: [f32; D]
+/// hi: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = min(max(x[i], lo[i]), hi[i])
+/// ```
+///
+/// `NaN` elements of `x` are clamped to `lo`, if `lo[i]` is greater than `hi[i]`
+/// the element is set to `hi[i]`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_clamp_vertical(
+    x: &[f32],
+    lo: &[f32],
+    hi: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), lo.len());
+    debug_assert_eq!(x.len(), hi.len());
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        ![x, lo, hi].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    clamp_vertical_to(x.as_ptr(), lo, hi, result.as_mut_ptr())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi` in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// lo: [f32; D]
+/// hi: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = min(max(x[i], lo[i]), hi[i])
+/// ```
+///
+/// `NaN` elements of `x` are clamped to `lo`, if `lo[i]` is greater than `hi[i]`
+/// the element is set to `hi[i]`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_clamp_vertical_inplace(
+    x: &mut [f32],
+    lo: &[f32],
+    hi: &[f32],
+) {
+    debug_assert_eq!(x.len(), lo.len());
+    debug_assert_eq!(x.len(), hi.len());
+
+    let x_ptr = x.as_mut_ptr();
+    clamp_vertical_to(x_ptr, lo, hi, x_ptr)
+}
+
+#[inline(always)]
+/// Clamps the values read from `x_ptr` to `lo` and `hi`, writing the output to the
+/// `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn clamp_value_to(
+    lo: f32,
+    hi: f32,
+    x_ptr: *const f32,
+    result: *mut f32,
+    len: usize,
+) {
+    let offset_from = len % 32;
+    let lo_reg = _mm256_set1_ps(lo);
+    let hi_reg = _mm256_set1_ps(hi);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for j in (0..32).step_by(8) {
+            let x = _mm256_loadu_ps(x_ptr.add(i + j));
+
+            // `vmaxps` returns the second operand if either is `NaN`, so `NaN` is `lo`.
+            let r = _mm256_min_ps(_mm256_max_ps(x, lo_reg), hi_reg);
+            _mm256_storeu_ps(result.add(i + j), r);
+        }
+
+        i += 32;
+    }
+
+    while i < len {
+        *result.add(i) = AutoMath::cmp_min(AutoMath::cmp_max(*x_ptr.add(i), lo), hi);
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Clamps `x` to `lo` and `hi` element wise, writing the output to the `result`
+/// pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn clamp_vertical_to(
+    x_ptr: *const f32,
+    lo: &[f32],
+    hi: &[f32],
+    result: *mut f32,
+) {
+    let len = lo.len();
+    let offset_from = len % 32;
+    let lo_ptr = lo.as_ptr();
+    let hi_ptr = hi.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for j in (0..32).step_by(8) {
+            let x = _mm256_loadu_ps(x_ptr.add(i + j));
+            let lo = _mm256_loadu_ps(lo_ptr.add(i + j));
+            let hi = _mm256_loadu_ps(hi_ptr.add(i + j));
+
+            // `vmaxps` returns the second operand if either is `NaN`, so `NaN` is `lo`.
+            let r = _mm256_min_ps(_mm256_max_ps(x, lo), hi);
+            _mm256_storeu_ps(result.add(i + j), r);
+        }
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+        let lo = _mm256_loadu_ps(lo_ptr.add(i));
+        let hi = _mm256_loadu_ps(hi_ptr.add(i));
+
+        let r = _mm256_min_ps(_mm256_max_ps(x, lo), hi);
+        _mm256_storeu_ps(result.add(i), r);
+
+        i += 8;
+    }
+
+    while i < len {
+        let clamped_lo = AutoMath::cmp_max(*x_ptr.add(i), *lo_ptr.add(i));
+        *result.add(i) = AutoMath::cmp_min(clamped_lo, *hi_ptr.add(i));
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_clamp_vertical() {
+        let (mut x, lo) = get_sample_vectors::<f32>(131);
+        let hi = lo.iter().map(|v| v + 0.25).collect::<Vec<f32>>();
+        x[3] = f32::NAN;
+
+        let expected = x
+            .iter()
+            .zip(lo.iter())
+            .zip(hi.iter())
+            .map(|((x, lo), hi)| x.max(*lo).min(*hi))
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_nofma_clamp_vertical(&x, &lo, &hi, &mut result) };
+        assert_eq!(result, expected);
+        assert_eq!(result[3], lo[3]);
+
+        unsafe { f32_xany_avx2_nofma_clamp_vertical_inplace(&mut x, &lo, &hi) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_clamp_value() {
+        let (mut x, _) = get_sample_vectors::<f32>(131);
+        x[3] = f32::NAN;
+        let expected = x
+            .iter()
+            .map(|v| {
+                if v.is_nan() {
+                    0.25
+                } else {
+                    v.clamp(0.25, 0.75)
+                }
+            })
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_nofma_clamp_value(0.25, 0.75, &x, &mut result) };
+        assert_eq!(result, expected);
+        assert_eq!(result[3], 0.25);
+
+        unsafe { f32_xany_avx2_nofma_clamp_value_inplace(0.25, 0.75, &mut x) };
+        assert_eq!(x, expected);
+    }
+}
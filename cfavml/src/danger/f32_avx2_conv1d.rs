@@ -0,0 +1,209 @@
+use core::arch::x86_64::*;
+
+use crate::danger::{
+    check_conv1d_valid_len,
+    f32_xany_avx2_fma_dot,
+    f32_xany_avx2_nofma_dot,
+};
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the valid mode 1D cross-correlation of `signal` with `kernel`, writing
+/// the result to `result`.
+///
+/// ```py
+/// N: int
+/// K: int
+/// signal: [f32; N]
+/// kernel: [f32; K]
+/// result: [f32; N - K + 1]
+///
+/// for i in 0..N - K + 1:
+///     result[i] = dot(kernel, signal[i:i + K])
+/// ```
+///
+/// Only positions where the kernel fits entirely within the signal are computed,
+/// so `result` is empty when the kernel is longer than the signal. The kernel is
+/// not flipped, reverse it first for a true convolution.
+///
+/// Kernels of `3`, `5` and `7` taps are unrolled, computing 8 outputs at once with
+/// each tap held in a register, other kernels compute a dot product per output.
+///
+/// # Panics
+///
+/// Panics if `kernel` is empty or `result` is not `N - K + 1` in length.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_conv1d_valid(
+    signal: &[f32],
+    kernel: &[f32],
+    result: &mut [f32],
+) {
+    check_conv1d_valid_len(signal.len(), kernel.len(), result.len());
+
+    match kernel.len() {
+        3 => conv1d_small_to::<3, false>(signal, kernel, result),
+        5 => conv1d_small_to::<5, false>(signal, kernel, result),
+        7 => conv1d_small_to::<7, false>(signal, kernel, result),
+        len => {
+            for (i, out) in result.iter_mut().enumerate() {
+                *out = f32_xany_avx2_nofma_dot(kernel, signal.get_unchecked(i..i + len));
+            }
+        },
+    }
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the valid mode 1D cross-correlation of `signal` with `kernel`, writing
+/// the result to `result`.
+///
+/// ```py
+/// N: int
+/// K: int
+/// signal: [f32; N]
+/// kernel: [f32; K]
+/// result: [f32; N - K + 1]
+///
+/// for i in 0..N - K + 1:
+///     result[i] = dot(kernel, signal[i:i + K])
+/// ```
+///
+/// Only positions where the kernel fits entirely within the signal are computed,
+/// so `result` is empty when the kernel is longer than the signal. The kernel is
+/// not flipped, reverse it first for a true convolution.
+///
+/// Kernels of `3`, `5` and `7` taps are unrolled, computing 8 outputs at once with
+/// each tap held in a register, other kernels compute a dot product per output.
+///
+/// # Panics
+///
+/// Panics if `kernel` is empty or `result` is not `N - K + 1` in length.
+///
+/// # Safety
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_conv1d_valid(
+    signal: &[f32],
+    kernel: &[f32],
+    result: &mut [f32],
+) {
+    check_conv1d_valid_len(signal.len(), kernel.len(), result.len());
+
+    match kernel.len() {
+        3 => conv1d_small_to::<3, true>(signal, kernel, result),
+        5 => conv1d_small_to::<5, true>(signal, kernel, result),
+        7 => conv1d_small_to::<7, true>(signal, kernel, result),
+        len => {
+            for (i, out) in result.iter_mut().enumerate() {
+                *out = f32_xany_avx2_fma_dot(kernel, signal.get_unchecked(i..i + len));
+            }
+        },
+    }
+}
+
+#[inline(always)]
+/// Computes `a * b + c`, fused when `FMA` is set, in which case the caller must have
+/// the FMA target feature enabled.
+unsafe fn mul_add<const FMA: bool>(a: __m256, b: __m256, c: __m256) -> __m256 {
+    if FMA {
+        _mm256_fmadd_ps(a, b, c)
+    } else {
+        _mm256_add_ps(_mm256_mul_ps(a, b), c)
+    }
+}
+
+#[inline(always)]
+/// Computes the valid mode correlation with a `K` tap kernel, broadcasting each tap
+/// to a register and sliding the signal under them 8 outputs at a time.
+///
+/// `kernel` must be `K` in length and `result` must be `signal.len() - K + 1`.
+unsafe fn conv1d_small_to<const K: usize, const FMA: bool>(
+    signal: &[f32],
+    kernel: &[f32],
+    result: &mut [f32],
+) {
+    let len = result.len();
+    let signal_ptr = signal.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let mut taps = [_mm256_setzero_ps(); K];
+    for (j, tap) in taps.iter_mut().enumerate() {
+        *tap = _mm256_set1_ps(*kernel.get_unchecked(j));
+    }
+
+    let mut i = 0;
+    while i < (len - (len % 8)) {
+        let mut acc = _mm256_mul_ps(taps[0], _mm256_loadu_ps(signal_ptr.add(i)));
+        for (j, tap) in taps.iter().enumerate().skip(1) {
+            acc = mul_add::<FMA>(*tap, _mm256_loadu_ps(signal_ptr.add(i + j)), acc);
+        }
+        _mm256_storeu_ps(result_ptr.add(i), acc);
+
+        i += 8;
+    }
+
+    while i < len {
+        let mut acc = AutoMath::mul(*kernel.get_unchecked(0), *signal_ptr.add(i));
+        for j in 1..K {
+            let product =
+                AutoMath::mul(*kernel.get_unchecked(j), *signal_ptr.add(i + j));
+            acc = AutoMath::add(acc, product);
+        }
+        *result_ptr.add(i) = acc;
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close_vector, get_sample_vectors, simple_dot};
+
+    fn naive_conv1d_valid(signal: &[f32], kernel: &[f32]) -> Vec<f32> {
+        signal
+            .windows(kernel.len())
+            .map(|window| simple_dot(kernel, window))
+            .collect()
+    }
+
+    #[test]
+    fn test_xany_conv1d_valid() {
+        let (signal, kernel) = get_sample_vectors::<f32>(131);
+
+        for k in [1, 2, 3, 5, 7, 9, 33, 131] {
+            let kernel = &kernel[..k];
+            let expected = naive_conv1d_valid(&signal, kernel);
+
+            let mut result = vec![0.0; 131 - k + 1];
+            unsafe { f32_xany_avx2_nofma_conv1d_valid(&signal, kernel, &mut result) };
+            assert_is_close_vector(&result, &expected);
+
+            let mut result = vec![0.0; 131 - k + 1];
+            unsafe { f32_xany_avx2_fma_conv1d_valid(&signal, kernel, &mut result) };
+            assert_is_close_vector(&result, &expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_conv1d_valid_kernel_longer_than_signal() {
+        let (signal, kernel) = get_sample_vectors::<f32>(5);
+        let mut result = Vec::new();
+        unsafe { f32_xany_avx2_nofma_conv1d_valid(&signal[..2], &kernel, &mut result) };
+        assert!(result.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "Result length 4 does not match the 3 valid positions")]
+    fn test_xany_conv1d_valid_bad_result_len() {
+        let (signal, kernel) = get_sample_vectors::<f32>(5);
+        let mut result = vec![0.0; 4];
+        unsafe { f32_xany_avx2_fma_conv1d_valid(&signal, &kernel[..3], &mut result) };
+    }
+}
@@ -1,4 +1,6 @@
-use crate::danger::utils::cosine;
+use core::arch::x86_64::*;
+
+use crate::danger::utils::{cosine, cosine_with_l2_norms};
 use crate::danger::{
     f32_xany_avx2_fma_dot,
     f32_xany_avx2_fma_norm,
@@ -8,6 +10,7 @@ use crate::danger::{
     f32_xconst_avx2_fma_norm,
     f32_xconst_avx2_nofma_dot,
     f32_xconst_avx2_nofma_norm,
+    sum_avx2_ps,
 };
 use crate::math::*;
 
@@ -107,10 +110,288 @@ pub unsafe fn f32_xany_avx2_fma_cosine(x: &[f32], y: &[f32]) -> f32 {
     cosine::<f32, AutoMath>(dot_product, norm_x, norm_y)
 }
 
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the cosine distance of two `f32` vectors in a single pass.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// dot_product = 0
+/// norm_x = 0
+/// norm_y = 0
+///
+/// for i in 0..D:
+///     dot_product = dot_product + x[i] * y[i]
+///     norm_x = norm_x + x[i] * x[i]
+///     norm_y = norm_y + y[i] * y[i]
+///
+/// return 1 - (dot_product / sqrt(norm_x * norm_y))
+/// ```
+///
+/// Unlike [f32_xany_avx2_nofma_cosine], which sweeps the vectors once for each of
+/// the dot product and the two norms, all three are accumulated from the same loads,
+/// so each vector is only read from memory once.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_cosine_fused(x: &[f32], y: &[f32]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut dot1 = _mm256_setzero_ps();
+    let mut dot2 = _mm256_setzero_ps();
+    let mut norm_x1 = _mm256_setzero_ps();
+    let mut norm_x2 = _mm256_setzero_ps();
+    let mut norm_y1 = _mm256_setzero_ps();
+    let mut norm_y2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let y1 = _mm256_loadu_ps(y_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let y2 = _mm256_loadu_ps(y_ptr.add(i + 8));
+
+        cosine_step_nofma(x1, y1, &mut dot1, &mut norm_x1, &mut norm_y1);
+        cosine_step_nofma(x2, y2, &mut dot2, &mut norm_x2, &mut norm_y2);
+
+        i += 16;
+    }
+
+    if i + 8 <= len {
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+        let y = _mm256_loadu_ps(y_ptr.add(i));
+        cosine_step_nofma(x, y, &mut dot1, &mut norm_x1, &mut norm_y1);
+
+        i += 8;
+    }
+
+    let mut dot_product = sum_avx2_ps(_mm256_add_ps(dot1, dot2));
+    let mut norm_x = sum_avx2_ps(_mm256_add_ps(norm_x1, norm_x2));
+    let mut norm_y = sum_avx2_ps(_mm256_add_ps(norm_y1, norm_y2));
+
+    while i < len {
+        let a = *x_ptr.add(i);
+        let b = *y_ptr.add(i);
+
+        dot_product = AutoMath::add(dot_product, AutoMath::mul(a, b));
+        norm_x = AutoMath::add(norm_x, AutoMath::mul(a, a));
+        norm_y = AutoMath::add(norm_y, AutoMath::mul(b, b));
+
+        i += 1;
+    }
+
+    cosine::<f32, AutoMath>(dot_product, norm_x, norm_y)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the cosine distance of two `f32` vectors in a single pass.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// dot_product = 0
+/// norm_x = 0
+/// norm_y = 0
+///
+/// for i in 0..D:
+///     dot_product = dot_product + x[i] * y[i]
+///     norm_x = norm_x + x[i] * x[i]
+///     norm_y = norm_y + y[i] * y[i]
+///
+/// return 1 - (dot_product / sqrt(norm_x * norm_y))
+/// ```
+///
+/// Unlike [f32_xany_avx2_fma_cosine], which sweeps the vectors once for each of
+/// the dot product and the two norms, all three are accumulated from the same loads,
+/// so each vector is only read from memory once.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_cosine_fused(x: &[f32], y: &[f32]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut dot1 = _mm256_setzero_ps();
+    let mut dot2 = _mm256_setzero_ps();
+    let mut norm_x1 = _mm256_setzero_ps();
+    let mut norm_x2 = _mm256_setzero_ps();
+    let mut norm_y1 = _mm256_setzero_ps();
+    let mut norm_y2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let y1 = _mm256_loadu_ps(y_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let y2 = _mm256_loadu_ps(y_ptr.add(i + 8));
+
+        cosine_step_fma(x1, y1, &mut dot1, &mut norm_x1, &mut norm_y1);
+        cosine_step_fma(x2, y2, &mut dot2, &mut norm_x2, &mut norm_y2);
+
+        i += 16;
+    }
+
+    if i + 8 <= len {
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+        let y = _mm256_loadu_ps(y_ptr.add(i));
+        cosine_step_fma(x, y, &mut dot1, &mut norm_x1, &mut norm_y1);
+
+        i += 8;
+    }
+
+    let mut dot_product = sum_avx2_ps(_mm256_add_ps(dot1, dot2));
+    let mut norm_x = sum_avx2_ps(_mm256_add_ps(norm_x1, norm_x2));
+    let mut norm_y = sum_avx2_ps(_mm256_add_ps(norm_y1, norm_y2));
+
+    while i < len {
+        let a = *x_ptr.add(i);
+        let b = *y_ptr.add(i);
+
+        dot_product = AutoMath::add(dot_product, AutoMath::mul(a, b));
+        norm_x = AutoMath::add(norm_x, AutoMath::mul(a, a));
+        norm_y = AutoMath::add(norm_y, AutoMath::mul(b, b));
+
+        i += 1;
+    }
+
+    cosine::<f32, AutoMath>(dot_product, norm_x, norm_y)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the cosine distance of two `f32` vectors using their precomputed L2
+/// norms, only the dot product of `x` and `y` is computed.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// return 1 - dot(x, y) / (x_norm * y_norm)
+/// ```
+///
+/// `x_norm` and `y_norm` **MUST** be the true L2 norms of `x` and `y`, i.e.
+/// `sqrt(dot(x, x))`, not the squared norms, otherwise the distance returned is
+/// meaningless. This avoids recomputing the norm of stored vectors for every
+/// query in a cosine index.
+///
+/// If either norm is zero, the distance is `0`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_cosine_with_norms(
+    x: &[f32],
+    x_norm: f32,
+    y: &[f32],
+    y_norm: f32,
+) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let dot_product = f32_xany_avx2_nofma_dot(x, y);
+    cosine_with_l2_norms::<f32, AutoMath>(dot_product, x_norm, y_norm)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the cosine distance of two `f32` vectors using their precomputed L2
+/// norms, only the dot product of `x` and `y` is computed.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// return 1 - dot(x, y) / (x_norm * y_norm)
+/// ```
+///
+/// `x_norm` and `y_norm` **MUST** be the true L2 norms of `x` and `y`, i.e.
+/// `sqrt(dot(x, x))`, not the squared norms, otherwise the distance returned is
+/// meaningless. This avoids recomputing the norm of stored vectors for every
+/// query in a cosine index.
+///
+/// If either norm is zero, the distance is `0`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_cosine_with_norms(
+    x: &[f32],
+    x_norm: f32,
+    y: &[f32],
+    y_norm: f32,
+) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let dot_product = f32_xany_avx2_fma_dot(x, y);
+    cosine_with_l2_norms::<f32, AutoMath>(dot_product, x_norm, y_norm)
+}
+
+#[inline(always)]
+unsafe fn cosine_step_nofma(
+    x: __m256,
+    y: __m256,
+    dot: &mut __m256,
+    norm_x: &mut __m256,
+    norm_y: &mut __m256,
+) {
+    *dot = _mm256_add_ps(*dot, _mm256_mul_ps(x, y));
+    *norm_x = _mm256_add_ps(*norm_x, _mm256_mul_ps(x, x));
+    *norm_y = _mm256_add_ps(*norm_y, _mm256_mul_ps(y, y));
+}
+
+#[inline(always)]
+unsafe fn cosine_step_fma(
+    x: __m256,
+    y: __m256,
+    dot: &mut __m256,
+    norm_x: &mut __m256,
+    norm_y: &mut __m256,
+) {
+    *dot = _mm256_fmadd_ps(x, y, *dot);
+    *norm_x = _mm256_fmadd_ps(x, x, *norm_x);
+    *norm_y = _mm256_fmadd_ps(y, y, *norm_y);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::{get_sample_vectors, is_close, simple_cosine};
+    use crate::test_utils::{get_sample_vectors, is_close, simple_cosine, simple_dot};
 
     #[test]
     fn test_xany_fma_cosine() {
@@ -139,4 +420,44 @@ mod tests {
         let dist = unsafe { f32_xconst_avx2_nofma_cosine::<1024>(&x, &y) };
         assert!(is_close(dist, simple_cosine(&x, &y)))
     }
+
+    #[test]
+    fn test_xany_nofma_cosine_fused() {
+        for len in [0, 7, 16, 127, 1024] {
+            let (x, y) = get_sample_vectors(len);
+            let dist = unsafe { f32_xany_avx2_nofma_cosine_fused(&x, &y) };
+            let expected = unsafe { f32_xany_avx2_nofma_cosine(&x, &y) };
+            assert!(is_close(dist, expected), "{dist} != {expected}");
+        }
+    }
+
+    #[test]
+    fn test_xany_fma_cosine_fused() {
+        for len in [0, 7, 16, 127, 1024] {
+            let (x, y) = get_sample_vectors(len);
+            let dist = unsafe { f32_xany_avx2_fma_cosine_fused(&x, &y) };
+            let expected = unsafe { f32_xany_avx2_fma_cosine(&x, &y) };
+            assert!(is_close(dist, expected), "{dist} != {expected}");
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_cosine_with_norms() {
+        let (x, y) = get_sample_vectors::<f32>(547);
+        let x_norm = simple_dot(&x, &x).sqrt();
+        let y_norm = simple_dot(&y, &y).sqrt();
+        let dist =
+            unsafe { f32_xany_avx2_nofma_cosine_with_norms(&x, x_norm, &y, y_norm) };
+        assert!(is_close(dist, simple_cosine(&x, &y)))
+    }
+
+    #[test]
+    fn test_xany_fma_cosine_with_norms() {
+        let (x, y) = get_sample_vectors::<f32>(547);
+        let x_norm = simple_dot(&x, &x).sqrt();
+        let y_norm = simple_dot(&y, &y).sqrt();
+        let dist =
+            unsafe { f32_xany_avx2_fma_cosine_with_norms(&x, x_norm, &y, y_norm) };
+        assert!(is_close(dist, simple_cosine(&x, &y)))
+    }
 }
@@ -0,0 +1,398 @@
+use core::arch::x86_64::*;
+
+use crate::danger::{
+    f32_xany_avx2_nofma_add_vertical,
+    f32_xany_avx2_nofma_mul_vertical,
+    partially_overlaps,
+    scan_axis_with,
+};
+use crate::math::*;
+use crate::vector_ops::Axis;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the running sum of `x`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     total = total + x[i]
+///     result[i] = total
+/// ```
+///
+/// Each register of 8 elements is scanned in log steps and offset by the carried
+/// total of the previous registers, so the additions are not performed in the same
+/// order as a sequential loop and the rounding may differ slightly.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_cumsum(x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    scan_to::<false, false>(x, result)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the running product of `x`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// total = 1
+/// for i in 0..D:
+///     total = total * x[i]
+///     result[i] = total
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_cumprod(x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    scan_to::<true, false>(x, result)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the exclusive running sum of `x`, writing the result to `result`, each
+/// output is the sum of all elements before it.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     result[i] = total
+///     total = total + x[i]
+/// ```
+///
+/// This is the scan used to turn a list of lengths into offsets, i.e. the row pointers
+/// of a CSR matrix. Like [f32_xany_avx2_nofma_cumsum] the rounding may differ slightly
+/// from a sequential loop.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_cumsum_exclusive(x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    scan_to::<false, true>(x, result)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the running sum of `matrix` along the given [Axis], writing the result
+/// to the matching rows of `result`, the equivalent of numpy's `cumsum(axis=)`.
+///
+/// ```py
+/// R: int
+/// C: int
+/// matrix: [[f32; C]; R]
+/// result: [[f32; C]; R]
+///
+/// if axis == Rows:
+///     for r in 0..R:
+///         result[r] = cumsum(matrix[r])
+/// else:
+///     result[0] = matrix[0]
+///     for r in 1..R:
+///         result[r] = result[r - 1] + matrix[r]
+/// ```
+///
+/// [Axis::Rows] scans each row with [f32_xany_avx2_nofma_cumsum] while [Axis::Cols]
+/// carries the previous output row down the matrix, adding a register of columns
+/// at a time. Scanning the output of one axis along the other produces an integral
+/// image, i.e. a summed area table.
+///
+/// # Safety
+///
+/// `result` **MUST** have the same number of rows as `matrix` and every row of both
+/// **MUST** be the same length, otherwise this routine will become immediately UB
+/// due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_cumsum_axis(
+    matrix: &[&[f32]],
+    axis: Axis,
+    result: &mut [&mut [f32]],
+) {
+    scan_axis_with(
+        matrix,
+        axis,
+        result,
+        |row, out| f32_xany_avx2_nofma_cumsum(row, out),
+        |row, prev| f32_xany_avx2_nofma_add_vertical(row, prev),
+    )
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the running product of `matrix` along the given [Axis], writing the
+/// result to the matching rows of `result`, the equivalent of numpy's
+/// `cumprod(axis=)`.
+///
+/// ```py
+/// R: int
+/// C: int
+/// matrix: [[f32; C]; R]
+/// result: [[f32; C]; R]
+///
+/// if axis == Rows:
+///     for r in 0..R:
+///         result[r] = cumprod(matrix[r])
+/// else:
+///     result[0] = matrix[0]
+///     for r in 1..R:
+///         result[r] = result[r - 1] * matrix[r]
+/// ```
+///
+/// # Safety
+///
+/// `result` **MUST** have the same number of rows as `matrix` and every row of both
+/// **MUST** be the same length, otherwise this routine will become immediately UB
+/// due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_cumprod_axis(
+    matrix: &[&[f32]],
+    axis: Axis,
+    result: &mut [&mut [f32]],
+) {
+    scan_axis_with(
+        matrix,
+        axis,
+        result,
+        |row, out| f32_xany_avx2_nofma_cumprod(row, out),
+        |row, prev| f32_xany_avx2_nofma_mul_vertical(row, prev),
+    )
+}
+
+#[inline(always)]
+/// Combines two registers with either a multiply or an add.
+unsafe fn scan_op<const MUL: bool>(a: __m256, b: __m256) -> __m256 {
+    if MUL {
+        _mm256_mul_ps(a, b)
+    } else {
+        _mm256_add_ps(a, b)
+    }
+}
+
+#[inline(always)]
+/// Computes the inclusive scan of a single register.
+///
+/// Each 128 bit lane is scanned by combining it with itself shifted up one and then
+/// two elements, the identity is shifted in rather than zero. The total of the low
+/// lane is then broadcast into the high lane.
+unsafe fn scan_register<const MUL: bool>(x: __m256, identity: __m256) -> __m256 {
+    let shifted = _mm256_castsi256_ps(_mm256_slli_si256::<4>(_mm256_castps_si256(x)));
+    let x = scan_op::<MUL>(x, _mm256_blend_ps::<0b0001_0001>(shifted, identity));
+
+    let shifted = _mm256_castsi256_ps(_mm256_slli_si256::<8>(_mm256_castps_si256(x)));
+    let x = scan_op::<MUL>(x, _mm256_blend_ps::<0b0011_0011>(shifted, identity));
+
+    // Moves the low lane into the high lane, zeroing the low lane.
+    let low_lane = _mm256_permute2f128_ps::<0x08>(x, x);
+    let low_total = _mm256_shuffle_ps::<0xFF>(low_lane, low_lane);
+    scan_op::<MUL>(x, _mm256_blend_ps::<0b0000_1111>(low_total, identity))
+}
+
+#[inline(always)]
+/// Computes the inclusive or exclusive scan of `x` writing the output to `result`.
+///
+/// The exclusive scan is the inclusive scan of each register shifted up one element,
+/// with the carried total of the previous registers shifted in.
+unsafe fn scan_to<const MUL: bool, const EXCLUSIVE: bool>(
+    x: &[f32],
+    result: &mut [f32],
+) {
+    let len = x.len();
+    let x_ptr = x.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let identity_value = if MUL { 1.0 } else { 0.0 };
+    let identity = _mm256_set1_ps(identity_value);
+    let last_lane = _mm256_set1_epi32(7);
+    let shift_up = _mm256_setr_epi32(0, 0, 1, 2, 3, 4, 5, 6);
+
+    let mut carry = identity;
+    let mut i = 0;
+    while i < (len - (len % 8)) {
+        let x = scan_register::<MUL>(_mm256_loadu_ps(x_ptr.add(i)), identity);
+        let x = scan_op::<MUL>(x, carry);
+        if EXCLUSIVE {
+            let shifted = _mm256_permutevar8x32_ps(x, shift_up);
+            _mm256_storeu_ps(
+                result_ptr.add(i),
+                _mm256_blend_ps::<0b0000_0001>(shifted, carry),
+            );
+        } else {
+            _mm256_storeu_ps(result_ptr.add(i), x);
+        }
+
+        carry = _mm256_permutevar8x32_ps(x, last_lane);
+
+        i += 8;
+    }
+
+    let mut total = _mm256_cvtss_f32(carry);
+    while i < len {
+        let value = *x_ptr.add(i);
+        if EXCLUSIVE {
+            *result_ptr.add(i) = total;
+        }
+        total = if MUL {
+            AutoMath::mul(total, value)
+        } else {
+            AutoMath::add(total, value)
+        };
+        if !EXCLUSIVE {
+            *result_ptr.add(i) = total;
+        }
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close_vector, get_sample_vectors};
+
+    fn scan(x: &[f32], init: f32, op: impl Fn(f32, f32) -> f32) -> Vec<f32> {
+        x.iter()
+            .scan(init, |total, v| {
+                *total = op(*total, *v);
+                Some(*total)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_xany_nofma_cumsum() {
+        for len in [0, 5, 8, 13, 131] {
+            let (x, _) = get_sample_vectors::<f32>(len);
+            let mut result = vec![0.0; len];
+            unsafe { f32_xany_avx2_nofma_cumsum(&x, &mut result) };
+            assert_is_close_vector(&result, &scan(&x, 0.0, |a, b| a + b));
+        }
+
+        let x = (1..=16).map(|v| v as f32).collect::<Vec<f32>>();
+        let mut result = vec![0.0; 16];
+        unsafe { f32_xany_avx2_nofma_cumsum(&x, &mut result) };
+        assert_eq!(result, scan(&x, 0.0, |a, b| a + b));
+    }
+
+    #[test]
+    fn test_xany_nofma_cumsum_exclusive() {
+        for len in [0, 1, 7, 9, 13, 131] {
+            let (x, _) = get_sample_vectors::<f32>(len);
+            let mut inclusive = vec![0.0; len];
+            let mut exclusive = vec![0.0; len];
+            unsafe {
+                f32_xany_avx2_nofma_cumsum(&x, &mut inclusive);
+                f32_xany_avx2_nofma_cumsum_exclusive(&x, &mut exclusive);
+            }
+
+            let expected = scan(&x, 0.0, |a, b| a + b);
+            assert_is_close_vector(&inclusive, &expected);
+            if len > 0 {
+                assert_eq!(exclusive[0], 0.0);
+                assert_is_close_vector(&exclusive[1..], &expected[..len - 1]);
+            }
+        }
+
+        let lengths = [3.0, 0.0, 2.0, 5.0, 1.0, 4.0, 4.0, 1.0, 2.0, 6.0, 3.0];
+        let mut offsets = [0.0; 11];
+        unsafe { f32_xany_avx2_nofma_cumsum_exclusive(&lengths, &mut offsets) };
+        assert_eq!(
+            offsets,
+            [0.0, 3.0, 3.0, 5.0, 10.0, 11.0, 15.0, 19.0, 20.0, 22.0, 28.0]
+        );
+    }
+
+    #[test]
+    fn test_xany_nofma_cumprod() {
+        // Values close to one keep the running product within a comparable range.
+        let (x, _) = get_sample_vectors::<f32>(67);
+        let x = x.iter().map(|v| 1.0 + v / 100.0).collect::<Vec<f32>>();
+        let mut result = vec![0.0; 67];
+        unsafe { f32_xany_avx2_nofma_cumprod(&x, &mut result) };
+        assert_is_close_vector(&result, &scan(&x, 1.0, |a, b| a * b));
+
+        let x = (1..=11).map(|v| v as f32).collect::<Vec<f32>>();
+        let mut result = vec![0.0; 11];
+        unsafe { f32_xany_avx2_nofma_cumprod(&x, &mut result) };
+        assert_eq!(result, scan(&x, 1.0, |a, b| a * b));
+    }
+
+    #[test]
+    fn test_xany_nofma_cumsum_axis() {
+        let (x, _) = get_sample_vectors::<f32>(4 * 19);
+        let matrix = x.chunks(19).collect::<Vec<&[f32]>>();
+
+        let mut output = vec![0.0; 4 * 19];
+        let mut result = output.chunks_mut(19).collect::<Vec<&mut [f32]>>();
+        unsafe { f32_xany_avx2_nofma_cumsum_axis(&matrix, Axis::Rows, &mut result) };
+        for (row, out) in matrix.iter().zip(result.iter()) {
+            assert_is_close_vector(out, &scan(row, 0.0, |a, b| a + b));
+        }
+
+        unsafe { f32_xany_avx2_nofma_cumsum_axis(&matrix, Axis::Cols, &mut result) };
+        for c in 0..19 {
+            let column = matrix.iter().map(|row| row[c]).collect::<Vec<f32>>();
+            let out = result.iter().map(|row| row[c]).collect::<Vec<f32>>();
+            assert_is_close_vector(&out, &scan(&column, 0.0, |a, b| a + b));
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_cumprod_axis() {
+        let matrix: [&[f32]; 3] = [&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0], &[2.0, 0.5, 1.0]];
+
+        let mut output = vec![0.0; 9];
+        let mut result = output.chunks_mut(3).collect::<Vec<&mut [f32]>>();
+        unsafe { f32_xany_avx2_nofma_cumprod_axis(&matrix, Axis::Rows, &mut result) };
+        assert_eq!(output, [1.0, 2.0, 6.0, 4.0, 20.0, 120.0, 2.0, 1.0, 1.0]);
+
+        let mut result = output.chunks_mut(3).collect::<Vec<&mut [f32]>>();
+        unsafe { f32_xany_avx2_nofma_cumprod_axis(&matrix, Axis::Cols, &mut result) };
+        assert_eq!(output, [1.0, 2.0, 3.0, 4.0, 10.0, 18.0, 8.0, 5.0, 18.0]);
+    }
+}
@@ -0,0 +1,340 @@
+use crate::danger::{
+    f32_xany_avx2_fma_dot,
+    f32_xany_avx2_nofma_dot,
+    f32_xconst_avx2_fma_dot,
+    f32_xconst_avx2_nofma_dot,
+    gemv_with,
+    Activation,
+};
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of two `[f32; DIMS]` vectors, adds `bias` and applies
+/// the given activation function to the result.
+///
+/// ```py
+/// D: int
+/// total: f32
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+///
+/// return activation(total + bias)
+/// ```
+///
+/// # Safety
+///
+/// DIMS **MUST** be a multiple of `64` and both vectors must be `DIMS` in length,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xconst_avx2_nofma_dot_bias_activation<const DIMS: usize>(
+    x: &[f32],
+    y: &[f32],
+    bias: f32,
+    activation: Activation,
+) -> f32 {
+    let dot_product = f32_xconst_avx2_nofma_dot::<DIMS>(x, y);
+    activation.apply::<f32, AutoMath>(AutoMath::add(dot_product, bias))
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of two `f32` vectors, adds `bias` and applies
+/// the given activation function to the result.
+///
+/// ```py
+/// D: int
+/// total: f32
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+///
+/// return activation(total + bias)
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_dot_bias_activation(
+    x: &[f32],
+    y: &[f32],
+    bias: f32,
+    activation: Activation,
+) -> f32 {
+    let dot_product = f32_xany_avx2_nofma_dot(x, y);
+    activation.apply::<f32, AutoMath>(AutoMath::add(dot_product, bias))
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of two `[f32; DIMS]` vectors, adds `bias` and applies
+/// the given activation function to the result.
+///
+/// ```py
+/// D: int
+/// total: f32
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+///
+/// return activation(total + bias)
+/// ```
+///
+/// # Safety
+///
+/// DIMS **MUST** be a multiple of `64` and both vectors must be `DIMS` in length,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xconst_avx2_fma_dot_bias_activation<const DIMS: usize>(
+    x: &[f32],
+    y: &[f32],
+    bias: f32,
+    activation: Activation,
+) -> f32 {
+    let dot_product = f32_xconst_avx2_fma_dot::<DIMS>(x, y);
+    activation.apply::<f32, AutoMath>(AutoMath::add(dot_product, bias))
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of two `f32` vectors, adds `bias` and applies
+/// the given activation function to the result.
+///
+/// ```py
+/// D: int
+/// total: f32
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+///
+/// return activation(total + bias)
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_dot_bias_activation(
+    x: &[f32],
+    y: &[f32],
+    bias: f32,
+    activation: Activation,
+) -> f32 {
+    let dot_product = f32_xany_avx2_fma_dot(x, y);
+    activation.apply::<f32, AutoMath>(AutoMath::add(dot_product, bias))
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the product of `matrix` with the vector `x`, adds `bias` and applies the
+/// given activation function to each element, writing the result to `out`.
+///
+/// ```py
+/// R: int
+/// D: int
+/// matrix: [[f32; D]; R]
+/// x: [f32; D]
+/// bias: [f32; R]
+/// out: [f32; R]
+///
+/// for i in 0..R:
+///     out[i] = activation(dot(matrix[i], x) + bias[i])
+/// ```
+///
+/// This is the forward pass of a dense layer, `x` stays resident in cache while
+/// each row is streamed past it.
+///
+/// # Panics
+///
+/// Panics if `bias` or `out` are not the same length as `matrix`.
+///
+/// # Safety
+///
+/// Every row of `matrix` **MUST** be the same length as `x`, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_gemv_bias_activation(
+    matrix: &[&[f32]],
+    x: &[f32],
+    bias: &[f32],
+    activation: Activation,
+    out: &mut [f32],
+) {
+    gemv_with(matrix, bias, out, |row, bias| {
+        f32_xany_avx2_nofma_dot_bias_activation(row, x, bias, activation)
+    })
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the product of `matrix` with the vector `x`, adds `bias` and applies the
+/// given activation function to each element, writing the result to `out`.
+///
+/// ```py
+/// R: int
+/// D: int
+/// matrix: [[f32; D]; R]
+/// x: [f32; D]
+/// bias: [f32; R]
+/// out: [f32; R]
+///
+/// for i in 0..R:
+///     out[i] = activation(dot(matrix[i], x) + bias[i])
+/// ```
+///
+/// This is the forward pass of a dense layer, `x` stays resident in cache while
+/// each row is streamed past it.
+///
+/// # Panics
+///
+/// Panics if `bias` or `out` are not the same length as `matrix`.
+///
+/// # Safety
+///
+/// Every row of `matrix` **MUST** be the same length as `x`, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_gemv_bias_activation(
+    matrix: &[&[f32]],
+    x: &[f32],
+    bias: &[f32],
+    activation: Activation,
+    out: &mut [f32],
+) {
+    gemv_with(matrix, bias, out, |row, bias| {
+        f32_xany_avx2_fma_dot_bias_activation(row, x, bias, activation)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        assert_is_close_vector,
+        get_sample_vectors,
+        simple_dot,
+    };
+
+    #[test]
+    fn test_xany_fma_dot_bias_activation() {
+        let (x, y) = get_sample_vectors(127);
+        let expected = simple_dot(&x, &y) - 40.0;
+
+        let res = unsafe {
+            f32_xany_avx2_fma_dot_bias_activation(&x, &y, -40.0, Activation::None)
+        };
+        assert_is_close(res, expected);
+
+        let res = unsafe {
+            f32_xany_avx2_fma_dot_bias_activation(&x, &y, -40.0, Activation::Relu)
+        };
+        assert_is_close(res, expected.max(0.0));
+    }
+
+    #[test]
+    fn test_xany_nofma_dot_bias_activation() {
+        let (x, y) = get_sample_vectors(127);
+        let expected = simple_dot::<f32>(&x, &y) - 30.0;
+        let expected = 1.0 / (1.0 + (-expected).exp());
+
+        let res = unsafe {
+            f32_xany_avx2_nofma_dot_bias_activation(&x, &y, -30.0, Activation::Sigmoid)
+        };
+        assert_is_close(res, expected);
+    }
+
+    #[test]
+    fn test_xconst_fma_dot_bias_activation() {
+        let (x, y) = get_sample_vectors(1024);
+        let expected = simple_dot(&x, &y) - 256.0;
+
+        let res = unsafe {
+            f32_xconst_avx2_fma_dot_bias_activation::<1024>(
+                &x,
+                &y,
+                -256.0,
+                Activation::Tanh,
+            )
+        };
+        assert_is_close(res, expected.tanh());
+    }
+
+    #[test]
+    fn test_xconst_nofma_dot_bias_activation() {
+        let (x, y) = get_sample_vectors(1024);
+        let expected = simple_dot(&x, &y) + 1.0;
+
+        let res = unsafe {
+            f32_xconst_avx2_nofma_dot_bias_activation::<1024>(
+                &x,
+                &y,
+                1.0,
+                Activation::Relu,
+            )
+        };
+        assert_is_close(res, expected);
+    }
+
+    #[test]
+    fn test_xany_gemv_bias_activation() {
+        let (data, x) = get_sample_vectors::<f32>(37 * 5);
+        let x = &x[..37];
+        let matrix = data.chunks(37).collect::<Vec<&[f32]>>();
+        let bias = [-10.0, 0.0, 5.0, -300.0, 1.0];
+
+        let expected = matrix
+            .iter()
+            .zip(bias)
+            .map(|(row, bias)| (simple_dot(row, x) + bias).max(0.0))
+            .collect::<Vec<f32>>();
+
+        let mut out = vec![f32::NAN; 5];
+        unsafe {
+            f32_xany_avx2_nofma_gemv_bias_activation(
+                &matrix,
+                x,
+                &bias,
+                Activation::Relu,
+                &mut out,
+            )
+        };
+        assert_is_close_vector(&out, &expected);
+
+        let mut out = vec![f32::NAN; 5];
+        unsafe {
+            f32_xany_avx2_fma_gemv_bias_activation(
+                &matrix,
+                x,
+                &bias,
+                Activation::Relu,
+                &mut out,
+            )
+        };
+        assert_is_close_vector(&out, &expected);
+    }
+}
@@ -0,0 +1,167 @@
+use core::arch::x86_64::*;
+use core::mem;
+
+use crate::danger::utils::two_sum_f32;
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of two `f32` vectors with compensated accumulation,
+/// following the `Dot2` algorithm of Ogita, Rump and Oishi.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// total = 0
+/// compensation = 0
+///
+/// for i in 0..D:
+///     product, product_error = two_product(x[i], y[i])
+///     total, sum_error = two_sum(total, product)
+///     compensation = compensation + product_error + sum_error
+///
+/// return total + compensation
+/// ```
+///
+/// The rounding error of every product and every addition is captured exactly and
+/// accumulated separately, so the result is as accurate as if it were computed in
+/// twice the working precision and then rounded to `f32`:
+///
+/// `|result - dot(x, y)| <= eps * |dot(x, y)| + γ(D)² * dot(|x|, |y|)`
+///
+/// where `eps = 2^-24` and `γ(D) = D * eps / (1 - D * eps)`. This makes it suitable
+/// for ill-conditioned inputs, i.e. nearly orthogonal vectors, where the plain
+/// [f32_xany_avx2_fma_dot](crate::danger::f32_xany_avx2_fma_dot) can lose every
+/// significant digit, at a cost of roughly 2-3x the runtime.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_dot_compensated(x: &[f32], y: &[f32]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut sum1 = _mm256_setzero_ps();
+    let mut sum2 = _mm256_setzero_ps();
+    let mut comp1 = _mm256_setzero_ps();
+    let mut comp2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let y1 = _mm256_loadu_ps(y_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let y2 = _mm256_loadu_ps(y_ptr.add(i + 8));
+
+        dot2_step(x1, y1, &mut sum1, &mut comp1);
+        dot2_step(x2, y2, &mut sum2, &mut comp2);
+
+        i += 16;
+    }
+
+    if i + 8 <= len {
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+        let y = _mm256_loadu_ps(y_ptr.add(i));
+        dot2_step(x, y, &mut sum1, &mut comp1);
+
+        i += 8;
+    }
+
+    // The lane totals are combined with the same error-free transformation so the
+    // horizontal reduction doesn't reintroduce the error we compensated for.
+    let sums = mem::transmute::<[__m256; 2], [f32; 16]>([sum1, sum2]);
+    let comps = mem::transmute::<__m256, [f32; 8]>(_mm256_add_ps(comp1, comp2));
+
+    let mut total = 0.0;
+    let mut compensation = comps.iter().sum::<f32>();
+    for lane_sum in sums {
+        let (sum, error) = two_sum_f32(total, lane_sum);
+        total = sum;
+        compensation += error;
+    }
+
+    while i < len {
+        let a = *x_ptr.add(i);
+        let b = *y_ptr.add(i);
+
+        let product = a * b;
+        let product_error = a.mul_add(b, -product);
+        let (sum, sum_error) = two_sum_f32(total, product);
+        total = sum;
+        compensation += product_error + sum_error;
+
+        i += 1;
+    }
+
+    total + compensation
+}
+
+#[inline(always)]
+unsafe fn dot2_step(x: __m256, y: __m256, sum: &mut __m256, comp: &mut __m256) {
+    // TwoProduct, the FMA recovers the exact rounding error of the product.
+    let product = _mm256_mul_ps(x, y);
+    let product_error = _mm256_fmsub_ps(x, y, product);
+
+    // TwoSum of the running total and the product.
+    let new_sum = _mm256_add_ps(*sum, product);
+    let z = _mm256_sub_ps(new_sum, *sum);
+    let sum_error = _mm256_add_ps(
+        _mm256_sub_ps(*sum, _mm256_sub_ps(new_sum, z)),
+        _mm256_sub_ps(product, z),
+    );
+
+    *sum = new_sum;
+    *comp = _mm256_add_ps(*comp, _mm256_add_ps(product_error, sum_error));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_fma_dot_compensated() {
+        for len in [0, 3, 8, 16, 127, 531] {
+            let (x, y) = get_sample_vectors::<f32>(len);
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(a, b)| *a as f64 * *b as f64)
+                .sum::<f64>();
+
+            let dot = unsafe { f32_xany_avx2_fma_dot_compensated(&x, &y) };
+            assert!(
+                (dot as f64 - expected).abs() <= f32::EPSILON as f64 * expected.abs(),
+                "{dot} != {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_xany_fma_dot_compensated_ill_conditioned() {
+        let (x, y) = get_sample_vectors::<f32>(265);
+
+        // Every product is cancelled out by its negation, leaving only the final 1.0.
+        let mut a = x.iter().map(|v| v * 1e6).collect::<Vec<_>>();
+        a.extend(x.iter().map(|v| v * -1e6));
+        a.push(1.0);
+        let mut b = y.clone();
+        b.extend_from_slice(&y);
+        b.push(1.0);
+
+        // The condition number is ~1e8, far beyond what `f32` accumulation can handle
+        // but well within the error bound of the compensated accumulation.
+        let dot = unsafe { f32_xany_avx2_fma_dot_compensated(&a, &b) };
+        assert!((dot - 1.0).abs() < 1e-4, "{dot} != 1.0");
+    }
+}
@@ -0,0 +1,184 @@
+use core::arch::x86_64::*;
+
+use crate::danger::utils::sum_avx2_pd;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of two `f32` vectors, accumulating in `f64`.
+///
+/// ```py
+/// D: int
+/// total: f64
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     total = total + f64(x[i]) * f64(y[i])
+/// ```
+///
+/// The product of two `f32` values is exactly representable as an `f64`, so the only
+/// rounding error comes from the `f64` accumulation. This is a cheaper accuracy win
+/// than the compensated dot product for long vectors, at roughly half the throughput
+/// of the plain `f32` dot product.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_dot_f64(x: &[f32], y: &[f32]) -> f64 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm256_setzero_pd();
+    let mut acc2 = _mm256_setzero_pd();
+    let mut acc3 = _mm256_setzero_pd();
+    let mut acc4 = _mm256_setzero_pd();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let [x1, x2, x3, x4] = load_widened_x16(x_ptr.add(i));
+        let [y1, y2, y3, y4] = load_widened_x16(y_ptr.add(i));
+
+        acc1 = _mm256_add_pd(acc1, _mm256_mul_pd(x1, y1));
+        acc2 = _mm256_add_pd(acc2, _mm256_mul_pd(x2, y2));
+        acc3 = _mm256_add_pd(acc3, _mm256_mul_pd(x3, y3));
+        acc4 = _mm256_add_pd(acc4, _mm256_mul_pd(x4, y4));
+
+        i += 16;
+    }
+
+    acc1 = _mm256_add_pd(acc1, acc2);
+    acc3 = _mm256_add_pd(acc3, acc4);
+    let mut total = sum_avx2_pd(_mm256_add_pd(acc1, acc3));
+
+    while i < len {
+        total += *x_ptr.add(i) as f64 * *y_ptr.add(i) as f64;
+
+        i += 1;
+    }
+
+    total
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of two `f32` vectors, accumulating in `f64`.
+///
+/// ```py
+/// D: int
+/// total: f64
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     total = total + f64(x[i]) * f64(y[i])
+/// ```
+///
+/// The product of two `f32` values is exactly representable as an `f64`, so the only
+/// rounding error comes from the `f64` accumulation. This is a cheaper accuracy win
+/// than the compensated dot product for long vectors, at roughly half the throughput
+/// of the plain `f32` dot product.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_dot_f64(x: &[f32], y: &[f32]) -> f64 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm256_setzero_pd();
+    let mut acc2 = _mm256_setzero_pd();
+    let mut acc3 = _mm256_setzero_pd();
+    let mut acc4 = _mm256_setzero_pd();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let [x1, x2, x3, x4] = load_widened_x16(x_ptr.add(i));
+        let [y1, y2, y3, y4] = load_widened_x16(y_ptr.add(i));
+
+        acc1 = _mm256_fmadd_pd(x1, y1, acc1);
+        acc2 = _mm256_fmadd_pd(x2, y2, acc2);
+        acc3 = _mm256_fmadd_pd(x3, y3, acc3);
+        acc4 = _mm256_fmadd_pd(x4, y4, acc4);
+
+        i += 16;
+    }
+
+    acc1 = _mm256_add_pd(acc1, acc2);
+    acc3 = _mm256_add_pd(acc3, acc4);
+    let mut total = sum_avx2_pd(_mm256_add_pd(acc1, acc3));
+
+    while i < len {
+        total += *x_ptr.add(i) as f64 * *y_ptr.add(i) as f64;
+
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// Loads 16 `f32` values widened into four `f64` registers.
+unsafe fn load_widened_x16(x: *const f32) -> [__m256d; 4] {
+    let low = _mm256_loadu_ps(x);
+    let high = _mm256_loadu_ps(x.add(8));
+
+    [
+        _mm256_cvtps_pd(_mm256_castps256_ps128(low)),
+        _mm256_cvtps_pd(_mm256_extractf128_ps::<1>(low)),
+        _mm256_cvtps_pd(_mm256_castps256_ps128(high)),
+        _mm256_cvtps_pd(_mm256_extractf128_ps::<1>(high)),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    fn simple_dot_f64(x: &[f32], y: &[f32]) -> f64 {
+        x.iter().zip(y).map(|(a, b)| *a as f64 * *b as f64).sum()
+    }
+
+    #[test]
+    fn test_xany_dot_f64() {
+        for len in [0, 5, 16, 127, 531] {
+            let (x, y) = get_sample_vectors::<f32>(len);
+            let expected = simple_dot_f64(&x, &y);
+
+            let nofma = unsafe { f32_xany_avx2_nofma_dot_f64(&x, &y) };
+            let fma = unsafe { f32_xany_avx2_fma_dot_f64(&x, &y) };
+            assert!((nofma - expected).abs() <= 1e-12 * expected.abs().max(1.0));
+            assert!((fma - expected).abs() <= 1e-12 * expected.abs().max(1.0));
+        }
+    }
+
+    #[test]
+    fn test_xany_dot_f64_keeps_small_terms() {
+        // The small terms are lost entirely when accumulating in `f32`.
+        let mut x = vec![1e8f32; 32];
+        x.extend(vec![1.0; 32]);
+        x.extend(vec![-1e8f32; 32]);
+        let y = vec![1.0; x.len()];
+
+        assert_eq!(unsafe { f32_xany_avx2_nofma_dot_f64(&x, &y) }, 32.0);
+        assert_eq!(unsafe { f32_xany_avx2_fma_dot_f64(&x, &y) }, 32.0);
+    }
+}
@@ -1,7 +1,7 @@
 use core::arch::x86_64::*;
 
 use crate::danger::utils::{CHUNK_0, CHUNK_1};
-use crate::danger::{offsets_avx2_ps, rollup_x8_ps, sum_avx2_ps};
+use crate::danger::{fold_chunk_pairs, offsets_avx2_ps, rollup_x8_ps, sum_avx2_ps};
 use crate::math::*;
 
 #[target_feature(enable = "avx2")]
@@ -64,11 +64,103 @@ pub unsafe fn f32_xconst_avx2_nofma_dot<const DIMS: usize>(x: &[f32], y: &[f32])
 /// Vectors **MUST** be the same length, otherwise this routine
 /// will become immediately UB due to out of bounds pointer accesses.
 pub unsafe fn f32_xany_avx2_nofma_dot(x: &[f32], y: &[f32]) -> f32 {
+    f32_xany_avx2_nofma_dot_plus(x, y, 0.0)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of the first `n` elements of two `f32` vectors, ignoring
+/// any elements beyond them, i.e. the unused capacity of padded buffers.
+///
+/// # Panics
+///
+/// Panics if `n` is greater than the length of either vector.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_dot_prefix(x: &[f32], y: &[f32], n: usize) -> f32 {
+    assert!(
+        n <= x.len().min(y.len()),
+        "Prefix length {n} exceeds the length of the vectors"
+    );
+    f32_xany_avx2_nofma_dot(x.get_unchecked(..n), y.get_unchecked(..n))
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of each pair of rows of two row-major `f32` matrices
+/// with `dim` columns, only including the first `lengths[row]` elements of each row.
+///
+/// ```py
+/// R: int
+/// dim: int
+/// x: [f32; R * dim]
+/// y: [f32; R * dim]
+/// lengths: [usize; R]
+/// result: [f32; R]
+///
+/// for row in 0..R:
+///     start = row * dim
+///     result[row] = dot(x[start:start + lengths[row]], y[start:start + lengths[row]])
+/// ```
+///
+/// This allows a batch of variable length vectors padded out to a fixed width, i.e.
+/// right-padded token embeddings, to be dotted without the padding contributing to
+/// the result or being processed at all.
+///
+/// # Panics
+///
+/// Panics if any of the `lengths` is greater than `dim`.
+///
+/// # Safety
+///
+/// `x` and `y` **MUST** both be `lengths.len() * dim` in length and `result` **MUST**
+/// be `lengths.len()` in length, otherwise this routine will become immediately UB
+/// due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_dot_ragged(
+    x: &[f32],
+    y: &[f32],
+    dim: usize,
+    lengths: &[usize],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), lengths.len() * dim);
+    debug_assert_eq!(y.len(), lengths.len() * dim);
+    debug_assert_eq!(result.len(), lengths.len());
+
+    for (row, &n) in lengths.iter().enumerate() {
+        assert!(n <= dim, "Row length {n} exceeds the row width {dim}");
+
+        let start = row * dim;
+        *result.get_unchecked_mut(row) = f32_xany_avx2_nofma_dot(
+            x.get_unchecked(start..start + n),
+            y.get_unchecked(start..start + n),
+        );
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes `bias` plus the dot product of two `f32` vectors.
+///
+/// The accumulator is seeded with `bias` rather than adding it to the result,
+/// which saves a separate add when applying the bias of a linear layer.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_avx2_nofma_dot_plus(x: &[f32], y: &[f32], bias: f32) -> f32 {
     debug_assert_eq!(x.len(), y.len());
 
     let len = x.len();
     let offset_from = len % 64;
-    let mut total = 0.0;
+    let mut total = bias;
 
     let x_ptr = x.as_ptr();
     let y_ptr = y.as_ptr();
@@ -103,12 +195,25 @@ pub unsafe fn f32_xany_avx2_nofma_dot(x: &[f32], y: &[f32]) -> f32 {
     if offset_from != 0 {
         let tail = offset_from % 8;
 
-        while i < (len - tail) {
+        // Alternate between independent accumulators so short vectors, which are
+        // handled entirely by this loop, don't serialize on a single add chain.
+        while i + 16 <= (len - tail) {
+            let x1 = _mm256_loadu_ps(x_ptr.add(i));
+            let y1 = _mm256_loadu_ps(y_ptr.add(i));
+            let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+            let y2 = _mm256_loadu_ps(y_ptr.add(i + 8));
+
+            acc1 = _mm256_add_ps(acc1, _mm256_mul_ps(x1, y1));
+            acc2 = _mm256_add_ps(acc2, _mm256_mul_ps(x2, y2));
+
+            i += 16;
+        }
+
+        if i < (len - tail) {
             let x = _mm256_loadu_ps(x_ptr.add(i));
             let y = _mm256_loadu_ps(y_ptr.add(i));
 
-            let res = _mm256_mul_ps(x, y);
-            acc1 = _mm256_add_ps(acc1, res);
+            acc3 = _mm256_add_ps(acc3, _mm256_mul_ps(x, y));
 
             i += 8;
         }
@@ -180,13 +285,105 @@ pub unsafe fn f32_xconst_avx2_fma_dot<const DIMS: usize>(x: &[f32], y: &[f32]) -
 /// Vectors **MUST** be the same length, otherwise this routine
 /// will become immediately UB due to out of bounds pointer accesses.
 pub unsafe fn f32_xany_avx2_fma_dot(x: &[f32], y: &[f32]) -> f32 {
+    f32_xany_avx2_fma_dot_plus(x, y, 0.0)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of the first `n` elements of two `f32` vectors, ignoring
+/// any elements beyond them, i.e. the unused capacity of padded buffers.
+///
+/// # Panics
+///
+/// Panics if `n` is greater than the length of either vector.
+///
+/// # Safety
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_dot_prefix(x: &[f32], y: &[f32], n: usize) -> f32 {
+    assert!(
+        n <= x.len().min(y.len()),
+        "Prefix length {n} exceeds the length of the vectors"
+    );
+    f32_xany_avx2_fma_dot(x.get_unchecked(..n), y.get_unchecked(..n))
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of each pair of rows of two row-major `f32` matrices
+/// with `dim` columns, only including the first `lengths[row]` elements of each row.
+///
+/// ```py
+/// R: int
+/// dim: int
+/// x: [f32; R * dim]
+/// y: [f32; R * dim]
+/// lengths: [usize; R]
+/// result: [f32; R]
+///
+/// for row in 0..R:
+///     start = row * dim
+///     result[row] = dot(x[start:start + lengths[row]], y[start:start + lengths[row]])
+/// ```
+///
+/// This allows a batch of variable length vectors padded out to a fixed width, i.e.
+/// right-padded token embeddings, to be dotted without the padding contributing to
+/// the result or being processed at all.
+///
+/// # Panics
+///
+/// Panics if any of the `lengths` is greater than `dim`.
+///
+/// # Safety
+///
+/// `x` and `y` **MUST** both be `lengths.len() * dim` in length and `result` **MUST**
+/// be `lengths.len()` in length, otherwise this routine will become immediately UB
+/// due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_dot_ragged(
+    x: &[f32],
+    y: &[f32],
+    dim: usize,
+    lengths: &[usize],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), lengths.len() * dim);
+    debug_assert_eq!(y.len(), lengths.len() * dim);
+    debug_assert_eq!(result.len(), lengths.len());
+
+    for (row, &n) in lengths.iter().enumerate() {
+        assert!(n <= dim, "Row length {n} exceeds the row width {dim}");
+
+        let start = row * dim;
+        *result.get_unchecked_mut(row) = f32_xany_avx2_fma_dot(
+            x.get_unchecked(start..start + n),
+            y.get_unchecked(start..start + n),
+        );
+    }
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes `bias` plus the dot product of two `f32` vectors.
+///
+/// The accumulator is seeded with `bias` rather than adding it to the result,
+/// which saves a separate add when applying the bias of a linear layer.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_avx2_fma_dot_plus(x: &[f32], y: &[f32], bias: f32) -> f32 {
     use crate::math::*;
 
     debug_assert_eq!(x.len(), y.len());
 
     let len = x.len();
     let offset_from = len % 64;
-    let mut total = 0.0;
+    let mut total = bias;
 
     let x_ptr = x.as_ptr();
     let y_ptr = y.as_ptr();
@@ -221,11 +418,25 @@ pub unsafe fn f32_xany_avx2_fma_dot(x: &[f32], y: &[f32]) -> f32 {
     if offset_from != 0 {
         let tail = offset_from % 8;
 
-        while i < (len - tail) {
+        // Alternate between independent accumulators so short vectors, which are
+        // handled entirely by this loop, don't serialize on a single FMA chain.
+        while i + 16 <= (len - tail) {
+            let x1 = _mm256_loadu_ps(x_ptr.add(i));
+            let y1 = _mm256_loadu_ps(y_ptr.add(i));
+            let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+            let y2 = _mm256_loadu_ps(y_ptr.add(i + 8));
+
+            acc1 = _mm256_fmadd_ps(x1, y1, acc1);
+            acc2 = _mm256_fmadd_ps(x2, y2, acc2);
+
+            i += 16;
+        }
+
+        if i < (len - tail) {
             let x = _mm256_loadu_ps(x_ptr.add(i));
             let y = _mm256_loadu_ps(y_ptr.add(i));
 
-            acc1 = _mm256_fmadd_ps(x, y, acc1);
+            acc3 = _mm256_fmadd_ps(x, y, acc3);
 
             i += 8;
         }
@@ -348,6 +559,66 @@ unsafe fn execute_f32_x64_fma_block_dot_product(
     *acc8 = _mm256_fmadd_ps(x8, y8, *acc8);
 }
 
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of two vectors split into multiple chunks, such as
+/// segmented buffers, without copying them into contiguous vectors first.
+///
+/// The chunk boundaries of `x` and `y` do not need to line up or be aligned to
+/// the register width, each overlapping pair of sub-slices is reduced with the
+/// contiguous routine and accumulated by seeding it with the running total.
+///
+/// ```py
+/// total: f32
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+/// ```
+///
+/// # Safety
+///
+/// The chunks of both vectors **MUST** contain the same number of elements in total.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_dot_chunks(x: &[&[f32]], y: &[&[f32]]) -> f32 {
+    fold_chunk_pairs(x, y, 0.0, |x, y, total| {
+        f32_xany_avx2_nofma_dot_plus(x, y, total)
+    })
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of two vectors split into multiple chunks, such as
+/// segmented buffers, without copying them into contiguous vectors first.
+///
+/// The chunk boundaries of `x` and `y` do not need to line up or be aligned to
+/// the register width, each overlapping pair of sub-slices is reduced with the
+/// contiguous routine and accumulated by seeding it with the running total.
+///
+/// ```py
+/// total: f32
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+/// ```
+///
+/// # Safety
+///
+/// The chunks of both vectors **MUST** contain the same number of elements in total.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_dot_chunks(x: &[&[f32]], y: &[&[f32]]) -> f32 {
+    fold_chunk_pairs(x, y, 0.0, |x, y, total| {
+        f32_xany_avx2_fma_dot_plus(x, y, total)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -380,4 +651,118 @@ mod tests {
         let dist = unsafe { f32_xconst_avx2_nofma_dot::<1024>(&x, &y) };
         assert_is_close(dist, simple_dot(&x, &y))
     }
+
+    #[test]
+    fn test_xconst_dot_embedding_dims() {
+        fn check<const DIMS: usize>() {
+            let (x, y) = get_sample_vectors(DIMS);
+            let expected = unsafe { f32_xany_avx2_fma_dot(&x, &y) };
+            let nofma = unsafe { f32_xconst_avx2_nofma_dot::<DIMS>(&x, &y) };
+            let fma = unsafe { f32_xconst_avx2_fma_dot::<DIMS>(&x, &y) };
+            assert_is_close(nofma, expected);
+            assert_is_close(fma, expected);
+        }
+
+        check::<128>();
+        check::<256>();
+        check::<768>();
+        check::<1536>();
+    }
+
+    #[test]
+    fn test_xany_dot_tail_lengths() {
+        for len in [0, 3, 8, 16, 24, 31, 56, 63, 64, 65, 120] {
+            let (x, y) = get_sample_vectors(len);
+            let expected = simple_dot(&x, &y);
+            assert_is_close(unsafe { f32_xany_avx2_nofma_dot(&x, &y) }, expected);
+            assert_is_close(unsafe { f32_xany_avx2_fma_dot(&x, &y) }, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_dot_plus() {
+        for len in [0, 3, 127, 1024] {
+            let (x, y) = get_sample_vectors(len);
+            let expected = simple_dot(&x, &y) + 1.5;
+            assert_is_close(
+                unsafe { f32_xany_avx2_nofma_dot_plus(&x, &y, 1.5) },
+                expected,
+            );
+            assert_is_close(
+                unsafe { f32_xany_avx2_fma_dot_plus(&x, &y, 1.5) },
+                expected,
+            );
+        }
+    }
+
+    #[test]
+    fn test_xany_dot_chunks() {
+        let (x, y) = get_sample_vectors::<f32>(531);
+        let expected = simple_dot(&x, &y);
+
+        // Chunk boundaries deliberately differ between the two vectors.
+        let x_chunks = [&x[..13], &x[13..13], &x[13..200], &x[200..]];
+        let y_chunks = [&y[..64], &y[64..301], &y[301..]];
+
+        let dist = unsafe { f32_xany_avx2_nofma_dot_chunks(&x_chunks, &y_chunks) };
+        assert_is_close(dist, expected);
+        let dist = unsafe { f32_xany_avx2_fma_dot_chunks(&x_chunks, &y_chunks) };
+        assert_is_close(dist, expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_dot_prefix() {
+        let (mut x, y) = get_sample_vectors::<f32>(531);
+        x.truncate(517);
+        for n in [0, 7, 64, 200, 517] {
+            let dist = unsafe { f32_xany_avx2_nofma_dot_prefix(&x, &y, n) };
+            let expected = unsafe { f32_xany_avx2_nofma_dot(&x[..n], &y[..n]) };
+            assert_eq!(dist, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_fma_dot_prefix() {
+        let (mut x, y) = get_sample_vectors::<f32>(531);
+        x.truncate(517);
+        for n in [0, 7, 64, 200, 517] {
+            let dist = unsafe { f32_xany_avx2_fma_dot_prefix(&x, &y, n) };
+            let expected = unsafe { f32_xany_avx2_fma_dot(&x[..n], &y[..n]) };
+            assert_eq!(dist, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_dot_ragged() {
+        let (x, y) = get_sample_vectors::<f32>(4 * 133);
+        let lengths = [133, 0, 70, 8];
+
+        let mut result = vec![f32::NAN; 4];
+        unsafe { f32_xany_avx2_nofma_dot_ragged(&x, &y, 133, &lengths, &mut result) };
+
+        for (row, n) in lengths.into_iter().enumerate() {
+            let start = row * 133;
+            let expected = unsafe {
+                f32_xany_avx2_nofma_dot(&x[start..start + n], &y[start..start + n])
+            };
+            assert_eq!(result[row], expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_fma_dot_ragged() {
+        let (x, y) = get_sample_vectors::<f32>(4 * 133);
+        let lengths = [133, 0, 70, 8];
+
+        let mut result = vec![f32::NAN; 4];
+        unsafe { f32_xany_avx2_fma_dot_ragged(&x, &y, 133, &lengths, &mut result) };
+
+        for (row, n) in lengths.into_iter().enumerate() {
+            let start = row * 133;
+            let expected = unsafe {
+                f32_xany_avx2_fma_dot(&x[start..start + n], &y[start..start + n])
+            };
+            assert_eq!(result[row], expected);
+        }
+    }
 }
@@ -1,6 +1,14 @@
 use core::arch::x86_64::*;
 
-use crate::danger::{offsets_avx2_ps, rollup_x8_ps, sum_avx2_ps, CHUNK_0, CHUNK_1};
+use crate::danger::{
+    bounded_distance_with,
+    offsets_avx2_ps,
+    rollup_x8_ps,
+    sum_avx2_ps,
+    CHUNK_0,
+    CHUNK_1,
+};
+use crate::math::AutoMath;
 
 #[target_feature(enable = "avx2")]
 #[inline]
@@ -259,6 +267,94 @@ pub unsafe fn f32_xany_avx2_fma_euclidean(x: &[f32], y: &[f32]) -> f32 {
     total + sum_avx2_ps(acc)
 }
 
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the squared Euclidean distance of two `f32` vectors, abandoning the
+/// computation once the partial distance exceeds `threshold` squared.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     total = total + (x[i] - y[i]) ** 2
+///     if total > threshold ** 2:
+///         return None
+///
+/// return total
+/// ```
+///
+/// The partial distance is only compared against the bound after every 256
+/// elements, so a pair is abandoned at most one interval after it crosses the
+/// threshold. Checking more often abandons sooner but stalls the accumulators on
+/// each comparison, checking less often keeps the kernel at full throughput but
+/// wastes work on pairs which are already too far apart.
+///
+/// # Safety
+///
+/// Vectors **MUST** match in size, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_euclidean_bounded(
+    x: &[f32],
+    y: &[f32],
+    threshold: f32,
+) -> Option<f32> {
+    debug_assert_eq!(x.len(), y.len());
+
+    bounded_distance_with::<f32, AutoMath, _>(x, y, threshold * threshold, |x, y| {
+        f32_xany_avx2_nofma_euclidean(x, y)
+    })
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the squared Euclidean distance of two `f32` vectors, abandoning the
+/// computation once the partial distance exceeds `threshold` squared.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     total = total + (x[i] - y[i]) ** 2
+///     if total > threshold ** 2:
+///         return None
+///
+/// return total
+/// ```
+///
+/// The partial distance is only compared against the bound after every 256
+/// elements, so a pair is abandoned at most one interval after it crosses the
+/// threshold. Checking more often abandons sooner but stalls the accumulators on
+/// each comparison, checking less often keeps the kernel at full throughput but
+/// wastes work on pairs which are already too far apart.
+///
+/// # Safety
+///
+/// Vectors **MUST** match in size, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is executed
+/// on non-AVX2/FMA enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_euclidean_bounded(
+    x: &[f32],
+    y: &[f32],
+    threshold: f32,
+) -> Option<f32> {
+    debug_assert_eq!(x.len(), y.len());
+
+    bounded_distance_with::<f32, AutoMath, _>(x, y, threshold * threshold, |x, y| {
+        f32_xany_avx2_fma_euclidean(x, y)
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 #[inline(always)]
 unsafe fn execute_f32_x64_nofma_block_euclidean(
@@ -401,6 +497,27 @@ mod tests {
         assert_is_close(dist, simple_euclidean(&x, &y));
     }
 
+    #[test]
+    fn test_xany_euclidean_bounded() {
+        let (x, y) = get_sample_vectors::<f32>(1031);
+        let expected = simple_euclidean(&x, &y);
+        let threshold = expected.sqrt();
+
+        let dist =
+            unsafe { f32_xany_avx2_nofma_euclidean_bounded(&x, &y, threshold * 1.01) };
+        assert_is_close(dist.unwrap(), expected);
+        let dist =
+            unsafe { f32_xany_avx2_fma_euclidean_bounded(&x, &y, threshold * 1.01) };
+        assert_is_close(dist.unwrap(), expected);
+
+        let dist =
+            unsafe { f32_xany_avx2_nofma_euclidean_bounded(&x, &y, threshold * 0.5) };
+        assert_eq!(dist, None);
+        let dist =
+            unsafe { f32_xany_avx2_fma_euclidean_bounded(&x, &y, threshold * 0.5) };
+        assert_eq!(dist, None);
+    }
+
     #[test]
     fn test_xconst_fma_euclidean() {
         let (x, y) = get_sample_vectors(1024);
@@ -0,0 +1,223 @@
+use core::arch::x86_64::*;
+
+/// The exponent bits of an `f32`, a value is non-finite when all of them are set.
+const F32_EXPONENT_MASK: i32 = 0x7F80_0000;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Writes a mask to `mask` marking which elements of the vector are `NaN`.
+///
+/// Each element of the mask is set to all-ones (`u32::MAX`) where the element is `NaN`
+/// and `0` otherwise, matching the output of a SIMD comparison so the mask can be
+/// used directly for blending.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// mask: [u32; D]
+///
+/// for i in 0..D:
+///     mask[i] = 0xFFFFFFFF if x[i] != x[i] else 0
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_is_nan(x: &[f32], mask: &mut [u32]) {
+    debug_assert_eq!(x.len(), mask.len());
+
+    let len = x.len();
+    let offset_from = len % 8;
+    let x_ptr = x.as_ptr();
+    let mask_ptr = mask.as_mut_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let v = _mm256_loadu_ps(x_ptr.add(i));
+        let is_nan = _mm256_cmp_ps::<_CMP_NEQ_UQ>(v, v);
+        _mm256_storeu_si256(mask_ptr.add(i).cast(), _mm256_castps_si256(is_nan));
+
+        i += 8;
+    }
+
+    while i < len {
+        let v = *x_ptr.add(i);
+        *mask_ptr.add(i) = if v.is_nan() { u32::MAX } else { 0 };
+
+        i += 1;
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Writes a mask to `mask` marking which elements of the vector are finite,
+/// that is neither `NaN` nor `inf`.
+///
+/// Each element of the mask is set to all-ones (`u32::MAX`) where the element is finite
+/// and `0` otherwise, matching the output of a SIMD comparison so the mask can be
+/// used directly for blending.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// mask: [u32; D]
+///
+/// for i in 0..D:
+///     exponent = bits(x[i]) & 0x7F800000
+///     mask[i] = 0xFFFFFFFF if exponent != 0x7F800000 else 0
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_is_finite(x: &[f32], mask: &mut [u32]) {
+    debug_assert_eq!(x.len(), mask.len());
+
+    let len = x.len();
+    let offset_from = len % 8;
+    let x_ptr = x.as_ptr();
+    let mask_ptr = mask.as_mut_ptr();
+
+    let exponent_mask = _mm256_set1_epi32(F32_EXPONENT_MASK);
+    let all_ones = _mm256_set1_epi32(-1);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let v = _mm256_loadu_si256(x_ptr.add(i).cast());
+        let exponent = _mm256_and_si256(v, exponent_mask);
+        let is_non_finite = _mm256_cmpeq_epi32(exponent, exponent_mask);
+        _mm256_storeu_si256(
+            mask_ptr.add(i).cast(),
+            _mm256_xor_si256(is_non_finite, all_ones),
+        );
+
+        i += 8;
+    }
+
+    while i < len {
+        let v = *x_ptr.add(i);
+        *mask_ptr.add(i) = if v.is_finite() { u32::MAX } else { 0 };
+
+        i += 1;
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Returns `true` if any element of the vector is `NaN`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     if x[i] != x[i]:
+///         return True
+/// return False
+/// ```
+///
+/// The search exits early once a `NaN` is found.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_contains_nan(x: &[f32]) -> bool {
+    let len = x.len();
+    let offset_from = len % 32;
+    let x_ptr = x.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let v1 = _mm256_loadu_ps(x_ptr.add(i));
+        let v2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let v3 = _mm256_loadu_ps(x_ptr.add(i + 16));
+        let v4 = _mm256_loadu_ps(x_ptr.add(i + 24));
+
+        // Any NaN in either operand makes the comparison unordered.
+        let unord1 = _mm256_cmp_ps::<_CMP_UNORD_Q>(v1, v2);
+        let unord2 = _mm256_cmp_ps::<_CMP_UNORD_Q>(v3, v4);
+        if _mm256_movemask_ps(_mm256_or_ps(unord1, unord2)) != 0 {
+            return true;
+        }
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let v = _mm256_loadu_ps(x_ptr.add(i));
+        if _mm256_movemask_ps(_mm256_cmp_ps::<_CMP_UNORD_Q>(v, v)) != 0 {
+            return true;
+        }
+
+        i += 8;
+    }
+
+    x.get_unchecked(i..).iter().any(|v| v.is_nan())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    fn sample_with_specials(len: usize) -> Vec<f32> {
+        let (mut x, _) = get_sample_vectors::<f32>(len);
+        x[1] = f32::NAN;
+        x[9] = f32::INFINITY;
+        x[17] = f32::NEG_INFINITY;
+        x[len - 1] = -f32::NAN;
+        x[len - 2] = f32::MAX;
+        x[len - 3] = f32::MIN_POSITIVE / 2.0;
+        x
+    }
+
+    #[test]
+    fn test_xany_nofma_is_nan() {
+        let x = sample_with_specials(131);
+        let mut mask = vec![0; 131];
+        unsafe { f32_xany_avx2_nofma_is_nan(&x, &mut mask) };
+
+        let expected = x
+            .iter()
+            .map(|v| if v.is_nan() { u32::MAX } else { 0 })
+            .collect::<Vec<_>>();
+        assert_eq!(mask, expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_is_finite() {
+        let x = sample_with_specials(131);
+        let mut mask = vec![0; 131];
+        unsafe { f32_xany_avx2_nofma_is_finite(&x, &mut mask) };
+
+        let expected = x
+            .iter()
+            .map(|v| if v.is_finite() { u32::MAX } else { 0 })
+            .collect::<Vec<_>>();
+        assert_eq!(mask, expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_contains_nan() {
+        let (mut x, _) = get_sample_vectors::<f32>(131);
+        assert!(!unsafe { f32_xany_avx2_nofma_contains_nan(&x) });
+
+        for pos in [0, 37, 100, 130] {
+            x[pos] = f32::NAN;
+            assert!(unsafe { f32_xany_avx2_nofma_contains_nan(&x) });
+            x[pos] = 1.0;
+        }
+
+        x[5] = f32::INFINITY;
+        assert!(!unsafe { f32_xany_avx2_nofma_contains_nan(&x) });
+    }
+}
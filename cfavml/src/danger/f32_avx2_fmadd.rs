@@ -0,0 +1,546 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Multiplies `a` and `b` element wise and adds `c`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// a: [f32; D]
+/// b: [f32; D]
+/// c: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = a[i] * b[i] + c[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_fmadd_vector(
+    a: &[f32],
+    b: &[f32],
+    c: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+    debug_assert_eq!(a.len(), result.len());
+    debug_assert!(
+        ![a, b, c].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    f32_avx2_nofma_fmadd_to::<false>(a, b, c.as_ptr(), result.as_mut_ptr())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Multiplies `a` and `b` element wise and adds `c`, writing the result back to `c`.
+///
+/// ```py
+/// D: int
+/// a: [f32; D]
+/// b: [f32; D]
+/// c: [f32; D]
+///
+/// for i in 0..D:
+///     c[i] = a[i] * b[i] + c[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_fmadd_vector_inplace(
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+) {
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+
+    let c_ptr = c.as_mut_ptr();
+    f32_avx2_nofma_fmadd_to::<false>(a, b, c_ptr, c_ptr)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Multiplies `a` and `b` element wise and subtracts `c`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// a: [f32; D]
+/// b: [f32; D]
+/// c: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = a[i] * b[i] - c[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_fmsub_vector(
+    a: &[f32],
+    b: &[f32],
+    c: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+    debug_assert_eq!(a.len(), result.len());
+    debug_assert!(
+        ![a, b, c].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    f32_avx2_nofma_fmadd_to::<true>(a, b, c.as_ptr(), result.as_mut_ptr())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Multiplies `a` and `b` element wise and subtracts `c`, writing the result back to `c`.
+///
+/// ```py
+/// D: int
+/// a: [f32; D]
+/// b: [f32; D]
+/// c: [f32; D]
+///
+/// for i in 0..D:
+///     c[i] = a[i] * b[i] - c[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_fmsub_vector_inplace(
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+) {
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+
+    let c_ptr = c.as_mut_ptr();
+    f32_avx2_nofma_fmadd_to::<true>(a, b, c_ptr, c_ptr)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Multiplies `a` and `b` element wise and adds `c`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// a: [f32; D]
+/// b: [f32; D]
+/// c: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = a[i] * b[i] + c[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_fmadd_vector(
+    a: &[f32],
+    b: &[f32],
+    c: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+    debug_assert_eq!(a.len(), result.len());
+    debug_assert!(
+        ![a, b, c].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    f32_avx2_fma_fmadd_to::<false>(a, b, c.as_ptr(), result.as_mut_ptr())
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Multiplies `a` and `b` element wise and adds `c`, writing the result back to `c`.
+///
+/// ```py
+/// D: int
+/// a: [f32; D]
+/// b: [f32; D]
+/// c: [f32; D]
+///
+/// for i in 0..D:
+///     c[i] = a[i] * b[i] + c[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_fmadd_vector_inplace(
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+) {
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+
+    let c_ptr = c.as_mut_ptr();
+    f32_avx2_fma_fmadd_to::<false>(a, b, c_ptr, c_ptr)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Multiplies `a` and `b` element wise and subtracts `c`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// a: [f32; D]
+/// b: [f32; D]
+/// c: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = a[i] * b[i] - c[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_fmsub_vector(
+    a: &[f32],
+    b: &[f32],
+    c: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+    debug_assert_eq!(a.len(), result.len());
+    debug_assert!(
+        ![a, b, c].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    f32_avx2_fma_fmadd_to::<true>(a, b, c.as_ptr(), result.as_mut_ptr())
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Multiplies `a` and `b` element wise and subtracts `c`, writing the result back to `c`.
+///
+/// ```py
+/// D: int
+/// a: [f32; D]
+/// b: [f32; D]
+/// c: [f32; D]
+///
+/// for i in 0..D:
+///     c[i] = a[i] * b[i] - c[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_fmsub_vector_inplace(
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+) {
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+
+    let c_ptr = c.as_mut_ptr();
+    f32_avx2_fma_fmadd_to::<true>(a, b, c_ptr, c_ptr)
+}
+
+#[inline(always)]
+/// Computes `a * b + c`, or `a * b - c` when `SUB` is `true`, writing the output
+/// to the `result` pointer.
+///
+/// `c_ptr` and `result` may point to the same memory.
+unsafe fn f32_avx2_nofma_fmadd_to<const SUB: bool>(
+    a: &[f32],
+    b: &[f32],
+    c_ptr: *const f32,
+    result: *mut f32,
+) {
+    let len = a.len();
+    let offset_from = len % 32;
+    let a_ptr = a.as_ptr();
+    let b_ptr = b.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let a0 = _mm256_loadu_ps(a_ptr.add(i));
+        let b0 = _mm256_loadu_ps(b_ptr.add(i));
+        let c0 = _mm256_loadu_ps(c_ptr.add(i));
+        let a1 = _mm256_loadu_ps(a_ptr.add(i + 8));
+        let b1 = _mm256_loadu_ps(b_ptr.add(i + 8));
+        let c1 = _mm256_loadu_ps(c_ptr.add(i + 8));
+        let a2 = _mm256_loadu_ps(a_ptr.add(i + 16));
+        let b2 = _mm256_loadu_ps(b_ptr.add(i + 16));
+        let c2 = _mm256_loadu_ps(c_ptr.add(i + 16));
+        let a3 = _mm256_loadu_ps(a_ptr.add(i + 24));
+        let b3 = _mm256_loadu_ps(b_ptr.add(i + 24));
+        let c3 = _mm256_loadu_ps(c_ptr.add(i + 24));
+
+        let r0 = if SUB {
+            _mm256_sub_ps(_mm256_mul_ps(a0, b0), c0)
+        } else {
+            _mm256_add_ps(_mm256_mul_ps(a0, b0), c0)
+        };
+        _mm256_storeu_ps(result.add(i), r0);
+        let r1 = if SUB {
+            _mm256_sub_ps(_mm256_mul_ps(a1, b1), c1)
+        } else {
+            _mm256_add_ps(_mm256_mul_ps(a1, b1), c1)
+        };
+        _mm256_storeu_ps(result.add(i + 8), r1);
+        let r2 = if SUB {
+            _mm256_sub_ps(_mm256_mul_ps(a2, b2), c2)
+        } else {
+            _mm256_add_ps(_mm256_mul_ps(a2, b2), c2)
+        };
+        _mm256_storeu_ps(result.add(i + 16), r2);
+        let r3 = if SUB {
+            _mm256_sub_ps(_mm256_mul_ps(a3, b3), c3)
+        } else {
+            _mm256_add_ps(_mm256_mul_ps(a3, b3), c3)
+        };
+        _mm256_storeu_ps(result.add(i + 24), r3);
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let a = _mm256_loadu_ps(a_ptr.add(i));
+        let b = _mm256_loadu_ps(b_ptr.add(i));
+        let c = _mm256_loadu_ps(c_ptr.add(i));
+        let r = if SUB {
+            _mm256_sub_ps(_mm256_mul_ps(a, b), c)
+        } else {
+            _mm256_add_ps(_mm256_mul_ps(a, b), c)
+        };
+        _mm256_storeu_ps(result.add(i), r);
+
+        i += 8;
+    }
+
+    while i < len {
+        let product = AutoMath::mul(*a_ptr.add(i), *b_ptr.add(i));
+        let c = *c_ptr.add(i);
+        *result.add(i) = if SUB {
+            AutoMath::sub(product, c)
+        } else {
+            AutoMath::add(product, c)
+        };
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Computes `a * b + c`, or `a * b - c` when `SUB` is `true`, writing the output
+/// to the `result` pointer.
+///
+/// `c_ptr` and `result` may point to the same memory.
+unsafe fn f32_avx2_fma_fmadd_to<const SUB: bool>(
+    a: &[f32],
+    b: &[f32],
+    c_ptr: *const f32,
+    result: *mut f32,
+) {
+    let len = a.len();
+    let offset_from = len % 32;
+    let a_ptr = a.as_ptr();
+    let b_ptr = b.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let a0 = _mm256_loadu_ps(a_ptr.add(i));
+        let b0 = _mm256_loadu_ps(b_ptr.add(i));
+        let c0 = _mm256_loadu_ps(c_ptr.add(i));
+        let a1 = _mm256_loadu_ps(a_ptr.add(i + 8));
+        let b1 = _mm256_loadu_ps(b_ptr.add(i + 8));
+        let c1 = _mm256_loadu_ps(c_ptr.add(i + 8));
+        let a2 = _mm256_loadu_ps(a_ptr.add(i + 16));
+        let b2 = _mm256_loadu_ps(b_ptr.add(i + 16));
+        let c2 = _mm256_loadu_ps(c_ptr.add(i + 16));
+        let a3 = _mm256_loadu_ps(a_ptr.add(i + 24));
+        let b3 = _mm256_loadu_ps(b_ptr.add(i + 24));
+        let c3 = _mm256_loadu_ps(c_ptr.add(i + 24));
+
+        let r0 = if SUB {
+            _mm256_fmsub_ps(a0, b0, c0)
+        } else {
+            _mm256_fmadd_ps(a0, b0, c0)
+        };
+        _mm256_storeu_ps(result.add(i), r0);
+        let r1 = if SUB {
+            _mm256_fmsub_ps(a1, b1, c1)
+        } else {
+            _mm256_fmadd_ps(a1, b1, c1)
+        };
+        _mm256_storeu_ps(result.add(i + 8), r1);
+        let r2 = if SUB {
+            _mm256_fmsub_ps(a2, b2, c2)
+        } else {
+            _mm256_fmadd_ps(a2, b2, c2)
+        };
+        _mm256_storeu_ps(result.add(i + 16), r2);
+        let r3 = if SUB {
+            _mm256_fmsub_ps(a3, b3, c3)
+        } else {
+            _mm256_fmadd_ps(a3, b3, c3)
+        };
+        _mm256_storeu_ps(result.add(i + 24), r3);
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let a = _mm256_loadu_ps(a_ptr.add(i));
+        let b = _mm256_loadu_ps(b_ptr.add(i));
+        let c = _mm256_loadu_ps(c_ptr.add(i));
+        let r = if SUB {
+            _mm256_fmsub_ps(a, b, c)
+        } else {
+            _mm256_fmadd_ps(a, b, c)
+        };
+        _mm256_storeu_ps(result.add(i), r);
+
+        i += 8;
+    }
+
+    while i < len {
+        let product = AutoMath::mul(*a_ptr.add(i), *b_ptr.add(i));
+        let c = *c_ptr.add(i);
+        *result.add(i) = if SUB {
+            AutoMath::sub(product, c)
+        } else {
+            AutoMath::add(product, c)
+        };
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close_vector, get_sample_vectors};
+
+    #[test]
+    fn test_xany_nofma_fmadd_vector() {
+        let (a, b) = get_sample_vectors::<f32>(131);
+        let mut c = b.iter().rev().copied().collect::<Vec<f32>>();
+        let expected = a
+            .iter()
+            .zip(b.iter())
+            .zip(c.iter())
+            .map(|((a, b), c)| a * b + c)
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_nofma_fmadd_vector(&a, &b, &c, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        unsafe { f32_xany_avx2_nofma_fmadd_vector_inplace(&a, &b, &mut c) };
+        assert_is_close_vector(&c, &expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_fmsub_vector() {
+        let (a, b) = get_sample_vectors::<f32>(131);
+        let mut c = b.iter().rev().copied().collect::<Vec<f32>>();
+        let expected = a
+            .iter()
+            .zip(b.iter())
+            .zip(c.iter())
+            .map(|((a, b), c)| a * b - c)
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_nofma_fmsub_vector(&a, &b, &c, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        unsafe { f32_xany_avx2_nofma_fmsub_vector_inplace(&a, &b, &mut c) };
+        assert_is_close_vector(&c, &expected);
+    }
+
+    #[test]
+    fn test_xany_fma_fmadd_vector() {
+        let (a, b) = get_sample_vectors::<f32>(131);
+        let mut c = b.iter().rev().copied().collect::<Vec<f32>>();
+        let expected = a
+            .iter()
+            .zip(b.iter())
+            .zip(c.iter())
+            .map(|((a, b), c)| a * b + c)
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_fma_fmadd_vector(&a, &b, &c, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        unsafe { f32_xany_avx2_fma_fmadd_vector_inplace(&a, &b, &mut c) };
+        assert_is_close_vector(&c, &expected);
+    }
+
+    #[test]
+    fn test_xany_fma_fmsub_vector() {
+        let (a, b) = get_sample_vectors::<f32>(131);
+        let mut c = b.iter().rev().copied().collect::<Vec<f32>>();
+        let expected = a
+            .iter()
+            .zip(b.iter())
+            .zip(c.iter())
+            .map(|((a, b), c)| a * b - c)
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_fma_fmsub_vector(&a, &b, &c, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        unsafe { f32_xany_avx2_fma_fmsub_vector_inplace(&a, &b, &mut c) };
+        assert_is_close_vector(&c, &expected);
+    }
+}
@@ -0,0 +1,147 @@
+use core::arch::x86_64::*;
+
+use crate::danger::{exp_avx2_ps, partially_overlaps};
+
+/// `sqrt(2 / pi)`
+const SQRT_2_OVER_PI: f32 = 0.797_884_6;
+const GELU_CUBIC: f32 = 0.044_715;
+/// Inputs below this produce `-0.0`, as `1 + tanh(u)` rounds to zero in the fallback.
+const GELU_SATURATION: f32 = -10.0;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Applies the GELU activation to the vector, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     u = sqrt(2 / pi) * (x[i] + 0.044715 * x[i] ** 3)
+///     result[i] = 0.5 * x[i] * (1 + tanh(u))
+/// ```
+///
+/// This is the tanh approximation of GELU, matching PyTorch's
+/// `gelu(x, approximate="tanh")` and the original BERT and GPT-2 implementations,
+/// **not** the exact `0.5 * x * (1 + erf(x / sqrt(2)))` form. The two differ by up
+/// to roughly `5e-4` in absolute terms.
+///
+/// Internally `0.5 * (1 + tanh(u))` is evaluated as the equivalent `1 / (1 + e^-2u)`
+/// so only a single vectorized `exp` is required, results agree with the scalar tanh
+/// formulation to within a few ULP.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_gelu(x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    f32_avx2_gelu_to(x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Applies the GELU activation to the vector in place.
+///
+/// See [f32_xany_avx2_nofma_gelu] for the approximation used.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_gelu_inplace(x: &mut [f32]) {
+    let x_ptr = x.as_mut_ptr();
+    f32_avx2_gelu_to(x_ptr, x_ptr, x.len())
+}
+
+#[inline(always)]
+/// Applies the GELU to the values read from `x_ptr` writing the output to the
+/// `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn f32_avx2_gelu_to(x_ptr: *const f32, result: *mut f32, len: usize) {
+    let offset_from = len % 8;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+        _mm256_storeu_ps(result.add(i), gelu_avx2_ps(x));
+
+        i += 8;
+    }
+
+    while i < len {
+        *result.add(i) = gelu(*x_ptr.add(i));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+unsafe fn gelu_avx2_ps(x: __m256) -> __m256 {
+    let one = _mm256_set1_ps(1.0);
+
+    // -2u = -2 * sqrt(2 / pi) * x * (1 + 0.044715 * x²)
+    let x2 = _mm256_mul_ps(x, x);
+    let inner = _mm256_add_ps(one, _mm256_mul_ps(_mm256_set1_ps(GELU_CUBIC), x2));
+    let neg_2u = _mm256_mul_ps(
+        _mm256_mul_ps(_mm256_set1_ps(-2.0 * SQRT_2_OVER_PI), x),
+        inner,
+    );
+
+    let result = _mm256_div_ps(x, _mm256_add_ps(one, exp_avx2_ps(neg_2u)));
+
+    // `exp_avx2_ps` clamps its input, so without saturating `gelu(-inf)` would be
+    // `-inf / (1 + finite)` rather than `-0.0`.
+    let saturated = _mm256_cmp_ps::<_CMP_LT_OQ>(x, _mm256_set1_ps(GELU_SATURATION));
+    _mm256_blendv_ps(result, _mm256_set1_ps(-0.0), saturated)
+}
+
+#[inline(always)]
+fn gelu(x: f32) -> f32 {
+    if x < GELU_SATURATION {
+        return -0.0;
+    }
+
+    let u = SQRT_2_OVER_PI * (x + GELU_CUBIC * x * x * x);
+    0.5 * x * (1.0 + u.tanh())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_gelu() {
+        // The infinities are placed so they go through both the vectorized loop and
+        // the scalar tail.
+        let mut x = vec![f32::NEG_INFINITY, f32::INFINITY];
+        x.extend((0..531).map(|i| (i as f32 - 265.0) / 20.0));
+        x.extend([-100.0, 100.0, 0.0, -0.0, f32::NEG_INFINITY, f32::INFINITY]);
+
+        let expected = x.iter().map(|v| gelu(*v)).collect::<Vec<_>>();
+
+        let mut result = vec![0.0; x.len()];
+        unsafe { f32_xany_avx2_nofma_gelu(&x, &mut result) };
+        for ((v, r), e) in x.iter().zip(&result).zip(&expected) {
+            assert!(
+                r == e || (r - e).abs() <= 1e-6 + 1e-5 * e.abs(),
+                "gelu({v}) = {r} != {e}"
+            );
+        }
+        assert!(result[0] == 0.0 && result[0].is_sign_negative());
+        assert_eq!(result[1], f32::INFINITY);
+
+        unsafe { f32_xany_avx2_nofma_gelu_inplace(&mut x) };
+        assert_eq!(x, result);
+    }
+}
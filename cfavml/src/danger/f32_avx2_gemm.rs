@@ -0,0 +1,472 @@
+use core::arch::x86_64::*;
+use core::mem::MaybeUninit;
+use core::slice;
+
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the matrix product `C = A·B` of the row-major `[f32; m * k]` matrix `a` and
+/// the row-major `[f32; k * n]` matrix `b`, writing the row-major `[f32; m * n]` result to `c`.
+///
+/// ```py
+/// m: int
+/// k: int
+/// n: int
+/// a: [f32; m * k]
+/// b: [f32; k * n]
+/// c: [f32; m * n]
+///
+/// for i in 0..m:
+///     for j in 0..n:
+///         c[i * n + j] = dot(a[i, :], b[:, j])
+/// ```
+///
+/// The output is computed in `8x8` tiles held entirely in registers, any rows
+/// which do not fill a complete tile are computed `1x8` at a time and any columns
+/// which do not fill a complete tile are handled by a scalar border.
+///
+/// This is designed for modest matrix sizes, larger matrices should use a dedicated
+/// BLAS library.
+///
+/// # Safety
+///
+/// `a`, `b` and `c` **MUST** be `m * k`, `k * n` and `m * n` in length respectively,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_gemm(
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+    m: usize,
+    k: usize,
+    n: usize,
+) {
+    debug_assert_eq!(a.len(), m * k);
+    debug_assert_eq!(b.len(), k * n);
+    debug_assert_eq!(c.len(), m * n);
+
+    gemm_to::<false, false>(a, b, c.as_mut_ptr(), m, k, n)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the matrix product `C = A·B` exactly like [f32_xany_avx2_nofma_gemm],
+/// writing the result to the uninitialized buffer `c` and returning it as initialized.
+///
+/// Every element of `c` is written without ever being read, so large output buffers
+/// do not need to be zeroed before use.
+///
+/// # Safety
+///
+/// `a`, `b` and `c` **MUST** be `m * k`, `k * n` and `m * n` in length respectively,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_gemm_uninit<'a>(
+    a: &[f32],
+    b: &[f32],
+    c: &'a mut [MaybeUninit<f32>],
+    m: usize,
+    k: usize,
+    n: usize,
+) -> &'a mut [f32] {
+    debug_assert_eq!(a.len(), m * k);
+    debug_assert_eq!(b.len(), k * n);
+    debug_assert_eq!(c.len(), m * n);
+
+    let c_ptr = c.as_mut_ptr().cast::<f32>();
+    gemm_to::<false, false>(a, b, c_ptr, m, k, n);
+
+    // SAFETY: Every element of `c` has been written above.
+    slice::from_raw_parts_mut(c_ptr, c.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the matrix product `C = A·B` exactly like [f32_xany_avx2_nofma_gemm],
+/// writing the tiles of the result with non-temporal stores.
+///
+/// Non-temporal stores bypass the cache, so writing an output far larger than the
+/// last level cache does not evict `a` and `b` or the working set of other threads.
+/// Outputs which are read again soon after should use [f32_xany_avx2_nofma_gemm]
+/// instead, as they will have to be fetched back from memory.
+///
+/// Streaming stores require 32 byte aligned addresses, so they are only used when
+/// `c` is 32 byte aligned and `n` is a multiple of `8`, keeping every row aligned.
+/// Otherwise this behaves exactly like [f32_xany_avx2_nofma_gemm]. A store fence is
+/// issued before returning so the output is visible to other threads.
+///
+/// # Safety
+///
+/// `a`, `b` and `c` **MUST** be `m * k`, `k * n` and `m * n` in length respectively,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_gemm_streaming(
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+    m: usize,
+    k: usize,
+    n: usize,
+) {
+    debug_assert_eq!(a.len(), m * k);
+    debug_assert_eq!(b.len(), k * n);
+    debug_assert_eq!(c.len(), m * n);
+
+    if (c.as_ptr() as usize).is_multiple_of(32) && n.is_multiple_of(8) {
+        gemm_to::<false, true>(a, b, c.as_mut_ptr(), m, k, n);
+        _mm_sfence();
+    } else {
+        gemm_to::<false, false>(a, b, c.as_mut_ptr(), m, k, n);
+    }
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the matrix product `C = A·B` of the row-major `[f32; m * k]` matrix `a` and
+/// the row-major `[f32; k * n]` matrix `b`, writing the row-major `[f32; m * n]` result to `c`.
+///
+/// ```py
+/// m: int
+/// k: int
+/// n: int
+/// a: [f32; m * k]
+/// b: [f32; k * n]
+/// c: [f32; m * n]
+///
+/// for i in 0..m:
+///     for j in 0..n:
+///         c[i * n + j] = dot(a[i, :], b[:, j])
+/// ```
+///
+/// The output is computed in `8x8` tiles held entirely in registers, any rows
+/// which do not fill a complete tile are computed `1x8` at a time and any columns
+/// which do not fill a complete tile are handled by a scalar border.
+///
+/// This is designed for modest matrix sizes, larger matrices should use a dedicated
+/// BLAS library.
+///
+/// # Safety
+///
+/// `a`, `b` and `c` **MUST** be `m * k`, `k * n` and `m * n` in length respectively,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_gemm(
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+    m: usize,
+    k: usize,
+    n: usize,
+) {
+    debug_assert_eq!(a.len(), m * k);
+    debug_assert_eq!(b.len(), k * n);
+    debug_assert_eq!(c.len(), m * n);
+
+    gemm_to::<true, false>(a, b, c.as_mut_ptr(), m, k, n)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the matrix product `C = A·B` exactly like [f32_xany_avx2_fma_gemm],
+/// writing the result to the uninitialized buffer `c` and returning it as initialized.
+///
+/// Every element of `c` is written without ever being read, so large output buffers
+/// do not need to be zeroed before use.
+///
+/// # Safety
+///
+/// `a`, `b` and `c` **MUST** be `m * k`, `k * n` and `m * n` in length respectively,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_gemm_uninit<'a>(
+    a: &[f32],
+    b: &[f32],
+    c: &'a mut [MaybeUninit<f32>],
+    m: usize,
+    k: usize,
+    n: usize,
+) -> &'a mut [f32] {
+    debug_assert_eq!(a.len(), m * k);
+    debug_assert_eq!(b.len(), k * n);
+    debug_assert_eq!(c.len(), m * n);
+
+    let c_ptr = c.as_mut_ptr().cast::<f32>();
+    gemm_to::<true, false>(a, b, c_ptr, m, k, n);
+
+    // SAFETY: Every element of `c` has been written above.
+    slice::from_raw_parts_mut(c_ptr, c.len())
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the matrix product `C = A·B` exactly like [f32_xany_avx2_fma_gemm],
+/// writing the tiles of the result with non-temporal stores.
+///
+/// Non-temporal stores bypass the cache, so writing an output far larger than the
+/// last level cache does not evict `a` and `b` or the working set of other threads.
+/// Outputs which are read again soon after should use [f32_xany_avx2_fma_gemm]
+/// instead, as they will have to be fetched back from memory.
+///
+/// Streaming stores require 32 byte aligned addresses, so they are only used when
+/// `c` is 32 byte aligned and `n` is a multiple of `8`, keeping every row aligned.
+/// Otherwise this behaves exactly like [f32_xany_avx2_fma_gemm]. A store fence is
+/// issued before returning so the output is visible to other threads.
+///
+/// # Safety
+///
+/// `a`, `b` and `c` **MUST** be `m * k`, `k * n` and `m * n` in length respectively,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_gemm_streaming(
+    a: &[f32],
+    b: &[f32],
+    c: &mut [f32],
+    m: usize,
+    k: usize,
+    n: usize,
+) {
+    debug_assert_eq!(a.len(), m * k);
+    debug_assert_eq!(b.len(), k * n);
+    debug_assert_eq!(c.len(), m * n);
+
+    if (c.as_ptr() as usize).is_multiple_of(32) && n.is_multiple_of(8) {
+        gemm_to::<true, true>(a, b, c.as_mut_ptr(), m, k, n);
+        _mm_sfence();
+    } else {
+        gemm_to::<true, false>(a, b, c.as_mut_ptr(), m, k, n);
+    }
+}
+
+#[inline(always)]
+/// Computes `C = A·B` writing every element of the output to `c_ptr` without reading it.
+///
+/// The regular stores fill the output a strip of `8` columns at a time, reusing the
+/// strip of `b` from cache. The streaming stores instead fill it row by row, as the
+/// write combining buffers only avoid reading the destination back from memory when
+/// whole cache lines are written in quick succession.
+unsafe fn gemm_to<const FMA: bool, const STREAM: bool>(
+    a: &[f32],
+    b: &[f32],
+    c_ptr: *mut f32,
+    m: usize,
+    k: usize,
+    n: usize,
+) {
+    let tiled_rows = m - (m % 8);
+    let tiled_cols = n - (n % 8);
+
+    let a_ptr = a.as_ptr();
+    let b_ptr = b.as_ptr();
+
+    if STREAM {
+        let mut i = 0;
+        while i < m {
+            let rows = if i < tiled_rows { 8 } else { 1 };
+
+            let mut j = 0;
+            while j < tiled_cols {
+                gemm_tile::<FMA, STREAM>(a_ptr, b_ptr, c_ptr, rows, k, n, i, j);
+                j += 8;
+            }
+
+            i += rows;
+        }
+    } else {
+        let mut j = 0;
+        while j < tiled_cols {
+            let mut i = 0;
+            while i < m {
+                let rows = if i < tiled_rows { 8 } else { 1 };
+                gemm_tile::<FMA, STREAM>(a_ptr, b_ptr, c_ptr, rows, k, n, i, j);
+                i += rows;
+            }
+
+            j += 8;
+        }
+    }
+
+    gemm_scalar_border(a, b, c_ptr, m, k, n, tiled_cols);
+}
+
+#[allow(clippy::too_many_arguments)]
+#[inline(always)]
+/// Computes the tile of `rows x 8` outputs starting at row `i` and column `j`, where
+/// `rows` is either `8` or `1`, holding the tile entirely in registers.
+unsafe fn gemm_tile<const FMA: bool, const STREAM: bool>(
+    a_ptr: *const f32,
+    b_ptr: *const f32,
+    c_ptr: *mut f32,
+    rows: usize,
+    k: usize,
+    n: usize,
+    i: usize,
+    j: usize,
+) {
+    if rows == 8 {
+        let mut acc = [_mm256_setzero_ps(); 8];
+
+        for p in 0..k {
+            let b = _mm256_loadu_ps(b_ptr.add(p * n + j));
+
+            for (r, acc) in acc.iter_mut().enumerate() {
+                let a = _mm256_set1_ps(*a_ptr.add((i + r) * k + p));
+                *acc = mul_add::<FMA>(a, b, *acc);
+            }
+        }
+
+        for (r, acc) in acc.into_iter().enumerate() {
+            store_ps::<STREAM>(c_ptr.add((i + r) * n + j), acc);
+        }
+    } else {
+        let mut acc = _mm256_setzero_ps();
+
+        for p in 0..k {
+            let a = _mm256_set1_ps(*a_ptr.add(i * k + p));
+            let b = _mm256_loadu_ps(b_ptr.add(p * n + j));
+            acc = mul_add::<FMA>(a, b, acc);
+        }
+
+        store_ps::<STREAM>(c_ptr.add(i * n + j), acc);
+    }
+}
+
+#[inline(always)]
+/// Computes `a * b + c`, fused if `FMA` is set.
+unsafe fn mul_add<const FMA: bool>(a: __m256, b: __m256, c: __m256) -> __m256 {
+    if FMA {
+        _mm256_fmadd_ps(a, b, c)
+    } else {
+        _mm256_add_ps(c, _mm256_mul_ps(a, b))
+    }
+}
+
+#[inline(always)]
+/// Stores a register with a non-temporal store if `STREAM` is set, which requires
+/// `ptr` to be 32 byte aligned.
+unsafe fn store_ps<const STREAM: bool>(ptr: *mut f32, x: __m256) {
+    if STREAM {
+        _mm256_stream_ps(ptr, x)
+    } else {
+        _mm256_storeu_ps(ptr, x)
+    }
+}
+
+#[inline(always)]
+/// Computes the columns `tiled_cols..n` of `C = A·B` which do not fill a complete tile.
+unsafe fn gemm_scalar_border(
+    a: &[f32],
+    b: &[f32],
+    c_ptr: *mut f32,
+    m: usize,
+    k: usize,
+    n: usize,
+    tiled_cols: usize,
+) {
+    for i in 0..m {
+        for j in tiled_cols..n {
+            let mut total = 0.0;
+            for p in 0..k {
+                let a = *a.get_unchecked(i * k + p);
+                let b = *b.get_unchecked(p * n + j);
+                total = AutoMath::add(total, AutoMath::mul(a, b));
+            }
+            c_ptr.add(i * n + j).write(total);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close_vector, get_sample_vectors, simple_gemm};
+
+    const SHAPES: [(usize, usize, usize); 5] = [
+        (8, 8, 8),
+        (16, 3, 24),
+        (13, 17, 11),
+        (67, 31, 45),
+        (1, 5, 1),
+    ];
+
+    #[test]
+    fn test_xany_nofma_gemm() {
+        for (m, k, n) in SHAPES {
+            let (a, _) = get_sample_vectors::<f32>(m * k);
+            let (_, b) = get_sample_vectors::<f32>(k * n);
+            let expected = simple_gemm(&a, &b, m, k, n);
+
+            let mut c = vec![0.0; m * n];
+            unsafe { f32_xany_avx2_nofma_gemm(&a, &b, &mut c, m, k, n) };
+            assert_is_close_vector(&c, &expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_gemm_uninit() {
+        for (m, k, n) in SHAPES {
+            let (a, _) = get_sample_vectors::<f32>(m * k);
+            let (_, b) = get_sample_vectors::<f32>(k * n);
+            let expected = simple_gemm(&a, &b, m, k, n);
+
+            let mut c = Vec::with_capacity(m * n);
+            let c = unsafe {
+                f32_xany_avx2_nofma_gemm_uninit(&a, &b, c.spare_capacity_mut(), m, k, n)
+            };
+            assert_is_close_vector(c, &expected);
+
+            let mut c = vec![MaybeUninit::uninit(); m * n];
+            let c = unsafe { f32_xany_avx2_fma_gemm_uninit(&a, &b, &mut c, m, k, n) };
+            assert_is_close_vector(c, &expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_gemm_streaming() {
+        for (m, k, n) in SHAPES {
+            let (a, _) = get_sample_vectors::<f32>(m * k);
+            let (_, b) = get_sample_vectors::<f32>(k * n);
+            let expected = simple_gemm(&a, &b, m, k, n);
+
+            let mut buffer = vec![0.0; m * n + 8];
+            let offset = buffer.as_ptr().align_offset(32);
+            let c = &mut buffer[offset..offset + m * n];
+            unsafe { f32_xany_avx2_nofma_gemm_streaming(&a, &b, c, m, k, n) };
+            assert_is_close_vector(c, &expected);
+
+            c.fill(0.0);
+            unsafe { f32_xany_avx2_fma_gemm_streaming(&a, &b, c, m, k, n) };
+            assert_is_close_vector(c, &expected);
+
+            // A misaligned output falls back to the regular stores.
+            let c = &mut buffer[offset + 1..offset + 1 + m * n];
+            unsafe { f32_xany_avx2_fma_gemm_streaming(&a, &b, c, m, k, n) };
+            assert_is_close_vector(c, &expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_fma_gemm() {
+        for (m, k, n) in SHAPES {
+            let (a, _) = get_sample_vectors::<f32>(m * k);
+            let (_, b) = get_sample_vectors::<f32>(k * n);
+            let expected = simple_gemm(&a, &b, m, k, n);
+
+            let mut c = vec![0.0; m * n];
+            unsafe { f32_xany_avx2_fma_gemm(&a, &b, &mut c, m, k, n) };
+            assert_is_close_vector(&c, &expected);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use core::arch::x86_64::*;
+use core::mem;
+
+use crate::danger::utils::{quantize_uniform_avx2_ps, quantize_uniform_f32};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Counts the elements of the vector falling into each of `bins` uniformly sized
+/// bins spanning `[lo, hi]`, adding the totals to `counts`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// counts: [u64; bins]
+///
+/// for i in 0..D:
+///     counts[quantize_uniform(bins, lo, hi, x[i])] += 1
+/// ```
+///
+/// Bins follow the same rules as `quantize_uniform`, values on a bin boundary belong
+/// to the bin above it, `hi` belongs to the last bin, out of range values are clamped
+/// into the first or last bin and `NaN` is counted in the first bin.
+///
+/// Bin indices are computed 8 at a time, each lane then increments its own partial
+/// histogram so repeated values do not serialize on the same counter, the partial
+/// histograms are merged into `counts` at the end.
+///
+/// # Safety
+///
+/// `counts` **MUST** be `bins` long, `bins` **MUST** be within `1..=2^24` and `lo`
+/// **MUST** be less than `hi`, otherwise this routine will become immediately UB due
+/// to out of bounds pointer accesses or produce meaningless counts.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_histogram(
+    bins: usize,
+    lo: f32,
+    hi: f32,
+    x: &[f32],
+    counts: &mut [u64],
+) {
+    debug_assert_eq!(counts.len(), bins);
+    debug_assert!(
+        (1..=1 << 24).contains(&bins),
+        "Bins must be within 1..=2^24"
+    );
+    debug_assert!(lo < hi, "The lower bound must be less than the upper bound");
+
+    let len = x.len();
+    let offset_from = len % 8;
+
+    let scale = bins as f32 / (hi - lo);
+    let max_bucket = (bins - 1) as f32;
+
+    let x_ptr = x.as_ptr();
+    let counts_ptr = counts.as_mut_ptr();
+
+    let mut i = 0;
+    if len >= 8 {
+        let lo_reg = _mm256_set1_ps(lo);
+        let scale_reg = _mm256_set1_ps(scale);
+        let max_bucket_reg = _mm256_set1_ps(max_bucket);
+
+        let mut partials = vec![0u64; bins * 8];
+        let partials_ptr = partials.as_mut_ptr();
+
+        while i < (len - offset_from) {
+            let x = _mm256_loadu_ps(x_ptr.add(i));
+            let buckets = quantize_uniform_avx2_ps(x, lo_reg, scale_reg, max_bucket_reg);
+            let buckets = mem::transmute::<__m256i, [u32; 8]>(buckets);
+
+            for (lane, bucket) in buckets.into_iter().enumerate() {
+                *partials_ptr.add(lane * bins + bucket as usize) += 1;
+            }
+
+            i += 8;
+        }
+
+        for lane in partials.chunks_exact(bins) {
+            for (bin, count) in lane.iter().enumerate() {
+                *counts_ptr.add(bin) += *count;
+            }
+        }
+    }
+
+    while i < len {
+        let bucket = quantize_uniform_f32(*x_ptr.add(i), lo, scale, max_bucket);
+        *counts_ptr.add(bucket as usize) += 1;
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::f32_xany_fallback_nofma_histogram;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_histogram() {
+        for len in [0, 3, 8, 33, 531] {
+            let (x, y) = get_sample_vectors::<f32>(len);
+            // Spread the values beyond the bin range on both sides.
+            let x = x
+                .iter()
+                .zip(y.iter())
+                .map(|(a, b)| (a - b) * 3.0)
+                .collect::<Vec<_>>();
+
+            let mut expected = vec![0; 7];
+            let mut counts = vec![0; 7];
+            unsafe {
+                f32_xany_fallback_nofma_histogram(7, -1.0, 1.5, &x, &mut expected);
+                f32_xany_avx2_nofma_histogram(7, -1.0, 1.5, &x, &mut counts);
+            }
+            assert_eq!(counts, expected);
+            assert_eq!(counts.iter().sum::<u64>(), len as u64);
+        }
+    }
+
+    #[test]
+    fn test_xany_histogram_accumulates() {
+        let x = [0.1, 0.1, 0.1, 0.6, 0.6, 0.1, 0.1, 0.1, f32::NAN, 2.0];
+        let mut counts = [1, 1];
+        unsafe { f32_xany_avx2_nofma_histogram(2, 0.0, 1.0, &x, &mut counts) };
+        assert_eq!(counts, [8, 4]);
+    }
+}
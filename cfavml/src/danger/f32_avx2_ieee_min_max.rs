@@ -0,0 +1,206 @@
+use core::arch::x86_64::*;
+
+use crate::danger::{f32_ieee_maximum, f32_ieee_minimum};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sets each element of the mutable vector `x` to the IEEE 754-2019 `maximum` of
+/// itself and the matching element of `y`.
+///
+/// Unlike the other `max` routines, which follow the semantics of `vmaxps` and return
+/// the second operand when either input is `NaN` or both are zero, this routine
+/// propagates `NaN` and orders `-0.0` below `+0.0`. This is slightly slower, so
+/// it should only be used when standards compliant behaviour is required.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     if is_nan(x[i]) or is_nan(y[i]):
+///         x[i] = NaN
+///     elif x[i] == y[i]:
+///         x[i] = +0.0 if is_positive(x[i]) or is_positive(y[i]) else x[i]
+///     else:
+///         x[i] = x[i] if x[i] > y[i] else y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_maximum_vertical(x: &mut [f32], y: &[f32]) {
+    execute_ieee_op::<true>(x, y)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sets each element of the mutable vector `x` to the IEEE 754-2019 `minimum` of
+/// itself and the matching element of `y`.
+///
+/// Unlike the other `min` routines, which follow the semantics of `vminps` and return
+/// the second operand when either input is `NaN` or both are zero, this routine
+/// propagates `NaN` and orders `-0.0` below `+0.0`. This is slightly slower, so
+/// it should only be used when standards compliant behaviour is required.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     if is_nan(x[i]) or is_nan(y[i]):
+///         x[i] = NaN
+///     elif x[i] == y[i]:
+///         x[i] = -0.0 if is_negative(x[i]) or is_negative(y[i]) else x[i]
+///     else:
+///         x[i] = x[i] if x[i] < y[i] else y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_minimum_vertical(x: &mut [f32], y: &[f32]) {
+    execute_ieee_op::<false>(x, y)
+}
+
+#[inline(always)]
+unsafe fn execute_ieee_op<const MAX: bool>(x: &mut [f32], y: &[f32]) {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_mut_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let x3 = _mm256_loadu_ps(x_ptr.add(i + 16));
+        let x4 = _mm256_loadu_ps(x_ptr.add(i + 24));
+
+        let y1 = _mm256_loadu_ps(y_ptr.add(i));
+        let y2 = _mm256_loadu_ps(y_ptr.add(i + 8));
+        let y3 = _mm256_loadu_ps(y_ptr.add(i + 16));
+        let y4 = _mm256_loadu_ps(y_ptr.add(i + 24));
+
+        _mm256_storeu_ps(x_ptr.add(i), ieee_op::<MAX>(x1, y1));
+        _mm256_storeu_ps(x_ptr.add(i + 8), ieee_op::<MAX>(x2, y2));
+        _mm256_storeu_ps(x_ptr.add(i + 16), ieee_op::<MAX>(x3, y3));
+        _mm256_storeu_ps(x_ptr.add(i + 24), ieee_op::<MAX>(x4, y4));
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+        let y = _mm256_loadu_ps(y_ptr.add(i));
+        _mm256_storeu_ps(x_ptr.add(i), ieee_op::<MAX>(x, y));
+
+        i += 8;
+    }
+
+    while i < len {
+        let x = x.get_unchecked_mut(i);
+        let y = *y.get_unchecked(i);
+        *x = if MAX {
+            f32_ieee_maximum(*x, y)
+        } else {
+            f32_ieee_minimum(*x, y)
+        };
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+unsafe fn ieee_op<const MAX: bool>(x: __m256, y: __m256) -> __m256 {
+    // Equal values can only differ by their sign when they are both zero, in which
+    // case ANDing the bits keeps `+0.0` and ORing them keeps `-0.0`.
+    let (r, equal_r) = if MAX {
+        (_mm256_max_ps(x, y), _mm256_and_ps(x, y))
+    } else {
+        (_mm256_min_ps(x, y), _mm256_or_ps(x, y))
+    };
+
+    let is_equal = _mm256_cmp_ps::<_CMP_EQ_OQ>(x, y);
+    let r = _mm256_blendv_ps(r, equal_r, is_equal);
+
+    // Adding the inputs produces a quiet NaN if either of them is NaN.
+    let is_unordered = _mm256_cmp_ps::<_CMP_UNORD_Q>(x, y);
+    _mm256_blendv_ps(r, _mm256_add_ps(x, y), is_unordered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    fn special_vectors() -> (Vec<f32>, Vec<f32>) {
+        let specials = [
+            (f32::NAN, 1.0),
+            (1.0, f32::NAN),
+            (-0.0, 0.0),
+            (0.0, -0.0),
+            (-0.0, -0.0),
+            (f32::INFINITY, f32::NEG_INFINITY),
+            (-1.0, 2.0),
+            (3.0, 3.0),
+        ];
+
+        let (mut x, mut y) = get_sample_vectors::<f32>(53);
+        for (i, (a, b)) in specials.iter().cycle().take(53).enumerate() {
+            if i % 2 == 0 {
+                x[i] = *a;
+                y[i] = *b;
+            }
+        }
+
+        (x, y)
+    }
+
+    fn assert_bitwise_eq(x: &[f32], expected: &[f32]) {
+        for (a, b) in x.iter().zip(expected) {
+            assert!(
+                a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan()),
+                "{a} != {b}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_xany_maximum_vertical() {
+        let (mut x, y) = special_vectors();
+        let expected = x
+            .iter()
+            .zip(y.iter())
+            .map(|(a, b)| f32_ieee_maximum(*a, *b))
+            .collect::<Vec<_>>();
+
+        unsafe { f32_xany_avx2_nofma_maximum_vertical(&mut x, &y) };
+        assert_bitwise_eq(&x, &expected);
+    }
+
+    #[test]
+    fn test_xany_minimum_vertical() {
+        let (mut x, y) = special_vectors();
+        let expected = x
+            .iter()
+            .zip(y.iter())
+            .map(|(a, b)| f32_ieee_minimum(*a, *b))
+            .collect::<Vec<_>>();
+
+        unsafe { f32_xany_avx2_nofma_minimum_vertical(&mut x, &y) };
+        assert_bitwise_eq(&x, &expected);
+    }
+}
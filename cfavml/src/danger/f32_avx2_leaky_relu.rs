@@ -0,0 +1,128 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Applies the leaky ReLU activation to the vector, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = x[i] if x[i] > 0 else slope * x[i]
+/// ```
+///
+/// A `slope` of `0.0` gives the plain ReLU, `NaN` inputs are propagated.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_leaky_relu(slope: f32, x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    f32_avx2_leaky_relu_to(slope, x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Applies the leaky ReLU activation to the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     if x[i] <= 0:
+///         x[i] = slope * x[i]
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_leaky_relu_inplace(slope: f32, x: &mut [f32]) {
+    let x_ptr = x.as_mut_ptr();
+    f32_avx2_leaky_relu_to(slope, x_ptr, x_ptr, x.len())
+}
+
+#[inline(always)]
+/// Applies the leaky ReLU to the values read from `x_ptr` writing the output to the
+/// `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn f32_avx2_leaky_relu_to(
+    slope: f32,
+    x_ptr: *const f32,
+    result: *mut f32,
+    len: usize,
+) {
+    let offset_from = len % 16;
+
+    let slope_reg = _mm256_set1_ps(slope);
+    let zero = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let v1 = _mm256_loadu_ps(x_ptr.add(i));
+        let v2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+
+        let positive1 = _mm256_cmp_ps::<_CMP_GT_OQ>(v1, zero);
+        let positive2 = _mm256_cmp_ps::<_CMP_GT_OQ>(v2, zero);
+        let scaled1 = _mm256_mul_ps(v1, slope_reg);
+        let scaled2 = _mm256_mul_ps(v2, slope_reg);
+
+        _mm256_storeu_ps(result.add(i), _mm256_blendv_ps(scaled1, v1, positive1));
+        _mm256_storeu_ps(result.add(i + 8), _mm256_blendv_ps(scaled2, v2, positive2));
+
+        i += 16;
+    }
+
+    while i < len {
+        let v = *x_ptr.add(i);
+        *result.add(i) = if v > 0.0 { v } else { slope * v };
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_leaky_relu() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let mut x = x.iter().zip(y).map(|(a, b)| a - b).collect::<Vec<_>>();
+        x[3] = f32::NAN;
+        x[4] = -0.0;
+
+        let expected = x
+            .iter()
+            .map(|v| if *v > 0.0 { *v } else { 0.01 * v })
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_nofma_leaky_relu(0.01, &x, &mut result) };
+        assert!(result[3].is_nan());
+        assert_eq!(result[4].to_bits(), (-0.0f32).to_bits());
+        result[3] = 0.0;
+        assert_eq!(&result[..3], &expected[..3]);
+        assert_eq!(&result[4..], &expected[4..]);
+
+        unsafe { f32_xany_avx2_nofma_leaky_relu_inplace(0.01, &mut x) };
+        assert!(x[3].is_nan());
+        assert_eq!(&x[4..], &expected[4..]);
+    }
+}
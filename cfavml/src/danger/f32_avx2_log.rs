@@ -0,0 +1,318 @@
+use core::arch::x86_64::*;
+use core::ptr;
+
+use crate::danger::{log_avx2_ps, partially_overlaps};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the natural logarithm of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = ln(x[i])
+/// ```
+///
+/// With `precise` the relative error is within a few ULP, otherwise a cheaper
+/// approximation is used with a relative error of roughly `2e-5`. `0.0` produces
+/// `-inf`, `inf` produces `inf` and negative or `NaN` inputs produce `NaN`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_ln(precise: bool, x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let (x_ptr, result_ptr) = (x.as_ptr(), result.as_mut_ptr());
+    if precise {
+        log_to::<true>(x_ptr, result_ptr, x.len(), 1.0)
+    } else {
+        log_to::<false>(x_ptr, result_ptr, x.len(), 1.0)
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the natural logarithm of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = ln(x[i])
+/// ```
+///
+/// See [f32_xany_avx2_nofma_ln] for the accuracy with and without `precise`.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_ln_inplace(precise: bool, x: &mut [f32]) {
+    let x_ptr = x.as_mut_ptr();
+    if precise {
+        log_to::<true>(x_ptr, x_ptr, x.len(), 1.0)
+    } else {
+        log_to::<false>(x_ptr, x_ptr, x.len(), 1.0)
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the base 2 logarithm of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = log2(x[i])
+/// ```
+///
+/// This is computed as `ln(x) * log2(e)`, see [f32_xany_avx2_nofma_ln] for the accuracy
+/// with and without `precise` and the handling of special values.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_log2(precise: bool, x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let (x_ptr, result_ptr) = (x.as_ptr(), result.as_mut_ptr());
+    if precise {
+        log_to::<true>(x_ptr, result_ptr, x.len(), core::f32::consts::LOG2_E)
+    } else {
+        log_to::<false>(x_ptr, result_ptr, x.len(), core::f32::consts::LOG2_E)
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the base 2 logarithm of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = log2(x[i])
+/// ```
+///
+/// See [f32_xany_avx2_nofma_log2] for the accuracy with and without `precise`.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_log2_inplace(precise: bool, x: &mut [f32]) {
+    let x_ptr = x.as_mut_ptr();
+    if precise {
+        log_to::<true>(x_ptr, x_ptr, x.len(), core::f32::consts::LOG2_E)
+    } else {
+        log_to::<false>(x_ptr, x_ptr, x.len(), core::f32::consts::LOG2_E)
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the base 10 logarithm of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = log10(x[i])
+/// ```
+///
+/// This is computed as `ln(x) * log10(e)`, see [f32_xany_avx2_nofma_ln] for the accuracy
+/// with and without `precise` and the handling of special values.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_log10(precise: bool, x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let (x_ptr, result_ptr) = (x.as_ptr(), result.as_mut_ptr());
+    if precise {
+        log_to::<true>(x_ptr, result_ptr, x.len(), core::f32::consts::LOG10_E)
+    } else {
+        log_to::<false>(x_ptr, result_ptr, x.len(), core::f32::consts::LOG10_E)
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the base 10 logarithm of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = log10(x[i])
+/// ```
+///
+/// See [f32_xany_avx2_nofma_log10] for the accuracy with and without `precise`.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_log10_inplace(precise: bool, x: &mut [f32]) {
+    let x_ptr = x.as_mut_ptr();
+    if precise {
+        log_to::<true>(x_ptr, x_ptr, x.len(), core::f32::consts::LOG10_E)
+    } else {
+        log_to::<false>(x_ptr, x_ptr, x.len(), core::f32::consts::LOG10_E)
+    }
+}
+
+#[inline(always)]
+/// Computes `ln(x) * scale` of the values read from `x_ptr` writing the output to the
+/// `result` pointer, the tail is padded out to a full register so every element goes
+/// through the same instructions.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn log_to<const PRECISE: bool>(
+    x_ptr: *const f32,
+    result: *mut f32,
+    len: usize,
+    scale: f32,
+) {
+    let scale = _mm256_set1_ps(scale);
+    let offset_from = len % 16;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+
+        _mm256_storeu_ps(result.add(i), _mm256_mul_ps(ln_ps::<PRECISE>(x1), scale));
+        _mm256_storeu_ps(
+            result.add(i + 8),
+            _mm256_mul_ps(ln_ps::<PRECISE>(x2), scale),
+        );
+
+        i += 16;
+    }
+
+    while i < len {
+        let n = (len - i).min(8);
+        let mut tail = [1.0f32; 8];
+        ptr::copy_nonoverlapping(x_ptr.add(i), tail.as_mut_ptr(), n);
+
+        let v = _mm256_mul_ps(ln_ps::<PRECISE>(_mm256_loadu_ps(tail.as_ptr())), scale);
+        _mm256_storeu_ps(tail.as_mut_ptr(), v);
+        ptr::copy_nonoverlapping(tail.as_ptr(), result.add(i), n);
+
+        i += n;
+    }
+}
+
+#[inline(always)]
+/// Computes `ln(x)` with [log_avx2_ps], scaling subnormal inputs into its range and
+/// replacing the results of `0.0`, `inf`, negative and `NaN` inputs.
+unsafe fn ln_ps<const PRECISE: bool>(x: __m256) -> __m256 {
+    let subnormal = _mm256_cmp_ps::<_CMP_LT_OQ>(x, _mm256_set1_ps(f32::MIN_POSITIVE));
+    let scaled = _mm256_mul_ps(x, _mm256_set1_ps(8_388_608.0));
+    let y = log_avx2_ps::<PRECISE>(_mm256_blendv_ps(x, scaled, subnormal));
+    let correction = _mm256_set1_ps(23.0 * core::f32::consts::LN_2);
+    let y = _mm256_sub_ps(y, _mm256_and_ps(subnormal, correction));
+
+    let is_inf = _mm256_cmp_ps::<_CMP_EQ_OQ>(x, _mm256_set1_ps(f32::INFINITY));
+    let is_zero = _mm256_cmp_ps::<_CMP_EQ_OQ>(x, _mm256_setzero_ps());
+    let is_nan = _mm256_cmp_ps::<_CMP_NGE_UQ>(x, _mm256_setzero_ps());
+
+    let y = _mm256_blendv_ps(y, _mm256_set1_ps(f32::INFINITY), is_inf);
+    let y = _mm256_blendv_ps(y, _mm256_set1_ps(f32::NEG_INFINITY), is_zero);
+    _mm256_blendv_ps(y, _mm256_set1_ps(f32::NAN), is_nan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    fn assert_relative_error(result: &[f32], expected: &[f32], tolerance: f32) {
+        for (r, e) in result.iter().zip(expected) {
+            assert!((r - e).abs() <= tolerance * e.abs(), "{r} vs {e}");
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_log() {
+        let (x, _) = get_sample_vectors::<f32>(531);
+        let x = x
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v + 1e-3) * 2.0f32.powi(i as i32 % 41 - 20))
+            .collect::<Vec<_>>();
+
+        let mut result = vec![0.0; x.len()];
+        for (precise, tolerance) in [(true, 1e-6), (false, 3e-5)] {
+            unsafe { f32_xany_avx2_nofma_ln(precise, &x, &mut result) };
+            let expected = x.iter().map(|v| v.ln()).collect::<Vec<_>>();
+            assert_relative_error(&result, &expected, tolerance);
+
+            unsafe { f32_xany_avx2_nofma_log2(precise, &x, &mut result) };
+            let expected = x.iter().map(|v| v.log2()).collect::<Vec<_>>();
+            assert_relative_error(&result, &expected, tolerance);
+
+            unsafe { f32_xany_avx2_nofma_log10(precise, &x, &mut result) };
+            let expected = x.iter().map(|v| v.log10()).collect::<Vec<_>>();
+            assert_relative_error(&result, &expected, tolerance);
+        }
+
+        let mut special = vec![
+            1.0,
+            0.0,
+            -0.0,
+            f32::INFINITY,
+            f32::MIN_POSITIVE / 4.0,
+            -1.0,
+            f32::NAN,
+        ];
+        unsafe { f32_xany_avx2_nofma_ln_inplace(true, &mut special) };
+        assert_eq!(
+            &special[..4],
+            [0.0, f32::NEG_INFINITY, f32::NEG_INFINITY, f32::INFINITY]
+        );
+        assert_relative_error(&special[4..5], &[(f32::MIN_POSITIVE / 4.0).ln()], 1e-6);
+        assert!(special[5].is_nan() && special[6].is_nan());
+    }
+}
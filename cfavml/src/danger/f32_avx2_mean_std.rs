@@ -0,0 +1,338 @@
+use core::arch::x86_64::*;
+
+use crate::danger::{copy_avx2_ps_register_to, mean_std_from_sums_f32};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the mean and population standard deviation of every column of the matrix,
+/// returning `(mean, std)`.
+///
+/// ```py
+/// D: int
+/// N: int
+/// matrix: [[f32; D]; N]
+/// mean: [f32; D]
+/// std: [f32; D]
+///
+/// for j in 0..D:
+///     mean[j] = sum(matrix[i, j] for i in 0..N) / N
+///     std[j] = sqrt(sum((matrix[i, j] - mean[j]) ** 2 for i in 0..N) / N)
+/// ```
+///
+/// This is the per-feature statistic required for z-score normalization of a dataset.
+/// The deviations are accumulated in a second pass over the rows rather than derived
+/// from `E[x²] - E[x]²`, so features with a large mean relative to their spread do not
+/// suffer from catastrophic cancellation. A matrix with a single row always produces a
+/// standard deviation of exactly `0.0`.
+///
+/// # Safety
+///
+/// The matrix **MUST** contain at least one row and all vectors within the matrix
+/// **MUST** be the same length.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_mean_std_vertical(
+    matrix: &[&[f32]],
+) -> (Vec<f32>, Vec<f32>) {
+    mean_spread_vertical::<true>(matrix)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the mean and population variance of every column of the matrix,
+/// returning `(mean, variance)`.
+///
+/// ```py
+/// D: int
+/// N: int
+/// matrix: [[f32; D]; N]
+/// mean: [f32; D]
+/// variance: [f32; D]
+///
+/// for j in 0..D:
+///     mean[j] = sum(matrix[i, j] for i in 0..N) / N
+///     variance[j] = sum((matrix[i, j] - mean[j]) ** 2 for i in 0..N) / N
+/// ```
+///
+/// This is the two-pass form shared with [f32_xany_avx2_nofma_mean_std_vertical], the
+/// column means are computed first and the squared deviations from them are summed in
+/// a second pass over the rows. It reads the matrix twice but stays accurate for
+/// features whose spread is tiny relative to their mean, where the single-pass
+/// [f32_xany_avx2_nofma_mean_std_vertical_fast] cancels to noise.
+///
+/// # Safety
+///
+/// The matrix **MUST** contain at least one row and all vectors within the matrix
+/// **MUST** be the same length.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_variance_vertical(
+    matrix: &[&[f32]],
+) -> (Vec<f32>, Vec<f32>) {
+    mean_spread_vertical::<false>(matrix)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the mean and population standard deviation of every column of the matrix
+/// in a single pass, returning `(mean, std)`.
+///
+/// ```py
+/// D: int
+/// N: int
+/// matrix: [[f32; D]; N]
+/// mean: [f32; D]
+/// std: [f32; D]
+///
+/// for j in 0..D:
+///     mean[j] = sum(matrix[i, j] for i in 0..N) / N
+///     std[j] = sqrt(sum(matrix[i, j] ** 2 for i in 0..N) / N - mean[j] ** 2)
+/// ```
+///
+/// The sum and sum of squares are accumulated together so the matrix is only read
+/// once, which is the better choice when the matrix is too large to stay in cache.
+/// The `E[x²] - E[x]²` form loses precision as the mean grows relative to the spread,
+/// variances within its rounding error are flushed to `0.0`. Prefer the two-pass
+/// [f32_xany_avx2_nofma_mean_std_vertical] or [f32_xany_avx2_nofma_variance_vertical]
+/// for normalizing features with a small variance.
+///
+/// # Safety
+///
+/// All vectors within the matrix **MUST** be the same length.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_mean_std_vertical_fast(
+    matrix: &[&[f32]],
+) -> (Vec<f32>, Vec<f32>) {
+    let len = matrix.first().map_or(0, |row| row.len());
+    let offset_from = len % 32;
+
+    let mut means = vec![0.0; len];
+    let mut stds = vec![0.0; len];
+    let means_ptr = means.as_mut_ptr();
+    let stds_ptr = stds.as_mut_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let (sum, sum_sq) = sum_sumsq_block::<4>(matrix, i);
+
+        for k in 0..4 {
+            copy_avx2_ps_register_to(means_ptr.add(i + k * 8), sum[k]);
+            copy_avx2_ps_register_to(stds_ptr.add(i + k * 8), sum_sq[k]);
+        }
+
+        i += 32;
+    }
+
+    while i < (len - len % 8) {
+        let (sum, sum_sq) = sum_sumsq_block::<1>(matrix, i);
+        copy_avx2_ps_register_to(means_ptr.add(i), sum[0]);
+        copy_avx2_ps_register_to(stds_ptr.add(i), sum_sq[0]);
+
+        i += 8;
+    }
+
+    while i < len {
+        for m in 0..matrix.len() {
+            let value = *matrix.get_unchecked(m).get_unchecked(i);
+            *means_ptr.add(i) += value;
+            *stds_ptr.add(i) += value * value;
+        }
+
+        i += 1;
+    }
+
+    for (mean, std) in means.iter_mut().zip(stds.iter_mut()) {
+        (*mean, *std) = mean_std_from_sums_f32(*mean, *std, matrix.len());
+    }
+
+    (means, stds)
+}
+
+#[inline(always)]
+/// Computes the mean of every column along with either its standard deviation or,
+/// when `STD` is `false`, its variance.
+unsafe fn mean_spread_vertical<const STD: bool>(
+    matrix: &[&[f32]],
+) -> (Vec<f32>, Vec<f32>) {
+    let len = matrix[0].len();
+    let offset_from = len % 32;
+
+    let mut means = vec![0.0; len];
+    let mut stds = vec![0.0; len];
+    let means_ptr = means.as_mut_ptr();
+    let stds_ptr = stds.as_mut_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let (mean, std) = mean_std_block::<4, STD>(matrix, i);
+
+        for k in 0..4 {
+            copy_avx2_ps_register_to(means_ptr.add(i + k * 8), mean[k]);
+            copy_avx2_ps_register_to(stds_ptr.add(i + k * 8), std[k]);
+        }
+
+        i += 32;
+    }
+
+    while i < (len - len % 8) {
+        let (mean, std) = mean_std_block::<1, STD>(matrix, i);
+        copy_avx2_ps_register_to(means_ptr.add(i), mean[0]);
+        copy_avx2_ps_register_to(stds_ptr.add(i), std[0]);
+
+        i += 8;
+    }
+
+    let n = matrix.len() as f32;
+    while i < len {
+        let mut sum = 0.0;
+        for m in 0..matrix.len() {
+            sum += *matrix.get_unchecked(m).get_unchecked(i);
+        }
+        let mean = sum / n;
+
+        let mut sum_sq = 0.0;
+        for m in 0..matrix.len() {
+            let diff = *matrix.get_unchecked(m).get_unchecked(i) - mean;
+            sum_sq += diff * diff;
+        }
+
+        let variance = sum_sq / n;
+        *means_ptr.add(i) = mean;
+        *stds_ptr.add(i) = if STD { variance.sqrt() } else { variance };
+
+        i += 1;
+    }
+
+    (means, stds)
+}
+
+#[inline(always)]
+/// Computes the mean and standard deviation, or variance when `STD` is `false`, of
+/// the `REGS * 8` columns starting at column `offset`.
+unsafe fn mean_std_block<const REGS: usize, const STD: bool>(
+    matrix: &[&[f32]],
+    offset: usize,
+) -> ([__m256; REGS], [__m256; REGS]) {
+    let len = matrix[0].len();
+    let n = _mm256_set1_ps(matrix.len() as f32);
+
+    let mut mean = [_mm256_setzero_ps(); REGS];
+    for m in 0..matrix.len() {
+        let arr = *matrix.get_unchecked(m);
+        debug_assert_eq!(arr.len(), len);
+        let arr = arr.as_ptr().add(offset);
+
+        for (k, acc) in mean.iter_mut().enumerate() {
+            *acc = _mm256_add_ps(*acc, _mm256_loadu_ps(arr.add(k * 8)));
+        }
+    }
+
+    for acc in mean.iter_mut() {
+        *acc = _mm256_div_ps(*acc, n);
+    }
+
+    let mut std = [_mm256_setzero_ps(); REGS];
+    for m in 0..matrix.len() {
+        let arr = matrix.get_unchecked(m).as_ptr().add(offset);
+
+        for k in 0..REGS {
+            let diff = _mm256_sub_ps(_mm256_loadu_ps(arr.add(k * 8)), mean[k]);
+            std[k] = _mm256_add_ps(std[k], _mm256_mul_ps(diff, diff));
+        }
+    }
+
+    for acc in std.iter_mut() {
+        *acc = _mm256_div_ps(*acc, n);
+        if STD {
+            *acc = _mm256_sqrt_ps(*acc);
+        }
+    }
+
+    (mean, std)
+}
+
+#[inline(always)]
+/// Computes the sum and sum of squares of the `REGS * 8` columns starting at column
+/// `offset`.
+unsafe fn sum_sumsq_block<const REGS: usize>(
+    matrix: &[&[f32]],
+    offset: usize,
+) -> ([__m256; REGS], [__m256; REGS]) {
+    let mut sum = [_mm256_setzero_ps(); REGS];
+    let mut sum_sq = [_mm256_setzero_ps(); REGS];
+    for m in 0..matrix.len() {
+        let arr = matrix.get_unchecked(m).as_ptr().add(offset);
+
+        for k in 0..REGS {
+            let x = _mm256_loadu_ps(arr.add(k * 8));
+            sum[k] = _mm256_add_ps(sum[k], x);
+            sum_sq[k] = _mm256_add_ps(sum_sq[k], _mm256_mul_ps(x, x));
+        }
+    }
+
+    (sum, sum_sq)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::{
+        f32_xany_fallback_nofma_mean_std_vertical,
+        f32_xany_fallback_nofma_mean_std_vertical_fast,
+        f32_xany_fallback_nofma_variance_vertical,
+    };
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    #[test]
+    fn test_xany_nofma_mean_std_vertical() {
+        for dims in [3, 8, 32, 67, 531] {
+            let rows = (0..17)
+                .map(|_| get_sample_vectors::<f32>(dims).0)
+                .collect::<Vec<_>>();
+            let matrix = rows.iter().map(|row| row.as_slice()).collect::<Vec<_>>();
+
+            let (mean, std) = unsafe { f32_xany_avx2_nofma_mean_std_vertical(&matrix) };
+            let (expected_mean, expected_std) =
+                unsafe { f32_xany_fallback_nofma_mean_std_vertical(&matrix) };
+
+            for j in 0..dims {
+                assert_is_close(mean[j], expected_mean[j]);
+                assert_is_close(std[j], expected_std[j]);
+            }
+
+            let (mean, variance) =
+                unsafe { f32_xany_avx2_nofma_variance_vertical(&matrix) };
+            let (expected_mean, expected_variance) =
+                unsafe { f32_xany_fallback_nofma_variance_vertical(&matrix) };
+
+            for j in 0..dims {
+                assert_is_close(mean[j], expected_mean[j]);
+                assert_is_close(variance[j], expected_variance[j]);
+                assert_is_close(variance[j], std[j] * std[j]);
+            }
+
+            let (mean, std) =
+                unsafe { f32_xany_avx2_nofma_mean_std_vertical_fast(&matrix) };
+            let (expected_mean, expected_std) =
+                unsafe { f32_xany_fallback_nofma_mean_std_vertical_fast(&matrix) };
+
+            for j in 0..dims {
+                assert_is_close(mean[j], expected_mean[j]);
+                assert_is_close(std[j], expected_std[j]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_mean_std_vertical_single_row() {
+        let (x, _) = get_sample_vectors::<f32>(67);
+
+        let (mean, std) = unsafe { f32_xany_avx2_nofma_mean_std_vertical(&[&x]) };
+        assert_eq!(mean, x);
+        assert!(std.iter().all(|v| *v == 0.0));
+    }
+}
@@ -0,0 +1,232 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+const MIN3: u8 = 0;
+const MAX3: u8 = 1;
+const MEDIAN3: u8 = 2;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the element-wise minimum of `a`, `b` and `c`, writing the
+/// result to `result`.
+///
+/// ```py
+/// D: int
+/// a: [f32; D]
+/// b: [f32; D]
+/// c: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = min(a[i], b[i], c[i])
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_min3(
+    a: &[f32],
+    b: &[f32],
+    c: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+    debug_assert_eq!(a.len(), result.len());
+    debug_assert!(
+        ![a, b, c].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    select3_to::<MIN3>(a, b, c, result)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the element-wise maximum of `a`, `b` and `c`, writing the
+/// result to `result`.
+///
+/// ```py
+/// D: int
+/// a: [f32; D]
+/// b: [f32; D]
+/// c: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = max(a[i], b[i], c[i])
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_max3(
+    a: &[f32],
+    b: &[f32],
+    c: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+    debug_assert_eq!(a.len(), result.len());
+    debug_assert!(
+        ![a, b, c].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    select3_to::<MAX3>(a, b, c, result)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the element-wise median of `a`, `b` and `c`, writing the
+/// result to `result`.
+///
+/// ```py
+/// D: int
+/// a: [f32; D]
+/// b: [f32; D]
+/// c: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = max(min(a[i], b[i]), min(max(a[i], b[i]), c[i]))
+/// ```
+///
+/// The median of three is the building block of median filters, i.e. denoising a
+/// signal with a window of three samples.
+///
+/// `NaN` elements follow the `min`/`max` routines rather than being ordered, so the
+/// result for an element where any input is `NaN` is not meaningful.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_median3(
+    a: &[f32],
+    b: &[f32],
+    c: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+    debug_assert_eq!(a.len(), result.len());
+    debug_assert!(
+        ![a, b, c].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    select3_to::<MEDIAN3>(a, b, c, result)
+}
+
+#[inline(always)]
+unsafe fn select3_ps<const OP: u8>(a: __m256, b: __m256, c: __m256) -> __m256 {
+    match OP {
+        MIN3 => _mm256_min_ps(_mm256_min_ps(a, b), c),
+        MAX3 => _mm256_max_ps(_mm256_max_ps(a, b), c),
+        _ => _mm256_max_ps(_mm256_min_ps(a, b), _mm256_min_ps(_mm256_max_ps(a, b), c)),
+    }
+}
+
+#[inline(always)]
+/// Applies the three input `OP` element wise to `a`, `b` and `c`, writing the output
+/// to `result`.
+unsafe fn select3_to<const OP: u8>(a: &[f32], b: &[f32], c: &[f32], result: &mut [f32]) {
+    let len = a.len();
+    let offset_from = len % 32;
+    let a_ptr = a.as_ptr();
+    let b_ptr = b.as_ptr();
+    let c_ptr = c.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for j in (0..32).step_by(8) {
+            let a = _mm256_loadu_ps(a_ptr.add(i + j));
+            let b = _mm256_loadu_ps(b_ptr.add(i + j));
+            let c = _mm256_loadu_ps(c_ptr.add(i + j));
+            _mm256_storeu_ps(result_ptr.add(i + j), select3_ps::<OP>(a, b, c));
+        }
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let a = _mm256_loadu_ps(a_ptr.add(i));
+        let b = _mm256_loadu_ps(b_ptr.add(i));
+        let c = _mm256_loadu_ps(c_ptr.add(i));
+        _mm256_storeu_ps(result_ptr.add(i), select3_ps::<OP>(a, b, c));
+
+        i += 8;
+    }
+
+    while i < len {
+        let a = *a_ptr.add(i);
+        let b = *b_ptr.add(i);
+        let c = *c_ptr.add(i);
+        *result_ptr.add(i) = match OP {
+            MIN3 => AutoMath::cmp_min(AutoMath::cmp_min(a, b), c),
+            MAX3 => AutoMath::cmp_max(AutoMath::cmp_max(a, b), c),
+            _ => median3(a, b, c),
+        };
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+fn median3(a: f32, b: f32, c: f32) -> f32 {
+    let lo = AutoMath::cmp_min(a, b);
+    let hi = AutoMath::cmp_max(a, b);
+    AutoMath::cmp_max(lo, AutoMath::cmp_min(hi, c))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    fn sorted3(a: &[f32], b: &[f32], c: &[f32]) -> Vec<[f32; 3]> {
+        (0..a.len())
+            .map(|i| {
+                let mut v = [a[i], b[i], c[i]];
+                v.sort_by(|x, y| x.partial_cmp(y).unwrap());
+                v
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_xany_nofma_min3_max3_median3() {
+        let (x, y) = get_sample_vectors::<f32>(262);
+        let (a, b, c) = (&x[..131], &x[131..], &y[..131]);
+        let sorted = sorted3(a, b, c);
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_nofma_min3(a, b, c, &mut result) };
+        assert_eq!(result, sorted.iter().map(|v| v[0]).collect::<Vec<f32>>());
+
+        unsafe { f32_xany_avx2_nofma_max3(a, b, c, &mut result) };
+        assert_eq!(result, sorted.iter().map(|v| v[2]).collect::<Vec<f32>>());
+
+        // Every ordering of the three inputs must select the same median.
+        for (x, y, z) in [(a, b, c), (b, c, a), (c, a, b), (c, b, a)] {
+            unsafe { f32_xany_avx2_nofma_median3(x, y, z, &mut result) };
+            assert_eq!(result, sorted.iter().map(|v| v[1]).collect::<Vec<f32>>());
+        }
+    }
+}
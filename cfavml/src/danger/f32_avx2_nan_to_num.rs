@@ -0,0 +1,184 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Replaces the non-finite values of the vector with the given substitutes,
+/// writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     if is_nan(x[i]):
+///         result[i] = nan_value
+///     elif x[i] == inf:
+///         result[i] = pos_inf_value
+///     elif x[i] == -inf:
+///         result[i] = neg_inf_value
+///     else:
+///         result[i] = x[i]
+/// ```
+///
+/// This mirrors numpy's `nan_to_num`, although the substitutes must always be given.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_nan_to_num(
+    nan_value: f32,
+    pos_inf_value: f32,
+    neg_inf_value: f32,
+    x: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    f32_avx2_nan_to_num_to(
+        nan_value,
+        pos_inf_value,
+        neg_inf_value,
+        x.as_ptr(),
+        result.as_mut_ptr(),
+        x.len(),
+    )
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Replaces the non-finite values of the vector with the given substitutes in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     if is_nan(x[i]):
+///         x[i] = nan_value
+///     elif x[i] == inf:
+///         x[i] = pos_inf_value
+///     elif x[i] == -inf:
+///         x[i] = neg_inf_value
+/// ```
+///
+/// This mirrors numpy's `nan_to_num`, although the substitutes must always be given.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_nan_to_num_inplace(
+    nan_value: f32,
+    pos_inf_value: f32,
+    neg_inf_value: f32,
+    x: &mut [f32],
+) {
+    let x_ptr = x.as_mut_ptr();
+    f32_avx2_nan_to_num_to(
+        nan_value,
+        pos_inf_value,
+        neg_inf_value,
+        x_ptr,
+        x_ptr,
+        x.len(),
+    )
+}
+
+#[inline(always)]
+/// Replaces the non-finite values read from `x_ptr` writing the output to the `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn f32_avx2_nan_to_num_to(
+    nan_value: f32,
+    pos_inf_value: f32,
+    neg_inf_value: f32,
+    x_ptr: *const f32,
+    result: *mut f32,
+    len: usize,
+) {
+    let offset_from = len % 8;
+
+    let nan_reg = _mm256_set1_ps(nan_value);
+    let pos_inf_reg = _mm256_set1_ps(pos_inf_value);
+    let neg_inf_reg = _mm256_set1_ps(neg_inf_value);
+    let inf = _mm256_set1_ps(f32::INFINITY);
+    let neg_inf = _mm256_set1_ps(f32::NEG_INFINITY);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let mut v = _mm256_loadu_ps(x_ptr.add(i));
+
+        let is_nan = _mm256_cmp_ps::<_CMP_UNORD_Q>(v, v);
+        let is_pos_inf = _mm256_cmp_ps::<_CMP_EQ_OQ>(v, inf);
+        let is_neg_inf = _mm256_cmp_ps::<_CMP_EQ_OQ>(v, neg_inf);
+
+        v = _mm256_blendv_ps(v, nan_reg, is_nan);
+        v = _mm256_blendv_ps(v, pos_inf_reg, is_pos_inf);
+        v = _mm256_blendv_ps(v, neg_inf_reg, is_neg_inf);
+        _mm256_storeu_ps(result.add(i), v);
+
+        i += 8;
+    }
+
+    while i < len {
+        let v = *x_ptr.add(i);
+        *result.add(i) = if v.is_nan() {
+            nan_value
+        } else if v == f32::INFINITY {
+            pos_inf_value
+        } else if v == f32::NEG_INFINITY {
+            neg_inf_value
+        } else {
+            v
+        };
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_nan_to_num() {
+        let (mut x, _) = get_sample_vectors::<f32>(131);
+        x[0] = f32::NAN;
+        x[12] = f32::INFINITY;
+        x[13] = f32::NEG_INFINITY;
+        x[129] = f32::INFINITY;
+        x[130] = -f32::NAN;
+
+        let expected = x
+            .iter()
+            .map(|v| {
+                if v.is_nan() {
+                    0.0
+                } else if v.is_infinite() {
+                    v.signum() * 1000.0
+                } else {
+                    *v
+                }
+            })
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_nofma_nan_to_num(0.0, 1000.0, -1000.0, &x, &mut result) };
+        assert_eq!(result, expected);
+
+        unsafe { f32_xany_avx2_nofma_nan_to_num_inplace(0.0, 1000.0, -1000.0, &mut x) };
+        assert_eq!(x, expected);
+    }
+}
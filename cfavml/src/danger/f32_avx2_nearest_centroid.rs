@@ -0,0 +1,258 @@
+use crate::danger::{
+    f32_xany_avx2_fma_euclidean,
+    f32_xany_avx2_nofma_euclidean,
+    nearest_batch_with,
+    nearest_with,
+};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Finds the centroid with the smallest squared Euclidean distance to `point`,
+/// returning its index and distance.
+///
+/// ```py
+/// K: int
+/// D: int
+/// point: [f32; D]
+/// centroids: [[f32; D]; K]
+///
+/// best = 0
+/// for k in 1..K:
+///     if euclidean(point, centroids[k]) < euclidean(point, centroids[best]):
+///         best = k
+///
+/// return best, euclidean(point, centroids[best])
+/// ```
+///
+/// The running minimum is tracked as each centroid is streamed past, so no vector of
+/// distances is materialized. Ties resolve to the lowest index and `NaN` distances
+/// are only selected if every distance is `NaN`.
+///
+/// # Panics
+///
+/// Panics if `centroids` is empty.
+///
+/// # Safety
+///
+/// Every centroid **MUST** be the same length as `point`, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_nearest_centroid(
+    point: &[f32],
+    centroids: &[&[f32]],
+) -> (usize, f32) {
+    nearest_with(point, centroids, |x, y| f32_xany_avx2_nofma_euclidean(x, y))
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Assigns each of `points` to the centroid with the smallest squared Euclidean
+/// distance, writing its index to `labels` and the distance to `distances`.
+///
+/// ```py
+/// N: int
+/// K: int
+/// D: int
+/// points: [[f32; D]; N]
+/// centroids: [[f32; D]; K]
+/// labels: [usize; N]
+/// distances: [f32; N]
+///
+/// for i in 0..N:
+///     labels[i], distances[i] = nearest_centroid(points[i], centroids)
+/// ```
+///
+/// This is the assignment step of Lloyd's algorithm for k-means.
+///
+/// # Panics
+///
+/// Panics if `centroids` is empty while `points` is not, or if `labels` and
+/// `distances` are not the same length as `points`.
+///
+/// # Safety
+///
+/// Every point and centroid **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_nearest_centroid_batch(
+    points: &[&[f32]],
+    centroids: &[&[f32]],
+    labels: &mut [usize],
+    distances: &mut [f32],
+) {
+    nearest_batch_with(points, centroids, labels, distances, |x, y| {
+        f32_xany_avx2_nofma_euclidean(x, y)
+    })
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Finds the centroid with the smallest squared Euclidean distance to `point`,
+/// returning its index and distance.
+///
+/// ```py
+/// K: int
+/// D: int
+/// point: [f32; D]
+/// centroids: [[f32; D]; K]
+///
+/// best = 0
+/// for k in 1..K:
+///     if euclidean(point, centroids[k]) < euclidean(point, centroids[best]):
+///         best = k
+///
+/// return best, euclidean(point, centroids[best])
+/// ```
+///
+/// The running minimum is tracked as each centroid is streamed past, so no vector of
+/// distances is materialized. Ties resolve to the lowest index and `NaN` distances
+/// are only selected if every distance is `NaN`.
+///
+/// # Panics
+///
+/// Panics if `centroids` is empty.
+///
+/// # Safety
+///
+/// Every centroid **MUST** be the same length as `point`, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_nearest_centroid(
+    point: &[f32],
+    centroids: &[&[f32]],
+) -> (usize, f32) {
+    nearest_with(point, centroids, |x, y| f32_xany_avx2_fma_euclidean(x, y))
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Assigns each of `points` to the centroid with the smallest squared Euclidean
+/// distance, writing its index to `labels` and the distance to `distances`.
+///
+/// ```py
+/// N: int
+/// K: int
+/// D: int
+/// points: [[f32; D]; N]
+/// centroids: [[f32; D]; K]
+/// labels: [usize; N]
+/// distances: [f32; N]
+///
+/// for i in 0..N:
+///     labels[i], distances[i] = nearest_centroid(points[i], centroids)
+/// ```
+///
+/// This is the assignment step of Lloyd's algorithm for k-means.
+///
+/// # Panics
+///
+/// Panics if `centroids` is empty while `points` is not, or if `labels` and
+/// `distances` are not the same length as `points`.
+///
+/// # Safety
+///
+/// Every point and centroid **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_nearest_centroid_batch(
+    points: &[&[f32]],
+    centroids: &[&[f32]],
+    labels: &mut [usize],
+    distances: &mut [f32],
+) {
+    nearest_batch_with(points, centroids, labels, distances, |x, y| {
+        f32_xany_avx2_fma_euclidean(x, y)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_euclidean};
+
+    const K: usize = 9;
+    const DIM: usize = 37;
+
+    fn expected_nearest(point: &[f32], centroids: &[&[f32]]) -> (usize, f32) {
+        let mut best = (0, simple_euclidean(point, centroids[0]));
+        for (k, centroid) in centroids.iter().enumerate().skip(1) {
+            let dist = simple_euclidean(point, centroid);
+            if dist < best.1 {
+                best = (k, dist);
+            }
+        }
+        best
+    }
+
+    #[test]
+    fn test_xany_nofma_nearest_centroid() {
+        let (data, points) = get_sample_vectors::<f32>(K * DIM);
+        let centroids = data.chunks(DIM).collect::<Vec<&[f32]>>();
+        let points = points.chunks(DIM).collect::<Vec<&[f32]>>();
+
+        let mut labels = vec![usize::MAX; K];
+        let mut distances = vec![f32::NAN; K];
+        unsafe {
+            f32_xany_avx2_nofma_nearest_centroid_batch(
+                &points,
+                &centroids,
+                &mut labels,
+                &mut distances,
+            )
+        };
+
+        for (i, point) in points.iter().enumerate() {
+            let (label, dist) =
+                unsafe { f32_xany_avx2_nofma_nearest_centroid(point, &centroids) };
+            let (expected_label, expected_dist) = expected_nearest(point, &centroids);
+            assert_eq!(label, expected_label);
+            assert_is_close(dist, expected_dist);
+            assert_eq!((labels[i], distances[i]), (label, dist));
+        }
+
+        // A point equal to a centroid is always assigned to it.
+        let (label, dist) =
+            unsafe { f32_xany_avx2_nofma_nearest_centroid(centroids[4], &centroids) };
+        assert_eq!((label, dist), (4, 0.0));
+    }
+
+    #[test]
+    fn test_xany_fma_nearest_centroid() {
+        let (data, points) = get_sample_vectors::<f32>(K * DIM);
+        let centroids = data.chunks(DIM).collect::<Vec<&[f32]>>();
+        let points = points.chunks(DIM).collect::<Vec<&[f32]>>();
+
+        let mut labels = vec![usize::MAX; K];
+        let mut distances = vec![f32::NAN; K];
+        unsafe {
+            f32_xany_avx2_fma_nearest_centroid_batch(
+                &points,
+                &centroids,
+                &mut labels,
+                &mut distances,
+            )
+        };
+
+        for (i, point) in points.iter().enumerate() {
+            let (label, dist) =
+                unsafe { f32_xany_avx2_fma_nearest_centroid(point, &centroids) };
+            let (expected_label, expected_dist) = expected_nearest(point, &centroids);
+            assert_eq!(label, expected_label);
+            assert_is_close(dist, expected_dist);
+            assert_eq!((labels[i], distances[i]), (label, dist));
+        }
+
+        // A point equal to a centroid is always assigned to it.
+        let (label, dist) =
+            unsafe { f32_xany_avx2_fma_nearest_centroid(centroids[4], &centroids) };
+        assert_eq!((label, dist), (4, 0.0));
+    }
+}
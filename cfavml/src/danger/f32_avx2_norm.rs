@@ -6,7 +6,10 @@ use crate::math::*;
 
 #[target_feature(enable = "avx2")]
 #[inline]
-/// Computes the squared norm of one `[f32; DIMS]` vector.
+/// Computes the squared L2 norm of one `[f32; DIMS]` vector.
+///
+/// This is equivalent to `dot(x, x)`, the square root is **not** applied,
+/// use the sibling `l2_norm` routine for the L2 norm.
 ///
 /// # Safety
 ///
@@ -50,7 +53,10 @@ pub unsafe fn f32_xconst_avx2_nofma_norm<const DIMS: usize>(x: &[f32]) -> f32 {
 
 #[target_feature(enable = "avx2")]
 #[inline]
-/// Computes the squared norm of one f32 vector.
+/// Computes the squared L2 norm of one f32 vector.
+///
+/// This is equivalent to `dot(x, x)`, the square root is **not** applied,
+/// use the sibling `l2_norm` routine for the L2 norm.
 ///
 /// This vector can be any size although it may perform worse than
 /// the specialized size handling.
@@ -117,7 +123,10 @@ pub unsafe fn f32_xany_avx2_nofma_norm(x: &[f32]) -> f32 {
 
 #[target_feature(enable = "avx2", enable = "fma")]
 #[inline]
-/// Computes the squared norm of one `[f32; DIMS]` vector.
+/// Computes the squared L2 norm of one `[f32; DIMS]` vector.
+///
+/// This is equivalent to `dot(x, x)`, the square root is **not** applied,
+/// use the sibling `l2_norm` routine for the L2 norm.
 ///
 /// # Safety
 ///
@@ -161,7 +170,10 @@ pub unsafe fn f32_xconst_avx2_fma_norm<const DIMS: usize>(x: &[f32]) -> f32 {
 
 #[target_feature(enable = "avx2", enable = "fma")]
 #[inline]
-/// Computes the squared norm of one f32 vector.
+/// Computes the squared L2 norm of one f32 vector.
+///
+/// This is equivalent to `dot(x, x)`, the square root is **not** applied,
+/// use the sibling `l2_norm` routine for the L2 norm.
 ///
 /// This vector can be any size although it may perform worse than
 /// the specialized size handling.
@@ -303,6 +315,120 @@ unsafe fn execute_f32_x64_fma_block_norm(
     *acc8 = _mm256_fmadd_ps(x8, x8, *acc8);
 }
 
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the L2 norm of one `[f32; DIMS]` vector.
+///
+/// ```py
+/// return sqrt(norm(x))
+/// ```
+///
+/// # Safety
+///
+/// DIMS **MUST** be a multiple of `64` and vector must be `DIMS` in length,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes avx2 instructions are available, if this method is executed
+/// on non-avx2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xconst_avx2_nofma_l2_norm<const DIMS: usize>(x: &[f32]) -> f32 {
+    f32_xconst_avx2_nofma_norm::<DIMS>(x).sqrt()
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the L2 norm of one f32 vector.
+///
+/// This vector can be any size although it may perform worse than
+/// the specialized size handling.
+///
+/// ```py
+/// return sqrt(norm(x))
+/// ```
+///
+/// # Safety
+///
+/// This method assumes avx2 instructions are available, if this method is executed
+/// on non-avx2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_l2_norm(x: &[f32]) -> f32 {
+    f32_xany_avx2_nofma_norm(x).sqrt()
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the L2 norm of one `[f32; DIMS]` vector.
+///
+/// ```py
+/// return sqrt(norm(x))
+/// ```
+///
+/// # Safety
+///
+/// DIMS **MUST** be a multiple of `64` and vector must be `DIMS` in length,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes avx2 instructions are available, if this method is executed
+/// on non-avx2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xconst_avx2_fma_l2_norm<const DIMS: usize>(x: &[f32]) -> f32 {
+    f32_xconst_avx2_fma_norm::<DIMS>(x).sqrt()
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the L2 norm of one f32 vector.
+///
+/// This vector can be any size although it may perform worse than
+/// the specialized size handling.
+///
+/// ```py
+/// return sqrt(norm(x))
+/// ```
+///
+/// # Safety
+///
+/// This method assumes avx2 instructions are available, if this method is executed
+/// on non-avx2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_l2_norm(x: &[f32]) -> f32 {
+    f32_xany_avx2_fma_norm(x).sqrt()
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the sum of squares of one f32 vector multiplied by `scale`.
+///
+/// This is the squared L2 norm scaled in the same pass, for example
+/// `1 / (D - 1)` when computing a variance with Bessel's correction.
+///
+/// ```py
+/// return scale * norm(x)
+/// ```
+///
+/// # Safety
+///
+/// This method assumes avx2 instructions are available, if this method is executed
+/// on non-avx2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_sum_of_scaled_squares(scale: f32, x: &[f32]) -> f32 {
+    scale * f32_xany_avx2_nofma_norm(x)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the sum of squares of one f32 vector multiplied by `scale`.
+///
+/// This is the squared L2 norm scaled in the same pass, for example
+/// `1 / (D - 1)` when computing a variance with Bessel's correction.
+///
+/// ```py
+/// return scale * norm(x)
+/// ```
+///
+/// # Safety
+///
+/// This method assumes avx2 instructions are available, if this method is executed
+/// on non-avx2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_sum_of_scaled_squares(scale: f32, x: &[f32]) -> f32 {
+    scale * f32_xany_avx2_fma_norm(x)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -335,4 +461,46 @@ mod tests {
         let dist = unsafe { f32_xconst_avx2_nofma_norm::<1024>(&x) };
         assert_is_close(dist, simple_dot(&x, &x));
     }
+
+    #[test]
+    fn test_xconst_nofma_l2_norm() {
+        let (x, _) = get_sample_vectors(1024);
+        let dist = unsafe { f32_xconst_avx2_nofma_l2_norm::<1024>(&x) };
+        assert_is_close(dist, simple_dot(&x, &x).sqrt());
+    }
+
+    #[test]
+    fn test_xany_nofma_l2_norm() {
+        let (x, _) = get_sample_vectors(131);
+        let dist = unsafe { f32_xany_avx2_nofma_l2_norm(&x) };
+        assert_is_close(dist, simple_dot(&x, &x).sqrt());
+    }
+
+    #[test]
+    fn test_xconst_fma_l2_norm() {
+        let (x, _) = get_sample_vectors(1024);
+        let dist = unsafe { f32_xconst_avx2_fma_l2_norm::<1024>(&x) };
+        assert_is_close(dist, simple_dot(&x, &x).sqrt());
+    }
+
+    #[test]
+    fn test_xany_fma_l2_norm() {
+        let (x, _) = get_sample_vectors(131);
+        let dist = unsafe { f32_xany_avx2_fma_l2_norm(&x) };
+        assert_is_close(dist, simple_dot(&x, &x).sqrt());
+    }
+
+    #[test]
+    fn test_xany_nofma_sum_of_scaled_squares() {
+        let (x, _) = get_sample_vectors(131);
+        let res = unsafe { f32_xany_avx2_nofma_sum_of_scaled_squares(0.25, &x) };
+        assert_is_close(res, 0.25 * simple_dot(&x, &x));
+    }
+
+    #[test]
+    fn test_xany_fma_sum_of_scaled_squares() {
+        let (x, _) = get_sample_vectors(131);
+        let res = unsafe { f32_xany_avx2_fma_sum_of_scaled_squares(0.25, &x) };
+        assert_is_close(res, 0.25 * simple_dot(&x, &x));
+    }
 }
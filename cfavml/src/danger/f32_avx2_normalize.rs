@@ -0,0 +1,234 @@
+use crate::danger::{
+    f32_xany_avx2_fma_l2_norm,
+    f32_xany_avx2_fma_norm,
+    f32_xany_avx2_nofma_div_value,
+    f32_xany_avx2_nofma_l2_norm,
+    f32_xany_avx2_nofma_mul_value,
+    f32_xany_avx2_nofma_norm,
+    rsqrt_approx_f32,
+};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Scales every row of the matrix in place to have an L2 norm of `1.0`, using the
+/// approximate reciprocal square root of each row's squared norm.
+///
+/// ```py
+/// D: int
+/// N: int
+/// matrix: [[f32; D]; N]
+///
+/// for row in matrix:
+///     norm = dot(row, row)
+///     if norm != 0:
+///         for i in 0..D:
+///             row[i] = row[i] * rsqrt(norm)
+/// ```
+///
+/// The reciprocal square root is approximated with `rsqrtss`, so the resulting rows
+/// have a norm within roughly `4e-4` of `1.0`, which is acceptable when preparing
+/// vectors for a cosine index. Use
+/// [f32_xany_avx2_nofma_normalize_batch_precise] when the exact norm matters.
+///
+/// Rows with a norm of zero are left unchanged, rows do not need to be the same length.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_normalize_batch(matrix: &mut [&mut [f32]]) {
+    for row in matrix.iter_mut() {
+        let norm = f32_xany_avx2_nofma_norm(row);
+        if norm != 0.0 {
+            f32_xany_avx2_nofma_mul_value(row, rsqrt_approx_f32(norm));
+        }
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Scales every row of the matrix in place to have an L2 norm of `1.0`, dividing
+/// each element by the exact L2 norm of its row.
+///
+/// ```py
+/// D: int
+/// N: int
+/// matrix: [[f32; D]; N]
+///
+/// for row in matrix:
+///     norm = sqrt(dot(row, row))
+///     if norm != 0:
+///         for i in 0..D:
+///             row[i] = row[i] / norm
+/// ```
+///
+/// Rows with a norm of zero are left unchanged, rows do not need to be the same length.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_normalize_batch_precise(matrix: &mut [&mut [f32]]) {
+    for row in matrix.iter_mut() {
+        let norm = f32_xany_avx2_nofma_l2_norm(row);
+        if norm != 0.0 {
+            f32_xany_avx2_nofma_div_value(row, norm);
+        }
+    }
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Scales every row of the matrix in place to have an L2 norm of `1.0`, using the
+/// approximate reciprocal square root of each row's squared norm.
+///
+/// ```py
+/// D: int
+/// N: int
+/// matrix: [[f32; D]; N]
+///
+/// for row in matrix:
+///     norm = dot(row, row)
+///     if norm != 0:
+///         for i in 0..D:
+///             row[i] = row[i] * rsqrt(norm)
+/// ```
+///
+/// The reciprocal square root is approximated with `rsqrtss`, so the resulting rows
+/// have a norm within roughly `4e-4` of `1.0`, which is acceptable when preparing
+/// vectors for a cosine index. Use
+/// [f32_xany_avx2_fma_normalize_batch_precise] when the exact norm matters.
+///
+/// Rows with a norm of zero are left unchanged, rows do not need to be the same length.
+///
+/// # Safety
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_normalize_batch(matrix: &mut [&mut [f32]]) {
+    for row in matrix.iter_mut() {
+        let norm = f32_xany_avx2_fma_norm(row);
+        if norm != 0.0 {
+            f32_xany_avx2_nofma_mul_value(row, rsqrt_approx_f32(norm));
+        }
+    }
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Scales every row of the matrix in place to have an L2 norm of `1.0`, dividing
+/// each element by the exact L2 norm of its row.
+///
+/// ```py
+/// D: int
+/// N: int
+/// matrix: [[f32; D]; N]
+///
+/// for row in matrix:
+///     norm = sqrt(dot(row, row))
+///     if norm != 0:
+///         for i in 0..D:
+///             row[i] = row[i] / norm
+/// ```
+///
+/// Rows with a norm of zero are left unchanged, rows do not need to be the same length.
+///
+/// # Safety
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_normalize_batch_precise(matrix: &mut [&mut [f32]]) {
+    for row in matrix.iter_mut() {
+        let norm = f32_xany_avx2_fma_l2_norm(row);
+        if norm != 0.0 {
+            f32_xany_avx2_nofma_div_value(row, norm);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        assert_is_close_vector,
+        get_sample_vectors,
+        simple_dot,
+    };
+
+    #[test]
+    fn test_xany_nofma_normalize_batch() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let mut rows = [x.clone(), y.clone(), vec![0.0; 37]];
+        let mut matrix = rows
+            .iter_mut()
+            .map(|r| r.as_mut_slice())
+            .collect::<Vec<_>>();
+        unsafe { f32_xany_avx2_nofma_normalize_batch(&mut matrix) };
+
+        for (row, original) in rows[..2].iter().zip([&x, &y]) {
+            let norm = simple_dot(row, row).sqrt();
+            assert!((norm - 1.0).abs() < 1e-3, "{norm} != 1.0");
+            assert!(row
+                .iter()
+                .zip(original.iter())
+                .all(|(a, b)| a.signum() == b.signum()));
+        }
+        assert!(rows[2].iter().all(|v| *v == 0.0));
+    }
+
+    #[test]
+    fn test_xany_nofma_normalize_batch_precise() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let mut rows = [x.clone(), y, vec![0.0; 37]];
+        let mut matrix = rows
+            .iter_mut()
+            .map(|r| r.as_mut_slice())
+            .collect::<Vec<_>>();
+        unsafe { f32_xany_avx2_nofma_normalize_batch_precise(&mut matrix) };
+
+        let norm = simple_dot(&x, &x).sqrt();
+        let expected = x.iter().map(|v| v / norm).collect::<Vec<_>>();
+        assert_is_close_vector(&rows[0], &expected);
+        assert_is_close(simple_dot(&rows[1], &rows[1]), 1.0);
+        assert!(rows[2].iter().all(|v| *v == 0.0));
+    }
+
+    #[test]
+    fn test_xany_fma_normalize_batch() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let mut rows = [x.clone(), y.clone(), vec![0.0; 37]];
+        let mut matrix = rows
+            .iter_mut()
+            .map(|r| r.as_mut_slice())
+            .collect::<Vec<_>>();
+        unsafe { f32_xany_avx2_fma_normalize_batch(&mut matrix) };
+
+        for (row, original) in rows[..2].iter().zip([&x, &y]) {
+            let norm = simple_dot(row, row).sqrt();
+            assert!((norm - 1.0).abs() < 1e-3, "{norm} != 1.0");
+            assert!(row
+                .iter()
+                .zip(original.iter())
+                .all(|(a, b)| a.signum() == b.signum()));
+        }
+        assert!(rows[2].iter().all(|v| *v == 0.0));
+    }
+
+    #[test]
+    fn test_xany_fma_normalize_batch_precise() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let mut rows = [x.clone(), y, vec![0.0; 37]];
+        let mut matrix = rows
+            .iter_mut()
+            .map(|r| r.as_mut_slice())
+            .collect::<Vec<_>>();
+        unsafe { f32_xany_avx2_fma_normalize_batch_precise(&mut matrix) };
+
+        let norm = simple_dot(&x, &x).sqrt();
+        let expected = x.iter().map(|v| v / norm).collect::<Vec<_>>();
+        assert_is_close_vector(&rows[0], &expected);
+        assert_is_close(simple_dot(&rows[1], &rows[1]), 1.0);
+        assert!(rows[2].iter().all(|v| *v == 0.0));
+    }
+}
@@ -0,0 +1,165 @@
+use crate::danger::{
+    f32_xany_avx2_fma_dot,
+    f32_xany_avx2_nofma_dot,
+    f32_xany_avx2_nofma_sum_horizontal,
+    par_tree_reduce,
+};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sums all elements of the vector in parallel, producing a bit identical result
+/// regardless of the number of threads in the rayon thread pool.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return total
+/// ```
+///
+/// The vector is split into fixed size blocks which are summed independently by
+/// [f32_xany_avx2_nofma_sum_horizontal] and combined in a fixed binary tree, so the order of additions only
+/// depends on the length of `x`. The result may differ from the sequential sum in
+/// the last bits, but repeating the sum always reproduces it exactly.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_par_sum_deterministic(x: &[f32]) -> f32 {
+    par_tree_reduce(
+        x.len(),
+        0.0,
+        |block| f32_xany_avx2_nofma_sum_horizontal(x.get_unchecked(block)),
+        |a, b| a + b,
+    )
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of two vectors in parallel, producing a bit identical
+/// result regardless of the number of threads in the rayon thread pool.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     total = total + x[i] * y[i]
+///
+/// return total
+/// ```
+///
+/// The partial dot products of fixed size blocks are computed by [f32_xany_avx2_nofma_dot] and
+/// combined in a fixed binary tree, so the order of additions only depends on the
+/// length of the vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_par_dot_deterministic(x: &[f32], y: &[f32]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    par_tree_reduce(
+        x.len(),
+        0.0,
+        |block| {
+            f32_xany_avx2_nofma_dot(
+                x.get_unchecked(block.clone()),
+                y.get_unchecked(block),
+            )
+        },
+        |a, b| a + b,
+    )
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of two vectors in parallel, producing a bit identical
+/// result regardless of the number of threads in the rayon thread pool.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     total = total + x[i] * y[i]
+///
+/// return total
+/// ```
+///
+/// The partial dot products of fixed size blocks are computed by [f32_xany_avx2_fma_dot] and
+/// combined in a fixed binary tree, so the order of additions only depends on the
+/// length of the vectors.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_par_dot_deterministic(x: &[f32], y: &[f32]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    par_tree_reduce(
+        x.len(),
+        0.0,
+        |block| {
+            f32_xany_avx2_fma_dot(x.get_unchecked(block.clone()), y.get_unchecked(block))
+        },
+        |a, b| a + b,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::{
+        generic_xany_fallback_nofma_par_sum_deterministic,
+        PAR_REDUCE_BLOCK,
+    };
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    #[test]
+    fn test_xany_par_deterministic() {
+        let (x, y) = get_sample_vectors::<f32>(5 * PAR_REDUCE_BLOCK + 131);
+
+        let reduce = |threads: usize| {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            pool.install(|| unsafe {
+                [
+                    f32_xany_avx2_nofma_par_sum_deterministic(&x).to_bits(),
+                    f32_xany_avx2_nofma_par_dot_deterministic(&x, &y).to_bits(),
+                    f32_xany_avx2_fma_par_dot_deterministic(&x, &y).to_bits(),
+                ]
+            })
+        };
+
+        let expected = reduce(1);
+        for threads in [2, 3, 8] {
+            assert_eq!(reduce(threads), expected, "{threads} threads");
+        }
+
+        let sum = unsafe { generic_xany_fallback_nofma_par_sum_deterministic(&x) };
+        assert_is_close(
+            f32::from_bits(expected[0]) / x.len() as f32,
+            sum / x.len() as f32,
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use crate::danger::f32_avx2_select::select_nth_in_scratch;
+use crate::danger::percentile_rank;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the median of the vector, interpolating between the two middle
+/// elements when `D` is even.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// return percentile(x, 50)
+/// ```
+///
+/// `scratch` is cleared and re-used as the working buffer, passing the same buffer
+/// between calls avoids re-allocating on each call.
+///
+/// See [f32_xany_avx2_nofma_percentile] for details on `NaN` handling.
+///
+/// # Safety
+///
+/// `x` **MUST** not be empty, otherwise this routine will become
+/// immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_median(x: &[f32], scratch: &mut Vec<f32>) -> f32 {
+    f32_xany_avx2_nofma_percentile(x, 50.0, scratch)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the `p`th percentile of the vector where `p` is within `[0, 100]`,
+/// linearly interpolating between the two nearest ranked elements.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// rank = (p / 100) * (D - 1)
+/// lower = floor(rank)
+/// sorted = sort(x)
+///
+/// return sorted[lower] + (rank - lower) * (sorted[lower + 1] - sorted[lower])
+/// ```
+///
+/// This matches numpy's default `linear` method, values of `p` outside of `[0, 100]`
+/// are clamped.
+///
+/// `x` is copied into `scratch` and the ranked elements are found using
+/// [f32_xany_avx2_nofma_select_nth](crate::danger::f32_xany_avx2_nofma_select_nth),
+/// `scratch` is cleared and re-used as the working buffer, passing the same buffer
+/// between calls avoids re-allocating on each call.
+///
+/// ### NaN ordering
+///
+/// `NaN` values are ordered after every other value, so they only contribute to the
+/// result when the ranked elements fall within the trailing `NaN`s.
+///
+/// # Safety
+///
+/// `x` **MUST** not be empty, otherwise this routine will become
+/// immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_percentile(
+    x: &[f32],
+    p: f32,
+    scratch: &mut Vec<f32>,
+) -> f32 {
+    debug_assert!(!x.is_empty(), "Vector must not be empty");
+
+    let len = x.len();
+    let (lower, fraction) = percentile_rank(len, p);
+
+    scratch.clear();
+    scratch.extend_from_slice(x);
+    scratch.resize(len * 2, 0.0);
+
+    let (values, partition_buffer) = scratch.split_at_mut(len);
+    let lower_value = select_nth_in_scratch(values, lower, partition_buffer);
+
+    if fraction == 0.0 {
+        return lower_value;
+    }
+
+    // Everything after `lower` is greater than or equal to it, so the next ranked
+    // element is the minimum of the remaining elements.
+    let upper_value = values
+        .get_unchecked(lower + 1..)
+        .iter()
+        .copied()
+        .reduce(f32::min)
+        .unwrap_or(lower_value);
+
+    lower_value + fraction * (upper_value - lower_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    fn simple_percentile(x: &[f32], p: f32) -> f32 {
+        let mut sorted = x.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let rank = (p / 100.0) * (sorted.len() - 1) as f32;
+        let lower = rank.floor() as usize;
+        let upper = (lower + 1).min(sorted.len() - 1);
+        sorted[lower] + (rank - lower as f32) * (sorted[upper] - sorted[lower])
+    }
+
+    #[test]
+    fn test_xany_nofma_percentile() {
+        let mut scratch = Vec::new();
+        for len in [1, 2, 15, 514, 1031] {
+            let (x, _) = get_sample_vectors::<f32>(len);
+            for p in [0.0, 10.0, 25.0, 50.0, 90.0, 99.0, 100.0] {
+                let res = unsafe { f32_xany_avx2_nofma_percentile(&x, p, &mut scratch) };
+                assert_is_close(res, simple_percentile(&x, p));
+            }
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_median() {
+        let mut scratch = Vec::new();
+        let x = [5.0, 1.0, 4.0, 2.0];
+        let res = unsafe { f32_xany_avx2_nofma_median(&x, &mut scratch) };
+        assert_eq!(res, 3.0);
+
+        let (x, _) = get_sample_vectors::<f32>(515);
+        let res = unsafe { f32_xany_avx2_nofma_median(&x, &mut scratch) };
+        assert_is_close(res, simple_percentile(&x, 50.0));
+        assert_eq!(scratch.len(), 515 * 2);
+    }
+
+    #[test]
+    fn test_xany_nofma_percentile_nan() {
+        let mut scratch = Vec::new();
+        let x = [3.0, f32::NAN, 1.0, 2.0];
+        let res = unsafe { f32_xany_avx2_nofma_percentile(&x, 50.0, &mut scratch) };
+        assert_eq!(res, 2.5);
+
+        let res = unsafe { f32_xany_avx2_nofma_percentile(&x, 100.0, &mut scratch) };
+        assert!(res.is_nan());
+    }
+}
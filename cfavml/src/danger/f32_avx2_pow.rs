@@ -0,0 +1,435 @@
+use core::arch::x86_64::*;
+use core::ptr;
+
+use crate::danger::{exp_avx2_pd, log_avx2_pd, partially_overlaps};
+
+/// The largest integer exponent raised by repeated squaring rather than through
+/// `exp(y * ln(x))`, the error of the squaring grows with the size of the exponent.
+const POWI_LIMIT: f32 = 32.0;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Raises each element of the vector to the power of `exp`, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// exp: f32
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = x[i] ** exp
+/// ```
+///
+/// Integer exponents with a magnitude of at most `32` are computed by repeated
+/// squaring in registers, taking the reciprocal of `x` first for negative exponents,
+/// the relative error is within `|exp|` ULP. Any other exponent is computed as
+/// `exp(exp * ln(|x|))` in double precision and rounded once, see
+/// [f32_xany_avx2_nofma_pow_vertical] for the handling of special values.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_pow_value(exp: f32, x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    pow_value_to(exp, x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Raises each element of the vector to the power of `exp` in place.
+///
+/// ```py
+/// D: int
+/// exp: f32
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] ** exp
+/// ```
+///
+/// See [f32_xany_avx2_nofma_pow_value] for the accuracy of the approximation.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_pow_value_inplace(exp: f32, x: &mut [f32]) {
+    let x_ptr = x.as_mut_ptr();
+    pow_value_to(exp, x_ptr, x_ptr, x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Raises each element of `x` to the power of the matching element of `exp`, writing
+/// the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// exp: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = x[i] ** exp[i]
+/// ```
+///
+/// This is computed as `exp(exp * ln(|x|))` in double precision and rounded once, so
+/// the result is within an ULP of the exact value. Special values follow the IEEE
+/// `pow` function, `x ** 0` and `1 ** exp` are `1.0` even for `NaN`, negative `x`
+/// with a non integer `exp` is `NaN` and negative `x` with an odd integer `exp` keeps
+/// its sign.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_pow_vertical(
+    x: &[f32],
+    exp: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), exp.len());
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        ![x, exp].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    pow_vertical_to(x.as_ptr(), exp, result.as_mut_ptr())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Raises each element of `x` to the power of the matching element of `exp` in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// exp: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] ** exp[i]
+/// ```
+///
+/// See [f32_xany_avx2_nofma_pow_vertical] for the accuracy and special values.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_pow_vertical_inplace(x: &mut [f32], exp: &[f32]) {
+    debug_assert_eq!(x.len(), exp.len());
+
+    let x_ptr = x.as_mut_ptr();
+    pow_vertical_to(x_ptr, exp, x_ptr)
+}
+
+#[inline(always)]
+/// Raises the values read from `x_ptr` to the power of `exp` writing the output to
+/// the `result` pointer, the tail is padded out to a full register so every element
+/// goes through the same instructions.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn pow_value_to(exp: f32, x_ptr: *const f32, result: *mut f32, len: usize) {
+    let is_powi = exp.trunc() == exp && exp.abs() <= POWI_LIMIT;
+    let y = _mm256_set1_ps(exp);
+    let offset_from = len % 16;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+
+        let (r1, r2) = if is_powi {
+            (powi_ps(x1, exp as i32), powi_ps(x2, exp as i32))
+        } else {
+            (pow_ps(x1, y), pow_ps(x2, y))
+        };
+
+        _mm256_storeu_ps(result.add(i), r1);
+        _mm256_storeu_ps(result.add(i + 8), r2);
+
+        i += 16;
+    }
+
+    while i < len {
+        let n = (len - i).min(8);
+        let mut tail = [1.0f32; 8];
+        ptr::copy_nonoverlapping(x_ptr.add(i), tail.as_mut_ptr(), n);
+
+        let x = _mm256_loadu_ps(tail.as_ptr());
+        let r = if is_powi {
+            powi_ps(x, exp as i32)
+        } else {
+            pow_ps(x, y)
+        };
+        _mm256_storeu_ps(tail.as_mut_ptr(), r);
+        ptr::copy_nonoverlapping(tail.as_ptr(), result.add(i), n);
+
+        i += n;
+    }
+}
+
+#[inline(always)]
+/// Raises the values read from `x_ptr` to the power of `exp` element wise, writing
+/// the output to the `result` pointer, the tail is padded out to a full register so
+/// every element goes through the same instructions.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn pow_vertical_to(x_ptr: *const f32, exp: &[f32], result: *mut f32) {
+    let len = exp.len();
+    let exp_ptr = exp.as_ptr();
+    let offset_from = len % 16;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let y1 = _mm256_loadu_ps(exp_ptr.add(i));
+        let y2 = _mm256_loadu_ps(exp_ptr.add(i + 8));
+
+        _mm256_storeu_ps(result.add(i), pow_ps(x1, y1));
+        _mm256_storeu_ps(result.add(i + 8), pow_ps(x2, y2));
+
+        i += 16;
+    }
+
+    while i < len {
+        let n = (len - i).min(8);
+        let mut x_tail = [1.0f32; 8];
+        let mut y_tail = [1.0f32; 8];
+        ptr::copy_nonoverlapping(x_ptr.add(i), x_tail.as_mut_ptr(), n);
+        ptr::copy_nonoverlapping(exp_ptr.add(i), y_tail.as_mut_ptr(), n);
+
+        let r = pow_ps(
+            _mm256_loadu_ps(x_tail.as_ptr()),
+            _mm256_loadu_ps(y_tail.as_ptr()),
+        );
+        _mm256_storeu_ps(x_tail.as_mut_ptr(), r);
+        ptr::copy_nonoverlapping(x_tail.as_ptr(), result.add(i), n);
+
+        i += n;
+    }
+}
+
+#[inline(always)]
+/// Computes `x ** n` by repeated squaring, negative exponents square the reciprocal of
+/// `x` so values which only underflow in the intermediate still produce a finite
+/// result.
+unsafe fn powi_ps(x: __m256, n: i32) -> __m256 {
+    let one = _mm256_set1_ps(1.0);
+    let mut base = if n < 0 { _mm256_div_ps(one, x) } else { x };
+    let mut n = n.unsigned_abs();
+
+    let mut acc = one;
+    while n > 0 {
+        if n & 1 == 1 {
+            acc = _mm256_mul_ps(acc, base);
+        }
+
+        n >>= 1;
+        if n > 0 {
+            base = _mm256_mul_ps(base, base);
+        }
+    }
+
+    acc
+}
+
+#[inline(always)]
+/// Computes `x ** y` as `exp(y * ln(|x|))` in double precision, applying the sign of
+/// `x` for odd integer `y` and replacing the results of the IEEE special cases.
+unsafe fn pow_ps(x: __m256, y: __m256) -> __m256 {
+    let sign_mask = _mm256_set1_ps(-0.0);
+    let one = _mm256_set1_ps(1.0);
+    let abs_x = _mm256_andnot_ps(sign_mask, x);
+
+    let lo = pow_abs_pd(
+        _mm256_cvtps_pd(_mm256_castps256_ps128(abs_x)),
+        _mm256_cvtps_pd(_mm256_castps256_ps128(y)),
+    );
+    let hi = pow_abs_pd(
+        _mm256_cvtps_pd(_mm256_extractf128_ps::<1>(abs_x)),
+        _mm256_cvtps_pd(_mm256_extractf128_ps::<1>(y)),
+    );
+    let r = _mm256_set_m128(_mm256_cvtpd_ps(hi), _mm256_cvtpd_ps(lo));
+
+    // Infinities count as even integers, the conversion of anything beyond the `i32`
+    // range produces `i32::MIN` which is also even.
+    let rounded =
+        _mm256_round_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(y);
+    let is_int = _mm256_cmp_ps::<_CMP_EQ_OQ>(rounded, y);
+    let low_bit = _mm256_castsi256_ps(_mm256_slli_epi32::<31>(_mm256_cvttps_epi32(y)));
+    let sign =
+        _mm256_and_ps(_mm256_and_ps(x, sign_mask), _mm256_and_ps(is_int, low_bit));
+    let r = _mm256_xor_ps(r, sign);
+
+    let is_neg = _mm256_and_ps(
+        _mm256_cmp_ps::<_CMP_LT_OQ>(x, _mm256_setzero_ps()),
+        _mm256_cmp_ps::<_CMP_GT_OQ>(x, _mm256_set1_ps(f32::NEG_INFINITY)),
+    );
+    let is_nan = _mm256_andnot_ps(is_int, is_neg);
+    let r = _mm256_blendv_ps(r, _mm256_set1_ps(f32::NAN), is_nan);
+
+    // `1 ** y`, `x ** 0` and `(-1) ** inf` are all exactly `1.0`.
+    let is_one = _mm256_or_ps(
+        _mm256_cmp_ps::<_CMP_EQ_OQ>(y, _mm256_setzero_ps()),
+        _mm256_cmp_ps::<_CMP_EQ_OQ>(x, one),
+    );
+    let is_inf_of_one = _mm256_and_ps(
+        _mm256_cmp_ps::<_CMP_EQ_OQ>(abs_x, one),
+        _mm256_cmp_ps::<_CMP_EQ_OQ>(
+            _mm256_andnot_ps(sign_mask, y),
+            _mm256_set1_ps(f32::INFINITY),
+        ),
+    );
+    _mm256_blendv_ps(r, one, _mm256_or_ps(is_one, is_inf_of_one))
+}
+
+#[inline(always)]
+/// Computes `a ** y` for non negative `a` as `exp(y * ln(a))`, `0.0`, `inf` and `NaN`
+/// are given their exact logarithm so the product saturates the exponential.
+unsafe fn pow_abs_pd(a: __m256d, y: __m256d) -> __m256d {
+    let l = log_avx2_pd::<true>(a);
+    let l = _mm256_blendv_pd(
+        l,
+        _mm256_set1_pd(f64::NEG_INFINITY),
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(a, _mm256_setzero_pd()),
+    );
+    let l = _mm256_blendv_pd(
+        l,
+        _mm256_set1_pd(f64::INFINITY),
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(a, _mm256_set1_pd(f64::INFINITY)),
+    );
+    let l = _mm256_blendv_pd(l, a, _mm256_cmp_pd::<_CMP_UNORD_Q>(a, a));
+
+    // `exp_avx2_pd` clamps to a finite `f64`, which rounds to `inf` or `0.0` as `f32`.
+    exp_avx2_pd(_mm256_mul_pd(y, l))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    fn assert_within_ulps(r: f32, e: f32, ulps: f32) {
+        if e.is_nan() {
+            assert!(r.is_nan(), "{r} != {e}");
+        } else if e.is_infinite() || e == 0.0 {
+            assert_eq!(r, e);
+        } else {
+            let tolerance = ulps * f32::EPSILON * e.abs().max(f32::MIN_POSITIVE);
+            assert!((r - e).abs() <= tolerance, "{r} != {e}");
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_pow_value() {
+        let (x, _) = get_sample_vectors::<f32>(533);
+        let x = x
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v - 0.5) * 2.0f32.powi(i as i32 % 9 - 4))
+            .collect::<Vec<f32>>();
+
+        for exp in [2.5, -1.75, 0.5, 3.0, -2.0, 7.0, 32.0, 0.0, 40.0] {
+            let mut result = vec![0.0; x.len()];
+            unsafe { f32_xany_avx2_nofma_pow_value(exp, &x, &mut result) };
+            for (v, r) in x.iter().zip(&result) {
+                let e = (*v as f64).powf(exp as f64) as f32;
+                assert_within_ulps(*r, e, exp.abs().max(1.0));
+            }
+
+            let mut inplace = x.clone();
+            unsafe { f32_xany_avx2_nofma_pow_value_inplace(exp, &mut inplace) };
+            assert_eq!(
+                inplace.iter().map(|v| v.to_bits()).collect::<Vec<_>>(),
+                result.iter().map(|v| v.to_bits()).collect::<Vec<_>>(),
+            );
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_pow_vertical() {
+        let (x, y) = get_sample_vectors::<f32>(533);
+        let x = x.iter().map(|v| v * 8.0).collect::<Vec<f32>>();
+        let mut y = y.iter().map(|v| (v - 0.5) * 20.0).collect::<Vec<f32>>();
+        y[7] = 3.0;
+        y[8] = -4.0;
+
+        let mut result = vec![0.0; x.len()];
+        unsafe { f32_xany_avx2_nofma_pow_vertical(&x, &y, &mut result) };
+        for ((v, e), r) in x.iter().zip(&y).zip(&result) {
+            assert_within_ulps(*r, v.powf(*e), 2.0);
+        }
+
+        let mut inplace = x.clone();
+        unsafe { f32_xany_avx2_nofma_pow_vertical_inplace(&mut inplace, &y) };
+        assert_eq!(inplace, result);
+    }
+
+    #[test]
+    fn test_xany_nofma_pow_special_values() {
+        let inf = f32::INFINITY;
+        let cases = [
+            (2.0, 0.0),
+            (f32::NAN, 0.0),
+            (1.0, f32::NAN),
+            (-1.0, inf),
+            (-1.0, -inf),
+            (-2.0, 3.0),
+            (-2.0, 2.0),
+            (-2.0, 0.5),
+            (-0.0, 3.0),
+            (-0.0, -3.0),
+            (-0.0, 0.5),
+            (0.0, -2.0),
+            (-inf, 0.5),
+            (-inf, 3.0),
+            (-inf, -3.0),
+            (inf, -0.5),
+            (0.5, inf),
+            (2.0, inf),
+            (2.0, -inf),
+            (f32::NAN, 2.0),
+            (2.0, f32::NAN),
+            (3.0, 200.0),
+            (1e-30, 4.5),
+            (1e-40, 0.5),
+            (-2.0, 1e10),
+        ];
+
+        let x = cases.iter().map(|c| c.0).collect::<Vec<f32>>();
+        let y = cases.iter().map(|c| c.1).collect::<Vec<f32>>();
+        let mut result = vec![0.0; x.len()];
+        unsafe { f32_xany_avx2_nofma_pow_vertical(&x, &y, &mut result) };
+        for ((v, e), r) in x.iter().zip(&y).zip(&result) {
+            let expected = v.powf(*e);
+            assert_within_ulps(*r, expected, 2.0);
+            if !expected.is_nan() {
+                assert_eq!(
+                    r.is_sign_negative(),
+                    expected.is_sign_negative(),
+                    "{v} ** {e}"
+                );
+            }
+        }
+    }
+}
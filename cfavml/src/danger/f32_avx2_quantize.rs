@@ -0,0 +1,150 @@
+use core::arch::x86_64::*;
+
+use crate::danger::utils::{quantize_uniform_avx2_ps, quantize_uniform_f32};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Maps each element of the vector into one of `levels` uniformly sized buckets
+/// spanning `[lo, hi]`, writing the bucket index of each element to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [u32; D]
+///
+/// scale = levels / (hi - lo)
+///
+/// for i in 0..D:
+///     bucket = floor((x[i] - lo) * scale)
+///     result[i] = clamp(bucket, 0, levels - 1)
+/// ```
+///
+/// Values on a bucket boundary belong to the bucket above it, except `hi` which
+/// belongs to the last bucket. Values below `lo` are clamped into the first bucket,
+/// values above `hi` into the last and `NaN` is mapped to the first bucket.
+/// Boundaries are computed in `f32` using the scale above, so values within rounding
+/// error of a boundary may land either side of it.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, `levels` **MUST** be within `1..=2^24` so every
+/// bucket index is exactly representable as an `f32`, and `lo` **MUST** be less than
+/// `hi`, otherwise this routine will become immediately UB due to out of bounds
+/// pointer accesses or produce meaningless indices.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_quantize_uniform(
+    levels: u32,
+    lo: f32,
+    hi: f32,
+    x: &[f32],
+    result: &mut [u32],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        (1..=1 << 24).contains(&levels),
+        "Levels must be within 1..=2^24"
+    );
+    debug_assert!(lo < hi, "The lower bound must be less than the upper bound");
+
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let scale = levels as f32 / (hi - lo);
+    let max_bucket = (levels - 1) as f32;
+
+    let lo_reg = _mm256_set1_ps(lo);
+    let scale_reg = _mm256_set1_ps(scale);
+    let max_bucket_reg = _mm256_set1_ps(max_bucket);
+
+    let x_ptr = x.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let x3 = _mm256_loadu_ps(x_ptr.add(i + 16));
+        let x4 = _mm256_loadu_ps(x_ptr.add(i + 24));
+
+        let r1 = quantize_uniform_avx2_ps(x1, lo_reg, scale_reg, max_bucket_reg);
+        let r2 = quantize_uniform_avx2_ps(x2, lo_reg, scale_reg, max_bucket_reg);
+        let r3 = quantize_uniform_avx2_ps(x3, lo_reg, scale_reg, max_bucket_reg);
+        let r4 = quantize_uniform_avx2_ps(x4, lo_reg, scale_reg, max_bucket_reg);
+
+        _mm256_storeu_si256(result_ptr.add(i).cast(), r1);
+        _mm256_storeu_si256(result_ptr.add(i + 8).cast(), r2);
+        _mm256_storeu_si256(result_ptr.add(i + 16).cast(), r3);
+        _mm256_storeu_si256(result_ptr.add(i + 24).cast(), r4);
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+        let r = quantize_uniform_avx2_ps(x, lo_reg, scale_reg, max_bucket_reg);
+        _mm256_storeu_si256(result_ptr.add(i).cast(), r);
+
+        i += 8;
+    }
+
+    while i < len {
+        *result_ptr.add(i) = quantize_uniform_f32(*x_ptr.add(i), lo, scale, max_bucket);
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::f32_xany_fallback_nofma_quantize_uniform;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_quantize_uniform() {
+        for len in [0, 3, 8, 33, 531] {
+            let (x, y) = get_sample_vectors::<f32>(len);
+            // Spread the values beyond the bucket range on both sides.
+            let x = x
+                .iter()
+                .zip(y.iter())
+                .map(|(a, b)| (a - b) * 3.0)
+                .collect::<Vec<_>>();
+
+            let mut expected = vec![0; len];
+            let mut result = vec![0; len];
+            unsafe {
+                f32_xany_fallback_nofma_quantize_uniform(
+                    10,
+                    -1.0,
+                    1.5,
+                    &x,
+                    &mut expected,
+                );
+                f32_xany_avx2_nofma_quantize_uniform(10, -1.0, 1.5, &x, &mut result);
+            }
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_quantize_uniform_edges() {
+        let x = [
+            0.0,
+            0.25,
+            0.5,
+            0.99,
+            1.0,
+            -5.0,
+            5.0,
+            f32::NAN,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+        ];
+        let mut result = [0; 10];
+        unsafe { f32_xany_avx2_nofma_quantize_uniform(4, 0.0, 1.0, &x, &mut result) };
+        assert_eq!(result, [0, 1, 2, 3, 3, 0, 3, 0, 3, 0]);
+    }
+}
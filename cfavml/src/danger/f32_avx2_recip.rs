@@ -0,0 +1,274 @@
+use core::arch::x86_64::*;
+use core::ptr;
+
+use crate::danger::partially_overlaps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Approximates `1 / x` of each element of the vector, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = 1 / x[i]
+/// ```
+///
+/// This uses the `rcpps` instruction with a relative error of at most `1.5 * 2^-12`,
+/// with `refine` a single Newton-Raphson step `r * (2 - x * r)` brings it to within
+/// a few ULP for roughly the cost of two more multiplies. `0.0` and infinite inputs
+/// produce `inf` and `0.0` either way.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_recip(refine: bool, x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let (x_ptr, result_ptr) = (x.as_ptr(), result.as_mut_ptr());
+    if refine {
+        approx_to::<false, true>(x_ptr, result_ptr, x.len())
+    } else {
+        approx_to::<false, false>(x_ptr, result_ptr, x.len())
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Approximates `1 / x` of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = 1 / x[i]
+/// ```
+///
+/// See [f32_xany_avx2_nofma_recip] for the accuracy with and without `refine`.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_recip_inplace(refine: bool, x: &mut [f32]) {
+    let x_ptr = x.as_mut_ptr();
+    if refine {
+        approx_to::<false, true>(x_ptr, x_ptr, x.len())
+    } else {
+        approx_to::<false, false>(x_ptr, x_ptr, x.len())
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Approximates `1 / sqrt(x)` of each element of the vector, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = 1 / sqrt(x[i])
+/// ```
+///
+/// This uses the `rsqrtps` instruction with a relative error of at most `1.5 * 2^-12`,
+/// with `refine` a single Newton-Raphson step `r * (1.5 - 0.5 * x * r * r)` brings it
+/// to within a few ULP. `0.0` and infinite inputs produce `inf` and `0.0` either way,
+/// negative inputs produce `NaN`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_rsqrt(refine: bool, x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let (x_ptr, result_ptr) = (x.as_ptr(), result.as_mut_ptr());
+    if refine {
+        approx_to::<true, true>(x_ptr, result_ptr, x.len())
+    } else {
+        approx_to::<true, false>(x_ptr, result_ptr, x.len())
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Approximates `1 / sqrt(x)` of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = 1 / sqrt(x[i])
+/// ```
+///
+/// See [f32_xany_avx2_nofma_rsqrt] for the accuracy with and without `refine`.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_rsqrt_inplace(refine: bool, x: &mut [f32]) {
+    let x_ptr = x.as_mut_ptr();
+    if refine {
+        approx_to::<true, true>(x_ptr, x_ptr, x.len())
+    } else {
+        approx_to::<true, false>(x_ptr, x_ptr, x.len())
+    }
+}
+
+#[inline(always)]
+/// Approximates `1 / sqrt(x)` when `RSQRT` is `true` and `1 / x` otherwise for the
+/// values read from `x_ptr` writing the output to the `result` pointer, the tail is
+/// padded out to a full register so every element goes through the same instructions.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn approx_to<const RSQRT: bool, const REFINE: bool>(
+    x_ptr: *const f32,
+    result: *mut f32,
+    len: usize,
+) {
+    let offset_from = len % 16;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let v1 = _mm256_loadu_ps(x_ptr.add(i));
+        let v2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+
+        _mm256_storeu_ps(result.add(i), approx_ps::<RSQRT, REFINE>(v1));
+        _mm256_storeu_ps(result.add(i + 8), approx_ps::<RSQRT, REFINE>(v2));
+
+        i += 16;
+    }
+
+    while i < len {
+        let n = (len - i).min(8);
+        let mut tail = [1.0f32; 8];
+        ptr::copy_nonoverlapping(x_ptr.add(i), tail.as_mut_ptr(), n);
+
+        let v = approx_ps::<RSQRT, REFINE>(_mm256_loadu_ps(tail.as_ptr()));
+        _mm256_storeu_ps(tail.as_mut_ptr(), v);
+        ptr::copy_nonoverlapping(tail.as_ptr(), result.add(i), n);
+
+        i += n;
+    }
+}
+
+#[inline(always)]
+unsafe fn approx_ps<const RSQRT: bool, const REFINE: bool>(x: __m256) -> __m256 {
+    if RSQRT {
+        rsqrt_ps::<REFINE>(x)
+    } else {
+        recip_ps::<REFINE>(x)
+    }
+}
+
+#[inline(always)]
+unsafe fn recip_ps<const REFINE: bool>(x: __m256) -> __m256 {
+    let r = _mm256_rcp_ps(x);
+    if !REFINE {
+        return r;
+    }
+
+    let two = _mm256_set1_ps(2.0);
+    let refined = _mm256_mul_ps(r, _mm256_sub_ps(two, _mm256_mul_ps(x, r)));
+    keep_unrefinable(r, refined)
+}
+
+#[inline(always)]
+unsafe fn rsqrt_ps<const REFINE: bool>(x: __m256) -> __m256 {
+    let r = _mm256_rsqrt_ps(x);
+    if !REFINE {
+        return r;
+    }
+
+    let half_x = _mm256_mul_ps(_mm256_set1_ps(0.5), x);
+    let step = _mm256_sub_ps(
+        _mm256_set1_ps(1.5),
+        _mm256_mul_ps(half_x, _mm256_mul_ps(r, r)),
+    );
+    keep_unrefinable(r, _mm256_mul_ps(r, step))
+}
+
+#[inline(always)]
+/// Keeps the approximation `r` where it is `0.0` or infinite, the refinement step
+/// multiplies the two together which would otherwise produce `NaN`.
+unsafe fn keep_unrefinable(r: __m256, refined: __m256) -> __m256 {
+    let abs = _mm256_andnot_ps(_mm256_set1_ps(-0.0), r);
+    let is_inf = _mm256_cmp_ps::<_CMP_EQ_OQ>(abs, _mm256_set1_ps(f32::INFINITY));
+    let is_zero = _mm256_cmp_ps::<_CMP_EQ_OQ>(abs, _mm256_setzero_ps());
+    _mm256_blendv_ps(refined, r, _mm256_or_ps(is_inf, is_zero))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    fn assert_relative_error(result: &[f32], expected: &[f32], tolerance: f32) {
+        for (r, e) in result.iter().zip(expected) {
+            assert!(((r - e) / e).abs() <= tolerance, "{r} vs {e}");
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_recip() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let x = x
+            .iter()
+            .zip(y)
+            .map(|(a, b)| (a - b) * 100.0)
+            .collect::<Vec<_>>();
+        let expected = x.iter().map(|v| 1.0 / v).collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; x.len()];
+        unsafe { f32_xany_avx2_nofma_recip(false, &x, &mut result) };
+        assert_relative_error(&result, &expected, 1.5 / 4096.0);
+
+        unsafe { f32_xany_avx2_nofma_recip(true, &x, &mut result) };
+        assert_relative_error(&result, &expected, 1e-6);
+
+        let mut special = vec![0.0, -0.0, f32::INFINITY];
+        unsafe { f32_xany_avx2_nofma_recip_inplace(true, &mut special) };
+        assert_eq!(special, [f32::INFINITY, f32::NEG_INFINITY, 0.0]);
+    }
+
+    #[test]
+    fn test_xany_nofma_rsqrt() {
+        let (x, _) = get_sample_vectors::<f32>(131);
+        let x = x.iter().map(|v| v * 1e4 + 1e-3).collect::<Vec<_>>();
+        let expected = x.iter().map(|v| 1.0 / v.sqrt()).collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; x.len()];
+        unsafe { f32_xany_avx2_nofma_rsqrt(false, &x, &mut result) };
+        assert_relative_error(&result, &expected, 1.5 / 4096.0);
+
+        unsafe { f32_xany_avx2_nofma_rsqrt(true, &x, &mut result) };
+        assert_relative_error(&result, &expected, 1e-6);
+
+        let mut special = vec![0.0, f32::INFINITY, -1.0];
+        unsafe { f32_xany_avx2_nofma_rsqrt_inplace(true, &mut special) };
+        assert_eq!(&special[..2], [f32::INFINITY, 0.0]);
+        assert!(special[2].is_nan());
+    }
+}
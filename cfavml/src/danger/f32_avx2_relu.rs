@@ -0,0 +1,117 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Applies the ReLU activation to the vector, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = max(x[i], 0)
+/// ```
+///
+/// Negative inputs produce `0.0` rather than the `-0.0` of a leaky ReLU with a
+/// `slope` of `0.0`, `NaN` inputs are propagated.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_relu(x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    f32_avx2_relu_to(x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Applies the ReLU activation to the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = max(x[i], 0)
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_relu_inplace(x: &mut [f32]) {
+    let x_ptr = x.as_mut_ptr();
+    f32_avx2_relu_to(x_ptr, x_ptr, x.len())
+}
+
+#[inline(always)]
+/// Applies the ReLU to the values read from `x_ptr` writing the output to the
+/// `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn f32_avx2_relu_to(x_ptr: *const f32, result: *mut f32, len: usize) {
+    let offset_from = len % 32;
+
+    let zero = _mm256_setzero_ps();
+
+    // `maxps` returns the second operand when either is `NaN`, so `x` goes second
+    // to propagate it.
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let v1 = _mm256_loadu_ps(x_ptr.add(i));
+        let v2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let v3 = _mm256_loadu_ps(x_ptr.add(i + 16));
+        let v4 = _mm256_loadu_ps(x_ptr.add(i + 24));
+
+        _mm256_storeu_ps(result.add(i), _mm256_max_ps(zero, v1));
+        _mm256_storeu_ps(result.add(i + 8), _mm256_max_ps(zero, v2));
+        _mm256_storeu_ps(result.add(i + 16), _mm256_max_ps(zero, v3));
+        _mm256_storeu_ps(result.add(i + 24), _mm256_max_ps(zero, v4));
+
+        i += 32;
+    }
+
+    while i < len {
+        let v = *x_ptr.add(i);
+        *result.add(i) = if 0.0 > v { 0.0 } else { v };
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_relu() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let mut x = x.iter().zip(y).map(|(a, b)| a - b).collect::<Vec<_>>();
+        x[3] = f32::NAN;
+        x[130] = f32::NAN;
+
+        let expected = x.iter().map(|v| v.max(0.0)).collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f32_xany_avx2_nofma_relu(&x, &mut result) };
+        assert!(result[3].is_nan() && result[130].is_nan());
+        assert_eq!(&result[4..130], &expected[4..130]);
+
+        unsafe { f32_xany_avx2_nofma_relu_inplace(&mut x) };
+        assert!(x[3].is_nan() && x[130].is_nan());
+        assert_eq!(&x[4..130], &expected[4..130]);
+    }
+}
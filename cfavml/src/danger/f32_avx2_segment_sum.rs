@@ -0,0 +1,128 @@
+use core::arch::x86_64::*;
+use core::mem;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sums the elements of `values` into the buckets of `result` selected by the
+/// matching element of `segment_ids`, equivalent to TensorFlow's
+/// `unsorted_segment_sum`.
+///
+/// ```py
+/// D: int
+/// values: [f32; D]
+/// segment_ids: [u32; D]
+/// result: [f32; num_segments]
+///
+/// for i in 0..D:
+///     result[segment_ids[i]] += values[i]
+/// ```
+///
+/// The totals are added to the existing values of `result`, so it must be zeroed
+/// beforehand to compute a plain segment sum. Segment ids do not need to be sorted.
+///
+/// Values are loaded 8 at a time and each lane adds into its own partial set of
+/// segments so repeated ids do not serialize on the same bucket, the partial sets are
+/// merged into `result` at the end.
+///
+/// # Safety
+///
+/// `values` and `segment_ids` **MUST** be the same length, `result` **MUST** be
+/// `num_segments` long and every segment id **MUST** be less than `num_segments`,
+/// otherwise this routine will become immediately UB due to out of bounds pointer
+/// accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_segment_sum(
+    values: &[f32],
+    segment_ids: &[u32],
+    num_segments: usize,
+    result: &mut [f32],
+) {
+    debug_assert_eq!(values.len(), segment_ids.len());
+    debug_assert_eq!(result.len(), num_segments);
+    debug_assert!(
+        segment_ids.iter().all(|&id| (id as usize) < num_segments),
+        "Segment ids must be less than `num_segments`"
+    );
+
+    let len = values.len();
+    let offset_from = len % 8;
+
+    let values_ptr = values.as_ptr();
+    let ids_ptr = segment_ids.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let mut i = 0;
+    if len >= 8 {
+        let mut partials = vec![0.0f32; num_segments * 8];
+        let partials_ptr = partials.as_mut_ptr();
+
+        while i < (len - offset_from) {
+            let v = _mm256_loadu_ps(values_ptr.add(i));
+            let ids = _mm256_loadu_si256(ids_ptr.add(i).cast());
+
+            let v = mem::transmute::<__m256, [f32; 8]>(v);
+            let ids = mem::transmute::<__m256i, [u32; 8]>(ids);
+
+            for lane in 0..8 {
+                *partials_ptr.add(lane * num_segments + ids[lane] as usize) += v[lane];
+            }
+
+            i += 8;
+        }
+
+        for lane in partials.chunks_exact(num_segments) {
+            for (segment, total) in lane.iter().enumerate() {
+                *result_ptr.add(segment) += *total;
+            }
+        }
+    }
+
+    while i < len {
+        *result_ptr.add(*ids_ptr.add(i) as usize) += *values_ptr.add(i);
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::generic_xany_fallback_nofma_segment_sum;
+    use crate::test_utils::{assert_is_close_vector, get_sample_vectors};
+
+    #[test]
+    fn test_xany_segment_sum() {
+        for len in [0, 3, 8, 33, 531] {
+            let (values, _) = get_sample_vectors::<f32>(len);
+            let segment_ids = (0..len as u32)
+                .map(|i| (i * 7 + i / 3) % 5)
+                .collect::<Vec<_>>();
+
+            let mut expected = vec![0.0; 5];
+            let mut result = vec![0.0; 5];
+            unsafe {
+                generic_xany_fallback_nofma_segment_sum(
+                    &values,
+                    &segment_ids,
+                    5,
+                    &mut expected,
+                );
+                f32_xany_avx2_nofma_segment_sum(&values, &segment_ids, 5, &mut result);
+            }
+            assert_is_close_vector(&result, &expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_segment_sum_accumulates() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0];
+        let segment_ids = [0, 0, 2, 2, 2, 0, 0, 0, 2, 0];
+        let mut result = [1.0, 1.0, 1.0];
+        unsafe {
+            f32_xany_avx2_nofma_segment_sum(&values, &segment_ids, 3, &mut result)
+        };
+        assert_eq!(result, [35.0, 1.0, 22.0]);
+    }
+}
@@ -0,0 +1,312 @@
+use core::arch::x86_64::*;
+use core::cmp::Ordering;
+
+use crate::danger::f32_xany_avx2_nofma_contains_nan;
+
+/// Ranges shorter than this are finished with the scalar selection routine.
+const SCALAR_SELECT_THRESHOLD: usize = 64;
+
+/// Permutation indices which move the lanes selected by the mask to the front
+/// of the register, keeping their order.
+static COMPRESS_FRONT_LUT: [[i32; 8]; 256] = build_compress_lut(false);
+/// Permutation indices which move the lanes selected by the mask to the back
+/// of the register, keeping their order.
+static COMPRESS_BACK_LUT: [[i32; 8]; 256] = build_compress_lut(true);
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Re-orders `x` such that the element at index `n` is the element which would be
+/// at that position if `x` were sorted, returning that element.
+///
+/// All elements before `n` are less than or equal to it and all elements after `n`
+/// are greater than or equal to it, this matches the behaviour of `select_nth_unstable`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// x = partition_around_nth(x, n)
+/// return x[n]
+/// ```
+///
+/// This is implemented as a quickselect where each partitioning pass is vectorized,
+/// which requires an additional scratch allocation of `D` elements.
+///
+/// ### NaN ordering
+///
+/// `NaN` values are ordered after every other value, including `inf`, so they are
+/// only ever selected when `n` is greater than or equal to the number of non-`NaN`
+/// elements. `-0.0` and `0.0` are treated as equal.
+///
+/// # Safety
+///
+/// `n` **MUST** be less than the length of `x`, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_select_nth(x: &mut [f32], n: usize) -> f32 {
+    let mut scratch = vec![0.0; x.len()];
+    select_nth_in_scratch(x, n, &mut scratch)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// The quickselect behind [f32_xany_avx2_nofma_select_nth] using the provided
+/// `scratch` buffer for partitioning rather than allocating.
+///
+/// # Safety
+///
+/// `n` **MUST** be less than the length of `x` and `scratch` **MUST** be at least
+/// the length of `x`, AVX2 instructions must be available.
+pub(crate) unsafe fn select_nth_in_scratch(
+    x: &mut [f32],
+    n: usize,
+    scratch: &mut [f32],
+) -> f32 {
+    debug_assert!(n < x.len(), "n must be within the bounds of the vector");
+    debug_assert!(
+        scratch.len() >= x.len(),
+        "scratch must be at least the length of the vector"
+    );
+
+    let len = if f32_xany_avx2_nofma_contains_nan(x) {
+        move_nans_to_end(x)
+    } else {
+        x.len()
+    };
+
+    if n >= len {
+        return *x.get_unchecked(n);
+    }
+
+    let mut lo = 0;
+    let mut hi = len;
+
+    while hi - lo > SCALAR_SELECT_THRESHOLD {
+        let pivot = median_of_three(x, lo, hi);
+        let (lt, eq) = partition_three_way(
+            x.get_unchecked_mut(lo..hi),
+            scratch.get_unchecked_mut(lo..hi),
+            pivot,
+        );
+
+        let nth = n - lo;
+        if nth < lt {
+            hi = lo + lt;
+        } else if nth < lt + eq {
+            return *x.get_unchecked(n);
+        } else {
+            lo += lt + eq;
+        }
+    }
+
+    let (_, nth, _) = x
+        .get_unchecked_mut(lo..hi)
+        .select_nth_unstable_by(n - lo, |a, b| {
+            a.partial_cmp(b).unwrap_or(Ordering::Equal)
+        });
+    *nth
+}
+
+#[inline(always)]
+/// Partitions `x` into the elements less than, equal to and greater than the `pivot`
+/// returning the number of elements less than and equal to the pivot.
+///
+/// The elements are partitioned into `scratch` and then copied back into `x`, the
+/// elements equal to the pivot are copied as they are so `-0.0` and `0.0` are kept.
+unsafe fn partition_three_way(
+    x: &mut [f32],
+    scratch: &mut [f32],
+    pivot: f32,
+) -> (usize, usize) {
+    let len = x.len();
+    let x_ptr = x.as_ptr();
+    let scratch_ptr = scratch.as_mut_ptr();
+    let pivot_reg = _mm256_set1_ps(pivot);
+
+    let mut left = 0;
+    let mut right = len;
+    let mut i = 0;
+
+    // Each store writes a full register, the unused lanes land in the gap between
+    // `left` and `right` which is always at least 8 elements larger than what
+    // remains to be processed while 16 or more elements remain.
+    while len - i >= 16 {
+        let v = _mm256_loadu_ps(x_ptr.add(i));
+        let lt_mask =
+            _mm256_movemask_ps(_mm256_cmp_ps::<_CMP_LT_OQ>(v, pivot_reg)) as usize;
+        let gt_mask =
+            _mm256_movemask_ps(_mm256_cmp_ps::<_CMP_GT_OQ>(v, pivot_reg)) as usize;
+
+        let lt_idx = _mm256_loadu_si256(
+            COMPRESS_FRONT_LUT.get_unchecked(lt_mask).as_ptr().cast(),
+        );
+        let gt_idx =
+            _mm256_loadu_si256(COMPRESS_BACK_LUT.get_unchecked(gt_mask).as_ptr().cast());
+
+        _mm256_storeu_ps(scratch_ptr.add(left), _mm256_permutevar8x32_ps(v, lt_idx));
+        left += lt_mask.count_ones() as usize;
+
+        _mm256_storeu_ps(
+            scratch_ptr.add(right - 8),
+            _mm256_permutevar8x32_ps(v, gt_idx),
+        );
+        right -= gt_mask.count_ones() as usize;
+
+        i += 8;
+    }
+
+    while i < len {
+        let v = *x.get_unchecked(i);
+        if v < pivot {
+            *scratch.get_unchecked_mut(left) = v;
+            left += 1;
+        } else if v > pivot {
+            right -= 1;
+            *scratch.get_unchecked_mut(right) = v;
+        }
+
+        i += 1;
+    }
+
+    let mut eq = left;
+    for &v in x.iter() {
+        if v == pivot {
+            *scratch.get_unchecked_mut(eq) = v;
+            eq += 1;
+        }
+    }
+    debug_assert_eq!(eq, right);
+
+    x.copy_from_slice(scratch);
+
+    (left, right - left)
+}
+
+#[inline(always)]
+/// Returns the median of the first, middle and last elements of `x[lo..hi]`.
+unsafe fn median_of_three(x: &[f32], lo: usize, hi: usize) -> f32 {
+    let a = *x.get_unchecked(lo);
+    let b = *x.get_unchecked(lo + (hi - lo) / 2);
+    let c = *x.get_unchecked(hi - 1);
+
+    a.max(b).min(a.min(b).max(c))
+}
+
+#[inline(always)]
+/// Moves all `NaN` values to the end of `x`, returning the number of non-`NaN` values.
+unsafe fn move_nans_to_end(x: &mut [f32]) -> usize {
+    let mut write = 0;
+    for read in 0..x.len() {
+        if !x.get_unchecked(read).is_nan() {
+            x.swap(write, read);
+            write += 1;
+        }
+    }
+    write
+}
+
+const fn build_compress_lut(to_back: bool) -> [[i32; 8]; 256] {
+    let mut lut = [[0; 8]; 256];
+
+    let mut mask = 0;
+    while mask < 256 {
+        let count = (mask as u32).count_ones() as usize;
+        let mut pos = if to_back { 8 - count } else { 0 };
+
+        let mut lane = 0;
+        while lane < 8 {
+            if mask & (1 << lane) != 0 {
+                lut[mask][pos] = lane;
+                pos += 1;
+            }
+            lane += 1;
+        }
+
+        mask += 1;
+    }
+
+    lut
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    fn check_select(x: &[f32], n: usize) {
+        let mut sorted = x.to_vec();
+        sorted.sort_by(|a, b| {
+            a.partial_cmp(b)
+                .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+        });
+
+        let mut selected = x.to_vec();
+        let value = unsafe { f32_xany_avx2_nofma_select_nth(&mut selected, n) };
+
+        if sorted[n].is_nan() {
+            assert!(value.is_nan());
+            return;
+        }
+
+        assert_eq!(value, sorted[n], "n: {n}");
+        assert_eq!(selected[n], value);
+        assert!(selected[..n].iter().all(|v| *v <= value));
+        assert!(selected[n + 1..].iter().all(|v| v.is_nan() || *v >= value));
+    }
+
+    #[test]
+    fn test_xany_nofma_select_nth() {
+        let (x, _) = get_sample_vectors::<f32>(1031);
+        for n in [0, 1, 100, 515, 1029, 1030] {
+            check_select(&x, n);
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_select_nth_duplicates() {
+        let x = (0..2048).map(|v| (v % 7) as f32).collect::<Vec<_>>();
+        for n in [0, 300, 1024, 2047] {
+            check_select(&x, n);
+        }
+
+        let x = vec![1.0; 777];
+        check_select(&x, 400);
+    }
+
+    #[test]
+    fn test_xany_nofma_select_nth_signed_zeros() {
+        let x = (0..1024)
+            .map(|v| match v % 3 {
+                0 => -0.0,
+                1 => 0.0,
+                _ => v as f32,
+            })
+            .collect::<Vec<_>>();
+        let negative_zeros = |x: &[f32]| {
+            x.iter()
+                .filter(|v| **v == 0.0 && v.is_sign_negative())
+                .count()
+        };
+
+        for n in [0, 300, 682, 1023] {
+            check_select(&x, n);
+
+            let mut selected = x.clone();
+            unsafe { f32_xany_avx2_nofma_select_nth(&mut selected, n) };
+            assert_eq!(negative_zeros(&selected), negative_zeros(&x), "n: {n}");
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_select_nth_nan() {
+        let (mut x, _) = get_sample_vectors::<f32>(515);
+        x[3] = f32::NAN;
+        x[200] = f32::NAN;
+        x[514] = f32::INFINITY;
+        for n in [0, 257, 512, 513, 514] {
+            check_select(&x, n);
+        }
+    }
+}
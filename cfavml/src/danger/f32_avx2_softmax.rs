@@ -0,0 +1,164 @@
+use core::arch::x86_64::*;
+use core::ptr;
+
+use crate::danger::{
+    exp_avx2_ps,
+    f32_xany_avx2_nofma_div_value,
+    f32_xany_avx2_nofma_max_horizontal,
+    partially_overlaps,
+    sum_avx2_ps,
+};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the softmax of the vector, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// m = max(x)
+/// for i in 0..D:
+///     result[i] = exp(x[i] - m)
+///
+/// total = sum(result)
+/// for i in 0..D:
+///     result[i] = result[i] / total
+/// ```
+///
+/// Subtracting the maximum first keeps every exponent at or below `0`, so large
+/// inputs cannot overflow. This takes three passes, the maximum is found with
+/// [f32_xany_avx2_nofma_max_horizontal], the exponents are written and summed in a
+/// single fused pass and `result` is divided by the total with
+/// [f32_xany_avx2_nofma_div_value]. Exponents which would be subnormal are flushed
+/// to `0.0`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_softmax(x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let max = f32_xany_avx2_nofma_max_horizontal(x);
+    let total = f32_avx2_exp_sum_to(x.as_ptr(), result.as_mut_ptr(), x.len(), max);
+    f32_xany_avx2_nofma_div_value(result, total);
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the softmax of the vector in place.
+///
+/// See [f32_xany_avx2_nofma_softmax] for the passes made over the vector.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_softmax_inplace(x: &mut [f32]) {
+    let max = f32_xany_avx2_nofma_max_horizontal(x);
+    let x_ptr = x.as_mut_ptr();
+    let total = f32_avx2_exp_sum_to(x_ptr, x_ptr, x.len(), max);
+    f32_xany_avx2_nofma_div_value(x, total);
+}
+
+#[inline(always)]
+/// Computes `e^(x - max)` of the values read from `x_ptr` writing the output to the
+/// `result` pointer and returning the sum of the outputs.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn f32_avx2_exp_sum_to(
+    x_ptr: *const f32,
+    result: *mut f32,
+    len: usize,
+    max: f32,
+) -> f32 {
+    let offset_from = len % 16;
+
+    let max = _mm256_set1_ps(max);
+    let mut acc1 = _mm256_setzero_ps();
+    let mut acc2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+
+        let e1 = shifted_exp(x1, max);
+        let e2 = shifted_exp(x2, max);
+        _mm256_storeu_ps(result.add(i), e1);
+        _mm256_storeu_ps(result.add(i + 8), e2);
+
+        acc1 = _mm256_add_ps(acc1, e1);
+        acc2 = _mm256_add_ps(acc2, e2);
+
+        i += 16;
+    }
+
+    while i < len {
+        let n = (len - i).min(8);
+        let mut tail = [f32::NEG_INFINITY; 8];
+        ptr::copy_nonoverlapping(x_ptr.add(i), tail.as_mut_ptr(), n);
+
+        let e = shifted_exp(_mm256_loadu_ps(tail.as_ptr()), max);
+        _mm256_storeu_ps(tail.as_mut_ptr(), e);
+        ptr::copy_nonoverlapping(tail.as_ptr(), result.add(i), n);
+
+        acc1 = _mm256_add_ps(acc1, e);
+
+        i += n;
+    }
+
+    sum_avx2_ps(_mm256_add_ps(acc1, acc2))
+}
+
+#[inline(always)]
+/// Computes `e^(x - max)`, the padding of `-inf` in the tail produces `0.0`.
+unsafe fn shifted_exp(x: __m256, max: __m256) -> __m256 {
+    let shifted = _mm256_sub_ps(x, max);
+    let underflow = _mm256_cmp_ps::<_CMP_LT_OQ>(shifted, _mm256_set1_ps(-87.3));
+    _mm256_andnot_ps(underflow, exp_avx2_ps(shifted))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    fn softmax(x: &[f32]) -> Vec<f32> {
+        let max = x.iter().copied().fold(f32::NEG_INFINITY, f32::max);
+        let exp = x.iter().map(|v| (v - max).exp()).collect::<Vec<_>>();
+        let total = exp.iter().sum::<f32>();
+        exp.iter().map(|v| v / total).collect()
+    }
+
+    #[test]
+    fn test_xany_nofma_softmax() {
+        let (x, _) = get_sample_vectors::<f32>(531);
+        let mut x = x.iter().map(|v| v * 20.0 - 10.0).collect::<Vec<_>>();
+        x[7] = 1000.0;
+        x[8] = 999.0;
+        x[530] = -1000.0;
+
+        let expected = softmax(&x);
+
+        let mut result = vec![0.0; x.len()];
+        unsafe { f32_xany_avx2_nofma_softmax(&x, &mut result) };
+        for (r, e) in result.iter().zip(&expected) {
+            assert!((r - e).abs() <= 1e-5 * e, "{r} vs {e}");
+        }
+        assert_eq!(result[530], 0.0);
+        assert_is_close(result.iter().sum::<f32>(), 1.0);
+
+        unsafe { f32_xany_avx2_nofma_softmax_inplace(&mut x) };
+        assert_eq!(x, result);
+    }
+}
@@ -0,0 +1,192 @@
+use core::arch::x86_64::*;
+
+use crate::danger::sum_avx2_ps;
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of the sparse vector described by `x_idx` and `x_val`
+/// with the dense vector `y`.
+///
+/// ```py
+/// N: int
+/// total: f32
+/// x_idx: [u32; N]
+/// x_val: [f32; N]
+/// y: [f32; D]
+///
+/// for i in 0..N:
+///     total = total + x_val[i] * y[x_idx[i]]
+/// ```
+///
+/// The elements of `y` are fetched 8 at a time with `_mm256_i32gather_ps`, the indices
+/// do not need to be sorted.
+///
+/// # Safety
+///
+/// `x_idx` and `x_val` **MUST** be the same length, and every index in `x_idx`
+/// **MUST** be less than `y.len()`, otherwise this routine will become immediately
+/// UB due to out of bounds pointer accesses. Indices are only validated in debug
+/// builds.
+///
+/// `y` **MUST NOT** be longer than `i32::MAX` elements, as the gather interprets
+/// the indices as signed 32-bit offsets.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_sparse_dot(
+    x_idx: &[u32],
+    x_val: &[f32],
+    y: &[f32],
+) -> f32 {
+    debug_assert_eq!(x_idx.len(), x_val.len());
+    debug_assert!(y.len() <= i32::MAX as usize);
+    debug_assert!(
+        x_idx.iter().all(|&idx| (idx as usize) < y.len()),
+        "Sparse index out of bounds"
+    );
+
+    let len = x_idx.len();
+    let offset_from = len % 16;
+
+    let idx_ptr = x_idx.as_ptr();
+    let val_ptr = x_val.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm256_setzero_ps();
+    let mut acc2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let idx1 = _mm256_loadu_si256(idx_ptr.add(i).cast());
+        let idx2 = _mm256_loadu_si256(idx_ptr.add(i + 8).cast());
+        let y1 = _mm256_i32gather_ps::<4>(y_ptr, idx1);
+        let y2 = _mm256_i32gather_ps::<4>(y_ptr, idx2);
+        let x1 = _mm256_loadu_ps(val_ptr.add(i));
+        let x2 = _mm256_loadu_ps(val_ptr.add(i + 8));
+
+        acc1 = _mm256_add_ps(acc1, _mm256_mul_ps(x1, y1));
+        acc2 = _mm256_add_ps(acc2, _mm256_mul_ps(x2, y2));
+
+        i += 16;
+    }
+
+    let mut total = sum_avx2_ps(_mm256_add_ps(acc1, acc2));
+
+    while i < len {
+        let idx = *idx_ptr.add(i) as usize;
+        total = AutoMath::add(total, AutoMath::mul(*val_ptr.add(i), *y_ptr.add(idx)));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of the sparse vector described by `x_idx` and `x_val`
+/// with the dense vector `y`.
+///
+/// ```py
+/// N: int
+/// total: f32
+/// x_idx: [u32; N]
+/// x_val: [f32; N]
+/// y: [f32; D]
+///
+/// for i in 0..N:
+///     total = total + x_val[i] * y[x_idx[i]]
+/// ```
+///
+/// The elements of `y` are fetched 8 at a time with `_mm256_i32gather_ps`, the indices
+/// do not need to be sorted.
+///
+/// # Safety
+///
+/// `x_idx` and `x_val` **MUST** be the same length, and every index in `x_idx`
+/// **MUST** be less than `y.len()`, otherwise this routine will become immediately
+/// UB due to out of bounds pointer accesses. Indices are only validated in debug
+/// builds.
+///
+/// `y` **MUST NOT** be longer than `i32::MAX` elements, as the gather interprets
+/// the indices as signed 32-bit offsets.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_sparse_dot(
+    x_idx: &[u32],
+    x_val: &[f32],
+    y: &[f32],
+) -> f32 {
+    debug_assert_eq!(x_idx.len(), x_val.len());
+    debug_assert!(y.len() <= i32::MAX as usize);
+    debug_assert!(
+        x_idx.iter().all(|&idx| (idx as usize) < y.len()),
+        "Sparse index out of bounds"
+    );
+
+    let len = x_idx.len();
+    let offset_from = len % 16;
+
+    let idx_ptr = x_idx.as_ptr();
+    let val_ptr = x_val.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm256_setzero_ps();
+    let mut acc2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let idx1 = _mm256_loadu_si256(idx_ptr.add(i).cast());
+        let idx2 = _mm256_loadu_si256(idx_ptr.add(i + 8).cast());
+        let y1 = _mm256_i32gather_ps::<4>(y_ptr, idx1);
+        let y2 = _mm256_i32gather_ps::<4>(y_ptr, idx2);
+        let x1 = _mm256_loadu_ps(val_ptr.add(i));
+        let x2 = _mm256_loadu_ps(val_ptr.add(i + 8));
+
+        acc1 = _mm256_fmadd_ps(x1, y1, acc1);
+        acc2 = _mm256_fmadd_ps(x2, y2, acc2);
+
+        i += 16;
+    }
+
+    let mut total = sum_avx2_ps(_mm256_add_ps(acc1, acc2));
+
+    while i < len {
+        let idx = *idx_ptr.add(i) as usize;
+        total = AutoMath::add(total, AutoMath::mul(*val_ptr.add(i), *y_ptr.add(idx)));
+
+        i += 1;
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    #[test]
+    fn test_xany_sparse_dot() {
+        let (y, values) = get_sample_vectors::<f32>(1024);
+
+        for nnz in [0, 5, 16, 127, 531] {
+            let idx = (0..nnz)
+                .map(|i| ((i * 389) % 1024) as u32)
+                .collect::<Vec<_>>();
+            let val = &values[..nnz];
+            let expected = idx
+                .iter()
+                .zip(val)
+                .map(|(i, v)| v * y[*i as usize])
+                .sum::<f32>();
+
+            let nofma = unsafe { f32_xany_avx2_nofma_sparse_dot(&idx, val, &y) };
+            let fma = unsafe { f32_xany_avx2_fma_sparse_dot(&idx, val, &y) };
+            assert_is_close(nofma, expected);
+            assert_is_close(fma, expected);
+        }
+    }
+}
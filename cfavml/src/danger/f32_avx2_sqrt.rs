@@ -0,0 +1,191 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the square root of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = sqrt(x[i])
+/// ```
+///
+/// The result is correctly rounded, negative inputs produce `NaN`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_sqrt(x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    f32_avx2_sqrt_to(x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the square root of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = sqrt(x[i])
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_sqrt_inplace(x: &mut [f32]) {
+    let x_ptr = x.as_mut_ptr();
+    f32_avx2_sqrt_to(x_ptr, x_ptr, x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the square root of each element of the `[f32; DIMS]` vector, writing the
+/// result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = sqrt(x[i])
+/// ```
+///
+/// # Safety
+///
+/// DIMS **MUST** be a multiple of `32` and both vectors must be `DIMS` in length,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xconst_avx2_nofma_sqrt<const DIMS: usize>(
+    x: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), DIMS);
+    debug_assert_eq!(result.len(), DIMS);
+    debug_assert_eq!(DIMS % 32, 0, "Input dimensions must be multiple of 32");
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    f32_avx2_sqrt_blocks_to(x.as_ptr(), result.as_mut_ptr(), DIMS)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the square root of each element of the `[f32; DIMS]` vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = sqrt(x[i])
+/// ```
+///
+/// # Safety
+///
+/// DIMS **MUST** be a multiple of `32` and the vector must be `DIMS` in length,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xconst_avx2_nofma_sqrt_inplace<const DIMS: usize>(x: &mut [f32]) {
+    debug_assert_eq!(x.len(), DIMS);
+    debug_assert_eq!(DIMS % 32, 0, "Input dimensions must be multiple of 32");
+
+    let x_ptr = x.as_mut_ptr();
+    f32_avx2_sqrt_blocks_to(x_ptr, x_ptr, DIMS)
+}
+
+#[inline(always)]
+/// Computes the square root of the values read from `x_ptr` writing the output to the
+/// `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn f32_avx2_sqrt_to(x_ptr: *const f32, result: *mut f32, len: usize) {
+    let offset_from = len % 32;
+    f32_avx2_sqrt_blocks_to(x_ptr, result, len - offset_from);
+
+    let mut i = len - offset_from;
+    while i < len {
+        *result.add(i) = (*x_ptr.add(i)).sqrt();
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Computes the square root of the first `len` values read from `x_ptr` in blocks of
+/// `32`, `len` must be a multiple of `32`.
+unsafe fn f32_avx2_sqrt_blocks_to(x_ptr: *const f32, result: *mut f32, len: usize) {
+    let mut i = 0;
+    while i < len {
+        let v1 = _mm256_loadu_ps(x_ptr.add(i));
+        let v2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let v3 = _mm256_loadu_ps(x_ptr.add(i + 16));
+        let v4 = _mm256_loadu_ps(x_ptr.add(i + 24));
+
+        _mm256_storeu_ps(result.add(i), _mm256_sqrt_ps(v1));
+        _mm256_storeu_ps(result.add(i + 8), _mm256_sqrt_ps(v2));
+        _mm256_storeu_ps(result.add(i + 16), _mm256_sqrt_ps(v3));
+        _mm256_storeu_ps(result.add(i + 24), _mm256_sqrt_ps(v4));
+
+        i += 32;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_sqrt() {
+        let (mut x, _) = get_sample_vectors::<f32>(131);
+        x[3] = -1.0;
+        let expected = x.iter().map(|v| v.sqrt()).collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; x.len()];
+        unsafe { f32_xany_avx2_nofma_sqrt(&x, &mut result) };
+        assert!(result[3].is_nan());
+        assert_eq!(&result[4..], &expected[4..]);
+
+        unsafe { f32_xany_avx2_nofma_sqrt_inplace(&mut x) };
+        assert_eq!(&x[4..], &expected[4..]);
+    }
+
+    #[test]
+    fn test_xconst_nofma_sqrt() {
+        let (mut x, _) = get_sample_vectors::<f32>(1024);
+        let expected = x.iter().map(|v| v.sqrt()).collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; x.len()];
+        unsafe { f32_xconst_avx2_nofma_sqrt::<1024>(&x, &mut result) };
+        assert_eq!(result, expected);
+
+        unsafe { f32_xconst_avx2_nofma_sqrt_inplace::<1024>(&mut x) };
+        assert_eq!(x, expected);
+    }
+}
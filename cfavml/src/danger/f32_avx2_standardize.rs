@@ -0,0 +1,114 @@
+use core::arch::x86_64::*;
+
+use crate::danger::{f32_xany_avx2_nofma_sum_sumsq, mean_std_from_sums_f32};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Standardizes the vector in place to zero mean and unit variance, returning the
+/// `(mean, std)` that were removed so the transform can be inverted with
+/// `x * std + mean`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// mean = sum(x) / D
+/// std = sqrt(sum(x * x) / D - mean ** 2)
+///
+/// for i in 0..D:
+///     if std == 0:
+///         x[i] = x[i] - mean
+///     else:
+///         x[i] = (x[i] - mean) / std
+/// ```
+///
+/// The statistics are gathered in a single pass with
+/// [f32_xany_avx2_nofma_sum_sumsq], so the same cancellation caveats apply, followed
+/// by a single affine pass over the vector.
+///
+/// When the standard deviation is zero, i.e. every element is equal, the vector is
+/// only centered and `std` is returned as `0.0` rather than dividing by zero. A
+/// variance within the rounding error of the single pass is treated as zero. An
+/// empty vector is left untouched and returns `(0.0, 0.0)`.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_standardize(x: &mut [f32]) -> (f32, f32) {
+    let (sum, sum_sq) = f32_xany_avx2_nofma_sum_sumsq(x);
+    let (mean, std) = mean_std_from_sums_f32(sum, sum_sq, x.len());
+    let divisor = if std == 0.0 { 1.0 } else { std };
+
+    let len = x.len();
+    let offset_from = len % 16;
+    let x_ptr = x.as_mut_ptr();
+
+    let mean_reg = _mm256_set1_ps(mean);
+    let divisor_reg = _mm256_set1_ps(divisor);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+
+        let x1 = _mm256_div_ps(_mm256_sub_ps(x1, mean_reg), divisor_reg);
+        let x2 = _mm256_div_ps(_mm256_sub_ps(x2, mean_reg), divisor_reg);
+
+        _mm256_storeu_ps(x_ptr.add(i), x1);
+        _mm256_storeu_ps(x_ptr.add(i + 8), x2);
+
+        i += 16;
+    }
+
+    while i < len {
+        let x = x_ptr.add(i);
+        *x = (*x - mean) / divisor;
+
+        i += 1;
+    }
+
+    (mean, std)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::f32_xany_fallback_nofma_standardize;
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    #[test]
+    fn test_xany_nofma_standardize() {
+        for len in [1, 5, 16, 127, 531] {
+            let (mut x, _) = get_sample_vectors::<f32>(len);
+            let mut expected = x.clone();
+
+            let (mean, std) = unsafe { f32_xany_avx2_nofma_standardize(&mut x) };
+            let (expected_mean, expected_std) =
+                unsafe { f32_xany_fallback_nofma_standardize(&mut expected) };
+
+            assert_is_close(mean, expected_mean);
+            assert_is_close(std, expected_std);
+            for (v, e) in x.iter().zip(&expected) {
+                assert!((v - e).abs() < 1e-4, "{v} != {e}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_standardize_zero_variance() {
+        let mut x = vec![3.5; 37];
+
+        let (mean, std) = unsafe { f32_xany_avx2_nofma_standardize(&mut x) };
+        assert_eq!((mean, std), (3.5, 0.0));
+        assert_eq!(x, vec![0.0; 37]);
+
+        let mut x = vec![0.1; 531];
+        let (_, std) = unsafe { f32_xany_avx2_nofma_standardize(&mut x) };
+        assert_eq!(std, 0.0);
+        assert!(x.iter().all(|v| v.abs() < 1e-6));
+
+        let (mean, std) = unsafe { f32_xany_avx2_nofma_standardize(&mut []) };
+        assert_eq!((mean, std), (0.0, 0.0));
+    }
+}
@@ -0,0 +1,351 @@
+use core::arch::x86_64::*;
+
+use crate::danger::sum_avx2_ps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sums every `stride`th element of the vector starting at `start_offset`, this is
+/// intended for interleaved (AoS) data such as `[x, y, z, x, y, z, ...]` where a single
+/// component needs to be reduced without de-interleaving the data first.
+///
+/// Strides of `3` and `4` load the data contiguously and mask out the unused lanes,
+/// all other strides use gather instructions.
+///
+/// ```py
+/// total: f32
+/// x: [f32; D]
+///
+/// for i in 0..count:
+///     total = total + x[start_offset + i * stride]
+/// ```
+///
+/// # Safety
+///
+/// `stride` **MUST** be non-zero and `start_offset + (count - 1) * stride` **MUST**
+/// be within the bounds of `x`, otherwise this routine will become immediately UB
+/// due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_strided_sum(
+    x: &[f32],
+    start_offset: usize,
+    stride: usize,
+    count: usize,
+) -> f32 {
+    debug_assert!(stride > 0, "Stride must be non-zero");
+    debug_assert!(count == 0 || start_offset + (count - 1) * stride < x.len());
+
+    strided_reduce::<false>(x, x, start_offset, stride, count)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of every `stride`th element of two interleaved (AoS)
+/// vectors sharing the same layout, starting at `start_offset`.
+///
+/// Strides of `3` and `4` load the data contiguously and mask out the unused lanes,
+/// all other strides use gather instructions.
+///
+/// ```py
+/// total: f32
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..count:
+///     j = start_offset + i * stride
+///     total = total + (x[j] * y[j])
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, `stride` **MUST** be non-zero and
+/// `start_offset + (count - 1) * stride` **MUST** be within the bounds of the vectors,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_strided_dot(
+    x: &[f32],
+    y: &[f32],
+    start_offset: usize,
+    stride: usize,
+    count: usize,
+) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert!(stride > 0, "Stride must be non-zero");
+    debug_assert!(count == 0 || start_offset + (count - 1) * stride < x.len());
+
+    strided_reduce::<true>(x, y, start_offset, stride, count)
+}
+
+#[inline(always)]
+unsafe fn strided_reduce<const DOT: bool>(
+    x: &[f32],
+    y: &[f32],
+    start_offset: usize,
+    stride: usize,
+    count: usize,
+) -> f32 {
+    if count == 0 {
+        return 0.0;
+    }
+
+    // The contiguous paths read up to the end of each block of 8 elements, which
+    // can run past the last strided element, so blocks are only taken while they
+    // are fully within the bounds of the vectors.
+    let available = x.len() - start_offset;
+    let x = x.as_ptr().add(start_offset);
+    let y = y.as_ptr().add(start_offset);
+
+    let (mut total, mut i) = match stride {
+        3 => reduce_stride3::<DOT>(x, y, available / 24 * 8, count),
+        4 => reduce_stride4::<DOT>(x, y, available / 32 * 8, count),
+        _ => reduce_gather::<DOT>(x, y, stride, count),
+    };
+
+    while i < count {
+        total += load_scalar::<DOT>(x, y, i * stride);
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+/// Reduces elements with a stride of 3, each block of 8 elements spans 3 registers
+/// with the wanted lanes falling at a different position in each register.
+unsafe fn reduce_stride3<const DOT: bool>(
+    x: *const f32,
+    y: *const f32,
+    max_elements: usize,
+    count: usize,
+) -> (f32, usize) {
+    let limit = count.min(max_elements);
+    let limit = limit - (limit % 8);
+
+    let mut acc1 = _mm256_setzero_ps();
+    let mut acc2 = _mm256_setzero_ps();
+    let mut acc3 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < limit {
+        let offset = i * 3;
+        acc1 = _mm256_add_ps(acc1, load_contiguous::<DOT>(x, y, offset));
+        acc2 = _mm256_add_ps(acc2, load_contiguous::<DOT>(x, y, offset + 8));
+        acc3 = _mm256_add_ps(acc3, load_contiguous::<DOT>(x, y, offset + 16));
+
+        i += 8;
+    }
+
+    let mask1 = _mm256_castsi256_ps(_mm256_setr_epi32(-1, 0, 0, -1, 0, 0, -1, 0));
+    let mask2 = _mm256_castsi256_ps(_mm256_setr_epi32(0, -1, 0, 0, -1, 0, 0, -1));
+    let mask3 = _mm256_castsi256_ps(_mm256_setr_epi32(0, 0, -1, 0, 0, -1, 0, 0));
+
+    let acc = _mm256_add_ps(
+        _mm256_and_ps(acc1, mask1),
+        _mm256_add_ps(_mm256_and_ps(acc2, mask2), _mm256_and_ps(acc3, mask3)),
+    );
+
+    (sum_avx2_ps(acc), i)
+}
+
+#[inline(always)]
+/// Reduces elements with a stride of 4, each register holds 2 wanted lanes
+/// at positions `0` and `4`.
+unsafe fn reduce_stride4<const DOT: bool>(
+    x: *const f32,
+    y: *const f32,
+    max_elements: usize,
+    count: usize,
+) -> (f32, usize) {
+    let limit = count.min(max_elements);
+    let limit = limit - (limit % 8);
+
+    let mut acc1 = _mm256_setzero_ps();
+    let mut acc2 = _mm256_setzero_ps();
+    let mut acc3 = _mm256_setzero_ps();
+    let mut acc4 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < limit {
+        let offset = i * 4;
+        acc1 = _mm256_add_ps(acc1, load_contiguous::<DOT>(x, y, offset));
+        acc2 = _mm256_add_ps(acc2, load_contiguous::<DOT>(x, y, offset + 8));
+        acc3 = _mm256_add_ps(acc3, load_contiguous::<DOT>(x, y, offset + 16));
+        acc4 = _mm256_add_ps(acc4, load_contiguous::<DOT>(x, y, offset + 24));
+
+        i += 8;
+    }
+
+    let mask = _mm256_castsi256_ps(_mm256_setr_epi32(-1, 0, 0, 0, -1, 0, 0, 0));
+    let acc = _mm256_add_ps(_mm256_add_ps(acc1, acc2), _mm256_add_ps(acc3, acc4));
+
+    (sum_avx2_ps(_mm256_and_ps(acc, mask)), i)
+}
+
+#[inline(always)]
+/// Reduces elements of any stride by gathering 8 elements at a time.
+unsafe fn reduce_gather<const DOT: bool>(
+    x: *const f32,
+    y: *const f32,
+    stride: usize,
+    count: usize,
+) -> (f32, usize) {
+    // Gather offsets are `i32`, anything larger is left to the scalar tail.
+    if stride > (i32::MAX as usize / 8) {
+        return (0.0, 0);
+    }
+
+    let step = stride as i32;
+    let index = _mm256_setr_epi32(
+        0,
+        step,
+        step * 2,
+        step * 3,
+        step * 4,
+        step * 5,
+        step * 6,
+        step * 7,
+    );
+
+    let limit = count - (count % 8);
+
+    let mut acc1 = _mm256_setzero_ps();
+    let mut acc2 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < limit {
+        let offset = i * stride;
+        let v = if DOT {
+            let a = _mm256_i32gather_ps::<4>(x.add(offset), index);
+            let b = _mm256_i32gather_ps::<4>(y.add(offset), index);
+            _mm256_mul_ps(a, b)
+        } else {
+            _mm256_i32gather_ps::<4>(x.add(offset), index)
+        };
+
+        // Alternate accumulators to hide some of the gather latency.
+        if (i / 8) % 2 == 0 {
+            acc1 = _mm256_add_ps(acc1, v);
+        } else {
+            acc2 = _mm256_add_ps(acc2, v);
+        }
+
+        i += 8;
+    }
+
+    (sum_avx2_ps(_mm256_add_ps(acc1, acc2)), i)
+}
+
+#[inline(always)]
+unsafe fn load_contiguous<const DOT: bool>(
+    x: *const f32,
+    y: *const f32,
+    offset: usize,
+) -> __m256 {
+    if DOT {
+        _mm256_mul_ps(
+            _mm256_loadu_ps(x.add(offset)),
+            _mm256_loadu_ps(y.add(offset)),
+        )
+    } else {
+        _mm256_loadu_ps(x.add(offset))
+    }
+}
+
+#[inline(always)]
+unsafe fn load_scalar<const DOT: bool>(
+    x: *const f32,
+    y: *const f32,
+    offset: usize,
+) -> f32 {
+    if DOT {
+        *x.add(offset) * *y.add(offset)
+    } else {
+        *x.add(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    fn simple_strided_sum(
+        x: &[f32],
+        start_offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> f32 {
+        (0..count).map(|i| x[start_offset + i * stride]).sum()
+    }
+
+    fn simple_strided_dot(
+        x: &[f32],
+        y: &[f32],
+        start_offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> f32 {
+        (0..count)
+            .map(|i| x[start_offset + i * stride] * y[start_offset + i * stride])
+            .sum()
+    }
+
+    #[test]
+    fn test_f32_xany_strided_sum() {
+        for stride in [1, 2, 3, 4, 5, 7, 16] {
+            for start_offset in 0..stride {
+                for count in [0usize, 1, 7, 8, 63, 64, 333] {
+                    // Exactly enough data for the last element so the contiguous paths
+                    // are forced to stop short of the end.
+                    let len = start_offset + count.saturating_sub(1) * stride + 1;
+                    let (x, _) = get_sample_vectors(len);
+
+                    let expected = simple_strided_sum(&x, start_offset, stride, count);
+                    let res = unsafe {
+                        f32_xany_avx2_nofma_strided_sum(&x, start_offset, stride, count)
+                    };
+                    assert_is_close(res, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_f32_xany_strided_dot() {
+        for stride in [1, 2, 3, 4, 5, 7, 16] {
+            for start_offset in 0..stride {
+                for count in [0usize, 1, 7, 8, 63, 64, 333] {
+                    let len = start_offset + count.saturating_sub(1) * stride + 1;
+                    let (x, y) = get_sample_vectors(len);
+
+                    let expected =
+                        simple_strided_dot(&x, &y, start_offset, stride, count);
+                    let res = unsafe {
+                        f32_xany_avx2_nofma_strided_dot(
+                            &x,
+                            &y,
+                            start_offset,
+                            stride,
+                            count,
+                        )
+                    };
+                    assert_is_close(res, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_f32_xany_strided_sum_ignores_other_components() {
+        let mut x = vec![0.0; 3 * 100];
+        for (i, v) in x.iter_mut().enumerate() {
+            *v = if i % 3 == 1 { 1.0 } else { f32::NAN };
+        }
+
+        let res = unsafe { f32_xany_avx2_nofma_strided_sum(&x, 1, 3, 100) };
+        assert_eq!(res, 100.0);
+    }
+}
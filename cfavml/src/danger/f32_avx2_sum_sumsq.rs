@@ -0,0 +1,192 @@
+use core::arch::x86_64::*;
+
+use crate::danger::sum_avx2_ps;
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes both the sum and the sum of squares of one `f32` vector in a single pass,
+/// returning `(sum(x), sum(x * x))`.
+///
+/// ```py
+/// D: int
+/// sum: f32
+/// sum_sq: f32
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     sum = sum + x[i]
+///     sum_sq = sum_sq + (x[i] * x[i])
+/// ```
+///
+/// These values can be used to compute the variance as `E[x²] - E[x]²` with a single
+/// pass over memory, however, this formula suffers from catastrophic cancellation
+/// when the mean is large relative to the variance. Where precision matters more than
+/// speed, a two-pass or Welford style computation should be used instead.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_sum_sumsq(x: &[f32]) -> (f32, f32) {
+    let len = x.len();
+    let offset_from = len % 32;
+    let x_ptr = x.as_ptr();
+
+    let mut sum0 = _mm256_setzero_ps();
+    let mut sum1 = _mm256_setzero_ps();
+    let mut sum2 = _mm256_setzero_ps();
+    let mut sum3 = _mm256_setzero_ps();
+    let mut sumsq0 = _mm256_setzero_ps();
+    let mut sumsq1 = _mm256_setzero_ps();
+    let mut sumsq2 = _mm256_setzero_ps();
+    let mut sumsq3 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x0 = _mm256_loadu_ps(x_ptr.add(i));
+        let x1 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 16));
+        let x3 = _mm256_loadu_ps(x_ptr.add(i + 24));
+
+        sum0 = _mm256_add_ps(sum0, x0);
+        sum1 = _mm256_add_ps(sum1, x1);
+        sum2 = _mm256_add_ps(sum2, x2);
+        sum3 = _mm256_add_ps(sum3, x3);
+        sumsq0 = _mm256_add_ps(sumsq0, _mm256_mul_ps(x0, x0));
+        sumsq1 = _mm256_add_ps(sumsq1, _mm256_mul_ps(x1, x1));
+        sumsq2 = _mm256_add_ps(sumsq2, _mm256_mul_ps(x2, x2));
+        sumsq3 = _mm256_add_ps(sumsq3, _mm256_mul_ps(x3, x3));
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+        sum0 = _mm256_add_ps(sum0, x);
+        sumsq0 = _mm256_add_ps(sumsq0, _mm256_mul_ps(x, x));
+
+        i += 8;
+    }
+
+    sum0 = _mm256_add_ps(_mm256_add_ps(sum0, sum1), _mm256_add_ps(sum2, sum3));
+    sumsq0 = _mm256_add_ps(_mm256_add_ps(sumsq0, sumsq1), _mm256_add_ps(sumsq2, sumsq3));
+
+    let mut sum = sum_avx2_ps(sum0);
+    let mut sum_sq = sum_avx2_ps(sumsq0);
+
+    while i < len {
+        let x = *x.get_unchecked(i);
+        sum = AutoMath::add(sum, x);
+        sum_sq = AutoMath::add(sum_sq, AutoMath::mul(x, x));
+
+        i += 1;
+    }
+
+    (sum, sum_sq)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes both the sum and the sum of squares of one `f32` vector in a single pass,
+/// returning `(sum(x), sum(x * x))`.
+///
+/// ```py
+/// D: int
+/// sum: f32
+/// sum_sq: f32
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     sum = sum + x[i]
+///     sum_sq = sum_sq + (x[i] * x[i])
+/// ```
+///
+/// These values can be used to compute the variance as `E[x²] - E[x]²` with a single
+/// pass over memory, however, this formula suffers from catastrophic cancellation
+/// when the mean is large relative to the variance. Where precision matters more than
+/// speed, a two-pass or Welford style computation should be used instead.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_fma_sum_sumsq(x: &[f32]) -> (f32, f32) {
+    let len = x.len();
+    let offset_from = len % 32;
+    let x_ptr = x.as_ptr();
+
+    let mut sum0 = _mm256_setzero_ps();
+    let mut sum1 = _mm256_setzero_ps();
+    let mut sum2 = _mm256_setzero_ps();
+    let mut sum3 = _mm256_setzero_ps();
+    let mut sumsq0 = _mm256_setzero_ps();
+    let mut sumsq1 = _mm256_setzero_ps();
+    let mut sumsq2 = _mm256_setzero_ps();
+    let mut sumsq3 = _mm256_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x0 = _mm256_loadu_ps(x_ptr.add(i));
+        let x1 = _mm256_loadu_ps(x_ptr.add(i + 8));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 16));
+        let x3 = _mm256_loadu_ps(x_ptr.add(i + 24));
+
+        sum0 = _mm256_add_ps(sum0, x0);
+        sum1 = _mm256_add_ps(sum1, x1);
+        sum2 = _mm256_add_ps(sum2, x2);
+        sum3 = _mm256_add_ps(sum3, x3);
+        sumsq0 = _mm256_fmadd_ps(x0, x0, sumsq0);
+        sumsq1 = _mm256_fmadd_ps(x1, x1, sumsq1);
+        sumsq2 = _mm256_fmadd_ps(x2, x2, sumsq2);
+        sumsq3 = _mm256_fmadd_ps(x3, x3, sumsq3);
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let x = _mm256_loadu_ps(x_ptr.add(i));
+        sum0 = _mm256_add_ps(sum0, x);
+        sumsq0 = _mm256_fmadd_ps(x, x, sumsq0);
+
+        i += 8;
+    }
+
+    sum0 = _mm256_add_ps(_mm256_add_ps(sum0, sum1), _mm256_add_ps(sum2, sum3));
+    sumsq0 = _mm256_add_ps(_mm256_add_ps(sumsq0, sumsq1), _mm256_add_ps(sumsq2, sumsq3));
+
+    let mut sum = sum_avx2_ps(sum0);
+    let mut sum_sq = sum_avx2_ps(sumsq0);
+
+    while i < len {
+        let x = *x.get_unchecked(i);
+        sum = AutoMath::add(sum, x);
+        sum_sq = AutoMath::add(sum_sq, AutoMath::mul(x, x));
+
+        i += 1;
+    }
+
+    (sum, sum_sq)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_xany_nofma_sum_sumsq() {
+        let (x, _) = get_sample_vectors::<f32>(131);
+        let (sum, sum_sq) = unsafe { f32_xany_avx2_nofma_sum_sumsq(&x) };
+        assert_is_close(sum, x.iter().sum::<f32>());
+        assert_is_close(sum_sq, simple_dot(&x, &x));
+    }
+
+    #[test]
+    fn test_xany_fma_sum_sumsq() {
+        let (x, _) = get_sample_vectors::<f32>(131);
+        let (sum, sum_sq) = unsafe { f32_xany_avx2_fma_sum_sumsq(&x) };
+        assert_is_close(sum, x.iter().sum::<f32>());
+        assert_is_close(sum_sq, simple_dot(&x, &x));
+    }
+}
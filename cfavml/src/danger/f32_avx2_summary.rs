@@ -0,0 +1,135 @@
+use core::arch::x86_64::*;
+use core::mem;
+
+use crate::danger::{sum_avx2_ps, Summary};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the sum, minimum, maximum, count and mean of the vector in a single pass.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// return Summary(
+///     sum=sum(x),
+///     min=min(x),
+///     max=max(x),
+///     count=D,
+///     mean=sum(x) / D,
+/// )
+/// ```
+///
+/// Separate accumulators are kept for each statistic so every element is only loaded
+/// once, rather than once per statistic as when calling the individual routines.
+///
+/// An empty vector produces a [Summary] with a `sum` of `0.0`, a `min` of `+inf`, a
+/// `max` of `-inf`, a `count` of `0` and a `mean` of `NaN`. Like the `min` and `max`
+/// routines, the minimum and maximum of a vector containing `NaN` are unspecified.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_summary(x: &[f32]) -> Summary<f32> {
+    let len = x.len();
+    let offset_from = len % 32;
+    let x_ptr = x.as_ptr();
+
+    let mut sums = [_mm256_setzero_ps(); 4];
+    let mut mins = [_mm256_set1_ps(f32::INFINITY); 4];
+    let mut maxs = [_mm256_set1_ps(f32::NEG_INFINITY); 4];
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for k in 0..4 {
+            let v = _mm256_loadu_ps(x_ptr.add(i + k * 8));
+            sums[k] = _mm256_add_ps(sums[k], v);
+            mins[k] = _mm256_min_ps(mins[k], v);
+            maxs[k] = _mm256_max_ps(maxs[k], v);
+        }
+
+        i += 32;
+    }
+
+    while i < (len - (len % 8)) {
+        let v = _mm256_loadu_ps(x_ptr.add(i));
+        sums[0] = _mm256_add_ps(sums[0], v);
+        mins[0] = _mm256_min_ps(mins[0], v);
+        maxs[0] = _mm256_max_ps(maxs[0], v);
+
+        i += 8;
+    }
+
+    let sum = _mm256_add_ps(
+        _mm256_add_ps(sums[0], sums[1]),
+        _mm256_add_ps(sums[2], sums[3]),
+    );
+    let min = _mm256_min_ps(
+        _mm256_min_ps(mins[0], mins[1]),
+        _mm256_min_ps(mins[2], mins[3]),
+    );
+    let max = _mm256_max_ps(
+        _mm256_max_ps(maxs[0], maxs[1]),
+        _mm256_max_ps(maxs[2], maxs[3]),
+    );
+
+    let mut sum = sum_avx2_ps(sum);
+    let mut min = mem::transmute::<__m256, [f32; 8]>(min)
+        .into_iter()
+        .fold(f32::INFINITY, f32::min);
+    let mut max = mem::transmute::<__m256, [f32; 8]>(max)
+        .into_iter()
+        .fold(f32::NEG_INFINITY, f32::max);
+
+    while i < len {
+        let v = *x_ptr.add(i);
+        sum += v;
+        min = min.min(v);
+        max = max.max(v);
+
+        i += 1;
+    }
+
+    Summary {
+        sum,
+        min,
+        max,
+        count: len,
+        mean: sum / len as f32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    #[test]
+    fn test_xany_nofma_summary() {
+        for len in [1, 7, 8, 33, 547] {
+            let (x, _) = get_sample_vectors::<f32>(len);
+            let summary = unsafe { f32_xany_avx2_nofma_summary(&x) };
+
+            let sum = x.iter().sum::<f32>();
+            assert_is_close(summary.sum, sum);
+            assert_eq!(summary.min, x.iter().copied().fold(f32::INFINITY, f32::min));
+            assert_eq!(
+                summary.max,
+                x.iter().copied().fold(f32::NEG_INFINITY, f32::max)
+            );
+            assert_eq!(summary.count, len);
+            assert_is_close(summary.mean, sum / len as f32);
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_summary_empty() {
+        let summary = unsafe { f32_xany_avx2_nofma_summary(&[]) };
+        assert_eq!(summary.sum, 0.0);
+        assert_eq!(summary.min, f32::INFINITY);
+        assert_eq!(summary.max, f32::NEG_INFINITY);
+        assert_eq!(summary.count, 0);
+        assert!(summary.mean.is_nan());
+    }
+}
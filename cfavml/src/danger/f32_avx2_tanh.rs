@@ -0,0 +1,154 @@
+use core::arch::x86_64::*;
+use core::ptr;
+
+use crate::danger::partially_overlaps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the hyperbolic tangent of each element of the vector, writing the result
+/// to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = tanh(x[i])
+/// ```
+///
+/// `tanh` is evaluated with a degree 13 / degree 6 rational approximation after clamping
+/// the input to `[-7.9, 7.9]`, beyond which the result is `±1`, the relative error is
+/// within a few ULP. Inputs smaller than `4e-4` in magnitude are returned as is and
+/// `NaN` is propagated.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_tanh(x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    f32_avx2_tanh_to(x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the hyperbolic tangent of each element of the vector in place.
+///
+/// See [f32_xany_avx2_nofma_tanh] for the accuracy of the approximation.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_tanh_inplace(x: &mut [f32]) {
+    let x_ptr = x.as_mut_ptr();
+    f32_avx2_tanh_to(x_ptr, x_ptr, x.len())
+}
+
+#[inline(always)]
+/// Computes `tanh(x)` of the values read from `x_ptr` writing the output to the
+/// `result` pointer, the tail is padded out to a full register so every element goes
+/// through the same instructions.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn f32_avx2_tanh_to(x_ptr: *const f32, result: *mut f32, len: usize) {
+    let offset_from = len % 16;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_ps(x_ptr.add(i));
+        let x2 = _mm256_loadu_ps(x_ptr.add(i + 8));
+
+        _mm256_storeu_ps(result.add(i), tanh_ps(x1));
+        _mm256_storeu_ps(result.add(i + 8), tanh_ps(x2));
+
+        i += 16;
+    }
+
+    while i < len {
+        let n = (len - i).min(8);
+        let mut tail = [0.0f32; 8];
+        ptr::copy_nonoverlapping(x_ptr.add(i), tail.as_mut_ptr(), n);
+
+        let v = tanh_ps(_mm256_loadu_ps(tail.as_ptr()));
+        _mm256_storeu_ps(tail.as_mut_ptr(), v);
+        ptr::copy_nonoverlapping(tail.as_ptr(), result.add(i), n);
+
+        i += n;
+    }
+}
+
+#[inline(always)]
+/// Computes `tanh(x)` with a rational approximation, `P(x) / Q(x)` with an odd
+/// polynomial `P` of degree 13 and even polynomial `Q` of degree 6.
+unsafe fn tanh_ps(x: __m256) -> __m256 {
+    let clamped = _mm256_min_ps(_mm256_set1_ps(7.905_311), x);
+    let clamped = _mm256_max_ps(_mm256_set1_ps(-7.905_311), clamped);
+    let x2 = _mm256_mul_ps(clamped, clamped);
+
+    let mut p = _mm256_set1_ps(-2.760_768_5e-16);
+    for coeff in [
+        2.000_188e-13,
+        -8.604_672e-11,
+        5.122_297e-8,
+        1.485_722_4e-5,
+        6.372_619e-4,
+        4.893_524_6e-3,
+    ] {
+        p = _mm256_add_ps(_mm256_mul_ps(p, x2), _mm256_set1_ps(coeff));
+    }
+    let p = _mm256_mul_ps(p, clamped);
+
+    let mut q = _mm256_set1_ps(1.198_258_4e-6);
+    for coeff in [1.185_347_1e-4, 2.268_434_6e-3, 4.893_525e-3] {
+        q = _mm256_add_ps(_mm256_mul_ps(q, x2), _mm256_set1_ps(coeff));
+    }
+
+    let sign_mask = _mm256_set1_ps(-0.0);
+    let abs = _mm256_andnot_ps(sign_mask, x);
+    let saturated = _mm256_or_ps(_mm256_set1_ps(1.0), _mm256_and_ps(sign_mask, x));
+
+    // Tiny inputs are returned as is, `tanh(x) = x` to within rounding there, and
+    // inputs beyond the clamp are exactly `±1`.
+    let tiny = _mm256_cmp_ps::<_CMP_LT_OQ>(abs, _mm256_set1_ps(4e-4));
+    let large = _mm256_cmp_ps::<_CMP_GE_OQ>(abs, _mm256_set1_ps(7.905_311));
+    let y = _mm256_blendv_ps(_mm256_div_ps(p, q), x, tiny);
+    _mm256_blendv_ps(y, saturated, large)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_tanh() {
+        let mut x = (0..531)
+            .map(|i| (i as f32 - 265.0) / 20.0)
+            .collect::<Vec<_>>();
+        x.extend([-10.0, 10.0, 1e-5, -0.0, f32::NEG_INFINITY, f32::NAN]);
+
+        let mut result = vec![0.0; x.len()];
+        unsafe { f32_xany_avx2_nofma_tanh(&x, &mut result) };
+        for (v, r) in x.iter().zip(&result).take(533) {
+            let e = v.tanh();
+            assert!((r - e).abs() <= 2e-6 * e.abs(), "tanh({v}) = {r} != {e}");
+        }
+        assert_eq!(&result[531..533], [-1.0, 1.0]);
+        assert_eq!(result[533], 1e-5);
+        assert!(result[534] == 0.0 && result[534].is_sign_negative());
+        assert_eq!(result[535], -1.0);
+        assert!(result[536].is_nan());
+
+        unsafe { f32_xany_avx2_nofma_tanh_inplace(&mut x) };
+        assert_eq!(&x[..536], &result[..536]);
+    }
+}
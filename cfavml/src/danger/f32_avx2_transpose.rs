@@ -0,0 +1,174 @@
+use core::arch::x86_64::*;
+
+/// The number of rows and columns processed per cache block.
+const BLOCK_SIZE: usize = 64;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Transposes the row-major `[f32; rows * cols]` matrix `src` writing the
+/// row-major `[f32; cols * rows]` result to `dst`.
+///
+/// ```py
+/// rows: int
+/// cols: int
+/// src: [f32; rows * cols]
+/// dst: [f32; cols * rows]
+///
+/// for r in 0..rows:
+///     for c in 0..cols:
+///         dst[c * rows + r] = src[r * cols + c]
+/// ```
+///
+/// The matrix is processed in cache sized blocks of `8x8` tiles which are
+/// transposed in-register, any rows or columns which do not fill a complete
+/// tile are handled by a scalar border.
+///
+/// # Safety
+///
+/// Both `src` and `dst` **MUST** be `rows * cols` in length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_transpose(
+    src: &[f32],
+    rows: usize,
+    cols: usize,
+    dst: &mut [f32],
+) {
+    debug_assert_eq!(src.len(), rows * cols);
+    debug_assert_eq!(dst.len(), rows * cols);
+
+    let tiled_rows = rows - (rows % 8);
+    let tiled_cols = cols - (cols % 8);
+
+    let src_ptr = src.as_ptr();
+    let dst_ptr = dst.as_mut_ptr();
+
+    let mut block_r = 0;
+    while block_r < tiled_rows {
+        let block_r_end = (block_r + BLOCK_SIZE).min(tiled_rows);
+
+        let mut block_c = 0;
+        while block_c < tiled_cols {
+            let block_c_end = (block_c + BLOCK_SIZE).min(tiled_cols);
+
+            let mut r = block_r;
+            while r < block_r_end {
+                let mut c = block_c;
+                while c < block_c_end {
+                    transpose_f32_8x8_tile(
+                        src_ptr.add(r * cols + c),
+                        cols,
+                        dst_ptr.add(c * rows + r),
+                        rows,
+                    );
+
+                    c += 8;
+                }
+
+                r += 8;
+            }
+
+            block_c += BLOCK_SIZE;
+        }
+
+        block_r += BLOCK_SIZE;
+    }
+
+    // Right hand border, covering the tiled rows only.
+    for r in 0..tiled_rows {
+        for c in tiled_cols..cols {
+            *dst.get_unchecked_mut(c * rows + r) = *src.get_unchecked(r * cols + c);
+        }
+    }
+
+    // Bottom border, covering every column.
+    for r in tiled_rows..rows {
+        for c in 0..cols {
+            *dst.get_unchecked_mut(c * rows + r) = *src.get_unchecked(r * cols + c);
+        }
+    }
+}
+
+#[inline(always)]
+/// Transposes a single `8x8` tile, reading rows `src_stride` elements apart and
+/// writing rows `dst_stride` elements apart.
+unsafe fn transpose_f32_8x8_tile(
+    src: *const f32,
+    src_stride: usize,
+    dst: *mut f32,
+    dst_stride: usize,
+) {
+    let r0 = _mm256_loadu_ps(src);
+    let r1 = _mm256_loadu_ps(src.add(src_stride));
+    let r2 = _mm256_loadu_ps(src.add(src_stride * 2));
+    let r3 = _mm256_loadu_ps(src.add(src_stride * 3));
+    let r4 = _mm256_loadu_ps(src.add(src_stride * 4));
+    let r5 = _mm256_loadu_ps(src.add(src_stride * 5));
+    let r6 = _mm256_loadu_ps(src.add(src_stride * 6));
+    let r7 = _mm256_loadu_ps(src.add(src_stride * 7));
+
+    let t0 = _mm256_unpacklo_ps(r0, r1);
+    let t1 = _mm256_unpackhi_ps(r0, r1);
+    let t2 = _mm256_unpacklo_ps(r2, r3);
+    let t3 = _mm256_unpackhi_ps(r2, r3);
+    let t4 = _mm256_unpacklo_ps(r4, r5);
+    let t5 = _mm256_unpackhi_ps(r4, r5);
+    let t6 = _mm256_unpacklo_ps(r6, r7);
+    let t7 = _mm256_unpackhi_ps(r6, r7);
+
+    let s0 = _mm256_shuffle_ps::<0x44>(t0, t2);
+    let s1 = _mm256_shuffle_ps::<0xEE>(t0, t2);
+    let s2 = _mm256_shuffle_ps::<0x44>(t1, t3);
+    let s3 = _mm256_shuffle_ps::<0xEE>(t1, t3);
+    let s4 = _mm256_shuffle_ps::<0x44>(t4, t6);
+    let s5 = _mm256_shuffle_ps::<0xEE>(t4, t6);
+    let s6 = _mm256_shuffle_ps::<0x44>(t5, t7);
+    let s7 = _mm256_shuffle_ps::<0xEE>(t5, t7);
+
+    _mm256_storeu_ps(dst, _mm256_permute2f128_ps::<0x20>(s0, s4));
+    _mm256_storeu_ps(dst.add(dst_stride), _mm256_permute2f128_ps::<0x20>(s1, s5));
+    _mm256_storeu_ps(
+        dst.add(dst_stride * 2),
+        _mm256_permute2f128_ps::<0x20>(s2, s6),
+    );
+    _mm256_storeu_ps(
+        dst.add(dst_stride * 3),
+        _mm256_permute2f128_ps::<0x20>(s3, s7),
+    );
+    _mm256_storeu_ps(
+        dst.add(dst_stride * 4),
+        _mm256_permute2f128_ps::<0x31>(s0, s4),
+    );
+    _mm256_storeu_ps(
+        dst.add(dst_stride * 5),
+        _mm256_permute2f128_ps::<0x31>(s1, s5),
+    );
+    _mm256_storeu_ps(
+        dst.add(dst_stride * 6),
+        _mm256_permute2f128_ps::<0x31>(s2, s6),
+    );
+    _mm256_storeu_ps(
+        dst.add(dst_stride * 7),
+        _mm256_permute2f128_ps::<0x31>(s3, s7),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{get_sample_vectors, simple_transpose};
+
+    #[test]
+    fn test_xany_nofma_transpose() {
+        for (rows, cols) in [(8, 8), (16, 24), (3, 5), (67, 131), (130, 9), (1, 17)] {
+            let (src, _) = get_sample_vectors::<f32>(rows * cols);
+            let expected = simple_transpose(&src, rows, cols);
+
+            let mut dst = vec![0.0; rows * cols];
+            unsafe { f32_xany_avx2_nofma_transpose(&src, rows, cols, &mut dst) };
+            assert_eq!(dst, expected, "rows: {rows}, cols: {cols}");
+        }
+    }
+}
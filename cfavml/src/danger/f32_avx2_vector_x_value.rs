@@ -25,6 +25,27 @@ macro_rules! complete_tail {
             }
         }
     }};
+    ($offset_from:expr, $i:expr, $arr:ident, $value:expr, $value_reg:expr, $inst:ident, func = $func:path) => {{
+        if $offset_from != 0 {
+            let len = $arr.len();
+            let arr_ptr = $arr.as_mut_ptr();
+            let remainder = $offset_from % 8;
+
+            while $i < (len - remainder) {
+                let x = _mm256_loadu_ps(arr_ptr.add($i));
+                let r = $inst(x, $value_reg);
+                copy_avx2_ps_register_to(arr_ptr.add($i), r);
+
+                $i += 8;
+            }
+
+            while $i < len {
+                let x = $arr.get_unchecked_mut($i);
+                *x = $func(*x, $value);
+                $i += 1;
+            }
+        }
+    }};
 }
 
 #[target_feature(enable = "avx2")]
@@ -46,6 +67,30 @@ pub unsafe fn f32_xconst_avx2_nofma_div_value<const DIMS: usize>(
     f32_xconst_avx2_nofma_mul_value::<DIMS>(arr, 1.0 / divider)
 }
 
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Approximately divides each element in the provided mutable `[f32; DIMS]` vector by `value`.
+///
+/// This computes the reciprocal of `value` once and multiplies each element by it,
+/// so the result may differ from the correctly rounded quotient by 1 ULP.
+/// [f32_xconst_avx2_nofma_div_value] currently uses the same reciprocal
+/// multiply, this routine is an explicit alias for callers relying on that behaviour.
+///
+/// # Safety
+///
+/// Vectors **MUST** be `DIMS` elements in length and divisible by 64,
+/// otherwise this function becomes immediately UB due to out of bounds
+/// access.
+///
+/// This method assumes avx2 instructions are available, if this method is executed
+/// on non-avx2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xconst_avx2_nofma_fast_div_value<const DIMS: usize>(
+    arr: &mut [f32],
+    divider: f32,
+) {
+    f32_xconst_avx2_nofma_mul_value::<DIMS>(arr, 1.0 / divider)
+}
+
 #[target_feature(enable = "avx2")]
 #[inline]
 /// Divides each element in the provided mutable `f32` vector by `value`.
@@ -58,6 +103,23 @@ pub unsafe fn f32_xany_avx2_nofma_div_value(arr: &mut [f32], divider: f32) {
     f32_xany_avx2_nofma_mul_value(arr, 1.0 / divider)
 }
 
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Approximately divides each element in the provided mutable `f32` vector by `value`.
+///
+/// This computes the reciprocal of `value` once and multiplies each element by it,
+/// so the result may differ from the correctly rounded quotient by 1 ULP.
+/// [f32_xany_avx2_nofma_div_value] currently uses the same reciprocal
+/// multiply, this routine is an explicit alias for callers relying on that behaviour.
+///
+/// # Safety
+///
+/// This method assumes avx2 instructions are available, if this method is executed
+/// on non-avx2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_fast_div_value(arr: &mut [f32], divider: f32) {
+    f32_xany_avx2_nofma_mul_value(arr, 1.0 / divider)
+}
+
 #[target_feature(enable = "avx2")]
 #[inline]
 /// Multiplies each element in the provided mutable `[f32; DIMS]` vector by `value`.
@@ -217,6 +279,132 @@ pub unsafe fn f32_xany_avx2_nofma_sub_value(arr: &mut [f32], value: f32) {
     complete_tail!(offset_from, i, arr, value, value_reg, _mm256_sub_ps, op = -=);
 }
 
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element in the provided mutable `[f32; DIMS]` vector
+/// to be no less than `value`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be `DIMS` elements in length and divisible by 64,
+/// otherwise this function becomes immediately UB due to out of bounds
+/// access.
+///
+/// This method assumes avx2 instructions are available, if this method is executed
+/// on non-avx2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xconst_avx2_nofma_max_value<const DIMS: usize>(
+    arr: &mut [f32],
+    value: f32,
+) {
+    debug_assert_eq!(arr.len(), DIMS);
+    debug_assert_eq!(DIMS % 64, 0, "Input dimensions must be multiple of 64");
+
+    let value = _mm256_set1_ps(value);
+    let arr = arr.as_mut_ptr();
+
+    let mut i = 0;
+    while i < DIMS {
+        execute_f32_x64_max(arr.add(i), value);
+        i += 64;
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element in the provided mutable `f32` vector
+/// to be no less than `value`.
+///
+/// # Safety
+///
+/// This method assumes avx2 instructions are available, if this method is executed
+/// on non-avx2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_max_value(arr: &mut [f32], value: f32) {
+    let len = arr.len();
+    let offset_from = len % 64;
+
+    let value_reg = _mm256_set1_ps(value);
+    let arr_ptr = arr.as_mut_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        execute_f32_x64_max(arr_ptr.add(i), value_reg);
+        i += 64;
+    }
+
+    complete_tail!(
+        offset_from,
+        i,
+        arr,
+        value,
+        value_reg,
+        _mm256_max_ps,
+        func = f32::max
+    );
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element in the provided mutable `[f32; DIMS]` vector
+/// to be no greater than `value`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be `DIMS` elements in length and divisible by 64,
+/// otherwise this function becomes immediately UB due to out of bounds
+/// access.
+///
+/// This method assumes avx2 instructions are available, if this method is executed
+/// on non-avx2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xconst_avx2_nofma_min_value<const DIMS: usize>(
+    arr: &mut [f32],
+    value: f32,
+) {
+    debug_assert_eq!(arr.len(), DIMS);
+    debug_assert_eq!(DIMS % 64, 0, "Input dimensions must be multiple of 64");
+
+    let value = _mm256_set1_ps(value);
+    let arr = arr.as_mut_ptr();
+
+    let mut i = 0;
+    while i < DIMS {
+        execute_f32_x64_min(arr.add(i), value);
+        i += 64;
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element in the provided mutable `f32` vector
+/// to be no greater than `value`.
+///
+/// # Safety
+///
+/// This method assumes avx2 instructions are available, if this method is executed
+/// on non-avx2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx2_nofma_min_value(arr: &mut [f32], value: f32) {
+    let len = arr.len();
+    let offset_from = len % 64;
+
+    let value_reg = _mm256_set1_ps(value);
+    let arr_ptr = arr.as_mut_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        execute_f32_x64_min(arr_ptr.add(i), value_reg);
+        i += 64;
+    }
+
+    complete_tail!(
+        offset_from,
+        i,
+        arr,
+        value,
+        value_reg,
+        _mm256_min_ps,
+        func = f32::min
+    );
+}
+
 #[inline(always)]
 unsafe fn execute_f32_x64_mul(x: *mut f32, multiplier: __m256) {
     let [x1, x2, x3, x4] = offsets_avx2_ps::<CHUNK_0>(x);
@@ -310,6 +498,68 @@ unsafe fn execute_f32_x64_sub(x: *mut f32, value: __m256) {
     ptr::copy_nonoverlapping(result.as_ptr(), x, result.len());
 }
 
+#[inline(always)]
+unsafe fn execute_f32_x64_max(x: *mut f32, value: __m256) {
+    let [x1, x2, x3, x4] = offsets_avx2_ps::<CHUNK_0>(x);
+    let [x5, x6, x7, x8] = offsets_avx2_ps::<CHUNK_1>(x);
+
+    let x1 = _mm256_loadu_ps(x1);
+    let x2 = _mm256_loadu_ps(x2);
+    let x3 = _mm256_loadu_ps(x3);
+    let x4 = _mm256_loadu_ps(x4);
+    let x5 = _mm256_loadu_ps(x5);
+    let x6 = _mm256_loadu_ps(x6);
+    let x7 = _mm256_loadu_ps(x7);
+    let x8 = _mm256_loadu_ps(x8);
+
+    let result1 = _mm256_max_ps(x1, value);
+    let result2 = _mm256_max_ps(x2, value);
+    let result3 = _mm256_max_ps(x3, value);
+    let result4 = _mm256_max_ps(x4, value);
+    let result5 = _mm256_max_ps(x5, value);
+    let result6 = _mm256_max_ps(x6, value);
+    let result7 = _mm256_max_ps(x7, value);
+    let result8 = _mm256_max_ps(x8, value);
+
+    let lanes = [
+        result1, result2, result3, result4, result5, result6, result7, result8,
+    ];
+
+    let result = mem::transmute::<[__m256; 8], [f32; 64]>(lanes);
+    ptr::copy_nonoverlapping(result.as_ptr(), x, result.len());
+}
+
+#[inline(always)]
+unsafe fn execute_f32_x64_min(x: *mut f32, value: __m256) {
+    let [x1, x2, x3, x4] = offsets_avx2_ps::<CHUNK_0>(x);
+    let [x5, x6, x7, x8] = offsets_avx2_ps::<CHUNK_1>(x);
+
+    let x1 = _mm256_loadu_ps(x1);
+    let x2 = _mm256_loadu_ps(x2);
+    let x3 = _mm256_loadu_ps(x3);
+    let x4 = _mm256_loadu_ps(x4);
+    let x5 = _mm256_loadu_ps(x5);
+    let x6 = _mm256_loadu_ps(x6);
+    let x7 = _mm256_loadu_ps(x7);
+    let x8 = _mm256_loadu_ps(x8);
+
+    let result1 = _mm256_min_ps(x1, value);
+    let result2 = _mm256_min_ps(x2, value);
+    let result3 = _mm256_min_ps(x3, value);
+    let result4 = _mm256_min_ps(x4, value);
+    let result5 = _mm256_min_ps(x5, value);
+    let result6 = _mm256_min_ps(x6, value);
+    let result7 = _mm256_min_ps(x7, value);
+    let result8 = _mm256_min_ps(x8, value);
+
+    let lanes = [
+        result1, result2, result3, result4, result5, result6, result7, result8,
+    ];
+
+    let result = mem::transmute::<[__m256; 8], [f32; 64]>(lanes);
+    ptr::copy_nonoverlapping(result.as_ptr(), x, result.len());
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -324,6 +574,15 @@ mod tests {
         assert_is_close_vector(&x, &expected);
     }
 
+    #[test]
+    fn test_f32_xany_fast_div() {
+        let value = 3.0;
+        let (mut x, _) = get_sample_vectors(557);
+        let expected = x.iter().copied().map(|v| v / value).collect::<Vec<_>>();
+        unsafe { f32_xany_avx2_nofma_fast_div_value(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
+
     #[test]
     fn test_f32_xany_mul() {
         let value = 2.0;
@@ -360,6 +619,15 @@ mod tests {
         assert_is_close_vector(&x, &expected);
     }
 
+    #[test]
+    fn test_f32_xconst_fast_div() {
+        let value = 3.0;
+        let (mut x, _) = get_sample_vectors(512);
+        let expected = x.iter().copied().map(|v| v / value).collect::<Vec<_>>();
+        unsafe { f32_xconst_avx2_nofma_fast_div_value::<512>(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
+
     #[test]
     fn test_f32_xconst_mul() {
         let value = 2.0;
@@ -386,4 +654,56 @@ mod tests {
         unsafe { f32_xconst_avx2_nofma_sub_value::<512>(&mut x, value) };
         assert_is_close_vector(&x, &expected);
     }
+
+    #[test]
+    fn test_f32_xany_max_value() {
+        let value = 0.5;
+        let (mut x, _) = get_sample_vectors(557);
+        let expected = x
+            .iter()
+            .copied()
+            .map(|v: f32| v.max(value))
+            .collect::<Vec<_>>();
+        unsafe { f32_xany_avx2_nofma_max_value(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_f32_xconst_max_value() {
+        let value = 0.5;
+        let (mut x, _) = get_sample_vectors(512);
+        let expected = x
+            .iter()
+            .copied()
+            .map(|v: f32| v.max(value))
+            .collect::<Vec<_>>();
+        unsafe { f32_xconst_avx2_nofma_max_value::<512>(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_f32_xany_min_value() {
+        let value = 0.5;
+        let (mut x, _) = get_sample_vectors(557);
+        let expected = x
+            .iter()
+            .copied()
+            .map(|v: f32| v.min(value))
+            .collect::<Vec<_>>();
+        unsafe { f32_xany_avx2_nofma_min_value(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_f32_xconst_min_value() {
+        let value = 0.5;
+        let (mut x, _) = get_sample_vectors(512);
+        let expected = x
+            .iter()
+            .copied()
+            .map(|v: f32| v.min(value))
+            .collect::<Vec<_>>();
+        unsafe { f32_xconst_avx2_nofma_min_value::<512>(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
 }
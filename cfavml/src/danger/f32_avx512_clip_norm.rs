@@ -0,0 +1,68 @@
+use crate::danger::{
+    clip_norm_scale,
+    f32_xany_avx512_fma_l2_norm,
+    f32_xany_avx512_nofma_mul_value,
+};
+use crate::math::*;
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Scales `x` by `max_norm / norm(x)` if the L2 norm of `x` is greater than `max_norm`,
+/// otherwise `x` is left unchanged, returning the original L2 norm of `x`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// norm = l2_norm(x)
+/// if norm > max_norm:
+///     for i in 0..D:
+///         x[i] = x[i] * (max_norm / norm)
+///
+/// return norm
+/// ```
+///
+/// Vectors with a norm of zero are left unchanged.
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_fma_clip_norm(max_norm: f32, x: &mut [f32]) -> f32 {
+    let norm = f32_xany_avx512_fma_l2_norm(x);
+    if let Some(scale) = clip_norm_scale::<f32, AutoMath>(norm, max_norm) {
+        f32_xany_avx512_nofma_mul_value(x, scale);
+    }
+    norm
+}
+
+#[cfg(all(test, target_feature = "avx512f"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        assert_is_close_vector,
+        get_sample_vectors,
+        simple_dot,
+    };
+
+    #[test]
+    fn test_xany_fma_clip_norm() {
+        let (mut x, _) = get_sample_vectors::<f32>(547);
+        let norm = simple_dot(&x, &x).sqrt();
+
+        let original = x.clone();
+        let res = unsafe { f32_xany_avx512_fma_clip_norm(norm * 2.0, &mut x) };
+        assert_is_close(res, norm);
+        assert_is_close_vector(&x, &original);
+
+        let res = unsafe { f32_xany_avx512_fma_clip_norm(1.0, &mut x) };
+        assert_is_close(res, norm);
+        assert_is_close(simple_dot(&x, &x).sqrt(), 1.0);
+
+        let mut zeroes = vec![0.0; 547];
+        let res = unsafe { f32_xany_avx512_fma_clip_norm(1.0, &mut zeroes) };
+        assert_eq!(res, 0.0);
+        assert!(zeroes.iter().all(|v| *v == 0.0));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::danger::utils::cosine;
+use crate::danger::utils::{cosine, cosine_with_l2_norms};
 use crate::danger::{
     f32_xany_avx512_fma_dot,
     f32_xany_avx512_fma_norm,
@@ -56,10 +56,54 @@ pub unsafe fn f32_xany_avx512_fma_cosine(x: &[f32], y: &[f32]) -> f32 {
     cosine::<f32, AutoMath>(dot_product, norm_x, norm_y)
 }
 
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the cosine distance of two `f32` vectors using their precomputed L2
+/// norms, only the dot product of `x` and `y` is computed.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// return 1 - dot(x, y) / (x_norm * y_norm)
+/// ```
+///
+/// `x_norm` and `y_norm` **MUST** be the true L2 norms of `x` and `y`, i.e.
+/// `sqrt(dot(x, x))`, not the squared norms, otherwise the distance returned is
+/// meaningless. This avoids recomputing the norm of stored vectors for every
+/// query in a cosine index.
+///
+/// If either norm is zero, the distance is `0`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_fma_cosine_with_norms(
+    x: &[f32],
+    x_norm: f32,
+    y: &[f32],
+    y_norm: f32,
+) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let dot_product = f32_xany_avx512_fma_dot(x, y);
+    cosine_with_l2_norms::<f32, AutoMath>(dot_product, x_norm, y_norm)
+}
+
 #[cfg(all(test, target_feature = "avx512f"))]
 mod tests {
     use super::*;
-    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_cosine};
+    use crate::test_utils::{
+        assert_is_close,
+        get_sample_vectors,
+        simple_cosine,
+        simple_dot,
+    };
 
     #[test]
     fn test_xconst_fma_cosine() {
@@ -74,4 +118,14 @@ mod tests {
         let dist = unsafe { f32_xany_avx512_fma_cosine(&x, &y) };
         assert_is_close(dist, simple_cosine(&x, &y));
     }
+
+    #[test]
+    fn test_xany_fma_cosine_with_norms() {
+        let (x, y) = get_sample_vectors::<f32>(547);
+        let x_norm = simple_dot(&x, &x).sqrt();
+        let y_norm = simple_dot(&y, &y).sqrt();
+        let dist =
+            unsafe { f32_xany_avx512_fma_cosine_with_norms(&x, x_norm, &y, y_norm) };
+        assert_is_close(dist, simple_cosine(&x, &y));
+    }
 }
@@ -1,6 +1,7 @@
 use core::arch::x86_64::*;
 
 use crate::danger::{
+    fold_chunk_pairs,
     load_two_variable_size_avx512_ps,
     offsets_avx512_ps,
     sum_avx512_x8_ps,
@@ -71,6 +72,102 @@ pub unsafe fn f32_xconst_avx512_fma_dot<const DIMS: usize>(x: &[f32], y: &[f32])
 /// Values within the vector should also be finite, although it is not
 /// going to crash the program, it is going to produce insane numbers.
 pub unsafe fn f32_xany_avx512_fma_dot(x: &[f32], y: &[f32]) -> f32 {
+    f32_xany_avx512_fma_dot_plus(x, y, 0.0)
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the dot product of the first `n` elements of two `f32` vectors, ignoring
+/// any elements beyond them, i.e. the unused capacity of padded buffers.
+///
+/// # Panics
+///
+/// Panics if `n` is greater than the length of either vector.
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_fma_dot_prefix(x: &[f32], y: &[f32], n: usize) -> f32 {
+    assert!(
+        n <= x.len().min(y.len()),
+        "Prefix length {n} exceeds the length of the vectors"
+    );
+    f32_xany_avx512_fma_dot(x.get_unchecked(..n), y.get_unchecked(..n))
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the dot product of each pair of rows of two row-major `f32` matrices
+/// with `dim` columns, only including the first `lengths[row]` elements of each row.
+///
+/// ```py
+/// R: int
+/// dim: int
+/// x: [f32; R * dim]
+/// y: [f32; R * dim]
+/// lengths: [usize; R]
+/// result: [f32; R]
+///
+/// for row in 0..R:
+///     start = row * dim
+///     result[row] = dot(x[start:start + lengths[row]], y[start:start + lengths[row]])
+/// ```
+///
+/// This allows a batch of variable length vectors padded out to a fixed width, i.e.
+/// right-padded token embeddings, to be dotted without the padding contributing to
+/// the result or being processed at all.
+///
+/// # Panics
+///
+/// Panics if any of the `lengths` is greater than `dim`.
+///
+/// # Safety
+///
+/// `x` and `y` **MUST** both be `lengths.len() * dim` in length and `result` **MUST**
+/// be `lengths.len()` in length, otherwise this routine will become immediately UB
+/// due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_fma_dot_ragged(
+    x: &[f32],
+    y: &[f32],
+    dim: usize,
+    lengths: &[usize],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), lengths.len() * dim);
+    debug_assert_eq!(y.len(), lengths.len() * dim);
+    debug_assert_eq!(result.len(), lengths.len());
+
+    for (row, &n) in lengths.iter().enumerate() {
+        assert!(n <= dim, "Row length {n} exceeds the row width {dim}");
+
+        let start = row * dim;
+        *result.get_unchecked_mut(row) = f32_xany_avx512_fma_dot(
+            x.get_unchecked(start..start + n),
+            y.get_unchecked(start..start + n),
+        );
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes `bias` plus the dot product of two `f32` vectors.
+///
+/// The accumulator is seeded with `bias` rather than adding it to the result,
+/// which saves a separate add when applying the bias of a linear layer.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f32_xany_avx512_fma_dot_plus(x: &[f32], y: &[f32], bias: f32) -> f32 {
     debug_assert_eq!(x.len(), y.len());
 
     let len = x.len();
@@ -79,7 +176,7 @@ pub unsafe fn f32_xany_avx512_fma_dot(x: &[f32], y: &[f32]) -> f32 {
     let x = x.as_ptr();
     let y = y.as_ptr();
 
-    let mut acc1 = _mm512_setzero_ps();
+    let mut acc1 = _mm512_zextps128_ps512(_mm_set_ss(bias));
     let mut acc2 = _mm512_setzero_ps();
     let mut acc3 = _mm512_setzero_ps();
     let mut acc4 = _mm512_setzero_ps();
@@ -106,12 +203,24 @@ pub unsafe fn f32_xany_avx512_fma_dot(x: &[f32], y: &[f32]) -> f32 {
         i += 128;
     }
 
-    while i < len {
-        let (x, y) = load_two_variable_size_avx512_ps(x.add(i), y.add(i), len - i);
+    // Alternate between independent accumulators so short vectors, which are
+    // handled entirely by this loop, don't serialize on a single FMA chain.
+    while i + 16 < len {
+        let x1 = _mm512_loadu_ps(x.add(i));
+        let y1 = _mm512_loadu_ps(y.add(i));
+        let (x2, y2) =
+            load_two_variable_size_avx512_ps(x.add(i + 16), y.add(i + 16), len - i - 16);
+
+        acc1 = _mm512_fmadd_ps(x1, y1, acc1);
+        acc2 = _mm512_fmadd_ps(x2, y2, acc2);
 
-        acc1 = _mm512_fmadd_ps(x, y, acc1);
+        i += 32;
+    }
+
+    if i < len {
+        let (x, y) = load_two_variable_size_avx512_ps(x.add(i), y.add(i), len - i);
 
-        i += 16;
+        acc3 = _mm512_fmadd_ps(x, y, acc3);
     }
 
     sum_avx512_x8_ps(acc1, acc2, acc3, acc4, acc5, acc6, acc7, acc8)
@@ -165,6 +274,36 @@ unsafe fn execute_f32_x128_fma_block_dot_product(
     *acc8 = _mm512_fmadd_ps(x8, y8, *acc8);
 }
 
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the dot product of two vectors split into multiple chunks, such as
+/// segmented buffers, without copying them into contiguous vectors first.
+///
+/// The chunk boundaries of `x` and `y` do not need to line up or be aligned to
+/// the register width, each overlapping pair of sub-slices is reduced with the
+/// contiguous routine and accumulated by seeding it with the running total.
+///
+/// ```py
+/// total: f32
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+/// ```
+///
+/// # Safety
+///
+/// The chunks of both vectors **MUST** contain the same number of elements in total.
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_fma_dot_chunks(x: &[&[f32]], y: &[&[f32]]) -> f32 {
+    fold_chunk_pairs(x, y, 0.0, |x, y, total| {
+        f32_xany_avx512_fma_dot_plus(x, y, total)
+    })
+}
+
 #[cfg(all(test, target_feature = "avx512f"))]
 mod tests {
     use super::*;
@@ -183,4 +322,63 @@ mod tests {
         let dist = unsafe { f32_xany_avx512_fma_dot(&x, &y) };
         assert!(is_close(dist, simple_dot(&x, &y)));
     }
+
+    #[test]
+    fn test_xany_fma_dot_tail_lengths() {
+        for len in [0, 3, 16, 17, 32, 40, 127, 128, 129, 250] {
+            let (x, y) = get_sample_vectors(len);
+            let dist = unsafe { f32_xany_avx512_fma_dot(&x, &y) };
+            assert!(is_close(dist, simple_dot(&x, &y)));
+        }
+    }
+
+    #[test]
+    fn test_xany_fma_dot_plus() {
+        for len in [0, 3, 547, 1024] {
+            let (x, y) = get_sample_vectors(len);
+            let dist = unsafe { f32_xany_avx512_fma_dot_plus(&x, &y, 1.5) };
+            assert!(is_close(dist, simple_dot(&x, &y) + 1.5));
+        }
+    }
+
+    #[test]
+    fn test_xany_fma_dot_chunks() {
+        let (x, y) = get_sample_vectors::<f32>(531);
+        let expected = simple_dot(&x, &y);
+
+        // Chunk boundaries deliberately differ between the two vectors.
+        let x_chunks = [&x[..13], &x[13..13], &x[13..200], &x[200..]];
+        let y_chunks = [&y[..64], &y[64..301], &y[301..]];
+
+        let dist = unsafe { f32_xany_avx512_fma_dot_chunks(&x_chunks, &y_chunks) };
+        assert!(is_close(dist, expected));
+    }
+
+    #[test]
+    fn test_xany_fma_dot_prefix() {
+        let (mut x, y) = get_sample_vectors::<f32>(531);
+        x.truncate(517);
+        for n in [0, 7, 64, 200, 517] {
+            let dist = unsafe { f32_xany_avx512_fma_dot_prefix(&x, &y, n) };
+            let expected = unsafe { f32_xany_avx512_fma_dot(&x[..n], &y[..n]) };
+            assert_eq!(dist, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_fma_dot_ragged() {
+        let (x, y) = get_sample_vectors::<f32>(4 * 133);
+        let lengths = [133, 0, 70, 8];
+
+        let mut result = vec![f32::NAN; 4];
+        unsafe { f32_xany_avx512_fma_dot_ragged(&x, &y, 133, &lengths, &mut result) };
+
+        for (row, n) in lengths.into_iter().enumerate() {
+            let start = row * 133;
+            let expected = unsafe {
+                f32_xany_avx512_fma_dot(&x[start..start + n], &y[start..start + n])
+            };
+            assert_eq!(result[row], expected);
+        }
+    }
 }
@@ -0,0 +1,152 @@
+use crate::danger::{
+    f32_xany_avx512_fma_dot,
+    f32_xany_avx512_fma_euclidean,
+    flat_row_pair,
+    pairwise_distances_with,
+};
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the dot product of rows `i` and `j` of the flat row-major `f32` matrix
+/// `data` with `dim` columns.
+///
+/// ```py
+/// D: int
+/// data: [f32; N * D]
+///
+/// return dot(data[i * D:(i + 1) * D], data[j * D:(j + 1) * D])
+/// ```
+///
+/// # Safety
+///
+/// Rows `i` and `j` **MUST** be within `data`, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_fma_flat_dot(
+    data: &[f32],
+    dim: usize,
+    i: usize,
+    j: usize,
+) -> f32 {
+    let (x, y) = flat_row_pair(data, dim, i, j);
+    f32_xany_avx512_fma_dot(x, y)
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the squared Euclidean distance of rows `i` and `j` of the flat
+/// row-major `f32` matrix `data` with `dim` columns.
+///
+/// ```py
+/// D: int
+/// data: [f32; N * D]
+///
+/// return euclidean(data[i * D:(i + 1) * D], data[j * D:(j + 1) * D])
+/// ```
+///
+/// # Safety
+///
+/// Rows `i` and `j` **MUST** be within `data`, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_fma_flat_euclidean(
+    data: &[f32],
+    dim: usize,
+    i: usize,
+    j: usize,
+) -> f32 {
+    let (x, y) = flat_row_pair(data, dim, i, j);
+    f32_xany_avx512_fma_euclidean(x, y)
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the squared Euclidean distance between every pair of rows of the flat
+/// row-major `f32` matrix `data`, writing the full `rows x rows` distance matrix
+/// to `result`.
+///
+/// ```py
+/// N: int
+/// D: int
+/// data: [f32; N * D]
+/// result: [f32; N * N]
+///
+/// for i in 0..N:
+///     for j in 0..N:
+///         result[i * N + j] = euclidean(data[i * D:(i + 1) * D], data[j * D:(j + 1) * D])
+/// ```
+///
+/// The output is the full symmetric matrix in row-major order, not just the upper
+/// triangle, with a diagonal of `0.0`. Only the upper triangle is computed, each
+/// distance is mirrored into the lower triangle.
+///
+/// # Safety
+///
+/// `data` **MUST** be at least `rows * dim` in length and `result` **MUST** be
+/// `rows * rows` in length, otherwise this routine will become immediately UB due
+/// to out of bounds pointer accesses.
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_fma_pairwise_distances(
+    data: &[f32],
+    rows: usize,
+    dim: usize,
+    result: &mut [f32],
+) {
+    pairwise_distances_with(data, rows, dim, 0.0, result, |x, y| {
+        f32_xany_avx512_fma_euclidean(x, y)
+    })
+}
+
+#[cfg(all(test, target_feature = "avx512f"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        get_sample_vectors,
+        simple_dot,
+        simple_euclidean,
+    };
+
+    const ROWS: usize = 7;
+    const DIM: usize = 261;
+
+    #[test]
+    fn test_xany_fma_flat_dot_euclidean() {
+        let (data, _) = get_sample_vectors::<f32>(ROWS * DIM);
+        let row = |i: usize| &data[i * DIM..(i + 1) * DIM];
+
+        for (i, j) in [(0, 0), (0, 6), (3, 1), (5, 5)] {
+            let dot = unsafe { f32_xany_avx512_fma_flat_dot(&data, DIM, i, j) };
+            assert_is_close(dot, simple_dot(row(i), row(j)));
+
+            let dist = unsafe { f32_xany_avx512_fma_flat_euclidean(&data, DIM, i, j) };
+            assert_is_close(dist, simple_euclidean(row(i), row(j)));
+        }
+    }
+
+    #[test]
+    fn test_xany_fma_pairwise_distances() {
+        let (data, _) = get_sample_vectors::<f32>(ROWS * DIM);
+        let row = |i: usize| &data[i * DIM..(i + 1) * DIM];
+
+        let mut result = vec![f32::NAN; ROWS * ROWS];
+        unsafe { f32_xany_avx512_fma_pairwise_distances(&data, ROWS, DIM, &mut result) };
+
+        for i in 0..ROWS {
+            for j in 0..ROWS {
+                let expected = if i == j {
+                    0.0
+                } else {
+                    simple_euclidean(row(i), row(j))
+                };
+                assert_is_close(result[i * ROWS + j], expected);
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Evaluates the polynomial with the given `coeffs` at each element of `x` using
+/// Horner's method, writing the result to `result`.
+///
+/// Coefficients are ordered from the highest degree to the constant term, matching
+/// numpy's `polyval`, an empty set of coefficients evaluates to `0`.
+///
+/// ```py
+/// D: int
+/// N: int
+/// coeffs: [f32; N]
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     acc = 0
+///     for j in 0..N:
+///         acc = acc * x[i] + coeffs[j]
+///     result[i] = acc
+/// ```
+///
+/// Each coefficient is broadcast once per block of 64 elements, so small
+/// polynomials remain resident in registers for the duration of the block.
+///
+/// # Safety
+///
+/// Vectors `x` and `result` **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_fma_polyval(
+    coeffs: &[f32],
+    x: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let len = x.len();
+    let offset_from = len % 64;
+    let x_ptr = x.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm512_loadu_ps(x_ptr.add(i));
+        let x2 = _mm512_loadu_ps(x_ptr.add(i + 16));
+        let x3 = _mm512_loadu_ps(x_ptr.add(i + 32));
+        let x4 = _mm512_loadu_ps(x_ptr.add(i + 48));
+
+        let mut acc1 = _mm512_setzero_ps();
+        let mut acc2 = _mm512_setzero_ps();
+        let mut acc3 = _mm512_setzero_ps();
+        let mut acc4 = _mm512_setzero_ps();
+
+        for coeff in coeffs {
+            let c = _mm512_set1_ps(*coeff);
+            acc1 = _mm512_fmadd_ps(acc1, x1, c);
+            acc2 = _mm512_fmadd_ps(acc2, x2, c);
+            acc3 = _mm512_fmadd_ps(acc3, x3, c);
+            acc4 = _mm512_fmadd_ps(acc4, x4, c);
+        }
+
+        _mm512_storeu_ps(result_ptr.add(i), acc1);
+        _mm512_storeu_ps(result_ptr.add(i + 16), acc2);
+        _mm512_storeu_ps(result_ptr.add(i + 32), acc3);
+        _mm512_storeu_ps(result_ptr.add(i + 48), acc4);
+
+        i += 64;
+    }
+
+    while i < (len - (len % 16)) {
+        let x = _mm512_loadu_ps(x_ptr.add(i));
+
+        let mut acc = _mm512_setzero_ps();
+        for coeff in coeffs {
+            let c = _mm512_set1_ps(*coeff);
+            acc = _mm512_fmadd_ps(acc, x, c);
+        }
+
+        _mm512_storeu_ps(result_ptr.add(i), acc);
+
+        i += 16;
+    }
+
+    while i < len {
+        let x = *x_ptr.add(i);
+
+        let mut acc = 0.0;
+        for coeff in coeffs {
+            acc = AutoMath::add(AutoMath::mul(acc, x), *coeff);
+        }
+
+        *result_ptr.add(i) = acc;
+
+        i += 1;
+    }
+}
+
+#[cfg(all(test, target_feature = "avx512f"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close_vector,
+        get_sample_vectors,
+        simple_polyval,
+    };
+
+    #[test]
+    fn test_xany_fma_polyval() {
+        let (x, _) = get_sample_vectors::<f32>(547);
+        let coeffs = [0.5, -1.25, 0.0, 3.0];
+
+        let mut result = vec![0.0; 547];
+        unsafe { f32_xany_avx512_fma_polyval(&coeffs, &x, &mut result) };
+        assert_is_close_vector(&result, &simple_polyval(&coeffs, &x));
+
+        unsafe { f32_xany_avx512_fma_polyval(&[], &x, &mut result) };
+        assert_eq!(result, vec![0.0; 547]);
+    }
+}
@@ -0,0 +1,120 @@
+use core::arch::x86_64::*;
+
+use crate::danger::overlaps;
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Reverses the order of the elements in `x`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = x[D - i - 1]
+/// ```
+///
+/// Elements are reversed within each register via `_mm512_permutexvar_ps` while the
+/// order of the registers themselves is reversed as they are written out.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// `result` **MUST NOT** overlap `x`, as elements of `x` would be read after being
+/// overwritten, use the `_inplace` variant to operate on a single buffer.
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_nofma_reverse(x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(!overlaps(x, result), "`result` must not overlap `x`");
+
+    let len = x.len();
+    let offset_from = len % 16;
+    let x_ptr = x.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+    let idx = _mm512_setr_epi32(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x = _mm512_loadu_ps(x_ptr.add(i));
+        _mm512_storeu_ps(result_ptr.add(len - i - 16), _mm512_permutexvar_ps(idx, x));
+
+        i += 16;
+    }
+
+    while i < len {
+        *result.get_unchecked_mut(len - i - 1) = *x.get_unchecked(i);
+
+        i += 1;
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Reverses the order of the elements in `x` in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..(D / 2):
+///     x[i], x[D - i - 1] = x[D - i - 1], x[i]
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_nofma_reverse_inplace(x: &mut [f32]) {
+    let len = x.len();
+    let x_ptr = x.as_mut_ptr();
+    let idx = _mm512_setr_epi32(15, 14, 13, 12, 11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0);
+
+    let mut i = 0;
+    while (i + 16) * 2 <= len {
+        let front_ptr = x_ptr.add(i);
+        let back_ptr = x_ptr.add(len - i - 16);
+
+        let front = _mm512_loadu_ps(front_ptr);
+        let back = _mm512_loadu_ps(back_ptr);
+        _mm512_storeu_ps(front_ptr, _mm512_permutexvar_ps(idx, back));
+        _mm512_storeu_ps(back_ptr, _mm512_permutexvar_ps(idx, front));
+
+        i += 16;
+    }
+
+    x.get_unchecked_mut(i..len - i).reverse();
+}
+
+#[cfg(all(test, target_feature = "avx512f"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_reverse() {
+        for size in [0, 3, 16, 127, 131, 547] {
+            let (x, _) = get_sample_vectors::<f32>(size);
+            let expected = x.iter().copied().rev().collect::<Vec<_>>();
+
+            let mut result = vec![0.0; size];
+            unsafe { f32_xany_avx512_nofma_reverse(&x, &mut result) };
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_reverse_inplace() {
+        for size in [0, 3, 16, 127, 131, 547] {
+            let (mut x, _) = get_sample_vectors::<f32>(size);
+            let expected = x.iter().copied().rev().collect::<Vec<_>>();
+
+            unsafe { f32_xany_avx512_nofma_reverse_inplace(&mut x) };
+            assert_eq!(x, expected);
+        }
+    }
+}
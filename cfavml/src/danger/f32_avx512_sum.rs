@@ -6,10 +6,23 @@ use crate::danger::{
     load_one_variable_size_avx512_ps,
     offsets_avx512_ps,
     sum_avx512_x8_ps,
+    sum_streaming_with,
     CHUNK_0,
     CHUNK_1,
+    PAIRWISE_SUM_BLOCK,
+    PAIRWISE_SUM_THRESHOLD,
 };
 
+/// Vectors shorter than this use [sum_small_f32] rather than the fully unrolled
+/// `128` element blocks.
+///
+/// Until a vector spans several blocks, reducing the 8 accumulators of the full unroll
+/// and winding down its tail a register at a time costs more than the extra
+/// accumulators save. Measured with the `bench_sum_threshold` benchmark on an
+/// AVX512 Xeon, the short path is faster up to `704` elements and the full unroll
+/// from `768`, so the threshold is six full `128` element blocks.
+const SMALL_SUM_THRESHOLD: usize = 768;
+
 #[target_feature(enable = "avx512f")]
 #[inline]
 /// Sums all elements of the vector.
@@ -80,11 +93,69 @@ pub unsafe fn f32_xconst_avx512_nofma_sum_horizontal<const DIMS: usize>(
 ///     total = total + x[i]
 /// ```
 ///
+/// Vectors longer than [PAIRWISE_SUM_THRESHOLD] are summed with
+/// [f32_xany_avx512_nofma_sum_pairwise] to bound the accumulated rounding error, shorter
+/// vectors use the plain accumulation which is the fastest option and accurate
+/// enough at those lengths.
+///
 /// # Safety
 ///
 /// This method assumes AVX512 instructions are available, if this method is executed
 /// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
 pub unsafe fn f32_xany_avx512_nofma_sum_horizontal(x: &[f32]) -> f32 {
+    if x.len() > PAIRWISE_SUM_THRESHOLD {
+        f32_xany_avx512_nofma_sum_pairwise(x)
+    } else {
+        sum_horizontal(x)
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Sums all elements of the vector using pairwise summation.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// def pairwise(x):
+///     if len(x) <= PAIRWISE_SUM_BLOCK:
+///         return sum(x)
+///     return pairwise(x[:len(x) // 2]) + pairwise(x[len(x) // 2:])
+/// ```
+///
+/// The vector is recursively halved until the blocks are small enough for the
+/// regular accumulation, so the rounding error grows with `O(log D)` rather than
+/// `O(D)` at a negligible cost, as only a handful of extra reductions are performed.
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_nofma_sum_pairwise(x: &[f32]) -> f32 {
+    if x.len() <= PAIRWISE_SUM_BLOCK {
+        return sum_horizontal(x);
+    }
+
+    // The split is kept a multiple of the block size so only the last block has a tail.
+    let mid = (x.len() / 2) & !127;
+    let (left, right) = x.split_at(mid);
+    f32_xany_avx512_nofma_sum_pairwise(left) + f32_xany_avx512_nofma_sum_pairwise(right)
+}
+
+#[inline(always)]
+/// Sums all elements of the vector with the plain accumulation.
+unsafe fn sum_horizontal(x: &[f32]) -> f32 {
+    if x.len() < SMALL_SUM_THRESHOLD {
+        sum_small_f32(x)
+    } else {
+        sum_unrolled_f32(x)
+    }
+}
+
+#[inline(always)]
+/// Sums all elements of the vector using 8 accumulators over `128` element blocks.
+unsafe fn sum_unrolled_f32(x: &[f32]) -> f32 {
     let len = x.len();
     let offset_from = len % 128;
 
@@ -128,6 +199,67 @@ pub unsafe fn f32_xany_avx512_nofma_sum_horizontal(x: &[f32]) -> f32 {
     sum_avx512_x8_ps(acc1, acc2, acc3, acc4, acc5, acc6, acc7, acc8)
 }
 
+#[inline(always)]
+/// Sums all elements of a short vector using 2 accumulators.
+unsafe fn sum_small_f32(x: &[f32]) -> f32 {
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x = x.as_ptr();
+
+    let mut acc1 = _mm512_setzero_ps();
+    let mut acc2 = _mm512_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        acc1 = _mm512_add_ps(acc1, _mm512_loadu_ps(x.add(i)));
+        acc2 = _mm512_add_ps(acc2, _mm512_loadu_ps(x.add(i + 16)));
+
+        i += 32;
+    }
+
+    while i < len {
+        let n = len - i;
+
+        let x = load_one_variable_size_avx512_ps(x.add(i), n);
+        acc1 = _mm512_add_ps(acc1, x);
+
+        i += 16;
+    }
+
+    _mm512_reduce_add_ps(_mm512_add_ps(acc1, acc2))
+}
+
+#[cfg(feature = "bench-utils")]
+#[doc(hidden)]
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Sums all elements of the vector with the short vector path regardless of its
+/// length, only exposed to benchmark the small sum threshold.
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_nofma_sum_small(x: &[f32]) -> f32 {
+    sum_small_f32(x)
+}
+
+#[cfg(feature = "bench-utils")]
+#[doc(hidden)]
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Sums all elements of the vector with the fully unrolled path regardless of its
+/// length, only exposed to benchmark the small sum threshold.
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_nofma_sum_unrolled(x: &[f32]) -> f32 {
+    sum_unrolled_f32(x)
+}
+
 #[allow(unused)]
 #[target_feature(enable = "avx512f")]
 #[inline]
@@ -322,6 +454,93 @@ unsafe fn sum_x128_block(
     *acc8 = _mm512_add_ps(*acc8, x8);
 }
 
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Sums all elements of the vector and multiplies the total by `scale`.
+///
+/// ```py
+/// D: int
+/// total: f32
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return scale * total
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_nofma_scaled_sum(scale: f32, x: &[f32]) -> f32 {
+    scale * f32_xany_avx512_nofma_sum_horizontal(x)
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Sums all elements of a vector split into multiple chunks, such as a segmented
+/// buffer, without copying them into a contiguous vector first.
+///
+/// Each chunk is summed with the contiguous routine, so chunks do not need to be
+/// aligned to the register width.
+///
+/// ```py
+/// total: f32
+/// chunks: [[f32; N]; C]
+///
+/// for chunk in chunks:
+///     for i in 0..N:
+///         total = total + chunk[i]
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_nofma_sum_chunks(chunks: &[&[f32]]) -> f32 {
+    let mut total = 0.0;
+    for chunk in chunks {
+        total += f32_xany_avx512_nofma_sum_horizontal(chunk);
+    }
+    total
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Sums a stream of `f32` values which are produced in chunks by `reader`, i.e.
+/// memory-mapped or network streamed data which does not fit in memory at once.
+///
+/// ```py
+/// total: f32
+/// buffer: [f32; chunk_size]
+///
+/// while (n := reader(buffer)) > 0:
+///     total = total + sum(buffer[:n])
+/// ```
+///
+/// `reader` fills the start of a reusable buffer of `chunk_size` elements and returns
+/// the number of elements written, returning `0` ends the stream. Each chunk is summed
+/// with the contiguous routine, the chunk totals are then combined with compensated
+/// summation so the result stays stable however many chunks are read.
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is `0` or `reader` returns a count greater than `chunk_size`.
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_nofma_sum_streaming<R>(chunk_size: usize, reader: R) -> f32
+where
+    R: FnMut(&mut [f32]) -> usize,
+{
+    sum_streaming_with(chunk_size, reader, |chunk| {
+        f32_xany_avx512_nofma_sum_horizontal(chunk)
+    })
+}
+
 #[cfg(all(test, target_feature = "avx512f"))]
 #[cfg(test)]
 mod tests {
@@ -342,6 +561,15 @@ mod tests {
         assert_is_close(sum, x.iter().sum::<f32>());
     }
 
+    #[test]
+    fn test_xany_nofma_sum_above_small_threshold() {
+        let (x, _) = get_sample_vectors(SMALL_SUM_THRESHOLD + 131);
+        let sum = unsafe { f32_xany_avx512_nofma_sum_horizontal(&x) };
+        // The reference is accumulated in f64 as a naive f32 sum drifts at this length.
+        let expected = x.iter().map(|v| *v as f64).sum::<f64>();
+        assert_is_close(sum, expected as f32);
+    }
+
     #[test]
     fn test_xconst_nofma_sum_vertical() {
         let mut matrix = Vec::new();
@@ -365,6 +593,18 @@ mod tests {
         assert_eq!(sum, expected_vertical_sum);
     }
 
+    #[test]
+    fn test_xany_nofma_sum_pairwise() {
+        let (x, _) = get_sample_vectors::<f32>(PAIRWISE_SUM_THRESHOLD + 4133);
+        let expected = x.iter().map(|v| *v as f64).sum::<f64>();
+
+        let pairwise = unsafe { f32_xany_avx512_nofma_sum_pairwise(&x) };
+        assert!(((pairwise as f64 - expected) / expected).abs() < 1e-6);
+
+        let sum = unsafe { f32_xany_avx512_nofma_sum_horizontal(&x) };
+        assert_eq!(sum, pairwise);
+    }
+
     #[test]
     fn test_xany_nofma_sum_vertical() {
         let mut matrix = Vec::new();
@@ -387,4 +627,39 @@ mod tests {
         let sum = unsafe { f32_xany_avx512_nofma_sum_vertical(&matrix_view) };
         assert_eq!(sum, expected_vertical_sum);
     }
+
+    #[test]
+    fn test_xany_nofma_scaled_sum() {
+        let (x, _) = get_sample_vectors::<f32>(547);
+        let sum = unsafe { f32_xany_avx512_nofma_scaled_sum(0.5, &x) };
+        assert_is_close(sum, 0.5 * x.iter().sum::<f32>());
+    }
+
+    #[test]
+    fn test_xany_nofma_sum_chunks() {
+        let (x, _) = get_sample_vectors::<f32>(531);
+        let chunks = [&x[..13], &x[13..13], &x[13..200], &x[200..]];
+        let sum = unsafe { f32_xany_avx512_nofma_sum_chunks(&chunks) };
+        assert_is_close(sum, x.iter().sum::<f32>());
+    }
+
+    #[test]
+    fn test_xany_nofma_sum_streaming() {
+        let (x, _) = get_sample_vectors::<f32>(1037);
+        let expected = x.iter().map(|v| *v as f64).sum::<f64>();
+
+        let mut offset = 0;
+        let total = unsafe {
+            f32_xany_avx512_nofma_sum_streaming(128, |buffer| {
+                let n = buffer.len().min(x.len() - offset);
+                buffer[..n].copy_from_slice(&x[offset..offset + n]);
+                offset += n;
+                n
+            })
+        };
+        assert!(
+            (total as f64 - expected).abs() < 1e-3,
+            "{total} != {expected}"
+        );
+    }
 }
@@ -0,0 +1,100 @@
+use core::arch::x86_64::*;
+
+use crate::math::*;
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes both the sum and the sum of squares of one `f32` vector in a single pass,
+/// returning `(sum(x), sum(x * x))`.
+///
+/// ```py
+/// D: int
+/// sum: f32
+/// sum_sq: f32
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     sum = sum + x[i]
+///     sum_sq = sum_sq + (x[i] * x[i])
+/// ```
+///
+/// These values can be used to compute the variance as `E[x²] - E[x]²` with a single
+/// pass over memory, however, this formula suffers from catastrophic cancellation
+/// when the mean is large relative to the variance. Where precision matters more than
+/// speed, a two-pass or Welford style computation should be used instead.
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_fma_sum_sumsq(x: &[f32]) -> (f32, f32) {
+    let len = x.len();
+    let offset_from = len % 64;
+    let x_ptr = x.as_ptr();
+
+    let mut sum0 = _mm512_setzero_ps();
+    let mut sum1 = _mm512_setzero_ps();
+    let mut sum2 = _mm512_setzero_ps();
+    let mut sum3 = _mm512_setzero_ps();
+    let mut sumsq0 = _mm512_setzero_ps();
+    let mut sumsq1 = _mm512_setzero_ps();
+    let mut sumsq2 = _mm512_setzero_ps();
+    let mut sumsq3 = _mm512_setzero_ps();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x0 = _mm512_loadu_ps(x_ptr.add(i));
+        let x1 = _mm512_loadu_ps(x_ptr.add(i + 16));
+        let x2 = _mm512_loadu_ps(x_ptr.add(i + 32));
+        let x3 = _mm512_loadu_ps(x_ptr.add(i + 48));
+
+        sum0 = _mm512_add_ps(sum0, x0);
+        sum1 = _mm512_add_ps(sum1, x1);
+        sum2 = _mm512_add_ps(sum2, x2);
+        sum3 = _mm512_add_ps(sum3, x3);
+        sumsq0 = _mm512_fmadd_ps(x0, x0, sumsq0);
+        sumsq1 = _mm512_fmadd_ps(x1, x1, sumsq1);
+        sumsq2 = _mm512_fmadd_ps(x2, x2, sumsq2);
+        sumsq3 = _mm512_fmadd_ps(x3, x3, sumsq3);
+
+        i += 64;
+    }
+
+    while i < (len - (len % 16)) {
+        let x = _mm512_loadu_ps(x_ptr.add(i));
+        sum0 = _mm512_add_ps(sum0, x);
+        sumsq0 = _mm512_fmadd_ps(x, x, sumsq0);
+
+        i += 16;
+    }
+
+    sum0 = _mm512_add_ps(_mm512_add_ps(sum0, sum1), _mm512_add_ps(sum2, sum3));
+    sumsq0 = _mm512_add_ps(_mm512_add_ps(sumsq0, sumsq1), _mm512_add_ps(sumsq2, sumsq3));
+
+    let mut sum = _mm512_reduce_add_ps(sum0);
+    let mut sum_sq = _mm512_reduce_add_ps(sumsq0);
+
+    while i < len {
+        let x = *x.get_unchecked(i);
+        sum = AutoMath::add(sum, x);
+        sum_sq = AutoMath::add(sum_sq, AutoMath::mul(x, x));
+
+        i += 1;
+    }
+
+    (sum, sum_sq)
+}
+
+#[cfg(all(test, target_feature = "avx512f"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_xany_fma_sum_sumsq() {
+        let (x, _) = get_sample_vectors::<f32>(547);
+        let (sum, sum_sq) = unsafe { f32_xany_avx512_fma_sum_sumsq(&x) };
+        assert_is_close(sum, x.iter().sum::<f32>());
+        assert_is_close(sum_sq, simple_dot(&x, &x));
+    }
+}
@@ -28,6 +28,30 @@ pub unsafe fn f32_xconst_avx512_nofma_div_value<const DIMS: usize>(
     f32_xconst_avx512_nofma_mul_value::<DIMS>(arr, 1.0 / divider)
 }
 
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Approximately divides each element in the provided mutable `[f32; DIMS]` vector by `value`.
+///
+/// This computes the reciprocal of `value` once and multiplies each element by it,
+/// so the result may differ from the correctly rounded quotient by 1 ULP.
+/// [f32_xconst_avx512_nofma_div_value] currently uses the same reciprocal
+/// multiply, this routine is an explicit alias for callers relying on that behaviour.
+///
+/// # Safety
+///
+/// Vectors **MUST** be `DIMS` elements in length and divisible by 128,
+/// otherwise this function becomes immediately UB due to out of bounds
+/// access.
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xconst_avx512_nofma_fast_div_value<const DIMS: usize>(
+    arr: &mut [f32],
+    divider: f32,
+) {
+    f32_xconst_avx512_nofma_mul_value::<DIMS>(arr, 1.0 / divider)
+}
+
 #[target_feature(enable = "avx512f")]
 #[inline]
 /// Divides each element in the provided mutable `f32` vector by `value`.
@@ -40,6 +64,23 @@ pub unsafe fn f32_xany_avx512_nofma_div_value(arr: &mut [f32], divider: f32) {
     f32_xany_avx512_nofma_mul_value(arr, 1.0 / divider)
 }
 
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Approximately divides each element in the provided mutable `f32` vector by `value`.
+///
+/// This computes the reciprocal of `value` once and multiplies each element by it,
+/// so the result may differ from the correctly rounded quotient by 1 ULP.
+/// [f32_xany_avx512_nofma_div_value] currently uses the same reciprocal
+/// multiply, this routine is an explicit alias for callers relying on that behaviour.
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_nofma_fast_div_value(arr: &mut [f32], divider: f32) {
+    f32_xany_avx512_nofma_mul_value(arr, 1.0 / divider)
+}
+
 #[target_feature(enable = "avx512f")]
 #[inline]
 /// Multiplies each element in the provided mutable `[f32; DIMS]` vector by `value`.
@@ -239,6 +280,148 @@ pub unsafe fn f32_xany_avx512_nofma_sub_value(arr: &mut [f32], value: f32) {
     }
 }
 
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Clamps each element in the provided mutable `[f32; DIMS]` vector
+/// to be no less than `value`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be `DIMS` elements in length and divisible by 128,
+/// otherwise this function becomes immediately UB due to out of bounds
+/// access.
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xconst_avx512_nofma_max_value<const DIMS: usize>(
+    arr: &mut [f32],
+    value: f32,
+) {
+    debug_assert_eq!(arr.len(), DIMS);
+    debug_assert_eq!(DIMS % 128, 0, "Input dimensions must be multiple of 128");
+
+    let value = _mm512_set1_ps(value);
+    let arr = arr.as_mut_ptr();
+
+    let mut i = 0;
+    while i < DIMS {
+        execute_f32_x128_max(arr.add(i), value);
+        i += 128;
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Clamps each element in the provided mutable `f32` vector
+/// to be no less than `value`.
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_nofma_max_value(arr: &mut [f32], value: f32) {
+    let len = arr.len();
+    let offset_from = len % 128;
+
+    let value = _mm512_set1_ps(value);
+    let arr = arr.as_mut_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        execute_f32_x128_max(arr.add(i), value);
+        i += 128;
+    }
+
+    while i < len {
+        let n = len - i;
+        let arr = arr.add(i);
+
+        let r = if n < 16 {
+            let mask = _bzhi_u32(0xFFFFFFFF, n as u32) as _;
+            let x = _mm512_maskz_loadu_ps(mask, arr);
+            _mm512_maskz_max_ps(mask, x, value)
+        } else {
+            let x = _mm512_loadu_ps(arr);
+            _mm512_max_ps(x, value)
+        };
+
+        copy_masked_avx512_ps_register_to(arr, r, n);
+
+        i += 16;
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Clamps each element in the provided mutable `[f32; DIMS]` vector
+/// to be no greater than `value`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be `DIMS` elements in length and divisible by 128,
+/// otherwise this function becomes immediately UB due to out of bounds
+/// access.
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xconst_avx512_nofma_min_value<const DIMS: usize>(
+    arr: &mut [f32],
+    value: f32,
+) {
+    debug_assert_eq!(arr.len(), DIMS);
+    debug_assert_eq!(DIMS % 128, 0, "Input dimensions must be multiple of 128");
+
+    let value = _mm512_set1_ps(value);
+    let arr = arr.as_mut_ptr();
+
+    let mut i = 0;
+    while i < DIMS {
+        execute_f32_x128_min(arr.add(i), value);
+        i += 128;
+    }
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Clamps each element in the provided mutable `f32` vector
+/// to be no greater than `value`.
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f32_xany_avx512_nofma_min_value(arr: &mut [f32], value: f32) {
+    let len = arr.len();
+    let offset_from = len % 128;
+
+    let value = _mm512_set1_ps(value);
+    let arr = arr.as_mut_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        execute_f32_x128_min(arr.add(i), value);
+        i += 128;
+    }
+
+    while i < len {
+        let n = len - i;
+        let arr = arr.add(i);
+
+        let r = if n < 16 {
+            let mask = _bzhi_u32(0xFFFFFFFF, n as u32) as _;
+            let x = _mm512_maskz_loadu_ps(mask, arr);
+            _mm512_maskz_min_ps(mask, x, value)
+        } else {
+            let x = _mm512_loadu_ps(arr);
+            _mm512_min_ps(x, value)
+        };
+
+        copy_masked_avx512_ps_register_to(arr, r, n);
+
+        i += 16;
+    }
+}
+
 #[inline(always)]
 unsafe fn execute_f32_x128_mul(x: *mut f32, multiplier: __m512) {
     let [x1, x2, x3, x4] = offsets_avx512_ps::<CHUNK_0>(x);
@@ -332,6 +515,68 @@ unsafe fn execute_f32_x128_sub(x: *mut f32, value: __m512) {
     ptr::copy_nonoverlapping(result.as_ptr(), x, result.len());
 }
 
+#[inline(always)]
+unsafe fn execute_f32_x128_max(x: *mut f32, value: __m512) {
+    let [x1, x2, x3, x4] = offsets_avx512_ps::<CHUNK_0>(x);
+    let [x5, x6, x7, x8] = offsets_avx512_ps::<CHUNK_1>(x);
+
+    let x1 = _mm512_loadu_ps(x1);
+    let x2 = _mm512_loadu_ps(x2);
+    let x3 = _mm512_loadu_ps(x3);
+    let x4 = _mm512_loadu_ps(x4);
+    let x5 = _mm512_loadu_ps(x5);
+    let x6 = _mm512_loadu_ps(x6);
+    let x7 = _mm512_loadu_ps(x7);
+    let x8 = _mm512_loadu_ps(x8);
+
+    let result1 = _mm512_max_ps(x1, value);
+    let result2 = _mm512_max_ps(x2, value);
+    let result3 = _mm512_max_ps(x3, value);
+    let result4 = _mm512_max_ps(x4, value);
+    let result5 = _mm512_max_ps(x5, value);
+    let result6 = _mm512_max_ps(x6, value);
+    let result7 = _mm512_max_ps(x7, value);
+    let result8 = _mm512_max_ps(x8, value);
+
+    let lanes = [
+        result1, result2, result3, result4, result5, result6, result7, result8,
+    ];
+
+    let result = mem::transmute::<[__m512; 8], [f32; 128]>(lanes);
+    ptr::copy_nonoverlapping(result.as_ptr(), x, result.len());
+}
+
+#[inline(always)]
+unsafe fn execute_f32_x128_min(x: *mut f32, value: __m512) {
+    let [x1, x2, x3, x4] = offsets_avx512_ps::<CHUNK_0>(x);
+    let [x5, x6, x7, x8] = offsets_avx512_ps::<CHUNK_1>(x);
+
+    let x1 = _mm512_loadu_ps(x1);
+    let x2 = _mm512_loadu_ps(x2);
+    let x3 = _mm512_loadu_ps(x3);
+    let x4 = _mm512_loadu_ps(x4);
+    let x5 = _mm512_loadu_ps(x5);
+    let x6 = _mm512_loadu_ps(x6);
+    let x7 = _mm512_loadu_ps(x7);
+    let x8 = _mm512_loadu_ps(x8);
+
+    let result1 = _mm512_min_ps(x1, value);
+    let result2 = _mm512_min_ps(x2, value);
+    let result3 = _mm512_min_ps(x3, value);
+    let result4 = _mm512_min_ps(x4, value);
+    let result5 = _mm512_min_ps(x5, value);
+    let result6 = _mm512_min_ps(x6, value);
+    let result7 = _mm512_min_ps(x7, value);
+    let result8 = _mm512_min_ps(x8, value);
+
+    let lanes = [
+        result1, result2, result3, result4, result5, result6, result7, result8,
+    ];
+
+    let result = mem::transmute::<[__m512; 8], [f32; 128]>(lanes);
+    ptr::copy_nonoverlapping(result.as_ptr(), x, result.len());
+}
+
 #[cfg(all(test, target_feature = "avx512f"))]
 #[cfg(test)]
 mod tests {
@@ -347,6 +592,15 @@ mod tests {
         assert_is_close_vector(&x, &expected);
     }
 
+    #[test]
+    fn test_f32_xany_fast_div() {
+        let value = 3.0;
+        let (mut x, _) = get_sample_vectors(557);
+        let expected = x.iter().copied().map(|v| v / value).collect::<Vec<_>>();
+        unsafe { f32_xany_avx512_nofma_fast_div_value(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
+
     #[test]
     fn test_f32_xany_mul() {
         let value = 2.0;
@@ -383,6 +637,15 @@ mod tests {
         assert_is_close_vector(&x, &expected);
     }
 
+    #[test]
+    fn test_f32_xconst_fast_div() {
+        let value = 3.0;
+        let (mut x, _) = get_sample_vectors(512);
+        let expected = x.iter().copied().map(|v| v / value).collect::<Vec<_>>();
+        unsafe { f32_xconst_avx512_nofma_fast_div_value::<512>(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
+
     #[test]
     fn test_f32_xconst_mul() {
         let value = 2.0;
@@ -409,4 +672,56 @@ mod tests {
         unsafe { f32_xconst_avx512_nofma_sub_value::<512>(&mut x, value) };
         assert_is_close_vector(&x, &expected);
     }
+
+    #[test]
+    fn test_f32_xany_max_value() {
+        let value = 0.5;
+        let (mut x, _) = get_sample_vectors(557);
+        let expected = x
+            .iter()
+            .copied()
+            .map(|v: f32| v.max(value))
+            .collect::<Vec<_>>();
+        unsafe { f32_xany_avx512_nofma_max_value(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_f32_xconst_max_value() {
+        let value = 0.5;
+        let (mut x, _) = get_sample_vectors(512);
+        let expected = x
+            .iter()
+            .copied()
+            .map(|v: f32| v.max(value))
+            .collect::<Vec<_>>();
+        unsafe { f32_xconst_avx512_nofma_max_value::<512>(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_f32_xany_min_value() {
+        let value = 0.5;
+        let (mut x, _) = get_sample_vectors(557);
+        let expected = x
+            .iter()
+            .copied()
+            .map(|v: f32| v.min(value))
+            .collect::<Vec<_>>();
+        unsafe { f32_xany_avx512_nofma_min_value(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_f32_xconst_min_value() {
+        let value = 0.5;
+        let (mut x, _) = get_sample_vectors(512);
+        let expected = x
+            .iter()
+            .copied()
+            .map(|v: f32| v.min(value))
+            .collect::<Vec<_>>();
+        unsafe { f32_xconst_avx512_nofma_min_value::<512>(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
 }
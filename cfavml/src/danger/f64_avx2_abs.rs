@@ -0,0 +1,114 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the absolute value of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// result: [f64; D]
+///
+/// for i in 0..D:
+///     result[i] = abs(x[i])
+/// ```
+///
+/// The sign bit is cleared, so `-0.0` becomes `0.0` and `NaN` inputs stay `NaN`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_abs(x: &[f64], result: &mut [f64]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    f64_avx2_abs_to(x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the absolute value of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     x[i] = abs(x[i])
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_abs_inplace(x: &mut [f64]) {
+    let x_ptr = x.as_mut_ptr();
+    f64_avx2_abs_to(x_ptr, x_ptr, x.len())
+}
+
+#[inline(always)]
+/// Computes the absolute value of the values read from `x_ptr` writing the output
+/// to the `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn f64_avx2_abs_to(x_ptr: *const f64, result: *mut f64, len: usize) {
+    let offset_from = len % 16;
+
+    // Clearing the sign bit, this also maps `-0.0` to `0.0` and keeps `NaN` a `NaN`.
+    let sign_mask = _mm256_set1_pd(-0.0);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let v1 = _mm256_loadu_pd(x_ptr.add(i));
+        let v2 = _mm256_loadu_pd(x_ptr.add(i + 4));
+        let v3 = _mm256_loadu_pd(x_ptr.add(i + 8));
+        let v4 = _mm256_loadu_pd(x_ptr.add(i + 12));
+
+        _mm256_storeu_pd(result.add(i), _mm256_andnot_pd(sign_mask, v1));
+        _mm256_storeu_pd(result.add(i + 4), _mm256_andnot_pd(sign_mask, v2));
+        _mm256_storeu_pd(result.add(i + 8), _mm256_andnot_pd(sign_mask, v3));
+        _mm256_storeu_pd(result.add(i + 12), _mm256_andnot_pd(sign_mask, v4));
+
+        i += 16;
+    }
+
+    while i < len {
+        *result.add(i) = (*x_ptr.add(i)).abs();
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_abs() {
+        let (x, y) = get_sample_vectors::<f64>(67);
+        let mut x = x.iter().zip(y).map(|(a, b)| a - b).collect::<Vec<_>>();
+        x[5] = f64::NAN;
+
+        let mut result = vec![0.0; 67];
+        unsafe { f64_xany_avx2_nofma_abs(&x, &mut result) };
+        assert!(result[5].is_nan());
+        unsafe { f64_xany_avx2_nofma_abs_inplace(&mut x) };
+        assert!(x[5].is_nan());
+
+        x[5] = 0.0;
+        result[5] = 0.0;
+        assert!(result.iter().all(|v| *v >= 0.0));
+        assert_eq!(x, result);
+    }
+}
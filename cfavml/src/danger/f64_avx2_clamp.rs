@@ -0,0 +1,278 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element of `x` to the bounds `lo` and `hi`, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// lo: f64
+/// hi: f64
+/// x: [f64; D]
+/// result: [f64; D]
+///
+/// for i in 0..D:
+///     result[i] = min(max(x[i], lo), hi)
+/// ```
+///
+/// `NaN` elements of `x` are clamped to `lo`, if `lo` is greater than `hi` the
+/// element is set to `hi`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_clamp_value(
+    lo: f64,
+    hi: f64,
+    x: &[f64],
+    result: &mut [f64],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    clamp_value_to(lo, hi, x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element of `x` to the bounds `lo` and `hi` in place.
+///
+/// ```py
+/// D: int
+/// lo: f64
+/// hi: f64
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     x[i] = min(max(x[i], lo), hi)
+/// ```
+///
+/// `NaN` elements of `x` are clamped to `lo`, if `lo` is greater than `hi` the
+/// element is set to `hi`.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_clamp_value_inplace(lo: f64, hi: f64, x: &mut [f64]) {
+    let x_ptr = x.as_mut_ptr();
+    clamp_value_to(lo, hi, x_ptr, x_ptr, x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi`, writing the
+/// result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// lo: [f64; D]
+/// hi: [f64; D]
+/// result: [f64; D]
+///
+/// for i in 0..D:
+///     result[i] = min(max(x[i], lo[i]), hi[i])
+/// ```
+///
+/// `NaN` elements of `x` are clamped to `lo`, if `lo[i]` is greater than `hi[i]`
+/// the element is set to `hi[i]`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_clamp_vertical(
+    x: &[f64],
+    lo: &[f64],
+    hi: &[f64],
+    result: &mut [f64],
+) {
+    debug_assert_eq!(x.len(), lo.len());
+    debug_assert_eq!(x.len(), hi.len());
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        ![x, lo, hi].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    clamp_vertical_to(x.as_ptr(), lo, hi, result.as_mut_ptr())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi` in place.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// lo: [f64; D]
+/// hi: [f64; D]
+///
+/// for i in 0..D:
+///     x[i] = min(max(x[i], lo[i]), hi[i])
+/// ```
+///
+/// `NaN` elements of `x` are clamped to `lo`, if `lo[i]` is greater than `hi[i]`
+/// the element is set to `hi[i]`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_clamp_vertical_inplace(
+    x: &mut [f64],
+    lo: &[f64],
+    hi: &[f64],
+) {
+    debug_assert_eq!(x.len(), lo.len());
+    debug_assert_eq!(x.len(), hi.len());
+
+    let x_ptr = x.as_mut_ptr();
+    clamp_vertical_to(x_ptr, lo, hi, x_ptr)
+}
+
+#[inline(always)]
+/// Clamps the values read from `x_ptr` to `lo` and `hi`, writing the output to the
+/// `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn clamp_value_to(
+    lo: f64,
+    hi: f64,
+    x_ptr: *const f64,
+    result: *mut f64,
+    len: usize,
+) {
+    let offset_from = len % 16;
+    let lo_reg = _mm256_set1_pd(lo);
+    let hi_reg = _mm256_set1_pd(hi);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for j in (0..16).step_by(4) {
+            let x = _mm256_loadu_pd(x_ptr.add(i + j));
+
+            // `vmaxpd` returns the second operand if either is `NaN`, so `NaN` is `lo`.
+            let r = _mm256_min_pd(_mm256_max_pd(x, lo_reg), hi_reg);
+            _mm256_storeu_pd(result.add(i + j), r);
+        }
+
+        i += 16;
+    }
+
+    while i < len {
+        *result.add(i) = AutoMath::cmp_min(AutoMath::cmp_max(*x_ptr.add(i), lo), hi);
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Clamps `x` to `lo` and `hi` element wise, writing the output to the `result`
+/// pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn clamp_vertical_to(
+    x_ptr: *const f64,
+    lo: &[f64],
+    hi: &[f64],
+    result: *mut f64,
+) {
+    let len = lo.len();
+    let offset_from = len % 16;
+    let lo_ptr = lo.as_ptr();
+    let hi_ptr = hi.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for j in (0..16).step_by(4) {
+            let x = _mm256_loadu_pd(x_ptr.add(i + j));
+            let lo = _mm256_loadu_pd(lo_ptr.add(i + j));
+            let hi = _mm256_loadu_pd(hi_ptr.add(i + j));
+
+            // `vmaxpd` returns the second operand if either is `NaN`, so `NaN` is `lo`.
+            let r = _mm256_min_pd(_mm256_max_pd(x, lo), hi);
+            _mm256_storeu_pd(result.add(i + j), r);
+        }
+
+        i += 16;
+    }
+
+    while i < len {
+        *result.add(i) = AutoMath::cmp_min(
+            AutoMath::cmp_max(*x_ptr.add(i), *lo_ptr.add(i)),
+            *hi_ptr.add(i),
+        );
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_clamp_vertical() {
+        let (mut x, lo) = get_sample_vectors::<f64>(131);
+        let hi = lo.iter().map(|v| v + 0.25).collect::<Vec<f64>>();
+        x[3] = f64::NAN;
+
+        let expected = x
+            .iter()
+            .zip(lo.iter())
+            .zip(hi.iter())
+            .map(|((x, lo), hi)| x.max(*lo).min(*hi))
+            .collect::<Vec<f64>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f64_xany_avx2_nofma_clamp_vertical(&x, &lo, &hi, &mut result) };
+        assert_eq!(result, expected);
+
+        unsafe { f64_xany_avx2_nofma_clamp_vertical_inplace(&mut x, &lo, &hi) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_clamp_value() {
+        let (mut x, _) = get_sample_vectors::<f64>(131);
+        x[3] = f64::NAN;
+        let expected = x
+            .iter()
+            .map(|v| {
+                if v.is_nan() {
+                    0.25
+                } else {
+                    v.clamp(0.25, 0.75)
+                }
+            })
+            .collect::<Vec<f64>>();
+
+        let mut result = vec![0.0; 131];
+        unsafe { f64_xany_avx2_nofma_clamp_value(0.25, 0.75, &x, &mut result) };
+        assert_eq!(result, expected);
+        assert_eq!(result[3], 0.25);
+
+        unsafe { f64_xany_avx2_nofma_clamp_value_inplace(0.25, 0.75, &mut x) };
+        assert_eq!(x, expected);
+    }
+}
@@ -0,0 +1,210 @@
+use crate::danger::{
+    f64_xany_avx2_fma_dot,
+    f64_xany_avx2_nofma_dot,
+    f64_xconst_avx2_fma_dot,
+    f64_xconst_avx2_nofma_dot,
+    Activation,
+};
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of two `[f64; DIMS]` vectors, adds `bias` and applies
+/// the given activation function to the result.
+///
+/// ```py
+/// D: int
+/// total: f64
+/// x: [f64; D]
+/// y: [f64; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+///
+/// return activation(total + bias)
+/// ```
+///
+/// # Safety
+///
+/// DIMS **MUST** be a multiple of `32` and both vectors must be `DIMS` in length,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xconst_avx2_nofma_dot_bias_activation<const DIMS: usize>(
+    x: &[f64],
+    y: &[f64],
+    bias: f64,
+    activation: Activation,
+) -> f64 {
+    let dot_product = f64_xconst_avx2_nofma_dot::<DIMS>(x, y);
+    activation.apply::<f64, AutoMath>(AutoMath::add(dot_product, bias))
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of two `f64` vectors, adds `bias` and applies
+/// the given activation function to the result.
+///
+/// ```py
+/// D: int
+/// total: f64
+/// x: [f64; D]
+/// y: [f64; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+///
+/// return activation(total + bias)
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_dot_bias_activation(
+    x: &[f64],
+    y: &[f64],
+    bias: f64,
+    activation: Activation,
+) -> f64 {
+    let dot_product = f64_xany_avx2_nofma_dot(x, y);
+    activation.apply::<f64, AutoMath>(AutoMath::add(dot_product, bias))
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of two `[f64; DIMS]` vectors, adds `bias` and applies
+/// the given activation function to the result.
+///
+/// ```py
+/// D: int
+/// total: f64
+/// x: [f64; D]
+/// y: [f64; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+///
+/// return activation(total + bias)
+/// ```
+///
+/// # Safety
+///
+/// DIMS **MUST** be a multiple of `32` and both vectors must be `DIMS` in length,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xconst_avx2_fma_dot_bias_activation<const DIMS: usize>(
+    x: &[f64],
+    y: &[f64],
+    bias: f64,
+    activation: Activation,
+) -> f64 {
+    let dot_product = f64_xconst_avx2_fma_dot::<DIMS>(x, y);
+    activation.apply::<f64, AutoMath>(AutoMath::add(dot_product, bias))
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of two `f64` vectors, adds `bias` and applies
+/// the given activation function to the result.
+///
+/// ```py
+/// D: int
+/// total: f64
+/// x: [f64; D]
+/// y: [f64; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+///
+/// return activation(total + bias)
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_fma_dot_bias_activation(
+    x: &[f64],
+    y: &[f64],
+    bias: f64,
+    activation: Activation,
+) -> f64 {
+    let dot_product = f64_xany_avx2_fma_dot(x, y);
+    activation.apply::<f64, AutoMath>(AutoMath::add(dot_product, bias))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_xany_fma_dot_bias_activation() {
+        let (x, y) = get_sample_vectors(127);
+        let expected = simple_dot(&x, &y) - 40.0;
+
+        let res = unsafe {
+            f64_xany_avx2_fma_dot_bias_activation(&x, &y, -40.0, Activation::None)
+        };
+        assert_is_close(res as f32, expected as f32);
+
+        let res = unsafe {
+            f64_xany_avx2_fma_dot_bias_activation(&x, &y, -40.0, Activation::Relu)
+        };
+        assert_is_close(res as f32, expected.max(0.0) as f32);
+    }
+
+    #[test]
+    fn test_xany_nofma_dot_bias_activation() {
+        let (x, y) = get_sample_vectors(127);
+        let expected = simple_dot::<f64>(&x, &y) - 30.0;
+        let expected = 1.0 / (1.0 + (-expected).exp());
+
+        let res = unsafe {
+            f64_xany_avx2_nofma_dot_bias_activation(&x, &y, -30.0, Activation::Sigmoid)
+        };
+        assert_is_close(res as f32, expected as f32);
+    }
+
+    #[test]
+    fn test_xconst_fma_dot_bias_activation() {
+        let (x, y) = get_sample_vectors(1024);
+        let expected = simple_dot(&x, &y) - 256.0;
+
+        let res = unsafe {
+            f64_xconst_avx2_fma_dot_bias_activation::<1024>(
+                &x,
+                &y,
+                -256.0,
+                Activation::Tanh,
+            )
+        };
+        assert_is_close(res as f32, expected.tanh() as f32);
+    }
+
+    #[test]
+    fn test_xconst_nofma_dot_bias_activation() {
+        let (x, y) = get_sample_vectors(1024);
+        let expected = simple_dot(&x, &y) + 1.0;
+
+        let res = unsafe {
+            f64_xconst_avx2_nofma_dot_bias_activation::<1024>(
+                &x,
+                &y,
+                1.0,
+                Activation::Relu,
+            )
+        };
+        assert_is_close(res as f32, expected as f32);
+    }
+}
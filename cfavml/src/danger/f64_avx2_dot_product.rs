@@ -1,7 +1,7 @@
 use core::arch::x86_64::*;
 
 use crate::danger::utils::{CHUNK_0, CHUNK_1};
-use crate::danger::{offsets_avx2_pd, rollup_x8_pd, sum_avx2_pd};
+use crate::danger::{fold_chunk_pairs, offsets_avx2_pd, rollup_x8_pd, sum_avx2_pd};
 use crate::math::*;
 
 #[target_feature(enable = "avx2")]
@@ -64,11 +64,47 @@ pub unsafe fn f64_xconst_avx2_nofma_dot<const DIMS: usize>(x: &[f64], y: &[f64])
 /// Vectors **MUST** be the same length, otherwise this routine
 /// will become immediately UB due to out of bounds pointer accesses.
 pub unsafe fn f64_xany_avx2_nofma_dot(x: &[f64], y: &[f64]) -> f64 {
+    f64_xany_avx2_nofma_dot_plus(x, y, 0.0)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of the first `n` elements of two `f64` vectors, ignoring
+/// any elements beyond them, i.e. the unused capacity of padded buffers.
+///
+/// # Panics
+///
+/// Panics if `n` is greater than the length of either vector.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_dot_prefix(x: &[f64], y: &[f64], n: usize) -> f64 {
+    assert!(
+        n <= x.len().min(y.len()),
+        "Prefix length {n} exceeds the length of the vectors"
+    );
+    f64_xany_avx2_nofma_dot(x.get_unchecked(..n), y.get_unchecked(..n))
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes `bias` plus the dot product of two `f64` vectors.
+///
+/// The accumulator is seeded with `bias` rather than adding it to the result,
+/// which saves a separate add when applying the bias of a linear layer.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f64_xany_avx2_nofma_dot_plus(x: &[f64], y: &[f64], bias: f64) -> f64 {
     debug_assert_eq!(x.len(), y.len());
 
     let len = x.len();
     let offset_from = len % 32;
-    let mut total = 0.0;
+    let mut total = bias;
 
     let x_ptr = x.as_ptr();
     let y_ptr = y.as_ptr();
@@ -103,12 +139,25 @@ pub unsafe fn f64_xany_avx2_nofma_dot(x: &[f64], y: &[f64]) -> f64 {
     if offset_from != 0 {
         let tail = offset_from % 4;
 
-        while i < (len - tail) {
+        // Alternate between independent accumulators so short vectors, which are
+        // handled entirely by this loop, don't serialize on a single add chain.
+        while i + 8 <= (len - tail) {
+            let x1 = _mm256_loadu_pd(x_ptr.add(i));
+            let y1 = _mm256_loadu_pd(y_ptr.add(i));
+            let x2 = _mm256_loadu_pd(x_ptr.add(i + 4));
+            let y2 = _mm256_loadu_pd(y_ptr.add(i + 4));
+
+            acc1 = _mm256_add_pd(acc1, _mm256_mul_pd(x1, y1));
+            acc2 = _mm256_add_pd(acc2, _mm256_mul_pd(x2, y2));
+
+            i += 8;
+        }
+
+        if i < (len - tail) {
             let x = _mm256_loadu_pd(x_ptr.add(i));
             let y = _mm256_loadu_pd(y_ptr.add(i));
 
-            let res = _mm256_mul_pd(x, y);
-            acc1 = _mm256_add_pd(acc1, res);
+            acc3 = _mm256_add_pd(acc3, _mm256_mul_pd(x, y));
 
             i += 4;
         }
@@ -180,13 +229,49 @@ pub unsafe fn f64_xconst_avx2_fma_dot<const DIMS: usize>(x: &[f64], y: &[f64]) -
 /// Vectors **MUST** be the same length, otherwise this routine
 /// will become immediately UB due to out of bounds pointer accesses.
 pub unsafe fn f64_xany_avx2_fma_dot(x: &[f64], y: &[f64]) -> f64 {
+    f64_xany_avx2_fma_dot_plus(x, y, 0.0)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of the first `n` elements of two `f64` vectors, ignoring
+/// any elements beyond them, i.e. the unused capacity of padded buffers.
+///
+/// # Panics
+///
+/// Panics if `n` is greater than the length of either vector.
+///
+/// # Safety
+///
+/// This method assumes AVX2 and FMA instructions are available, if this method is
+/// executed on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_fma_dot_prefix(x: &[f64], y: &[f64], n: usize) -> f64 {
+    assert!(
+        n <= x.len().min(y.len()),
+        "Prefix length {n} exceeds the length of the vectors"
+    );
+    f64_xany_avx2_fma_dot(x.get_unchecked(..n), y.get_unchecked(..n))
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes `bias` plus the dot product of two `f64` vectors.
+///
+/// The accumulator is seeded with `bias` rather than adding it to the result,
+/// which saves a separate add when applying the bias of a linear layer.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f64_xany_avx2_fma_dot_plus(x: &[f64], y: &[f64], bias: f64) -> f64 {
     use crate::math::*;
 
     debug_assert_eq!(x.len(), y.len());
 
     let len = x.len();
     let offset_from = len % 32;
-    let mut total = 0.0;
+    let mut total = bias;
 
     let x_ptr = x.as_ptr();
     let y_ptr = y.as_ptr();
@@ -221,11 +306,25 @@ pub unsafe fn f64_xany_avx2_fma_dot(x: &[f64], y: &[f64]) -> f64 {
     if offset_from != 0 {
         let tail = offset_from % 4;
 
-        while i < (len - tail) {
+        // Alternate between independent accumulators so short vectors, which are
+        // handled entirely by this loop, don't serialize on a single FMA chain.
+        while i + 8 <= (len - tail) {
+            let x1 = _mm256_loadu_pd(x_ptr.add(i));
+            let y1 = _mm256_loadu_pd(y_ptr.add(i));
+            let x2 = _mm256_loadu_pd(x_ptr.add(i + 4));
+            let y2 = _mm256_loadu_pd(y_ptr.add(i + 4));
+
+            acc1 = _mm256_fmadd_pd(x1, y1, acc1);
+            acc2 = _mm256_fmadd_pd(x2, y2, acc2);
+
+            i += 8;
+        }
+
+        if i < (len - tail) {
             let x = _mm256_loadu_pd(x_ptr.add(i));
             let y = _mm256_loadu_pd(y_ptr.add(i));
 
-            acc1 = _mm256_fmadd_pd(x, y, acc1);
+            acc3 = _mm256_fmadd_pd(x, y, acc3);
 
             i += 4;
         }
@@ -348,6 +447,66 @@ unsafe fn execute_f64_x32_fma_block_dot_product(
     *acc8 = _mm256_fmadd_pd(x8, y8, *acc8);
 }
 
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the dot product of two vectors split into multiple chunks, such as
+/// segmented buffers, without copying them into contiguous vectors first.
+///
+/// The chunk boundaries of `x` and `y` do not need to line up or be aligned to
+/// the register width, each overlapping pair of sub-slices is reduced with the
+/// contiguous routine and accumulated by seeding it with the running total.
+///
+/// ```py
+/// total: f64
+/// x: [f64; D]
+/// y: [f64; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+/// ```
+///
+/// # Safety
+///
+/// The chunks of both vectors **MUST** contain the same number of elements in total.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_dot_chunks(x: &[&[f64]], y: &[&[f64]]) -> f64 {
+    fold_chunk_pairs(x, y, 0.0, |x, y, total| {
+        f64_xany_avx2_nofma_dot_plus(x, y, total)
+    })
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes the dot product of two vectors split into multiple chunks, such as
+/// segmented buffers, without copying them into contiguous vectors first.
+///
+/// The chunk boundaries of `x` and `y` do not need to line up or be aligned to
+/// the register width, each overlapping pair of sub-slices is reduced with the
+/// contiguous routine and accumulated by seeding it with the running total.
+///
+/// ```py
+/// total: f64
+/// x: [f64; D]
+/// y: [f64; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+/// ```
+///
+/// # Safety
+///
+/// The chunks of both vectors **MUST** contain the same number of elements in total.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_fma_dot_chunks(x: &[&[f64]], y: &[&[f64]]) -> f64 {
+    fold_chunk_pairs(x, y, 0.0, |x, y, total| {
+        f64_xany_avx2_fma_dot_plus(x, y, total)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -380,4 +539,63 @@ mod tests {
         let dist = unsafe { f64_xconst_avx2_nofma_dot::<1024>(&x, &y) };
         assert_is_close(dist as f32, simple_dot(&x, &y) as f32)
     }
+
+    #[test]
+    fn test_xany_dot_tail_lengths() {
+        for len in [0, 3, 4, 8, 12, 15, 28, 31, 32, 33, 60] {
+            let (x, y) = get_sample_vectors::<f64>(len);
+            let expected = simple_dot(&x, &y) as f32;
+            assert_is_close(unsafe { f64_xany_avx2_nofma_dot(&x, &y) } as f32, expected);
+            assert_is_close(unsafe { f64_xany_avx2_fma_dot(&x, &y) } as f32, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_dot_plus() {
+        for len in [0, 3, 127, 1024] {
+            let (x, y) = get_sample_vectors::<f64>(len);
+            let expected = (simple_dot(&x, &y) + 1.5) as f32;
+            let dist = unsafe { f64_xany_avx2_nofma_dot_plus(&x, &y, 1.5) };
+            assert_is_close(dist as f32, expected);
+            let dist = unsafe { f64_xany_avx2_fma_dot_plus(&x, &y, 1.5) };
+            assert_is_close(dist as f32, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_dot_chunks() {
+        let (x, y) = get_sample_vectors::<f64>(531);
+        let expected = simple_dot(&x, &y);
+
+        // Chunk boundaries deliberately differ between the two vectors.
+        let x_chunks = [&x[..13], &x[13..13], &x[13..200], &x[200..]];
+        let y_chunks = [&y[..64], &y[64..301], &y[301..]];
+
+        let dist = unsafe { f64_xany_avx2_nofma_dot_chunks(&x_chunks, &y_chunks) };
+        assert_is_close(dist as f32, expected as f32);
+        let dist = unsafe { f64_xany_avx2_fma_dot_chunks(&x_chunks, &y_chunks) };
+        assert_is_close(dist as f32, expected as f32);
+    }
+
+    #[test]
+    fn test_xany_nofma_dot_prefix() {
+        let (mut x, y) = get_sample_vectors::<f64>(531);
+        x.truncate(517);
+        for n in [0, 7, 64, 200, 517] {
+            let dist = unsafe { f64_xany_avx2_nofma_dot_prefix(&x, &y, n) };
+            let expected = unsafe { f64_xany_avx2_nofma_dot(&x[..n], &y[..n]) };
+            assert_eq!(dist, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_fma_dot_prefix() {
+        let (mut x, y) = get_sample_vectors::<f64>(531);
+        x.truncate(517);
+        for n in [0, 7, 64, 200, 517] {
+            let dist = unsafe { f64_xany_avx2_fma_dot_prefix(&x, &y, n) };
+            let expected = unsafe { f64_xany_avx2_fma_dot(&x[..n], &y[..n]) };
+            assert_eq!(dist, expected);
+        }
+    }
 }
@@ -0,0 +1,129 @@
+use core::arch::x86_64::*;
+use core::ptr;
+
+use crate::danger::{exp_avx2_pd, partially_overlaps};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes `e^x` of each element of the vector, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// result: [f64; D]
+///
+/// for i in 0..D:
+///     result[i] = exp(x[i])
+/// ```
+///
+/// `e^r` is evaluated with the Cephes rational approximation after reducing the
+/// input by multiples of `ln(2)`, the relative error is within a few ULP. Inputs above
+/// `709.0` produce `inf`, inputs below `-708.3` produce `0.0` rather than a subnormal
+/// and `NaN` is propagated.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_exp(x: &[f64], result: &mut [f64]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    f64_avx2_exp_to(x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes `e^x` of each element of the vector in place.
+///
+/// See [f64_xany_avx2_nofma_exp] for the accuracy and range of the approximation.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_exp_inplace(x: &mut [f64]) {
+    let x_ptr = x.as_mut_ptr();
+    f64_avx2_exp_to(x_ptr, x_ptr, x.len())
+}
+
+#[inline(always)]
+/// Computes `e^x` of the values read from `x_ptr` writing the output to the `result`
+/// pointer, the tail is padded out to a full register so every element goes through
+/// the same instructions.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn f64_avx2_exp_to(x_ptr: *const f64, result: *mut f64, len: usize) {
+    let offset_from = len % 8;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_pd(x_ptr.add(i));
+        let x2 = _mm256_loadu_pd(x_ptr.add(i + 4));
+
+        _mm256_storeu_pd(result.add(i), exp_pd(x1));
+        _mm256_storeu_pd(result.add(i + 4), exp_pd(x2));
+
+        i += 8;
+    }
+
+    while i < len {
+        let n = (len - i).min(4);
+        let mut tail = [0.0f64; 4];
+        ptr::copy_nonoverlapping(x_ptr.add(i), tail.as_mut_ptr(), n);
+
+        let v = exp_pd(_mm256_loadu_pd(tail.as_ptr()));
+        _mm256_storeu_pd(tail.as_mut_ptr(), v);
+        ptr::copy_nonoverlapping(tail.as_ptr(), result.add(i), n);
+
+        i += n;
+    }
+}
+
+#[inline(always)]
+/// Computes `e^x` with [exp_avx2_pd], replacing the clamped results of inputs outside
+/// of its range with `inf` and `0.0`.
+unsafe fn exp_pd(x: __m256d) -> __m256d {
+    let y = exp_avx2_pd(x);
+
+    let overflow = _mm256_cmp_pd::<_CMP_GT_OQ>(x, _mm256_set1_pd(709.0));
+    let underflow = _mm256_cmp_pd::<_CMP_LT_OQ>(x, _mm256_set1_pd(-708.3));
+
+    let y = _mm256_blendv_pd(y, _mm256_set1_pd(f64::INFINITY), overflow);
+    _mm256_blendv_pd(y, _mm256_setzero_pd(), underflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_exp() {
+        let mut x = (0..531)
+            .map(|i| (i as f64 - 265.0) * 2.5)
+            .collect::<Vec<_>>();
+        x.extend([-1000.0, 1000.0, 0.0, f64::NAN]);
+
+        let mut result = vec![0.0; x.len()];
+        unsafe { f64_xany_avx2_nofma_exp(&x, &mut result) };
+        for (v, r) in x.iter().zip(&result).take(531) {
+            let e = v.exp();
+            match *v {
+                v if v < -708.3 => assert_eq!(*r, 0.0),
+                v if v > 709.0 => assert_eq!(*r, f64::INFINITY),
+                _ => assert!((r - e).abs() <= 1e-14 * e, "exp({v}) = {r} != {e}"),
+            }
+        }
+        assert_eq!(&result[531..534], [0.0, f64::INFINITY, 1.0]);
+        assert!(result[534].is_nan());
+
+        unsafe { f64_xany_avx2_nofma_exp_inplace(&mut x) };
+        assert_eq!(&x[..534], &result[..534]);
+    }
+}
@@ -0,0 +1,243 @@
+use core::arch::x86_64::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Returns the index of the first element in the vector greater than `value`,
+/// or `None` if no element matches.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     if x[i] > value:
+///         return i
+/// return None
+/// ```
+///
+/// Comparisons involving `NaN` are always false, so `NaN` elements never match.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_find_first_gt(x: &[f64], value: f64) -> Option<usize> {
+    find_first::<false>(x, value)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Returns the index of the first element in the vector equal to `value`,
+/// or `None` if no element matches.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     if x[i] == value:
+///         return i
+/// return None
+/// ```
+///
+/// Comparisons involving `NaN` are always false, so searching for `NaN` never matches.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_find_first_eq(x: &[f64], value: f64) -> Option<usize> {
+    find_first::<true>(x, value)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Returns the index of the last element in the vector greater than `value`,
+/// or `None` if no element matches.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+///
+/// for i in reversed(0..D):
+///     if x[i] > value:
+///         return i
+/// return None
+/// ```
+///
+/// Comparisons involving `NaN` are always false, so `NaN` elements never match.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_find_last_gt(x: &[f64], value: f64) -> Option<usize> {
+    find_last::<false>(x, value)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Returns the index of the last element in the vector equal to `value`,
+/// or `None` if no element matches.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+///
+/// for i in reversed(0..D):
+///     if x[i] == value:
+///         return i
+/// return None
+/// ```
+///
+/// Comparisons involving `NaN` are always false, so searching for `NaN` never matches.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_find_last_eq(x: &[f64], value: f64) -> Option<usize> {
+    find_last::<true>(x, value)
+}
+
+#[inline(always)]
+unsafe fn find_first<const EQ: bool>(x: &[f64], value: f64) -> Option<usize> {
+    let len = x.len();
+    let x_ptr = x.as_ptr();
+    let value_reg = _mm256_set1_pd(value);
+
+    let mut i = 0;
+    while i + 16 <= len {
+        let mask = block_mask_x16::<EQ>(x_ptr.add(i), value_reg);
+        if mask != 0 {
+            return Some(i + mask.trailing_zeros() as usize);
+        }
+
+        i += 16;
+    }
+
+    while i + 4 <= len {
+        let mask = register_mask::<EQ>(x_ptr.add(i), value_reg);
+        if mask != 0 {
+            return Some(i + mask.trailing_zeros() as usize);
+        }
+
+        i += 4;
+    }
+
+    (i..len).find(|&n| matches::<EQ>(*x.get_unchecked(n), value))
+}
+
+#[inline(always)]
+unsafe fn find_last<const EQ: bool>(x: &[f64], value: f64) -> Option<usize> {
+    let x_ptr = x.as_ptr();
+    let value_reg = _mm256_set1_pd(value);
+
+    let mut end = x.len();
+    while end >= 16 {
+        let mask = block_mask_x16::<EQ>(x_ptr.add(end - 16), value_reg);
+        if mask != 0 {
+            // The mask only occupies the lower 16 bits.
+            return Some(end - 1 - (mask.leading_zeros() - 16) as usize);
+        }
+
+        end -= 16;
+    }
+
+    while end >= 4 {
+        let mask = register_mask::<EQ>(x_ptr.add(end - 4), value_reg);
+        if mask != 0 {
+            // The mask only occupies the lower 4 bits.
+            return Some(end - 1 - (mask.leading_zeros() - 28) as usize);
+        }
+
+        end -= 4;
+    }
+
+    (0..end)
+        .rev()
+        .find(|&n| matches::<EQ>(*x.get_unchecked(n), value))
+}
+
+#[inline(always)]
+/// Compares 16 elements against `value`, returning a bitmask with bit `n` set
+/// where element `n` matched.
+unsafe fn block_mask_x16<const EQ: bool>(x: *const f64, value: __m256d) -> u32 {
+    let m1 = register_mask::<EQ>(x, value);
+    let m2 = register_mask::<EQ>(x.add(4), value);
+    let m3 = register_mask::<EQ>(x.add(8), value);
+    let m4 = register_mask::<EQ>(x.add(12), value);
+
+    m1 | (m2 << 4) | (m3 << 8) | (m4 << 12)
+}
+
+#[inline(always)]
+unsafe fn register_mask<const EQ: bool>(x: *const f64, value: __m256d) -> u32 {
+    let x = _mm256_loadu_pd(x);
+    let cmp = if EQ {
+        _mm256_cmp_pd::<_CMP_EQ_OQ>(x, value)
+    } else {
+        _mm256_cmp_pd::<_CMP_GT_OQ>(x, value)
+    };
+
+    _mm256_movemask_pd(cmp) as u32
+}
+
+#[inline(always)]
+fn matches<const EQ: bool>(x: f64, value: f64) -> bool {
+    if EQ {
+        x == value
+    } else {
+        x > value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_find_gt() {
+        for len in [0, 5, 8, 31, 32, 77, 531] {
+            let (x, _) = get_sample_vectors::<f64>(len);
+            for threshold in [0.0, 0.5, 0.99, 1.0] {
+                let first = unsafe { f64_xany_avx2_nofma_find_first_gt(&x, threshold) };
+                assert_eq!(first, x.iter().position(|v| *v > threshold));
+
+                let last = unsafe { f64_xany_avx2_nofma_find_last_gt(&x, threshold) };
+                assert_eq!(last, x.iter().rposition(|v| *v > threshold));
+            }
+        }
+    }
+
+    #[test]
+    fn test_xany_find_eq() {
+        let (sample, _) = get_sample_vectors::<f64>(531);
+        for pos in [0, 7, 8, 31, 32, 200, 529, 530] {
+            let mut x = sample.clone();
+            x[pos] = 2.0;
+            x[530 - pos] = 2.0;
+
+            let first = unsafe { f64_xany_avx2_nofma_find_first_eq(&x, 2.0) };
+            assert_eq!(first, x.iter().position(|v| *v == 2.0));
+
+            let last = unsafe { f64_xany_avx2_nofma_find_last_eq(&x, 2.0) };
+            assert_eq!(last, x.iter().rposition(|v| *v == 2.0));
+        }
+
+        let first = unsafe { f64_xany_avx2_nofma_find_first_eq(&sample, 3.0) };
+        assert_eq!(first, None);
+    }
+
+    #[test]
+    fn test_xany_find_nan_never_matches() {
+        let x = vec![f64::NAN; 40];
+        assert_eq!(unsafe { f64_xany_avx2_nofma_find_first_gt(&x, 0.0) }, None);
+        assert_eq!(
+            unsafe { f64_xany_avx2_nofma_find_first_eq(&x, f64::NAN) },
+            None
+        );
+        assert_eq!(unsafe { f64_xany_avx2_nofma_find_last_gt(&x, 0.0) }, None);
+    }
+}
@@ -0,0 +1,206 @@
+use core::arch::x86_64::*;
+
+use crate::danger::{f64_ieee_maximum, f64_ieee_minimum};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sets each element of the mutable vector `x` to the IEEE 754-2019 `maximum` of
+/// itself and the matching element of `y`.
+///
+/// Unlike the other `max` routines, which follow the semantics of `vmaxpd` and return
+/// the second operand when either input is `NaN` or both are zero, this routine
+/// propagates `NaN` and orders `-0.0` below `+0.0`. This is slightly slower, so
+/// it should only be used when standards compliant behaviour is required.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// y: [f64; D]
+///
+/// for i in 0..D:
+///     if is_nan(x[i]) or is_nan(y[i]):
+///         x[i] = NaN
+///     elif x[i] == y[i]:
+///         x[i] = +0.0 if is_positive(x[i]) or is_positive(y[i]) else x[i]
+///     else:
+///         x[i] = x[i] if x[i] > y[i] else y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_maximum_vertical(x: &mut [f64], y: &[f64]) {
+    execute_ieee_op::<true>(x, y)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sets each element of the mutable vector `x` to the IEEE 754-2019 `minimum` of
+/// itself and the matching element of `y`.
+///
+/// Unlike the other `min` routines, which follow the semantics of `vminpd` and return
+/// the second operand when either input is `NaN` or both are zero, this routine
+/// propagates `NaN` and orders `-0.0` below `+0.0`. This is slightly slower, so
+/// it should only be used when standards compliant behaviour is required.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// y: [f64; D]
+///
+/// for i in 0..D:
+///     if is_nan(x[i]) or is_nan(y[i]):
+///         x[i] = NaN
+///     elif x[i] == y[i]:
+///         x[i] = -0.0 if is_negative(x[i]) or is_negative(y[i]) else x[i]
+///     else:
+///         x[i] = x[i] if x[i] < y[i] else y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_minimum_vertical(x: &mut [f64], y: &[f64]) {
+    execute_ieee_op::<false>(x, y)
+}
+
+#[inline(always)]
+unsafe fn execute_ieee_op<const MAX: bool>(x: &mut [f64], y: &[f64]) {
+    debug_assert_eq!(x.len(), y.len());
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_mut_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_pd(x_ptr.add(i));
+        let x2 = _mm256_loadu_pd(x_ptr.add(i + 4));
+        let x3 = _mm256_loadu_pd(x_ptr.add(i + 8));
+        let x4 = _mm256_loadu_pd(x_ptr.add(i + 12));
+
+        let y1 = _mm256_loadu_pd(y_ptr.add(i));
+        let y2 = _mm256_loadu_pd(y_ptr.add(i + 4));
+        let y3 = _mm256_loadu_pd(y_ptr.add(i + 8));
+        let y4 = _mm256_loadu_pd(y_ptr.add(i + 12));
+
+        _mm256_storeu_pd(x_ptr.add(i), ieee_op::<MAX>(x1, y1));
+        _mm256_storeu_pd(x_ptr.add(i + 4), ieee_op::<MAX>(x2, y2));
+        _mm256_storeu_pd(x_ptr.add(i + 8), ieee_op::<MAX>(x3, y3));
+        _mm256_storeu_pd(x_ptr.add(i + 12), ieee_op::<MAX>(x4, y4));
+
+        i += 16;
+    }
+
+    while i < (len - (len % 4)) {
+        let x = _mm256_loadu_pd(x_ptr.add(i));
+        let y = _mm256_loadu_pd(y_ptr.add(i));
+        _mm256_storeu_pd(x_ptr.add(i), ieee_op::<MAX>(x, y));
+
+        i += 4;
+    }
+
+    while i < len {
+        let x = x.get_unchecked_mut(i);
+        let y = *y.get_unchecked(i);
+        *x = if MAX {
+            f64_ieee_maximum(*x, y)
+        } else {
+            f64_ieee_minimum(*x, y)
+        };
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+unsafe fn ieee_op<const MAX: bool>(x: __m256d, y: __m256d) -> __m256d {
+    // Equal values can only differ by their sign when they are both zero, in which
+    // case ANDing the bits keeps `+0.0` and ORing them keeps `-0.0`.
+    let (r, equal_r) = if MAX {
+        (_mm256_max_pd(x, y), _mm256_and_pd(x, y))
+    } else {
+        (_mm256_min_pd(x, y), _mm256_or_pd(x, y))
+    };
+
+    let is_equal = _mm256_cmp_pd::<_CMP_EQ_OQ>(x, y);
+    let r = _mm256_blendv_pd(r, equal_r, is_equal);
+
+    // Adding the inputs produces a quiet NaN if either of them is NaN.
+    let is_unordered = _mm256_cmp_pd::<_CMP_UNORD_Q>(x, y);
+    _mm256_blendv_pd(r, _mm256_add_pd(x, y), is_unordered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    fn special_vectors() -> (Vec<f64>, Vec<f64>) {
+        let specials = [
+            (f64::NAN, 1.0),
+            (1.0, f64::NAN),
+            (-0.0, 0.0),
+            (0.0, -0.0),
+            (-0.0, -0.0),
+            (f64::INFINITY, f64::NEG_INFINITY),
+            (-1.0, 2.0),
+            (3.0, 3.0),
+        ];
+
+        let (mut x, mut y) = get_sample_vectors::<f64>(53);
+        for (i, (a, b)) in specials.iter().cycle().take(53).enumerate() {
+            if i % 2 == 0 {
+                x[i] = *a;
+                y[i] = *b;
+            }
+        }
+
+        (x, y)
+    }
+
+    fn assert_bitwise_eq(x: &[f64], expected: &[f64]) {
+        for (a, b) in x.iter().zip(expected) {
+            assert!(
+                a.to_bits() == b.to_bits() || (a.is_nan() && b.is_nan()),
+                "{a} != {b}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_xany_maximum_vertical() {
+        let (mut x, y) = special_vectors();
+        let expected = x
+            .iter()
+            .zip(y.iter())
+            .map(|(a, b)| f64_ieee_maximum(*a, *b))
+            .collect::<Vec<_>>();
+
+        unsafe { f64_xany_avx2_nofma_maximum_vertical(&mut x, &y) };
+        assert_bitwise_eq(&x, &expected);
+    }
+
+    #[test]
+    fn test_xany_minimum_vertical() {
+        let (mut x, y) = special_vectors();
+        let expected = x
+            .iter()
+            .zip(y.iter())
+            .map(|(a, b)| f64_ieee_minimum(*a, *b))
+            .collect::<Vec<_>>();
+
+        unsafe { f64_xany_avx2_nofma_minimum_vertical(&mut x, &y) };
+        assert_bitwise_eq(&x, &expected);
+    }
+}
@@ -0,0 +1,318 @@
+use core::arch::x86_64::*;
+use core::ptr;
+
+use crate::danger::{log_avx2_pd, partially_overlaps};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the natural logarithm of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// result: [f64; D]
+///
+/// for i in 0..D:
+///     result[i] = ln(x[i])
+/// ```
+///
+/// With `precise` the relative error is within a few ULP, otherwise a cheaper
+/// approximation is used with a relative error of roughly `1e-7`. `0.0` produces
+/// `-inf`, `inf` produces `inf` and negative or `NaN` inputs produce `NaN`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_ln(precise: bool, x: &[f64], result: &mut [f64]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let (x_ptr, result_ptr) = (x.as_ptr(), result.as_mut_ptr());
+    if precise {
+        log_to::<true>(x_ptr, result_ptr, x.len(), 1.0)
+    } else {
+        log_to::<false>(x_ptr, result_ptr, x.len(), 1.0)
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the natural logarithm of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     x[i] = ln(x[i])
+/// ```
+///
+/// See [f64_xany_avx2_nofma_ln] for the accuracy with and without `precise`.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_ln_inplace(precise: bool, x: &mut [f64]) {
+    let x_ptr = x.as_mut_ptr();
+    if precise {
+        log_to::<true>(x_ptr, x_ptr, x.len(), 1.0)
+    } else {
+        log_to::<false>(x_ptr, x_ptr, x.len(), 1.0)
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the base 2 logarithm of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// result: [f64; D]
+///
+/// for i in 0..D:
+///     result[i] = log2(x[i])
+/// ```
+///
+/// This is computed as `ln(x) * log2(e)`, see [f64_xany_avx2_nofma_ln] for the accuracy
+/// with and without `precise` and the handling of special values.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_log2(precise: bool, x: &[f64], result: &mut [f64]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let (x_ptr, result_ptr) = (x.as_ptr(), result.as_mut_ptr());
+    if precise {
+        log_to::<true>(x_ptr, result_ptr, x.len(), core::f64::consts::LOG2_E)
+    } else {
+        log_to::<false>(x_ptr, result_ptr, x.len(), core::f64::consts::LOG2_E)
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the base 2 logarithm of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     x[i] = log2(x[i])
+/// ```
+///
+/// See [f64_xany_avx2_nofma_log2] for the accuracy with and without `precise`.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_log2_inplace(precise: bool, x: &mut [f64]) {
+    let x_ptr = x.as_mut_ptr();
+    if precise {
+        log_to::<true>(x_ptr, x_ptr, x.len(), core::f64::consts::LOG2_E)
+    } else {
+        log_to::<false>(x_ptr, x_ptr, x.len(), core::f64::consts::LOG2_E)
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the base 10 logarithm of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// result: [f64; D]
+///
+/// for i in 0..D:
+///     result[i] = log10(x[i])
+/// ```
+///
+/// This is computed as `ln(x) * log10(e)`, see [f64_xany_avx2_nofma_ln] for the accuracy
+/// with and without `precise` and the handling of special values.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_log10(precise: bool, x: &[f64], result: &mut [f64]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let (x_ptr, result_ptr) = (x.as_ptr(), result.as_mut_ptr());
+    if precise {
+        log_to::<true>(x_ptr, result_ptr, x.len(), core::f64::consts::LOG10_E)
+    } else {
+        log_to::<false>(x_ptr, result_ptr, x.len(), core::f64::consts::LOG10_E)
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the base 10 logarithm of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     x[i] = log10(x[i])
+/// ```
+///
+/// See [f64_xany_avx2_nofma_log10] for the accuracy with and without `precise`.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_log10_inplace(precise: bool, x: &mut [f64]) {
+    let x_ptr = x.as_mut_ptr();
+    if precise {
+        log_to::<true>(x_ptr, x_ptr, x.len(), core::f64::consts::LOG10_E)
+    } else {
+        log_to::<false>(x_ptr, x_ptr, x.len(), core::f64::consts::LOG10_E)
+    }
+}
+
+#[inline(always)]
+/// Computes `ln(x) * scale` of the values read from `x_ptr` writing the output to the
+/// `result` pointer, the tail is padded out to a full register so every element goes
+/// through the same instructions.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn log_to<const PRECISE: bool>(
+    x_ptr: *const f64,
+    result: *mut f64,
+    len: usize,
+    scale: f64,
+) {
+    let scale = _mm256_set1_pd(scale);
+    let offset_from = len % 8;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_pd(x_ptr.add(i));
+        let x2 = _mm256_loadu_pd(x_ptr.add(i + 4));
+
+        _mm256_storeu_pd(result.add(i), _mm256_mul_pd(ln_pd::<PRECISE>(x1), scale));
+        _mm256_storeu_pd(
+            result.add(i + 4),
+            _mm256_mul_pd(ln_pd::<PRECISE>(x2), scale),
+        );
+
+        i += 8;
+    }
+
+    while i < len {
+        let n = (len - i).min(4);
+        let mut tail = [1.0f64; 4];
+        ptr::copy_nonoverlapping(x_ptr.add(i), tail.as_mut_ptr(), n);
+
+        let v = _mm256_mul_pd(ln_pd::<PRECISE>(_mm256_loadu_pd(tail.as_ptr())), scale);
+        _mm256_storeu_pd(tail.as_mut_ptr(), v);
+        ptr::copy_nonoverlapping(tail.as_ptr(), result.add(i), n);
+
+        i += n;
+    }
+}
+
+#[inline(always)]
+/// Computes `ln(x)` with [log_avx2_pd], scaling subnormal inputs into its range and
+/// replacing the results of `0.0`, `inf`, negative and `NaN` inputs.
+unsafe fn ln_pd<const PRECISE: bool>(x: __m256d) -> __m256d {
+    let subnormal = _mm256_cmp_pd::<_CMP_LT_OQ>(x, _mm256_set1_pd(f64::MIN_POSITIVE));
+    let scaled = _mm256_mul_pd(x, _mm256_set1_pd(4_503_599_627_370_496.0));
+    let y = log_avx2_pd::<PRECISE>(_mm256_blendv_pd(x, scaled, subnormal));
+    let correction = _mm256_set1_pd(52.0 * core::f64::consts::LN_2);
+    let y = _mm256_sub_pd(y, _mm256_and_pd(subnormal, correction));
+
+    let is_inf = _mm256_cmp_pd::<_CMP_EQ_OQ>(x, _mm256_set1_pd(f64::INFINITY));
+    let is_zero = _mm256_cmp_pd::<_CMP_EQ_OQ>(x, _mm256_setzero_pd());
+    let is_nan = _mm256_cmp_pd::<_CMP_NGE_UQ>(x, _mm256_setzero_pd());
+
+    let y = _mm256_blendv_pd(y, _mm256_set1_pd(f64::INFINITY), is_inf);
+    let y = _mm256_blendv_pd(y, _mm256_set1_pd(f64::NEG_INFINITY), is_zero);
+    _mm256_blendv_pd(y, _mm256_set1_pd(f64::NAN), is_nan)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    fn assert_relative_error(result: &[f64], expected: &[f64], tolerance: f64) {
+        for (r, e) in result.iter().zip(expected) {
+            assert!((r - e).abs() <= tolerance * e.abs(), "{r} vs {e}");
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_log() {
+        let (x, _) = get_sample_vectors::<f64>(531);
+        let x = x
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v + 1e-3) * 2.0f64.powi(i as i32 % 41 - 20))
+            .collect::<Vec<_>>();
+
+        let mut result = vec![0.0; x.len()];
+        for (precise, tolerance) in [(true, 1e-14), (false, 2e-7)] {
+            unsafe { f64_xany_avx2_nofma_ln(precise, &x, &mut result) };
+            let expected = x.iter().map(|v| v.ln()).collect::<Vec<_>>();
+            assert_relative_error(&result, &expected, tolerance);
+
+            unsafe { f64_xany_avx2_nofma_log2(precise, &x, &mut result) };
+            let expected = x.iter().map(|v| v.log2()).collect::<Vec<_>>();
+            assert_relative_error(&result, &expected, tolerance);
+
+            unsafe { f64_xany_avx2_nofma_log10(precise, &x, &mut result) };
+            let expected = x.iter().map(|v| v.log10()).collect::<Vec<_>>();
+            assert_relative_error(&result, &expected, tolerance);
+        }
+
+        let mut special = vec![
+            1.0,
+            0.0,
+            -0.0,
+            f64::INFINITY,
+            f64::MIN_POSITIVE / 4.0,
+            -1.0,
+            f64::NAN,
+        ];
+        unsafe { f64_xany_avx2_nofma_ln_inplace(true, &mut special) };
+        assert_eq!(
+            &special[..4],
+            [0.0, f64::NEG_INFINITY, f64::NEG_INFINITY, f64::INFINITY]
+        );
+        assert_relative_error(&special[4..5], &[(f64::MIN_POSITIVE / 4.0).ln()], 1e-14);
+        assert!(special[5].is_nan() && special[6].is_nan());
+    }
+}
@@ -0,0 +1,121 @@
+use core::arch::x86_64::*;
+
+use crate::danger::overlaps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Reverses the order of the elements in `x`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// result: [f64; D]
+///
+/// for i in 0..D:
+///     result[i] = x[D - i - 1]
+/// ```
+///
+/// Elements are reversed within each register via `_mm256_permute4x64_pd` while the
+/// order of the registers themselves is reversed as they are written out.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// `result` **MUST NOT** overlap `x`, as elements of `x` would be read after being
+/// overwritten, use the `_inplace` variant to operate on a single buffer.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_reverse(x: &[f64], result: &mut [f64]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(!overlaps(x, result), "`result` must not overlap `x`");
+
+    let len = x.len();
+    let offset_from = len % 4;
+    let x_ptr = x.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x = _mm256_loadu_pd(x_ptr.add(i));
+        _mm256_storeu_pd(
+            result_ptr.add(len - i - 4),
+            _mm256_permute4x64_pd::<0b00_01_10_11>(x),
+        );
+
+        i += 4;
+    }
+
+    while i < len {
+        *result.get_unchecked_mut(len - i - 1) = *x.get_unchecked(i);
+
+        i += 1;
+    }
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Reverses the order of the elements in `x` in place.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+///
+/// for i in 0..(D / 2):
+///     x[i], x[D - i - 1] = x[D - i - 1], x[i]
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_reverse_inplace(x: &mut [f64]) {
+    let len = x.len();
+    let x_ptr = x.as_mut_ptr();
+
+    let mut i = 0;
+    while (i + 4) * 2 <= len {
+        let front_ptr = x_ptr.add(i);
+        let back_ptr = x_ptr.add(len - i - 4);
+
+        let front = _mm256_loadu_pd(front_ptr);
+        let back = _mm256_loadu_pd(back_ptr);
+        _mm256_storeu_pd(front_ptr, _mm256_permute4x64_pd::<0b00_01_10_11>(back));
+        _mm256_storeu_pd(back_ptr, _mm256_permute4x64_pd::<0b00_01_10_11>(front));
+
+        i += 4;
+    }
+
+    x.get_unchecked_mut(i..len - i).reverse();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_reverse() {
+        for size in [0, 3, 16, 127, 131, 547] {
+            let (x, _) = get_sample_vectors::<f64>(size);
+            let expected = x.iter().copied().rev().collect::<Vec<_>>();
+
+            let mut result = vec![0.0; size];
+            unsafe { f64_xany_avx2_nofma_reverse(&x, &mut result) };
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_reverse_inplace() {
+        for size in [0, 3, 16, 127, 131, 547] {
+            let (mut x, _) = get_sample_vectors::<f64>(size);
+            let expected = x.iter().copied().rev().collect::<Vec<_>>();
+
+            unsafe { f64_xany_avx2_nofma_reverse_inplace(&mut x) };
+            assert_eq!(x, expected);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use core::arch::x86_64::*;
+
+use crate::danger::RoundingMode;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Rounds each element of `x` to an integer in the given [RoundingMode], writing
+/// the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// result: [f64; D]
+///
+/// for i in 0..D:
+///     result[i] = round(x[i], mode)
+/// ```
+///
+/// `NaN` and infinite values are returned unchanged.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_round(
+    mode: RoundingMode,
+    x: &[f64],
+    result: &mut [f64],
+) {
+    debug_assert_eq!(x.len(), result.len());
+
+    round_with_mode(mode, x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Rounds each element of `x` to an integer in the given [RoundingMode] in place.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     x[i] = round(x[i], mode)
+/// ```
+///
+/// `NaN` and infinite values are returned unchanged.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_round_inplace(mode: RoundingMode, x: &mut [f64]) {
+    let x_ptr = x.as_mut_ptr();
+    round_with_mode(mode, x_ptr, x_ptr, x.len())
+}
+
+#[inline(always)]
+/// Selects the `_mm256_round_pd` control matching `mode` for [round_to].
+unsafe fn round_with_mode(
+    mode: RoundingMode,
+    x_ptr: *const f64,
+    result: *mut f64,
+    len: usize,
+) {
+    match mode {
+        RoundingMode::NearestEven => round_to::<
+            { _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC },
+        >(mode, x_ptr, result, len),
+        RoundingMode::TowardZero => {
+            round_to::<{ _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC }>(
+                mode, x_ptr, result, len,
+            )
+        },
+        RoundingMode::TowardPositive => round_to::<
+            { _MM_FROUND_TO_POS_INF | _MM_FROUND_NO_EXC },
+        >(mode, x_ptr, result, len),
+        RoundingMode::TowardNegative => round_to::<
+            { _MM_FROUND_TO_NEG_INF | _MM_FROUND_NO_EXC },
+        >(mode, x_ptr, result, len),
+    }
+}
+
+#[inline(always)]
+/// Rounds the values read from `x_ptr` with the `_mm256_round_pd` control `ROUND`
+/// writing the output to the `result` pointer, the scalar tail is rounded by the
+/// matching `mode`.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn round_to<const ROUND: i32>(
+    mode: RoundingMode,
+    x_ptr: *const f64,
+    result_ptr: *mut f64,
+    len: usize,
+) {
+    let offset_from = len % 16;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for k in [0, 4, 8, 12] {
+            let x = _mm256_round_pd::<ROUND>(_mm256_loadu_pd(x_ptr.add(i + k)));
+            _mm256_storeu_pd(result_ptr.add(i + k), x);
+        }
+
+        i += 16;
+    }
+
+    while i < (len - (len % 4)) {
+        let x = _mm256_round_pd::<ROUND>(_mm256_loadu_pd(x_ptr.add(i)));
+        _mm256_storeu_pd(result_ptr.add(i), x);
+
+        i += 4;
+    }
+
+    while i < len {
+        *result_ptr.add(i) = mode.round_f64(*x_ptr.add(i));
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::f64_xany_fallback_nofma_round;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_round() {
+        let modes = [
+            RoundingMode::NearestEven,
+            RoundingMode::TowardZero,
+            RoundingMode::TowardPositive,
+            RoundingMode::TowardNegative,
+        ];
+
+        for len in [0, 5, 8, 37, 531] {
+            let (x, _) = get_sample_vectors::<f64>(len);
+            let mut x = x.iter().map(|v| v * 0.37 - 0.5).collect::<Vec<_>>();
+            if len > 0 {
+                // Exact half way values exercise the tie breaking.
+                x[0] = 2.5;
+                x[len / 2] = -0.5;
+            }
+
+            for mode in modes {
+                let mut expected = vec![0.0; len];
+                let mut result = vec![0.0; len];
+                unsafe {
+                    f64_xany_fallback_nofma_round(mode, &x, &mut expected);
+                    f64_xany_avx2_nofma_round(mode, &x, &mut result);
+                }
+                assert_eq!(result, expected, "{mode:?}");
+
+                let mut inplace = x.clone();
+                unsafe { f64_xany_avx2_nofma_round_inplace(mode, &mut inplace) };
+                assert_eq!(inplace, expected, "{mode:?}");
+            }
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the square root of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// result: [f64; D]
+///
+/// for i in 0..D:
+///     result[i] = sqrt(x[i])
+/// ```
+///
+/// The result is correctly rounded, negative inputs produce `NaN`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_sqrt(x: &[f64], result: &mut [f64]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    f64_avx2_sqrt_to(x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the square root of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     x[i] = sqrt(x[i])
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_sqrt_inplace(x: &mut [f64]) {
+    let x_ptr = x.as_mut_ptr();
+    f64_avx2_sqrt_to(x_ptr, x_ptr, x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the square root of each element of the `[f64; DIMS]` vector, writing the
+/// result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// result: [f64; D]
+///
+/// for i in 0..D:
+///     result[i] = sqrt(x[i])
+/// ```
+///
+/// # Safety
+///
+/// DIMS **MUST** be a multiple of `16` and both vectors must be `DIMS` in length,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xconst_avx2_nofma_sqrt<const DIMS: usize>(
+    x: &[f64],
+    result: &mut [f64],
+) {
+    debug_assert_eq!(x.len(), DIMS);
+    debug_assert_eq!(result.len(), DIMS);
+    debug_assert_eq!(DIMS % 16, 0, "Input dimensions must be multiple of 16");
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    f64_avx2_sqrt_blocks_to(x.as_ptr(), result.as_mut_ptr(), DIMS)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the square root of each element of the `[f64; DIMS]` vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     x[i] = sqrt(x[i])
+/// ```
+///
+/// # Safety
+///
+/// DIMS **MUST** be a multiple of `16` and the vector must be `DIMS` in length,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xconst_avx2_nofma_sqrt_inplace<const DIMS: usize>(x: &mut [f64]) {
+    debug_assert_eq!(x.len(), DIMS);
+    debug_assert_eq!(DIMS % 16, 0, "Input dimensions must be multiple of 16");
+
+    let x_ptr = x.as_mut_ptr();
+    f64_avx2_sqrt_blocks_to(x_ptr, x_ptr, DIMS)
+}
+
+#[inline(always)]
+/// Computes the square root of the values read from `x_ptr` writing the output to the
+/// `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn f64_avx2_sqrt_to(x_ptr: *const f64, result: *mut f64, len: usize) {
+    let offset_from = len % 16;
+    f64_avx2_sqrt_blocks_to(x_ptr, result, len - offset_from);
+
+    let mut i = len - offset_from;
+    while i < len {
+        *result.add(i) = (*x_ptr.add(i)).sqrt();
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Computes the square root of the first `len` values read from `x_ptr` in blocks of
+/// `16`, `len` must be a multiple of `16`.
+unsafe fn f64_avx2_sqrt_blocks_to(x_ptr: *const f64, result: *mut f64, len: usize) {
+    let mut i = 0;
+    while i < len {
+        let v1 = _mm256_loadu_pd(x_ptr.add(i));
+        let v2 = _mm256_loadu_pd(x_ptr.add(i + 4));
+        let v3 = _mm256_loadu_pd(x_ptr.add(i + 8));
+        let v4 = _mm256_loadu_pd(x_ptr.add(i + 12));
+
+        _mm256_storeu_pd(result.add(i), _mm256_sqrt_pd(v1));
+        _mm256_storeu_pd(result.add(i + 4), _mm256_sqrt_pd(v2));
+        _mm256_storeu_pd(result.add(i + 8), _mm256_sqrt_pd(v3));
+        _mm256_storeu_pd(result.add(i + 12), _mm256_sqrt_pd(v4));
+
+        i += 16;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_sqrt() {
+        let (mut x, _) = get_sample_vectors::<f64>(131);
+        x[3] = -1.0;
+        let expected = x.iter().map(|v| v.sqrt()).collect::<Vec<f64>>();
+
+        let mut result = vec![0.0; x.len()];
+        unsafe { f64_xany_avx2_nofma_sqrt(&x, &mut result) };
+        assert!(result[3].is_nan());
+        assert_eq!(&result[4..], &expected[4..]);
+
+        unsafe { f64_xany_avx2_nofma_sqrt_inplace(&mut x) };
+        assert_eq!(&x[4..], &expected[4..]);
+    }
+
+    #[test]
+    fn test_xconst_nofma_sqrt() {
+        let (mut x, _) = get_sample_vectors::<f64>(1024);
+        let expected = x.iter().map(|v| v.sqrt()).collect::<Vec<f64>>();
+
+        let mut result = vec![0.0; x.len()];
+        unsafe { f64_xconst_avx2_nofma_sqrt::<1024>(&x, &mut result) };
+        assert_eq!(result, expected);
+
+        unsafe { f64_xconst_avx2_nofma_sqrt_inplace::<1024>(&mut x) };
+        assert_eq!(x, expected);
+    }
+}
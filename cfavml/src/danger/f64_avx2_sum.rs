@@ -345,6 +345,58 @@ unsafe fn sum_x64_block(
     *acc8 = _mm256_add_pd(*acc8, x8);
 }
 
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sums all elements of the vector and multiplies the total by `scale`.
+///
+/// ```py
+/// D: int
+/// total: f64
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return scale * total
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_scaled_sum(scale: f64, x: &[f64]) -> f64 {
+    scale * f64_xany_avx2_nofma_sum_horizontal(x)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sums all elements of a vector split into multiple chunks, such as a segmented
+/// buffer, without copying them into a contiguous vector first.
+///
+/// Each chunk is summed with the contiguous routine, so chunks do not need to be
+/// aligned to the register width.
+///
+/// ```py
+/// total: f64
+/// chunks: [[f64; N]; C]
+///
+/// for chunk in chunks:
+///     for i in 0..N:
+///         total = total + chunk[i]
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_sum_chunks(chunks: &[&[f64]]) -> f64 {
+    let mut total = 0.0;
+    for chunk in chunks {
+        total += f64_xany_avx2_nofma_sum_horizontal(chunk);
+    }
+    total
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -409,4 +461,19 @@ mod tests {
         let sum = unsafe { f64_xany_avx2_nofma_sum_vertical(&matrix_view) };
         assert_eq!(sum, expected_vertical_sum);
     }
+
+    #[test]
+    fn test_xany_nofma_scaled_sum() {
+        let (x, _) = get_sample_vectors::<f64>(131);
+        let sum = unsafe { f64_xany_avx2_nofma_scaled_sum(0.5, &x) };
+        assert_is_close(sum as f32, (0.5 * x.iter().sum::<f64>()) as f32);
+    }
+
+    #[test]
+    fn test_xany_nofma_sum_chunks() {
+        let (x, _) = get_sample_vectors::<f64>(531);
+        let chunks = [&x[..13], &x[13..13], &x[13..200], &x[200..]];
+        let sum = unsafe { f64_xany_avx2_nofma_sum_chunks(&chunks) };
+        assert_is_close(sum as f32, x.iter().sum::<f64>() as f32);
+    }
 }
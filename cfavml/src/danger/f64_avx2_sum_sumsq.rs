@@ -0,0 +1,192 @@
+use core::arch::x86_64::*;
+
+use crate::danger::sum_avx2_pd;
+use crate::math::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes both the sum and the sum of squares of one `f64` vector in a single pass,
+/// returning `(sum(x), sum(x * x))`.
+///
+/// ```py
+/// D: int
+/// sum: f64
+/// sum_sq: f64
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     sum = sum + x[i]
+///     sum_sq = sum_sq + (x[i] * x[i])
+/// ```
+///
+/// These values can be used to compute the variance as `E[x²] - E[x]²` with a single
+/// pass over memory, however, this formula suffers from catastrophic cancellation
+/// when the mean is large relative to the variance. Where precision matters more than
+/// speed, a two-pass or Welford style computation should be used instead.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_sum_sumsq(x: &[f64]) -> (f64, f64) {
+    let len = x.len();
+    let offset_from = len % 16;
+    let x_ptr = x.as_ptr();
+
+    let mut sum0 = _mm256_setzero_pd();
+    let mut sum1 = _mm256_setzero_pd();
+    let mut sum2 = _mm256_setzero_pd();
+    let mut sum3 = _mm256_setzero_pd();
+    let mut sumsq0 = _mm256_setzero_pd();
+    let mut sumsq1 = _mm256_setzero_pd();
+    let mut sumsq2 = _mm256_setzero_pd();
+    let mut sumsq3 = _mm256_setzero_pd();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x0 = _mm256_loadu_pd(x_ptr.add(i));
+        let x1 = _mm256_loadu_pd(x_ptr.add(i + 4));
+        let x2 = _mm256_loadu_pd(x_ptr.add(i + 8));
+        let x3 = _mm256_loadu_pd(x_ptr.add(i + 12));
+
+        sum0 = _mm256_add_pd(sum0, x0);
+        sum1 = _mm256_add_pd(sum1, x1);
+        sum2 = _mm256_add_pd(sum2, x2);
+        sum3 = _mm256_add_pd(sum3, x3);
+        sumsq0 = _mm256_add_pd(sumsq0, _mm256_mul_pd(x0, x0));
+        sumsq1 = _mm256_add_pd(sumsq1, _mm256_mul_pd(x1, x1));
+        sumsq2 = _mm256_add_pd(sumsq2, _mm256_mul_pd(x2, x2));
+        sumsq3 = _mm256_add_pd(sumsq3, _mm256_mul_pd(x3, x3));
+
+        i += 16;
+    }
+
+    while i < (len - (len % 4)) {
+        let x = _mm256_loadu_pd(x_ptr.add(i));
+        sum0 = _mm256_add_pd(sum0, x);
+        sumsq0 = _mm256_add_pd(sumsq0, _mm256_mul_pd(x, x));
+
+        i += 4;
+    }
+
+    sum0 = _mm256_add_pd(_mm256_add_pd(sum0, sum1), _mm256_add_pd(sum2, sum3));
+    sumsq0 = _mm256_add_pd(_mm256_add_pd(sumsq0, sumsq1), _mm256_add_pd(sumsq2, sumsq3));
+
+    let mut sum = sum_avx2_pd(sum0);
+    let mut sum_sq = sum_avx2_pd(sumsq0);
+
+    while i < len {
+        let x = *x.get_unchecked(i);
+        sum = AutoMath::add(sum, x);
+        sum_sq = AutoMath::add(sum_sq, AutoMath::mul(x, x));
+
+        i += 1;
+    }
+
+    (sum, sum_sq)
+}
+
+#[target_feature(enable = "avx2", enable = "fma")]
+#[inline]
+/// Computes both the sum and the sum of squares of one `f64` vector in a single pass,
+/// returning `(sum(x), sum(x * x))`.
+///
+/// ```py
+/// D: int
+/// sum: f64
+/// sum_sq: f64
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     sum = sum + x[i]
+///     sum_sq = sum_sq + (x[i] * x[i])
+/// ```
+///
+/// These values can be used to compute the variance as `E[x²] - E[x]²` with a single
+/// pass over memory, however, this formula suffers from catastrophic cancellation
+/// when the mean is large relative to the variance. Where precision matters more than
+/// speed, a two-pass or Welford style computation should be used instead.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_fma_sum_sumsq(x: &[f64]) -> (f64, f64) {
+    let len = x.len();
+    let offset_from = len % 16;
+    let x_ptr = x.as_ptr();
+
+    let mut sum0 = _mm256_setzero_pd();
+    let mut sum1 = _mm256_setzero_pd();
+    let mut sum2 = _mm256_setzero_pd();
+    let mut sum3 = _mm256_setzero_pd();
+    let mut sumsq0 = _mm256_setzero_pd();
+    let mut sumsq1 = _mm256_setzero_pd();
+    let mut sumsq2 = _mm256_setzero_pd();
+    let mut sumsq3 = _mm256_setzero_pd();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x0 = _mm256_loadu_pd(x_ptr.add(i));
+        let x1 = _mm256_loadu_pd(x_ptr.add(i + 4));
+        let x2 = _mm256_loadu_pd(x_ptr.add(i + 8));
+        let x3 = _mm256_loadu_pd(x_ptr.add(i + 12));
+
+        sum0 = _mm256_add_pd(sum0, x0);
+        sum1 = _mm256_add_pd(sum1, x1);
+        sum2 = _mm256_add_pd(sum2, x2);
+        sum3 = _mm256_add_pd(sum3, x3);
+        sumsq0 = _mm256_fmadd_pd(x0, x0, sumsq0);
+        sumsq1 = _mm256_fmadd_pd(x1, x1, sumsq1);
+        sumsq2 = _mm256_fmadd_pd(x2, x2, sumsq2);
+        sumsq3 = _mm256_fmadd_pd(x3, x3, sumsq3);
+
+        i += 16;
+    }
+
+    while i < (len - (len % 4)) {
+        let x = _mm256_loadu_pd(x_ptr.add(i));
+        sum0 = _mm256_add_pd(sum0, x);
+        sumsq0 = _mm256_fmadd_pd(x, x, sumsq0);
+
+        i += 4;
+    }
+
+    sum0 = _mm256_add_pd(_mm256_add_pd(sum0, sum1), _mm256_add_pd(sum2, sum3));
+    sumsq0 = _mm256_add_pd(_mm256_add_pd(sumsq0, sumsq1), _mm256_add_pd(sumsq2, sumsq3));
+
+    let mut sum = sum_avx2_pd(sum0);
+    let mut sum_sq = sum_avx2_pd(sumsq0);
+
+    while i < len {
+        let x = *x.get_unchecked(i);
+        sum = AutoMath::add(sum, x);
+        sum_sq = AutoMath::add(sum_sq, AutoMath::mul(x, x));
+
+        i += 1;
+    }
+
+    (sum, sum_sq)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_xany_nofma_sum_sumsq() {
+        let (x, _) = get_sample_vectors::<f64>(131);
+        let (sum, sum_sq) = unsafe { f64_xany_avx2_nofma_sum_sumsq(&x) };
+        assert_is_close(sum as f32, x.iter().sum::<f64>() as f32);
+        assert_is_close(sum_sq as f32, simple_dot(&x, &x) as f32);
+    }
+
+    #[test]
+    fn test_xany_fma_sum_sumsq() {
+        let (x, _) = get_sample_vectors::<f64>(131);
+        let (sum, sum_sq) = unsafe { f64_xany_avx2_fma_sum_sumsq(&x) };
+        assert_is_close(sum as f32, x.iter().sum::<f64>() as f32);
+        assert_is_close(sum_sq as f32, simple_dot(&x, &x) as f32);
+    }
+}
@@ -0,0 +1,149 @@
+use core::arch::x86_64::*;
+use core::ptr;
+
+use crate::danger::{exp_avx2_pd, partially_overlaps};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the hyperbolic tangent of each element of the vector, writing the result
+/// to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// result: [f64; D]
+///
+/// for i in 0..D:
+///     result[i] = tanh(x[i])
+/// ```
+///
+/// Inputs smaller than `0.625` in magnitude are evaluated with the Cephes rational
+/// approximation `x + x^3 * P(x^2) / Q(x^2)`, larger inputs with `1 - 2 / (e^2|x| + 1)`
+/// and the sign of `x` is copied onto either result. The relative error is within a
+/// few ULP and `NaN` is propagated.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_tanh(x: &[f64], result: &mut [f64]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    f64_avx2_tanh_to(x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the hyperbolic tangent of each element of the vector in place.
+///
+/// See [f64_xany_avx2_nofma_tanh] for the accuracy of the approximation.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx2_nofma_tanh_inplace(x: &mut [f64]) {
+    let x_ptr = x.as_mut_ptr();
+    f64_avx2_tanh_to(x_ptr, x_ptr, x.len())
+}
+
+#[inline(always)]
+/// Computes `tanh(x)` of the values read from `x_ptr` writing the output to the
+/// `result` pointer, the tail is padded out to a full register so every element goes
+/// through the same instructions.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn f64_avx2_tanh_to(x_ptr: *const f64, result: *mut f64, len: usize) {
+    let offset_from = len % 8;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_pd(x_ptr.add(i));
+        let x2 = _mm256_loadu_pd(x_ptr.add(i + 4));
+
+        _mm256_storeu_pd(result.add(i), tanh_pd(x1));
+        _mm256_storeu_pd(result.add(i + 4), tanh_pd(x2));
+
+        i += 8;
+    }
+
+    while i < len {
+        let n = (len - i).min(4);
+        let mut tail = [0.0f64; 4];
+        ptr::copy_nonoverlapping(x_ptr.add(i), tail.as_mut_ptr(), n);
+
+        let v = tanh_pd(_mm256_loadu_pd(tail.as_ptr()));
+        _mm256_storeu_pd(tail.as_mut_ptr(), v);
+        ptr::copy_nonoverlapping(tail.as_ptr(), result.add(i), n);
+
+        i += n;
+    }
+}
+
+#[inline(always)]
+/// Computes `tanh(x)` with a rational approximation for small inputs and [exp_avx2_pd]
+/// for larger inputs, selecting between the two per lane.
+unsafe fn tanh_pd(x: __m256d) -> __m256d {
+    let sign_mask = _mm256_set1_pd(-0.0);
+    let abs = _mm256_andnot_pd(sign_mask, x);
+    let x2 = _mm256_mul_pd(x, x);
+
+    let mut p = _mm256_set1_pd(-9.643_991_794_250_523e-1);
+    for coeff in [-9.928_772_310_019_185e1, -1.614_687_684_417_079_5e3] {
+        p = _mm256_add_pd(_mm256_mul_pd(p, x2), _mm256_set1_pd(coeff));
+    }
+
+    let mut q = _mm256_add_pd(x2, _mm256_set1_pd(1.128_116_784_916_329_3e2));
+    for coeff in [2.235_488_390_601_004_6e3, 4.844_063_053_251_255e3] {
+        q = _mm256_add_pd(_mm256_mul_pd(q, x2), _mm256_set1_pd(coeff));
+    }
+
+    let ratio = _mm256_div_pd(p, q);
+    let small = _mm256_add_pd(x, _mm256_mul_pd(_mm256_mul_pd(x, x2), ratio));
+    let small = _mm256_or_pd(small, _mm256_and_pd(sign_mask, x));
+
+    let one = _mm256_set1_pd(1.0);
+    let e = exp_avx2_pd(_mm256_add_pd(abs, abs));
+    let large = _mm256_sub_pd(
+        one,
+        _mm256_div_pd(_mm256_set1_pd(2.0), _mm256_add_pd(e, one)),
+    );
+    let large = _mm256_or_pd(large, _mm256_and_pd(sign_mask, x));
+
+    let is_small = _mm256_cmp_pd::<_CMP_LT_OQ>(abs, _mm256_set1_pd(0.625));
+    _mm256_blendv_pd(large, small, is_small)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_tanh() {
+        let mut x = (0..531)
+            .map(|i| (i as f64 - 265.0) / 20.0)
+            .collect::<Vec<_>>();
+        x.extend([-30.0, 30.0, 1e-5, -0.0, f64::NEG_INFINITY, f64::NAN]);
+
+        let mut result = vec![0.0; x.len()];
+        unsafe { f64_xany_avx2_nofma_tanh(&x, &mut result) };
+        for (v, r) in x.iter().zip(&result).take(534) {
+            let e = v.tanh();
+            assert!((r - e).abs() <= 1e-14 * e.abs(), "tanh({v}) = {r} != {e}");
+        }
+        assert_eq!(&result[531..533], [-1.0, 1.0]);
+        assert!(result[534] == 0.0 && result[534].is_sign_negative());
+        assert_eq!(result[535], -1.0);
+        assert!(result[536].is_nan());
+
+        unsafe { f64_xany_avx2_nofma_tanh_inplace(&mut x) };
+        assert_eq!(&x[..536], &result[..536]);
+    }
+}
@@ -1,6 +1,7 @@
 use core::arch::x86_64::*;
 
 use crate::danger::{
+    fold_chunk_pairs,
     load_two_variable_size_avx512_pd,
     offsets_avx512_pd,
     sum_avx512_x8_pd,
@@ -71,6 +72,46 @@ pub unsafe fn f64_xconst_avx512_fma_dot<const DIMS: usize>(x: &[f64], y: &[f64])
 /// Values within the vector should also be finite, although it is not
 /// going to crash the program, it is going to produce insane numbers.
 pub unsafe fn f64_xany_avx512_fma_dot(x: &[f64], y: &[f64]) -> f64 {
+    f64_xany_avx512_fma_dot_plus(x, y, 0.0)
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the dot product of the first `n` elements of two `f64` vectors, ignoring
+/// any elements beyond them, i.e. the unused capacity of padded buffers.
+///
+/// # Panics
+///
+/// Panics if `n` is greater than the length of either vector.
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx512_fma_dot_prefix(x: &[f64], y: &[f64], n: usize) -> f64 {
+    assert!(
+        n <= x.len().min(y.len()),
+        "Prefix length {n} exceeds the length of the vectors"
+    );
+    f64_xany_avx512_fma_dot(x.get_unchecked(..n), y.get_unchecked(..n))
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes `bias` plus the dot product of two `f64` vectors.
+///
+/// The accumulator is seeded with `bias` rather than adding it to the result,
+/// which saves a separate add when applying the bias of a linear layer.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f64_xany_avx512_fma_dot_plus(x: &[f64], y: &[f64], bias: f64) -> f64 {
     debug_assert_eq!(x.len(), y.len());
 
     let len = x.len();
@@ -79,7 +120,7 @@ pub unsafe fn f64_xany_avx512_fma_dot(x: &[f64], y: &[f64]) -> f64 {
     let x = x.as_ptr();
     let y = y.as_ptr();
 
-    let mut acc1 = _mm512_setzero_pd();
+    let mut acc1 = _mm512_zextpd128_pd512(_mm_set_sd(bias));
     let mut acc2 = _mm512_setzero_pd();
     let mut acc3 = _mm512_setzero_pd();
     let mut acc4 = _mm512_setzero_pd();
@@ -106,12 +147,24 @@ pub unsafe fn f64_xany_avx512_fma_dot(x: &[f64], y: &[f64]) -> f64 {
         i += 64;
     }
 
-    while i < len {
-        let (x, y) = load_two_variable_size_avx512_pd(x.add(i), y.add(i), len - i);
+    // Alternate between independent accumulators so short vectors, which are
+    // handled entirely by this loop, don't serialize on a single FMA chain.
+    while i + 8 < len {
+        let x1 = _mm512_loadu_pd(x.add(i));
+        let y1 = _mm512_loadu_pd(y.add(i));
+        let (x2, y2) =
+            load_two_variable_size_avx512_pd(x.add(i + 8), y.add(i + 8), len - i - 8);
+
+        acc1 = _mm512_fmadd_pd(x1, y1, acc1);
+        acc2 = _mm512_fmadd_pd(x2, y2, acc2);
+
+        i += 16;
+    }
 
-        acc1 = _mm512_fmadd_pd(x, y, acc1);
+    if i < len {
+        let (x, y) = load_two_variable_size_avx512_pd(x.add(i), y.add(i), len - i);
 
-        i += 8;
+        acc3 = _mm512_fmadd_pd(x, y, acc3);
     }
 
     sum_avx512_x8_pd(acc1, acc2, acc3, acc4, acc5, acc6, acc7, acc8)
@@ -165,6 +218,36 @@ unsafe fn execute_f64_x64_fma_block_dot_product(
     *acc8 = _mm512_fmadd_pd(x8, y8, *acc8);
 }
 
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the dot product of two vectors split into multiple chunks, such as
+/// segmented buffers, without copying them into contiguous vectors first.
+///
+/// The chunk boundaries of `x` and `y` do not need to line up or be aligned to
+/// the register width, each overlapping pair of sub-slices is reduced with the
+/// contiguous routine and accumulated by seeding it with the running total.
+///
+/// ```py
+/// total: f64
+/// x: [f64; D]
+/// y: [f64; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+/// ```
+///
+/// # Safety
+///
+/// The chunks of both vectors **MUST** contain the same number of elements in total.
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx512_fma_dot_chunks(x: &[&[f64]], y: &[&[f64]]) -> f64 {
+    fold_chunk_pairs(x, y, 0.0, |x, y, total| {
+        f64_xany_avx512_fma_dot_plus(x, y, total)
+    })
+}
+
 #[cfg(all(test, target_feature = "avx512f"))]
 mod tests {
     use super::*;
@@ -183,4 +266,46 @@ mod tests {
         let dist = unsafe { f64_xany_avx512_fma_dot(&x, &y) };
         assert_is_close(dist as f32, simple_dot(&x, &y) as f32);
     }
+
+    #[test]
+    fn test_xany_fma_dot_tail_lengths() {
+        for len in [0, 3, 8, 9, 16, 20, 63, 64, 65, 120] {
+            let (x, y) = get_sample_vectors::<f64>(len);
+            let dist = unsafe { f64_xany_avx512_fma_dot(&x, &y) };
+            assert_is_close(dist as f32, simple_dot(&x, &y) as f32);
+        }
+    }
+
+    #[test]
+    fn test_xany_fma_dot_plus() {
+        for len in [0, 3, 547, 1024] {
+            let (x, y) = get_sample_vectors::<f64>(len);
+            let dist = unsafe { f64_xany_avx512_fma_dot_plus(&x, &y, 1.5) };
+            assert_is_close(dist as f32, (simple_dot(&x, &y) + 1.5) as f32);
+        }
+    }
+
+    #[test]
+    fn test_xany_fma_dot_chunks() {
+        let (x, y) = get_sample_vectors::<f64>(531);
+        let expected = simple_dot(&x, &y);
+
+        // Chunk boundaries deliberately differ between the two vectors.
+        let x_chunks = [&x[..13], &x[13..13], &x[13..200], &x[200..]];
+        let y_chunks = [&y[..64], &y[64..301], &y[301..]];
+
+        let dist = unsafe { f64_xany_avx512_fma_dot_chunks(&x_chunks, &y_chunks) };
+        assert_is_close(dist as f32, expected as f32);
+    }
+
+    #[test]
+    fn test_xany_fma_dot_prefix() {
+        let (mut x, y) = get_sample_vectors::<f64>(531);
+        x.truncate(517);
+        for n in [0, 7, 64, 200, 517] {
+            let dist = unsafe { f64_xany_avx512_fma_dot_prefix(&x, &y, n) };
+            let expected = unsafe { f64_xany_avx512_fma_dot(&x[..n], &y[..n]) };
+            assert_eq!(dist, expected);
+        }
+    }
 }
@@ -4,7 +4,10 @@ use crate::danger::{offsets_avx512_pd, sum_avx512_x8_pd, CHUNK_0, CHUNK_1};
 
 #[target_feature(enable = "avx512f")]
 #[inline]
-/// Computes the squared norm of one `[f64; DIMS]` vector.
+/// Computes the squared L2 norm of one `[f64; DIMS]` vector.
+///
+/// This is equivalent to `dot(x, x)`, the square root is **not** applied,
+/// use the sibling `l2_norm` routine for the L2 norm.
 ///
 /// # Safety
 ///
@@ -51,7 +54,10 @@ pub unsafe fn f64_xconst_avx512_fma_norm<const DIMS: usize>(x: &[f64]) -> f64 {
 
 #[target_feature(enable = "avx512f")]
 #[inline]
-/// Computes the squared norm of one `f64` vector.
+/// Computes the squared L2 norm of one `f64` vector.
+///
+/// This is equivalent to `dot(x, x)`, the square root is **not** applied,
+/// use the sibling `l2_norm` routine for the L2 norm.
 ///
 /// # Safety
 ///
@@ -143,6 +149,62 @@ unsafe fn execute_f64_x64_fma_block_norm(
     *acc8 = _mm512_fmadd_pd(x8, x8, *acc8);
 }
 
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the L2 norm of one `[f64; DIMS]` vector.
+///
+/// ```py
+/// return sqrt(norm(x))
+/// ```
+///
+/// # Safety
+///
+/// DIMS **MUST** be a multiple of `64` and vectors must be `DIMS` in length,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f64_xconst_avx512_fma_l2_norm<const DIMS: usize>(x: &[f64]) -> f64 {
+    f64_xconst_avx512_fma_norm::<DIMS>(x).sqrt()
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the L2 norm of one `f64` vector.
+///
+/// ```py
+/// return sqrt(norm(x))
+/// ```
+///
+/// # Safety
+///
+/// NOTE:
+/// Values within the vector should also be finite, although it is not
+/// going to crash the program, it is going to produce insane numbers.
+pub unsafe fn f64_xany_avx512_fma_l2_norm(x: &[f64]) -> f64 {
+    f64_xany_avx512_fma_norm(x).sqrt()
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Computes the sum of squares of one f64 vector multiplied by `scale`.
+///
+/// This is the squared L2 norm scaled in the same pass, for example
+/// `1 / (D - 1)` when computing a variance with Bessel's correction.
+///
+/// ```py
+/// return scale * norm(x)
+/// ```
+///
+/// # Safety
+///
+/// This method assumes avx512 instructions are available, if this method is executed
+/// on non-avx512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx512_fma_sum_of_scaled_squares(scale: f64, x: &[f64]) -> f64 {
+    scale * f64_xany_avx512_fma_norm(x)
+}
+
 #[cfg(all(test, target_feature = "avx512f"))]
 mod tests {
     use super::*;
@@ -161,4 +223,25 @@ mod tests {
         let dist = unsafe { f64_xany_avx512_fma_norm(&x) };
         assert_is_close(dist as f32, simple_dot(&x, &x) as f32);
     }
+
+    #[test]
+    fn test_xconst_fma_l2_norm() {
+        let (x, _) = get_sample_vectors(1024);
+        let dist = unsafe { f64_xconst_avx512_fma_l2_norm::<1024>(&x) };
+        assert_is_close(dist as f32, simple_dot(&x, &x).sqrt() as f32);
+    }
+
+    #[test]
+    fn test_xany_fma_l2_norm() {
+        let (x, _) = get_sample_vectors(547);
+        let dist = unsafe { f64_xany_avx512_fma_l2_norm(&x) };
+        assert_is_close(dist as f32, simple_dot(&x, &x).sqrt() as f32);
+    }
+
+    #[test]
+    fn test_xany_fma_sum_of_scaled_squares() {
+        let (x, _) = get_sample_vectors(547);
+        let res = unsafe { f64_xany_avx512_fma_sum_of_scaled_squares(0.25, &x) };
+        assert_is_close(res as f32, (0.25 * simple_dot(&x, &x)) as f32);
+    }
 }
@@ -10,6 +10,17 @@ use crate::danger::{
     CHUNK_1,
 };
 
+/// Vectors shorter than this use [sum_small_f64] rather than the fully unrolled
+/// `64` element blocks.
+///
+/// Until a vector spans several blocks, reducing the 8 accumulators of the full unroll
+/// and winding down its tail a register at a time costs more than the extra
+/// accumulators save. Measured with the `bench_sum_threshold` benchmark on an
+/// AVX512 Xeon, the short path is faster below `320` elements, the two are within
+/// noise up to `512` and the full unroll pulls ahead beyond that, so the threshold
+/// is six full `64` element blocks, matching the `f32` routines.
+const SMALL_SUM_THRESHOLD: usize = 384;
+
 #[target_feature(enable = "avx512f")]
 #[inline]
 /// Sums all elements of the vector.
@@ -85,6 +96,16 @@ pub unsafe fn f64_xconst_avx512_nofma_sum_horizontal<const DIMS: usize>(
 /// This method assumes AVX512 instructions are available, if this method is executed
 /// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
 pub unsafe fn f64_xany_avx512_nofma_sum_horizontal(x: &[f64]) -> f64 {
+    if x.len() < SMALL_SUM_THRESHOLD {
+        sum_small_f64(x)
+    } else {
+        sum_unrolled_f64(x)
+    }
+}
+
+#[inline(always)]
+/// Sums all elements of the vector using 8 accumulators over `64` element blocks.
+unsafe fn sum_unrolled_f64(x: &[f64]) -> f64 {
     let len = x.len();
     let offset_from = len % 64;
 
@@ -128,6 +149,67 @@ pub unsafe fn f64_xany_avx512_nofma_sum_horizontal(x: &[f64]) -> f64 {
     sum_avx512_x8_pd(acc1, acc2, acc3, acc4, acc5, acc6, acc7, acc8)
 }
 
+#[inline(always)]
+/// Sums all elements of a short vector using 2 accumulators.
+unsafe fn sum_small_f64(x: &[f64]) -> f64 {
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x = x.as_ptr();
+
+    let mut acc1 = _mm512_setzero_pd();
+    let mut acc2 = _mm512_setzero_pd();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        acc1 = _mm512_add_pd(acc1, _mm512_loadu_pd(x.add(i)));
+        acc2 = _mm512_add_pd(acc2, _mm512_loadu_pd(x.add(i + 8)));
+
+        i += 16;
+    }
+
+    while i < len {
+        let n = len - i;
+
+        let x = load_one_variable_size_avx512_pd(x.add(i), n);
+        acc1 = _mm512_add_pd(acc1, x);
+
+        i += 8;
+    }
+
+    _mm512_reduce_add_pd(_mm512_add_pd(acc1, acc2))
+}
+
+#[cfg(feature = "bench-utils")]
+#[doc(hidden)]
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Sums all elements of the vector with the short vector path regardless of its
+/// length, only exposed to benchmark the small sum threshold.
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx512_nofma_sum_small(x: &[f64]) -> f64 {
+    sum_small_f64(x)
+}
+
+#[cfg(feature = "bench-utils")]
+#[doc(hidden)]
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Sums all elements of the vector with the fully unrolled path regardless of its
+/// length, only exposed to benchmark the small sum threshold.
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx512_nofma_sum_unrolled(x: &[f64]) -> f64 {
+    sum_unrolled_f64(x)
+}
+
 #[allow(unused)]
 #[target_feature(enable = "avx512f")]
 #[inline]
@@ -322,6 +404,58 @@ unsafe fn sum_x64_block(
     *acc8 = _mm512_add_pd(*acc8, x8);
 }
 
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Sums all elements of the vector and multiplies the total by `scale`.
+///
+/// ```py
+/// D: int
+/// total: f64
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return scale * total
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx512_nofma_scaled_sum(scale: f64, x: &[f64]) -> f64 {
+    scale * f64_xany_avx512_nofma_sum_horizontal(x)
+}
+
+#[target_feature(enable = "avx512f")]
+#[inline]
+/// Sums all elements of a vector split into multiple chunks, such as a segmented
+/// buffer, without copying them into a contiguous vector first.
+///
+/// Each chunk is summed with the contiguous routine, so chunks do not need to be
+/// aligned to the register width.
+///
+/// ```py
+/// total: f64
+/// chunks: [[f64; N]; C]
+///
+/// for chunk in chunks:
+///     for i in 0..N:
+///         total = total + chunk[i]
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX512 instructions are available, if this method is executed
+/// on non-AVX512 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn f64_xany_avx512_nofma_sum_chunks(chunks: &[&[f64]]) -> f64 {
+    let mut total = 0.0;
+    for chunk in chunks {
+        total += f64_xany_avx512_nofma_sum_horizontal(chunk);
+    }
+    total
+}
+
 #[cfg(all(test, target_feature = "avx512f"))]
 #[cfg(test)]
 mod tests {
@@ -342,6 +476,13 @@ mod tests {
         assert_is_close(sum as f32, x.iter().sum::<f64>() as f32);
     }
 
+    #[test]
+    fn test_xany_nofma_sum_above_small_threshold() {
+        let (x, _) = get_sample_vectors(SMALL_SUM_THRESHOLD + 131);
+        let sum = unsafe { f64_xany_avx512_nofma_sum_horizontal(&x) };
+        assert_is_close(sum as f32, x.iter().sum::<f64>() as f32);
+    }
+
     #[test]
     fn test_xconst_nofma_sum_vertical() {
         let mut matrix = Vec::new();
@@ -387,4 +528,19 @@ mod tests {
         let sum = unsafe { f64_xany_avx512_nofma_sum_vertical(&matrix_view) };
         assert_eq!(sum, expected_vertical_sum);
     }
+
+    #[test]
+    fn test_xany_nofma_scaled_sum() {
+        let (x, _) = get_sample_vectors::<f64>(547);
+        let sum = unsafe { f64_xany_avx512_nofma_scaled_sum(0.5, &x) };
+        assert_is_close(sum as f32, (0.5 * x.iter().sum::<f64>()) as f32);
+    }
+
+    #[test]
+    fn test_xany_nofma_sum_chunks() {
+        let (x, _) = get_sample_vectors::<f64>(531);
+        let chunks = [&x[..13], &x[13..13], &x[13..200], &x[200..]];
+        let sum = unsafe { f64_xany_avx512_nofma_sum_chunks(&chunks) };
+        assert_is_close(sum as f32, x.iter().sum::<f64>() as f32);
+    }
 }
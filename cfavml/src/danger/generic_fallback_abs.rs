@@ -0,0 +1,152 @@
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[inline]
+/// Computes the absolute value of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = abs(x[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_abs<T>(x: &[T], result: &mut [T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = AutoMath::abs(*x.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Computes the absolute value of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+///
+/// for i in 0..D:
+///     x[i] = abs(x[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_abs_inplace<T>(x: &mut [T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    for v in x.iter_mut() {
+        *v = AutoMath::abs(*v);
+    }
+}
+
+#[inline]
+/// Computes the absolute value of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [i32; D]
+/// result: [i32; D]
+///
+/// for i in 0..D:
+///     result[i] = abs(x[i])
+/// ```
+///
+/// `i32::MIN` wraps back to itself, matching `vpabsd`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i32_xany_fallback_nofma_abs(x: &[i32], result: &mut [i32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = x.get_unchecked(i).wrapping_abs();
+    }
+}
+
+#[inline]
+/// Computes the absolute value of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [i32; D]
+///
+/// for i in 0..D:
+///     x[i] = abs(x[i])
+/// ```
+///
+/// `i32::MIN` wraps back to itself, matching `vpabsd`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn i32_xany_fallback_nofma_abs_inplace(x: &mut [i32]) {
+    for v in x.iter_mut() {
+        *v = v.wrapping_abs();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_abs() {
+        let x = [-1.5f32, 0.0, -0.0, 2.0, f32::NEG_INFINITY];
+        let mut result = [0.0; 5];
+        unsafe { generic_xany_fallback_nofma_abs(&x, &mut result) };
+        assert_eq!(result, [1.5, 0.0, 0.0, 2.0, f32::INFINITY]);
+        assert!(result.iter().all(|v| v.is_sign_positive()));
+
+        let mut x = [-3.0f64, 4.0];
+        unsafe { generic_xany_fallback_nofma_abs_inplace(&mut x) };
+        assert_eq!(x, [3.0, 4.0]);
+
+        let mut x = [i32::MIN, -7, 0, 7];
+        unsafe { i32_xany_fallback_nofma_abs_inplace(&mut x) };
+        assert_eq!(x, [i32::MIN, 7, 0, 7]);
+    }
+}
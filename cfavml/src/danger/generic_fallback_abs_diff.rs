@@ -0,0 +1,110 @@
+use core::ops::Sub;
+
+#[inline]
+/// Computes the absolute difference `|x[i] - y[i]|` element wise, writing the result
+/// to `x`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// y: [T; D]
+///
+/// for i in 0..D:
+///     x[i] = max(x[i], y[i]) - min(x[i], y[i])
+/// ```
+///
+/// The larger value is always the one subtracted from, so unsigned inputs never wrap,
+/// and `NaN` is propagated for floats.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// For signed integers the difference **MUST** be representable by `T`, i.e.
+/// `i8::MAX` and `i8::MIN` have no absolute difference representable as an `i8`.
+pub unsafe fn generic_xany_fallback_nofma_abs_diff_vertical<T>(x: &mut [T], y: &[T])
+where
+    T: Copy + PartialOrd + Sub<Output = T>,
+{
+    debug_assert_eq!(x.len(), y.len());
+
+    for i in 0..x.len() {
+        let x = x.get_unchecked_mut(i);
+        let y = *y.get_unchecked(i);
+
+        *x = if *x > y { *x - y } else { y - *x };
+    }
+}
+
+#[inline]
+/// Computes the sum of absolute differences between `x` and `y`, widening to `u64`
+/// so the total cannot overflow.
+///
+/// ```py
+/// D: int
+/// total: u64
+/// x: [u8; D]
+/// y: [u8; D]
+///
+/// for i in 0..D:
+///     total = total + abs(x[i] - y[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn u8_xany_fallback_nofma_sad(x: &[u8], y: &[u8]) -> u64 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let mut total = 0;
+    for i in 0..x.len() {
+        total += x.get_unchecked(i).abs_diff(*y.get_unchecked(i)) as u64;
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_abs_diff_vertical() {
+        let (mut x, y) = get_sample_vectors::<u8>(531);
+        let expected = x
+            .iter()
+            .zip(y.iter())
+            .map(|(a, b)| a.abs_diff(*b))
+            .collect::<Vec<_>>();
+        unsafe { generic_xany_fallback_nofma_abs_diff_vertical(&mut x, &y) };
+        assert_eq!(x, expected);
+
+        let mut x = [1.0f32, -2.0, f32::NAN, 0.5];
+        let y = [3.0, 2.0, 1.0, f32::NAN];
+        unsafe { generic_xany_fallback_nofma_abs_diff_vertical(&mut x, &y) };
+        assert_eq!(&x[..2], &[2.0, 4.0]);
+        assert!(x[2].is_nan() && x[3].is_nan());
+    }
+
+    #[test]
+    fn test_xany_nofma_sad() {
+        let (x, y) = get_sample_vectors::<u8>(531);
+        let expected = x
+            .iter()
+            .zip(y.iter())
+            .map(|(a, b)| a.abs_diff(*b) as u64)
+            .sum::<u64>();
+        assert_eq!(unsafe { u8_xany_fallback_nofma_sad(&x, &y) }, expected);
+    }
+}
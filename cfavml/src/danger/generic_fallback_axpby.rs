@@ -0,0 +1,167 @@
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[inline]
+/// Computes `alpha * x + beta * y` element wise, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// alpha: T
+/// beta: T
+/// x: [T; D]
+/// y: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = alpha * x[i] + beta * y[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_axpby<T>(
+    alpha: T,
+    beta: T,
+    x: &[T],
+    y: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        ![x, y].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    for i in 0..x.len() {
+        let x = *x.get_unchecked(i);
+        let y = *y.get_unchecked(i);
+        *result.get_unchecked_mut(i) =
+            AutoMath::add(AutoMath::mul(alpha, x), AutoMath::mul(beta, y));
+    }
+}
+
+#[inline]
+/// Computes `alpha * x + beta * y` element wise, writing the result back to `y`.
+///
+/// ```py
+/// D: int
+/// alpha: T
+/// beta: T
+/// x: [T; D]
+/// y: [T; D]
+///
+/// for i in 0..D:
+///     y[i] = alpha * x[i] + beta * y[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_axpby_inplace<T>(
+    alpha: T,
+    beta: T,
+    x: &[T],
+    y: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), y.len());
+
+    for i in 0..x.len() {
+        let x = *x.get_unchecked(i);
+        let y = y.get_unchecked_mut(i);
+        *y = AutoMath::add(AutoMath::mul(alpha, x), AutoMath::mul(beta, *y));
+    }
+}
+
+#[inline]
+/// Computes the exponential moving average of `prev` and `new` element wise,
+/// writing the result back to `prev`.
+///
+/// ```py
+/// D: int
+/// alpha: T
+/// prev: [T; D]
+/// new: [T; D]
+///
+/// for i in 0..D:
+///     prev[i] = alpha * new[i] + (1 - alpha) * prev[i]
+/// ```
+///
+/// This is [generic_xany_fallback_nofma_axpby_inplace] with the coefficients coupled,
+/// `alpha` is the weight given to the new observation.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_ema_inplace<T>(
+    alpha: T,
+    prev: &mut [T],
+    new: &[T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    generic_xany_fallback_nofma_axpby_inplace(
+        alpha,
+        AutoMath::sub(AutoMath::one(), alpha),
+        new,
+        prev,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close_vector, get_sample_vectors};
+
+    #[test]
+    fn test_f32_xany_nofma_axpby() {
+        let (x, mut y) = get_sample_vectors::<f32>(514);
+        let expected = x
+            .iter()
+            .zip(y.iter())
+            .map(|(x, y)| 0.5 * x + 1.5 * y)
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 514];
+        unsafe { generic_xany_fallback_nofma_axpby(0.5, 1.5, &x, &y, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        unsafe { generic_xany_fallback_nofma_axpby_inplace(0.5, 1.5, &x, &mut y) };
+        assert_is_close_vector(&y, &expected);
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_ema_inplace() {
+        let (new, mut prev) = get_sample_vectors::<f32>(514);
+        let expected = new
+            .iter()
+            .zip(prev.iter())
+            .map(|(new, prev)| 0.25 * new + 0.75 * prev)
+            .collect::<Vec<f32>>();
+
+        unsafe { generic_xany_fallback_nofma_ema_inplace(0.25, &mut prev, &new) };
+        assert_is_close_vector(&prev, &expected);
+    }
+}
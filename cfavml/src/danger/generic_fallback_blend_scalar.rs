@@ -0,0 +1,213 @@
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[inline]
+/// Blends each element of `x` towards the constant `c` by `t`, writing the result
+/// to `result`.
+///
+/// ```py
+/// D: int
+/// t: T
+/// c: T
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = x[i] * (1 - t) + c * t
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_blend_scalar<T>(
+    t: T,
+    x: &[T],
+    c: T,
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let scale = AutoMath::sub(AutoMath::one(), t);
+    let offset = AutoMath::mul(c, t);
+
+    for i in 0..x.len() {
+        let scaled = AutoMath::mul(*x.get_unchecked(i), scale);
+        *result.get_unchecked_mut(i) = AutoMath::add(scaled, offset);
+    }
+}
+
+#[inline]
+/// Blends each element of `x` towards the constant `c` by `t` in place.
+///
+/// ```py
+/// D: int
+/// t: T
+/// c: T
+/// x: [T; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] * (1 - t) + c * t
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_blend_scalar_inplace<T>(
+    t: T,
+    x: &mut [T],
+    c: T,
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    let scale = AutoMath::sub(AutoMath::one(), t);
+    let offset = AutoMath::mul(c, t);
+
+    for v in x.iter_mut() {
+        *v = AutoMath::add(AutoMath::mul(*v, scale), offset);
+    }
+}
+
+#[inline]
+/// Blends each element of `x` towards the constant `c` by the matching element of
+/// `t`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// c: T
+/// t: [T; D]
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = x[i] * (1 - t[i]) + c * t[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_blend_scalar_vertical<T>(
+    t: &[T],
+    x: &[T],
+    c: T,
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(t.len(), x.len());
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        ![t, x].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) =
+            blend_one(*t.get_unchecked(i), *x.get_unchecked(i), c);
+    }
+}
+
+#[inline]
+/// Blends each element of `x` towards the constant `c` by the matching element of
+/// `t` in place.
+///
+/// ```py
+/// D: int
+/// c: T
+/// t: [T; D]
+/// x: [T; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] * (1 - t[i]) + c * t[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_blend_scalar_vertical_inplace<T>(
+    t: &[T],
+    x: &mut [T],
+    c: T,
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(t.len(), x.len());
+
+    for i in 0..x.len() {
+        let v = x.get_unchecked_mut(i);
+        *v = blend_one(*t.get_unchecked(i), *v, c);
+    }
+}
+
+#[inline(always)]
+fn blend_one<T>(t: T, x: T, c: T) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    let scaled = AutoMath::mul(x, AutoMath::sub(AutoMath::one(), t));
+    AutoMath::add(scaled, AutoMath::mul(c, t))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close_vector, get_sample_vectors};
+
+    #[test]
+    fn test_xany_nofma_blend_scalar() {
+        let (mut x, t) = get_sample_vectors::<f32>(514);
+
+        let expected = x.iter().map(|v| v * 0.5 + 4.0 * 0.5).collect::<Vec<f32>>();
+        let mut result = vec![0.0; 514];
+        unsafe { generic_xany_fallback_nofma_blend_scalar(0.5, &x, 4.0, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        let expected = x
+            .iter()
+            .zip(t.iter())
+            .map(|(x, t)| x * (1.0 - t) + 4.0 * t)
+            .collect::<Vec<f32>>();
+        unsafe {
+            generic_xany_fallback_nofma_blend_scalar_vertical(&t, &x, 4.0, &mut result)
+        };
+        assert_is_close_vector(&result, &expected);
+
+        unsafe {
+            generic_xany_fallback_nofma_blend_scalar_vertical_inplace(&t, &mut x, 4.0)
+        };
+        assert_is_close_vector(&x, &expected);
+
+        unsafe { generic_xany_fallback_nofma_blend_scalar_inplace(1.0, &mut x, 4.0) };
+        assert!(x.iter().all(|v| *v == 4.0));
+    }
+}
@@ -0,0 +1,152 @@
+use crate::danger::RoundingMode;
+
+#[inline]
+/// Narrows each `i32` element of `x` to `i16`, saturating values outside of
+/// the `i16::MIN..=i16::MAX` range, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [i32; D]
+/// result: [i16; D]
+///
+/// for i in 0..D:
+///     result[i] = clamp(x[i], -32768, 32767)
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i32_xany_fallback_nofma_to_i16_saturating(x: &[i32], result: &mut [i16]) {
+    debug_assert_eq!(x.len(), result.len());
+
+    for i in 0..x.len() {
+        let x = *x.get_unchecked(i);
+        *result.get_unchecked_mut(i) = x.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+    }
+}
+
+#[inline]
+/// Converts each `f32` element of `x` to `u8`, rounding in the given [RoundingMode]
+/// and saturating values outside of the `0..=255` range, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [u8; D]
+///
+/// for i in 0..D:
+///     result[i] = clamp(round(x[i], mode), 0, 255)
+/// ```
+///
+/// With [RoundingMode::NearestEven] values exactly half way between two integers are
+/// rounded to the nearest even integer, i.e. `0.5 -> 0` and `1.5 -> 2`. `NaN` values
+/// become `0`. The mode is applied exactly with [RoundingMode::round], so the output
+/// matches the SIMD routines bit for bit.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_to_u8_saturating(
+    mode: RoundingMode,
+    x: &[f32],
+    result: &mut [u8],
+) {
+    debug_assert_eq!(x.len(), result.len());
+
+    for i in 0..x.len() {
+        let x = *x.get_unchecked(i);
+        *result.get_unchecked_mut(i) = mode.round(x).clamp(0.0, 255.0) as u8;
+    }
+}
+
+#[inline]
+/// Applies the affine transform `x * scale + bias` to each `f32` element of `x` then
+/// converts it to `u8`, rounding in the given [RoundingMode] and saturating values
+/// outside of the `0..=255` range, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [u8; D]
+///
+/// for i in 0..D:
+///     result[i] = clamp(round(x[i] * scale + bias, mode), 0, 255)
+/// ```
+///
+/// Rounding and `NaN` handling match [f32_xany_fallback_nofma_to_u8_saturating].
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_to_u8_scale_clamp(
+    mode: RoundingMode,
+    scale: f32,
+    bias: f32,
+    x: &[f32],
+    result: &mut [u8],
+) {
+    debug_assert_eq!(x.len(), result.len());
+
+    for i in 0..x.len() {
+        let x = *x.get_unchecked(i) * scale + bias;
+        *result.get_unchecked_mut(i) = mode.round(x).clamp(0.0, 255.0) as u8;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_i32_xany_nofma_to_i16_saturating() {
+        let x = [i32::MIN, -32769, -32768, 0, 32767, 32768, i32::MAX];
+        let mut result = [0; 7];
+        unsafe { i32_xany_fallback_nofma_to_i16_saturating(&x, &mut result) };
+        assert_eq!(result, [-32768, -32768, -32768, 0, 32767, 32767, 32767]);
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_to_u8_saturating() {
+        let x = [-1.0, -0.5, 0.5, 1.5, 254.5, 255.5, 256.0, f32::NAN];
+        let mut result = [0; 8];
+        unsafe {
+            f32_xany_fallback_nofma_to_u8_saturating(
+                RoundingMode::NearestEven,
+                &x,
+                &mut result,
+            )
+        };
+        assert_eq!(result, [0, 0, 0, 2, 254, 255, 255, 0]);
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_to_u8_scale_clamp() {
+        let x = [-1.0, -0.5, 0.0, 0.5, 1.0, 2.0, f32::NAN];
+        let mut result = [0; 7];
+        unsafe {
+            f32_xany_fallback_nofma_to_u8_scale_clamp(
+                RoundingMode::NearestEven,
+                127.5,
+                127.5,
+                &x,
+                &mut result,
+            )
+        };
+        assert_eq!(result, [0, 64, 128, 191, 255, 255, 0]);
+    }
+}
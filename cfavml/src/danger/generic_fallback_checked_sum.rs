@@ -0,0 +1,139 @@
+#[inline]
+/// Sums all elements of the vector, returning `None` if the total does not fit
+/// within an `i64`.
+///
+/// ```py
+/// D: int
+/// total: i128
+/// x: [i64; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return total if i64.MIN <= total <= i64.MAX else None
+/// ```
+///
+/// Overflow is checked against the final total rather than each partial sum, so
+/// intermediate values may exceed the range of an `i64` as long as the result does not.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn i64_xany_fallback_nofma_checked_sum(x: &[i64]) -> Option<i64> {
+    let len = x.len();
+    let offset_from = len % 4;
+
+    let mut acc1: i128 = 0;
+    let mut acc2: i128 = 0;
+    let mut acc3: i128 = 0;
+    let mut acc4: i128 = 0;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        acc1 += *x.get_unchecked(i) as i128;
+        acc2 += *x.get_unchecked(i + 1) as i128;
+        acc3 += *x.get_unchecked(i + 2) as i128;
+        acc4 += *x.get_unchecked(i + 3) as i128;
+
+        i += 4;
+    }
+
+    while i < len {
+        acc1 += *x.get_unchecked(i) as i128;
+        i += 1;
+    }
+
+    i64::try_from((acc1 + acc2) + (acc3 + acc4)).ok()
+}
+
+#[inline]
+/// Sums all elements of the vector, returning `None` if the total does not fit
+/// within a `u64`.
+///
+/// ```py
+/// D: int
+/// total: u128
+/// x: [u64; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return total if total <= u64.MAX else None
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn u64_xany_fallback_nofma_checked_sum(x: &[u64]) -> Option<u64> {
+    let len = x.len();
+    let offset_from = len % 4;
+
+    let mut acc1: u128 = 0;
+    let mut acc2: u128 = 0;
+    let mut acc3: u128 = 0;
+    let mut acc4: u128 = 0;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        acc1 += *x.get_unchecked(i) as u128;
+        acc2 += *x.get_unchecked(i + 1) as u128;
+        acc3 += *x.get_unchecked(i + 2) as u128;
+        acc4 += *x.get_unchecked(i + 3) as u128;
+
+        i += 4;
+    }
+
+    while i < len {
+        acc1 += *x.get_unchecked(i) as u128;
+        i += 1;
+    }
+
+    u64::try_from((acc1 + acc2) + (acc3 + acc4)).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_i64_xany_nofma_checked_sum() {
+        let (x, _) = get_sample_vectors::<i64>(531);
+        let x = x.into_iter().map(|v| v >> 16).collect::<Vec<_>>();
+
+        let sum = unsafe { i64_xany_fallback_nofma_checked_sum(&x) };
+        assert_eq!(sum, Some(x.iter().sum::<i64>()));
+
+        let x = vec![i64::MAX / 4; 13];
+        let sum = unsafe { i64_xany_fallback_nofma_checked_sum(&x) };
+        assert_eq!(sum, None);
+
+        let mut x = vec![i64::MAX; 16];
+        x.extend(vec![i64::MIN; 16]);
+        let sum = unsafe { i64_xany_fallback_nofma_checked_sum(&x) };
+        assert_eq!(sum, Some(-16));
+    }
+
+    #[test]
+    fn test_u64_xany_nofma_checked_sum() {
+        let (x, _) = get_sample_vectors::<u64>(531);
+        let x = x.into_iter().map(|v| v >> 16).collect::<Vec<_>>();
+
+        let sum = unsafe { u64_xany_fallback_nofma_checked_sum(&x) };
+        assert_eq!(sum, Some(x.iter().sum::<u64>()));
+
+        let x = vec![u64::MAX / 4; 5];
+        let sum = unsafe { u64_xany_fallback_nofma_checked_sum(&x) };
+        assert_eq!(sum, None);
+    }
+}
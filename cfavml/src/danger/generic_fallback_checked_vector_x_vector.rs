@@ -0,0 +1,98 @@
+use crate::danger::OverflowError;
+
+macro_rules! checked_vertical {
+    ($t:ty, add = $add:ident, sub = $sub:ident $(,)?) => {
+        #[inline]
+        /// Computes `x[i] + y[i]` element wise, writing the result to `x` and returning
+        /// an [OverflowError] with the index of the first element to overflow.
+        ///
+        /// When an overflow occurs every element before the returned index has been
+        /// updated and every element from it onwards is left unchanged.
+        ///
+        /// These are fallback routines, they are designed to be optimized
+        /// by the compiler only, in areas where manually optimized routines
+        /// are unable to run due to lack of CPU features.
+        ///
+        /// # Safety
+        ///
+        /// Vectors **MUST** be the same length, otherwise this routine
+        /// will become immediately UB due to out of bounds pointer accesses.
+        pub unsafe fn $add(x: &mut [$t], y: &[$t]) -> Result<(), OverflowError> {
+            debug_assert_eq!(x.len(), y.len());
+
+            for i in 0..x.len() {
+                let x = x.get_unchecked_mut(i);
+                *x = x
+                    .checked_add(*y.get_unchecked(i))
+                    .ok_or(OverflowError { index: i })?;
+            }
+
+            Ok(())
+        }
+
+        #[inline]
+        /// Computes `x[i] - y[i]` element wise, writing the result to `x` and returning
+        /// an [OverflowError] with the index of the first element to overflow.
+        ///
+        /// When an overflow occurs every element before the returned index has been
+        /// updated and every element from it onwards is left unchanged.
+        ///
+        /// These are fallback routines, they are designed to be optimized
+        /// by the compiler only, in areas where manually optimized routines
+        /// are unable to run due to lack of CPU features.
+        ///
+        /// # Safety
+        ///
+        /// Vectors **MUST** be the same length, otherwise this routine
+        /// will become immediately UB due to out of bounds pointer accesses.
+        pub unsafe fn $sub(x: &mut [$t], y: &[$t]) -> Result<(), OverflowError> {
+            debug_assert_eq!(x.len(), y.len());
+
+            for i in 0..x.len() {
+                let x = x.get_unchecked_mut(i);
+                *x = x
+                    .checked_sub(*y.get_unchecked(i))
+                    .ok_or(OverflowError { index: i })?;
+            }
+
+            Ok(())
+        }
+    };
+}
+
+checked_vertical!(
+    i32,
+    add = i32_xany_fallback_nofma_checked_add_vertical,
+    sub = i32_xany_fallback_nofma_checked_sub_vertical,
+);
+checked_vertical!(
+    u32,
+    add = u32_xany_fallback_nofma_checked_add_vertical,
+    sub = u32_xany_fallback_nofma_checked_sub_vertical,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_checked_vertical() {
+        let mut x = [1i32, 2, i32::MAX, 4];
+        let error =
+            unsafe { i32_xany_fallback_nofma_checked_add_vertical(&mut x, &[1; 4]) };
+        assert_eq!(error, Err(OverflowError { index: 2 }));
+        assert_eq!(x, [2, 3, i32::MAX, 4]);
+
+        let mut x = [5u32, 2, 1, 4];
+        let error =
+            unsafe { u32_xany_fallback_nofma_checked_sub_vertical(&mut x, &[2; 4]) };
+        assert_eq!(error, Err(OverflowError { index: 2 }));
+        assert_eq!(x, [3, 0, 1, 4]);
+
+        let mut x = [5u32, 2, 1, 4];
+        unsafe {
+            u32_xany_fallback_nofma_checked_add_vertical(&mut x, &[2; 4]).unwrap()
+        };
+        assert_eq!(x, [7, 4, 3, 6]);
+    }
+}
@@ -0,0 +1,380 @@
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[inline]
+/// Clamps each element of `x` to the bounds `lo` and `hi`, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// lo: T
+/// hi: T
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = min(max(x[i], lo), hi)
+/// ```
+///
+/// `NaN` elements of `x` are clamped to `lo`, if `lo` is greater than `hi` the
+/// element is set to `hi`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_clamp_value<T>(
+    lo: T,
+    hi: T,
+    x: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        let clamped_lo = AutoMath::cmp_max(*x.get_unchecked(i), lo);
+        *result.get_unchecked_mut(i) = AutoMath::cmp_min(clamped_lo, hi);
+    }
+}
+
+#[inline]
+/// Clamps each element of `x` to the bounds `lo` and `hi` in place.
+///
+/// ```py
+/// D: int
+/// lo: T
+/// hi: T
+/// x: [T; D]
+///
+/// for i in 0..D:
+///     x[i] = min(max(x[i], lo), hi)
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_clamp_value_inplace<T>(
+    lo: T,
+    hi: T,
+    x: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    for v in x.iter_mut() {
+        *v = AutoMath::cmp_min(AutoMath::cmp_max(*v, lo), hi);
+    }
+}
+
+#[inline]
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi`, writing the
+/// result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// lo: [T; D]
+/// hi: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = min(max(x[i], lo[i]), hi[i])
+/// ```
+///
+/// `NaN` elements of `x` are clamped to `lo`, if `lo[i]` is greater than `hi[i]`
+/// the element is set to `hi[i]`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_clamp_vertical<T>(
+    x: &[T],
+    lo: &[T],
+    hi: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), lo.len());
+    debug_assert_eq!(x.len(), hi.len());
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        ![x, lo, hi].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    for i in 0..x.len() {
+        let clamped_lo = AutoMath::cmp_max(*x.get_unchecked(i), *lo.get_unchecked(i));
+        *result.get_unchecked_mut(i) =
+            AutoMath::cmp_min(clamped_lo, *hi.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi` in place.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// lo: [T; D]
+/// hi: [T; D]
+///
+/// for i in 0..D:
+///     x[i] = min(max(x[i], lo[i]), hi[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_clamp_vertical_inplace<T>(
+    x: &mut [T],
+    lo: &[T],
+    hi: &[T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), lo.len());
+    debug_assert_eq!(x.len(), hi.len());
+
+    for i in 0..x.len() {
+        let v = x.get_unchecked_mut(i);
+        *v = AutoMath::cmp_min(
+            AutoMath::cmp_max(*v, *lo.get_unchecked(i)),
+            *hi.get_unchecked(i),
+        );
+    }
+}
+
+#[inline]
+/// Clamps each element of `x` to the bounds `lo` and `hi`, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// lo: i32
+/// hi: i32
+/// x: [i32; D]
+/// result: [i32; D]
+///
+/// for i in 0..D:
+///     result[i] = min(max(x[i], lo), hi)
+/// ```
+///
+/// If `lo` is greater than `hi` the element is set to `hi`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i32_xany_fallback_nofma_clamp_value(
+    lo: i32,
+    hi: i32,
+    x: &[i32],
+    result: &mut [i32],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = (*x.get_unchecked(i)).max(lo).min(hi);
+    }
+}
+
+#[inline]
+/// Clamps each element of `x` to the bounds `lo` and `hi` in place.
+///
+/// ```py
+/// D: int
+/// lo: i32
+/// hi: i32
+/// x: [i32; D]
+///
+/// for i in 0..D:
+///     x[i] = min(max(x[i], lo), hi)
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn i32_xany_fallback_nofma_clamp_value_inplace(
+    lo: i32,
+    hi: i32,
+    x: &mut [i32],
+) {
+    for v in x.iter_mut() {
+        *v = (*v).max(lo).min(hi);
+    }
+}
+
+#[inline]
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi`, writing the
+/// result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [i32; D]
+/// lo: [i32; D]
+/// hi: [i32; D]
+/// result: [i32; D]
+///
+/// for i in 0..D:
+///     result[i] = min(max(x[i], lo[i]), hi[i])
+/// ```
+///
+/// If `lo[i]` is greater than `hi[i]` the element is set to `hi[i]`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i32_xany_fallback_nofma_clamp_vertical(
+    x: &[i32],
+    lo: &[i32],
+    hi: &[i32],
+    result: &mut [i32],
+) {
+    debug_assert_eq!(x.len(), lo.len());
+    debug_assert_eq!(x.len(), hi.len());
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        ![x, lo, hi].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = (*x.get_unchecked(i))
+            .max(*lo.get_unchecked(i))
+            .min(*hi.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi` in place.
+///
+/// ```py
+/// D: int
+/// x: [i32; D]
+/// lo: [i32; D]
+/// hi: [i32; D]
+///
+/// for i in 0..D:
+///     x[i] = min(max(x[i], lo[i]), hi[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i32_xany_fallback_nofma_clamp_vertical_inplace(
+    x: &mut [i32],
+    lo: &[i32],
+    hi: &[i32],
+) {
+    debug_assert_eq!(x.len(), lo.len());
+    debug_assert_eq!(x.len(), hi.len());
+
+    for i in 0..x.len() {
+        let v = x.get_unchecked_mut(i);
+        *v = (*v).max(*lo.get_unchecked(i)).min(*hi.get_unchecked(i));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_clamp_vertical() {
+        let mut x = [-2.0f64, 0.5, 3.0, f64::NAN, 1.0];
+        let lo = [-1.0, 0.0, 0.0, -5.0, 2.0];
+        let hi = [1.0, 1.0, 2.5, 5.0, 4.0];
+        let expected = [-1.0, 0.5, 2.5, -5.0, 2.0];
+
+        let mut result = [0.0; 5];
+        unsafe { generic_xany_fallback_nofma_clamp_vertical(&x, &lo, &hi, &mut result) };
+        assert_eq!(result, expected);
+
+        unsafe { generic_xany_fallback_nofma_clamp_vertical_inplace(&mut x, &lo, &hi) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_clamp_value() {
+        let mut x = [-2.0f32, 0.5, 3.0, f32::NAN];
+        let expected = [0.0, 0.5, 1.0, 0.0];
+
+        let mut result = [0.0; 4];
+        unsafe { generic_xany_fallback_nofma_clamp_value(0.0, 1.0, &x, &mut result) };
+        assert_eq!(result, expected);
+
+        unsafe { generic_xany_fallback_nofma_clamp_value_inplace(0.0, 1.0, &mut x) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_i32_xany_nofma_clamp() {
+        let mut x = [i32::MIN, -3, 0, 7, i32::MAX];
+
+        let mut result = [0; 5];
+        unsafe { i32_xany_fallback_nofma_clamp_value(-2, 5, &x, &mut result) };
+        assert_eq!(result, [-2, -2, 0, 5, 5]);
+
+        let lo = [0, -5, 1, 0, 0];
+        let hi = [1, 5, 2, 10, 100];
+        unsafe { i32_xany_fallback_nofma_clamp_vertical(&x, &lo, &hi, &mut result) };
+        assert_eq!(result, [0, -3, 1, 7, 100]);
+
+        unsafe { i32_xany_fallback_nofma_clamp_vertical_inplace(&mut x, &lo, &hi) };
+        assert_eq!(x, result);
+        unsafe { i32_xany_fallback_nofma_clamp_value_inplace(2, 3, &mut x) };
+        assert_eq!(x, [2, 2, 2, 3, 3]);
+    }
+}
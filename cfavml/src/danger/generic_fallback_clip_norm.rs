@@ -0,0 +1,64 @@
+use crate::danger::{
+    clip_norm_scale,
+    generic_xany_fallback_nofma_l2_norm,
+    generic_xany_fallback_nofma_mul_value,
+};
+use crate::math::*;
+
+#[inline]
+/// Scales `x` by `max_norm / norm(x)` if the L2 norm of `x` is greater than `max_norm`,
+/// otherwise `x` is left unchanged, returning the original L2 norm of `x`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+///
+/// norm = l2_norm(x)
+/// if norm > max_norm:
+///     for i in 0..D:
+///         x[i] = x[i] * (max_norm / norm)
+///
+/// return norm
+/// ```
+///
+/// Vectors with a norm of zero are left unchanged.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_clip_norm<T>(max_norm: T, x: &mut [T]) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    let norm = generic_xany_fallback_nofma_l2_norm(x);
+    if let Some(scale) = clip_norm_scale::<T, AutoMath>(norm, max_norm) {
+        generic_xany_fallback_nofma_mul_value(x, scale);
+    }
+    norm
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_f32_xany_nofma_clip_norm() {
+        let (mut x, _) = get_sample_vectors::<f32>(514);
+        let norm = simple_dot(&x, &x).sqrt();
+
+        let res = unsafe { generic_xany_fallback_nofma_clip_norm(norm + 1.0, &mut x) };
+        assert_is_close(res, norm);
+        assert_is_close(simple_dot(&x, &x).sqrt(), norm);
+
+        let res = unsafe { generic_xany_fallback_nofma_clip_norm(2.5, &mut x) };
+        assert_is_close(res, norm);
+        assert_is_close(simple_dot(&x, &x).sqrt(), 2.5);
+    }
+}
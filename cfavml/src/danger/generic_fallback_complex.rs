@@ -0,0 +1,186 @@
+//! Complex number fallback routines operating on interleaved data.
+//!
+//! Complex vectors are expected to be laid out as `[re0, im0, re1, im1, ...]`,
+//! see the AVX2 complex routines for more info.
+use crate::danger::{
+    generic_xany_fallback_nofma_add_vertical,
+    generic_xany_fallback_nofma_sub_vertical,
+};
+use crate::math::*;
+
+#[inline]
+/// Adds each complex element of `y` to `x` element wise.
+///
+/// ```py
+/// D: int
+/// x: [complex<T>; D]
+/// y: [complex<T>; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] + y[i]
+/// ```
+///
+/// # Safety
+///
+/// Lengths of `x` and `y` **MUST** be equal and laid out interleaved.
+pub unsafe fn generic_xany_fallback_nofma_complex_add_vertical<T>(x: &mut [T], y: &[T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len() % 2, 0, "Complex vectors must be interleaved pairs");
+    generic_xany_fallback_nofma_add_vertical(x, y)
+}
+
+#[inline]
+/// Subtracts each complex element of `y` from `x` element wise.
+///
+/// ```py
+/// D: int
+/// x: [complex<T>; D]
+/// y: [complex<T>; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] - y[i]
+/// ```
+///
+/// # Safety
+///
+/// Lengths of `x` and `y` **MUST** be equal and laid out interleaved.
+pub unsafe fn generic_xany_fallback_nofma_complex_sub_vertical<T>(x: &mut [T], y: &[T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len() % 2, 0, "Complex vectors must be interleaved pairs");
+    generic_xany_fallback_nofma_sub_vertical(x, y)
+}
+
+#[inline]
+/// Multiplies each complex element of `x` by the complex elements of `y`
+/// element wise.
+///
+/// ```py
+/// D: int
+/// x: [complex<T>; D]
+/// y: [complex<T>; D]
+///
+/// for i in 0..D:
+///     x[i] = (x[i].re * y[i].re - x[i].im * y[i].im)
+///          + (x[i].re * y[i].im + x[i].im * y[i].re)j
+/// ```
+///
+/// # Safety
+///
+/// Lengths of `x` and `y` **MUST** be equal and laid out interleaved.
+pub unsafe fn generic_xany_fallback_nofma_complex_mul_vertical<T>(x: &mut [T], y: &[T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len() % 2, 0, "Complex vectors must be interleaved pairs");
+
+    let mut i = 0;
+    while i < x.len() {
+        let a_re = *x.get_unchecked(i);
+        let a_im = *x.get_unchecked(i + 1);
+        let b_re = *y.get_unchecked(i);
+        let b_im = *y.get_unchecked(i + 1);
+
+        *x.get_unchecked_mut(i) =
+            AutoMath::sub(AutoMath::mul(a_re, b_re), AutoMath::mul(a_im, b_im));
+        *x.get_unchecked_mut(i + 1) =
+            AutoMath::add(AutoMath::mul(a_re, b_im), AutoMath::mul(a_im, b_re));
+
+        i += 2;
+    }
+}
+
+#[inline]
+/// Computes the conjugated dot product of two complex vectors, returning
+/// the `(real, imaginary)` parts of the result.
+///
+/// ```py
+/// D: int
+/// total: complex<T>
+/// x: [complex<T>; D]
+/// y: [complex<T>; D]
+///
+/// for i in 0..D:
+///     total = total + (conj(x[i]) * y[i])
+/// ```
+///
+/// # Safety
+///
+/// Lengths of `x` and `y` **MUST** be equal and laid out interleaved.
+pub unsafe fn generic_xany_fallback_nofma_complex_dot_conj<T>(x: &[T], y: &[T]) -> (T, T)
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert_eq!(x.len() % 2, 0, "Complex vectors must be interleaved pairs");
+
+    let mut re = AutoMath::zero();
+    let mut im = AutoMath::zero();
+
+    let mut i = 0;
+    while i < x.len() {
+        let a_re = *x.get_unchecked(i);
+        let a_im = *x.get_unchecked(i + 1);
+        let b_re = *y.get_unchecked(i);
+        let b_im = *y.get_unchecked(i + 1);
+
+        re = AutoMath::add(
+            re,
+            AutoMath::add(AutoMath::mul(a_re, b_re), AutoMath::mul(a_im, b_im)),
+        );
+        im = AutoMath::add(
+            im,
+            AutoMath::sub(AutoMath::mul(a_re, b_im), AutoMath::mul(a_im, b_re)),
+        );
+
+        i += 2;
+    }
+
+    (re, im)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        assert_is_close_vector,
+        get_sample_vectors,
+        simple_complex_dot_conj,
+        simple_complex_mul,
+    };
+
+    #[test]
+    fn test_complex_add_sub_vertical() {
+        let (mut x, y) = get_sample_vectors::<f32>(262);
+        let original = x.clone();
+        unsafe { generic_xany_fallback_nofma_complex_add_vertical(&mut x, &y) };
+        unsafe { generic_xany_fallback_nofma_complex_sub_vertical(&mut x, &y) };
+        assert_is_close_vector(&x, &original);
+    }
+
+    #[test]
+    fn test_complex_mul_vertical() {
+        let (mut x, y) = get_sample_vectors::<f32>(262);
+        let expected = simple_complex_mul(&x, &y);
+        unsafe { generic_xany_fallback_nofma_complex_mul_vertical(&mut x, &y) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_complex_dot_conj() {
+        let (x, y) = get_sample_vectors::<f32>(262);
+        let (expected_re, expected_im) = simple_complex_dot_conj(&x, &y);
+        let (re, im) = unsafe { generic_xany_fallback_nofma_complex_dot_conj(&x, &y) };
+        assert_is_close(re, expected_re);
+        assert_is_close(im, expected_im);
+    }
+}
@@ -0,0 +1,81 @@
+use crate::danger::{check_conv1d_valid_len, generic_xany_fallback_nofma_dot};
+use crate::math::*;
+
+#[inline]
+/// Computes the valid mode 1D cross-correlation of `signal` with `kernel`, writing
+/// the result to `result`.
+///
+/// ```py
+/// N: int
+/// K: int
+/// signal: [T; N]
+/// kernel: [T; K]
+/// result: [T; N - K + 1]
+///
+/// for i in 0..N - K + 1:
+///     result[i] = dot(kernel, signal[i:i + K])
+/// ```
+///
+/// Only positions where the kernel fits entirely within the signal are computed,
+/// so `result` is empty when the kernel is longer than the signal. The kernel is
+/// not flipped, reverse it first for a true convolution.
+///
+/// # Panics
+///
+/// Panics if `kernel` is empty or `result` is not `N - K + 1` in length.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_conv1d_valid<T>(
+    signal: &[T],
+    kernel: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    check_conv1d_valid_len(signal.len(), kernel.len(), result.len());
+
+    for (i, out) in result.iter_mut().enumerate() {
+        let window = signal.get_unchecked(i..i + kernel.len());
+        *out = generic_xany_fallback_nofma_dot(kernel, window);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close_vector, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_xany_nofma_conv1d_valid() {
+        let (signal, kernel) = get_sample_vectors::<f32>(514);
+
+        for k in [1, 3, 8, 65] {
+            let kernel = &kernel[..k];
+            let expected = signal
+                .windows(k)
+                .map(|window| simple_dot(kernel, window))
+                .collect::<Vec<f32>>();
+
+            let mut result = vec![0.0; 514 - k + 1];
+            unsafe {
+                generic_xany_fallback_nofma_conv1d_valid(&signal, kernel, &mut result)
+            };
+            assert_is_close_vector(&result, &expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Kernel must not be empty")]
+    fn test_xany_nofma_conv1d_valid_empty_kernel() {
+        let mut result = vec![0.0f32; 4];
+        unsafe { generic_xany_fallback_nofma_conv1d_valid(&[1.0; 4], &[], &mut result) };
+    }
+}
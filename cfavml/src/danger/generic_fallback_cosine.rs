@@ -1,4 +1,4 @@
-use crate::danger::{cosine, generic_xany_fallback_nofma_dot};
+use crate::danger::{cosine, cosine_with_l2_norms, generic_xany_fallback_nofma_dot};
 use crate::math::*;
 
 #[inline]
@@ -23,10 +23,96 @@ where
     cosine::<T, AutoMath>(dot_product, norm_x, norm_y)
 }
 
+#[inline]
+/// Computes the cosine distance of two `T` vectors in a single pass.
+///
+/// The dot product and both norms are accumulated together, so each vector is only
+/// read from memory once rather than once per term as in
+/// [generic_xany_fallback_nofma_cosine].
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_cosine_fused<T>(x: &[T], y: &[T]) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), y.len());
+
+    let mut dot_product = AutoMath::zero();
+    let mut norm_x = AutoMath::zero();
+    let mut norm_y = AutoMath::zero();
+
+    for i in 0..x.len() {
+        let a = *x.get_unchecked(i);
+        let b = *y.get_unchecked(i);
+
+        dot_product = AutoMath::add(dot_product, AutoMath::mul(a, b));
+        norm_x = AutoMath::add(norm_x, AutoMath::mul(a, a));
+        norm_y = AutoMath::add(norm_y, AutoMath::mul(b, b));
+    }
+
+    cosine::<T, AutoMath>(dot_product, norm_x, norm_y)
+}
+
+#[inline]
+/// Computes the cosine distance of two `T` vectors using their precomputed L2
+/// norms, only the dot product of `x` and `y` is computed.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// y: [T; D]
+///
+/// return 1 - dot(x, y) / (x_norm * y_norm)
+/// ```
+///
+/// `x_norm` and `y_norm` **MUST** be the true L2 norms of `x` and `y`, i.e.
+/// `sqrt(dot(x, x))`, not the squared norms, otherwise the distance returned is
+/// meaningless. This avoids recomputing the norm of stored vectors for every
+/// query in a cosine index.
+///
+/// If either norm is zero, the distance is `0`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_cosine_with_norms<T>(
+    x: &[T],
+    x_norm: T,
+    y: &[T],
+    y_norm: T,
+) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), y.len());
+
+    let dot_product = generic_xany_fallback_nofma_dot(x, y);
+    cosine_with_l2_norms::<T, AutoMath>(dot_product, x_norm, y_norm)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_cosine};
+    use crate::test_utils::{
+        assert_is_close,
+        get_sample_vectors,
+        simple_cosine,
+        simple_dot,
+    };
 
     #[test]
     fn test_f32_xany_nofma_dot() {
@@ -35,4 +121,28 @@ mod tests {
         let expected = simple_cosine(&x, &y);
         assert_is_close(dist, expected);
     }
+
+    #[test]
+    fn test_f32_xany_nofma_cosine_fused() {
+        let (x, y) = get_sample_vectors(514);
+        let dist = unsafe { generic_xany_fallback_nofma_cosine_fused(&x, &y) };
+        let expected = unsafe { generic_xany_fallback_nofma_cosine(&x, &y) };
+        assert_is_close(dist, expected);
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_cosine_with_norms() {
+        let (x, y) = get_sample_vectors::<f32>(547);
+        let x_norm = simple_dot(&x, &x).sqrt();
+        let y_norm = simple_dot(&y, &y).sqrt();
+        let dist = unsafe {
+            generic_xany_fallback_nofma_cosine_with_norms(&x, x_norm, &y, y_norm)
+        };
+        assert_is_close(dist, simple_cosine(&x, &y));
+
+        let dist = unsafe {
+            generic_xany_fallback_nofma_cosine_with_norms(&x, 0.0, &y, y_norm)
+        };
+        assert_eq!(dist, 0.0);
+    }
 }
@@ -0,0 +1,268 @@
+use crate::danger::{
+    generic_xany_fallback_nofma_add_vertical,
+    generic_xany_fallback_nofma_mul_vertical,
+    partially_overlaps,
+    scan_axis_with,
+};
+use crate::math::*;
+use crate::vector_ops::Axis;
+
+#[inline]
+/// Computes the running sum of `x`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     total = total + x[i]
+///     result[i] = total
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_cumsum<T>(x: &[T], result: &mut [T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let mut total = AutoMath::zero();
+    for i in 0..x.len() {
+        total = AutoMath::add(total, *x.get_unchecked(i));
+        *result.get_unchecked_mut(i) = total;
+    }
+}
+
+#[inline]
+/// Computes the exclusive running sum of `x`, writing the result to `result`, each
+/// output is the sum of all elements before it.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     result[i] = total
+///     total = total + x[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_cumsum_exclusive<T>(x: &[T], result: &mut [T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let mut total = AutoMath::zero();
+    for i in 0..x.len() {
+        let value = *x.get_unchecked(i);
+        *result.get_unchecked_mut(i) = total;
+        total = AutoMath::add(total, value);
+    }
+}
+
+#[inline]
+/// Computes the running product of `x`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// total = 1
+/// for i in 0..D:
+///     total = total * x[i]
+///     result[i] = total
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_cumprod<T>(x: &[T], result: &mut [T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let mut total = AutoMath::one();
+    for i in 0..x.len() {
+        total = AutoMath::mul(total, *x.get_unchecked(i));
+        *result.get_unchecked_mut(i) = total;
+    }
+}
+
+#[inline]
+/// Computes the running sum of `matrix` along the given [Axis], writing the result
+/// to the matching rows of `result`, the equivalent of numpy's `cumsum(axis=)`.
+///
+/// ```py
+/// R: int
+/// C: int
+/// matrix: [[T; C]; R]
+/// result: [[T; C]; R]
+///
+/// if axis == Rows:
+///     for r in 0..R:
+///         result[r] = cumsum(matrix[r])
+/// else:
+///     result[0] = matrix[0]
+///     for r in 1..R:
+///         result[r] = result[r - 1] + matrix[r]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// `result` **MUST** have the same number of rows as `matrix` and every row of both
+/// **MUST** be the same length, otherwise this routine will become immediately UB
+/// due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_cumsum_axis<T>(
+    matrix: &[&[T]],
+    axis: Axis,
+    result: &mut [&mut [T]],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    scan_axis_with(
+        matrix,
+        axis,
+        result,
+        |row, out| generic_xany_fallback_nofma_cumsum(row, out),
+        |row, prev| generic_xany_fallback_nofma_add_vertical(row, prev),
+    )
+}
+
+#[inline]
+/// Computes the running product of `matrix` along the given [Axis], writing the
+/// result to the matching rows of `result`, the equivalent of numpy's
+/// `cumprod(axis=)`.
+///
+/// ```py
+/// R: int
+/// C: int
+/// matrix: [[T; C]; R]
+/// result: [[T; C]; R]
+///
+/// if axis == Rows:
+///     for r in 0..R:
+///         result[r] = cumprod(matrix[r])
+/// else:
+///     result[0] = matrix[0]
+///     for r in 1..R:
+///         result[r] = result[r - 1] * matrix[r]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// `result` **MUST** have the same number of rows as `matrix` and every row of both
+/// **MUST** be the same length, otherwise this routine will become immediately UB
+/// due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_cumprod_axis<T>(
+    matrix: &[&[T]],
+    axis: Axis,
+    result: &mut [&mut [T]],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    scan_axis_with(
+        matrix,
+        axis,
+        result,
+        |row, out| generic_xany_fallback_nofma_cumprod(row, out),
+        |row, prev| generic_xany_fallback_nofma_mul_vertical(row, prev),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_cumsum_cumprod() {
+        let x = [1.0f32, 2.0, 3.0, 4.0, 5.0];
+        let mut result = [0.0; 5];
+
+        unsafe { generic_xany_fallback_nofma_cumsum(&x, &mut result) };
+        assert_eq!(result, [1.0, 3.0, 6.0, 10.0, 15.0]);
+
+        unsafe { generic_xany_fallback_nofma_cumprod(&x, &mut result) };
+        assert_eq!(result, [1.0, 2.0, 6.0, 24.0, 120.0]);
+
+        unsafe { generic_xany_fallback_nofma_cumsum_exclusive(&x, &mut result) };
+        assert_eq!(result, [0.0, 1.0, 3.0, 6.0, 10.0]);
+    }
+
+    #[test]
+    fn test_xany_nofma_cumsum_axis_integral_image() {
+        let matrix: [&[f32]; 3] = [&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0], &[7.0, 8.0, 9.0]];
+
+        let mut rows = vec![0.0; 9];
+        let mut result = rows.chunks_mut(3).collect::<Vec<&mut [f32]>>();
+        unsafe {
+            generic_xany_fallback_nofma_cumsum_axis(&matrix, Axis::Rows, &mut result)
+        };
+        assert_eq!(rows, [1.0, 3.0, 6.0, 4.0, 9.0, 15.0, 7.0, 15.0, 24.0]);
+
+        let row_sums = rows.chunks(3).collect::<Vec<&[f32]>>();
+        let mut integral = vec![0.0; 9];
+        let mut result = integral.chunks_mut(3).collect::<Vec<&mut [f32]>>();
+        unsafe {
+            generic_xany_fallback_nofma_cumsum_axis(&row_sums, Axis::Cols, &mut result)
+        };
+        assert_eq!(integral, [1.0, 3.0, 6.0, 5.0, 12.0, 21.0, 12.0, 27.0, 45.0]);
+
+        let mut output = vec![0.0; 9];
+        let mut result = output.chunks_mut(3).collect::<Vec<&mut [f32]>>();
+        unsafe {
+            generic_xany_fallback_nofma_cumprod_axis(&matrix, Axis::Cols, &mut result)
+        };
+        assert_eq!(output, [1.0, 2.0, 3.0, 4.0, 10.0, 18.0, 28.0, 80.0, 162.0]);
+    }
+}
@@ -0,0 +1,174 @@
+use crate::danger::{gemv_with, generic_xany_fallback_nofma_dot, Activation};
+use crate::math::*;
+
+#[inline]
+/// Computes the dot product of two `T` vectors, adds `bias` and applies the
+/// given activation function to the result.
+///
+/// ```py
+/// D: int
+/// total: T
+/// x: [T; D]
+/// y: [T; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+///
+/// return activation(total + bias)
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_dot_bias_activation<T>(
+    x: &[T],
+    y: &[T],
+    bias: T,
+    activation: Activation,
+) -> T
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    let dot_product = generic_xany_fallback_nofma_dot(x, y);
+    activation.apply::<T, AutoMath>(AutoMath::add(dot_product, bias))
+}
+
+#[inline]
+/// Computes the product of `matrix` with the vector `x`, adds `bias` and applies the
+/// given activation function to each element, writing the result to `out`.
+///
+/// ```py
+/// R: int
+/// D: int
+/// matrix: [[T; D]; R]
+/// x: [T; D]
+/// bias: [T; R]
+/// out: [T; R]
+///
+/// for i in 0..R:
+///     out[i] = activation(dot(matrix[i], x) + bias[i])
+/// ```
+///
+/// This is the forward pass of a dense layer, `x` stays resident in cache while
+/// each row is streamed past it.
+///
+/// # Panics
+///
+/// Panics if `bias` or `out` are not the same length as `matrix`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Every row of `matrix` **MUST** be the same length as `x`, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_gemv_bias_activation<T>(
+    matrix: &[&[T]],
+    x: &[T],
+    bias: &[T],
+    activation: Activation,
+    out: &mut [T],
+) where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    gemv_with(matrix, bias, out, |row, bias| {
+        generic_xany_fallback_nofma_dot_bias_activation(row, x, bias, activation)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_f32_xany_nofma_dot_bias_activation() {
+        let (x, y) = get_sample_vectors(514);
+        let expected = simple_dot(&x, &y) - 150.0;
+
+        let res = unsafe {
+            generic_xany_fallback_nofma_dot_bias_activation(
+                &x,
+                &y,
+                -150.0,
+                Activation::None,
+            )
+        };
+        assert_is_close(res, expected);
+
+        let res = unsafe {
+            generic_xany_fallback_nofma_dot_bias_activation(
+                &x,
+                &y,
+                -150.0,
+                Activation::Relu,
+            )
+        };
+        assert_is_close(res, expected.max(0.0));
+
+        let res = unsafe {
+            generic_xany_fallback_nofma_dot_bias_activation(
+                &x,
+                &y,
+                -128.0,
+                Activation::Tanh,
+            )
+        };
+        assert_is_close(res, (expected + 22.0).tanh());
+    }
+
+    #[test]
+    fn test_xany_nofma_gemv_bias_activation() {
+        let matrix: [&[f32]; 3] = [&[1.0, 2.0], &[-3.0, 0.5], &[0.0, 0.0]];
+        let x = [2.0, 4.0];
+        let bias = [1.0, 2.0, -1.0];
+
+        let mut out = [0.0; 3];
+        unsafe {
+            generic_xany_fallback_nofma_gemv_bias_activation(
+                &matrix,
+                &x,
+                &bias,
+                Activation::None,
+                &mut out,
+            )
+        };
+        assert_eq!(out, [11.0, -2.0, -1.0]);
+
+        unsafe {
+            generic_xany_fallback_nofma_gemv_bias_activation(
+                &matrix,
+                &x,
+                &bias,
+                Activation::Relu,
+                &mut out,
+            )
+        };
+        assert_eq!(out, [11.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "`out` must match the matrix rows")]
+    fn test_xany_nofma_gemv_bias_activation_out_len() {
+        let matrix: [&[f32]; 2] = [&[1.0], &[2.0]];
+        let mut out = [0.0; 1];
+        unsafe {
+            generic_xany_fallback_nofma_gemv_bias_activation(
+                &matrix,
+                &[1.0],
+                &[0.0, 0.0],
+                Activation::None,
+                &mut out,
+            )
+        };
+    }
+}
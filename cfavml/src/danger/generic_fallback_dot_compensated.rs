@@ -0,0 +1,109 @@
+use crate::danger::utils::two_sum_f32;
+
+#[inline]
+/// Computes the dot product of two `f32` vectors with compensated accumulation,
+/// following the `Dot2` algorithm of Ogita, Rump and Oishi.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// total = 0
+/// compensation = 0
+///
+/// for i in 0..D:
+///     product, product_error = two_product(x[i], y[i])
+///     total, sum_error = two_sum(total, product)
+///     compensation = compensation + product_error + sum_error
+///
+/// return total + compensation
+/// ```
+///
+/// The result is as accurate as if it were computed in twice the working precision
+/// and then rounded to `f32`, see
+/// [f32_xany_avx2_fma_dot_compensated](crate::danger::f32_xany_avx2_fma_dot_compensated)
+/// for the error bound.
+///
+/// The product errors are recovered with Dekker's splitting rather than a fused
+/// multiply-add, so this remains exact on CPUs without FMA support, provided no
+/// product overflows.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_dot_compensated(x: &[f32], y: &[f32]) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let mut total = 0.0;
+    let mut compensation = 0.0;
+
+    for i in 0..x.len() {
+        let (product, product_error) =
+            two_product_f32(*x.get_unchecked(i), *y.get_unchecked(i));
+        let (sum, sum_error) = two_sum_f32(total, product);
+
+        total = sum;
+        compensation += product_error + sum_error;
+    }
+
+    total + compensation
+}
+
+#[inline(always)]
+/// Computes `a * b` returning the rounded product and its exact rounding error,
+/// Dekker's `TwoProduct` error-free transformation.
+fn two_product_f32(a: f32, b: f32) -> (f32, f32) {
+    let product = a * b;
+    let (a_hi, a_lo) = split_f32(a);
+    let (b_hi, b_lo) = split_f32(b);
+
+    let error = a_lo * b_lo - (((product - a_hi * b_hi) - a_lo * b_hi) - a_hi * b_lo);
+    (product, error)
+}
+
+#[inline(always)]
+/// Splits `a` into two non-overlapping halves of 12 significant bits each.
+fn split_f32(a: f32) -> (f32, f32) {
+    // 2^12 + 1
+    let c = 4097.0 * a;
+    let hi = c - (c - a);
+    (hi, a - hi)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_two_product_f32_is_exact() {
+        let (x, y) = get_sample_vectors::<f32>(64);
+        for (a, b) in x.into_iter().zip(y) {
+            let (product, error) = two_product_f32(a, b);
+            assert_eq!(product as f64 + error as f64, a as f64 * b as f64);
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_dot_compensated_ill_conditioned() {
+        let (x, y) = get_sample_vectors::<f32>(265);
+
+        let mut a = x.iter().map(|v| v * 1e6).collect::<Vec<_>>();
+        a.extend(x.iter().map(|v| v * -1e6));
+        a.push(1.0);
+        let mut b = y.clone();
+        b.extend_from_slice(&y);
+        b.push(1.0);
+
+        // The condition number is ~1e8, far beyond what `f32` accumulation can handle
+        // but well within the error bound of the compensated accumulation.
+        let dot = unsafe { f32_xany_fallback_nofma_dot_compensated(&a, &b) };
+        assert!((dot - 1.0).abs() < 1e-4, "{dot} != 1.0");
+    }
+}
@@ -0,0 +1,44 @@
+#[inline]
+/// Computes the dot product of two `f32` vectors, accumulating in `f64`.
+///
+/// ```py
+/// D: int
+/// total: f64
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..D:
+///     total = total + f64(x[i]) * f64(y[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_dot_f64(x: &[f32], y: &[f32]) -> f64 {
+    debug_assert_eq!(x.len(), y.len());
+
+    let mut total = 0.0;
+    for i in 0..x.len() {
+        total += *x.get_unchecked(i) as f64 * *y.get_unchecked(i) as f64;
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_dot_f64_keeps_small_terms() {
+        let x = [1e8f32, 1.0, 1.0, -1e8];
+        let y = [1.0; 4];
+
+        assert_eq!(unsafe { f32_xany_fallback_nofma_dot_f64(&x, &y) }, 2.0);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::danger::fold_chunk_pairs;
 use crate::danger::utils::rollup_scalar_x8;
 use crate::math::*;
 
@@ -13,11 +14,112 @@ where
     T: Copy,
     AutoMath: Math<T>,
 {
-    fallback_dot::<T, AutoMath>(x, y)
+    fallback_dot::<T, AutoMath>(x, y, AutoMath::zero())
 }
 
 #[inline]
-pub(super) unsafe fn fallback_dot<T, M>(x: &[T], y: &[T]) -> T
+/// Computes the dot product of the first `n` elements of two `T` vectors, ignoring
+/// any elements beyond them, i.e. the unused capacity of padded buffers.
+///
+/// # Panics
+///
+/// Panics if `n` is greater than the length of either vector.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_dot_prefix<T>(x: &[T], y: &[T], n: usize) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    assert!(
+        n <= x.len().min(y.len()),
+        "Prefix length {n} exceeds the length of the vectors"
+    );
+    generic_xany_fallback_nofma_dot(x.get_unchecked(..n), y.get_unchecked(..n))
+}
+
+#[inline]
+/// Computes the dot product of each pair of rows of two row-major `T` matrices
+/// with `dim` columns, only including the first `lengths[row]` elements of each row.
+///
+/// ```py
+/// R: int
+/// dim: int
+/// x: [T; R * dim]
+/// y: [T; R * dim]
+/// lengths: [usize; R]
+/// result: [T; R]
+///
+/// for row in 0..R:
+///     start = row * dim
+///     result[row] = dot(x[start:start + lengths[row]], y[start:start + lengths[row]])
+/// ```
+///
+/// This allows a batch of variable length vectors padded out to a fixed width, i.e.
+/// right-padded token embeddings, to be dotted without the padding contributing to
+/// the result or being processed at all.
+///
+/// # Panics
+///
+/// Panics if any of the `lengths` is greater than `dim`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// `x` and `y` **MUST** both be `lengths.len() * dim` in length and `result` **MUST**
+/// be `lengths.len()` in length, otherwise this routine will become immediately UB
+/// due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_dot_ragged<T>(
+    x: &[T],
+    y: &[T],
+    dim: usize,
+    lengths: &[usize],
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), lengths.len() * dim);
+    debug_assert_eq!(y.len(), lengths.len() * dim);
+    debug_assert_eq!(result.len(), lengths.len());
+
+    for (row, &n) in lengths.iter().enumerate() {
+        assert!(n <= dim, "Row length {n} exceeds the row width {dim}");
+
+        let start = row * dim;
+        *result.get_unchecked_mut(row) = generic_xany_fallback_nofma_dot(
+            x.get_unchecked(start..start + n),
+            y.get_unchecked(start..start + n),
+        );
+    }
+}
+
+#[inline]
+/// Computes `bias` plus the dot product of two `T` vectors.
+///
+/// The accumulator is seeded with `bias` rather than adding it to the result,
+/// which saves a separate add when applying the bias of a linear layer.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal in length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_dot_plus<T>(x: &[T], y: &[T], bias: T) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    fallback_dot::<T, AutoMath>(x, y, bias)
+}
+
+#[inline]
+pub(super) unsafe fn fallback_dot<T, M>(x: &[T], y: &[T], init: T) -> T
 where
     T: Copy,
     M: Math<T>,
@@ -34,7 +136,7 @@ where
     // We do this manual unrolling to allow the compiler to vectorize
     // the loop and avoid some branching even if we're not doing it explicitly.
     // This made a significant difference in benchmarking ~8x
-    let mut acc1 = M::zero();
+    let mut acc1 = init;
     let mut acc2 = M::zero();
     let mut acc3 = M::zero();
     let mut acc4 = M::zero();
@@ -86,6 +188,43 @@ where
     rollup_scalar_x8::<T, M>(acc1, acc2, acc3, acc4, acc5, acc6, acc7, acc8)
 }
 
+#[inline]
+/// Computes the dot product of two vectors split into multiple chunks, such as
+/// segmented buffers, without copying them into contiguous vectors first.
+///
+/// The chunk boundaries of `x` and `y` do not need to line up or be aligned to
+/// the register width, each overlapping pair of sub-slices is reduced with the
+/// contiguous routine and accumulated by seeding it with the running total.
+///
+/// ```py
+/// total: T
+/// x: [T; D]
+/// y: [T; D]
+///
+/// for i in 0..D:
+///     total = total + (x[i] * y[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// The chunks of both vectors **MUST** contain the same number of elements in total.
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_dot_chunks<T>(x: &[&[T]], y: &[&[T]]) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    fold_chunk_pairs(x, y, AutoMath::zero(), |x, y, total| {
+        fallback_dot::<T, AutoMath>(x, y, total)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,4 +245,76 @@ mod tests {
         let expected = simple_dot(&x, &y);
         assert_is_close(dist, expected);
     }
+
+    #[test]
+    fn test_f32_xany_nofma_dot_plus() {
+        let (x, y) = get_sample_vectors(514);
+        let dist = unsafe { generic_xany_fallback_nofma_dot_plus(&x, &y, 1.5) };
+        let expected = simple_dot(&x, &y) + 1.5;
+        assert_is_close(dist, expected);
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_dot_chunks() {
+        let (x, y) = get_sample_vectors::<f32>(531);
+        let expected = simple_dot(&x, &y);
+
+        // Chunk boundaries deliberately differ between the two vectors.
+        let x_chunks = [&x[..13], &x[13..13], &x[13..200], &x[200..]];
+        let y_chunks = [&y[..64], &y[64..301], &y[301..]];
+
+        let dist =
+            unsafe { generic_xany_fallback_nofma_dot_chunks(&x_chunks, &y_chunks) };
+        assert_is_close(dist, expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_dot_prefix() {
+        let (mut x, y) = get_sample_vectors::<f32>(531);
+        x.truncate(517);
+        for n in [0, 7, 64, 200, 517] {
+            let dist = unsafe { generic_xany_fallback_nofma_dot_prefix(&x, &y, n) };
+            let expected = unsafe { generic_xany_fallback_nofma_dot(&x[..n], &y[..n]) };
+            assert_eq!(dist, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Prefix length 518 exceeds the length of the vectors")]
+    fn test_xany_nofma_dot_prefix_too_long() {
+        let (x, y) = get_sample_vectors::<f32>(517);
+        unsafe { generic_xany_fallback_nofma_dot_prefix(&x, &y, 518) };
+    }
+
+    #[test]
+    fn test_xany_nofma_dot_ragged() {
+        let (x, y) = get_sample_vectors::<f32>(4 * 133);
+        let lengths = [133, 0, 70, 8];
+
+        let mut result = vec![f32::NAN; 4];
+        unsafe {
+            generic_xany_fallback_nofma_dot_ragged(&x, &y, 133, &lengths, &mut result)
+        };
+
+        for (row, n) in lengths.into_iter().enumerate() {
+            let start = row * 133;
+            let expected = unsafe {
+                generic_xany_fallback_nofma_dot(
+                    &x[start..start + n],
+                    &y[start..start + n],
+                )
+            };
+            assert_eq!(result[row], expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "Row length 9 exceeds the row width 8")]
+    fn test_xany_nofma_dot_ragged_too_long() {
+        let (x, y) = get_sample_vectors::<f32>(16);
+        let mut result = vec![0.0; 2];
+        unsafe {
+            generic_xany_fallback_nofma_dot_ragged(&x, &y, 8, &[8, 9], &mut result)
+        };
+    }
 }
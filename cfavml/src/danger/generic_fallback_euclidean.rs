@@ -1,4 +1,4 @@
-use crate::danger::utils::rollup_scalar_x8;
+use crate::danger::utils::{bounded_distance_with, rollup_scalar_x8};
 use crate::math::*;
 
 #[inline]
@@ -20,6 +20,53 @@ where
     fallback_euclidean::<T, AutoMath>(x, y)
 }
 
+#[inline]
+/// Computes the squared Euclidean distance of two `T` vectors, abandoning the
+/// computation once the partial distance exceeds `threshold` squared.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// y: [T; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     total = total + (x[i] - y[i]) ** 2
+///     if total > threshold ** 2:
+///         return None
+///
+/// return total
+/// ```
+///
+/// The partial distance is only compared against the bound after every 256
+/// elements, a pair is abandoned at most one interval after it crosses the
+/// threshold while the unrolled loop stays free of branches in between.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_euclidean_bounded<T>(
+    x: &[T],
+    y: &[T],
+    threshold: T,
+) -> Option<T>
+where
+    T: Copy + PartialOrd,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), y.len());
+
+    let bound = AutoMath::mul(threshold, threshold);
+    bounded_distance_with::<T, AutoMath, _>(x, y, bound, |x, y| {
+        fallback_euclidean::<T, AutoMath>(x, y)
+    })
+}
+
 #[inline]
 unsafe fn fallback_euclidean<T, M>(x: &[T], y: &[T]) -> T
 where
@@ -121,4 +168,24 @@ mod tests {
         let dist = unsafe { generic_xany_fallback_nofma_euclidean(&x, &y) };
         assert_is_close(dist, simple_euclidean(&x, &y));
     }
+
+    #[test]
+    fn test_xany_nofma_euclidean_bounded() {
+        let (x, y) = get_sample_vectors::<f32>(1031);
+        let expected = simple_euclidean(&x, &y);
+        let threshold = expected.sqrt();
+
+        let dist = unsafe {
+            generic_xany_fallback_nofma_euclidean_bounded(&x, &y, threshold * 1.01)
+        };
+        assert_is_close(dist.unwrap(), expected);
+        let dist = unsafe {
+            generic_xany_fallback_nofma_euclidean_bounded(&x, &y, threshold * 0.5)
+        };
+        assert_eq!(dist, None);
+
+        let dist =
+            unsafe { generic_xany_fallback_nofma_euclidean_bounded(&x, &y, f32::NAN) };
+        assert_eq!(dist, None);
+    }
 }
@@ -0,0 +1,162 @@
+#[inline]
+/// Returns the index of the first element in the vector greater than `value`,
+/// or `None` if no element matches.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+///
+/// for i in 0..D:
+///     if x[i] > value:
+///         return i
+/// return None
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_find_first_gt<T>(
+    x: &[T],
+    value: T,
+) -> Option<usize>
+where
+    T: Copy + PartialOrd,
+{
+    x.iter().position(|v| *v > value)
+}
+
+#[inline]
+/// Returns the index of the first element in the vector equal to `value`,
+/// or `None` if no element matches.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+///
+/// for i in 0..D:
+///     if x[i] == value:
+///         return i
+/// return None
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_find_first_eq<T>(
+    x: &[T],
+    value: T,
+) -> Option<usize>
+where
+    T: Copy + PartialOrd,
+{
+    x.iter().position(|v| *v == value)
+}
+
+#[inline]
+/// Returns the index of the last element in the vector greater than `value`,
+/// or `None` if no element matches.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+///
+/// for i in reversed(0..D):
+///     if x[i] > value:
+///         return i
+/// return None
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_find_last_gt<T>(
+    x: &[T],
+    value: T,
+) -> Option<usize>
+where
+    T: Copy + PartialOrd,
+{
+    x.iter().rposition(|v| *v > value)
+}
+
+#[inline]
+/// Returns the index of the last element in the vector equal to `value`,
+/// or `None` if no element matches.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+///
+/// for i in reversed(0..D):
+///     if x[i] == value:
+///         return i
+/// return None
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_find_last_eq<T>(
+    x: &[T],
+    value: T,
+) -> Option<usize>
+where
+    T: Copy + PartialOrd,
+{
+    x.iter().rposition(|v| *v == value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_find() {
+        let x = [1.0f32, 3.0, 2.0, 3.0, f32::NAN, 0.5];
+
+        assert_eq!(
+            unsafe { generic_xany_fallback_nofma_find_first_gt(&x, 1.5) },
+            Some(1)
+        );
+        assert_eq!(
+            unsafe { generic_xany_fallback_nofma_find_last_gt(&x, 1.5) },
+            Some(3)
+        );
+        assert_eq!(
+            unsafe { generic_xany_fallback_nofma_find_first_eq(&x, 3.0) },
+            Some(1)
+        );
+        assert_eq!(
+            unsafe { generic_xany_fallback_nofma_find_last_eq(&x, 3.0) },
+            Some(3)
+        );
+        assert_eq!(
+            unsafe { generic_xany_fallback_nofma_find_first_gt(&x, 5.0) },
+            None
+        );
+        assert_eq!(
+            unsafe { generic_xany_fallback_nofma_find_first_eq(&x, f32::NAN) },
+            None
+        );
+    }
+}
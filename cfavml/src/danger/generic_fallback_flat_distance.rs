@@ -0,0 +1,177 @@
+use crate::danger::{
+    flat_row_pair,
+    generic_xany_fallback_nofma_dot,
+    generic_xany_fallback_nofma_euclidean,
+    pairwise_distances_with,
+};
+use crate::math::*;
+
+#[inline]
+/// Computes the dot product of rows `i` and `j` of the flat row-major `T` matrix
+/// `data` with `dim` columns.
+///
+/// ```py
+/// D: int
+/// data: [T; N * D]
+///
+/// return dot(data[i * D:(i + 1) * D], data[j * D:(j + 1) * D])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Rows `i` and `j` **MUST** be within `data`, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_flat_dot<T>(
+    data: &[T],
+    dim: usize,
+    i: usize,
+    j: usize,
+) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    let (x, y) = flat_row_pair(data, dim, i, j);
+    generic_xany_fallback_nofma_dot(x, y)
+}
+
+#[inline]
+/// Computes the squared Euclidean distance of rows `i` and `j` of the flat
+/// row-major `T` matrix `data` with `dim` columns.
+///
+/// ```py
+/// D: int
+/// data: [T; N * D]
+///
+/// return euclidean(data[i * D:(i + 1) * D], data[j * D:(j + 1) * D])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Rows `i` and `j` **MUST** be within `data`, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_flat_euclidean<T>(
+    data: &[T],
+    dim: usize,
+    i: usize,
+    j: usize,
+) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    let (x, y) = flat_row_pair(data, dim, i, j);
+    generic_xany_fallback_nofma_euclidean(x, y)
+}
+
+#[inline]
+/// Computes the squared Euclidean distance between every pair of rows of the flat
+/// row-major `T` matrix `data`, writing the full `rows x rows` distance matrix
+/// to `result`.
+///
+/// ```py
+/// N: int
+/// D: int
+/// data: [T; N * D]
+/// result: [T; N * N]
+///
+/// for i in 0..N:
+///     for j in 0..N:
+///         result[i * N + j] = euclidean(data[i * D:(i + 1) * D], data[j * D:(j + 1) * D])
+/// ```
+///
+/// The output is the full symmetric matrix in row-major order, not just the upper
+/// triangle, with a diagonal of zero. Only the upper triangle is computed, each
+/// distance is mirrored into the lower triangle.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// `data` **MUST** be at least `rows * dim` in length and `result` **MUST** be
+/// `rows * rows` in length, otherwise this routine will become immediately UB due
+/// to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_pairwise_distances<T>(
+    data: &[T],
+    rows: usize,
+    dim: usize,
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    pairwise_distances_with(data, rows, dim, AutoMath::zero(), result, |x, y| {
+        generic_xany_fallback_nofma_euclidean(x, y)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        get_sample_vectors,
+        simple_dot,
+        simple_euclidean,
+    };
+
+    const ROWS: usize = 5;
+    const DIM: usize = 37;
+
+    #[test]
+    fn test_xany_nofma_flat_dot_euclidean() {
+        let (data, _) = get_sample_vectors::<f32>(ROWS * DIM);
+        let row = |i: usize| &data[i * DIM..(i + 1) * DIM];
+
+        for (i, j) in [(0, 0), (0, 4), (3, 1)] {
+            let dot = unsafe { generic_xany_fallback_nofma_flat_dot(&data, DIM, i, j) };
+            assert_is_close(dot, simple_dot(row(i), row(j)));
+
+            let dist =
+                unsafe { generic_xany_fallback_nofma_flat_euclidean(&data, DIM, i, j) };
+            assert_is_close(dist, simple_euclidean(row(i), row(j)));
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_pairwise_distances() {
+        let (data, _) = get_sample_vectors::<f32>(ROWS * DIM);
+        let row = |i: usize| &data[i * DIM..(i + 1) * DIM];
+
+        let mut result = vec![f32::NAN; ROWS * ROWS];
+        unsafe {
+            generic_xany_fallback_nofma_pairwise_distances(&data, ROWS, DIM, &mut result)
+        };
+
+        for i in 0..ROWS {
+            for j in 0..ROWS {
+                let expected = if i == j {
+                    0.0
+                } else {
+                    simple_euclidean(row(i), row(j))
+                };
+                assert_is_close(result[i * ROWS + j], expected);
+                assert_eq!(result[i * ROWS + j], result[j * ROWS + i]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_pairwise_distances_empty() {
+        let mut result: Vec<f32> = Vec::new();
+        unsafe {
+            generic_xany_fallback_nofma_pairwise_distances(&[], 0, 8, &mut result)
+        };
+        assert!(result.is_empty());
+    }
+}
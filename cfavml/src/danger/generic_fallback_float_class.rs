@@ -0,0 +1,117 @@
+#[inline]
+/// Writes a mask to `mask` marking which elements of the vector are `NaN`.
+///
+/// Each element of the mask is set to all-ones (`u32::MAX`) where the element is `NaN`
+/// and `0` otherwise.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// mask: [u32; D]
+///
+/// for i in 0..D:
+///     mask[i] = 0xFFFFFFFF if x[i] != x[i] else 0
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_is_nan(x: &[f32], mask: &mut [u32]) {
+    debug_assert_eq!(x.len(), mask.len());
+
+    for i in 0..x.len() {
+        let is_nan = x.get_unchecked(i).is_nan();
+        *mask.get_unchecked_mut(i) = if is_nan { u32::MAX } else { 0 };
+    }
+}
+
+#[inline]
+/// Writes a mask to `mask` marking which elements of the vector are finite,
+/// that is neither `NaN` nor `inf`.
+///
+/// Each element of the mask is set to all-ones (`u32::MAX`) where the element is finite
+/// and `0` otherwise.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// mask: [u32; D]
+///
+/// for i in 0..D:
+///     exponent = bits(x[i]) & 0x7F800000
+///     mask[i] = 0xFFFFFFFF if exponent != 0x7F800000 else 0
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_is_finite(x: &[f32], mask: &mut [u32]) {
+    debug_assert_eq!(x.len(), mask.len());
+
+    for i in 0..x.len() {
+        let exponent = x.get_unchecked(i).to_bits() & 0x7F80_0000;
+        *mask.get_unchecked_mut(i) = if exponent != 0x7F80_0000 { u32::MAX } else { 0 };
+    }
+}
+
+#[inline]
+/// Returns `true` if any element of the vector is `NaN`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     if x[i] != x[i]:
+///         return True
+/// return False
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn f32_xany_fallback_nofma_contains_nan(x: &[f32]) -> bool {
+    x.iter().any(|v| v.is_nan())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f32_xany_nofma_float_class() {
+        let x = [
+            1.0,
+            f32::NAN,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            -0.0,
+            f32::MAX,
+        ];
+        let mut mask = [0; 6];
+
+        unsafe { f32_xany_fallback_nofma_is_nan(&x, &mut mask) };
+        assert_eq!(mask, [0, u32::MAX, 0, 0, 0, 0]);
+
+        unsafe { f32_xany_fallback_nofma_is_finite(&x, &mut mask) };
+        assert_eq!(mask, [u32::MAX, 0, 0, 0, u32::MAX, u32::MAX]);
+
+        assert!(unsafe { f32_xany_fallback_nofma_contains_nan(&x) });
+        assert!(!unsafe { f32_xany_fallback_nofma_contains_nan(&x[2..]) });
+    }
+}
@@ -0,0 +1,214 @@
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[inline]
+/// Multiplies `a` and `b` element wise and adds `c`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// a: [T; D]
+/// b: [T; D]
+/// c: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = a[i] * b[i] + c[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_fmadd_vector<T>(
+    a: &[T],
+    b: &[T],
+    c: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+    debug_assert_eq!(a.len(), result.len());
+    debug_assert!(
+        ![a, b, c].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    for i in 0..a.len() {
+        let product = AutoMath::mul(*a.get_unchecked(i), *b.get_unchecked(i));
+        *result.get_unchecked_mut(i) = AutoMath::add(product, *c.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Multiplies `a` and `b` element wise and subtracts `c`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// a: [T; D]
+/// b: [T; D]
+/// c: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = a[i] * b[i] - c[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_fmsub_vector<T>(
+    a: &[T],
+    b: &[T],
+    c: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+    debug_assert_eq!(a.len(), result.len());
+    debug_assert!(
+        ![a, b, c].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    for i in 0..a.len() {
+        let product = AutoMath::mul(*a.get_unchecked(i), *b.get_unchecked(i));
+        *result.get_unchecked_mut(i) = AutoMath::sub(product, *c.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Multiplies `a` and `b` element wise and adds `c`, writing the result back to `c`.
+///
+/// ```py
+/// D: int
+/// a: [T; D]
+/// b: [T; D]
+/// c: [T; D]
+///
+/// for i in 0..D:
+///     c[i] = a[i] * b[i] + c[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_fmadd_vector_inplace<T>(
+    a: &[T],
+    b: &[T],
+    c: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+
+    for i in 0..a.len() {
+        let product = AutoMath::mul(*a.get_unchecked(i), *b.get_unchecked(i));
+        let c = c.get_unchecked_mut(i);
+        *c = AutoMath::add(product, *c);
+    }
+}
+
+#[inline]
+/// Multiplies `a` and `b` element wise and subtracts `c`, writing the result back to `c`.
+///
+/// ```py
+/// D: int
+/// a: [T; D]
+/// b: [T; D]
+/// c: [T; D]
+///
+/// for i in 0..D:
+///     c[i] = a[i] * b[i] - c[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_fmsub_vector_inplace<T>(
+    a: &[T],
+    b: &[T],
+    c: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+
+    for i in 0..a.len() {
+        let product = AutoMath::mul(*a.get_unchecked(i), *b.get_unchecked(i));
+        let c = c.get_unchecked_mut(i);
+        *c = AutoMath::sub(product, *c);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close_vector, get_sample_vectors};
+
+    #[test]
+    fn test_f32_xany_nofma_fmadd_vector() {
+        let (a, b) = get_sample_vectors::<f32>(514);
+        let mut c = b.iter().rev().copied().collect::<Vec<f32>>();
+        let expected = a
+            .iter()
+            .zip(b.iter())
+            .zip(c.iter())
+            .map(|((a, b), c)| a * b + c)
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 514];
+        unsafe { generic_xany_fallback_nofma_fmadd_vector(&a, &b, &c, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        unsafe { generic_xany_fallback_nofma_fmadd_vector_inplace(&a, &b, &mut c) };
+        assert_is_close_vector(&c, &expected);
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_fmsub_vector() {
+        let (a, b) = get_sample_vectors::<f32>(514);
+        let mut c = b.iter().rev().copied().collect::<Vec<f32>>();
+        let expected = a
+            .iter()
+            .zip(b.iter())
+            .zip(c.iter())
+            .map(|((a, b), c)| a * b - c)
+            .collect::<Vec<f32>>();
+
+        let mut result = vec![0.0; 514];
+        unsafe { generic_xany_fallback_nofma_fmsub_vector(&a, &b, &c, &mut result) };
+        assert_is_close_vector(&result, &expected);
+
+        unsafe { generic_xany_fallback_nofma_fmsub_vector_inplace(&a, &b, &mut c) };
+        assert_is_close_vector(&c, &expected);
+    }
+}
@@ -0,0 +1,117 @@
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[inline]
+/// Applies the GELU activation to the vector, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     u = sqrt(2 / pi) * (x[i] + 0.044715 * x[i] ** 3)
+///     result[i] = 0.5 * x[i] * (1 + tanh(u))
+/// ```
+///
+/// This is the tanh approximation of GELU, matching PyTorch's
+/// `gelu(x, approximate="tanh")`, **not** the exact erf form.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_gelu<T>(x: &[T], result: &mut [T])
+where
+    T: Copy + From<f32> + PartialOrd,
+    AutoMath: TranscendentalMath<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = gelu(*x.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Applies the GELU activation to the vector in place.
+///
+/// See [generic_xany_fallback_nofma_gelu] for the approximation used.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_gelu_inplace<T>(x: &mut [T])
+where
+    T: Copy + From<f32> + PartialOrd,
+    AutoMath: TranscendentalMath<T>,
+{
+    for v in x.iter_mut() {
+        *v = gelu(*v);
+    }
+}
+
+#[inline(always)]
+fn gelu<T>(x: T) -> T
+where
+    T: Copy + From<f32> + PartialOrd,
+    AutoMath: TranscendentalMath<T>,
+{
+    // Below `-10` the `1 + tanh(u)` term rounds to zero, saturating there also keeps
+    // `gelu(-inf)` at `-0.0` rather than the `NaN` of `-inf * 0`.
+    if x < T::from(-10.0) {
+        return T::from(-0.0);
+    }
+
+    // sqrt(2 / pi)
+    let sqrt_2_over_pi = T::from(0.797_884_6);
+    let cubic = T::from(0.044_715);
+    let half = T::from(0.5);
+
+    let x3 = AutoMath::mul(AutoMath::mul(x, x), x);
+    let u = AutoMath::mul(sqrt_2_over_pi, AutoMath::add(x, AutoMath::mul(cubic, x3)));
+    let half_x = AutoMath::mul(half, x);
+
+    AutoMath::mul(half_x, AutoMath::add(AutoMath::one(), AutoMath::tanh(u)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_gelu() {
+        let x = [-3.0f64, -1.0, 0.0, 1.0, 3.0];
+        // Reference values from `torch.nn.functional.gelu(x, approximate="tanh")`.
+        let expected = [-0.003637392, -0.158808, 0.0, 0.841192, 2.996363];
+
+        let mut result = [0.0; 5];
+        unsafe { generic_xany_fallback_nofma_gelu(&x, &mut result) };
+        for (r, e) in result.iter().zip(expected) {
+            assert!((r - e).abs() < 1e-6, "{r} != {e}");
+        }
+
+        let mut x = x;
+        unsafe { generic_xany_fallback_nofma_gelu_inplace(&mut x) };
+        assert_eq!(x, result);
+
+        let x = [f64::NEG_INFINITY, -100.0, 100.0, f64::INFINITY];
+        let mut result = [0.0; 4];
+        unsafe { generic_xany_fallback_nofma_gelu(&x, &mut result) };
+        assert_eq!(result, [-0.0, -0.0, 100.0, f64::INFINITY]);
+        assert!(result[0].is_sign_negative());
+    }
+}
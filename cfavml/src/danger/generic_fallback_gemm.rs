@@ -0,0 +1,141 @@
+use core::mem::MaybeUninit;
+use core::slice;
+
+use crate::math::*;
+
+#[inline]
+/// Computes the matrix product `C = A·B` of the row-major `[T; m * k]` matrix `a` and
+/// the row-major `[T; k * n]` matrix `b`, writing the row-major `[T; m * n]` result to `c`.
+///
+/// ```py
+/// m: int
+/// k: int
+/// n: int
+/// a: [T; m * k]
+/// b: [T; k * n]
+/// c: [T; m * n]
+///
+/// for i in 0..m:
+///     for j in 0..n:
+///         c[i * n + j] = dot(a[i, :], b[:, j])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// `a`, `b` and `c` **MUST** be `m * k`, `k * n` and `m * n` in length respectively,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_gemm<T>(
+    a: &[T],
+    b: &[T],
+    c: &mut [T],
+    m: usize,
+    k: usize,
+    n: usize,
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(a.len(), m * k);
+    debug_assert_eq!(b.len(), k * n);
+    debug_assert_eq!(c.len(), m * n);
+
+    c.fill(AutoMath::zero());
+    gemm_accumulate(a, b, c, m, k, n);
+}
+
+#[inline]
+/// Computes the matrix product `C = A·B` exactly like
+/// [generic_xany_fallback_nofma_gemm], writing the result to the uninitialized buffer
+/// `c` and returning it as initialized.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// `a`, `b` and `c` **MUST** be `m * k`, `k * n` and `m * n` in length respectively,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_gemm_uninit<'a, T>(
+    a: &[T],
+    b: &[T],
+    c: &'a mut [MaybeUninit<T>],
+    m: usize,
+    k: usize,
+    n: usize,
+) -> &'a mut [T]
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(a.len(), m * k);
+    debug_assert_eq!(b.len(), k * n);
+    debug_assert_eq!(c.len(), m * n);
+
+    // The result is accumulated into `c`, so it is zeroed here instead of requiring
+    // the caller to initialize it.
+    for v in c.iter_mut() {
+        v.write(AutoMath::zero());
+    }
+
+    // SAFETY: Every element of `c` has been written above.
+    let c = slice::from_raw_parts_mut(c.as_mut_ptr().cast::<T>(), c.len());
+    gemm_accumulate(a, b, c, m, k, n);
+    c
+}
+
+#[inline(always)]
+/// Adds `A·B` onto the existing values of `c`.
+unsafe fn gemm_accumulate<T>(a: &[T], b: &[T], c: &mut [T], m: usize, k: usize, n: usize)
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    // The `i, p, j` loop order keeps the inner loop contiguous over `b` and `c`
+    // allowing the compiler to vectorize it.
+    for i in 0..m {
+        for p in 0..k {
+            let a = *a.get_unchecked(i * k + p);
+            for j in 0..n {
+                let b = *b.get_unchecked(p * n + j);
+                let c = c.get_unchecked_mut(i * n + j);
+                *c = AutoMath::add(*c, AutoMath::mul(a, b));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close_vector, get_sample_vectors, simple_gemm};
+
+    #[test]
+    fn test_f32_xany_nofma_gemm() {
+        let (m, k, n) = (13, 17, 11);
+        let (a, _) = get_sample_vectors::<f32>(m * k);
+        let (_, b) = get_sample_vectors::<f32>(k * n);
+        let expected = simple_gemm(&a, &b, m, k, n);
+
+        let mut c = vec![0.0; m * n];
+        unsafe { generic_xany_fallback_nofma_gemm(&a, &b, &mut c, m, k, n) };
+        assert_is_close_vector(&c, &expected);
+
+        let mut c = Vec::with_capacity(m * n);
+        let c = unsafe {
+            generic_xany_fallback_nofma_gemm_uninit(
+                &a,
+                &b,
+                c.spare_capacity_mut(),
+                m,
+                k,
+                n,
+            )
+        };
+        assert_is_close_vector(c, &expected);
+    }
+}
@@ -0,0 +1,63 @@
+use crate::danger::utils::quantize_uniform_f32;
+
+#[inline]
+/// Counts the elements of the vector falling into each of `bins` uniformly sized
+/// bins spanning `[lo, hi]`, adding the totals to `counts`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// counts: [u64; bins]
+///
+/// for i in 0..D:
+///     counts[quantize_uniform(bins, lo, hi, x[i])] += 1
+/// ```
+///
+/// Bins follow the same rules as `quantize_uniform`, values on a bin boundary belong
+/// to the bin above it, `hi` belongs to the last bin, out of range values are clamped
+/// into the first or last bin and `NaN` is counted in the first bin.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// `counts` **MUST** be `bins` long, `bins` **MUST** be within `1..=2^24` and `lo`
+/// **MUST** be less than `hi`, otherwise this routine will become immediately UB due
+/// to out of bounds pointer accesses or produce meaningless counts.
+pub unsafe fn f32_xany_fallback_nofma_histogram(
+    bins: usize,
+    lo: f32,
+    hi: f32,
+    x: &[f32],
+    counts: &mut [u64],
+) {
+    debug_assert_eq!(counts.len(), bins);
+    debug_assert!(
+        (1..=1 << 24).contains(&bins),
+        "Bins must be within 1..=2^24"
+    );
+    debug_assert!(lo < hi, "The lower bound must be less than the upper bound");
+
+    let scale = bins as f32 / (hi - lo);
+    let max_bucket = (bins - 1) as f32;
+
+    for &v in x {
+        let bucket = quantize_uniform_f32(v, lo, scale, max_bucket);
+        *counts.get_unchecked_mut(bucket as usize) += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_histogram() {
+        let x = [0.0, 0.25, 0.5, 0.99, 1.0, -5.0, 5.0, f32::NAN, 0.3];
+        let mut counts = [0; 4];
+        unsafe { f32_xany_fallback_nofma_histogram(4, 0.0, 1.0, &x, &mut counts) };
+        assert_eq!(counts, [3, 2, 1, 3]);
+    }
+}
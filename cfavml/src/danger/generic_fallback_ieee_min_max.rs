@@ -0,0 +1,146 @@
+macro_rules! ieee_min_max {
+    (
+        $t:ty,
+        maximum = $maximum:ident,
+        minimum = $minimum:ident,
+        maximum_vertical = $maximum_vertical:ident,
+        minimum_vertical = $minimum_vertical:ident $(,)?
+    ) => {
+        #[inline(always)]
+        /// Returns the IEEE 754-2019 `maximum` of `a` and `b`, propagating `NaN`
+        /// and ordering `-0.0` below `+0.0`.
+        pub(crate) fn $maximum(a: $t, b: $t) -> $t {
+            if a.is_nan() || b.is_nan() {
+                a + b
+            } else if a == b {
+                // Equal values can only differ by their sign when they are both zero.
+                <$t>::from_bits(a.to_bits() & b.to_bits())
+            } else if a > b {
+                a
+            } else {
+                b
+            }
+        }
+
+        #[inline(always)]
+        /// Returns the IEEE 754-2019 `minimum` of `a` and `b`, propagating `NaN`
+        /// and ordering `-0.0` below `+0.0`.
+        pub(crate) fn $minimum(a: $t, b: $t) -> $t {
+            if a.is_nan() || b.is_nan() {
+                a + b
+            } else if a == b {
+                <$t>::from_bits(a.to_bits() | b.to_bits())
+            } else if a < b {
+                a
+            } else {
+                b
+            }
+        }
+
+        #[inline]
+        /// Sets each element of the mutable vector `x` to the IEEE 754-2019 `maximum` of
+        /// itself and the matching element of `y`.
+        ///
+        /// Unlike the other `max` routines this propagates `NaN` and orders `-0.0`
+        /// below `+0.0`.
+        ///
+        /// These are fallback routines, they are designed to be optimized
+        /// by the compiler only, in areas where manually optimized routines
+        /// are unable to run due to lack of CPU features.
+        ///
+        /// # Safety
+        ///
+        /// Vectors **MUST** be the same length, otherwise this routine
+        /// will become immediately UB due to out of bounds pointer accesses.
+        pub unsafe fn $maximum_vertical(x: &mut [$t], y: &[$t]) {
+            debug_assert_eq!(x.len(), y.len());
+
+            for i in 0..x.len() {
+                let x = x.get_unchecked_mut(i);
+                *x = $maximum(*x, *y.get_unchecked(i));
+            }
+        }
+
+        #[inline]
+        /// Sets each element of the mutable vector `x` to the IEEE 754-2019 `minimum` of
+        /// itself and the matching element of `y`.
+        ///
+        /// Unlike the other `min` routines this propagates `NaN` and orders `-0.0`
+        /// below `+0.0`.
+        ///
+        /// These are fallback routines, they are designed to be optimized
+        /// by the compiler only, in areas where manually optimized routines
+        /// are unable to run due to lack of CPU features.
+        ///
+        /// # Safety
+        ///
+        /// Vectors **MUST** be the same length, otherwise this routine
+        /// will become immediately UB due to out of bounds pointer accesses.
+        pub unsafe fn $minimum_vertical(x: &mut [$t], y: &[$t]) {
+            debug_assert_eq!(x.len(), y.len());
+
+            for i in 0..x.len() {
+                let x = x.get_unchecked_mut(i);
+                *x = $minimum(*x, *y.get_unchecked(i));
+            }
+        }
+    };
+}
+
+ieee_min_max!(
+    f32,
+    maximum = f32_ieee_maximum,
+    minimum = f32_ieee_minimum,
+    maximum_vertical = f32_xany_fallback_nofma_maximum_vertical,
+    minimum_vertical = f32_xany_fallback_nofma_minimum_vertical,
+);
+ieee_min_max!(
+    f64,
+    maximum = f64_ieee_maximum,
+    minimum = f64_ieee_minimum,
+    maximum_vertical = f64_xany_fallback_nofma_maximum_vertical,
+    minimum_vertical = f64_xany_fallback_nofma_minimum_vertical,
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f32_ieee_maximum_minimum() {
+        assert!(f32_ieee_maximum(f32::NAN, 1.0).is_nan());
+        assert!(f32_ieee_maximum(1.0, f32::NAN).is_nan());
+        assert!(f32_ieee_minimum(f32::NAN, 1.0).is_nan());
+        assert!(f32_ieee_minimum(1.0, f32::NAN).is_nan());
+
+        assert_eq!(f32_ieee_maximum(-0.0, 0.0).to_bits(), 0.0f32.to_bits());
+        assert_eq!(f32_ieee_maximum(0.0, -0.0).to_bits(), 0.0f32.to_bits());
+        assert_eq!(f32_ieee_minimum(-0.0, 0.0).to_bits(), (-0.0f32).to_bits());
+        assert_eq!(f32_ieee_minimum(0.0, -0.0).to_bits(), (-0.0f32).to_bits());
+
+        assert_eq!(f32_ieee_maximum(-1.0, 2.0), 2.0);
+        assert_eq!(f32_ieee_minimum(-1.0, 2.0), -1.0);
+        assert_eq!(f32_ieee_maximum(f32::INFINITY, 2.0), f32::INFINITY);
+        assert_eq!(f32_ieee_minimum(f32::NEG_INFINITY, 2.0), f32::NEG_INFINITY);
+    }
+
+    #[test]
+    fn test_f64_xany_nofma_maximum_minimum_vertical() {
+        let x = [f64::NAN, 1.0, -0.0, 0.0, -1.0, 3.0];
+        let y = [1.0, f64::NAN, 0.0, -0.0, 2.0, 3.0];
+
+        let mut max = x;
+        unsafe { f64_xany_fallback_nofma_maximum_vertical(&mut max, &y) };
+        assert!(max[0].is_nan() && max[1].is_nan());
+        assert_eq!(max[2].to_bits(), 0.0f64.to_bits());
+        assert_eq!(max[3].to_bits(), 0.0f64.to_bits());
+        assert_eq!(&max[4..], &[2.0, 3.0]);
+
+        let mut min = x;
+        unsafe { f64_xany_fallback_nofma_minimum_vertical(&mut min, &y) };
+        assert!(min[0].is_nan() && min[1].is_nan());
+        assert_eq!(min[2].to_bits(), (-0.0f64).to_bits());
+        assert_eq!(min[3].to_bits(), (-0.0f64).to_bits());
+        assert_eq!(&min[4..], &[-1.0, 3.0]);
+    }
+}
@@ -0,0 +1,90 @@
+use crate::danger::partially_overlaps;
+
+#[inline]
+/// Applies the leaky ReLU activation to the vector, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = x[i] if x[i] > 0 else slope * x[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_leaky_relu(
+    slope: f32,
+    x: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = leaky_relu(slope, *x.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Applies the leaky ReLU activation to the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     if x[i] <= 0:
+///         x[i] = slope * x[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn f32_xany_fallback_nofma_leaky_relu_inplace(slope: f32, x: &mut [f32]) {
+    for v in x.iter_mut() {
+        *v = leaky_relu(slope, *v);
+    }
+}
+
+#[inline(always)]
+fn leaky_relu(slope: f32, v: f32) -> f32 {
+    if v > 0.0 {
+        v
+    } else {
+        slope * v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_leaky_relu() {
+        let x = [-2.0, -0.5, 0.0, 0.5, 2.0];
+
+        let mut result = [0.0; 5];
+        unsafe { f32_xany_fallback_nofma_leaky_relu(0.1, &x, &mut result) };
+        assert_eq!(result, [-0.2, -0.05, 0.0, 0.5, 2.0]);
+
+        let mut x = x;
+        unsafe { f32_xany_fallback_nofma_leaky_relu_inplace(0.0, &mut x) };
+        assert_eq!(x, [-0.0, -0.0, 0.0, 0.5, 2.0]);
+    }
+}
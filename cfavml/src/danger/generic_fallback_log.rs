@@ -0,0 +1,199 @@
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[inline]
+/// Computes the natural logarithm of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = ln(x[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_ln<T>(x: &[T], result: &mut [T])
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = AutoMath::ln(*x.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Computes the natural logarithm of each element of the vector in place.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_ln_inplace<T>(x: &mut [T])
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    for v in x.iter_mut() {
+        *v = AutoMath::ln(*v);
+    }
+}
+
+#[inline]
+/// Computes the base 2 logarithm of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = log2(x[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_log2<T>(x: &[T], result: &mut [T])
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = AutoMath::log2(*x.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Computes the base 2 logarithm of each element of the vector in place.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_log2_inplace<T>(x: &mut [T])
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    for v in x.iter_mut() {
+        *v = AutoMath::log2(*v);
+    }
+}
+
+#[inline]
+/// Computes the base 10 logarithm of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = log10(x[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_log10<T>(x: &[T], result: &mut [T])
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = AutoMath::log10(*x.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Computes the base 10 logarithm of each element of the vector in place.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_log10_inplace<T>(x: &mut [T])
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    for v in x.iter_mut() {
+        *v = AutoMath::log10(*v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_log() {
+        let x = [1.0f32, f32::INFINITY, 0.0];
+        let mut result = [0.0; 3];
+        unsafe { generic_xany_fallback_nofma_ln(&x, &mut result) };
+        assert_eq!(result, [0.0, f32::INFINITY, f32::NEG_INFINITY]);
+
+        let mut x = [8.0f64, 0.5, -1.0];
+        unsafe { generic_xany_fallback_nofma_log2_inplace(&mut x) };
+        assert_eq!(&x[..2], [3.0, -1.0]);
+        assert!(x[2].is_nan());
+
+        let x = [1000.0f32, 0.01];
+        let mut result = [0.0; 2];
+        unsafe { generic_xany_fallback_nofma_log10(&x, &mut result) };
+        assert_eq!(result, [3.0, -2.0]);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::danger::mean_std_from_sums_f32;
+
+#[inline]
+/// Computes the mean and population standard deviation of every column of the matrix,
+/// returning `(mean, std)`.
+///
+/// ```py
+/// D: int
+/// N: int
+/// matrix: [[f32; D]; N]
+/// mean: [f32; D]
+/// std: [f32; D]
+///
+/// for j in 0..D:
+///     mean[j] = sum(matrix[i, j] for i in 0..N) / N
+///     std[j] = sqrt(sum((matrix[i, j] - mean[j]) ** 2 for i in 0..N) / N)
+/// ```
+///
+/// The deviations are accumulated in a second pass over the rows, a matrix with a
+/// single row always produces a standard deviation of exactly `0.0`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// The matrix **MUST** contain at least one row and all vectors within the matrix
+/// **MUST** be the same length.
+pub unsafe fn f32_xany_fallback_nofma_mean_std_vertical(
+    matrix: &[&[f32]],
+) -> (Vec<f32>, Vec<f32>) {
+    let (means, mut stds) = f32_xany_fallback_nofma_variance_vertical(matrix);
+    for std in stds.iter_mut() {
+        *std = std.sqrt();
+    }
+
+    (means, stds)
+}
+
+#[inline]
+/// Computes the mean and population variance of every column of the matrix,
+/// returning `(mean, variance)`.
+///
+/// ```py
+/// D: int
+/// N: int
+/// matrix: [[f32; D]; N]
+/// mean: [f32; D]
+/// variance: [f32; D]
+///
+/// for j in 0..D:
+///     mean[j] = sum(matrix[i, j] for i in 0..N) / N
+///     variance[j] = sum((matrix[i, j] - mean[j]) ** 2 for i in 0..N) / N
+/// ```
+///
+/// The deviations are accumulated in a second pass over the rows, which stays
+/// accurate where the single-pass [f32_xany_fallback_nofma_mean_std_vertical_fast]
+/// cancels to noise.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// The matrix **MUST** contain at least one row and all vectors within the matrix
+/// **MUST** be the same length.
+pub unsafe fn f32_xany_fallback_nofma_variance_vertical(
+    matrix: &[&[f32]],
+) -> (Vec<f32>, Vec<f32>) {
+    let len = matrix[0].len();
+    let n = matrix.len() as f32;
+
+    let mut means = vec![0.0; len];
+    for row in matrix {
+        debug_assert_eq!(row.len(), len);
+
+        for j in 0..len {
+            *means.get_unchecked_mut(j) += *row.get_unchecked(j);
+        }
+    }
+
+    for mean in means.iter_mut() {
+        *mean /= n;
+    }
+
+    let mut variances = vec![0.0; len];
+    for row in matrix {
+        for j in 0..len {
+            let diff = *row.get_unchecked(j) - *means.get_unchecked(j);
+            *variances.get_unchecked_mut(j) += diff * diff;
+        }
+    }
+
+    for variance in variances.iter_mut() {
+        *variance /= n;
+    }
+
+    (means, variances)
+}
+
+#[inline]
+/// Computes the mean and population standard deviation of every column of the matrix
+/// in a single pass, returning `(mean, std)`.
+///
+/// ```py
+/// D: int
+/// N: int
+/// matrix: [[f32; D]; N]
+/// mean: [f32; D]
+/// std: [f32; D]
+///
+/// for j in 0..D:
+///     mean[j] = sum(matrix[i, j] for i in 0..N) / N
+///     std[j] = sqrt(sum(matrix[i, j] ** 2 for i in 0..N) / N - mean[j] ** 2)
+/// ```
+///
+/// The matrix is only read once, but the `E[x²] - E[x]²` form loses precision as the
+/// mean grows relative to the spread, variances within its rounding error are flushed
+/// to `0.0`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// All vectors within the matrix **MUST** be the same length.
+pub unsafe fn f32_xany_fallback_nofma_mean_std_vertical_fast(
+    matrix: &[&[f32]],
+) -> (Vec<f32>, Vec<f32>) {
+    let len = matrix.first().map_or(0, |row| row.len());
+
+    let mut means = vec![0.0; len];
+    let mut stds = vec![0.0; len];
+    for row in matrix {
+        debug_assert_eq!(row.len(), len);
+
+        for j in 0..len {
+            let value = *row.get_unchecked(j);
+            *means.get_unchecked_mut(j) += value;
+            *stds.get_unchecked_mut(j) += value * value;
+        }
+    }
+
+    for (mean, std) in means.iter_mut().zip(stds.iter_mut()) {
+        (*mean, *std) = mean_std_from_sums_f32(*mean, *std, matrix.len());
+    }
+
+    (means, stds)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_mean_std_vertical() {
+        let matrix: [&[f32]; 4] =
+            [&[1.0, 10.0], &[3.0, 10.0], &[5.0, 10.0], &[7.0, 10.0]];
+
+        let (mean, std) = unsafe { f32_xany_fallback_nofma_mean_std_vertical(&matrix) };
+        assert_eq!(mean, [4.0, 10.0]);
+        assert_eq!(std, [5.0f32.sqrt(), 0.0]);
+
+        let (mean, variance) =
+            unsafe { f32_xany_fallback_nofma_variance_vertical(&matrix) };
+        assert_eq!(mean, [4.0, 10.0]);
+        assert_eq!(variance, [5.0, 0.0]);
+
+        let (mean, std) =
+            unsafe { f32_xany_fallback_nofma_mean_std_vertical_fast(&matrix) };
+        assert_eq!(mean, [4.0, 10.0]);
+        assert_eq!(std, [5.0f32.sqrt(), 0.0]);
+    }
+
+    #[test]
+    fn test_xany_nofma_mean_std_vertical_large_offset() {
+        // A naive `E[x²] - E[x]²` loses every significant digit here.
+        let matrix: [&[f32]; 2] = [&[1e4 + 1.0], &[1e4 - 1.0]];
+
+        let (mean, std) = unsafe { f32_xany_fallback_nofma_mean_std_vertical(&matrix) };
+        assert_eq!(mean, [1e4]);
+        assert_eq!(std, [1.0]);
+
+        let (_, variance) =
+            unsafe { f32_xany_fallback_nofma_variance_vertical(&matrix) };
+        assert_eq!(variance, [1.0]);
+
+        // The single-pass form cancels to zero, which is why it is not the default.
+        let (_, std) =
+            unsafe { f32_xany_fallback_nofma_mean_std_vertical_fast(&matrix) };
+        assert_eq!(std, [0.0]);
+    }
+}
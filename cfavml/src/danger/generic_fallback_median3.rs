@@ -0,0 +1,182 @@
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[inline]
+/// Computes the element-wise minimum of `a`, `b` and `c`, writing the
+/// result to `result`.
+///
+/// ```py
+/// D: int
+/// a: [T; D]
+/// b: [T; D]
+/// c: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = min(a[i], b[i], c[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_min3<T>(
+    a: &[T],
+    b: &[T],
+    c: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+    debug_assert_eq!(a.len(), result.len());
+    debug_assert!(
+        ![a, b, c].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    for i in 0..a.len() {
+        let a = *a.get_unchecked(i);
+        let b = *b.get_unchecked(i);
+        let c = *c.get_unchecked(i);
+        *result.get_unchecked_mut(i) = AutoMath::cmp_min(AutoMath::cmp_min(a, b), c);
+    }
+}
+
+#[inline]
+/// Computes the element-wise maximum of `a`, `b` and `c`, writing the
+/// result to `result`.
+///
+/// ```py
+/// D: int
+/// a: [T; D]
+/// b: [T; D]
+/// c: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = max(a[i], b[i], c[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_max3<T>(
+    a: &[T],
+    b: &[T],
+    c: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+    debug_assert_eq!(a.len(), result.len());
+    debug_assert!(
+        ![a, b, c].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    for i in 0..a.len() {
+        let a = *a.get_unchecked(i);
+        let b = *b.get_unchecked(i);
+        let c = *c.get_unchecked(i);
+        *result.get_unchecked_mut(i) = AutoMath::cmp_max(AutoMath::cmp_max(a, b), c);
+    }
+}
+
+#[inline]
+/// Computes the element-wise median of `a`, `b` and `c`, writing the
+/// result to `result`.
+///
+/// ```py
+/// D: int
+/// a: [T; D]
+/// b: [T; D]
+/// c: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = max(min(a[i], b[i]), min(max(a[i], b[i]), c[i]))
+/// ```
+///
+/// The median of three is the building block of median filters, i.e. denoising a
+/// signal with a window of three samples.
+///
+/// `NaN` elements follow the `min`/`max` routines rather than being ordered, so the
+/// result for an element where any input is `NaN` is not meaningful.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_median3<T>(
+    a: &[T],
+    b: &[T],
+    c: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(a.len(), b.len());
+    debug_assert_eq!(a.len(), c.len());
+    debug_assert_eq!(a.len(), result.len());
+    debug_assert!(
+        ![a, b, c].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    for i in 0..a.len() {
+        let a = *a.get_unchecked(i);
+        let b = *b.get_unchecked(i);
+        let c = *c.get_unchecked(i);
+        *result.get_unchecked_mut(i) = AutoMath::cmp_max(
+            AutoMath::cmp_min(a, b),
+            AutoMath::cmp_min(AutoMath::cmp_max(a, b), c),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_min3_max3_median3() {
+        let (x, y) = get_sample_vectors::<f32>(1028);
+        let (a, b, c) = (&x[..514], &x[514..], &y[..514]);
+
+        let mut min = vec![0.0; 514];
+        let mut max = vec![0.0; 514];
+        let mut median = vec![0.0; 514];
+        unsafe {
+            generic_xany_fallback_nofma_min3(a, b, c, &mut min);
+            generic_xany_fallback_nofma_max3(a, b, c, &mut max);
+            generic_xany_fallback_nofma_median3(c, a, b, &mut median);
+        }
+
+        for i in 0..514 {
+            let mut sorted = [a[i], b[i], c[i]];
+            sorted.sort_by(|x, y| x.partial_cmp(y).unwrap());
+            assert_eq!([min[i], median[i], max[i]], sorted);
+        }
+    }
+}
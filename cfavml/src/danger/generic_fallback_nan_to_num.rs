@@ -0,0 +1,119 @@
+use crate::danger::partially_overlaps;
+
+#[inline]
+/// Replaces the non-finite values of the vector with the given substitutes,
+/// writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     if is_nan(x[i]):
+///         result[i] = nan_value
+///     elif x[i] == inf:
+///         result[i] = pos_inf_value
+///     elif x[i] == -inf:
+///         result[i] = neg_inf_value
+///     else:
+///         result[i] = x[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_nan_to_num(
+    nan_value: f32,
+    pos_inf_value: f32,
+    neg_inf_value: f32,
+    x: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        let v = *x.get_unchecked(i);
+        *result.get_unchecked_mut(i) =
+            nan_to_num(nan_value, pos_inf_value, neg_inf_value, v);
+    }
+}
+
+#[inline]
+/// Replaces the non-finite values of the vector with the given substitutes in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     if is_nan(x[i]):
+///         x[i] = nan_value
+///     elif x[i] == inf:
+///         x[i] = pos_inf_value
+///     elif x[i] == -inf:
+///         x[i] = neg_inf_value
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn f32_xany_fallback_nofma_nan_to_num_inplace(
+    nan_value: f32,
+    pos_inf_value: f32,
+    neg_inf_value: f32,
+    x: &mut [f32],
+) {
+    for v in x.iter_mut() {
+        *v = nan_to_num(nan_value, pos_inf_value, neg_inf_value, *v);
+    }
+}
+
+#[inline(always)]
+fn nan_to_num(nan_value: f32, pos_inf_value: f32, neg_inf_value: f32, v: f32) -> f32 {
+    if v.is_nan() {
+        nan_value
+    } else if v == f32::INFINITY {
+        pos_inf_value
+    } else if v == f32::NEG_INFINITY {
+        neg_inf_value
+    } else {
+        v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f32_xany_nofma_nan_to_num() {
+        let mut x = [1.0, f32::NAN, f32::INFINITY, f32::NEG_INFINITY, -0.0];
+        let expected = [1.0, 0.0, 1000.0, -1000.0, -0.0];
+
+        let mut result = [0.0; 5];
+        unsafe {
+            f32_xany_fallback_nofma_nan_to_num(0.0, 1000.0, -1000.0, &x, &mut result)
+        };
+        assert_eq!(result, expected);
+
+        unsafe {
+            f32_xany_fallback_nofma_nan_to_num_inplace(0.0, 1000.0, -1000.0, &mut x)
+        };
+        assert_eq!(x, expected);
+    }
+}
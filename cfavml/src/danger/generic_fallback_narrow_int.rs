@@ -0,0 +1,396 @@
+//! Fallback routines for the narrow `u8` and `i16` integer types which are not covered
+//! by the generic [Math](crate::math::Math) routines.
+
+use crate::danger::{overflow_as_u8, overflow_as_u8_mut, U8Overflow};
+
+#[inline]
+/// Computes `x[i] + y[i]` element wise, wrapping or saturating on overflow as
+/// selected by the element type, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// y: [T; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] + y[i]
+/// ```
+///
+/// `u8` and `Wrapping<u8>` wrap on overflow, `Saturating<u8>` saturates at the
+/// bounds of `u8` like [u8_xany_fallback_nofma_saturating_add_vertical].
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn u8_xany_fallback_nofma_add_vertical<T: U8Overflow>(x: &mut [T], y: &[T]) {
+    let (x, y) = (overflow_as_u8_mut(x), overflow_as_u8(y));
+    if T::SATURATING {
+        apply_inplace(x, y, u8::saturating_add)
+    } else {
+        apply_inplace(x, y, u8::wrapping_add)
+    }
+}
+
+#[inline]
+/// Computes `x[i] - y[i]` element wise, wrapping or saturating on overflow as
+/// selected by the element type, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// y: [T; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] - y[i]
+/// ```
+///
+/// `u8` and `Wrapping<u8>` wrap on overflow, `Saturating<u8>` saturates at the
+/// bounds of `u8` like [u8_xany_fallback_nofma_saturating_sub_vertical].
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn u8_xany_fallback_nofma_sub_vertical<T: U8Overflow>(x: &mut [T], y: &[T]) {
+    let (x, y) = (overflow_as_u8_mut(x), overflow_as_u8(y));
+    if T::SATURATING {
+        apply_inplace(x, y, u8::saturating_sub)
+    } else {
+        apply_inplace(x, y, u8::wrapping_sub)
+    }
+}
+
+#[inline]
+/// Computes `x[i] + y[i]` element wise, saturating at the bounds of `u8`, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [u8; D]
+/// y: [u8; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] + y[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn u8_xany_fallback_nofma_saturating_add_vertical(x: &mut [u8], y: &[u8]) {
+    apply_inplace(x, y, u8::saturating_add)
+}
+
+#[inline]
+/// Computes `x[i] - y[i]` element wise, saturating at the bounds of `u8`, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [u8; D]
+/// y: [u8; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] - y[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn u8_xany_fallback_nofma_saturating_sub_vertical(x: &mut [u8], y: &[u8]) {
+    apply_inplace(x, y, u8::saturating_sub)
+}
+
+#[inline]
+/// Sums all elements of the vector, widening to `u64` so the total cannot overflow.
+///
+/// ```py
+/// D: int
+/// total: u64
+/// x: [u8; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn u8_xany_fallback_nofma_sum_horizontal(x: &[u8]) -> u64 {
+    x.iter().map(|v| *v as u64).sum()
+}
+
+#[inline]
+/// Computes `x[i] + y[i]` element wise, wrapping on overflow, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [i16; D]
+/// y: [i16; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] + y[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i16_xany_fallback_nofma_add_vertical(x: &mut [i16], y: &[i16]) {
+    apply_inplace(x, y, i16::wrapping_add)
+}
+
+#[inline]
+/// Computes `x[i] - y[i]` element wise, wrapping on overflow, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [i16; D]
+/// y: [i16; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] - y[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i16_xany_fallback_nofma_sub_vertical(x: &mut [i16], y: &[i16]) {
+    apply_inplace(x, y, i16::wrapping_sub)
+}
+
+#[inline]
+/// Computes `x[i] + y[i]` element wise, saturating at the bounds of `i16`, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [i16; D]
+/// y: [i16; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] + y[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i16_xany_fallback_nofma_saturating_add_vertical(x: &mut [i16], y: &[i16]) {
+    apply_inplace(x, y, i16::saturating_add)
+}
+
+#[inline]
+/// Computes `x[i] - y[i]` element wise, saturating at the bounds of `i16`, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [i16; D]
+/// y: [i16; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] - y[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn i16_xany_fallback_nofma_saturating_sub_vertical(x: &mut [i16], y: &[i16]) {
+    apply_inplace(x, y, i16::saturating_sub)
+}
+
+#[inline]
+/// Sums all elements of the vector, widening to `i64` so the total cannot overflow.
+///
+/// ```py
+/// D: int
+/// total: i64
+/// x: [i16; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn i16_xany_fallback_nofma_sum_horizontal(x: &[i16]) -> i64 {
+    x.iter().map(|v| *v as i64).sum()
+}
+
+#[inline]
+/// Computes the horizontal minimum of the given vector that is `[u8; N]`.
+///
+/// An empty vector returns `u8::MAX`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn u8_xany_fallback_nofma_min_horizontal(arr: &[u8]) -> u8 {
+    arr.iter().copied().fold(u8::MAX, u8::min)
+}
+
+#[inline]
+/// Computes the horizontal maximum of the given vector that is `[u8; N]`.
+///
+/// An empty vector returns `u8::MIN`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn u8_xany_fallback_nofma_max_horizontal(arr: &[u8]) -> u8 {
+    arr.iter().copied().fold(u8::MIN, u8::max)
+}
+
+#[inline]
+/// Computes the horizontal minimum of the given vector that is `[i16; N]`.
+///
+/// An empty vector returns `i16::MAX`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn i16_xany_fallback_nofma_min_horizontal(arr: &[i16]) -> i16 {
+    arr.iter().copied().fold(i16::MAX, i16::min)
+}
+
+#[inline]
+/// Computes the horizontal maximum of the given vector that is `[i16; N]`.
+///
+/// An empty vector returns `i16::MIN`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn i16_xany_fallback_nofma_max_horizontal(arr: &[i16]) -> i16 {
+    arr.iter().copied().fold(i16::MIN, i16::max)
+}
+
+#[inline(always)]
+unsafe fn apply_inplace<T: Copy>(x: &mut [T], y: &[T], op: impl Fn(T, T) -> T) {
+    debug_assert_eq!(x.len(), y.len());
+
+    for i in 0..x.len() {
+        let x = x.get_unchecked_mut(i);
+        *x = op(*x, *y.get_unchecked(i));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_u8_xany_nofma_narrow_int() {
+        let mut x = vec![250u8, 3, 128];
+        let y = vec![10u8, 5, 128];
+
+        unsafe { u8_xany_fallback_nofma_saturating_add_vertical(&mut x, &y) };
+        assert_eq!(x, [255, 8, 255]);
+
+        unsafe { u8_xany_fallback_nofma_sub_vertical(&mut x, &y) };
+        assert_eq!(x, [245, 3, 127]);
+
+        unsafe { u8_xany_fallback_nofma_saturating_sub_vertical(&mut x, &y) };
+        assert_eq!(x, [235, 0, 0]);
+
+        unsafe { u8_xany_fallback_nofma_add_vertical(&mut x, &y) };
+        assert_eq!(x, [245, 5, 128]);
+
+        assert_eq!(unsafe { u8_xany_fallback_nofma_sum_horizontal(&x) }, 378);
+        assert_eq!(unsafe { u8_xany_fallback_nofma_min_horizontal(&x) }, 5);
+        assert_eq!(unsafe { u8_xany_fallback_nofma_max_horizontal(&x) }, 245);
+        assert_eq!(
+            unsafe { u8_xany_fallback_nofma_min_horizontal(&[]) },
+            u8::MAX
+        );
+    }
+
+    #[test]
+    fn test_i16_xany_nofma_narrow_int() {
+        let mut x = vec![i16::MAX, -5, i16::MIN];
+        let y = vec![1i16, 5, -1];
+
+        unsafe { i16_xany_fallback_nofma_saturating_add_vertical(&mut x, &y) };
+        assert_eq!(x, [i16::MAX, 0, i16::MIN]);
+
+        unsafe { i16_xany_fallback_nofma_add_vertical(&mut x, &y) };
+        assert_eq!(x, [i16::MIN, 5, i16::MAX]);
+
+        assert_eq!(
+            unsafe { i16_xany_fallback_nofma_sum_horizontal(&x) },
+            i16::MIN as i64 + 5 + i16::MAX as i64
+        );
+        assert_eq!(
+            unsafe { i16_xany_fallback_nofma_min_horizontal(&x) },
+            i16::MIN
+        );
+        assert_eq!(
+            unsafe { i16_xany_fallback_nofma_max_horizontal(&x) },
+            i16::MAX
+        );
+    }
+}
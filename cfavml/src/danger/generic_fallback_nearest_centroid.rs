@@ -0,0 +1,146 @@
+use crate::danger::{
+    generic_xany_fallback_nofma_euclidean,
+    nearest_batch_with,
+    nearest_with,
+};
+use crate::math::*;
+
+#[inline]
+/// Finds the centroid with the smallest squared Euclidean distance to `point`,
+/// returning its index and distance.
+///
+/// ```py
+/// K: int
+/// D: int
+/// point: [T; D]
+/// centroids: [[T; D]; K]
+///
+/// best = 0
+/// for k in 1..K:
+///     if euclidean(point, centroids[k]) < euclidean(point, centroids[best]):
+///         best = k
+///
+/// return best, euclidean(point, centroids[best])
+/// ```
+///
+/// The running minimum is tracked as each centroid is streamed past, so no vector of
+/// distances is materialized. Ties resolve to the lowest index and `NaN` distances
+/// are only selected if every distance is `NaN`.
+///
+/// # Panics
+///
+/// Panics if `centroids` is empty.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Every centroid **MUST** be the same length as `point`, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_nearest_centroid<T>(
+    point: &[T],
+    centroids: &[&[T]],
+) -> (usize, T)
+where
+    T: Copy + PartialOrd,
+    AutoMath: Math<T>,
+{
+    nearest_with(point, centroids, |x, y| {
+        generic_xany_fallback_nofma_euclidean(x, y)
+    })
+}
+
+#[inline]
+/// Assigns each of `points` to the centroid with the smallest squared Euclidean
+/// distance, writing its index to `labels` and the distance to `distances`.
+///
+/// ```py
+/// N: int
+/// K: int
+/// D: int
+/// points: [[T; D]; N]
+/// centroids: [[T; D]; K]
+/// labels: [usize; N]
+/// distances: [T; N]
+///
+/// for i in 0..N:
+///     labels[i], distances[i] = nearest_centroid(points[i], centroids)
+/// ```
+///
+/// This is the assignment step of Lloyd's algorithm for k-means.
+///
+/// # Panics
+///
+/// Panics if `centroids` is empty while `points` is not, or if `labels` and
+/// `distances` are not the same length as `points`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Every point and centroid **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_nearest_centroid_batch<T>(
+    points: &[&[T]],
+    centroids: &[&[T]],
+    labels: &mut [usize],
+    distances: &mut [T],
+) where
+    T: Copy + PartialOrd,
+    AutoMath: Math<T>,
+{
+    nearest_batch_with(points, centroids, labels, distances, |x, y| {
+        generic_xany_fallback_nofma_euclidean(x, y)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_nearest_centroid() {
+        let centroids: [&[f32]; 3] = [&[0.0, 0.0], &[10.0, 10.0], &[0.0, 10.0]];
+
+        let res = unsafe {
+            generic_xany_fallback_nofma_nearest_centroid(&[1.0, 9.0], &centroids)
+        };
+        assert_eq!(res, (2, 2.0));
+
+        // Equidistant centroids resolve to the lowest index.
+        let res = unsafe {
+            generic_xany_fallback_nofma_nearest_centroid(&[5.0, 5.0], &centroids)
+        };
+        assert_eq!(res, (0, 50.0));
+
+        let nan_first: [&[f32]; 2] = [&[f32::NAN, 0.0], &[3.0, 4.0]];
+        let res = unsafe {
+            generic_xany_fallback_nofma_nearest_centroid(&[0.0, 0.0], &nan_first)
+        };
+        assert_eq!(res, (1, 25.0));
+
+        let points: [&[f32]; 2] = [&[9.0, 9.0], &[-1.0, 0.0]];
+        let mut labels = [0; 2];
+        let mut distances = [0.0; 2];
+        unsafe {
+            generic_xany_fallback_nofma_nearest_centroid_batch(
+                &points,
+                &centroids,
+                &mut labels,
+                &mut distances,
+            )
+        };
+        assert_eq!(labels, [1, 0]);
+        assert_eq!(distances, [2.0, 1.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "`centroids` must not be empty")]
+    fn test_xany_nofma_nearest_centroid_empty() {
+        unsafe { generic_xany_fallback_nofma_nearest_centroid::<f32>(&[1.0], &[]) };
+    }
+}
@@ -0,0 +1,103 @@
+use crate::danger::generic_xany_fallback_nofma_dot;
+use crate::math::*;
+
+#[inline]
+/// Computes the squared L2 norm of one `T` vector.
+///
+/// This is equivalent to `dot(x, x)`, the square root is **not** applied,
+/// use the sibling `l2_norm` routine for the L2 norm.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_norm<T>(x: &[T]) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    generic_xany_fallback_nofma_dot(x, x)
+}
+
+#[inline]
+/// Computes the L2 norm of one `T` vector.
+///
+/// ```py
+/// return sqrt(norm(x))
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_l2_norm<T>(x: &[T]) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    AutoMath::sqrt(generic_xany_fallback_nofma_norm(x))
+}
+
+#[inline]
+/// Computes the sum of squares of one `T` vector multiplied by `scale`.
+///
+/// This is the squared L2 norm scaled in the same pass, for example
+/// `1 / (D - 1)` when computing a variance with Bessel's correction.
+///
+/// ```py
+/// return scale * norm(x)
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_sum_of_scaled_squares<T>(
+    scale: T,
+    x: &[T],
+) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    AutoMath::mul(scale, generic_xany_fallback_nofma_norm(x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_f32_xany_nofma_norm() {
+        let (x, _) = get_sample_vectors::<f32>(514);
+        let norm = unsafe { generic_xany_fallback_nofma_norm(&x) };
+        assert_is_close(norm, simple_dot(&x, &x));
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_l2_norm() {
+        let (x, _) = get_sample_vectors::<f32>(514);
+        let norm = unsafe { generic_xany_fallback_nofma_l2_norm(&x) };
+        assert_is_close(norm, simple_dot(&x, &x).sqrt());
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_sum_of_scaled_squares() {
+        let (x, _) = get_sample_vectors(131);
+        let res = unsafe { generic_xany_fallback_nofma_sum_of_scaled_squares(0.25, &x) };
+        assert_is_close(res, 0.25 * simple_dot(&x, &x));
+    }
+}
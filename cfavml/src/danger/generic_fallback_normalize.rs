@@ -0,0 +1,102 @@
+use crate::danger::{
+    generic_xany_fallback_nofma_div_value,
+    generic_xany_fallback_nofma_l2_norm,
+};
+use crate::math::*;
+
+#[inline]
+/// Scales every row of the matrix in place to have an L2 norm of `1.0`.
+///
+/// ```py
+/// D: int
+/// N: int
+/// matrix: [[T; D]; N]
+///
+/// for row in matrix:
+///     norm = sqrt(dot(row, row))
+///     if norm != 0:
+///         for i in 0..D:
+///             row[i] = row[i] / norm
+/// ```
+///
+/// There is no portable reciprocal square root approximation, so this is identical
+/// to [generic_xany_fallback_nofma_normalize_batch_precise].
+///
+/// Rows with a norm of zero are left unchanged, rows do not need to be the same length.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_normalize_batch<T>(matrix: &mut [&mut [T]])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    generic_xany_fallback_nofma_normalize_batch_precise(matrix)
+}
+
+#[inline]
+/// Scales every row of the matrix in place to have an L2 norm of `1.0`, dividing
+/// each element by the exact L2 norm of its row.
+///
+/// ```py
+/// D: int
+/// N: int
+/// matrix: [[T; D]; N]
+///
+/// for row in matrix:
+///     norm = sqrt(dot(row, row))
+///     if norm != 0:
+///         for i in 0..D:
+///             row[i] = row[i] / norm
+/// ```
+///
+/// Rows with a norm of zero are left unchanged, rows do not need to be the same length.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_normalize_batch_precise<T>(
+    matrix: &mut [&mut [T]],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    for row in matrix.iter_mut() {
+        let norm = generic_xany_fallback_nofma_l2_norm(row);
+        if !AutoMath::cmp_eq(norm, AutoMath::zero()) {
+            generic_xany_fallback_nofma_div_value(row, norm);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_xany_nofma_normalize_batch() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let mut rows = [x, y, vec![0.0; 37]];
+        let mut matrix = rows
+            .iter_mut()
+            .map(|r| r.as_mut_slice())
+            .collect::<Vec<_>>();
+        unsafe { generic_xany_fallback_nofma_normalize_batch(&mut matrix) };
+
+        assert_is_close(simple_dot(&rows[0], &rows[0]), 1.0);
+        assert_is_close(simple_dot(&rows[1], &rows[1]), 1.0);
+        assert!(rows[2].iter().all(|v| *v == 0.0));
+    }
+}
@@ -0,0 +1,147 @@
+use core::num::{Saturating, Wrapping};
+
+use crate::danger::u8_xany_fallback_nofma_sum_horizontal;
+
+mod sealed {
+    use core::num::{Saturating, Wrapping};
+
+    pub trait Sealed {}
+
+    impl Sealed for u8 {}
+    impl Sealed for Wrapping<u8> {}
+    impl Sealed for Saturating<u8> {}
+}
+
+/// A `u8` element type which encodes in its type how arithmetic on it overflows.
+///
+/// The routines generic over this trait pick the wrapping or saturating variant of
+/// an operation from the element type, `u8` and [`Wrapping<u8>`](Wrapping) wrap
+/// around on overflow while [`Saturating<u8>`](Saturating) clamps at `0` and
+/// `u8::MAX`.
+///
+/// Both newtypes are `#[repr(transparent)]` wrappers around `u8`, so a slice of any
+/// implementor has the exact layout of a `[u8]` and is reinterpreted as one for the
+/// SIMD routines. This trait is sealed as that layout guarantee is what makes the
+/// reinterpretation sound.
+pub trait U8Overflow: sealed::Sealed + Copy {
+    /// If the arithmetic saturates rather than wraps.
+    const SATURATING: bool;
+
+    /// Narrows the exact total of a sum of `u8` values into the element type,
+    /// wrapping or saturating it.
+    fn from_widened_sum(total: u64) -> Self;
+}
+
+impl U8Overflow for u8 {
+    const SATURATING: bool = false;
+
+    #[inline(always)]
+    fn from_widened_sum(total: u64) -> Self {
+        total as u8
+    }
+}
+
+impl U8Overflow for Wrapping<u8> {
+    const SATURATING: bool = false;
+
+    #[inline(always)]
+    fn from_widened_sum(total: u64) -> Self {
+        Wrapping(total as u8)
+    }
+}
+
+impl U8Overflow for Saturating<u8> {
+    const SATURATING: bool = true;
+
+    #[inline(always)]
+    fn from_widened_sum(total: u64) -> Self {
+        Saturating(total.min(u8::MAX as u64) as u8)
+    }
+}
+
+#[inline(always)]
+/// Reinterprets a slice of a [U8Overflow] element type as the `u8` values it wraps.
+pub(crate) fn overflow_as_u8<T: U8Overflow>(x: &[T]) -> &[u8] {
+    // SAFETY: `T` is `u8` or one of the sealed `#[repr(transparent)]` wrappers around it.
+    unsafe { core::slice::from_raw_parts(x.as_ptr().cast(), x.len()) }
+}
+
+#[inline(always)]
+/// Reinterprets a mutable slice of a [U8Overflow] element type as the `u8` values
+/// it wraps.
+pub(crate) fn overflow_as_u8_mut<T: U8Overflow>(x: &mut [T]) -> &mut [u8] {
+    // SAFETY: `T` is `u8` or one of the sealed `#[repr(transparent)]` wrappers around it.
+    unsafe { core::slice::from_raw_parts_mut(x.as_mut_ptr().cast(), x.len()) }
+}
+
+#[inline]
+/// Sums all elements of the vector, wrapping or saturating the total as selected
+/// by the element type.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return total
+/// ```
+///
+/// The total is computed exactly by [u8_xany_fallback_nofma_sum_horizontal] and
+/// narrowed once at the end, which matches accumulating in `T` as a saturated total
+/// can never decrease.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn u8_xany_fallback_nofma_sum<T: U8Overflow>(x: &[T]) -> T {
+    T::from_widened_sum(u8_xany_fallback_nofma_sum_horizontal(overflow_as_u8(x)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::{
+        u8_xany_fallback_nofma_add_vertical,
+        u8_xany_fallback_nofma_sub_vertical,
+    };
+
+    #[test]
+    fn test_xany_nofma_overflow_add_sub_vertical() {
+        let mut x = [Wrapping(250u8), Wrapping(3), Wrapping(0)];
+        let y = [Wrapping(10u8), Wrapping(4), Wrapping(1)];
+        unsafe { u8_xany_fallback_nofma_add_vertical(&mut x, &y) };
+        assert_eq!(x, [Wrapping(4), Wrapping(7), Wrapping(1)]);
+        unsafe { u8_xany_fallback_nofma_sub_vertical(&mut x, &y) };
+        assert_eq!(x, [Wrapping(250), Wrapping(3), Wrapping(0)]);
+
+        let mut x = [Saturating(250u8), Saturating(3), Saturating(0)];
+        let y = [Saturating(10u8), Saturating(4), Saturating(1)];
+        unsafe { u8_xany_fallback_nofma_add_vertical(&mut x, &y) };
+        assert_eq!(x, [Saturating(255), Saturating(7), Saturating(1)]);
+        unsafe { u8_xany_fallback_nofma_sub_vertical(&mut x, &y) };
+        assert_eq!(x, [Saturating(245), Saturating(3), Saturating(0)]);
+    }
+
+    #[test]
+    fn test_xany_nofma_overflow_sum() {
+        let x = vec![200u8; 3];
+        assert_eq!(unsafe { u8_xany_fallback_nofma_sum(&x) }, 88);
+
+        let x = vec![Wrapping(200u8); 3];
+        assert_eq!(unsafe { u8_xany_fallback_nofma_sum(&x) }, Wrapping(88));
+
+        let x = vec![Saturating(200u8); 3];
+        assert_eq!(unsafe { u8_xany_fallback_nofma_sum(&x) }, Saturating(255));
+
+        let x = [Saturating(20u8), Saturating(30)];
+        assert_eq!(unsafe { u8_xany_fallback_nofma_sum(&x) }, Saturating(50));
+    }
+}
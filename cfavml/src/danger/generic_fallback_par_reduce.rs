@@ -0,0 +1,138 @@
+use crate::danger::{
+    generic_xany_fallback_nofma_dot,
+    generic_xany_fallback_nofma_sum_horizontal,
+    par_tree_reduce,
+};
+use crate::math::*;
+
+#[inline]
+/// Sums all elements of the vector in parallel, producing a bit identical result
+/// regardless of the number of threads in the rayon thread pool.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return total
+/// ```
+///
+/// The vector is split into fixed size blocks which are summed independently and
+/// combined in a fixed binary tree, so the order of additions only depends on the
+/// length of `x`. The result may differ from the sequential sum in the last bits,
+/// but repeating the sum always reproduces it exactly.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_par_sum_deterministic<T>(x: &[T]) -> T
+where
+    T: Copy + Send + Sync,
+    AutoMath: Math<T>,
+{
+    par_tree_reduce(
+        x.len(),
+        AutoMath::zero(),
+        |block| generic_xany_fallback_nofma_sum_horizontal(x.get_unchecked(block)),
+        |a, b| AutoMath::add(a, b),
+    )
+}
+
+#[inline]
+/// Computes the dot product of two vectors in parallel, producing a bit identical
+/// result regardless of the number of threads in the rayon thread pool.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// y: [T; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     total = total + x[i] * y[i]
+///
+/// return total
+/// ```
+///
+/// The partial dot products of fixed size blocks are combined in a fixed binary
+/// tree, so like [generic_xany_fallback_nofma_par_sum_deterministic] the order of
+/// additions only depends on the length of the vectors.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_par_dot_deterministic<T>(x: &[T], y: &[T]) -> T
+where
+    T: Copy + Send + Sync,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), y.len());
+
+    par_tree_reduce(
+        x.len(),
+        AutoMath::zero(),
+        |block| {
+            generic_xany_fallback_nofma_dot(
+                x.get_unchecked(block.clone()),
+                y.get_unchecked(block),
+            )
+        },
+        |a, b| AutoMath::add(a, b),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::danger::PAR_REDUCE_BLOCK;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_xany_nofma_par_deterministic() {
+        let (x, y) = get_sample_vectors::<f32>(5 * PAR_REDUCE_BLOCK + 131);
+
+        let reduce = |threads: usize| {
+            let pool = rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap();
+            pool.install(|| unsafe {
+                (
+                    generic_xany_fallback_nofma_par_sum_deterministic(&x).to_bits(),
+                    generic_xany_fallback_nofma_par_dot_deterministic(&x, &y).to_bits(),
+                )
+            })
+        };
+
+        let (sum, dot) = reduce(1);
+        for threads in [2, 3, 8] {
+            assert_eq!(reduce(threads), (sum, dot), "{threads} threads");
+        }
+
+        assert_is_close(
+            f32::from_bits(sum) / x.len() as f32,
+            x.iter().sum::<f32>() / x.len() as f32,
+        );
+        assert_is_close(
+            f32::from_bits(dot) / x.len() as f32,
+            simple_dot(&x, &y) / x.len() as f32,
+        );
+        assert_eq!(
+            unsafe { generic_xany_fallback_nofma_par_sum_deterministic::<f32>(&[]) },
+            0.0
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use crate::danger::{f32_xany_fallback_nofma_select_nth, percentile_rank};
+
+#[inline]
+/// Computes the median of the vector, interpolating between the two middle
+/// elements when `D` is even.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// return percentile(x, 50)
+/// ```
+///
+/// `scratch` is cleared and re-used as the working buffer, passing the same buffer
+/// between calls avoids re-allocating on each call.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// `x` **MUST** not be empty, otherwise this routine will become
+/// immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_median(x: &[f32], scratch: &mut Vec<f32>) -> f32 {
+    f32_xany_fallback_nofma_percentile(x, 50.0, scratch)
+}
+
+#[inline]
+/// Computes the `p`th percentile of the vector where `p` is within `[0, 100]`,
+/// linearly interpolating between the two nearest ranked elements.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// rank = (p / 100) * (D - 1)
+/// lower = floor(rank)
+/// sorted = sort(x)
+///
+/// return sorted[lower] + (rank - lower) * (sorted[lower + 1] - sorted[lower])
+/// ```
+///
+/// This matches numpy's default `linear` method, values of `p` outside of `[0, 100]`
+/// are clamped. `NaN` values are ordered after every other value.
+///
+/// `scratch` is cleared and re-used as the working buffer, passing the same buffer
+/// between calls avoids re-allocating on each call.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// `x` **MUST** not be empty, otherwise this routine will become
+/// immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_percentile(
+    x: &[f32],
+    p: f32,
+    scratch: &mut Vec<f32>,
+) -> f32 {
+    debug_assert!(!x.is_empty(), "Vector must not be empty");
+
+    let (lower, fraction) = percentile_rank(x.len(), p);
+
+    scratch.clear();
+    scratch.extend_from_slice(x);
+
+    let lower_value = f32_xany_fallback_nofma_select_nth(scratch, lower);
+
+    if fraction == 0.0 {
+        return lower_value;
+    }
+
+    let upper_value = scratch
+        .get_unchecked(lower + 1..)
+        .iter()
+        .copied()
+        .reduce(f32::min)
+        .unwrap_or(lower_value);
+
+    lower_value + fraction * (upper_value - lower_value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_f32_xany_nofma_percentile() {
+        let mut scratch = Vec::new();
+        let x = [5.0, 1.0, 4.0, 2.0, 3.0];
+
+        let res = unsafe { f32_xany_fallback_nofma_percentile(&x, 0.0, &mut scratch) };
+        assert_eq!(res, 1.0);
+        let res = unsafe { f32_xany_fallback_nofma_percentile(&x, 25.0, &mut scratch) };
+        assert_eq!(res, 2.0);
+        let res = unsafe { f32_xany_fallback_nofma_percentile(&x, 90.0, &mut scratch) };
+        assert_eq!(res, 4.6);
+        let res = unsafe { f32_xany_fallback_nofma_median(&x[..4], &mut scratch) };
+        assert_eq!(res, 3.0);
+    }
+}
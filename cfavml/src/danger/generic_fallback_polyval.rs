@@ -0,0 +1,77 @@
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[inline]
+/// Evaluates the polynomial with the given `coeffs` at each element of `x` using
+/// Horner's method, writing the result to `result`.
+///
+/// Coefficients are ordered from the highest degree to the constant term, matching
+/// numpy's `polyval`, an empty set of coefficients evaluates to `0`.
+///
+/// ```py
+/// D: int
+/// N: int
+/// coeffs: [T; N]
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     acc = 0
+///     for j in 0..N:
+///         acc = acc * x[i] + coeffs[j]
+///     result[i] = acc
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors `x` and `result` **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_polyval<T>(
+    coeffs: &[T],
+    x: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        let x = *x.get_unchecked(i);
+
+        let mut acc = AutoMath::zero();
+        for coeff in coeffs {
+            acc = AutoMath::add(AutoMath::mul(acc, x), *coeff);
+        }
+
+        *result.get_unchecked_mut(i) = acc;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close_vector,
+        get_sample_vectors,
+        simple_polyval,
+    };
+
+    #[test]
+    fn test_f32_xany_nofma_polyval() {
+        let (x, _) = get_sample_vectors::<f32>(131);
+        let coeffs = [0.5, -1.25, 0.0, 3.0];
+
+        let mut result = vec![0.0; 131];
+        unsafe { generic_xany_fallback_nofma_polyval(&coeffs, &x, &mut result) };
+        assert_is_close_vector(&result, &simple_polyval(&coeffs, &x));
+    }
+}
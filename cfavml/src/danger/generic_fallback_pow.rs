@@ -0,0 +1,172 @@
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[inline]
+/// Raises each element of the vector to the power of `exp`, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// exp: T
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = x[i] ** exp
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_pow_value<T>(exp: T, x: &[T], result: &mut [T])
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = AutoMath::powf(*x.get_unchecked(i), exp);
+    }
+}
+
+#[inline]
+/// Raises each element of the vector to the power of `exp` in place.
+///
+/// ```py
+/// D: int
+/// exp: T
+/// x: [T; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] ** exp
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_pow_value_inplace<T>(exp: T, x: &mut [T])
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    for v in x.iter_mut() {
+        *v = AutoMath::powf(*v, exp);
+    }
+}
+
+#[inline]
+/// Raises each element of `x` to the power of the matching element of `exp`, writing
+/// the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// exp: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = x[i] ** exp[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_pow_vertical<T>(
+    x: &[T],
+    exp: &[T],
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    debug_assert_eq!(x.len(), exp.len());
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        ![x, exp].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) =
+            AutoMath::powf(*x.get_unchecked(i), *exp.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Raises each element of `x` to the power of the matching element of `exp` in place.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// exp: [T; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] ** exp[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_pow_vertical_inplace<T>(x: &mut [T], exp: &[T])
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    debug_assert_eq!(x.len(), exp.len());
+
+    for i in 0..x.len() {
+        let v = x.get_unchecked_mut(i);
+        *v = AutoMath::powf(*v, *exp.get_unchecked(i));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_pow() {
+        let x = [2.0f32, 9.0, -2.0, 0.0, f32::NAN];
+        let mut result = [0.0; 5];
+
+        unsafe { generic_xany_fallback_nofma_pow_value(3.0, &x, &mut result) };
+        assert_eq!(&result[..4], [8.0, 729.0, -8.0, 0.0]);
+        assert!(result[4].is_nan());
+
+        let exp = [0.5f32, 0.5, 2.0, -1.0, 0.0];
+        unsafe { generic_xany_fallback_nofma_pow_vertical(&x, &exp, &mut result) };
+        assert_eq!(result, [2.0f32.sqrt(), 3.0, 4.0, f32::INFINITY, 1.0]);
+
+        let mut x = x;
+        unsafe { generic_xany_fallback_nofma_pow_vertical_inplace(&mut x, &exp) };
+        assert_eq!(x, result);
+
+        unsafe { generic_xany_fallback_nofma_pow_value_inplace(2.0, &mut x) };
+        assert_eq!(x, [2.0f32.sqrt().powf(2.0), 9.0, 16.0, f32::INFINITY, 1.0]);
+    }
+}
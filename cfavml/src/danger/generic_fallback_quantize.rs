@@ -0,0 +1,88 @@
+use crate::danger::utils::quantize_uniform_f32;
+
+#[inline]
+/// Maps each element of the vector into one of `levels` uniformly sized buckets
+/// spanning `[lo, hi]`, writing the bucket index of each element to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [u32; D]
+///
+/// scale = levels / (hi - lo)
+///
+/// for i in 0..D:
+///     bucket = floor((x[i] - lo) * scale)
+///     result[i] = clamp(bucket, 0, levels - 1)
+/// ```
+///
+/// Values on a bucket boundary belong to the bucket above it, except `hi` which
+/// belongs to the last bucket. Values below `lo` are clamped into the first bucket,
+/// values above `hi` into the last and `NaN` is mapped to the first bucket.
+/// Boundaries are computed in `f32` using the scale above, so values within rounding
+/// error of a boundary may land either side of it.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, `levels` **MUST** be within `1..=2^24` so every
+/// bucket index is exactly representable as an `f32`, and `lo` **MUST** be less than
+/// `hi`, otherwise this routine will become immediately UB due to out of bounds
+/// pointer accesses or produce meaningless indices.
+pub unsafe fn f32_xany_fallback_nofma_quantize_uniform(
+    levels: u32,
+    lo: f32,
+    hi: f32,
+    x: &[f32],
+    result: &mut [u32],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        (1..=1 << 24).contains(&levels),
+        "Levels must be within 1..=2^24"
+    );
+    debug_assert!(lo < hi, "The lower bound must be less than the upper bound");
+
+    let scale = levels as f32 / (hi - lo);
+    let max_bucket = (levels - 1) as f32;
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) =
+            quantize_uniform_f32(*x.get_unchecked(i), lo, scale, max_bucket);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_quantize_uniform() {
+        let x = [
+            0.0,
+            0.25,
+            0.5,
+            0.99,
+            1.0,
+            -5.0,
+            5.0,
+            f32::NAN,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+        ];
+        let mut result = [0; 10];
+        unsafe {
+            f32_xany_fallback_nofma_quantize_uniform(4, 0.0, 1.0, &x, &mut result)
+        };
+        assert_eq!(result, [0, 1, 2, 3, 3, 0, 3, 0, 3, 0]);
+
+        let mut result = [0; 10];
+        unsafe {
+            f32_xany_fallback_nofma_quantize_uniform(1, 0.0, 1.0, &x, &mut result)
+        };
+        assert_eq!(result, [0; 10]);
+    }
+}
@@ -0,0 +1,145 @@
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[inline]
+/// Computes `1 / x` of each element of the vector, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = 1 / x[i]
+/// ```
+///
+/// Unlike the approximate SIMD routines the result is exact.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_recip<T>(x: &[T], result: &mut [T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) =
+            AutoMath::div(AutoMath::one(), *x.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Computes `1 / x` of each element of the vector in place.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_recip_inplace<T>(x: &mut [T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    for v in x.iter_mut() {
+        *v = AutoMath::div(AutoMath::one(), *v);
+    }
+}
+
+#[inline]
+/// Computes `1 / sqrt(x)` of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = 1 / sqrt(x[i])
+/// ```
+///
+/// Unlike the approximate SIMD routines the result is exact.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_rsqrt<T>(x: &[T], result: &mut [T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        let root = AutoMath::sqrt(*x.get_unchecked(i));
+        *result.get_unchecked_mut(i) = AutoMath::div(AutoMath::one(), root);
+    }
+}
+
+#[inline]
+/// Computes `1 / sqrt(x)` of each element of the vector in place.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_rsqrt_inplace<T>(x: &mut [T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    for v in x.iter_mut() {
+        *v = AutoMath::div(AutoMath::one(), AutoMath::sqrt(*v));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_recip_rsqrt() {
+        let x = [4.0f32, 0.25, 0.0, 16.0];
+        let mut result = [0.0; 4];
+        unsafe { generic_xany_fallback_nofma_recip(&x, &mut result) };
+        assert_eq!(result, [0.25, 4.0, f32::INFINITY, 0.0625]);
+
+        unsafe { generic_xany_fallback_nofma_rsqrt(&x, &mut result) };
+        assert_eq!(result, [0.5, 2.0, f32::INFINITY, 0.25]);
+
+        let mut x = [4.0f64, 0.25];
+        unsafe { generic_xany_fallback_nofma_rsqrt_inplace(&mut x) };
+        assert_eq!(x, [0.5, 2.0]);
+        unsafe { generic_xany_fallback_nofma_recip_inplace(&mut x) };
+        assert_eq!(x, [2.0, 0.5]);
+    }
+}
@@ -0,0 +1,109 @@
+#[inline]
+/// Reduces the vector to a single value using a user supplied combine function,
+/// allowing custom reductions which the crate does not provide a routine for.
+///
+/// ```py
+/// D: int
+/// total: T = init
+/// x: [T; D]
+///
+/// for i in 0..D:
+///     total = f(total, x[i])
+/// ```
+///
+/// The vector is processed in blocks of 8 independent lanes which lets the compiler
+/// keep the loads and combines in registers, and vectorize them when `f` is simple
+/// enough. The lanes are then combined with `f` before finally being combined with `init`.
+///
+/// Because elements are spread across lanes, `f` **MUST** be associative and commutative,
+/// e.g. `+`, `*`, `min` and `max`, otherwise the result will differ from the sequential
+/// fold shown above. Note that floating point addition and multiplication are only
+/// approximately associative, so results may differ slightly from a sequential fold.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_reduce<T, F>(x: &[T], init: T, f: F) -> T
+where
+    T: Copy,
+    F: Fn(T, T) -> T,
+{
+    let len = x.len();
+    if len < 8 {
+        return x.iter().fold(init, |acc, v| f(acc, *v));
+    }
+
+    let offset_from = len % 8;
+
+    let mut acc1 = *x.get_unchecked(0);
+    let mut acc2 = *x.get_unchecked(1);
+    let mut acc3 = *x.get_unchecked(2);
+    let mut acc4 = *x.get_unchecked(3);
+    let mut acc5 = *x.get_unchecked(4);
+    let mut acc6 = *x.get_unchecked(5);
+    let mut acc7 = *x.get_unchecked(6);
+    let mut acc8 = *x.get_unchecked(7);
+
+    let mut i = 8;
+    while i < (len - offset_from) {
+        acc1 = f(acc1, *x.get_unchecked(i));
+        acc2 = f(acc2, *x.get_unchecked(i + 1));
+        acc3 = f(acc3, *x.get_unchecked(i + 2));
+        acc4 = f(acc4, *x.get_unchecked(i + 3));
+        acc5 = f(acc5, *x.get_unchecked(i + 4));
+        acc6 = f(acc6, *x.get_unchecked(i + 5));
+        acc7 = f(acc7, *x.get_unchecked(i + 6));
+        acc8 = f(acc8, *x.get_unchecked(i + 7));
+
+        i += 8;
+    }
+
+    while i < len {
+        acc1 = f(acc1, *x.get_unchecked(i));
+        i += 1;
+    }
+
+    acc1 = f(acc1, acc2);
+    acc3 = f(acc3, acc4);
+    acc5 = f(acc5, acc6);
+    acc7 = f(acc7, acc8);
+
+    acc1 = f(acc1, acc3);
+    acc5 = f(acc5, acc7);
+
+    f(init, f(acc1, acc5))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    #[test]
+    fn test_f32_xany_nofma_reduce() {
+        for len in [0, 3, 8, 9, 531] {
+            let (x, _) = get_sample_vectors::<f32>(len);
+
+            let sum =
+                unsafe { generic_xany_fallback_nofma_reduce(&x, 1.0, |a, b| a + b) };
+            assert_is_close(sum, 1.0 + x.iter().sum::<f32>());
+
+            let max =
+                unsafe { generic_xany_fallback_nofma_reduce(&x, f32::MIN, f32::max) };
+            assert_eq!(max, x.iter().copied().fold(f32::MIN, f32::max));
+        }
+    }
+
+    #[test]
+    fn test_i64_xany_nofma_reduce() {
+        let (x, _) = get_sample_vectors::<i64>(531);
+
+        let xor = unsafe { generic_xany_fallback_nofma_reduce(&x, 0, |a, b| a ^ b) };
+        assert_eq!(xor, x.iter().fold(0, |a, b| a ^ b));
+    }
+}
@@ -0,0 +1,88 @@
+use crate::danger::partially_overlaps;
+
+#[inline]
+/// Applies the ReLU activation to the vector, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = max(x[i], 0)
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_relu(x: &[f32], result: &mut [f32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = relu(*x.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Applies the ReLU activation to the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = max(x[i], 0)
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn f32_xany_fallback_nofma_relu_inplace(x: &mut [f32]) {
+    for v in x.iter_mut() {
+        *v = relu(*v);
+    }
+}
+
+#[inline(always)]
+/// Matches `maxps` with `x` as the second operand, so `NaN` is propagated.
+fn relu(v: f32) -> f32 {
+    if 0.0 > v {
+        0.0
+    } else {
+        v
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_relu() {
+        let x = [-2.0, -0.5, 0.0, 0.5, 2.0];
+
+        let mut result = [1.0; 5];
+        unsafe { f32_xany_fallback_nofma_relu(&x, &mut result) };
+        assert_eq!(result, [0.0, 0.0, 0.0, 0.5, 2.0]);
+        assert!(result[0].is_sign_positive());
+
+        let mut x = [f32::NAN, -1.0];
+        unsafe { f32_xany_fallback_nofma_relu_inplace(&mut x) };
+        assert!(x[0].is_nan());
+        assert_eq!(x[1], 0.0);
+    }
+}
@@ -0,0 +1,68 @@
+use crate::danger::overlaps;
+
+#[inline]
+/// Reverses the order of the elements in `x`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = x[D - i - 1]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// `result` **MUST NOT** overlap `x`, as elements of `x` would be read after being
+/// overwritten, use the `_inplace` variant to operate on a single buffer.
+pub unsafe fn generic_xany_fallback_nofma_reverse<T: Copy>(x: &[T], result: &mut [T]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(!overlaps(x, result), "`result` must not overlap `x`");
+
+    let len = x.len();
+    for i in 0..len {
+        *result.get_unchecked_mut(len - i - 1) = *x.get_unchecked(i);
+    }
+}
+
+#[inline]
+/// Reverses the order of the elements in `x` in place.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_reverse_inplace<T>(x: &mut [T]) {
+    x.reverse()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_f32_xany_nofma_reverse() {
+        let (mut x, _) = get_sample_vectors::<f32>(557);
+        let expected = x.iter().copied().rev().collect::<Vec<_>>();
+
+        let mut result = vec![0.0; 557];
+        unsafe { generic_xany_fallback_nofma_reverse(&x, &mut result) };
+        assert_eq!(result, expected);
+
+        unsafe { generic_xany_fallback_nofma_reverse_inplace(&mut x) };
+        assert_eq!(x, expected);
+    }
+}
@@ -0,0 +1,173 @@
+use core::ptr;
+
+use crate::danger::overlaps;
+
+#[inline]
+/// Cyclically rotates the elements of `x` by `shift` positions, writing the result
+/// to `result`.
+///
+/// A positive `shift` moves elements towards the end of the vector (to the right),
+/// a negative `shift` moves elements towards the start of the vector (to the left),
+/// this matches the behaviour of `np.roll`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[(i + shift) % D] = x[i]
+/// ```
+///
+/// The rotation is performed as two bulk copies, which the compiler lowers to
+/// vectorized `memcpy` calls, so no architecture specific routines are provided.
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// `result` **MUST NOT** overlap `x`, use [generic_xany_fallback_nofma_rotate_inplace]
+/// to operate on a single buffer.
+pub unsafe fn generic_xany_fallback_nofma_rotate<T: Copy>(
+    shift: isize,
+    x: &[T],
+    result: &mut [T],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(!overlaps(x, result), "`result` must not overlap `x`");
+
+    let len = x.len();
+    if len == 0 {
+        return;
+    }
+
+    let k = shift.rem_euclid(len as isize) as usize;
+    let x_ptr = x.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    ptr::copy_nonoverlapping(x_ptr, result_ptr.add(k), len - k);
+    ptr::copy_nonoverlapping(x_ptr.add(len - k), result_ptr, k);
+}
+
+#[inline]
+/// Cyclically rotates the elements of `x` by `shift` positions in place, following
+/// the same sign convention as [generic_xany_fallback_nofma_rotate].
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_rotate_inplace<T>(shift: isize, x: &mut [T]) {
+    if x.is_empty() {
+        return;
+    }
+
+    let k = shift.rem_euclid(x.len() as isize) as usize;
+    x.rotate_right(k);
+}
+
+#[inline]
+/// Shifts the elements of `x` by `shift` positions without wrapping, filling the
+/// vacated positions with `pad` and writing the result to `result`.
+///
+/// The sign convention matches [generic_xany_fallback_nofma_rotate], a positive
+/// `shift` moves elements to the right, a negative `shift` moves elements to the left.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     j = i - shift
+///     result[i] = x[j] if 0 <= j < D else pad
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be equal length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// `result` **MUST NOT** overlap `x`.
+pub unsafe fn generic_xany_fallback_nofma_shift_elements<T: Copy>(
+    shift: isize,
+    pad: T,
+    x: &[T],
+    result: &mut [T],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(!overlaps(x, result), "`result` must not overlap `x`");
+
+    let len = x.len();
+    let k = shift.unsigned_abs().min(len);
+    let x_ptr = x.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    if shift >= 0 {
+        result.get_unchecked_mut(..k).fill(pad);
+        ptr::copy_nonoverlapping(x_ptr, result_ptr.add(k), len - k);
+    } else {
+        ptr::copy_nonoverlapping(x_ptr.add(k), result_ptr, len - k);
+        result.get_unchecked_mut(len - k..).fill(pad);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_f32_xany_nofma_rotate() {
+        let (x, _) = get_sample_vectors::<f32>(557);
+
+        for shift in [0isize, 1, 3, -3, 556, 557, 1200, -1200] {
+            let mut expected = x.clone();
+            let k = shift.rem_euclid(557) as usize;
+            expected.rotate_right(k);
+
+            let mut result = vec![0.0; 557];
+            unsafe { generic_xany_fallback_nofma_rotate(shift, &x, &mut result) };
+            assert_eq!(result, expected, "shift: {shift}");
+        }
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_rotate_inplace() {
+        let (x, _) = get_sample_vectors::<f32>(557);
+
+        for shift in [0isize, 3, -3, 1200, -1200] {
+            let mut expected = vec![0.0; 557];
+            unsafe { generic_xany_fallback_nofma_rotate(shift, &x, &mut expected) };
+
+            let mut result = x.clone();
+            unsafe { generic_xany_fallback_nofma_rotate_inplace(shift, &mut result) };
+            assert_eq!(result, expected, "shift: {shift}");
+        }
+
+        unsafe { generic_xany_fallback_nofma_rotate_inplace::<f32>(3, &mut []) };
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_shift_elements() {
+        let x = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let mut result = [0.0; 5];
+
+        unsafe { generic_xany_fallback_nofma_shift_elements(2, -1.0, &x, &mut result) };
+        assert_eq!(result, [-1.0, -1.0, 1.0, 2.0, 3.0]);
+
+        unsafe { generic_xany_fallback_nofma_shift_elements(-2, -1.0, &x, &mut result) };
+        assert_eq!(result, [3.0, 4.0, 5.0, -1.0, -1.0]);
+
+        unsafe { generic_xany_fallback_nofma_shift_elements(7, -1.0, &x, &mut result) };
+        assert_eq!(result, [-1.0; 5]);
+
+        unsafe { generic_xany_fallback_nofma_shift_elements(0, -1.0, &x, &mut result) };
+        assert_eq!(result, x);
+    }
+}
@@ -0,0 +1,170 @@
+use crate::danger::RoundingMode;
+
+#[inline]
+/// Rounds each element of `x` to an integer in the given [RoundingMode], writing
+/// the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+/// result: [f32; D]
+///
+/// for i in 0..D:
+///     result[i] = round(x[i], mode)
+/// ```
+///
+/// `NaN` and infinite values are returned unchanged. The mode is applied exactly with
+/// [RoundingMode::round], so the output matches the SIMD routines bit for bit.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_round(
+    mode: RoundingMode,
+    x: &[f32],
+    result: &mut [f32],
+) {
+    debug_assert_eq!(x.len(), result.len());
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = mode.round(*x.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Rounds each element of `x` to an integer in the given [RoundingMode] in place.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// for i in 0..D:
+///     x[i] = round(x[i], mode)
+/// ```
+///
+/// `NaN` and infinite values are returned unchanged.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn f32_xany_fallback_nofma_round_inplace(mode: RoundingMode, x: &mut [f32]) {
+    for v in x.iter_mut() {
+        *v = mode.round(*v);
+    }
+}
+
+#[inline]
+/// Rounds each element of `x` to an integer in the given [RoundingMode], writing
+/// the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+/// result: [f64; D]
+///
+/// for i in 0..D:
+///     result[i] = round(x[i], mode)
+/// ```
+///
+/// `NaN` and infinite values are returned unchanged. The mode is applied exactly with
+/// [RoundingMode::round_f64], so the output matches the SIMD routines bit for bit.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f64_xany_fallback_nofma_round(
+    mode: RoundingMode,
+    x: &[f64],
+    result: &mut [f64],
+) {
+    debug_assert_eq!(x.len(), result.len());
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = mode.round_f64(*x.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Rounds each element of `x` to an integer in the given [RoundingMode] in place.
+///
+/// ```py
+/// D: int
+/// x: [f64; D]
+///
+/// for i in 0..D:
+///     x[i] = round(x[i], mode)
+/// ```
+///
+/// `NaN` and infinite values are returned unchanged.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn f64_xany_fallback_nofma_round_inplace(mode: RoundingMode, x: &mut [f64]) {
+    for v in x.iter_mut() {
+        *v = mode.round_f64(*v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_round() {
+        let x = [-1.5f32, -0.2, 0.5, 2.5, f32::INFINITY];
+        let mut result = [0.0; 5];
+
+        unsafe {
+            f32_xany_fallback_nofma_round(RoundingMode::NearestEven, &x, &mut result)
+        };
+        assert_eq!(result, [-2.0, -0.0, 0.0, 2.0, f32::INFINITY]);
+
+        unsafe {
+            f32_xany_fallback_nofma_round(RoundingMode::TowardPositive, &x, &mut result)
+        };
+        assert_eq!(result, [-1.0, -0.0, 1.0, 3.0, f32::INFINITY]);
+    }
+
+    #[test]
+    fn test_xany_nofma_round_inplace() {
+        let mut x = [-1.5f32, -0.2, 0.5, 2.5, f32::NAN];
+        unsafe {
+            f32_xany_fallback_nofma_round_inplace(RoundingMode::TowardZero, &mut x)
+        };
+        assert_eq!(&x[..4], [-1.0, -0.0, 0.0, 2.0]);
+        assert!(x[4].is_nan());
+
+        let mut x = [-1.5f64, -0.2, 0.5, 2.5, f64::INFINITY];
+        let mut result = [0.0; 5];
+        unsafe {
+            f64_xany_fallback_nofma_round(RoundingMode::TowardNegative, &x, &mut result)
+        };
+        assert_eq!(result, [-2.0, -1.0, 0.0, 2.0, f64::INFINITY]);
+
+        unsafe {
+            f64_xany_fallback_nofma_round_inplace(RoundingMode::NearestEven, &mut x)
+        };
+        assert_eq!(x, [-2.0, -0.0, 0.0, 2.0, f64::INFINITY]);
+    }
+}
@@ -0,0 +1,45 @@
+#[inline]
+/// Sums all elements of the vector, saturating at `u32::MAX` rather than
+/// overflowing.
+///
+/// ```py
+/// D: int
+/// total: u32
+/// x: [u32; D]
+///
+/// for i in 0..D:
+///     total = min(total + x[i], u32.MAX)
+///
+/// return total
+/// ```
+///
+/// See [u32_xany_avx2_nofma_saturating_sum](crate::danger::u32_xany_avx2_nofma_saturating_sum)
+/// for how this differs from the widening and checked integer sums.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn u32_xany_fallback_nofma_saturating_sum(x: &[u32]) -> u32 {
+    x.iter().fold(0, |total: u32, v| total.saturating_add(*v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_u32_xany_nofma_saturating_sum() {
+        let x = [1, 2, 3, 4];
+        let sum = unsafe { u32_xany_fallback_nofma_saturating_sum(&x) };
+        assert_eq!(sum, 10);
+
+        let x = [u32::MAX - 1, 1, 1, 7];
+        let sum = unsafe { u32_xany_fallback_nofma_saturating_sum(&x) };
+        assert_eq!(sum, u32::MAX);
+    }
+}
@@ -0,0 +1,72 @@
+use crate::math::*;
+
+#[inline]
+/// Sums the elements of `values` into the buckets of `result` selected by the
+/// matching element of `segment_ids`, equivalent to TensorFlow's
+/// `unsorted_segment_sum`.
+///
+/// ```py
+/// D: int
+/// values: [T; D]
+/// segment_ids: [u32; D]
+/// result: [T; num_segments]
+///
+/// for i in 0..D:
+///     result[segment_ids[i]] += values[i]
+/// ```
+///
+/// The totals are added to the existing values of `result`, so it must be zeroed
+/// beforehand to compute a plain segment sum. Segment ids do not need to be sorted.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// `values` and `segment_ids` **MUST** be the same length, `result` **MUST** be
+/// `num_segments` long and every segment id **MUST** be less than `num_segments`,
+/// otherwise this routine will become immediately UB due to out of bounds pointer
+/// accesses.
+pub unsafe fn generic_xany_fallback_nofma_segment_sum<T>(
+    values: &[T],
+    segment_ids: &[u32],
+    num_segments: usize,
+    result: &mut [T],
+) where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(values.len(), segment_ids.len());
+    debug_assert_eq!(result.len(), num_segments);
+    debug_assert!(
+        segment_ids.iter().all(|&id| (id as usize) < num_segments),
+        "Segment ids must be less than `num_segments`"
+    );
+
+    for i in 0..values.len() {
+        let bucket = result.get_unchecked_mut(*segment_ids.get_unchecked(i) as usize);
+        *bucket = AutoMath::add(*bucket, *values.get_unchecked(i));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_segment_sum() {
+        let values = [1.0f64, 2.0, 3.0, 4.0, 5.0, 6.0];
+        let segment_ids = [3, 0, 3, 1, 0, 3];
+        let mut result = [0.0; 4];
+        unsafe {
+            generic_xany_fallback_nofma_segment_sum(
+                &values,
+                &segment_ids,
+                4,
+                &mut result,
+            )
+        };
+        assert_eq!(result, [7.0, 4.0, 0.0, 10.0]);
+    }
+}
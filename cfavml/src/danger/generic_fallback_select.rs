@@ -0,0 +1,54 @@
+#[inline]
+/// Re-orders `x` such that the element at index `n` is the element which would be
+/// at that position if `x` were sorted, returning that element.
+///
+/// All elements before `n` are less than or equal to it and all elements after `n`
+/// are greater than or equal to it, this matches the behaviour of `select_nth_unstable`.
+///
+/// ### NaN ordering
+///
+/// `NaN` values are ordered after every other value, including `inf`, so they are
+/// only ever selected when `n` is greater than or equal to the number of non-`NaN`
+/// elements. `-0.0` and `0.0` are treated as equal.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// `n` **MUST** be less than the length of `x`, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_select_nth(x: &mut [f32], n: usize) -> f32 {
+    debug_assert!(n < x.len(), "n must be within the bounds of the vector");
+
+    let (_, nth, _) = x.select_nth_unstable_by(n, |a, b| {
+        a.partial_cmp(b)
+            .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+    });
+    *nth
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_f32_xany_nofma_select_nth() {
+        let (mut x, _) = get_sample_vectors::<f32>(1031);
+        x[10] = f32::NAN;
+
+        let mut sorted = x.clone();
+        sorted.sort_by(|a, b| {
+            a.partial_cmp(b)
+                .unwrap_or_else(|| a.is_nan().cmp(&b.is_nan()))
+        });
+
+        let value = unsafe { f32_xany_fallback_nofma_select_nth(&mut x, 515) };
+        assert_eq!(value, sorted[515]);
+
+        let value = unsafe { f32_xany_fallback_nofma_select_nth(&mut x, 1030) };
+        assert!(value.is_nan());
+    }
+}
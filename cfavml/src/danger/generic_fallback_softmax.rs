@@ -0,0 +1,100 @@
+use crate::danger::{
+    generic_xany_fallback_nofma_div_value,
+    generic_xany_fallback_nofma_max_horizontal,
+    partially_overlaps,
+};
+use crate::math::*;
+
+#[inline]
+/// Computes the softmax of the vector, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// m = max(x)
+/// for i in 0..D:
+///     result[i] = exp(x[i] - m)
+///
+/// total = sum(result)
+/// for i in 0..D:
+///     result[i] = result[i] / total
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_softmax<T>(x: &[T], result: &mut [T])
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    let max = generic_xany_fallback_nofma_max_horizontal(x);
+
+    let mut total = AutoMath::zero();
+    for i in 0..x.len() {
+        let e = AutoMath::exp(AutoMath::sub(*x.get_unchecked(i), max));
+        *result.get_unchecked_mut(i) = e;
+        total = AutoMath::add(total, e);
+    }
+
+    generic_xany_fallback_nofma_div_value(result, total);
+}
+
+#[inline]
+/// Computes the softmax of the vector in place.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_softmax_inplace<T>(x: &mut [T])
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    let max = generic_xany_fallback_nofma_max_horizontal(x);
+
+    let mut total = AutoMath::zero();
+    for v in x.iter_mut() {
+        *v = AutoMath::exp(AutoMath::sub(*v, max));
+        total = AutoMath::add(total, *v);
+    }
+
+    generic_xany_fallback_nofma_div_value(x, total);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_softmax() {
+        let x = [1.0f32, 1.0, 1.0, 1.0];
+        let mut result = [0.0; 4];
+        unsafe { generic_xany_fallback_nofma_softmax(&x, &mut result) };
+        assert_eq!(result, [0.25; 4]);
+
+        let mut x = [1000.0f64, 1000.0 + 2f64.ln(), -1000.0];
+        unsafe { generic_xany_fallback_nofma_softmax_inplace(&mut x) };
+        assert!((x[0] - 1.0 / 3.0).abs() < 1e-12);
+        assert!((x[1] - 2.0 / 3.0).abs() < 1e-12);
+        assert_eq!(x[2], 0.0);
+    }
+}
@@ -0,0 +1,160 @@
+use core::cmp::Ordering;
+
+use crate::math::*;
+
+#[inline]
+/// Computes the dot product of the sparse vector described by `x_idx` and `x_val`
+/// with the dense vector `y`.
+///
+/// ```py
+/// N: int
+/// total: T
+/// x_idx: [u32; N]
+/// x_val: [T; N]
+/// y: [T; D]
+///
+/// for i in 0..N:
+///     total = total + x_val[i] * y[x_idx[i]]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// `x_idx` and `x_val` **MUST** be the same length, and every index in `x_idx`
+/// **MUST** be less than `y.len()`, otherwise this routine will become immediately
+/// UB due to out of bounds pointer accesses. Indices are only validated in debug
+/// builds.
+pub unsafe fn generic_xany_fallback_nofma_sparse_dot<T>(
+    x_idx: &[u32],
+    x_val: &[T],
+    y: &[T],
+) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x_idx.len(), x_val.len());
+    debug_assert!(
+        x_idx.iter().all(|&idx| (idx as usize) < y.len()),
+        "Sparse index out of bounds"
+    );
+
+    let mut total = AutoMath::zero();
+    for i in 0..x_idx.len() {
+        let idx = *x_idx.get_unchecked(i) as usize;
+        total = AutoMath::add(
+            total,
+            AutoMath::mul(*x_val.get_unchecked(i), *y.get_unchecked(idx)),
+        );
+    }
+
+    total
+}
+
+#[inline]
+/// Computes the dot product of two sparse vectors, each described by a list of
+/// indices and their corresponding values.
+///
+/// ```py
+/// total: T
+/// x: {u32: T}
+/// y: {u32: T}
+///
+/// for idx in x.keys() & y.keys():
+///     total = total + x[idx] * y[idx]
+/// ```
+///
+/// Both index lists are walked in a single merge pass, only the indices present in
+/// both vectors contribute to the result. The access pattern is entirely data
+/// dependent, so no architecture specific routines are provided.
+///
+/// # Safety
+///
+/// `x_idx` and `x_val`, as well as `y_idx` and `y_val`, **MUST** be the same length,
+/// otherwise this routine will become immediately UB due to out of bounds pointer
+/// accesses.
+///
+/// Both index lists must be sorted in strictly increasing order, otherwise the result
+/// is unspecified.
+pub unsafe fn generic_xany_fallback_nofma_sparse_sparse_dot<T>(
+    x_idx: &[u32],
+    x_val: &[T],
+    y_idx: &[u32],
+    y_val: &[T],
+) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x_idx.len(), x_val.len());
+    debug_assert_eq!(y_idx.len(), y_val.len());
+    debug_assert!(
+        x_idx.windows(2).all(|w| w[0] < w[1]),
+        "`x_idx` must be sorted"
+    );
+    debug_assert!(
+        y_idx.windows(2).all(|w| w[0] < w[1]),
+        "`y_idx` must be sorted"
+    );
+
+    let mut total = AutoMath::zero();
+
+    let mut i = 0;
+    let mut j = 0;
+    while i < x_idx.len() && j < y_idx.len() {
+        match x_idx.get_unchecked(i).cmp(y_idx.get_unchecked(j)) {
+            Ordering::Less => i += 1,
+            Ordering::Greater => j += 1,
+            Ordering::Equal => {
+                total = AutoMath::add(
+                    total,
+                    AutoMath::mul(*x_val.get_unchecked(i), *y_val.get_unchecked(j)),
+                );
+
+                i += 1;
+                j += 1;
+            },
+        }
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_sparse_dot() {
+        let y = [1.0, 2.0, 3.0, 4.0, 5.0];
+
+        let dot = unsafe {
+            generic_xany_fallback_nofma_sparse_dot(&[4, 0, 2], &[1.0, 2.0, 3.0], &y)
+        };
+        assert_eq!(dot, 5.0 + 2.0 + 9.0);
+
+        let dot = unsafe { generic_xany_fallback_nofma_sparse_dot::<f32>(&[], &[], &y) };
+        assert_eq!(dot, 0.0);
+    }
+
+    #[test]
+    fn test_xany_nofma_sparse_sparse_dot() {
+        let x_idx = [1, 3, 4, 9, 12];
+        let x_val = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let y_idx = [0, 3, 9, 10, 12, 40];
+        let y_val = [7.0, 2.0, 0.5, 8.0, -1.0, 3.0];
+
+        let dot = unsafe {
+            generic_xany_fallback_nofma_sparse_sparse_dot(&x_idx, &x_val, &y_idx, &y_val)
+        };
+        assert_eq!(dot, 2.0 * 2.0 + 4.0 * 0.5 - 5.0);
+
+        let dot = unsafe {
+            generic_xany_fallback_nofma_sparse_sparse_dot(&x_idx, &x_val, &[], &[])
+        };
+        assert_eq!(dot, 0.0);
+    }
+}
@@ -0,0 +1,86 @@
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[inline]
+/// Computes the square root of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = sqrt(x[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_sqrt<T>(x: &[T], result: &mut [T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = AutoMath::sqrt(*x.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Computes the square root of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+///
+/// for i in 0..D:
+///     x[i] = sqrt(x[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_sqrt_inplace<T>(x: &mut [T])
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    for v in x.iter_mut() {
+        *v = AutoMath::sqrt(*v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_sqrt() {
+        let x = [4.0f32, 0.0, 2.25, 1e6];
+        let mut result = [0.0; 4];
+        unsafe { generic_xany_fallback_nofma_sqrt(&x, &mut result) };
+        assert_eq!(result, [2.0, 0.0, 1.5, 1e3]);
+
+        let mut x = [9.0f64, -1.0];
+        unsafe { generic_xany_fallback_nofma_sqrt_inplace(&mut x) };
+        assert_eq!(x[0], 3.0);
+        assert!(x[1].is_nan());
+    }
+}
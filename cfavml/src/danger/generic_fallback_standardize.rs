@@ -0,0 +1,66 @@
+use crate::danger::{generic_xany_fallback_nofma_sum_sumsq, mean_std_from_sums_f32};
+
+#[inline]
+/// Standardizes the vector in place to zero mean and unit variance, returning the
+/// `(mean, std)` that were removed so the transform can be inverted with
+/// `x * std + mean`.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// mean = sum(x) / D
+/// std = sqrt(sum(x * x) / D - mean ** 2)
+///
+/// for i in 0..D:
+///     if std == 0:
+///         x[i] = x[i] - mean
+///     else:
+///         x[i] = (x[i] - mean) / std
+/// ```
+///
+/// When the standard deviation is zero the vector is only centered and `std` is
+/// returned as `0.0`, an empty vector is left untouched and returns `(0.0, 0.0)`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn f32_xany_fallback_nofma_standardize(x: &mut [f32]) -> (f32, f32) {
+    let (sum, sum_sq) = generic_xany_fallback_nofma_sum_sumsq(x);
+    let (mean, std) = mean_std_from_sums_f32(sum, sum_sq, x.len());
+    let divisor = if std == 0.0 { 1.0 } else { std };
+
+    for v in x.iter_mut() {
+        *v = (*v - mean) / divisor;
+    }
+
+    (mean, std)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_standardize() {
+        let mut x = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+
+        let (mean, std) = unsafe { f32_xany_fallback_nofma_standardize(&mut x) };
+        assert_eq!((mean, std), (5.0, 2.0));
+        assert_eq!(x, [-1.5, -0.5, -0.5, -0.5, 0.0, 0.0, 1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_xany_nofma_standardize_zero_variance() {
+        let mut x = [-1.25; 3];
+
+        let (mean, std) = unsafe { f32_xany_fallback_nofma_standardize(&mut x) };
+        assert_eq!((mean, std), (-1.25, 0.0));
+        assert_eq!(x, [0.0; 3]);
+    }
+}
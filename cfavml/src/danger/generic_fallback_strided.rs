@@ -0,0 +1,156 @@
+#[inline]
+/// Sums every `stride`th element of the vector starting at `start_offset`, this is
+/// intended for interleaved (AoS) data such as `[x, y, z, x, y, z, ...]` where a single
+/// component needs to be reduced without de-interleaving the data first.
+///
+/// ```py
+/// total: f32
+/// x: [f32; D]
+///
+/// for i in 0..count:
+///     total = total + x[start_offset + i * stride]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// `stride` **MUST** be non-zero and `start_offset + (count - 1) * stride` **MUST**
+/// be within the bounds of `x`, otherwise this routine will become immediately UB
+/// due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_strided_sum(
+    x: &[f32],
+    start_offset: usize,
+    stride: usize,
+    count: usize,
+) -> f32 {
+    debug_assert!(stride > 0, "Stride must be non-zero");
+    debug_assert!(count == 0 || start_offset + (count - 1) * stride < x.len());
+
+    let x = x.as_ptr().add(start_offset);
+
+    let mut acc1 = 0.0;
+    let mut acc2 = 0.0;
+    let mut acc3 = 0.0;
+    let mut acc4 = 0.0;
+
+    let offset_from = count % 4;
+    let mut i = 0;
+    while i < (count - offset_from) {
+        acc1 += *x.add(i * stride);
+        acc2 += *x.add((i + 1) * stride);
+        acc3 += *x.add((i + 2) * stride);
+        acc4 += *x.add((i + 3) * stride);
+
+        i += 4;
+    }
+
+    while i < count {
+        acc1 += *x.add(i * stride);
+        i += 1;
+    }
+
+    (acc1 + acc2) + (acc3 + acc4)
+}
+
+#[inline]
+/// Computes the dot product of every `stride`th element of two interleaved (AoS)
+/// vectors sharing the same layout, starting at `start_offset`.
+///
+/// ```py
+/// total: f32
+/// x: [f32; D]
+/// y: [f32; D]
+///
+/// for i in 0..count:
+///     j = start_offset + i * stride
+///     total = total + (x[j] * y[j])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, `stride` **MUST** be non-zero and
+/// `start_offset + (count - 1) * stride` **MUST** be within the bounds of the vectors,
+/// otherwise this routine will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn f32_xany_fallback_nofma_strided_dot(
+    x: &[f32],
+    y: &[f32],
+    start_offset: usize,
+    stride: usize,
+    count: usize,
+) -> f32 {
+    debug_assert_eq!(x.len(), y.len());
+    debug_assert!(stride > 0, "Stride must be non-zero");
+    debug_assert!(count == 0 || start_offset + (count - 1) * stride < x.len());
+
+    let x = x.as_ptr().add(start_offset);
+    let y = y.as_ptr().add(start_offset);
+
+    let mut acc1 = 0.0;
+    let mut acc2 = 0.0;
+    let mut acc3 = 0.0;
+    let mut acc4 = 0.0;
+
+    let offset_from = count % 4;
+    let mut i = 0;
+    while i < (count - offset_from) {
+        acc1 += *x.add(i * stride) * *y.add(i * stride);
+        acc2 += *x.add((i + 1) * stride) * *y.add((i + 1) * stride);
+        acc3 += *x.add((i + 2) * stride) * *y.add((i + 2) * stride);
+        acc4 += *x.add((i + 3) * stride) * *y.add((i + 3) * stride);
+
+        i += 4;
+    }
+
+    while i < count {
+        acc1 += *x.add(i * stride) * *y.add(i * stride);
+        i += 1;
+    }
+
+    (acc1 + acc2) + (acc3 + acc4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    #[test]
+    fn test_f32_xany_nofma_strided_sum() {
+        for stride in [1, 3, 4, 5] {
+            for count in [0, 1, 7, 333] {
+                let len = 1 + count * stride;
+                let (x, _) = get_sample_vectors(len);
+
+                let expected: f32 = (0..count).map(|i| x[1 + i * stride]).sum();
+                let res =
+                    unsafe { f32_xany_fallback_nofma_strided_sum(&x, 1, stride, count) };
+                assert_is_close(res, expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_strided_dot() {
+        for stride in [1, 3, 4, 5] {
+            for count in [0, 1, 7, 333] {
+                let len = 1 + count * stride;
+                let (x, y) = get_sample_vectors(len);
+
+                let expected: f32 = (0..count)
+                    .map(|i| x[1 + i * stride] * y[1 + i * stride])
+                    .sum();
+                let res = unsafe {
+                    f32_xany_fallback_nofma_strided_dot(&x, &y, 1, stride, count)
+                };
+                assert_is_close(res, expected);
+            }
+        }
+    }
+}
@@ -1,5 +1,19 @@
+use crate::danger::utils::sum_streaming_with;
 use crate::math::*;
 
+/// The length above which the `sum_horizontal` routines switch from plain accumulation
+/// to pairwise summation.
+///
+/// With plain accumulation the rounding error grows linearly with the length of the
+/// vector, by `2^16` elements the expected relative error of an `f32` sum is in the
+/// order of `1e-4`. Pairwise summation grows the error logarithmically instead, at a
+/// cost of a few percent throughput, which is why it is not used for shorter vectors.
+pub const PAIRWISE_SUM_THRESHOLD: usize = 1 << 16;
+
+/// The block size at which pairwise summation stops halving and falls back to plain
+/// accumulation.
+pub(crate) const PAIRWISE_SUM_BLOCK: usize = 1 << 12;
+
 #[inline]
 /// Sums all elements of the vector.
 ///
@@ -12,6 +26,11 @@ use crate::math::*;
 ///     total = total + x[i]
 /// ```
 ///
+/// Vectors longer than [PAIRWISE_SUM_THRESHOLD] are summed with
+/// [generic_xany_fallback_nofma_sum_pairwise] to bound the accumulated rounding error,
+/// shorter vectors use the plain accumulation which is the fastest option and accurate
+/// enough at those lengths.
+///
 /// # Safety
 ///
 /// This method in theory is safe, but like the rest of the dangerous API, makes
@@ -21,7 +40,49 @@ where
     T: Copy,
     AutoMath: Math<T>,
 {
-    sum::<T, AutoMath>(x)
+    if x.len() > PAIRWISE_SUM_THRESHOLD {
+        generic_xany_fallback_nofma_sum_pairwise(x)
+    } else {
+        sum::<T, AutoMath>(x)
+    }
+}
+
+#[inline]
+/// Sums all elements of the vector using pairwise summation.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+///
+/// def pairwise(x):
+///     if len(x) <= PAIRWISE_SUM_BLOCK:
+///         return sum(x)
+///     return pairwise(x[:len(x) // 2]) + pairwise(x[len(x) // 2:])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_sum_pairwise<T>(x: &[T]) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    if x.len() <= PAIRWISE_SUM_BLOCK {
+        return sum::<T, AutoMath>(x);
+    }
+
+    let mid = (x.len() / 2) & !127;
+    let (left, right) = x.split_at(mid);
+    AutoMath::add(
+        generic_xany_fallback_nofma_sum_pairwise(left),
+        generic_xany_fallback_nofma_sum_pairwise(right),
+    )
 }
 
 #[allow(unused)]
@@ -182,6 +243,109 @@ where
     results
 }
 
+#[inline]
+/// Sums all elements of the vector and multiplies the total by `scale`.
+///
+/// ```py
+/// D: int
+/// total: T
+/// x: [T; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return scale * total
+/// ```
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_scaled_sum<T>(scale: T, x: &[T]) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    AutoMath::mul(scale, sum::<T, AutoMath>(x))
+}
+
+#[inline]
+/// Sums all elements of a vector split into multiple chunks, such as a segmented
+/// buffer, without copying them into a contiguous vector first.
+///
+/// Each chunk is summed with the contiguous routine, so chunks do not need to be
+/// aligned to the register width.
+///
+/// ```py
+/// total: T
+/// chunks: [[T; N]; C]
+///
+/// for chunk in chunks:
+///     for i in 0..N:
+///         total = total + chunk[i]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_sum_chunks<T>(chunks: &[&[T]]) -> T
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    let mut total = AutoMath::zero();
+    for chunk in chunks {
+        total = AutoMath::add(total, sum::<T, AutoMath>(chunk));
+    }
+    total
+}
+
+#[inline]
+/// Sums a stream of `f32` values which are produced in chunks by `reader`, i.e.
+/// memory-mapped or network streamed data which does not fit in memory at once.
+///
+/// ```py
+/// total: f32
+/// buffer: [f32; chunk_size]
+///
+/// while (n := reader(buffer)) > 0:
+///     total = total + sum(buffer[:n])
+/// ```
+///
+/// `reader` fills the start of a reusable buffer of `chunk_size` elements and returns
+/// the number of elements written, returning `0` ends the stream. Each chunk is summed
+/// with the contiguous routine, the chunk totals are then combined with compensated
+/// summation so the result stays stable however many chunks are read.
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is `0` or `reader` returns a count greater than `chunk_size`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn f32_xany_fallback_nofma_sum_streaming<R>(
+    chunk_size: usize,
+    reader: R,
+) -> f32
+where
+    R: FnMut(&mut [f32]) -> usize,
+{
+    sum_streaming_with(chunk_size, reader, |chunk| {
+        generic_xany_fallback_nofma_sum_horizontal(chunk)
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -194,6 +358,18 @@ mod tests {
         assert_is_close(sum, x.iter().sum::<f32>());
     }
 
+    #[test]
+    fn test_xany_nofma_sum_pairwise() {
+        let (x, _) = get_sample_vectors::<f32>(PAIRWISE_SUM_THRESHOLD + 4133);
+        let expected = x.iter().map(|v| *v as f64).sum::<f64>();
+
+        let pairwise = unsafe { generic_xany_fallback_nofma_sum_pairwise(&x) };
+        assert!(((pairwise as f64 - expected) / expected).abs() < 1e-6);
+
+        let sum = unsafe { generic_xany_fallback_nofma_sum_horizontal(&x) };
+        assert_eq!(sum, pairwise);
+    }
+
     #[test]
     fn test_xany_nofma_sum_vertical() {
         let mut matrix = Vec::new();
@@ -216,4 +392,45 @@ mod tests {
         let sum = unsafe { generic_xany_fallback_nofma_sum_vertical(&matrix_view) };
         assert_eq!(sum, expected_vertical_sum);
     }
+
+    #[test]
+    fn test_f32_xany_nofma_scaled_sum() {
+        let (x, _) = get_sample_vectors::<f32>(131);
+        let sum = unsafe { generic_xany_fallback_nofma_scaled_sum(0.5, &x) };
+        assert_is_close(sum, 0.5 * x.iter().sum::<f32>());
+    }
+
+    #[test]
+    fn test_xany_nofma_sum_chunks() {
+        let (x, _) = get_sample_vectors::<f32>(531);
+        let chunks = [&x[..13], &x[13..13], &x[13..200], &x[200..]];
+        let sum = unsafe { generic_xany_fallback_nofma_sum_chunks(&chunks) };
+        assert_is_close(sum, x.iter().sum::<f32>());
+    }
+
+    #[test]
+    fn test_xany_nofma_sum_streaming() {
+        let (x, _) = get_sample_vectors::<f32>(1037);
+        let expected = x.iter().map(|v| *v as f64).sum::<f64>();
+
+        let mut offset = 0;
+        let total = unsafe {
+            f32_xany_fallback_nofma_sum_streaming(128, |buffer| {
+                let n = buffer.len().min(x.len() - offset);
+                buffer[..n].copy_from_slice(&x[offset..offset + n]);
+                offset += n;
+                n
+            })
+        };
+        assert!(
+            (total as f64 - expected).abs() < 1e-3,
+            "{total} != {expected}"
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "Reader filled 9 elements, exceeding the chunk size 8")]
+    fn test_xany_nofma_sum_streaming_overfilled() {
+        unsafe { f32_xany_fallback_nofma_sum_streaming(8, |_| 9) };
+    }
 }
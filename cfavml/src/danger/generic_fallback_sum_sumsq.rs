@@ -0,0 +1,59 @@
+use crate::math::*;
+
+#[inline]
+/// Computes both the sum and the sum of squares of one `T` vector in a single pass,
+/// returning `(sum(x), sum(x * x))`.
+///
+/// ```py
+/// D: int
+/// sum: T
+/// sum_sq: T
+/// x: [T; D]
+///
+/// for i in 0..D:
+///     sum = sum + x[i]
+///     sum_sq = sum_sq + (x[i] * x[i])
+/// ```
+///
+/// These values can be used to compute the variance as `E[x²] - E[x]²` with a single
+/// pass over memory, however, this formula suffers from catastrophic cancellation
+/// when the mean is large relative to the variance. Where precision matters more than
+/// speed, a two-pass or Welford style computation should be used instead.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_sum_sumsq<T>(x: &[T]) -> (T, T)
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    let mut sum = AutoMath::zero();
+    let mut sum_sq = AutoMath::zero();
+
+    for &x in x {
+        sum = AutoMath::add(sum, x);
+        sum_sq = AutoMath::add(sum_sq, AutoMath::mul(x, x));
+    }
+
+    (sum, sum_sq)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_f32_xany_nofma_sum_sumsq() {
+        let (x, _) = get_sample_vectors::<f32>(514);
+        let (sum, sum_sq) = unsafe { generic_xany_fallback_nofma_sum_sumsq(&x) };
+        assert_is_close(sum, x.iter().sum::<f32>());
+        assert_is_close(sum_sq, simple_dot(&x, &x));
+    }
+}
@@ -0,0 +1,76 @@
+use crate::danger::Summary;
+
+#[inline]
+/// Computes the sum, minimum, maximum, count and mean of the vector in a single pass.
+///
+/// ```py
+/// D: int
+/// x: [f32; D]
+///
+/// return Summary(
+///     sum=sum(x),
+///     min=min(x),
+///     max=max(x),
+///     count=D,
+///     mean=sum(x) / D,
+/// )
+/// ```
+///
+/// An empty vector produces a [Summary] with a `sum` of `0.0`, a `min` of `+inf`, a
+/// `max` of `-inf`, a `count` of `0` and a `mean` of `NaN`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn f32_xany_fallback_nofma_summary(x: &[f32]) -> Summary<f32> {
+    let mut sum = 0.0;
+    let mut min = f32::INFINITY;
+    let mut max = f32::NEG_INFINITY;
+
+    for &v in x {
+        sum += v;
+        min = min.min(v);
+        max = max.max(v);
+    }
+
+    Summary {
+        sum,
+        min,
+        max,
+        count: x.len(),
+        mean: sum / x.len() as f32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_summary() {
+        let summary = unsafe { f32_xany_fallback_nofma_summary(&[3.0, -1.0, 4.0, 2.0]) };
+        assert_eq!(
+            summary,
+            Summary {
+                sum: 8.0,
+                min: -1.0,
+                max: 4.0,
+                count: 4,
+                mean: 2.0,
+            }
+        );
+
+        let summary = unsafe { f32_xany_fallback_nofma_summary(&[]) };
+        assert_eq!(
+            (summary.min, summary.max),
+            (f32::INFINITY, f32::NEG_INFINITY)
+        );
+        assert_eq!((summary.sum, summary.count), (0.0, 0));
+        assert!(summary.mean.is_nan());
+    }
+}
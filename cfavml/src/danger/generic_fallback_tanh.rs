@@ -0,0 +1,78 @@
+use crate::danger::partially_overlaps;
+use crate::math::*;
+
+#[inline]
+/// Computes the hyperbolic tangent of each element of the vector, writing the result
+/// to `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D]
+///
+/// for i in 0..D:
+///     result[i] = tanh(x[i])
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_tanh<T>(x: &[T], result: &mut [T])
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    for i in 0..x.len() {
+        *result.get_unchecked_mut(i) = AutoMath::tanh(*x.get_unchecked(i));
+    }
+}
+
+#[inline]
+/// Computes the hyperbolic tangent of each element of the vector in place.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_tanh_inplace<T>(x: &mut [T])
+where
+    T: Copy,
+    AutoMath: TranscendentalMath<T>,
+{
+    for v in x.iter_mut() {
+        *v = AutoMath::tanh(*v);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_tanh() {
+        let x = [0.0f32, 100.0, -100.0];
+        let mut result = [1.0; 3];
+        unsafe { generic_xany_fallback_nofma_tanh(&x, &mut result) };
+        assert_eq!(result, [0.0, 1.0, -1.0]);
+
+        let mut x = [0.5f64, f64::NAN];
+        unsafe { generic_xany_fallback_nofma_tanh_inplace(&mut x) };
+        assert_eq!(x[0], 0.5f64.tanh());
+        assert!(x[1].is_nan());
+    }
+}
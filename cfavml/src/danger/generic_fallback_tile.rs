@@ -0,0 +1,105 @@
+use core::ptr;
+
+use crate::danger::overlaps;
+
+#[inline]
+/// Concatenates `times` copies of `x`, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// result: [T; D * times]
+///
+/// for t in 0..times:
+///     for i in 0..D:
+///         result[t * D + i] = x[i]
+/// ```
+///
+/// After the first copy of `x`, the already written prefix of `result` is copied onto
+/// the remainder, doubling in size each time. Only `log2(times)` bulk copies are
+/// needed which the compiler lowers to vectorized `memcpy` calls, so no architecture
+/// specific routines are provided.
+///
+/// # Safety
+///
+/// `result` **MUST** be `x.len() * times` long, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// `result` **MUST NOT** overlap `x`.
+pub unsafe fn generic_xany_fallback_nofma_tile<T: Copy>(
+    x: &[T],
+    times: usize,
+    result: &mut [T],
+) {
+    debug_assert_eq!(result.len(), x.len() * times);
+    debug_assert!(!overlaps(x, result), "`result` must not overlap `x`");
+
+    let total = x.len() * times;
+    if total == 0 {
+        return;
+    }
+
+    let result_ptr = result.as_mut_ptr();
+    ptr::copy_nonoverlapping(x.as_ptr(), result_ptr, x.len());
+
+    let mut filled = x.len();
+    while filled < total {
+        let count = filled.min(total - filled);
+        ptr::copy_nonoverlapping(result_ptr, result_ptr.add(filled), count);
+        filled += count;
+    }
+}
+
+#[inline]
+/// Broadcasts the vector `row` across every row of the row-major `matrix`, which has
+/// `rows` rows of `row.len()` columns.
+///
+/// ```py
+/// D: int
+/// row: [T; D]
+/// matrix: [[T; D]; rows]
+///
+/// for r in 0..rows:
+///     matrix[r] = row
+/// ```
+///
+/// This is typically used to expand a bias vector across a batch before applying
+/// it with a vertical add.
+///
+/// # Safety
+///
+/// `matrix` **MUST** be `row.len() * rows` long, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_broadcast_row<T: Copy>(
+    row: &[T],
+    rows: usize,
+    matrix: &mut [T],
+) {
+    generic_xany_fallback_nofma_tile(row, rows, matrix)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_tile() {
+        for (len, times) in [(0, 3), (3, 0), (1, 1), (5, 7), (13, 64), (127, 3)] {
+            let (x, _) = get_sample_vectors::<f32>(len);
+            let expected = x.repeat(times);
+
+            let mut result = vec![0.0; len * times];
+            unsafe { generic_xany_fallback_nofma_tile(&x, times, &mut result) };
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_broadcast_row() {
+        let row = [1, 2, 3];
+        let mut matrix = [0; 9];
+        unsafe { generic_xany_fallback_nofma_broadcast_row(&row, 3, &mut matrix) };
+        assert_eq!(matrix, [1, 2, 3, 1, 2, 3, 1, 2, 3]);
+    }
+}
@@ -0,0 +1,54 @@
+#[inline]
+/// Transposes the row-major `[T; rows * cols]` matrix `src` writing the
+/// row-major `[T; cols * rows]` result to `dst`.
+///
+/// ```py
+/// rows: int
+/// cols: int
+/// src: [T; rows * cols]
+/// dst: [T; cols * rows]
+///
+/// for r in 0..rows:
+///     for c in 0..cols:
+///         dst[c * rows + r] = src[r * cols + c]
+/// ```
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// Both `src` and `dst` **MUST** be `rows * cols` in length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+pub unsafe fn generic_xany_fallback_nofma_transpose<T: Copy>(
+    src: &[T],
+    rows: usize,
+    cols: usize,
+    dst: &mut [T],
+) {
+    debug_assert_eq!(src.len(), rows * cols);
+    debug_assert_eq!(dst.len(), rows * cols);
+
+    for r in 0..rows {
+        for c in 0..cols {
+            *dst.get_unchecked_mut(c * rows + r) = *src.get_unchecked(r * cols + c);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{get_sample_vectors, simple_transpose};
+
+    #[test]
+    fn test_f32_xany_nofma_transpose() {
+        let (src, _) = get_sample_vectors::<f32>(67 * 131);
+        let expected = simple_transpose(&src, 67, 131);
+
+        let mut dst = vec![0.0; 67 * 131];
+        unsafe { generic_xany_fallback_nofma_transpose(&src, 67, 131, &mut dst) };
+        assert_eq!(dst, expected);
+    }
+}
@@ -0,0 +1,111 @@
+#[cfg(target_pointer_width = "64")]
+use crate::danger::{
+    i64_xany_fallback_nofma_checked_sum,
+    isize_as_i64,
+    u64_xany_fallback_nofma_checked_sum,
+    usize_as_u64,
+};
+
+#[inline]
+/// Sums all elements of the vector, returning `None` if the total does not fit
+/// within a `usize`.
+///
+/// ```py
+/// D: int
+/// total: u128
+/// x: [usize; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return total if total <= usize.MAX else None
+/// ```
+///
+/// On 64-bit targets `usize` is `u64` and the vector is summed by
+/// [u64_xany_fallback_nofma_checked_sum](crate::danger::u64_xany_fallback_nofma_checked_sum),
+/// narrower targets accumulate each element widened to a `u64`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn usize_xany_fallback_nofma_checked_sum(x: &[usize]) -> Option<usize> {
+    #[cfg(target_pointer_width = "64")]
+    {
+        u64_xany_fallback_nofma_checked_sum(usize_as_u64(x)).map(|total| total as usize)
+    }
+
+    #[cfg(not(target_pointer_width = "64"))]
+    {
+        let total = x.iter().map(|v| *v as u64).sum::<u64>();
+        usize::try_from(total).ok()
+    }
+}
+
+#[inline]
+/// Sums all elements of the vector, returning `None` if the total does not fit
+/// within an `isize`.
+///
+/// ```py
+/// D: int
+/// total: i128
+/// x: [isize; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return total if isize.MIN <= total <= isize.MAX else None
+/// ```
+///
+/// On 64-bit targets `isize` is `i64` and the vector is summed by
+/// [i64_xany_fallback_nofma_checked_sum](crate::danger::i64_xany_fallback_nofma_checked_sum),
+/// narrower targets accumulate each element widened to an `i64`.
+///
+/// These are fallback routines, they are designed to be optimized
+/// by the compiler only, in areas where manually optimized routines
+/// are unable to run due to lack of CPU features.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn isize_xany_fallback_nofma_checked_sum(x: &[isize]) -> Option<isize> {
+    #[cfg(target_pointer_width = "64")]
+    {
+        i64_xany_fallback_nofma_checked_sum(isize_as_i64(x)).map(|total| total as isize)
+    }
+
+    #[cfg(not(target_pointer_width = "64"))]
+    {
+        let total = x.iter().map(|v| *v as i64).sum::<i64>();
+        isize::try_from(total).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_usize_isize_xany_nofma_checked_sum() {
+        let x = [3usize, 5, 7, usize::MAX - 20];
+        assert_eq!(
+            unsafe { usize_xany_fallback_nofma_checked_sum(&x) },
+            Some(usize::MAX - 5)
+        );
+        let x = [6usize, usize::MAX - 5];
+        assert_eq!(unsafe { usize_xany_fallback_nofma_checked_sum(&x) }, None);
+
+        let x = [isize::MAX, 1, -2];
+        assert_eq!(
+            unsafe { isize_xany_fallback_nofma_checked_sum(&x) },
+            Some(isize::MAX - 1)
+        );
+        let x = [isize::MIN, -1];
+        assert_eq!(unsafe { isize_xany_fallback_nofma_checked_sum(&x) }, None);
+    }
+}
@@ -16,6 +16,27 @@ where
     generic_xany_fallback_nofma_mul_value(arr, AutoMath::div(AutoMath::one(), divider))
 }
 
+#[inline]
+/// Approximately divides each element in the provided mutable `[T; DIMS]` vector by `value`.
+///
+/// This computes the reciprocal of `value` once and multiplies each element by it,
+/// so the result may differ from the correctly rounded quotient by 1 ULP.
+/// [generic_xany_fallback_nofma_div_value] currently uses the same reciprocal
+/// multiply, this routine is an explicit alias for callers relying on that behaviour.
+///
+/// # Safety
+///
+/// Vectors **MUST** be `DIMS` elements in length and divisible by 128,
+/// otherwise this function becomes immediately UB due to out of bounds
+/// access.
+pub unsafe fn generic_xany_fallback_nofma_fast_div_value<T>(arr: &mut [T], divider: T)
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    generic_xany_fallback_nofma_mul_value(arr, AutoMath::div(AutoMath::one(), divider))
+}
+
 #[inline]
 /// Multiplies each element in the provided mutable `[T; DIMS]` vector by `value`.
 ///
@@ -64,6 +85,40 @@ where
     generic_xany_fallback_sub_impl::<T, AutoMath>(arr, value)
 }
 
+#[inline]
+/// Clamps each element in the provided mutable `T` vector to be no less than `value`.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_max_value<T>(arr: &mut [T], value: T)
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    for x in arr.iter_mut() {
+        *x = AutoMath::cmp_max(*x, value);
+    }
+}
+
+#[inline]
+/// Clamps each element in the provided mutable `T` vector to be no greater than `value`.
+///
+/// # Safety
+///
+/// This method in theory is safe, but like the rest of the dangerous API, makes
+/// no guarantee that it will always remain safe with no strings attached.
+pub unsafe fn generic_xany_fallback_nofma_min_value<T>(arr: &mut [T], value: T)
+where
+    T: Copy,
+    AutoMath: Math<T>,
+{
+    for x in arr.iter_mut() {
+        *x = AutoMath::cmp_min(*x, value);
+    }
+}
+
 #[inline(always)]
 unsafe fn generic_xany_fallback_mul_impl<T, M>(arr: &mut [T], multiplier: T)
 where
@@ -198,6 +253,15 @@ mod tests {
         assert_is_close_vector(&x, &expected);
     }
 
+    #[test]
+    fn test_f32_xany_nofma_fast_div() {
+        let value = 3.0;
+        let (mut x, _) = get_sample_vectors(557);
+        let expected = x.iter().copied().map(|v| v / value).collect::<Vec<_>>();
+        unsafe { generic_xany_fallback_nofma_fast_div_value(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
+
     #[test]
     fn test_f32_xany_nofma_mul() {
         let value = 2.0;
@@ -224,4 +288,30 @@ mod tests {
         unsafe { generic_xany_fallback_nofma_sub_value(&mut x, value) };
         assert_is_close_vector(&x, &expected);
     }
+
+    #[test]
+    fn test_f32_xany_nofma_max_value() {
+        let value = 0.5;
+        let (mut x, _) = get_sample_vectors(557);
+        let expected = x
+            .iter()
+            .copied()
+            .map(|v: f32| v.max(value))
+            .collect::<Vec<_>>();
+        unsafe { generic_xany_fallback_nofma_max_value(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
+
+    #[test]
+    fn test_f32_xany_nofma_min_value() {
+        let value = 0.5;
+        let (mut x, _) = get_sample_vectors(557);
+        let expected = x
+            .iter()
+            .copied()
+            .map(|v: f32| v.min(value))
+            .collect::<Vec<_>>();
+        unsafe { generic_xany_fallback_nofma_min_value(&mut x, value) };
+        assert_is_close_vector(&x, &expected);
+    }
 }
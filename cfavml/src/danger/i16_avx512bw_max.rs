@@ -0,0 +1,81 @@
+use core::arch::x86_64::*;
+use core::mem;
+
+use crate::danger::load_one_variable_size_avx512_epi16;
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes the horizontal maximum of the given vector that is `[i16; N]`.
+///
+/// An empty vector returns `i16::MIN`.
+///
+/// # Safety
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i16_xany_avx512bw_nofma_max_horizontal(arr: &[i16]) -> i16 {
+    let len = arr.len();
+    let offset_from = len % 128;
+    let arr = arr.as_ptr();
+
+    let identity = _mm512_set1_epi16(i16::MIN);
+    let mut acc1 = identity;
+    let mut acc2 = identity;
+    let mut acc3 = identity;
+    let mut acc4 = identity;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm512_loadu_si512(arr.add(i).cast());
+        let x2 = _mm512_loadu_si512(arr.add(i + 32).cast());
+        let x3 = _mm512_loadu_si512(arr.add(i + 64).cast());
+        let x4 = _mm512_loadu_si512(arr.add(i + 96).cast());
+
+        acc1 = _mm512_max_epi16(acc1, x1);
+        acc2 = _mm512_max_epi16(acc2, x2);
+        acc3 = _mm512_max_epi16(acc3, x3);
+        acc4 = _mm512_max_epi16(acc4, x4);
+
+        i += 128;
+    }
+
+    // Lanes beyond the end of the vector are filled with the identity value
+    // so they never affect the result.
+    while i < len {
+        let x = load_one_variable_size_avx512_epi16(
+            arr.add(i).cast::<i16>(),
+            len - i,
+            identity,
+        );
+        acc1 = _mm512_max_epi16(acc1, x);
+
+        i += 32;
+    }
+
+    acc1 = _mm512_max_epi16(acc1, acc2);
+    acc3 = _mm512_max_epi16(acc3, acc4);
+    acc1 = _mm512_max_epi16(acc1, acc3);
+
+    let values = mem::transmute::<__m512i, [i16; 32]>(acc1);
+    values.into_iter().fold(i16::MIN, i16::max)
+}
+
+#[cfg(all(test, target_feature = "avx512bw"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_max_horizontal() {
+        for len in [1, 31, 32, 531, 4099] {
+            let (x, _) = get_sample_vectors::<i16>(len);
+            let res = unsafe { i16_xany_avx512bw_nofma_max_horizontal(&x) };
+            assert_eq!(res, x.iter().copied().max().unwrap());
+        }
+
+        assert_eq!(
+            unsafe { i16_xany_avx512bw_nofma_max_horizontal(&[]) },
+            i16::MIN
+        );
+    }
+}
@@ -0,0 +1,98 @@
+use core::arch::x86_64::*;
+
+use crate::danger::load_one_variable_size_avx512_epi16;
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Sums all elements of the vector, widening to `i64` so the total cannot overflow.
+///
+/// ```py
+/// D: int
+/// total: i64
+/// x: [i16; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+/// ```
+///
+/// Adjacent pairs are summed into `i32` lanes with `vpmaddwd` which are then widened
+/// into `i64` accumulators.
+///
+/// # Safety
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i16_xany_avx512bw_nofma_sum_horizontal(x: &[i16]) -> i64 {
+    let len = x.len();
+    let offset_from = len % 64;
+    let x_ptr = x.as_ptr();
+
+    let ones = _mm512_set1_epi16(1);
+    let mut acc1 = _mm512_setzero_si512();
+    let mut acc2 = _mm512_setzero_si512();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm512_loadu_si512(x_ptr.add(i).cast());
+        let x2 = _mm512_loadu_si512(x_ptr.add(i + 32).cast());
+
+        // Each pair sum fits within an `i32`, so the two registers can be combined
+        // before widening.
+        let pairs =
+            _mm512_add_epi32(_mm512_madd_epi16(x1, ones), _mm512_madd_epi16(x2, ones));
+        let (low, high) = widen_epi32(pairs);
+        acc1 = _mm512_add_epi64(acc1, low);
+        acc2 = _mm512_add_epi64(acc2, high);
+
+        i += 64;
+    }
+
+    let remainder = len - i;
+    if remainder > 0 {
+        let zero = _mm512_setzero_si512();
+        let x = load_one_variable_size_avx512_epi16(x_ptr.add(i), remainder, zero);
+        let mut pairs = _mm512_madd_epi16(x, ones);
+
+        if remainder > 32 {
+            let x = load_one_variable_size_avx512_epi16(
+                x_ptr.add(i + 32),
+                remainder - 32,
+                zero,
+            );
+            pairs = _mm512_add_epi32(pairs, _mm512_madd_epi16(x, ones));
+        }
+
+        let (low, high) = widen_epi32(pairs);
+        acc1 = _mm512_add_epi64(acc1, low);
+        acc2 = _mm512_add_epi64(acc2, high);
+    }
+
+    _mm512_reduce_add_epi64(_mm512_add_epi64(acc1, acc2))
+}
+
+#[inline(always)]
+/// Sign extends the 16 `i32` lanes of the register into two registers of `i64` lanes.
+unsafe fn widen_epi32(v: __m512i) -> (__m512i, __m512i) {
+    let low = _mm512_cvtepi32_epi64(_mm512_castsi512_si256(v));
+    let high = _mm512_cvtepi32_epi64(_mm512_extracti64x4_epi64::<1>(v));
+    (low, high)
+}
+
+#[cfg(all(test, target_feature = "avx512bw"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_sum() {
+        for len in [0, 31, 33, 64, 531, 4099] {
+            let (x, _) = get_sample_vectors::<i16>(len);
+            let sum = unsafe { i16_xany_avx512bw_nofma_sum_horizontal(&x) };
+            assert_eq!(sum, x.iter().map(|v| *v as i64).sum::<i64>());
+        }
+
+        let x = vec![i16::MAX; 70_000];
+        let sum = unsafe { i16_xany_avx512bw_nofma_sum_horizontal(&x) };
+        assert_eq!(sum, i16::MAX as i64 * 70_000);
+    }
+}
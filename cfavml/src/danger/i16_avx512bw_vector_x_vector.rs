@@ -0,0 +1,273 @@
+use core::arch::x86_64::*;
+
+use crate::danger::{
+    copy_masked_avx512_epi16_register_to,
+    load_one_variable_size_avx512_epi16,
+};
+
+/// Applies the given op to `128` elements of `x` and `y` writing the result to `x`.
+macro_rules! x128_op_inplace {
+    ($x:expr, $y:expr, $op:ident) => {{
+        let [x1, x2, x3, x4] =
+            [0, 1, 2, 3].map(|k| _mm512_loadu_si512($x.add(k * 32).cast()));
+        let [y1, y2, y3, y4] =
+            [0, 1, 2, 3].map(|k| _mm512_loadu_si512($y.add(k * 32).cast()));
+
+        _mm512_storeu_si512($x.cast(), $op(x1, y1));
+        _mm512_storeu_si512($x.add(32).cast(), $op(x2, y2));
+        _mm512_storeu_si512($x.add(64).cast(), $op(x3, y3));
+        _mm512_storeu_si512($x.add(96).cast(), $op(x4, y4));
+    }};
+}
+
+/// Applies the given op to the remaining elements of `x` and `y` writing the result
+/// to `x`, the final partial register is handled with masked loads and stores.
+macro_rules! execute_tail_op_inplace {
+    ($len:expr, $i:expr, $x:expr, $y:expr, $op:ident) => {{
+        while $i < ($len - ($len % 32)) {
+            let x = _mm512_loadu_si512($x.add($i).cast());
+            let y = _mm512_loadu_si512($y.add($i).cast());
+            _mm512_storeu_si512($x.add($i).cast(), $op(x, y));
+
+            $i += 32;
+        }
+
+        let remainder = $len - $i;
+        if remainder > 0 {
+            let zero = _mm512_setzero_si512();
+            let x = load_one_variable_size_avx512_epi16(
+                $x.add($i).cast::<i16>(),
+                remainder,
+                zero,
+            );
+            let y = load_one_variable_size_avx512_epi16(
+                $y.add($i).cast::<i16>(),
+                remainder,
+                zero,
+            );
+            copy_masked_avx512_epi16_register_to(
+                $x.add($i).cast::<i16>(),
+                $op(x, y),
+                remainder,
+            );
+        }
+    }};
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes `x[i] + y[i]` element wise, wrapping on overflow, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [i16; D]
+/// y: [i16; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] + y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i16_xany_avx512bw_nofma_add_vertical(x: &mut [i16], y: &[i16]) {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 128;
+
+    let x = x.as_mut_ptr();
+    let y = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        x128_op_inplace!(x.add(i), y.add(i), _mm512_add_epi16);
+
+        i += 128;
+    }
+
+    execute_tail_op_inplace!(len, i, x, y, _mm512_add_epi16);
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes `x[i] - y[i]` element wise, wrapping on overflow, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [i16; D]
+/// y: [i16; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] - y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i16_xany_avx512bw_nofma_sub_vertical(x: &mut [i16], y: &[i16]) {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 128;
+
+    let x = x.as_mut_ptr();
+    let y = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        x128_op_inplace!(x.add(i), y.add(i), _mm512_sub_epi16);
+
+        i += 128;
+    }
+
+    execute_tail_op_inplace!(len, i, x, y, _mm512_sub_epi16);
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes `x[i] + y[i]` element wise, saturating at the bounds of `i16`, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [i16; D]
+/// y: [i16; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] + y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i16_xany_avx512bw_nofma_saturating_add_vertical(x: &mut [i16], y: &[i16]) {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 128;
+
+    let x = x.as_mut_ptr();
+    let y = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        x128_op_inplace!(x.add(i), y.add(i), _mm512_adds_epi16);
+
+        i += 128;
+    }
+
+    execute_tail_op_inplace!(len, i, x, y, _mm512_adds_epi16);
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes `x[i] - y[i]` element wise, saturating at the bounds of `i16`, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [i16; D]
+/// y: [i16; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] - y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i16_xany_avx512bw_nofma_saturating_sub_vertical(x: &mut [i16], y: &[i16]) {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 128;
+
+    let x = x.as_mut_ptr();
+    let y = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        x128_op_inplace!(x.add(i), y.add(i), _mm512_subs_epi16);
+
+        i += 128;
+    }
+
+    execute_tail_op_inplace!(len, i, x, y, _mm512_subs_epi16);
+}
+
+#[cfg(all(test, target_feature = "avx512bw"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_add_vertical() {
+        for len in [63, 64, 531] {
+            let (mut x, y) = get_sample_vectors::<i16>(len);
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(x, y)| x.wrapping_add(*y))
+                .collect::<Vec<i16>>();
+
+            unsafe { i16_xany_avx512bw_nofma_add_vertical(&mut x, &y) };
+            assert_eq!(x, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_sub_vertical() {
+        for len in [63, 64, 531] {
+            let (mut x, y) = get_sample_vectors::<i16>(len);
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(x, y)| x.wrapping_sub(*y))
+                .collect::<Vec<i16>>();
+
+            unsafe { i16_xany_avx512bw_nofma_sub_vertical(&mut x, &y) };
+            assert_eq!(x, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_saturating_add_vertical() {
+        for len in [63, 64, 531] {
+            let (mut x, y) = get_sample_vectors::<i16>(len);
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(x, y)| x.saturating_add(*y))
+                .collect::<Vec<i16>>();
+
+            unsafe { i16_xany_avx512bw_nofma_saturating_add_vertical(&mut x, &y) };
+            assert_eq!(x, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_saturating_sub_vertical() {
+        for len in [63, 64, 531] {
+            let (mut x, y) = get_sample_vectors::<i16>(len);
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(x, y)| x.saturating_sub(*y))
+                .collect::<Vec<i16>>();
+
+            unsafe { i16_xany_avx512bw_nofma_saturating_sub_vertical(&mut x, &y) };
+            assert_eq!(x, expected);
+        }
+    }
+}
@@ -0,0 +1,109 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the absolute value of each element of the vector, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// x: [i32; D]
+/// result: [i32; D]
+///
+/// for i in 0..D:
+///     result[i] = abs(x[i])
+/// ```
+///
+/// `i32::MIN` has no positive counterpart and wraps back to `i32::MIN`, the same as
+/// [i32::wrapping_abs].
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i32_xany_avx2_nofma_abs(x: &[i32], result: &mut [i32]) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    i32_avx2_abs_to(x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the absolute value of each element of the vector in place.
+///
+/// ```py
+/// D: int
+/// x: [i32; D]
+///
+/// for i in 0..D:
+///     x[i] = abs(x[i])
+/// ```
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i32_xany_avx2_nofma_abs_inplace(x: &mut [i32]) {
+    let x_ptr = x.as_mut_ptr();
+    i32_avx2_abs_to(x_ptr, x_ptr, x.len())
+}
+
+#[inline(always)]
+/// Computes the absolute value of the values read from `x_ptr` writing the output
+/// to the `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn i32_avx2_abs_to(x_ptr: *const i32, result: *mut i32, len: usize) {
+    let offset_from = len % 32;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let v1 = _mm256_loadu_si256(x_ptr.add(i).cast());
+        let v2 = _mm256_loadu_si256(x_ptr.add(i + 8).cast());
+        let v3 = _mm256_loadu_si256(x_ptr.add(i + 16).cast());
+        let v4 = _mm256_loadu_si256(x_ptr.add(i + 24).cast());
+
+        _mm256_storeu_si256(result.add(i).cast(), _mm256_abs_epi32(v1));
+        _mm256_storeu_si256(result.add(i + 8).cast(), _mm256_abs_epi32(v2));
+        _mm256_storeu_si256(result.add(i + 16).cast(), _mm256_abs_epi32(v3));
+        _mm256_storeu_si256(result.add(i + 24).cast(), _mm256_abs_epi32(v4));
+
+        i += 32;
+    }
+
+    while i < len {
+        *result.add(i) = (*x_ptr.add(i)).wrapping_abs();
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_abs() {
+        let mut x = (-70..61).map(|v| v * 1_000_003).collect::<Vec<i32>>();
+        x[7] = i32::MIN;
+        x[8] = i32::MAX;
+
+        let expected = x.iter().map(|v| v.wrapping_abs()).collect::<Vec<i32>>();
+
+        let mut result = vec![0; x.len()];
+        unsafe { i32_xany_avx2_nofma_abs(&x, &mut result) };
+        assert_eq!(result, expected);
+
+        unsafe { i32_xany_avx2_nofma_abs_inplace(&mut x) };
+        assert_eq!(x, expected);
+    }
+}
@@ -0,0 +1,85 @@
+use core::arch::x86_64::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Narrows each `i32` element of `x` to `i16`, saturating values outside of
+/// the `i16::MIN..=i16::MAX` range, writing the result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [i32; D]
+/// result: [i16; D]
+///
+/// for i in 0..D:
+///     result[i] = clamp(x[i], -32768, 32767)
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i32_xany_avx2_nofma_to_i16_saturating(x: &[i32], result: &mut [i16]) {
+    debug_assert_eq!(x.len(), result.len());
+
+    let len = x.len();
+    let offset_from = len % 16;
+
+    let x_ptr = x.as_ptr();
+    let result_ptr = result.as_mut_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_si256(x_ptr.add(i).cast());
+        let x2 = _mm256_loadu_si256(x_ptr.add(i + 8).cast());
+
+        // `packs` operates on each 128-bit lane independently, so the 64-bit
+        // groups must be re-ordered to restore the element order.
+        let packed = _mm256_packs_epi32(x1, x2);
+        let packed = _mm256_permute4x64_epi64::<0b11_01_10_00>(packed);
+        _mm256_storeu_si256(result_ptr.add(i).cast(), packed);
+
+        i += 16;
+    }
+
+    while i < len {
+        let x = *x.get_unchecked(i);
+        *result.get_unchecked_mut(i) = x.clamp(i16::MIN as i32, i16::MAX as i32) as i16;
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_xany_nofma_to_i16_saturating() {
+        let mut x = vec![
+            i32::MIN,
+            -32769,
+            -32768,
+            -32767,
+            -1,
+            0,
+            1,
+            32766,
+            32767,
+            32768,
+            i32::MAX,
+        ];
+        x.extend((-70_000..70_000).step_by(997));
+
+        let expected = x
+            .iter()
+            .map(|v| (*v).clamp(i16::MIN as i32, i16::MAX as i32) as i16)
+            .collect::<Vec<_>>();
+
+        let mut result = vec![0; x.len()];
+        unsafe { i32_xany_avx2_nofma_to_i16_saturating(&x, &mut result) };
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,260 @@
+use core::arch::x86_64::*;
+
+use crate::danger::partially_overlaps;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element of `x` to the bounds `lo` and `hi`, writing the result to
+/// `result`.
+///
+/// ```py
+/// D: int
+/// lo: i32
+/// hi: i32
+/// x: [i32; D]
+/// result: [i32; D]
+///
+/// for i in 0..D:
+///     result[i] = min(max(x[i], lo), hi)
+/// ```
+///
+/// If `lo` is greater than `hi` the element is set to `hi`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i32_xany_avx2_nofma_clamp_value(
+    lo: i32,
+    hi: i32,
+    x: &[i32],
+    result: &mut [i32],
+) {
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        !partially_overlaps(x, result),
+        "`result` must not partially overlap `x`"
+    );
+
+    clamp_value_to(lo, hi, x.as_ptr(), result.as_mut_ptr(), x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element of `x` to the bounds `lo` and `hi` in place.
+///
+/// ```py
+/// D: int
+/// lo: i32
+/// hi: i32
+/// x: [i32; D]
+///
+/// for i in 0..D:
+///     x[i] = min(max(x[i], lo), hi)
+/// ```
+///
+/// If `lo` is greater than `hi` the element is set to `hi`.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i32_xany_avx2_nofma_clamp_value_inplace(lo: i32, hi: i32, x: &mut [i32]) {
+    let x_ptr = x.as_mut_ptr();
+    clamp_value_to(lo, hi, x_ptr, x_ptr, x.len())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi`, writing the
+/// result to `result`.
+///
+/// ```py
+/// D: int
+/// x: [i32; D]
+/// lo: [i32; D]
+/// hi: [i32; D]
+/// result: [i32; D]
+///
+/// for i in 0..D:
+///     result[i] = min(max(x[i], lo[i]), hi[i])
+/// ```
+///
+/// If `lo[i]` is greater than `hi[i]` the element is set to `hi[i]`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i32_xany_avx2_nofma_clamp_vertical(
+    x: &[i32],
+    lo: &[i32],
+    hi: &[i32],
+    result: &mut [i32],
+) {
+    debug_assert_eq!(x.len(), lo.len());
+    debug_assert_eq!(x.len(), hi.len());
+    debug_assert_eq!(x.len(), result.len());
+    debug_assert!(
+        ![x, lo, hi].iter().any(|v| partially_overlaps(v, result)),
+        "`result` must not partially overlap an input"
+    );
+
+    clamp_vertical_to(x.as_ptr(), lo, hi, result.as_mut_ptr())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi` in place.
+///
+/// ```py
+/// D: int
+/// x: [i32; D]
+/// lo: [i32; D]
+/// hi: [i32; D]
+///
+/// for i in 0..D:
+///     x[i] = min(max(x[i], lo[i]), hi[i])
+/// ```
+///
+/// If `lo[i]` is greater than `hi[i]` the element is set to `hi[i]`.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i32_xany_avx2_nofma_clamp_vertical_inplace(
+    x: &mut [i32],
+    lo: &[i32],
+    hi: &[i32],
+) {
+    debug_assert_eq!(x.len(), lo.len());
+    debug_assert_eq!(x.len(), hi.len());
+
+    let x_ptr = x.as_mut_ptr();
+    clamp_vertical_to(x_ptr, lo, hi, x_ptr)
+}
+
+#[inline(always)]
+/// Clamps the values read from `x_ptr` to `lo` and `hi`, writing the output to the
+/// `result` pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn clamp_value_to(
+    lo: i32,
+    hi: i32,
+    x_ptr: *const i32,
+    result: *mut i32,
+    len: usize,
+) {
+    let offset_from = len % 32;
+    let lo_reg = _mm256_set1_epi32(lo);
+    let hi_reg = _mm256_set1_epi32(hi);
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for j in (0..32).step_by(8) {
+            let x = _mm256_loadu_si256(x_ptr.add(i + j).cast());
+            let r = _mm256_min_epi32(_mm256_max_epi32(x, lo_reg), hi_reg);
+            _mm256_storeu_si256(result.add(i + j).cast(), r);
+        }
+
+        i += 32;
+    }
+
+    while i < len {
+        *result.add(i) = (*x_ptr.add(i)).max(lo).min(hi);
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+/// Clamps `x` to `lo` and `hi` element wise, writing the output to the `result`
+/// pointer.
+///
+/// `x_ptr` and `result` may point to the same memory.
+unsafe fn clamp_vertical_to(
+    x_ptr: *const i32,
+    lo: &[i32],
+    hi: &[i32],
+    result: *mut i32,
+) {
+    let len = lo.len();
+    let offset_from = len % 32;
+    let lo_ptr = lo.as_ptr();
+    let hi_ptr = hi.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        for j in (0..32).step_by(8) {
+            let x = _mm256_loadu_si256(x_ptr.add(i + j).cast());
+            let lo = _mm256_loadu_si256(lo_ptr.add(i + j).cast());
+            let hi = _mm256_loadu_si256(hi_ptr.add(i + j).cast());
+            let r = _mm256_min_epi32(_mm256_max_epi32(x, lo), hi);
+            _mm256_storeu_si256(result.add(i + j).cast(), r);
+        }
+
+        i += 32;
+    }
+
+    while i < len {
+        *result.add(i) = (*x_ptr.add(i)).max(*lo_ptr.add(i)).min(*hi_ptr.add(i));
+
+        i += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_clamp_vertical() {
+        let (mut x, lo) = get_sample_vectors::<i32>(131);
+        let hi = lo
+            .iter()
+            .map(|v| v.saturating_add(1 << 28))
+            .collect::<Vec<i32>>();
+
+        let expected = x
+            .iter()
+            .zip(lo.iter())
+            .zip(hi.iter())
+            .map(|((x, lo), hi)| (*x).max(*lo).min(*hi))
+            .collect::<Vec<i32>>();
+
+        let mut result = vec![0; 131];
+        unsafe { i32_xany_avx2_nofma_clamp_vertical(&x, &lo, &hi, &mut result) };
+        assert_eq!(result, expected);
+
+        unsafe { i32_xany_avx2_nofma_clamp_vertical_inplace(&mut x, &lo, &hi) };
+        assert_eq!(x, expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_clamp_value() {
+        let (mut x, _) = get_sample_vectors::<i32>(131);
+        let expected = x
+            .iter()
+            .map(|v| (*v).clamp(-5, 1 << 20))
+            .collect::<Vec<i32>>();
+
+        let mut result = vec![0; 131];
+        unsafe { i32_xany_avx2_nofma_clamp_value(-5, 1 << 20, &x, &mut result) };
+        assert_eq!(result, expected);
+
+        unsafe { i32_xany_avx2_nofma_clamp_value_inplace(-5, 1 << 20, &mut x) };
+        assert_eq!(x, expected);
+    }
+}
@@ -0,0 +1,230 @@
+use core::arch::x86_64::*;
+
+/// The maximum number of registers which can be accumulated before the
+/// 32-bit halves risk overflowing their 64-bit lanes.
+const MAX_BLOCK_ITERATIONS: usize = 1 << 30;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sums all elements of the vector, returning `None` if the total does not fit
+/// within an `i64`.
+///
+/// ```py
+/// D: int
+/// total: i128
+/// x: [i64; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return total if i64.MIN <= total <= i64.MAX else None
+/// ```
+///
+/// Overflow is checked against the final total rather than each partial sum, so
+/// intermediate values may exceed the range of an `i64` as long as the result does not.
+///
+/// Each element is split into its low and high 32-bit halves which are accumulated
+/// in separate 64-bit lanes alongside a count of negative elements, the partial sums
+/// are then combined exactly using `i128` arithmetic.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn i64_xany_avx2_nofma_checked_sum(x: &[i64]) -> Option<i64> {
+    let len = x.len();
+    let offset_from = len % 8;
+    let x_ptr = x.as_ptr();
+
+    let mut total: i128 = 0;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let block_end = (len - offset_from).min(i + MAX_BLOCK_ITERATIONS * 8);
+        total += sum_block::<true>(x_ptr, i, block_end);
+        i = block_end;
+    }
+
+    while i < len {
+        total += *x_ptr.add(i) as i128;
+        i += 1;
+    }
+
+    i64::try_from(total).ok()
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sums all elements of the vector, returning `None` if the total does not fit
+/// within a `u64`.
+///
+/// ```py
+/// D: int
+/// total: u128
+/// x: [u64; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return total if total <= u64.MAX else None
+/// ```
+///
+/// The elements are accumulated in 32-bit halves exactly like
+/// [i64_xany_avx2_nofma_checked_sum], without the correction for negative elements.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u64_xany_avx2_nofma_checked_sum(x: &[u64]) -> Option<u64> {
+    let len = x.len();
+    let offset_from = len % 8;
+    let x_ptr = x.as_ptr().cast::<i64>();
+
+    // The total of `u64` values is never negative so always fits in an `i128`.
+    let mut total: i128 = 0;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let block_end = (len - offset_from).min(i + MAX_BLOCK_ITERATIONS * 8);
+        total += sum_block::<false>(x_ptr, i, block_end);
+        i = block_end;
+    }
+
+    while i < len {
+        total += *x.get_unchecked(i) as i128;
+        i += 1;
+    }
+
+    u64::try_from(total).ok()
+}
+
+#[inline(always)]
+/// Sums the elements between `start` and `end` which must be a multiple of 8 apart,
+/// the block must be small enough that no lane can overflow.
+///
+/// The elements are treated as `i64` if `SIGNED` is set and `u64` otherwise.
+unsafe fn sum_block<const SIGNED: bool>(
+    x: *const i64,
+    start: usize,
+    end: usize,
+) -> i128 {
+    let zero = _mm256_setzero_si256();
+    let low_mask = _mm256_set1_epi64x(0xFFFF_FFFF);
+
+    let mut low1 = _mm256_setzero_si256();
+    let mut low2 = _mm256_setzero_si256();
+    let mut high1 = _mm256_setzero_si256();
+    let mut high2 = _mm256_setzero_si256();
+    let mut neg1 = _mm256_setzero_si256();
+    let mut neg2 = _mm256_setzero_si256();
+
+    let mut i = start;
+    while i < end {
+        let x1 = _mm256_loadu_si256(x.add(i).cast());
+        let x2 = _mm256_loadu_si256(x.add(i + 4).cast());
+
+        low1 = _mm256_add_epi64(low1, _mm256_and_si256(x1, low_mask));
+        low2 = _mm256_add_epi64(low2, _mm256_and_si256(x2, low_mask));
+
+        // The high half is taken as unsigned, negative values are corrected
+        // for by subtracting `2^64` for each of them when combining.
+        high1 = _mm256_add_epi64(high1, _mm256_srli_epi64::<32>(x1));
+        high2 = _mm256_add_epi64(high2, _mm256_srli_epi64::<32>(x2));
+
+        // Adds `-1` for every negative element.
+        if SIGNED {
+            neg1 = _mm256_add_epi64(neg1, _mm256_cmpgt_epi64(zero, x1));
+            neg2 = _mm256_add_epi64(neg2, _mm256_cmpgt_epi64(zero, x2));
+        }
+
+        i += 8;
+    }
+
+    let low = sum_lanes_epi64(_mm256_add_epi64(low1, low2));
+    let high = sum_lanes_epi64(_mm256_add_epi64(high1, high2));
+    let neg = sum_lanes_epi64(_mm256_add_epi64(neg1, neg2));
+
+    low + (high << 32) + (neg << 64)
+}
+
+#[inline(always)]
+unsafe fn sum_lanes_epi64(v: __m256i) -> i128 {
+    let lanes = core::mem::transmute::<__m256i, [i64; 4]>(v);
+    lanes.iter().map(|v| *v as i128).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_i64_xany_checked_sum() {
+        for len in [0, 3, 8, 531, 4099] {
+            let (x, _) = get_sample_vectors::<i64>(len);
+            // Shift the values down so the total fits.
+            let x = x.into_iter().map(|v| v >> 16).collect::<Vec<_>>();
+
+            let sum = unsafe { i64_xany_avx2_nofma_checked_sum(&x) };
+            assert_eq!(sum, Some(x.iter().sum::<i64>()));
+        }
+    }
+
+    #[test]
+    fn test_i64_xany_checked_sum_overflow() {
+        let x = vec![i64::MAX / 4; 13];
+        let sum = unsafe { i64_xany_avx2_nofma_checked_sum(&x) };
+        assert_eq!(sum, None);
+
+        let x = vec![i64::MIN / 4; 13];
+        let sum = unsafe { i64_xany_avx2_nofma_checked_sum(&x) };
+        assert_eq!(sum, None);
+    }
+
+    #[test]
+    fn test_i64_xany_checked_sum_intermediate_overflow() {
+        // The running total overflows but the final total does not.
+        let mut x = vec![i64::MAX; 16];
+        x.extend(vec![i64::MIN; 16]);
+        x.push(i64::MAX);
+
+        let sum = unsafe { i64_xany_avx2_nofma_checked_sum(&x) };
+        assert_eq!(sum, Some(i64::MAX - 16));
+
+        x.push(17);
+        let sum = unsafe { i64_xany_avx2_nofma_checked_sum(&x) };
+        assert_eq!(sum, None);
+    }
+
+    #[test]
+    fn test_u64_xany_checked_sum() {
+        for len in [0, 3, 8, 531, 4099] {
+            let (x, _) = get_sample_vectors::<u64>(len);
+            let x = x.into_iter().map(|v| v >> 16).collect::<Vec<_>>();
+
+            let sum = unsafe { u64_xany_avx2_nofma_checked_sum(&x) };
+            assert_eq!(sum, Some(x.iter().sum::<u64>()));
+        }
+
+        // Values above `i64::MAX` must not be treated as negative.
+        let x = vec![u64::MAX / 16; 16];
+        let sum = unsafe { u64_xany_avx2_nofma_checked_sum(&x) };
+        assert_eq!(sum, Some((u64::MAX / 16) * 16));
+
+        let x = vec![u64::MAX / 4; 13];
+        let sum = unsafe { u64_xany_avx2_nofma_checked_sum(&x) };
+        assert_eq!(sum, None);
+    }
+
+    #[test]
+    fn test_i64_xany_checked_sum_limits() {
+        let x = [i64::MIN, 0, 0, 0, 0, 0, 0, 0];
+        let sum = unsafe { i64_xany_avx2_nofma_checked_sum(&x) };
+        assert_eq!(sum, Some(i64::MIN));
+
+        let x = [i64::MAX, 0, 0, 0, 0, 0, 0, 0, 0];
+        let sum = unsafe { i64_xany_avx2_nofma_checked_sum(&x) };
+        assert_eq!(sum, Some(i64::MAX));
+    }
+}
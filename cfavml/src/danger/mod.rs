@@ -1,164 +1,718 @@
+mod activation;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod c32_avx2_complex;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+mod f16_avx2_dot_product;
+#[cfg(feature = "half")]
+mod f16_fallback_dot_product;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_abs;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_abs_diff;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_axpby;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_blend_scalar;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_cast;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_clamp;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_clip_norm;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_conv1d;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_cosine;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_cumsum;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_dot_activation;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_dot_compensated;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_dot_f64;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_dot_product;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_euclidean;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_exp;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_find;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_flat_distance;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_float_class;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_fmadd;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_gelu;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_gemm;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_histogram;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_ieee_min_max;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_leaky_relu;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_log;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_max;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_mean_std;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_median3;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_min;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_nan_to_num;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_nearest_centroid;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_norm;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_normalize;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "rayon"))]
+mod f32_avx2_par_reduce;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_percentile;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_polyval;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_pow;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_quantize;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_recip;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_relu;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_reverse;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_round;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_segment_sum;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_select;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_softmax;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_sparse_dot;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_sqrt;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_standardize;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_strided;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_sum;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_sum_sumsq;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_summary;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_tanh;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f32_avx2_transpose;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_vector_x_value;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f32_avx2_vector_x_vector;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_axpby;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_clip_norm;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_cosine;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_dot_activation;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_dot_product;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_euclidean;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_flat_distance;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_fmadd;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_max;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_min;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_nearest_centroid;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_norm;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_normalize;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "nightly",
+    feature = "rayon"
+))]
+mod f32_avx512_par_reduce;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_polyval;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_reverse;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_sum;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f32_avx512_sum_sumsq;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_vector_x_value;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f32_avx512_vector_x_vector;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_abs;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_abs_diff;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_axpby;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_clamp;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_clip_norm;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_cosine;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_dot_activation;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_dot_product;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_euclidean;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_exp;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_find;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_fmadd;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_ieee_min_max;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_log;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_max;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_min;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_norm;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_reverse;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_round;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_sqrt;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_sum;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_sum_sumsq;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod f64_avx2_tanh;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_vector_x_value;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 mod f64_avx2_vector_x_vector;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f64_avx512_axpby;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f64_avx512_clip_norm;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_cosine;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f64_avx512_dot_activation;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_dot_product;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_euclidean;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f64_avx512_fmadd;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_max;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_min;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_norm;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f64_avx512_reverse;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_sum;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod f64_avx512_sum_sumsq;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_vector_x_value;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 mod f64_avx512_vector_x_vector;
+mod generic_fallback_abs;
+mod generic_fallback_abs_diff;
+mod generic_fallback_axpby;
+mod generic_fallback_blend_scalar;
+mod generic_fallback_cast;
+mod generic_fallback_checked_sum;
+mod generic_fallback_checked_vector_x_vector;
+mod generic_fallback_clamp;
+mod generic_fallback_clip_norm;
+mod generic_fallback_complex;
+mod generic_fallback_conv1d;
 mod generic_fallback_cosine;
+mod generic_fallback_cumsum;
+mod generic_fallback_dot_activation;
+mod generic_fallback_dot_compensated;
+mod generic_fallback_dot_f64;
 mod generic_fallback_dot_product;
 mod generic_fallback_euclidean;
+mod generic_fallback_exp;
+mod generic_fallback_find;
+mod generic_fallback_flat_distance;
+mod generic_fallback_float_class;
+mod generic_fallback_fmadd;
+mod generic_fallback_gelu;
+mod generic_fallback_gemm;
+mod generic_fallback_histogram;
+mod generic_fallback_ieee_min_max;
+mod generic_fallback_leaky_relu;
+mod generic_fallback_log;
 mod generic_fallback_max;
+mod generic_fallback_mean_std;
+mod generic_fallback_median3;
 mod generic_fallback_min;
+mod generic_fallback_nan_to_num;
+mod generic_fallback_narrow_int;
+mod generic_fallback_nearest_centroid;
+mod generic_fallback_norm;
+mod generic_fallback_normalize;
+mod generic_fallback_overflow;
+#[cfg(feature = "rayon")]
+mod generic_fallback_par_reduce;
+mod generic_fallback_percentile;
+mod generic_fallback_polyval;
+mod generic_fallback_pow;
+mod generic_fallback_quantize;
+mod generic_fallback_recip;
+mod generic_fallback_reduce;
+mod generic_fallback_relu;
+mod generic_fallback_reverse;
+mod generic_fallback_rotate;
+mod generic_fallback_round;
+mod generic_fallback_saturating_sum;
+mod generic_fallback_segment_sum;
+mod generic_fallback_select;
+mod generic_fallback_softmax;
+mod generic_fallback_sparse_dot;
+mod generic_fallback_sqrt;
+mod generic_fallback_standardize;
+mod generic_fallback_strided;
 mod generic_fallback_sum;
+mod generic_fallback_sum_sumsq;
+mod generic_fallback_summary;
+mod generic_fallback_tanh;
+mod generic_fallback_tile;
+mod generic_fallback_transpose;
+mod generic_fallback_usize_checked_sum;
 mod generic_fallback_vector_x_value;
 mod generic_fallback_vector_x_vector;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod i16_avx512bw_max;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod i16_avx512bw_min;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod i16_avx512bw_sum;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod i16_avx512bw_vector_x_vector;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod i32_avx2_abs;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod i32_avx2_cast;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod i32_avx2_checked_vector_x_vector;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod i32_avx2_clamp;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod i64_avx2_checked_sum;
+mod overflow;
+mod rounding;
+mod summary;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod u32_avx2_checked_vector_x_vector;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod u32_avx2_saturating_sum;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod u8_avx2_abs_diff;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+mod u8_avx2_sad;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod u8_avx512bw_max;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod u8_avx512bw_min;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod u8_avx512bw_sad;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod u8_avx512bw_sum;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+mod u8_avx512bw_vector_x_vector;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_pointer_width = "64"
+))]
+mod usize_avx2_checked_sum;
 mod utils;
 
 pub(crate) use utils::*;
 
+pub use self::activation::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::c32_avx2_complex::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "half"))]
+pub use self::f16_avx2_dot_product::*;
+#[cfg(feature = "half")]
+pub use self::f16_fallback_dot_product::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_abs::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_abs_diff::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_axpby::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_blend_scalar::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_cast::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_clamp::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_clip_norm::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_conv1d::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_cosine::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_cumsum::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_dot_activation::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_dot_compensated::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_dot_f64::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_dot_product::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_euclidean::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_exp::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_find::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_flat_distance::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_float_class::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_fmadd::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_gelu::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_gemm::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_histogram::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_ieee_min_max::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_leaky_relu::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_log::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_max::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_mean_std::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_median3::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_min::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_nan_to_num::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_nearest_centroid::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_norm::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_normalize::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "rayon"))]
+pub use self::f32_avx2_par_reduce::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_percentile::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_polyval::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_pow::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_quantize::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_recip::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_relu::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_reverse::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_round::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_segment_sum::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_select::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_softmax::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_sparse_dot::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_sqrt::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_standardize::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_strided::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_sum::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_sum_sumsq::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_summary::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_tanh::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f32_avx2_transpose::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_vector_x_value::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f32_avx2_vector_x_vector::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_axpby::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_clip_norm::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_cosine::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_dot_activation::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_dot_product::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_euclidean::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_flat_distance::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_fmadd::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_max::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_min::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_nearest_centroid::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_norm::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_normalize::*;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    feature = "nightly",
+    feature = "rayon"
+))]
+pub use self::f32_avx512_par_reduce::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_polyval::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_reverse::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_sum::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f32_avx512_sum_sumsq::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_vector_x_value::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f32_avx512_vector_x_vector::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_abs::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_abs_diff::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_axpby::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_clamp::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_clip_norm::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_cosine::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_dot_activation::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_dot_product::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_euclidean::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_exp::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_find::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_fmadd::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_ieee_min_max::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_log::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_max::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_min::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_norm::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_reverse::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_round::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_sqrt::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_sum::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_sum_sumsq::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::f64_avx2_tanh::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_vector_x_value::*;
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 pub use self::f64_avx2_vector_x_vector::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f64_avx512_axpby::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f64_avx512_clip_norm::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_cosine::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f64_avx512_dot_activation::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_dot_product::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_euclidean::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f64_avx512_fmadd::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_max::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_min::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_norm::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f64_avx512_reverse::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_sum::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::f64_avx512_sum_sumsq::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_vector_x_value::*;
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 pub use self::f64_avx512_vector_x_vector::*;
+pub use self::generic_fallback_abs::*;
+pub use self::generic_fallback_abs_diff::*;
+pub use self::generic_fallback_axpby::*;
+pub use self::generic_fallback_blend_scalar::*;
+pub use self::generic_fallback_cast::*;
+pub use self::generic_fallback_checked_sum::*;
+pub use self::generic_fallback_checked_vector_x_vector::*;
+pub use self::generic_fallback_clamp::*;
+pub use self::generic_fallback_clip_norm::*;
+pub use self::generic_fallback_complex::*;
+pub use self::generic_fallback_conv1d::*;
 pub use self::generic_fallback_cosine::*;
+pub use self::generic_fallback_cumsum::*;
+pub use self::generic_fallback_dot_activation::*;
+pub use self::generic_fallback_dot_compensated::*;
+pub use self::generic_fallback_dot_f64::*;
 pub use self::generic_fallback_dot_product::*;
 pub use self::generic_fallback_euclidean::*;
+pub use self::generic_fallback_exp::*;
+pub use self::generic_fallback_find::*;
+pub use self::generic_fallback_flat_distance::*;
+pub use self::generic_fallback_float_class::*;
+pub use self::generic_fallback_fmadd::*;
+pub use self::generic_fallback_gelu::*;
+pub use self::generic_fallback_gemm::*;
+pub use self::generic_fallback_histogram::*;
+pub use self::generic_fallback_ieee_min_max::*;
+pub use self::generic_fallback_leaky_relu::*;
+pub use self::generic_fallback_log::*;
 pub use self::generic_fallback_max::*;
+pub use self::generic_fallback_mean_std::*;
+pub use self::generic_fallback_median3::*;
 pub use self::generic_fallback_min::*;
+pub use self::generic_fallback_nan_to_num::*;
+pub use self::generic_fallback_narrow_int::*;
+pub use self::generic_fallback_nearest_centroid::*;
+pub use self::generic_fallback_norm::*;
+pub use self::generic_fallback_normalize::*;
+pub use self::generic_fallback_overflow::*;
+#[cfg(feature = "rayon")]
+pub use self::generic_fallback_par_reduce::*;
+pub use self::generic_fallback_percentile::*;
+pub use self::generic_fallback_polyval::*;
+pub use self::generic_fallback_pow::*;
+pub use self::generic_fallback_quantize::*;
+pub use self::generic_fallback_recip::*;
+pub use self::generic_fallback_reduce::*;
+pub use self::generic_fallback_relu::*;
+pub use self::generic_fallback_reverse::*;
+pub use self::generic_fallback_rotate::*;
+pub use self::generic_fallback_round::*;
+pub use self::generic_fallback_saturating_sum::*;
+pub use self::generic_fallback_segment_sum::*;
+pub use self::generic_fallback_select::*;
+pub use self::generic_fallback_softmax::*;
+pub use self::generic_fallback_sparse_dot::*;
+pub use self::generic_fallback_sqrt::*;
+pub use self::generic_fallback_standardize::*;
+pub use self::generic_fallback_strided::*;
 pub use self::generic_fallback_sum::*;
+pub use self::generic_fallback_sum_sumsq::*;
+pub use self::generic_fallback_summary::*;
+pub use self::generic_fallback_tanh::*;
+pub use self::generic_fallback_tile::*;
+pub use self::generic_fallback_transpose::*;
+pub use self::generic_fallback_usize_checked_sum::*;
 pub use self::generic_fallback_vector_x_value::*;
 pub use self::generic_fallback_vector_x_vector::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::i16_avx512bw_max::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::i16_avx512bw_min::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::i16_avx512bw_sum::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::i16_avx512bw_vector_x_vector::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::i32_avx2_abs::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::i32_avx2_cast::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::i32_avx2_checked_vector_x_vector::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::i32_avx2_clamp::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::i64_avx2_checked_sum::*;
+pub use self::overflow::*;
+pub use self::rounding::*;
+pub use self::summary::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::u32_avx2_checked_vector_x_vector::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::u32_avx2_saturating_sum::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::u8_avx2_abs_diff::*;
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+pub use self::u8_avx2_sad::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::u8_avx512bw_max::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::u8_avx512bw_min::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::u8_avx512bw_sad::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::u8_avx512bw_sum::*;
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+pub use self::u8_avx512bw_vector_x_vector::*;
+#[cfg(all(
+    any(target_arch = "x86", target_arch = "x86_64"),
+    target_pointer_width = "64"
+))]
+pub use self::usize_avx2_checked_sum::*;
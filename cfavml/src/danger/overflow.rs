@@ -0,0 +1,27 @@
+use core::fmt;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// Returned by the checked integer routines when an element overflows its type.
+pub struct OverflowError {
+    /// The index of the first element which overflowed.
+    pub index: usize,
+}
+
+impl fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Arithmetic overflow at index {}", self.index)
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overflow_error_display() {
+        let error = OverflowError { index: 3 };
+        assert_eq!(error.to_string(), "Arithmetic overflow at index 3");
+    }
+}
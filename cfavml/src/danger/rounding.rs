@@ -0,0 +1,78 @@
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+/// The direction values are rounded to an integer in by the rounding and converting
+/// routines.
+///
+/// Every backend rounds exactly as selected, the SIMD routines map each mode onto the
+/// matching hardware rounding control and the fallback routines replicate it with
+/// [RoundingMode::round], so results are identical across backends.
+pub enum RoundingMode {
+    #[default]
+    /// Rounds to the nearest integer, values exactly half way between two integers
+    /// are rounded to the nearest even integer, i.e. `0.5 -> 0` and `1.5 -> 2`.
+    NearestEven,
+    /// Rounds towards zero, discarding the fractional part, i.e. `-1.7 -> -1`.
+    TowardZero,
+    /// Rounds towards positive infinity, i.e. `1.2 -> 2` and `-1.7 -> -1`.
+    TowardPositive,
+    /// Rounds towards negative infinity, i.e. `1.7 -> 1` and `-1.2 -> -2`.
+    TowardNegative,
+}
+
+impl RoundingMode {
+    #[inline]
+    /// Rounds a single value with this mode, `NaN` and infinite values are returned
+    /// unchanged.
+    pub fn round(self, x: f32) -> f32 {
+        match self {
+            RoundingMode::NearestEven => x.round_ties_even(),
+            RoundingMode::TowardZero => x.trunc(),
+            RoundingMode::TowardPositive => x.ceil(),
+            RoundingMode::TowardNegative => x.floor(),
+        }
+    }
+
+    #[inline]
+    /// Rounds a single `f64` value with this mode, `NaN` and infinite values are
+    /// returned unchanged.
+    pub fn round_f64(self, x: f64) -> f64 {
+        match self {
+            RoundingMode::NearestEven => x.round_ties_even(),
+            RoundingMode::TowardZero => x.trunc(),
+            RoundingMode::TowardPositive => x.ceil(),
+            RoundingMode::TowardNegative => x.floor(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rounding_mode_round() {
+        let x = [-2.5f32, -1.7, -0.5, 0.5, 1.2, 1.5, 2.5];
+        let round = |mode: RoundingMode| x.map(|v| mode.round(v));
+
+        assert_eq!(
+            round(RoundingMode::NearestEven),
+            [-2.0, -2.0, -0.0, 0.0, 1.0, 2.0, 2.0]
+        );
+        assert_eq!(
+            round(RoundingMode::TowardZero),
+            [-2.0, -1.0, -0.0, 0.0, 1.0, 1.0, 2.0]
+        );
+        assert_eq!(
+            round(RoundingMode::TowardPositive),
+            [-2.0, -1.0, -0.0, 1.0, 2.0, 2.0, 3.0]
+        );
+        assert_eq!(
+            round(RoundingMode::TowardNegative),
+            [-3.0, -2.0, -1.0, 0.0, 1.0, 1.0, 2.0]
+        );
+        assert_eq!(
+            [-2.5f64, 1.5, 2.5].map(|v| RoundingMode::NearestEven.round_f64(v)),
+            [-2.0, 2.0, 2.0]
+        );
+        assert_eq!(RoundingMode::default(), RoundingMode::NearestEven);
+    }
+}
@@ -0,0 +1,19 @@
+#[derive(Debug, Copy, Clone, PartialEq)]
+/// The common statistics of a vector, computed together in a single pass over it by
+/// the `summary` routines.
+///
+/// The summary of an empty vector has a `sum` of `0.0`, a `min` of `+inf`, a `max` of
+/// `-inf`, a `count` of `0` and a `mean` of `NaN`. These are the identities of each
+/// statistic, so they can be told apart from any non-empty input by `count` alone.
+pub struct Summary<T> {
+    /// The total of all elements.
+    pub sum: T,
+    /// The smallest element.
+    pub min: T,
+    /// The largest element.
+    pub max: T,
+    /// The number of elements.
+    pub count: usize,
+    /// The arithmetic mean of the elements, `sum / count`.
+    pub mean: T,
+}
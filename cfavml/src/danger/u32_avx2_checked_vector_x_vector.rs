@@ -0,0 +1,248 @@
+use core::arch::x86_64::*;
+
+use crate::danger::OverflowError;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes `x[i] + y[i]` element wise, writing the result to `x` and returning an
+/// [OverflowError] with the index of the first element to overflow.
+///
+/// ```py
+/// D: int
+/// x: [u32; D]
+/// y: [u32; D]
+///
+/// for i in 0..D:
+///     if overflows(x[i] + y[i]):
+///         return OverflowError(i)
+///     x[i] = x[i] + y[i]
+/// ```
+///
+/// Overflow is detected by comparing the wrapped result against `x`, an addition
+/// overflows when the result is less than `x`.
+///
+/// When an overflow occurs every element before the returned index has been updated
+/// and every element from it onwards is left unchanged.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u32_xany_avx2_nofma_checked_add_vertical(
+    x: &mut [u32],
+    y: &[u32],
+) -> Result<(), OverflowError> {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_mut_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let [x1, x2, x3, x4] =
+            [0, 1, 2, 3].map(|k| _mm256_loadu_si256(x_ptr.add(i + k * 8).cast()));
+        let [y1, y2, y3, y4] =
+            [0, 1, 2, 3].map(|k| _mm256_loadu_si256(y_ptr.add(i + k * 8).cast()));
+
+        let r1 = _mm256_add_epi32(x1, y1);
+        let r2 = _mm256_add_epi32(x2, y2);
+        let r3 = _mm256_add_epi32(x3, y3);
+        let r4 = _mm256_add_epi32(x4, y4);
+
+        let overflow = _mm256_or_si256(
+            _mm256_or_si256(add_overflow(x1, y1, r1), add_overflow(x2, y2, r2)),
+            _mm256_or_si256(add_overflow(x3, y3, r3), add_overflow(x4, y4, r4)),
+        );
+        // Leave the block to the scalar loop which finds the exact index.
+        if _mm256_movemask_ps(_mm256_castsi256_ps(overflow)) != 0 {
+            break;
+        }
+
+        _mm256_storeu_si256(x_ptr.add(i).cast(), r1);
+        _mm256_storeu_si256(x_ptr.add(i + 8).cast(), r2);
+        _mm256_storeu_si256(x_ptr.add(i + 16).cast(), r3);
+        _mm256_storeu_si256(x_ptr.add(i + 24).cast(), r4);
+
+        i += 32;
+    }
+
+    while i < len {
+        let x = x_ptr.add(i);
+        *x = (*x)
+            .checked_add(*y_ptr.add(i))
+            .ok_or(OverflowError { index: i })?;
+
+        i += 1;
+    }
+
+    Ok(())
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes `x[i] - y[i]` element wise, writing the result to `x` and returning an
+/// [OverflowError] with the index of the first element to overflow.
+///
+/// ```py
+/// D: int
+/// x: [u32; D]
+/// y: [u32; D]
+///
+/// for i in 0..D:
+///     if overflows(x[i] - y[i]):
+///         return OverflowError(i)
+///     x[i] = x[i] - y[i]
+/// ```
+///
+/// Overflow is detected with an unsigned comparison, a subtraction overflows when
+/// `y` is greater than `x`.
+///
+/// When an overflow occurs every element before the returned index has been updated
+/// and every element from it onwards is left unchanged.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u32_xany_avx2_nofma_checked_sub_vertical(
+    x: &mut [u32],
+    y: &[u32],
+) -> Result<(), OverflowError> {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 32;
+
+    let x_ptr = x.as_mut_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let [x1, x2, x3, x4] =
+            [0, 1, 2, 3].map(|k| _mm256_loadu_si256(x_ptr.add(i + k * 8).cast()));
+        let [y1, y2, y3, y4] =
+            [0, 1, 2, 3].map(|k| _mm256_loadu_si256(y_ptr.add(i + k * 8).cast()));
+
+        let r1 = _mm256_sub_epi32(x1, y1);
+        let r2 = _mm256_sub_epi32(x2, y2);
+        let r3 = _mm256_sub_epi32(x3, y3);
+        let r4 = _mm256_sub_epi32(x4, y4);
+
+        let overflow = _mm256_or_si256(
+            _mm256_or_si256(sub_overflow(x1, y1, r1), sub_overflow(x2, y2, r2)),
+            _mm256_or_si256(sub_overflow(x3, y3, r3), sub_overflow(x4, y4, r4)),
+        );
+        // Leave the block to the scalar loop which finds the exact index.
+        if _mm256_movemask_ps(_mm256_castsi256_ps(overflow)) != 0 {
+            break;
+        }
+
+        _mm256_storeu_si256(x_ptr.add(i).cast(), r1);
+        _mm256_storeu_si256(x_ptr.add(i + 8).cast(), r2);
+        _mm256_storeu_si256(x_ptr.add(i + 16).cast(), r3);
+        _mm256_storeu_si256(x_ptr.add(i + 24).cast(), r4);
+
+        i += 32;
+    }
+
+    while i < len {
+        let x = x_ptr.add(i);
+        *x = (*x)
+            .checked_sub(*y_ptr.add(i))
+            .ok_or(OverflowError { index: i })?;
+
+        i += 1;
+    }
+
+    Ok(())
+}
+
+#[inline(always)]
+/// Sets every bit of each lane where `x + y` wrapped, which is when the result is
+/// less than `x`.
+unsafe fn add_overflow(x: __m256i, _y: __m256i, result: __m256i) -> __m256i {
+    let no_overflow = _mm256_cmpeq_epi32(_mm256_max_epu32(x, result), result);
+    _mm256_xor_si256(no_overflow, _mm256_set1_epi32(-1))
+}
+
+#[inline(always)]
+/// Sets every bit of each lane where `x - y` wrapped, which is when `y` is greater
+/// than `x`.
+unsafe fn sub_overflow(x: __m256i, y: __m256i, _result: __m256i) -> __m256i {
+    let no_overflow = _mm256_cmpeq_epi32(_mm256_max_epu32(x, y), x);
+    _mm256_xor_si256(no_overflow, _mm256_set1_epi32(-1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_checked_add_vertical() {
+        for len in [0, 5, 32, 531] {
+            let (x, y) = get_sample_vectors::<u32>(len);
+            // Halve the values so no element overflows.
+            let mut x = x.into_iter().map(|v| v / 2).collect::<Vec<_>>();
+            let y = y.into_iter().map(|v| v / 2).collect::<Vec<_>>();
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(a, b)| a + b)
+                .collect::<Vec<_>>();
+
+            unsafe { u32_xany_avx2_nofma_checked_add_vertical(&mut x, &y).unwrap() };
+            assert_eq!(x, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_checked_sub_vertical() {
+        for len in [0, 5, 32, 531] {
+            let (x, y) = get_sample_vectors::<u32>(len);
+            let mut x = x
+                .into_iter()
+                .map(|v| v / 2 + u32::MAX / 4)
+                .collect::<Vec<_>>();
+            let y = y.into_iter().map(|v| v / 4).collect::<Vec<_>>();
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(a, b)| a - b)
+                .collect::<Vec<_>>();
+
+            unsafe { u32_xany_avx2_nofma_checked_sub_vertical(&mut x, &y).unwrap() };
+            assert_eq!(x, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_checked_overflow_index() {
+        for pos in [0, 7, 31, 32, 100, 130] {
+            let mut x = vec![1u32; 131];
+            let mut y = vec![2u32; 131];
+            x[pos] = u32::MAX;
+
+            let error = unsafe { u32_xany_avx2_nofma_checked_add_vertical(&mut x, &y) };
+            assert_eq!(error, Err(OverflowError { index: pos }));
+            assert!(x[..pos].iter().all(|v| *v == 3));
+            assert_eq!(x[pos], u32::MAX);
+            assert!(x[pos + 1..].iter().all(|v| *v == 1));
+
+            let mut x = vec![3u32; 131];
+            y[pos] = u32::MAX;
+
+            let error = unsafe { u32_xany_avx2_nofma_checked_sub_vertical(&mut x, &y) };
+            assert_eq!(error, Err(OverflowError { index: pos }));
+            assert!(x[..pos].iter().all(|v| *v == 3 - 2));
+            assert!(x[pos..].iter().all(|v| *v == 3));
+        }
+    }
+}
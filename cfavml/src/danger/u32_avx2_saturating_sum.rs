@@ -0,0 +1,116 @@
+use core::arch::x86_64::*;
+use core::mem;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sums all elements of the vector, saturating at `u32::MAX` rather than
+/// overflowing.
+///
+/// ```py
+/// D: int
+/// total: u32
+/// x: [u32; D]
+///
+/// for i in 0..D:
+///     total = min(total + x[i], u32.MAX)
+///
+/// return total
+/// ```
+///
+/// This is intended for counters where "at least `u32::MAX`" is an acceptable answer,
+/// unlike the other integer sums the total is neither widened, which returns the exact
+/// total in a larger type, nor checked, which reports overflow as `None`. As every
+/// element is non-negative the result is exactly `min(sum(x), u32::MAX)` regardless of
+/// the order the elements are added in.
+///
+/// AVX2 has no saturating 32-bit add, lanes which wrap around are detected by the sum
+/// being less than the value added to it and are set to `u32::MAX`.
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u32_xany_avx2_nofma_saturating_sum(x: &[u32]) -> u32 {
+    let len = x.len();
+    let offset_from = len % 16;
+    let x_ptr = x.as_ptr();
+
+    let mut acc1 = _mm256_setzero_si256();
+    let mut acc2 = _mm256_setzero_si256();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_si256(x_ptr.add(i).cast());
+        let x2 = _mm256_loadu_si256(x_ptr.add(i + 8).cast());
+
+        acc1 = saturating_add_epu32(acc1, x1);
+        acc2 = saturating_add_epu32(acc2, x2);
+
+        i += 16;
+    }
+
+    if i + 8 <= len {
+        let x = _mm256_loadu_si256(x_ptr.add(i).cast());
+        acc1 = saturating_add_epu32(acc1, x);
+
+        i += 8;
+    }
+
+    let lanes = mem::transmute::<__m256i, [u32; 8]>(saturating_add_epu32(acc1, acc2));
+    let mut total = lanes.into_iter().fold(0, u32::saturating_add);
+
+    while i < len {
+        total = total.saturating_add(*x_ptr.add(i));
+        i += 1;
+    }
+
+    total
+}
+
+#[inline(always)]
+unsafe fn saturating_add_epu32(a: __m256i, b: __m256i) -> __m256i {
+    let sum = _mm256_add_epi32(a, b);
+    // The addition wrapped if the sum is less than `a`, i.e. `max(sum, a) != sum`.
+    let wrapped = _mm256_xor_si256(
+        _mm256_cmpeq_epi32(_mm256_max_epu32(sum, a), sum),
+        _mm256_set1_epi32(-1),
+    );
+    _mm256_or_si256(sum, wrapped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_u32_xany_saturating_sum() {
+        for len in [0, 3, 8, 16, 531] {
+            let (x, _) = get_sample_vectors::<u32>(len);
+            // Shift the values down so the total fits.
+            let x = x.into_iter().map(|v| v >> 12).collect::<Vec<_>>();
+
+            let sum = unsafe { u32_xany_avx2_nofma_saturating_sum(&x) };
+            assert_eq!(sum, x.iter().sum::<u32>());
+        }
+    }
+
+    #[test]
+    fn test_u32_xany_saturating_sum_saturates() {
+        for len in [3, 8, 16, 531] {
+            let (x, _) = get_sample_vectors::<u32>(len);
+            let expected = x
+                .iter()
+                .map(|v| *v as u64)
+                .sum::<u64>()
+                .min(u32::MAX as u64);
+
+            let sum = unsafe { u32_xany_avx2_nofma_saturating_sum(&x) };
+            assert_eq!(sum as u64, expected);
+        }
+
+        let x = [u32::MAX, 1, 0, 0, 0, 0, 0, 0, u32::MAX, 5];
+        let sum = unsafe { u32_xany_avx2_nofma_saturating_sum(&x) };
+        assert_eq!(sum, u32::MAX);
+    }
+}
@@ -0,0 +1,91 @@
+use core::arch::x86_64::*;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the absolute difference `|x[i] - y[i]|` element wise, writing the result
+/// to `x`.
+///
+/// ```py
+/// D: int
+/// x: [u8; D]
+/// y: [u8; D]
+///
+/// for i in 0..D:
+///     x[i] = max(x[i], y[i]) - min(x[i], y[i])
+/// ```
+///
+/// Unlike a `sub` followed by an `abs`, the result never wraps.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u8_xany_avx2_nofma_abs_diff_vertical(x: &mut [u8], y: &[u8]) {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 128;
+
+    let x_ptr = x.as_mut_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let [x1, x2, x3, x4] =
+            [0, 1, 2, 3].map(|k| _mm256_loadu_si256(x_ptr.add(i + k * 32).cast()));
+        let [y1, y2, y3, y4] =
+            [0, 1, 2, 3].map(|k| _mm256_loadu_si256(y_ptr.add(i + k * 32).cast()));
+
+        _mm256_storeu_si256(x_ptr.add(i).cast(), abs_diff_epu8(x1, y1));
+        _mm256_storeu_si256(x_ptr.add(i + 32).cast(), abs_diff_epu8(x2, y2));
+        _mm256_storeu_si256(x_ptr.add(i + 64).cast(), abs_diff_epu8(x3, y3));
+        _mm256_storeu_si256(x_ptr.add(i + 96).cast(), abs_diff_epu8(x4, y4));
+
+        i += 128;
+    }
+
+    while i < (len - (len % 32)) {
+        let x = _mm256_loadu_si256(x_ptr.add(i).cast());
+        let y = _mm256_loadu_si256(y_ptr.add(i).cast());
+        _mm256_storeu_si256(x_ptr.add(i).cast(), abs_diff_epu8(x, y));
+
+        i += 32;
+    }
+
+    while i < len {
+        let x = x_ptr.add(i);
+        *x = (*x).abs_diff(*y_ptr.add(i));
+
+        i += 1;
+    }
+}
+
+#[inline(always)]
+unsafe fn abs_diff_epu8(x: __m256i, y: __m256i) -> __m256i {
+    // One of the saturating subtractions is always zero.
+    _mm256_or_si256(_mm256_subs_epu8(x, y), _mm256_subs_epu8(y, x))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_abs_diff_vertical() {
+        for len in [0, 3, 32, 129, 531] {
+            let (x, y) = get_sample_vectors::<u8>(len);
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(a, b)| a.abs_diff(*b))
+                .collect::<Vec<_>>();
+
+            let mut result = x.clone();
+            unsafe { u8_xany_avx2_nofma_abs_diff_vertical(&mut result, &y) };
+            assert_eq!(result, expected);
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use core::arch::x86_64::*;
+use core::mem;
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Computes the sum of absolute differences between `x` and `y`, widening to `u64`
+/// so the total cannot overflow.
+///
+/// ```py
+/// D: int
+/// total: u64
+/// x: [u8; D]
+/// y: [u8; D]
+///
+/// for i in 0..D:
+///     total = total + abs(x[i] - y[i])
+/// ```
+///
+/// Each pair of registers is reduced with a single `vpsadbw`, producing four partial
+/// `u64` sums per 32 elements which are accumulated in `u64` lanes.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u8_xany_avx2_nofma_sad(x: &[u8], y: &[u8]) -> u64 {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 128;
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let mut acc1 = _mm256_setzero_si256();
+    let mut acc2 = _mm256_setzero_si256();
+    let mut acc3 = _mm256_setzero_si256();
+    let mut acc4 = _mm256_setzero_si256();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm256_loadu_si256(x_ptr.add(i).cast());
+        let y1 = _mm256_loadu_si256(y_ptr.add(i).cast());
+        let x2 = _mm256_loadu_si256(x_ptr.add(i + 32).cast());
+        let y2 = _mm256_loadu_si256(y_ptr.add(i + 32).cast());
+        let x3 = _mm256_loadu_si256(x_ptr.add(i + 64).cast());
+        let y3 = _mm256_loadu_si256(y_ptr.add(i + 64).cast());
+        let x4 = _mm256_loadu_si256(x_ptr.add(i + 96).cast());
+        let y4 = _mm256_loadu_si256(y_ptr.add(i + 96).cast());
+
+        acc1 = _mm256_add_epi64(acc1, _mm256_sad_epu8(x1, y1));
+        acc2 = _mm256_add_epi64(acc2, _mm256_sad_epu8(x2, y2));
+        acc3 = _mm256_add_epi64(acc3, _mm256_sad_epu8(x3, y3));
+        acc4 = _mm256_add_epi64(acc4, _mm256_sad_epu8(x4, y4));
+
+        i += 128;
+    }
+
+    while i < (len - (len % 32)) {
+        let x = _mm256_loadu_si256(x_ptr.add(i).cast());
+        let y = _mm256_loadu_si256(y_ptr.add(i).cast());
+        acc1 = _mm256_add_epi64(acc1, _mm256_sad_epu8(x, y));
+
+        i += 32;
+    }
+
+    acc1 = _mm256_add_epi64(acc1, acc2);
+    acc3 = _mm256_add_epi64(acc3, acc4);
+    let lanes = mem::transmute::<__m256i, [u64; 4]>(_mm256_add_epi64(acc1, acc3));
+
+    let mut total = lanes.iter().sum::<u64>();
+    while i < len {
+        total += (*x_ptr.add(i)).abs_diff(*y_ptr.add(i)) as u64;
+
+        i += 1;
+    }
+
+    total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_sad() {
+        for len in [0, 31, 32, 531, 4099] {
+            let (x, y) = get_sample_vectors::<u8>(len);
+            let sad = unsafe { u8_xany_avx2_nofma_sad(&x, &y) };
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(a, b)| a.abs_diff(*b) as u64)
+                .sum::<u64>();
+            assert_eq!(sad, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_sad_extremes() {
+        let x = vec![255; 300];
+        let y = vec![0; 300];
+        assert_eq!(unsafe { u8_xany_avx2_nofma_sad(&x, &y) }, 255 * 300);
+        assert_eq!(unsafe { u8_xany_avx2_nofma_sad(&y, &x) }, 255 * 300);
+    }
+}
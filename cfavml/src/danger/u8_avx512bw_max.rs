@@ -0,0 +1,81 @@
+use core::arch::x86_64::*;
+use core::mem;
+
+use crate::danger::load_one_variable_size_avx512_epi8;
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes the horizontal maximum of the given vector that is `[u8; N]`.
+///
+/// An empty vector returns `u8::MIN`.
+///
+/// # Safety
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u8_xany_avx512bw_nofma_max_horizontal(arr: &[u8]) -> u8 {
+    let len = arr.len();
+    let offset_from = len % 256;
+    let arr = arr.as_ptr();
+
+    let identity = _mm512_set1_epi8(u8::MIN as i8);
+    let mut acc1 = identity;
+    let mut acc2 = identity;
+    let mut acc3 = identity;
+    let mut acc4 = identity;
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm512_loadu_si512(arr.add(i).cast());
+        let x2 = _mm512_loadu_si512(arr.add(i + 64).cast());
+        let x3 = _mm512_loadu_si512(arr.add(i + 128).cast());
+        let x4 = _mm512_loadu_si512(arr.add(i + 192).cast());
+
+        acc1 = _mm512_max_epu8(acc1, x1);
+        acc2 = _mm512_max_epu8(acc2, x2);
+        acc3 = _mm512_max_epu8(acc3, x3);
+        acc4 = _mm512_max_epu8(acc4, x4);
+
+        i += 256;
+    }
+
+    // Lanes beyond the end of the vector are filled with the identity value
+    // so they never affect the result.
+    while i < len {
+        let x = load_one_variable_size_avx512_epi8(
+            arr.add(i).cast::<i8>(),
+            len - i,
+            identity,
+        );
+        acc1 = _mm512_max_epu8(acc1, x);
+
+        i += 64;
+    }
+
+    acc1 = _mm512_max_epu8(acc1, acc2);
+    acc3 = _mm512_max_epu8(acc3, acc4);
+    acc1 = _mm512_max_epu8(acc1, acc3);
+
+    let values = mem::transmute::<__m512i, [u8; 64]>(acc1);
+    values.into_iter().fold(u8::MIN, u8::max)
+}
+
+#[cfg(all(test, target_feature = "avx512bw"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_max_horizontal() {
+        for len in [1, 63, 64, 531, 4099] {
+            let (x, _) = get_sample_vectors::<u8>(len);
+            let res = unsafe { u8_xany_avx512bw_nofma_max_horizontal(&x) };
+            assert_eq!(res, x.iter().copied().max().unwrap());
+        }
+
+        assert_eq!(
+            unsafe { u8_xany_avx512bw_nofma_max_horizontal(&[]) },
+            u8::MIN
+        );
+    }
+}
@@ -0,0 +1,99 @@
+use core::arch::x86_64::*;
+
+use crate::danger::load_one_variable_size_avx512_epi8;
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes the sum of absolute differences between `x` and `y`, widening to `u64`
+/// so the total cannot overflow.
+///
+/// ```py
+/// D: int
+/// total: u64
+/// x: [u8; D]
+/// y: [u8; D]
+///
+/// for i in 0..D:
+///     total = total + abs(x[i] - y[i])
+/// ```
+///
+/// Each pair of registers is reduced with a single `vpsadbw`, producing eight partial
+/// `u64` sums per 64 elements which are accumulated in `u64` lanes.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u8_xany_avx512bw_nofma_sad(x: &[u8], y: &[u8]) -> u64 {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 256;
+    let x_ptr = x.as_ptr();
+    let y_ptr = y.as_ptr();
+
+    let zero = _mm512_setzero_si512();
+    let mut acc1 = _mm512_setzero_si512();
+    let mut acc2 = _mm512_setzero_si512();
+    let mut acc3 = _mm512_setzero_si512();
+    let mut acc4 = _mm512_setzero_si512();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm512_loadu_si512(x_ptr.add(i).cast());
+        let y1 = _mm512_loadu_si512(y_ptr.add(i).cast());
+        let x2 = _mm512_loadu_si512(x_ptr.add(i + 64).cast());
+        let y2 = _mm512_loadu_si512(y_ptr.add(i + 64).cast());
+        let x3 = _mm512_loadu_si512(x_ptr.add(i + 128).cast());
+        let y3 = _mm512_loadu_si512(y_ptr.add(i + 128).cast());
+        let x4 = _mm512_loadu_si512(x_ptr.add(i + 192).cast());
+        let y4 = _mm512_loadu_si512(y_ptr.add(i + 192).cast());
+
+        acc1 = _mm512_add_epi64(acc1, _mm512_sad_epu8(x1, y1));
+        acc2 = _mm512_add_epi64(acc2, _mm512_sad_epu8(x2, y2));
+        acc3 = _mm512_add_epi64(acc3, _mm512_sad_epu8(x3, y3));
+        acc4 = _mm512_add_epi64(acc4, _mm512_sad_epu8(x4, y4));
+
+        i += 256;
+    }
+
+    while i < (len - (len % 64)) {
+        let x = _mm512_loadu_si512(x_ptr.add(i).cast());
+        let y = _mm512_loadu_si512(y_ptr.add(i).cast());
+        acc1 = _mm512_add_epi64(acc1, _mm512_sad_epu8(x, y));
+
+        i += 64;
+    }
+
+    if i < len {
+        let x = load_one_variable_size_avx512_epi8(x_ptr.add(i).cast(), len - i, zero);
+        let y = load_one_variable_size_avx512_epi8(y_ptr.add(i).cast(), len - i, zero);
+        acc1 = _mm512_add_epi64(acc1, _mm512_sad_epu8(x, y));
+    }
+
+    acc1 = _mm512_add_epi64(acc1, acc2);
+    acc3 = _mm512_add_epi64(acc3, acc4);
+    _mm512_reduce_add_epi64(_mm512_add_epi64(acc1, acc3)) as u64
+}
+
+#[cfg(all(test, target_feature = "avx512bw"))]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_sad() {
+        for len in [0, 63, 64, 531, 4099] {
+            let (x, y) = get_sample_vectors::<u8>(len);
+            let sad = unsafe { u8_xany_avx512bw_nofma_sad(&x, &y) };
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(a, b)| a.abs_diff(*b) as u64)
+                .sum::<u64>();
+            assert_eq!(sad, expected);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use core::arch::x86_64::*;
+
+use crate::danger::{load_one_variable_size_avx512_epi8, overflow_as_u8, U8Overflow};
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Sums all elements of the vector, widening to `u64` so the total cannot overflow.
+///
+/// ```py
+/// D: int
+/// total: u64
+/// x: [u8; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+/// ```
+///
+/// Each register is reduced with `vpsadbw` against zero, producing eight partial
+/// `u64` sums per 64 elements.
+///
+/// # Safety
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u8_xany_avx512bw_nofma_sum_horizontal(x: &[u8]) -> u64 {
+    let len = x.len();
+    let offset_from = len % 256;
+    let x_ptr = x.as_ptr();
+
+    let zero = _mm512_setzero_si512();
+    let mut acc1 = _mm512_setzero_si512();
+    let mut acc2 = _mm512_setzero_si512();
+    let mut acc3 = _mm512_setzero_si512();
+    let mut acc4 = _mm512_setzero_si512();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        let x1 = _mm512_loadu_si512(x_ptr.add(i).cast());
+        let x2 = _mm512_loadu_si512(x_ptr.add(i + 64).cast());
+        let x3 = _mm512_loadu_si512(x_ptr.add(i + 128).cast());
+        let x4 = _mm512_loadu_si512(x_ptr.add(i + 192).cast());
+
+        acc1 = _mm512_add_epi64(acc1, _mm512_sad_epu8(x1, zero));
+        acc2 = _mm512_add_epi64(acc2, _mm512_sad_epu8(x2, zero));
+        acc3 = _mm512_add_epi64(acc3, _mm512_sad_epu8(x3, zero));
+        acc4 = _mm512_add_epi64(acc4, _mm512_sad_epu8(x4, zero));
+
+        i += 256;
+    }
+
+    while i < (len - (len % 64)) {
+        let x = _mm512_loadu_si512(x_ptr.add(i).cast());
+        acc1 = _mm512_add_epi64(acc1, _mm512_sad_epu8(x, zero));
+
+        i += 64;
+    }
+
+    if i < len {
+        let x = load_one_variable_size_avx512_epi8(x_ptr.add(i).cast(), len - i, zero);
+        acc1 = _mm512_add_epi64(acc1, _mm512_sad_epu8(x, zero));
+    }
+
+    acc1 = _mm512_add_epi64(acc1, acc2);
+    acc3 = _mm512_add_epi64(acc3, acc4);
+    _mm512_reduce_add_epi64(_mm512_add_epi64(acc1, acc3)) as u64
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Sums all elements of the vector, wrapping or saturating the total as selected
+/// by the element type.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+///
+/// total = 0
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return total
+/// ```
+///
+/// The total is computed exactly by [u8_xany_avx512bw_nofma_sum_horizontal] and
+/// narrowed once at the end.
+///
+/// # Safety
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u8_xany_avx512bw_nofma_sum<T: U8Overflow>(x: &[T]) -> T {
+    T::from_widened_sum(u8_xany_avx512bw_nofma_sum_horizontal(overflow_as_u8(x)))
+}
+
+#[cfg(all(test, target_feature = "avx512bw"))]
+mod tests {
+    use core::num::{Saturating, Wrapping};
+
+    use super::*;
+    use crate::danger::u8_xany_fallback_nofma_sum;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_sum() {
+        for len in [0, 63, 64, 531, 4099] {
+            let (x, _) = get_sample_vectors::<u8>(len);
+            let sum = unsafe { u8_xany_avx512bw_nofma_sum_horizontal(&x) };
+            assert_eq!(sum, x.iter().map(|v| *v as u64).sum::<u64>());
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_overflow_sum() {
+        let (x, _) = get_sample_vectors::<u8>(547);
+        let expected = unsafe { u8_xany_fallback_nofma_sum(&x) };
+        assert_eq!(unsafe { u8_xany_avx512bw_nofma_sum(&x) }, expected);
+
+        let x = x.into_iter().map(Saturating).collect::<Vec<_>>();
+        assert_eq!(
+            unsafe { u8_xany_avx512bw_nofma_sum(&x) },
+            Saturating(u8::MAX)
+        );
+
+        let x = vec![Wrapping(200u8); 3];
+        assert_eq!(unsafe { u8_xany_avx512bw_nofma_sum(&x) }, Wrapping(88));
+    }
+}
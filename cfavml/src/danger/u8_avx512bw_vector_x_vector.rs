@@ -0,0 +1,403 @@
+use core::arch::x86_64::*;
+
+use crate::danger::{
+    copy_masked_avx512_epi8_register_to,
+    load_one_variable_size_avx512_epi8,
+    overflow_as_u8,
+    overflow_as_u8_mut,
+    U8Overflow,
+};
+
+/// Applies the given op to `256` elements of `x` and `y` writing the result to `x`.
+macro_rules! x256_op_inplace {
+    ($x:expr, $y:expr, $op:ident) => {{
+        let [x1, x2, x3, x4] =
+            [0, 1, 2, 3].map(|k| _mm512_loadu_si512($x.add(k * 64).cast()));
+        let [y1, y2, y3, y4] =
+            [0, 1, 2, 3].map(|k| _mm512_loadu_si512($y.add(k * 64).cast()));
+
+        _mm512_storeu_si512($x.cast(), $op(x1, y1));
+        _mm512_storeu_si512($x.add(64).cast(), $op(x2, y2));
+        _mm512_storeu_si512($x.add(128).cast(), $op(x3, y3));
+        _mm512_storeu_si512($x.add(192).cast(), $op(x4, y4));
+    }};
+}
+
+/// Applies the given op to the remaining elements of `x` and `y` writing the result
+/// to `x`, the final partial register is handled with masked loads and stores.
+macro_rules! execute_tail_op_inplace {
+    ($len:expr, $i:expr, $x:expr, $y:expr, $op:ident) => {{
+        while $i < ($len - ($len % 64)) {
+            let x = _mm512_loadu_si512($x.add($i).cast());
+            let y = _mm512_loadu_si512($y.add($i).cast());
+            _mm512_storeu_si512($x.add($i).cast(), $op(x, y));
+
+            $i += 64;
+        }
+
+        let remainder = $len - $i;
+        if remainder > 0 {
+            let zero = _mm512_setzero_si512();
+            let x = load_one_variable_size_avx512_epi8(
+                $x.add($i).cast::<i8>(),
+                remainder,
+                zero,
+            );
+            let y = load_one_variable_size_avx512_epi8(
+                $y.add($i).cast::<i8>(),
+                remainder,
+                zero,
+            );
+            copy_masked_avx512_epi8_register_to(
+                $x.add($i).cast::<i8>(),
+                $op(x, y),
+                remainder,
+            );
+        }
+    }};
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes `x[i] + y[i]` element wise, wrapping or saturating on overflow as
+/// selected by the element type, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// y: [T; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] + y[i]
+/// ```
+///
+/// `u8` and `Wrapping<u8>` wrap on overflow, `Saturating<u8>` dispatches to
+/// [u8_xany_avx512bw_nofma_saturating_add_vertical].
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u8_xany_avx512bw_nofma_add_vertical<T: U8Overflow>(x: &mut [T], y: &[T]) {
+    let (x, y) = (overflow_as_u8_mut(x), overflow_as_u8(y));
+    if T::SATURATING {
+        u8_xany_avx512bw_nofma_saturating_add_vertical(x, y)
+    } else {
+        wrapping_add_vertical(x, y)
+    }
+}
+
+#[inline(always)]
+unsafe fn wrapping_add_vertical(x: &mut [u8], y: &[u8]) {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 256;
+
+    let x = x.as_mut_ptr();
+    let y = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        x256_op_inplace!(x.add(i), y.add(i), _mm512_add_epi8);
+
+        i += 256;
+    }
+
+    execute_tail_op_inplace!(len, i, x, y, _mm512_add_epi8);
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes `x[i] - y[i]` element wise, wrapping or saturating on overflow as
+/// selected by the element type, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [T; D]
+/// y: [T; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] - y[i]
+/// ```
+///
+/// `u8` and `Wrapping<u8>` wrap on overflow, `Saturating<u8>` dispatches to
+/// [u8_xany_avx512bw_nofma_saturating_sub_vertical].
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u8_xany_avx512bw_nofma_sub_vertical<T: U8Overflow>(x: &mut [T], y: &[T]) {
+    let (x, y) = (overflow_as_u8_mut(x), overflow_as_u8(y));
+    if T::SATURATING {
+        u8_xany_avx512bw_nofma_saturating_sub_vertical(x, y)
+    } else {
+        wrapping_sub_vertical(x, y)
+    }
+}
+
+#[inline(always)]
+unsafe fn wrapping_sub_vertical(x: &mut [u8], y: &[u8]) {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 256;
+
+    let x = x.as_mut_ptr();
+    let y = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        x256_op_inplace!(x.add(i), y.add(i), _mm512_sub_epi8);
+
+        i += 256;
+    }
+
+    execute_tail_op_inplace!(len, i, x, y, _mm512_sub_epi8);
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes `x[i] + y[i]` element wise, saturating at the bounds of `u8`, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [u8; D]
+/// y: [u8; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] + y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u8_xany_avx512bw_nofma_saturating_add_vertical(x: &mut [u8], y: &[u8]) {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 256;
+
+    let x = x.as_mut_ptr();
+    let y = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        x256_op_inplace!(x.add(i), y.add(i), _mm512_adds_epu8);
+
+        i += 256;
+    }
+
+    execute_tail_op_inplace!(len, i, x, y, _mm512_adds_epu8);
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes `x[i] - y[i]` element wise, saturating at the bounds of `u8`, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [u8; D]
+/// y: [u8; D]
+///
+/// for i in 0..D:
+///     x[i] = x[i] - y[i]
+/// ```
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u8_xany_avx512bw_nofma_saturating_sub_vertical(x: &mut [u8], y: &[u8]) {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 256;
+
+    let x = x.as_mut_ptr();
+    let y = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        x256_op_inplace!(x.add(i), y.add(i), _mm512_subs_epu8);
+
+        i += 256;
+    }
+
+    execute_tail_op_inplace!(len, i, x, y, _mm512_subs_epu8);
+}
+
+#[target_feature(enable = "avx512f", enable = "avx512bw")]
+#[inline]
+/// Computes the absolute difference `|x[i] - y[i]|` element wise, writing the result to `x`.
+///
+/// ```py
+/// D: int
+/// x: [u8; D]
+/// y: [u8; D]
+///
+/// for i in 0..D:
+///     x[i] = max(x[i], y[i]) - min(x[i], y[i])
+/// ```
+///
+/// Unlike a `sub` followed by an `abs`, the result never wraps.
+///
+/// # Safety
+///
+/// Vectors **MUST** be the same length, otherwise this routine
+/// will become immediately UB due to out of bounds pointer accesses.
+///
+/// This method assumes AVX512BW instructions are available, if this method is executed
+/// on non-AVX512BW enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn u8_xany_avx512bw_nofma_abs_diff_vertical(x: &mut [u8], y: &[u8]) {
+    debug_assert_eq!(x.len(), y.len());
+    let len = x.len();
+    let offset_from = len % 256;
+
+    let x = x.as_mut_ptr();
+    let y = y.as_ptr();
+
+    let mut i = 0;
+    while i < (len - offset_from) {
+        x256_op_inplace!(x.add(i), y.add(i), abs_diff_epu8);
+
+        i += 256;
+    }
+
+    execute_tail_op_inplace!(len, i, x, y, abs_diff_epu8);
+}
+
+#[inline(always)]
+unsafe fn abs_diff_epu8(x: __m512i, y: __m512i) -> __m512i {
+    // One of the saturating subtractions is always zero.
+    _mm512_or_si512(_mm512_subs_epu8(x, y), _mm512_subs_epu8(y, x))
+}
+
+#[cfg(all(test, target_feature = "avx512bw"))]
+mod tests {
+    use core::num::{Saturating, Wrapping};
+
+    use super::*;
+    use crate::danger::{
+        u8_xany_fallback_nofma_add_vertical,
+        u8_xany_fallback_nofma_sub_vertical,
+    };
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_xany_nofma_add_vertical() {
+        for len in [63, 64, 531] {
+            let (mut x, y) = get_sample_vectors::<u8>(len);
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(x, y)| x.wrapping_add(*y))
+                .collect::<Vec<u8>>();
+
+            unsafe { u8_xany_avx512bw_nofma_add_vertical(&mut x, &y) };
+            assert_eq!(x, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_sub_vertical() {
+        for len in [63, 64, 531] {
+            let (mut x, y) = get_sample_vectors::<u8>(len);
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(x, y)| x.wrapping_sub(*y))
+                .collect::<Vec<u8>>();
+
+            unsafe { u8_xany_avx512bw_nofma_sub_vertical(&mut x, &y) };
+            assert_eq!(x, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_saturating_add_vertical() {
+        for len in [63, 64, 531] {
+            let (mut x, y) = get_sample_vectors::<u8>(len);
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(x, y)| x.saturating_add(*y))
+                .collect::<Vec<u8>>();
+
+            unsafe { u8_xany_avx512bw_nofma_saturating_add_vertical(&mut x, &y) };
+            assert_eq!(x, expected);
+        }
+    }
+
+    #[test]
+    fn test_xany_nofma_saturating_sub_vertical() {
+        for len in [63, 64, 531] {
+            let (mut x, y) = get_sample_vectors::<u8>(len);
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(x, y)| x.saturating_sub(*y))
+                .collect::<Vec<u8>>();
+
+            unsafe { u8_xany_avx512bw_nofma_saturating_sub_vertical(&mut x, &y) };
+            assert_eq!(x, expected);
+        }
+    }
+
+    fn check_overflow_matches_fallback<T: U8Overflow + PartialEq + core::fmt::Debug>(
+        wrap: impl Fn(u8) -> T,
+    ) {
+        let (x, y) = get_sample_vectors::<u8>(547);
+        let x = x.into_iter().map(&wrap).collect::<Vec<T>>();
+        let y = y.into_iter().map(&wrap).collect::<Vec<T>>();
+
+        let mut expected = x.clone();
+        let mut result = x.clone();
+        unsafe {
+            u8_xany_fallback_nofma_add_vertical(&mut expected, &y);
+            u8_xany_avx512bw_nofma_add_vertical(&mut result, &y);
+        }
+        assert_eq!(result, expected);
+
+        unsafe {
+            u8_xany_fallback_nofma_sub_vertical(&mut expected, &x);
+            u8_xany_avx512bw_nofma_sub_vertical(&mut result, &x);
+        }
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_xany_nofma_overflow_wrapping() {
+        check_overflow_matches_fallback(Wrapping);
+    }
+
+    #[test]
+    fn test_xany_nofma_overflow_saturating() {
+        check_overflow_matches_fallback(Saturating);
+    }
+
+    #[test]
+    fn test_xany_nofma_abs_diff_vertical() {
+        for len in [63, 64, 531] {
+            let (mut x, y) = get_sample_vectors::<u8>(len);
+            let expected = x
+                .iter()
+                .zip(y.iter())
+                .map(|(x, y)| x.abs_diff(*y))
+                .collect::<Vec<u8>>();
+
+            unsafe { u8_xany_avx512bw_nofma_abs_diff_vertical(&mut x, &y) };
+            assert_eq!(x, expected);
+        }
+    }
+}
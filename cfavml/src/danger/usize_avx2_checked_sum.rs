@@ -0,0 +1,84 @@
+use crate::danger::{
+    i64_xany_avx2_nofma_checked_sum,
+    isize_as_i64,
+    u64_xany_avx2_nofma_checked_sum,
+    usize_as_u64,
+};
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sums all elements of the vector, returning `None` if the total does not fit
+/// within a `usize`.
+///
+/// ```py
+/// D: int
+/// total: u128
+/// x: [usize; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return total if total <= usize.MAX else None
+/// ```
+///
+/// `usize` is `u64` on the 64-bit targets this routine is available on, so the
+/// vector is summed by [u64_xany_avx2_nofma_checked_sum].
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn usize_xany_avx2_nofma_checked_sum(x: &[usize]) -> Option<usize> {
+    u64_xany_avx2_nofma_checked_sum(usize_as_u64(x)).map(|total| total as usize)
+}
+
+#[target_feature(enable = "avx2")]
+#[inline]
+/// Sums all elements of the vector, returning `None` if the total does not fit
+/// within an `isize`.
+///
+/// ```py
+/// D: int
+/// total: i128
+/// x: [isize; D]
+///
+/// for i in 0..D:
+///     total = total + x[i]
+///
+/// return total if isize.MIN <= total <= isize.MAX else None
+/// ```
+///
+/// `isize` is `i64` on the 64-bit targets this routine is available on, so the
+/// vector is summed by [i64_xany_avx2_nofma_checked_sum].
+///
+/// # Safety
+///
+/// This method assumes AVX2 instructions are available, if this method is executed
+/// on non-AVX2 enabled systems, it will lead to an `ILLEGAL_INSTRUCTION` error.
+pub unsafe fn isize_xany_avx2_nofma_checked_sum(x: &[isize]) -> Option<isize> {
+    i64_xany_avx2_nofma_checked_sum(isize_as_i64(x)).map(|total| total as isize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_usize_isize_xany_checked_sum() {
+        let (x, _) = get_sample_vectors::<usize>(531);
+        let x = x.into_iter().map(|v| v >> 16).collect::<Vec<_>>();
+        let sum = unsafe { usize_xany_avx2_nofma_checked_sum(&x) };
+        assert_eq!(sum, Some(x.iter().sum::<usize>()));
+
+        let (x, _) = get_sample_vectors::<isize>(531);
+        let x = x.into_iter().map(|v| v >> 16).collect::<Vec<_>>();
+        let sum = unsafe { isize_xany_avx2_nofma_checked_sum(&x) };
+        assert_eq!(sum, Some(x.iter().sum::<isize>()));
+
+        let x = vec![usize::MAX / 4; 13];
+        assert_eq!(unsafe { usize_xany_avx2_nofma_checked_sum(&x) }, None);
+        let x = vec![isize::MIN / 4; 13];
+        assert_eq!(unsafe { isize_xany_avx2_nofma_checked_sum(&x) }, None);
+    }
+}
@@ -2,6 +2,7 @@ use core::arch::x86_64::*;
 use core::{mem, ptr};
 
 use crate::math::Math;
+use crate::vector_ops::Axis;
 
 pub const CHUNK_0: usize = 0;
 pub const CHUNK_1: usize = 1;
@@ -12,6 +13,7 @@ pub const fn _MM_SHUFFLE(z: u32, y: u32, x: u32, w: u32) -> i32 {
 }
 
 #[inline(always)]
+#[allow(clippy::if_same_then_else)]
 pub fn cosine<T: Copy, M: Math<T>>(dot_product: T, norm_x: T, norm_y: T) -> T {
     if M::cmp_eq(norm_x, M::zero()) && M::cmp_eq(norm_y, M::zero()) {
         M::zero()
@@ -25,6 +27,326 @@ pub fn cosine<T: Copy, M: Math<T>>(dot_product: T, norm_x: T, norm_y: T) -> T {
     }
 }
 
+#[inline(always)]
+/// Computes the cosine distance from a dot product and the L2 norms (not the squared
+/// norms used by [cosine]) of both vectors.
+pub fn cosine_with_l2_norms<T: Copy, M: Math<T>>(
+    dot_product: T,
+    norm_x: T,
+    norm_y: T,
+) -> T {
+    if M::cmp_eq(norm_x, M::zero()) || M::cmp_eq(norm_y, M::zero()) {
+        M::zero()
+    } else {
+        M::sub(M::one(), M::div(dot_product, M::mul(norm_x, norm_y)))
+    }
+}
+
+#[inline(always)]
+/// Returns the multiplier required to scale a vector with the given L2 `norm`
+/// down to `max_norm`, or `None` if the vector does not need clipping.
+///
+/// Vectors with a norm of zero are never clipped.
+pub fn clip_norm_scale<T: Copy, M: Math<T>>(norm: T, max_norm: T) -> Option<T> {
+    if M::cmp_eq(norm, M::zero()) || M::cmp_eq(M::cmp_max(norm, max_norm), max_norm) {
+        None
+    } else {
+        Some(M::div(max_norm, norm))
+    }
+}
+
+#[inline(always)]
+/// Returns the index of the lower ranked element for percentile `p` of a vector
+/// with `len` elements, along with the fraction used to linearly interpolate
+/// towards the element ranked directly above it.
+///
+/// `p` is clamped to `[0, 100]`, this matches numpy's default `linear` method.
+pub fn percentile_rank(len: usize, p: f32) -> (usize, f32) {
+    let rank = (p.clamp(0.0, 100.0) as f64 / 100.0) * (len.saturating_sub(1) as f64);
+    let lower = rank.floor();
+    (lower as usize, (rank - lower) as f32)
+}
+
+/// Walks two chunked vectors in lockstep, calling `f` with each pair of overlapping
+/// sub-slices and the running total, so the chunk boundaries of `x` and `y` do not
+/// need to line up.
+///
+/// Both vectors must contain the same number of elements in total, any trailing
+/// elements of the longer vector are ignored.
+pub(crate) fn fold_chunk_pairs<T, F>(x: &[&[T]], y: &[&[T]], init: T, mut f: F) -> T
+where
+    F: FnMut(&[T], &[T], T) -> T,
+{
+    debug_assert_eq!(
+        x.iter().map(|c| c.len()).sum::<usize>(),
+        y.iter().map(|c| c.len()).sum::<usize>(),
+        "Chunked vectors must be the same length",
+    );
+
+    let mut total = init;
+    let mut x_chunks = x.iter();
+    let mut y_chunks = y.iter();
+    let mut x_chunk: &[T] = &[];
+    let mut y_chunk: &[T] = &[];
+
+    loop {
+        if x_chunk.is_empty() {
+            match x_chunks.next() {
+                Some(chunk) => x_chunk = chunk,
+                None => break,
+            }
+            continue;
+        }
+
+        if y_chunk.is_empty() {
+            match y_chunks.next() {
+                Some(chunk) => y_chunk = chunk,
+                None => break,
+            }
+            continue;
+        }
+
+        let n = x_chunk.len().min(y_chunk.len());
+        total = f(&x_chunk[..n], &y_chunk[..n], total);
+        x_chunk = &x_chunk[n..];
+        y_chunk = &y_chunk[n..];
+    }
+
+    total
+}
+
+#[inline(always)]
+/// Checks the output of a valid mode 1D correlation of `signal_len` samples with a
+/// `kernel_len` tap kernel is `result_len` in length.
+///
+/// The output has one element per position the kernel fits entirely within the
+/// signal, so it is empty when the kernel is longer than the signal.
+pub(crate) fn check_conv1d_valid_len(
+    signal_len: usize,
+    kernel_len: usize,
+    result_len: usize,
+) {
+    assert!(kernel_len > 0, "Kernel must not be empty");
+
+    let expected = signal_len.saturating_sub(kernel_len - 1);
+    assert_eq!(
+        result_len, expected,
+        "Result length {result_len} does not match the {expected} valid positions"
+    );
+}
+
+#[inline(always)]
+/// Computes a running scan of `matrix` along `axis`, writing the output to `result`.
+///
+/// Along [Axis::Rows] each row is scanned independently with `scan_row`, along
+/// [Axis::Cols] each output row is the input row combined in place with the previous
+/// output row by `combine_rows`, so every column carries its own running value.
+pub(crate) fn scan_axis_with<T: Copy>(
+    matrix: &[&[T]],
+    axis: Axis,
+    result: &mut [&mut [T]],
+    mut scan_row: impl FnMut(&[T], &mut [T]),
+    mut combine_rows: impl FnMut(&mut [T], &[T]),
+) {
+    debug_assert_eq!(matrix.len(), result.len());
+
+    match axis {
+        Axis::Rows => {
+            for (row, out) in matrix.iter().zip(result.iter_mut()) {
+                scan_row(row, out);
+            }
+        },
+        Axis::Cols => {
+            for (r, row) in matrix.iter().enumerate() {
+                let (prev, rest) = result.split_at_mut(r);
+                rest[0].copy_from_slice(row);
+                if let Some(prev) = prev.last() {
+                    combine_rows(rest[0], prev);
+                }
+            }
+        },
+    }
+}
+
+#[inline(always)]
+/// Returns rows `i` and `j` of the row-major matrix `data` with `dim` columns.
+///
+/// # Safety
+///
+/// Both rows **MUST** be within `data`, the bounds are only checked in debug builds.
+pub(crate) unsafe fn flat_row_pair<T>(
+    data: &[T],
+    dim: usize,
+    i: usize,
+    j: usize,
+) -> (&[T], &[T]) {
+    debug_assert!((i + 1) * dim <= data.len(), "Row `i` is out of bounds");
+    debug_assert!((j + 1) * dim <= data.len(), "Row `j` is out of bounds");
+
+    (
+        data.get_unchecked(i * dim..(i + 1) * dim),
+        data.get_unchecked(j * dim..(j + 1) * dim),
+    )
+}
+
+#[inline(always)]
+/// Fills `result` with the full, symmetric `rows x rows` row-major matrix of
+/// `distance` between every pair of rows of `data`, writing `zero` to the diagonal.
+///
+/// Only the upper triangle is computed and each value is mirrored into the lower
+/// triangle. Row `i` is compared against every following row before moving on, so
+/// it stays resident in cache while the remaining rows are streamed past it.
+///
+/// # Safety
+///
+/// `data` **MUST** be at least `rows * dim` in length and `result` **MUST** be
+/// `rows * rows` in length, the lengths are only checked in debug builds.
+pub(crate) unsafe fn pairwise_distances_with<T, F>(
+    data: &[T],
+    rows: usize,
+    dim: usize,
+    zero: T,
+    result: &mut [T],
+    mut distance: F,
+) where
+    T: Copy,
+    F: FnMut(&[T], &[T]) -> T,
+{
+    debug_assert!(data.len() >= rows * dim, "`data` must contain `rows` rows");
+    debug_assert_eq!(result.len(), rows * rows, "`result` must be `rows * rows`");
+
+    for i in 0..rows {
+        *result.get_unchecked_mut(i * rows + i) = zero;
+
+        for j in (i + 1)..rows {
+            let (x, y) = flat_row_pair(data, dim, i, j);
+            let dist = distance(x, y);
+
+            *result.get_unchecked_mut(i * rows + j) = dist;
+            *result.get_unchecked_mut(j * rows + i) = dist;
+        }
+    }
+}
+
+#[inline(always)]
+/// Returns the index of the row of `centroids` with the smallest `distance` to
+/// `point` along with that distance, the first row wins any ties.
+///
+/// `NaN` distances are only selected if every distance is `NaN`.
+///
+/// # Panics
+///
+/// Panics if `centroids` is empty.
+pub(crate) fn nearest_with<T, F>(
+    point: &[T],
+    centroids: &[&[T]],
+    mut distance: F,
+) -> (usize, T)
+where
+    T: Copy + PartialOrd,
+    F: FnMut(&[T], &[T]) -> T,
+{
+    assert!(!centroids.is_empty(), "`centroids` must not be empty");
+
+    let is_nan = |v: T| v.partial_cmp(&v).is_none();
+
+    let mut best = (0, distance(point, centroids[0]));
+    for (i, centroid) in centroids.iter().enumerate().skip(1) {
+        let dist = distance(point, centroid);
+        if dist < best.1 || (is_nan(best.1) && !is_nan(dist)) {
+            best = (i, dist);
+        }
+    }
+    best
+}
+
+#[inline(always)]
+/// Assigns each of `points` to its nearest row of `centroids` with [nearest_with],
+/// writing the index to `labels` and the distance to `distances`.
+///
+/// # Panics
+///
+/// Panics if `centroids` is empty while `points` is not, or if `labels` and
+/// `distances` are not the same length as `points`.
+pub(crate) fn nearest_batch_with<T, F>(
+    points: &[&[T]],
+    centroids: &[&[T]],
+    labels: &mut [usize],
+    distances: &mut [T],
+    mut distance: F,
+) where
+    T: Copy + PartialOrd,
+    F: FnMut(&[T], &[T]) -> T,
+{
+    assert_eq!(points.len(), labels.len(), "`labels` must match `points`");
+    assert_eq!(
+        points.len(),
+        distances.len(),
+        "`distances` must match `points`"
+    );
+
+    for ((point, label), dist) in points.iter().zip(labels).zip(distances) {
+        (*label, *dist) = nearest_with(point, centroids, &mut distance);
+    }
+}
+
+#[inline(always)]
+/// Writes `row_op(matrix[i], bias[i])` to `out[i]` for every row of `matrix`.
+///
+/// # Panics
+///
+/// Panics if `bias` or `out` are not the same length as `matrix`.
+pub(crate) fn gemv_with<T, F>(matrix: &[&[T]], bias: &[T], out: &mut [T], mut row_op: F)
+where
+    T: Copy,
+    F: FnMut(&[T], T) -> T,
+{
+    assert_eq!(
+        matrix.len(),
+        bias.len(),
+        "`bias` must match the matrix rows"
+    );
+    assert_eq!(matrix.len(), out.len(), "`out` must match the matrix rows");
+
+    for ((row, bias), out) in matrix.iter().zip(bias).zip(out) {
+        *out = row_op(row, *bias);
+    }
+}
+
+/// The number of elements accumulated by the bounded distance routines between
+/// checks of the partial distance against the bound.
+pub(crate) const BOUNDED_CHECK_INTERVAL: usize = 256;
+
+#[inline(always)]
+/// Accumulates `distance` over [BOUNDED_CHECK_INTERVAL] sized chunks of `x` and `y`,
+/// returning `None` as soon as the partial total exceeds `bound`.
+///
+/// A `NaN` total never compares within the bound, so it also returns `None`.
+pub(crate) fn bounded_distance_with<T, M, F>(
+    x: &[T],
+    y: &[T],
+    bound: T,
+    mut distance: F,
+) -> Option<T>
+where
+    T: Copy + PartialOrd,
+    M: Math<T>,
+    F: FnMut(&[T], &[T]) -> T,
+{
+    let mut total = M::zero();
+    for (x, y) in x
+        .chunks(BOUNDED_CHECK_INTERVAL)
+        .zip(y.chunks(BOUNDED_CHECK_INTERVAL))
+    {
+        total = M::add(total, distance(x, y));
+        if total > bound {
+            return None;
+        }
+    }
+
+    (total <= bound).then_some(total)
+}
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[inline(always)]
 /// Performs a sum of all packed values in the provided [__m256] register
@@ -298,6 +620,90 @@ pub(crate) unsafe fn load_one_variable_size_avx512_pd(
     }
 }
 
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[inline(always)]
+/// Loads upto `n` `i8` elements from `x`, lanes beyond `n` are taken from `fill`.
+///
+/// `fill` should be the identity value of the operation being applied, i.e. `0`
+/// for a sum or the type's maximum value for a min.
+///
+/// NOTE:
+/// This requires the AVX512BW subset to be available.
+pub(crate) unsafe fn load_one_variable_size_avx512_epi8(
+    x: *const i8,
+    n: usize,
+    fill: __m512i,
+) -> __m512i {
+    if n < 64 {
+        let mask = (1u64 << n) - 1;
+        _mm512_mask_loadu_epi8(fill, mask, x)
+    } else {
+        _mm512_loadu_si512(x.cast())
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[inline(always)]
+/// Loads upto `n` `i16` elements from `x`, lanes beyond `n` are taken from `fill`.
+///
+/// `fill` should be the identity value of the operation being applied, i.e. `0`
+/// for a sum or the type's maximum value for a min.
+///
+/// NOTE:
+/// This requires the AVX512BW subset to be available.
+pub(crate) unsafe fn load_one_variable_size_avx512_epi16(
+    x: *const i16,
+    n: usize,
+    fill: __m512i,
+) -> __m512i {
+    if n < 32 {
+        let mask = (1u32 << n) - 1;
+        _mm512_mask_loadu_epi16(fill, mask, x)
+    } else {
+        _mm512_loadu_si512(x.cast())
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[inline(always)]
+/// Copies the `i8` lanes of the given `reg` into `arr` for upto `len` elements.
+///
+/// NOTE:
+/// This will implicitly cap the number of elements to `min(len, 64)` to prevent
+/// going out of bounds on the register, and requires the AVX512BW subset to be available.
+pub(crate) unsafe fn copy_masked_avx512_epi8_register_to(
+    arr: *mut i8,
+    reg: __m512i,
+    len: usize,
+) {
+    if len < 64 {
+        let mask = (1u64 << len) - 1;
+        _mm512_mask_storeu_epi8(arr, mask, reg)
+    } else {
+        _mm512_storeu_si512(arr.cast(), reg)
+    }
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
+#[inline(always)]
+/// Copies the `i16` lanes of the given `reg` into `arr` for upto `len` elements.
+///
+/// NOTE:
+/// This will implicitly cap the number of elements to `min(len, 32)` to prevent
+/// going out of bounds on the register, and requires the AVX512BW subset to be available.
+pub(crate) unsafe fn copy_masked_avx512_epi16_register_to(
+    arr: *mut i16,
+    reg: __m512i,
+    len: usize,
+) {
+    if len < 32 {
+        let mask = (1u32 << len) - 1;
+        _mm512_mask_storeu_epi16(arr, mask, reg)
+    } else {
+        _mm512_storeu_si512(arr.cast(), reg)
+    }
+}
+
 #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), feature = "nightly"))]
 #[inline(always)]
 /// Copies the data from the given `reg` into `arr` for upto `len` elements.
@@ -338,6 +744,94 @@ pub(crate) unsafe fn copy_avx2_ps_register_to(arr: *mut f32, reg: __m256) {
     ptr::copy_nonoverlapping(result.as_ptr(), arr, result.len());
 }
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+/// Approximates `1 / sqrt(x)` with the `rsqrtss` instruction, the relative error is
+/// at most `1.5 * 2^-12`.
+pub(crate) unsafe fn rsqrt_approx_f32(x: f32) -> f32 {
+    _mm_cvtss_f32(_mm_rsqrt_ss(_mm_set_ss(x)))
+}
+
+#[inline(always)]
+/// Computes `a + b` returning the rounded sum and its exact rounding error,
+/// Knuth's `TwoSum` error-free transformation.
+pub(crate) fn two_sum_f32(a: f32, b: f32) -> (f32, f32) {
+    let sum = a + b;
+    let z = sum - a;
+    (sum, (a - (sum - z)) + (b - z))
+}
+
+#[inline(always)]
+/// Repeatedly fills a reusable buffer of `chunk_size` elements with `reader` until it
+/// returns `0`, summing each filled chunk with `sum`.
+///
+/// The chunk totals are combined with [two_sum_f32] so the accumulation across chunks
+/// adds no rounding error of its own, regardless of how many chunks are read.
+///
+/// # Panics
+///
+/// Panics if `chunk_size` is `0` or `reader` reports more elements than `chunk_size`.
+pub(crate) fn sum_streaming_with<R, S>(
+    chunk_size: usize,
+    mut reader: R,
+    mut sum: S,
+) -> f32
+where
+    R: FnMut(&mut [f32]) -> usize,
+    S: FnMut(&[f32]) -> f32,
+{
+    assert_ne!(chunk_size, 0, "Chunk size must be greater than 0");
+
+    let mut buffer = vec![0.0; chunk_size];
+    let mut total = 0.0;
+    let mut compensation = 0.0;
+
+    loop {
+        let n = reader(&mut buffer);
+        if n == 0 {
+            break;
+        }
+        assert!(
+            n <= chunk_size,
+            "Reader filled {n} elements, exceeding the chunk size {chunk_size}"
+        );
+
+        let (new_total, error) = two_sum_f32(total, sum(&buffer[..n]));
+        total = new_total;
+        compensation += error;
+    }
+
+    total + compensation
+}
+
+#[inline(always)]
+/// Computes the uniform bucket index of `x` given the `lo` bound, the `scale` of
+/// `levels / (hi - lo)` and the index of the last bucket, clamping out of range
+/// values and mapping `NaN` to the first bucket.
+pub(crate) fn quantize_uniform_f32(x: f32, lo: f32, scale: f32, max_bucket: f32) -> u32 {
+    // `f32::max` returns the other operand when one is NaN, mapping NaN to 0.
+    ((x - lo) * scale).max(0.0).min(max_bucket).floor() as u32
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+/// Computes the uniform bucket index of each value in the provided [__m256] register,
+/// matching [quantize_uniform_f32].
+pub(crate) unsafe fn quantize_uniform_avx2_ps(
+    x: __m256,
+    lo: __m256,
+    scale: __m256,
+    max_bucket: __m256,
+) -> __m256i {
+    let bucket = _mm256_mul_ps(_mm256_sub_ps(x, lo), scale);
+
+    // `vmaxps` returns the second operand when the first is NaN, mapping NaN to 0.
+    let bucket = _mm256_max_ps(bucket, _mm256_setzero_ps());
+    let bucket = _mm256_min_ps(bucket, max_bucket);
+
+    _mm256_cvttps_epi32(_mm256_floor_ps(bucket))
+}
+
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 #[inline(always)]
 /// Copies the data from the given `reg` into `arr`.
@@ -346,6 +840,357 @@ pub(crate) unsafe fn copy_avx2_pd_register_to(arr: *mut f64, reg: __m256d) {
     ptr::copy_nonoverlapping(result.as_ptr(), arr, result.len());
 }
 
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+/// Computes `e^x` for each lane of the [__m256] register.
+///
+/// The input is split as `x = n * ln(2) + r` with `|r| <= ln(2) / 2`, `e^r` is
+/// evaluated with the degree 6 Cephes polynomial using Horner's method and the
+/// result is scaled by `2^n` by constructing the exponent bits directly. Inputs are
+/// clamped to `[-87.3, 88.0]` so the result is always a normal, finite value, the
+/// relative error is within a few ULP over that range.
+pub(crate) unsafe fn exp_avx2_ps(x: __m256) -> __m256 {
+    let x = _mm256_min_ps(_mm256_set1_ps(88.0), x);
+    let x = _mm256_max_ps(_mm256_set1_ps(-87.3), x);
+
+    let n = _mm256_round_ps::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+        _mm256_mul_ps(x, _mm256_set1_ps(core::f32::consts::LOG2_E)),
+    );
+
+    // ln(2) is split into two parts so `n * ln(2)` is subtracted without rounding.
+    let r = _mm256_sub_ps(x, _mm256_mul_ps(n, _mm256_set1_ps(0.693_359_4)));
+    let r = _mm256_sub_ps(r, _mm256_mul_ps(n, _mm256_set1_ps(-2.121_944_4e-4)));
+
+    let mut y = _mm256_set1_ps(1.987_569_1e-4);
+    for coeff in [
+        1.398_2e-3,
+        8.333_452e-3,
+        4.166_579_6e-2,
+        1.666_666_5e-1,
+        0.5,
+    ] {
+        y = _mm256_add_ps(_mm256_mul_ps(y, r), _mm256_set1_ps(coeff));
+    }
+    y = _mm256_add_ps(_mm256_mul_ps(y, _mm256_mul_ps(r, r)), r);
+    y = _mm256_add_ps(y, _mm256_set1_ps(1.0));
+
+    let exponent = _mm256_add_epi32(_mm256_cvtps_epi32(n), _mm256_set1_epi32(127));
+    let scale = _mm256_castsi256_ps(_mm256_slli_epi32::<23>(exponent));
+
+    _mm256_mul_ps(y, scale)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+/// Computes `e^x` for each lane of the [__m256d] register.
+///
+/// The input is split as `x = n * ln(2) + r` with `|r| <= ln(2) / 2`, `e^r` is
+/// evaluated with the Cephes rational approximation `1 + 2 * P(r) / (Q(r) - P(r))`
+/// and the result is scaled by `2^n` by constructing the exponent bits directly.
+/// Inputs are clamped to `[-708.3, 709.0]` so the result is always a normal, finite
+/// value, the relative error is within a few ULP over that range.
+pub(crate) unsafe fn exp_avx2_pd(x: __m256d) -> __m256d {
+    let x = _mm256_min_pd(_mm256_set1_pd(709.0), x);
+    let x = _mm256_max_pd(_mm256_set1_pd(-708.3), x);
+
+    let n = _mm256_round_pd::<{ _MM_FROUND_TO_NEAREST_INT | _MM_FROUND_NO_EXC }>(
+        _mm256_mul_pd(x, _mm256_set1_pd(core::f64::consts::LOG2_E)),
+    );
+
+    // ln(2) is split into two parts so `n * ln(2)` is subtracted without rounding.
+    let r = _mm256_sub_pd(x, _mm256_mul_pd(n, _mm256_set1_pd(6.931_457_519_531_25e-1)));
+    let r = _mm256_sub_pd(
+        r,
+        _mm256_mul_pd(n, _mm256_set1_pd(1.428_606_820_309_417_2e-6)),
+    );
+    let rr = _mm256_mul_pd(r, r);
+
+    let mut p = _mm256_set1_pd(1.261_771_930_748_105_9e-4);
+    for coeff in [3.029_944_077_074_419_6e-2, 9.999_999_999_999_999e-1] {
+        p = _mm256_add_pd(_mm256_mul_pd(p, rr), _mm256_set1_pd(coeff));
+    }
+    let p = _mm256_mul_pd(p, r);
+
+    let mut q = _mm256_set1_pd(3.001_985_051_386_644_5e-6);
+    for coeff in [2.524_483_403_496_841e-3, 2.272_655_482_081_550_3e-1, 2.0] {
+        q = _mm256_add_pd(_mm256_mul_pd(q, rr), _mm256_set1_pd(coeff));
+    }
+
+    let y = _mm256_div_pd(p, _mm256_sub_pd(q, p));
+    let y = _mm256_add_pd(_mm256_set1_pd(1.0), _mm256_add_pd(y, y));
+
+    // Adding `2^52` places the integer `n + 1023` in the low bits of the mantissa.
+    let biased = _mm256_add_pd(n, _mm256_set1_pd(4_503_599_627_370_496.0 + 1023.0));
+    let scale =
+        _mm256_castsi256_pd(_mm256_slli_epi64::<52>(_mm256_castpd_si256(biased)));
+
+    _mm256_mul_pd(y, scale)
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+/// Computes `ln(x)` for each lane of the [__m256] register.
+///
+/// The input is split as `x = m * 2^e` with `m` in `[sqrt(0.5), sqrt(2))` by reading
+/// the exponent bits directly and `ln(m)` is evaluated as `r - r^2 / 2 + r^3 * P(r)`
+/// with `r = m - 1`. With `PRECISE` the degree 8 Cephes polynomial is used and the
+/// relative error is within a few ULP, otherwise a degree 3 fit is used with a relative
+/// error of roughly `2e-5` for less than half the multiplies.
+///
+/// Every lane **MUST** be a positive, finite and normal value.
+pub(crate) unsafe fn log_avx2_ps<const PRECISE: bool>(x: __m256) -> __m256 {
+    let bits = _mm256_castps_si256(x);
+    let exponent =
+        _mm256_sub_epi32(_mm256_srli_epi32::<23>(bits), _mm256_set1_epi32(126));
+    let e = _mm256_cvtepi32_ps(exponent);
+
+    // Replacing the exponent bits with those of `0.5` leaves `m` in `[0.5, 1)`.
+    let mantissa = _mm256_and_si256(bits, _mm256_set1_epi32(0x007F_FFFF));
+    let m =
+        _mm256_castsi256_ps(_mm256_or_si256(mantissa, _mm256_set1_epi32(0x3F00_0000)));
+
+    // Small mantissas are doubled so `r` is centered on zero.
+    let small =
+        _mm256_cmp_ps::<_CMP_LT_OQ>(m, _mm256_set1_ps(core::f32::consts::FRAC_1_SQRT_2));
+    let e = _mm256_sub_ps(e, _mm256_and_ps(small, _mm256_set1_ps(1.0)));
+    let r = _mm256_sub_ps(
+        _mm256_add_ps(m, _mm256_and_ps(small, m)),
+        _mm256_set1_ps(1.0),
+    );
+    let rr = _mm256_mul_ps(r, r);
+
+    let coeffs: &[f32] = if PRECISE {
+        &[
+            7.037_683_6e-2,
+            -1.151_461e-1,
+            1.167_699_9e-1,
+            -1.242_014_1e-1,
+            1.424_932_3e-1,
+            -1.666_805_8e-1,
+            2.000_071_4e-1,
+            -2.499_999_4e-1,
+            3.333_333e-1,
+        ]
+    } else {
+        &[
+            -1.477_699_6e-1,
+            2.189_166_7e-1,
+            -2.523_527e-1,
+            3.327_530_4e-1,
+        ]
+    };
+    let mut p = _mm256_set1_ps(coeffs[0]);
+    for coeff in &coeffs[1..] {
+        p = _mm256_add_ps(_mm256_mul_ps(p, r), _mm256_set1_ps(*coeff));
+    }
+    let y = _mm256_mul_ps(_mm256_mul_ps(p, r), rr);
+
+    // ln(2) is split into two parts so `e * ln(2)` is added without rounding.
+    let y = _mm256_add_ps(y, _mm256_mul_ps(e, _mm256_set1_ps(-2.121_944_4e-4)));
+    let y = _mm256_sub_ps(y, _mm256_mul_ps(rr, _mm256_set1_ps(0.5)));
+    let y = _mm256_add_ps(r, y);
+    _mm256_add_ps(y, _mm256_mul_ps(e, _mm256_set1_ps(0.693_359_4)))
+}
+
+#[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+#[inline(always)]
+/// Computes `ln(x)` for each lane of the [__m256d] register.
+///
+/// The input is split as `x = m * 2^e` with `m` in `[sqrt(0.5), sqrt(2))` by reading
+/// the exponent bits directly and `ln(m)` is evaluated as `r - r^2 / 2 + r^3 * R(r)`
+/// with `r = m - 1`. With `PRECISE` `R` is the Cephes rational approximation and the
+/// relative error is within a few ULP, otherwise the degree 8 polynomial used for `f32`
+/// is evaluated in double precision with a relative error of roughly `1e-7`, avoiding
+/// the division.
+///
+/// Every lane **MUST** be a positive, finite and normal value.
+pub(crate) unsafe fn log_avx2_pd<const PRECISE: bool>(x: __m256d) -> __m256d {
+    let bits = _mm256_castpd_si256(x);
+
+    // Placing the exponent bits in the mantissa of `2^52` converts them to a float
+    // without needing the AVX512 `i64` conversions.
+    let exponent = _mm256_or_si256(
+        _mm256_srli_epi64::<52>(bits),
+        _mm256_set1_epi64x(0x4330_0000_0000_0000),
+    );
+    let e = _mm256_sub_pd(
+        _mm256_castsi256_pd(exponent),
+        _mm256_set1_pd(4_503_599_627_370_496.0 + 1022.0),
+    );
+
+    // Replacing the exponent bits with those of `0.5` leaves `m` in `[0.5, 1)`.
+    let mantissa = _mm256_and_si256(bits, _mm256_set1_epi64x(0x000F_FFFF_FFFF_FFFF));
+    let m = _mm256_castsi256_pd(_mm256_or_si256(
+        mantissa,
+        _mm256_set1_epi64x(0x3FE0_0000_0000_0000),
+    ));
+
+    // Small mantissas are doubled so `r` is centered on zero.
+    let small =
+        _mm256_cmp_pd::<_CMP_LT_OQ>(m, _mm256_set1_pd(core::f64::consts::FRAC_1_SQRT_2));
+    let e = _mm256_sub_pd(e, _mm256_and_pd(small, _mm256_set1_pd(1.0)));
+    let r = _mm256_sub_pd(
+        _mm256_add_pd(m, _mm256_and_pd(small, m)),
+        _mm256_set1_pd(1.0),
+    );
+    let rr = _mm256_mul_pd(r, r);
+
+    let ratio = if PRECISE {
+        let mut p = _mm256_set1_pd(1.018_756_638_045_809_3e-4);
+        for coeff in [
+            4.974_949_949_767_47e-1,
+            4.705_791_198_788_817,
+            1.449_892_253_416_109_3e1,
+            1.793_686_785_078_198_3e1,
+            7.708_387_337_558_857,
+        ] {
+            p = _mm256_add_pd(_mm256_mul_pd(p, r), _mm256_set1_pd(coeff));
+        }
+
+        let mut q = _mm256_add_pd(r, _mm256_set1_pd(1.128_735_871_891_674_5e1));
+        for coeff in [
+            4.522_791_458_375_322e1,
+            8.298_752_669_127_766e1,
+            7.115_447_506_185_639e1,
+            2.312_516_201_267_653_4e1,
+        ] {
+            q = _mm256_add_pd(_mm256_mul_pd(q, r), _mm256_set1_pd(coeff));
+        }
+
+        _mm256_div_pd(p, q)
+    } else {
+        let mut p = _mm256_set1_pd(7.037_683_629_2e-2);
+        for coeff in [
+            -1.151_461_031e-1,
+            1.167_699_874e-1,
+            -1.242_014_084_6e-1,
+            1.424_932_278_7e-1,
+            -1.666_805_766_5e-1,
+            2.000_071_476_5e-1,
+            -2.499_999_399_3e-1,
+            3.333_333_117_4e-1,
+        ] {
+            p = _mm256_add_pd(_mm256_mul_pd(p, r), _mm256_set1_pd(coeff));
+        }
+        p
+    };
+    let y = _mm256_mul_pd(_mm256_mul_pd(ratio, r), rr);
+
+    // ln(2) is split into two parts so `e * ln(2)` is added without rounding.
+    let y = _mm256_sub_pd(
+        y,
+        _mm256_mul_pd(e, _mm256_set1_pd(2.121_944_400_546_905_8e-4)),
+    );
+    let y = _mm256_sub_pd(y, _mm256_mul_pd(rr, _mm256_set1_pd(0.5)));
+    let y = _mm256_add_pd(r, y);
+    _mm256_add_pd(y, _mm256_mul_pd(e, _mm256_set1_pd(0.693_359_375)))
+}
+
+#[inline(always)]
+/// Derives the mean and population standard deviation of `n` values from their sum
+/// and sum of squares.
+///
+/// `E[x²] - E[x]²` carries a rounding error of roughly `eps * E[x²]`, any variance
+/// within that bound is indistinguishable from noise and is flushed to exactly zero,
+/// so constant inputs reliably report a standard deviation of `0.0`.
+pub(crate) fn mean_std_from_sums_f32(sum: f32, sum_sq: f32, n: usize) -> (f32, f32) {
+    if n == 0 {
+        return (0.0, 0.0);
+    }
+
+    let mean = sum / n as f32;
+    let mean_sq = sum_sq / n as f32;
+    let variance = mean_sq - mean * mean;
+
+    if variance <= f32::EPSILON * mean_sq {
+        (mean, 0.0)
+    } else {
+        (mean, variance.sqrt())
+    }
+}
+
+#[inline(always)]
+/// Returns `true` if the memory of the two slices overlaps at all.
+pub(crate) fn overlaps<A, B>(a: &[A], b: &[B]) -> bool {
+    let a = a.as_ptr_range();
+    let b = b.as_ptr_range();
+    (a.start as usize) < (b.end as usize) && (b.start as usize) < (a.end as usize)
+}
+
+#[inline(always)]
+/// Returns `true` if the memory of the two slices overlaps without them starting at
+/// the same address, which is never a valid input for any routine.
+pub(crate) fn partially_overlaps<A, B>(a: &[A], b: &[B]) -> bool {
+    overlaps(a, b) && !ptr::eq(a.as_ptr().cast::<u8>(), b.as_ptr().cast::<u8>())
+}
+
+#[cfg(target_pointer_width = "64")]
+#[inline(always)]
+/// Reinterprets a slice of `usize` as the `u64` values of the same layout.
+pub(crate) fn usize_as_u64(x: &[usize]) -> &[u64] {
+    // SAFETY: `usize` has the same size and alignment as `u64` on 64-bit targets.
+    unsafe { core::slice::from_raw_parts(x.as_ptr().cast(), x.len()) }
+}
+
+#[cfg(target_pointer_width = "64")]
+#[inline(always)]
+/// Reinterprets a slice of `isize` as the `i64` values of the same layout.
+pub(crate) fn isize_as_i64(x: &[isize]) -> &[i64] {
+    // SAFETY: `isize` has the same size and alignment as `i64` on 64-bit targets.
+    unsafe { core::slice::from_raw_parts(x.as_ptr().cast(), x.len()) }
+}
+
+#[cfg(feature = "rayon")]
+/// The number of elements covered by each leaf of [par_tree_reduce].
+///
+/// This is part of the result of a deterministic reduction, changing it changes
+/// the order values are combined in and therefore the rounding of the result.
+pub(crate) const PAR_REDUCE_BLOCK: usize = 16 * 1024;
+
+#[cfg(feature = "rayon")]
+/// Reduces `0..len` in parallel with a fixed tree of [PAR_REDUCE_BLOCK] sized blocks,
+/// returning `zero` if `len` is `0`.
+///
+/// Each block is reduced by `leaf` and the block totals are combined by `combine` in
+/// a balanced binary tree, always splitting the range of blocks at its midpoint. The
+/// shape of the tree only depends on `len`, rayon only decides which thread evaluates
+/// each subtree, so the result is bit identical regardless of the thread pool size.
+pub(crate) fn par_tree_reduce<T, L, C>(len: usize, zero: T, leaf: L, combine: C) -> T
+where
+    T: Send,
+    L: Fn(core::ops::Range<usize>) -> T + Sync,
+    C: Fn(T, T) -> T + Sync,
+{
+    fn reduce_blocks<T, L, C>(
+        blocks: core::ops::Range<usize>,
+        len: usize,
+        leaf: &L,
+        combine: &C,
+    ) -> T
+    where
+        T: Send,
+        L: Fn(core::ops::Range<usize>) -> T + Sync,
+        C: Fn(T, T) -> T + Sync,
+    {
+        if blocks.len() == 1 {
+            let start = blocks.start * PAR_REDUCE_BLOCK;
+            return leaf(start..len.min(start + PAR_REDUCE_BLOCK));
+        }
+
+        let mid = blocks.start + blocks.len() / 2;
+        let (left, right) = rayon::join(
+            || reduce_blocks(blocks.start..mid, len, leaf, combine),
+            || reduce_blocks(mid..blocks.end, len, leaf, combine),
+        );
+        combine(left, right)
+    }
+
+    match len.div_ceil(PAR_REDUCE_BLOCK) {
+        0 => zero,
+        blocks => reduce_blocks(0..blocks, len, &leaf, &combine),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use core::array;
@@ -353,6 +1198,28 @@ mod tests {
     use super::*;
     use crate::math::AutoMath;
 
+    #[test]
+    fn test_fold_chunk_pairs() {
+        let x: [&[u32]; 3] = [&[1, 2, 3], &[], &[4, 5]];
+        let y: [&[u32]; 2] = [&[1], &[2, 3, 4, 5]];
+
+        let mut pairs = Vec::new();
+        let total = fold_chunk_pairs(&x, &y, 0, |x, y, total| {
+            pairs.push((x.to_vec(), y.to_vec()));
+            total + x.iter().zip(y).map(|(a, b)| a * b).sum::<u32>()
+        });
+
+        assert_eq!(total, 1 + 4 + 9 + 16 + 25);
+        assert_eq!(
+            pairs,
+            vec![
+                (vec![1], vec![1]),
+                (vec![2, 3], vec![2, 3]),
+                (vec![4, 5], vec![4, 5]),
+            ],
+        );
+    }
+
     #[test]
     fn test_avx2_offsets() {
         let x: [f32; 32] = array::from_fn(|i| i as f32);
@@ -385,6 +1252,16 @@ mod tests {
         assert_eq!(res, 8.0);
     }
 
+    #[test]
+    fn test_percentile_rank() {
+        assert_eq!(percentile_rank(5, 0.0), (0, 0.0));
+        assert_eq!(percentile_rank(5, 50.0), (2, 0.0));
+        assert_eq!(percentile_rank(5, 100.0), (4, 0.0));
+        assert_eq!(percentile_rank(4, 50.0), (1, 0.5));
+        assert_eq!(percentile_rank(1, 75.0), (0, 0.0));
+        assert_eq!(percentile_rank(5, 150.0), (4, 0.0));
+    }
+
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     #[test]
     fn test_sum_avx2() {
@@ -407,6 +1284,54 @@ mod tests {
         }
     }
 
+    #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+    #[test]
+    fn test_exp_avx2_ps() {
+        let mut x = -87.0;
+        while x <= 87.0 {
+            let values = [
+                x,
+                x + 0.1,
+                x + 0.25,
+                x + 0.3,
+                x + 0.5,
+                x + 0.6,
+                x + 0.75,
+                x + 0.9,
+            ];
+            let result = unsafe {
+                mem::transmute::<__m256, [f32; 8]>(exp_avx2_ps(mem::transmute::<
+                    [f32; 8],
+                    __m256,
+                >(values)))
+            };
+
+            for (v, r) in values.iter().zip(result) {
+                let expected = v.exp();
+                assert!(
+                    ((r - expected) / expected).abs() < 1e-6,
+                    "exp({v}) = {r} != {expected}"
+                );
+            }
+
+            x += 1.0;
+        }
+    }
+
+    #[test]
+    fn test_overlaps() {
+        let data = [0.0f32; 16];
+
+        assert!(overlaps(&data[..8], &data[4..12]));
+        assert!(overlaps(&data[..8], &data[..8]));
+        assert!(!overlaps(&data[..8], &data[8..]));
+        assert!(!overlaps(&data[..0], &data[..8]));
+
+        assert!(partially_overlaps(&data[..8], &data[4..12]));
+        assert!(!partially_overlaps(&data[..8], &data[..8]));
+        assert!(!partially_overlaps(&data[..8], &data[8..]));
+    }
+
     #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
     #[test]
     fn test_rollup_avx2_x8() {
@@ -0,0 +1,340 @@
+//! Safe, runtime dispatched distance functions.
+//!
+//! Each call detects the backend with [detected_backend](crate::backend::detected_backend)
+//! and runs the matching routine from [danger](crate::danger). This is convenient for
+//! one-off calls, hot loops should resolve the routines once with
+//! [VectorOps](crate::vector_ops::VectorOps) instead.
+//!
+//! ```
+//! use cfavml::distance_ops::{f32_xany_cosine, f32_xany_squared_euclidean};
+//!
+//! let a = vec![1.0, 0.0];
+//! let b = vec![0.0, 1.0];
+//! assert_eq!(f32_xany_cosine(&a, &a), 0.0);
+//! assert_eq!(f32_xany_cosine(&a, &b), 1.0);
+//! assert_eq!(f32_xany_squared_euclidean(&a, &b), 2.0);
+//! ```
+
+use crate::backend::dispatch_backend;
+use crate::danger;
+
+/// Computes the cosine distance of two `f32` vectors.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_cosine(x: &[f32], y: &[f32]) -> f32 {
+    assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f32_xany_cosine"),
+            avx512 => danger::f32_xany_avx512_fma_cosine(x, y),
+            avx2_fma => danger::f32_xany_avx2_fma_cosine_fused(x, y),
+            avx2 => danger::f32_xany_avx2_nofma_cosine_fused(x, y),
+            fallback => danger::generic_xany_fallback_nofma_cosine(x, y),
+        )
+    }
+}
+
+/// Computes the cosine distance of two `[f32; DIMS]` vectors.
+///
+/// The fully unrolled `xconst` routines are used when `DIMS` is a multiple of their
+/// block size, `128` for AVX512 and `64` for AVX2, other dimensions use the `xany`
+/// routines.
+///
+/// # Panics
+///
+/// Panics if either vector is not `DIMS` in length.
+pub fn f32_xconst_cosine<const DIMS: usize>(x: &[f32], y: &[f32]) -> f32 {
+    assert_eq!(x.len(), DIMS, "Vectors must be `DIMS` in length");
+    assert_eq!(y.len(), DIMS, "Vectors must be `DIMS` in length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f32_xconst_cosine"),
+            avx512 => if DIMS.is_multiple_of(128) {
+                danger::f32_xconst_avx512_fma_cosine::<DIMS>(x, y)
+            } else {
+                danger::f32_xany_avx512_fma_cosine(x, y)
+            },
+            avx2_fma => if DIMS.is_multiple_of(64) {
+                danger::f32_xconst_avx2_fma_cosine::<DIMS>(x, y)
+            } else {
+                danger::f32_xany_avx2_fma_cosine_fused(x, y)
+            },
+            avx2 => if DIMS.is_multiple_of(64) {
+                danger::f32_xconst_avx2_nofma_cosine::<DIMS>(x, y)
+            } else {
+                danger::f32_xany_avx2_nofma_cosine_fused(x, y)
+            },
+            fallback => danger::generic_xany_fallback_nofma_cosine(x, y),
+        )
+    }
+}
+
+/// Computes the cosine distance of two `f64` vectors.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_cosine(x: &[f64], y: &[f64]) -> f64 {
+    assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f64_xany_cosine"),
+            avx512 => danger::f64_xany_avx512_fma_cosine(x, y),
+            avx2_fma => danger::f64_xany_avx2_fma_cosine(x, y),
+            avx2 => danger::f64_xany_avx2_nofma_cosine(x, y),
+            fallback => danger::generic_xany_fallback_nofma_cosine(x, y),
+        )
+    }
+}
+
+/// Computes the cosine distance of two `[f64; DIMS]` vectors.
+///
+/// The fully unrolled `xconst` routines are used when `DIMS` is a multiple of their
+/// block size, `64` for AVX512 and `32` for AVX2, other dimensions use the `xany`
+/// routines.
+///
+/// # Panics
+///
+/// Panics if either vector is not `DIMS` in length.
+pub fn f64_xconst_cosine<const DIMS: usize>(x: &[f64], y: &[f64]) -> f64 {
+    assert_eq!(x.len(), DIMS, "Vectors must be `DIMS` in length");
+    assert_eq!(y.len(), DIMS, "Vectors must be `DIMS` in length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f64_xconst_cosine"),
+            avx512 => if DIMS.is_multiple_of(64) {
+                danger::f64_xconst_avx512_fma_cosine::<DIMS>(x, y)
+            } else {
+                danger::f64_xany_avx512_fma_cosine(x, y)
+            },
+            avx2_fma => if DIMS.is_multiple_of(32) {
+                danger::f64_xconst_avx2_fma_cosine::<DIMS>(x, y)
+            } else {
+                danger::f64_xany_avx2_fma_cosine(x, y)
+            },
+            avx2 => if DIMS.is_multiple_of(32) {
+                danger::f64_xconst_avx2_nofma_cosine::<DIMS>(x, y)
+            } else {
+                danger::f64_xany_avx2_nofma_cosine(x, y)
+            },
+            fallback => danger::generic_xany_fallback_nofma_cosine(x, y),
+        )
+    }
+}
+
+/// Computes the squared Euclidean distance of two `f32` vectors.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_squared_euclidean(x: &[f32], y: &[f32]) -> f32 {
+    assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f32_xany_squared_euclidean"),
+            avx512 => danger::f32_xany_avx512_fma_euclidean(x, y),
+            avx2_fma => danger::f32_xany_avx2_fma_euclidean(x, y),
+            avx2 => danger::f32_xany_avx2_nofma_euclidean(x, y),
+            fallback => danger::generic_xany_fallback_nofma_euclidean(x, y),
+        )
+    }
+}
+
+/// Computes the squared Euclidean distance of two `[f32; DIMS]` vectors.
+///
+/// The fully unrolled `xconst` routines are used when `DIMS` is a multiple of their
+/// block size, `128` for AVX512 and `64` for AVX2, other dimensions use the `xany`
+/// routines.
+///
+/// # Panics
+///
+/// Panics if either vector is not `DIMS` in length.
+pub fn f32_xconst_squared_euclidean<const DIMS: usize>(x: &[f32], y: &[f32]) -> f32 {
+    assert_eq!(x.len(), DIMS, "Vectors must be `DIMS` in length");
+    assert_eq!(y.len(), DIMS, "Vectors must be `DIMS` in length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f32_xconst_squared_euclidean"),
+            avx512 => if DIMS.is_multiple_of(128) {
+                danger::f32_xconst_avx512_fma_euclidean::<DIMS>(x, y)
+            } else {
+                danger::f32_xany_avx512_fma_euclidean(x, y)
+            },
+            avx2_fma => if DIMS.is_multiple_of(64) {
+                danger::f32_xconst_avx2_fma_euclidean::<DIMS>(x, y)
+            } else {
+                danger::f32_xany_avx2_fma_euclidean(x, y)
+            },
+            avx2 => if DIMS.is_multiple_of(64) {
+                danger::f32_xconst_avx2_nofma_euclidean::<DIMS>(x, y)
+            } else {
+                danger::f32_xany_avx2_nofma_euclidean(x, y)
+            },
+            fallback => danger::generic_xany_fallback_nofma_euclidean(x, y),
+        )
+    }
+}
+
+/// Computes the Euclidean distance of two `f32` vectors, the square root of
+/// [f32_xany_squared_euclidean].
+///
+/// Comparisons between distances give the same ordering with or without the square
+/// root, so ranking and nearest neighbour searches can skip it by using
+/// [f32_xany_squared_euclidean] directly.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_euclidean(x: &[f32], y: &[f32]) -> f32 {
+    f32_xany_squared_euclidean(x, y).sqrt()
+}
+
+/// Computes the squared Euclidean distance of two `f64` vectors.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_squared_euclidean(x: &[f64], y: &[f64]) -> f64 {
+    assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f64_xany_squared_euclidean"),
+            avx512 => danger::f64_xany_avx512_fma_euclidean(x, y),
+            avx2_fma => danger::f64_xany_avx2_fma_euclidean(x, y),
+            avx2 => danger::f64_xany_avx2_nofma_euclidean(x, y),
+            fallback => danger::generic_xany_fallback_nofma_euclidean(x, y),
+        )
+    }
+}
+
+/// Computes the squared Euclidean distance of two `[f64; DIMS]` vectors.
+///
+/// The fully unrolled `xconst` routines are used when `DIMS` is a multiple of their
+/// block size, `64` for AVX512 and `32` for AVX2, other dimensions use the `xany`
+/// routines.
+///
+/// # Panics
+///
+/// Panics if either vector is not `DIMS` in length.
+pub fn f64_xconst_squared_euclidean<const DIMS: usize>(x: &[f64], y: &[f64]) -> f64 {
+    assert_eq!(x.len(), DIMS, "Vectors must be `DIMS` in length");
+    assert_eq!(y.len(), DIMS, "Vectors must be `DIMS` in length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f64_xconst_squared_euclidean"),
+            avx512 => if DIMS.is_multiple_of(64) {
+                danger::f64_xconst_avx512_fma_euclidean::<DIMS>(x, y)
+            } else {
+                danger::f64_xany_avx512_fma_euclidean(x, y)
+            },
+            avx2_fma => if DIMS.is_multiple_of(32) {
+                danger::f64_xconst_avx2_fma_euclidean::<DIMS>(x, y)
+            } else {
+                danger::f64_xany_avx2_fma_euclidean(x, y)
+            },
+            avx2 => if DIMS.is_multiple_of(32) {
+                danger::f64_xconst_avx2_nofma_euclidean::<DIMS>(x, y)
+            } else {
+                danger::f64_xany_avx2_nofma_euclidean(x, y)
+            },
+            fallback => danger::generic_xany_fallback_nofma_euclidean(x, y),
+        )
+    }
+}
+
+/// Computes the Euclidean distance of two `f64` vectors, the square root of
+/// [f64_xany_squared_euclidean].
+///
+/// Comparisons between distances give the same ordering with or without the square
+/// root, so ranking and nearest neighbour searches can skip it by using
+/// [f64_xany_squared_euclidean] directly.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_euclidean(x: &[f64], y: &[f64]) -> f64 {
+    f64_xany_squared_euclidean(x, y).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{
+        assert_is_close,
+        get_sample_vectors,
+        simple_cosine,
+        simple_euclidean,
+    };
+
+    #[test]
+    fn test_f32_cosine() {
+        let (x, y) = get_sample_vectors::<f32>(1024);
+        let expected = simple_cosine(&x, &y);
+        assert_is_close(f32_xany_cosine(&x, &y), expected);
+        assert_is_close(f32_xconst_cosine::<1024>(&x, &y), expected);
+
+        let (x, y) = get_sample_vectors::<f32>(100);
+        let expected = simple_cosine(&x, &y);
+        assert_is_close(f32_xany_cosine(&x, &y), expected);
+        assert_is_close(f32_xconst_cosine::<100>(&x, &y), expected);
+    }
+
+    #[test]
+    fn test_f64_cosine() {
+        let (x, y) = get_sample_vectors::<f64>(1024);
+        let expected = simple_cosine(&x, &y) as f32;
+        assert_is_close(f64_xany_cosine(&x, &y) as f32, expected);
+        assert_is_close(f64_xconst_cosine::<1024>(&x, &y) as f32, expected);
+
+        let (x, y) = get_sample_vectors::<f64>(100);
+        let expected = simple_cosine(&x, &y) as f32;
+        assert_is_close(f64_xany_cosine(&x, &y) as f32, expected);
+        assert_is_close(f64_xconst_cosine::<100>(&x, &y) as f32, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectors must be the same length")]
+    fn test_xany_cosine_length_mismatch() {
+        let (x, y) = get_sample_vectors::<f64>(64);
+        f64_xany_cosine(&x, &y[..63]);
+    }
+
+    #[test]
+    fn test_f32_euclidean() {
+        let (x, y) = get_sample_vectors::<f32>(1024);
+        let expected = simple_euclidean(&x, &y);
+        assert_is_close(f32_xany_squared_euclidean(&x, &y), expected);
+        assert_is_close(f32_xconst_squared_euclidean::<1024>(&x, &y), expected);
+        assert_is_close(f32_xany_euclidean(&x, &y), expected.sqrt());
+
+        let (x, y) = get_sample_vectors::<f32>(100);
+        let expected = simple_euclidean(&x, &y);
+        assert_is_close(f32_xany_squared_euclidean(&x, &y), expected);
+        assert_is_close(f32_xconst_squared_euclidean::<100>(&x, &y), expected);
+        assert_is_close(f32_xany_euclidean(&x, &y), expected.sqrt());
+    }
+
+    #[test]
+    fn test_f64_euclidean() {
+        let (x, y) = get_sample_vectors::<f64>(1024);
+        let expected = simple_euclidean(&x, &y);
+        assert_is_close(f64_xany_squared_euclidean(&x, &y) as f32, expected as f32);
+        assert_is_close(
+            f64_xconst_squared_euclidean::<1024>(&x, &y) as f32,
+            expected as f32,
+        );
+        assert_is_close(f64_xany_euclidean(&x, &y) as f32, expected.sqrt() as f32);
+
+        assert_eq!(f64_xany_euclidean(&[3.0, 0.0], &[0.0, 4.0]), 5.0);
+    }
+}
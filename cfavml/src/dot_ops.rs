@@ -0,0 +1,292 @@
+//! Safe, runtime dispatched dot products.
+//!
+//! Each call detects the backend with [detected_backend](crate::backend::detected_backend)
+//! and runs the matching routine from [danger](crate::danger). This is convenient for
+//! one-off calls, hot loops should resolve the routines once with
+//! [VectorOps](crate::vector_ops::VectorOps) instead.
+//!
+//! ```
+//! use cfavml::dot_ops::{f32_xany_dot, f32_xconst_dot};
+//!
+//! let a = vec![1.0, 2.0, 3.0];
+//! let b = vec![4.0, 5.0, 6.0];
+//! assert_eq!(f32_xany_dot(&a, &b), 32.0);
+//! assert_eq!(f32_xconst_dot::<3>(&a, &b), 32.0);
+//! ```
+
+use crate::backend::dispatch_backend;
+use crate::danger;
+use crate::danger::Activation;
+
+/// Computes the dot product of two `f32` vectors.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_dot(x: &[f32], y: &[f32]) -> f32 {
+    assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f32_xany_dot"),
+            avx512 => danger::f32_xany_avx512_fma_dot(x, y),
+            avx2_fma => danger::f32_xany_avx2_fma_dot(x, y),
+            avx2 => danger::f32_xany_avx2_nofma_dot(x, y),
+            fallback => danger::generic_xany_fallback_nofma_dot(x, y),
+        )
+    }
+}
+
+/// Computes the dot product of two `[f32; DIMS]` vectors.
+///
+/// The fully unrolled `xconst` routines are used when `DIMS` is a multiple of their
+/// block size, `128` for AVX512 and `64` for AVX2, other dimensions use the `xany`
+/// routines.
+///
+/// # Panics
+///
+/// Panics if either vector is not `DIMS` in length.
+pub fn f32_xconst_dot<const DIMS: usize>(x: &[f32], y: &[f32]) -> f32 {
+    assert_eq!(x.len(), DIMS, "Vectors must be `DIMS` in length");
+    assert_eq!(y.len(), DIMS, "Vectors must be `DIMS` in length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f32_xconst_dot"),
+            avx512 => if DIMS.is_multiple_of(128) {
+                danger::f32_xconst_avx512_fma_dot::<DIMS>(x, y)
+            } else {
+                danger::f32_xany_avx512_fma_dot(x, y)
+            },
+            avx2_fma => if DIMS.is_multiple_of(64) {
+                danger::f32_xconst_avx2_fma_dot::<DIMS>(x, y)
+            } else {
+                danger::f32_xany_avx2_fma_dot(x, y)
+            },
+            avx2 => if DIMS.is_multiple_of(64) {
+                danger::f32_xconst_avx2_nofma_dot::<DIMS>(x, y)
+            } else {
+                danger::f32_xany_avx2_nofma_dot(x, y)
+            },
+            fallback => danger::generic_xany_fallback_nofma_dot(x, y),
+        )
+    }
+}
+
+/// Computes the dot product of two `f64` vectors.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_dot(x: &[f64], y: &[f64]) -> f64 {
+    assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f64_xany_dot"),
+            avx512 => danger::f64_xany_avx512_fma_dot(x, y),
+            avx2_fma => danger::f64_xany_avx2_fma_dot(x, y),
+            avx2 => danger::f64_xany_avx2_nofma_dot(x, y),
+            fallback => danger::generic_xany_fallback_nofma_dot(x, y),
+        )
+    }
+}
+
+/// Computes the dot product of two `[f64; DIMS]` vectors.
+///
+/// The fully unrolled `xconst` routines are used when `DIMS` is a multiple of their
+/// block size, `64` for AVX512 and `32` for AVX2, other dimensions use the `xany`
+/// routines.
+///
+/// # Panics
+///
+/// Panics if either vector is not `DIMS` in length.
+pub fn f64_xconst_dot<const DIMS: usize>(x: &[f64], y: &[f64]) -> f64 {
+    assert_eq!(x.len(), DIMS, "Vectors must be `DIMS` in length");
+    assert_eq!(y.len(), DIMS, "Vectors must be `DIMS` in length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f64_xconst_dot"),
+            avx512 => if DIMS.is_multiple_of(64) {
+                danger::f64_xconst_avx512_fma_dot::<DIMS>(x, y)
+            } else {
+                danger::f64_xany_avx512_fma_dot(x, y)
+            },
+            avx2_fma => if DIMS.is_multiple_of(32) {
+                danger::f64_xconst_avx2_fma_dot::<DIMS>(x, y)
+            } else {
+                danger::f64_xany_avx2_fma_dot(x, y)
+            },
+            avx2 => if DIMS.is_multiple_of(32) {
+                danger::f64_xconst_avx2_nofma_dot::<DIMS>(x, y)
+            } else {
+                danger::f64_xany_avx2_nofma_dot(x, y)
+            },
+            fallback => danger::generic_xany_fallback_nofma_dot(x, y),
+        )
+    }
+}
+
+/// Computes the dot product of two `f32` vectors, adds `bias` and applies the given
+/// [Activation] to the result in a single call.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_dot_bias_activation(
+    x: &[f32],
+    y: &[f32],
+    bias: f32,
+    activation: Activation,
+) -> f32 {
+    assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f32_xany_dot_bias_activation"),
+            avx512 => danger::f32_xany_avx512_fma_dot_bias_activation(x, y, bias, activation),
+            avx2_fma => danger::f32_xany_avx2_fma_dot_bias_activation(x, y, bias, activation),
+            avx2 => danger::f32_xany_avx2_nofma_dot_bias_activation(x, y, bias, activation),
+            fallback => danger::generic_xany_fallback_nofma_dot_bias_activation(
+                x, y, bias, activation,
+            ),
+        )
+    }
+}
+
+/// Computes `relu(x · y + bias)`, the fused epilogue of a ReLU layer, see
+/// [f32_xany_dot_bias_activation] for the other activations.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_dot_bias_relu(x: &[f32], y: &[f32], bias: f32) -> f32 {
+    f32_xany_dot_bias_activation(x, y, bias, Activation::Relu)
+}
+
+/// Computes the dot product of two `f16` vectors, converting each element to `f32`
+/// and accumulating in `f32`.
+///
+/// The AVX2 routines additionally require the F16C extension, which is checked
+/// separately from the backend, at compile time when the crate is built with it
+/// enabled and at runtime otherwise. CPUs without it convert each element with
+/// `half` in the fallback routine instead.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+#[cfg(feature = "half")]
+pub fn f16_xany_dot_f32(x: &[half::f16], y: &[half::f16]) -> f32 {
+    f16_xany_dot_plus_f32(x, y, 0.0)
+}
+
+/// Computes `bias` plus the dot product of two `f16` vectors, converting each
+/// element to `f32` and accumulating in `f32`.
+///
+/// See [f16_xany_dot_f32] for how the routine is selected.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+#[cfg(feature = "half")]
+pub fn f16_xany_dot_plus_f32(x: &[half::f16], y: &[half::f16], bias: f32) -> f32 {
+    assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => returned("f16_xany_dot_plus_f32"),
+            avx512 => if has_f16c() {
+                danger::f16_xany_avx2_fma_dot_plus_f32(x, y, bias)
+            } else {
+                danger::f16_xany_fallback_nofma_dot_plus_f32(x, y, bias)
+            },
+            avx2_fma => if has_f16c() {
+                danger::f16_xany_avx2_fma_dot_plus_f32(x, y, bias)
+            } else {
+                danger::f16_xany_fallback_nofma_dot_plus_f32(x, y, bias)
+            },
+            avx2 => if has_f16c() {
+                danger::f16_xany_avx2_nofma_dot_plus_f32(x, y, bias)
+            } else {
+                danger::f16_xany_fallback_nofma_dot_plus_f32(x, y, bias)
+            },
+            fallback => danger::f16_xany_fallback_nofma_dot_plus_f32(x, y, bias),
+        )
+    }
+}
+
+#[cfg(all(feature = "half", any(target_arch = "x86", target_arch = "x86_64")))]
+#[inline(always)]
+/// Returns if the F16C extension is available, resolved at compile time when the
+/// crate is built with it enabled and detected at runtime otherwise.
+fn has_f16c() -> bool {
+    cfg!(target_feature = "f16c") || std::arch::is_x86_feature_detected!("f16c")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_f32_dot() {
+        let (x, y) = get_sample_vectors::<f32>(1024);
+        let expected = simple_dot(&x, &y);
+        assert_is_close(f32_xany_dot(&x, &y), expected);
+        assert_is_close(f32_xconst_dot::<1024>(&x, &y), expected);
+
+        let (x, y) = get_sample_vectors::<f32>(100);
+        let expected = simple_dot(&x, &y);
+        assert_is_close(f32_xany_dot(&x, &y), expected);
+        assert_is_close(f32_xconst_dot::<100>(&x, &y), expected);
+    }
+
+    #[test]
+    fn test_f64_dot() {
+        let (x, y) = get_sample_vectors::<f64>(1024);
+        let expected = simple_dot(&x, &y) as f32;
+        assert_is_close(f64_xany_dot(&x, &y) as f32, expected);
+        assert_is_close(f64_xconst_dot::<1024>(&x, &y) as f32, expected);
+
+        let (x, y) = get_sample_vectors::<f64>(100);
+        let expected = simple_dot(&x, &y) as f32;
+        assert_is_close(f64_xany_dot(&x, &y) as f32, expected);
+        assert_is_close(f64_xconst_dot::<100>(&x, &y) as f32, expected);
+    }
+
+    #[test]
+    fn test_f32_dot_bias_relu() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let dot = simple_dot(&x, &y);
+        assert_is_close(f32_xany_dot_bias_relu(&x, &y, 1.5), dot + 1.5);
+        assert_eq!(f32_xany_dot_bias_relu(&x, &y, -dot - 1.0), 0.0);
+
+        let sigmoid = f32_xany_dot_bias_activation(&x, &y, -dot, Activation::Sigmoid);
+        assert_is_close(sigmoid, 0.5);
+    }
+
+    #[cfg(feature = "half")]
+    #[test]
+    fn test_f16_dot_f32() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let x = x.into_iter().map(half::f16::from_f32).collect::<Vec<_>>();
+        let y = y.into_iter().map(half::f16::from_f32).collect::<Vec<_>>();
+
+        let x_f32 = x.iter().map(|v| v.to_f32()).collect::<Vec<_>>();
+        let y_f32 = y.iter().map(|v| v.to_f32()).collect::<Vec<_>>();
+        let expected = simple_dot(&x_f32, &y_f32);
+
+        assert_is_close(f16_xany_dot_f32(&x, &y), expected);
+        assert_is_close(f16_xany_dot_plus_f32(&x, &y, 1.5), expected + 1.5);
+        let fallback = unsafe { danger::f16_xany_fallback_nofma_dot_f32(&x, &y) };
+        assert_is_close(fallback, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectors must be `DIMS` in length")]
+    fn test_xconst_dot_length_mismatch() {
+        let (x, y) = get_sample_vectors::<f32>(64);
+        f32_xconst_dot::<128>(&x, &y);
+    }
+}
@@ -9,8 +9,18 @@
     feature(stdarch_x86_avx512)
 )]
 
+pub mod backend;
+#[cfg(any(test, feature = "bench-utils"))]
+pub mod bench_utils;
 pub mod danger;
+pub mod distance_ops;
+pub mod dot_ops;
 pub mod math;
+pub mod overflow_ops;
+pub mod unary_ops;
+pub mod vector_ops;
 
 #[cfg(test)]
 mod test_utils;
+#[cfg(feature = "verify")]
+mod verify;
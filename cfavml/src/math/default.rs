@@ -1,4 +1,4 @@
-use super::Math;
+use super::{Math, TranscendentalMath};
 
 /// Standard math operations that apply no specialised handling.
 pub struct StdMath;
@@ -70,6 +70,38 @@ impl Math<f32> for StdMath {
     }
 }
 
+impl TranscendentalMath<f32> for StdMath {
+    #[inline(always)]
+    fn exp(a: f32) -> f32 {
+        a.exp()
+    }
+
+    #[inline(always)]
+    fn ln(a: f32) -> f32 {
+        a.ln()
+    }
+
+    #[inline(always)]
+    fn log2(a: f32) -> f32 {
+        a.log2()
+    }
+
+    #[inline(always)]
+    fn log10(a: f32) -> f32 {
+        a.log10()
+    }
+
+    #[inline(always)]
+    fn tanh(a: f32) -> f32 {
+        a.tanh()
+    }
+
+    #[inline(always)]
+    fn powf(a: f32, b: f32) -> f32 {
+        a.powf(b)
+    }
+}
+
 impl Math<f64> for StdMath {
     #[inline(always)]
     fn zero() -> f64 {
@@ -136,3 +168,35 @@ impl Math<f64> for StdMath {
         a / b
     }
 }
+
+impl TranscendentalMath<f64> for StdMath {
+    #[inline(always)]
+    fn exp(a: f64) -> f64 {
+        a.exp()
+    }
+
+    #[inline(always)]
+    fn ln(a: f64) -> f64 {
+        a.ln()
+    }
+
+    #[inline(always)]
+    fn log2(a: f64) -> f64 {
+        a.log2()
+    }
+
+    #[inline(always)]
+    fn log10(a: f64) -> f64 {
+        a.log10()
+    }
+
+    #[inline(always)]
+    fn tanh(a: f64) -> f64 {
+        a.tanh()
+    }
+
+    #[inline(always)]
+    fn powf(a: f64, b: f64) -> f64 {
+        a.powf(b)
+    }
+}
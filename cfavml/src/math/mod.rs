@@ -1,10 +1,12 @@
 mod default;
 #[cfg(feature = "nightly")]
 mod fast_math;
+mod transcendental;
 
 pub use default::StdMath;
 #[cfg(feature = "nightly")]
 pub use fast_math::FastMath;
+pub(crate) use transcendental::TranscendentalMath;
 
 #[cfg(not(feature = "nightly"))]
 pub type AutoMath = StdMath;
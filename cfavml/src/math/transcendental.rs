@@ -0,0 +1,26 @@
+use super::Math;
+
+/// Transcendental operations used by the fallback routines.
+///
+/// These are kept out of [Math] so implementing it downstream does not require
+/// them, the trait is not exported and only implemented for the crate's own math
+/// types.
+pub trait TranscendentalMath<T>: Math<T> {
+    /// Returns `e^a`.
+    fn exp(a: T) -> T;
+
+    /// Returns the natural logarithm of the value.
+    fn ln(a: T) -> T;
+
+    /// Returns the base 2 logarithm of the value.
+    fn log2(a: T) -> T;
+
+    /// Returns the base 10 logarithm of the value.
+    fn log10(a: T) -> T;
+
+    /// Returns the hyperbolic tangent of the value.
+    fn tanh(a: T) -> T;
+
+    /// Returns `a^b`.
+    fn powf(a: T, b: T) -> T;
+}
@@ -0,0 +1,169 @@
+//! Safe, runtime dispatched `u8` arithmetic with the overflow behaviour selected by
+//! the element type.
+//!
+//! `u8` and [`Wrapping<u8>`](core::num::Wrapping) wrap around on overflow while
+//! [`Saturating<u8>`](core::num::Saturating) clamps at `0` and `u8::MAX`, see
+//! [U8Overflow]. The AVX512BW routines are used when the CPU supports them, which
+//! [`backend_for::<u8>()`](crate::backend::backend_for) reports, all other CPUs use
+//! the fallback routines.
+//!
+//! ```
+//! use core::num::Saturating;
+//!
+//! use cfavml::overflow_ops::{u8_xany_add_vertical, u8_xany_sum};
+//!
+//! let mut a = vec![Saturating(250u8), Saturating(3)];
+//! u8_xany_add_vertical(&mut a, &[Saturating(10), Saturating(4)]);
+//! assert_eq!(a, [Saturating(255), Saturating(7)]);
+//! assert_eq!(u8_xany_sum(&a), Saturating(255));
+//! assert_eq!(u8_xany_sum(&[200u8, 100]), 44);
+//! ```
+
+use crate::backend::backend_for;
+use crate::danger;
+use crate::danger::U8Overflow;
+#[cfg(feature = "verify")]
+use crate::verify;
+
+/// Computes `x[i] + y[i]` element wise, wrapping or saturating on overflow as
+/// selected by the element type, writing the result to `x`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn u8_xany_add_vertical<T: U8Overflow>(x: &mut [T], y: &[T]) {
+    assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+    #[cfg(feature = "verify")]
+    let original = x.to_vec();
+
+    unsafe {
+        match backend_for::<u8>() {
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            crate::backend::Backend::Avx512 => {
+                danger::u8_xany_avx512bw_nofma_add_vertical(x, y)
+            },
+            _ => danger::u8_xany_fallback_nofma_add_vertical(x, y),
+        }
+    }
+
+    #[cfg(feature = "verify")]
+    verify_matches_fallback("u8_xany_add_vertical", x, || {
+        let mut expected = original;
+        unsafe { danger::u8_xany_fallback_nofma_add_vertical(&mut expected, y) };
+        expected
+    });
+}
+
+/// Computes `x[i] - y[i]` element wise, wrapping or saturating on overflow as
+/// selected by the element type, writing the result to `x`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn u8_xany_sub_vertical<T: U8Overflow>(x: &mut [T], y: &[T]) {
+    assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+    #[cfg(feature = "verify")]
+    let original = x.to_vec();
+
+    unsafe {
+        match backend_for::<u8>() {
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            crate::backend::Backend::Avx512 => {
+                danger::u8_xany_avx512bw_nofma_sub_vertical(x, y)
+            },
+            _ => danger::u8_xany_fallback_nofma_sub_vertical(x, y),
+        }
+    }
+
+    #[cfg(feature = "verify")]
+    verify_matches_fallback("u8_xany_sub_vertical", x, || {
+        let mut expected = original;
+        unsafe { danger::u8_xany_fallback_nofma_sub_vertical(&mut expected, y) };
+        expected
+    });
+}
+
+/// Sums all elements of the vector, wrapping or saturating the total as selected by
+/// the element type.
+pub fn u8_xany_sum<T: U8Overflow>(x: &[T]) -> T {
+    let result = unsafe {
+        match backend_for::<u8>() {
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            crate::backend::Backend::Avx512 => danger::u8_xany_avx512bw_nofma_sum(x),
+            _ => danger::u8_xany_fallback_nofma_sum(x),
+        }
+    };
+
+    #[cfg(feature = "verify")]
+    verify_matches_fallback("u8_xany_sum", &[result], || {
+        vec![unsafe { danger::u8_xany_fallback_nofma_sum(x) }]
+    });
+    result
+}
+
+#[cfg(feature = "verify")]
+/// Checks the `result` of the selected backend against the output of `fallback`,
+/// comparing the `u8` values the element types wrap.
+fn verify_matches_fallback<T: U8Overflow>(
+    op: &str,
+    result: &[T],
+    fallback: impl FnOnce() -> Vec<T>,
+) {
+    let backend = backend_for::<u8>();
+    if backend != crate::backend::Backend::Fallback {
+        let expected = fallback();
+        verify::assert_matches_fallback(
+            op,
+            backend,
+            danger::overflow_as_u8(result),
+            danger::overflow_as_u8(&expected),
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::num::{Saturating, Wrapping};
+
+    use super::*;
+    use crate::test_utils::get_sample_vectors;
+
+    #[test]
+    fn test_u8_overflow_ops() {
+        let (x, y) = get_sample_vectors::<u8>(531);
+
+        let mut result = x.iter().copied().map(Wrapping).collect::<Vec<_>>();
+        let other = y.iter().copied().map(Wrapping).collect::<Vec<_>>();
+        u8_xany_add_vertical(&mut result, &other);
+        let expected = x.iter().zip(&y).map(|(x, y)| Wrapping(x.wrapping_add(*y)));
+        assert!(result.iter().copied().eq(expected));
+
+        let mut result = x.iter().copied().map(Saturating).collect::<Vec<_>>();
+        let other = y.iter().copied().map(Saturating).collect::<Vec<_>>();
+        u8_xany_sub_vertical(&mut result, &other);
+        let expected = x
+            .iter()
+            .zip(&y)
+            .map(|(x, y)| Saturating(x.saturating_sub(*y)));
+        assert!(result.iter().copied().eq(expected));
+
+        let total = x.iter().map(|v| *v as u64).sum::<u64>();
+        assert_eq!(u8_xany_sum(&x), total as u8);
+        assert_eq!(u8_xany_sum(&result), Saturating(u8::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectors must be the same length")]
+    fn test_u8_add_vertical_length_mismatch() {
+        u8_xany_add_vertical(&mut [1u8, 2], &[1]);
+    }
+}
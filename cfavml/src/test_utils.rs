@@ -1,28 +1,7 @@
-use rand::distributions::{Distribution, Standard};
-use rand::{Rng, SeedableRng};
-use rand_chacha::ChaCha8Rng;
-
+pub use crate::bench_utils::get_sample_vectors;
 use crate::danger::cosine;
 use crate::math::{AutoMath, Math};
 
-const SEED: u64 = 34535345353;
-
-pub fn get_sample_vectors<T>(size: usize) -> (Vec<T>, Vec<T>)
-where
-    Standard: Distribution<T>,
-{
-    let mut rng = ChaCha8Rng::seed_from_u64(SEED);
-
-    let mut x = Vec::new();
-    let mut y = Vec::new();
-    for _ in 0..size {
-        x.push(rng.gen());
-        y.push(rng.gen());
-    }
-
-    (x, y)
-}
-
 /// Checks if x is within a certain threshold distance of each other.
 pub fn is_close(x: f32, y: f32) -> bool {
     let max = x.max(y);
@@ -98,3 +77,73 @@ pub fn assert_is_close_vector_f64(x: &[f64], y: &[f64]) {
         );
     }
 }
+
+/// Multiplies two interleaved complex vectors element wise.
+pub fn simple_complex_mul(x: &[f32], y: &[f32]) -> Vec<f32> {
+    let mut result = Vec::with_capacity(x.len());
+
+    for i in (0..x.len()).step_by(2) {
+        result.push((x[i] * y[i]) - (x[i + 1] * y[i + 1]));
+        result.push((x[i] * y[i + 1]) + (x[i + 1] * y[i]));
+    }
+
+    result
+}
+
+/// Computes the conjugated dot product of two interleaved complex vectors.
+pub fn simple_complex_dot_conj(x: &[f32], y: &[f32]) -> (f32, f32) {
+    let mut re = 0.0;
+    let mut im = 0.0;
+
+    for i in (0..x.len()).step_by(2) {
+        re += (x[i] * y[i]) + (x[i + 1] * y[i + 1]);
+        im += (x[i] * y[i + 1]) - (x[i + 1] * y[i]);
+    }
+
+    (re, im)
+}
+
+/// Transposes a row-major `rows x cols` matrix.
+pub fn simple_transpose<T: Copy>(x: &[T], rows: usize, cols: usize) -> Vec<T> {
+    let mut result = Vec::with_capacity(x.len());
+
+    for c in 0..cols {
+        for r in 0..rows {
+            result.push(x[r * cols + c]);
+        }
+    }
+
+    result
+}
+
+/// Computes `C = A·B` for row-major matrices using the naive triple loop.
+pub fn simple_gemm(a: &[f32], b: &[f32], m: usize, k: usize, n: usize) -> Vec<f32> {
+    let mut result = vec![0.0; m * n];
+
+    for i in 0..m {
+        for j in 0..n {
+            let mut total = 0.0;
+            for p in 0..k {
+                total += a[i * k + p] * b[p * n + j];
+            }
+            result[i * n + j] = total;
+        }
+    }
+
+    result
+}
+
+/// Evaluates the polynomial at each element of `x` by summing the powers directly,
+/// coefficients are ordered from the highest degree.
+pub fn simple_polyval(coeffs: &[f32], x: &[f32]) -> Vec<f32> {
+    x.iter()
+        .map(|x| {
+            coeffs
+                .iter()
+                .rev()
+                .enumerate()
+                .map(|(power, c)| c * x.powi(power as i32))
+                .sum()
+        })
+        .collect()
+}
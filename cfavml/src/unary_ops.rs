@@ -0,0 +1,1664 @@
+//! Safe, runtime dispatched element wise operations.
+//!
+//! Each call detects the backend with [detected_backend](crate::backend::detected_backend)
+//! and runs the matching routine from [danger](crate::danger). The AVX512 backend uses
+//! the AVX2 routines where there is no dedicated AVX512 routine.
+//!
+//! ```
+//! use cfavml::unary_ops::{f32_xany_abs, i32_xany_abs_inplace};
+//!
+//! let mut result = vec![0.0; 3];
+//! f32_xany_abs(&[-1.0, 2.0, -3.0], &mut result);
+//! assert_eq!(result, [1.0, 2.0, 3.0]);
+//!
+//! let mut a = vec![-4, 5, -6];
+//! i32_xany_abs_inplace(&mut a);
+//! assert_eq!(a, [4, 5, 6]);
+//! ```
+
+use crate::backend::dispatch_backend;
+use crate::danger;
+use crate::danger::RoundingMode;
+
+#[cfg(feature = "verify")]
+/// The relative error of the AVX2 reciprocal approximations without `refine`,
+/// `1.5 * 2^-12` rounded up, which the `verify` feature checks them with.
+const RECIP_TOLERANCE: f64 = 4e-4;
+
+/// Computes the absolute value of each element of `x`, writing the result to `result`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_abs(x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_abs", result),
+            avx512 => danger::f32_xany_avx2_nofma_abs(x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_abs(x, result),
+            avx2 => danger::f32_xany_avx2_nofma_abs(x, result),
+            fallback => danger::generic_xany_fallback_nofma_abs(x, result),
+        )
+    }
+}
+
+/// Computes the absolute value of each element of `x` in place.
+pub fn f32_xany_abs_inplace(x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_abs_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_abs_inplace(x),
+            avx2_fma => danger::f32_xany_avx2_nofma_abs_inplace(x),
+            avx2 => danger::f32_xany_avx2_nofma_abs_inplace(x),
+            fallback => danger::generic_xany_fallback_nofma_abs_inplace(x),
+        )
+    }
+}
+
+/// Computes the absolute value of each element of `x`, writing the result to `result`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_abs(x: &[f64], result: &mut [f64]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f64_xany_abs", result),
+            avx512 => danger::f64_xany_avx2_nofma_abs(x, result),
+            avx2_fma => danger::f64_xany_avx2_nofma_abs(x, result),
+            avx2 => danger::f64_xany_avx2_nofma_abs(x, result),
+            fallback => danger::generic_xany_fallback_nofma_abs(x, result),
+        )
+    }
+}
+
+/// Computes the absolute value of each element of `x` in place.
+pub fn f64_xany_abs_inplace(x: &mut [f64]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f64_xany_abs_inplace", x),
+            avx512 => danger::f64_xany_avx2_nofma_abs_inplace(x),
+            avx2_fma => danger::f64_xany_avx2_nofma_abs_inplace(x),
+            avx2 => danger::f64_xany_avx2_nofma_abs_inplace(x),
+            fallback => danger::generic_xany_fallback_nofma_abs_inplace(x),
+        )
+    }
+}
+
+/// Computes the absolute value of each element of `x`, writing the result to `result`.
+///
+/// `i32::MIN` wraps back to `i32::MIN`, the same as [i32::wrapping_abs].
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn i32_xany_abs(x: &[i32], result: &mut [i32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("i32_xany_abs", result),
+            avx512 => danger::i32_xany_avx2_nofma_abs(x, result),
+            avx2_fma => danger::i32_xany_avx2_nofma_abs(x, result),
+            avx2 => danger::i32_xany_avx2_nofma_abs(x, result),
+            fallback => danger::i32_xany_fallback_nofma_abs(x, result),
+        )
+    }
+}
+
+/// Computes the absolute value of each element of `x` in place.
+///
+/// `i32::MIN` wraps back to `i32::MIN`, the same as [i32::wrapping_abs].
+pub fn i32_xany_abs_inplace(x: &mut [i32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("i32_xany_abs_inplace", x),
+            avx512 => danger::i32_xany_avx2_nofma_abs_inplace(x),
+            avx2_fma => danger::i32_xany_avx2_nofma_abs_inplace(x),
+            avx2 => danger::i32_xany_avx2_nofma_abs_inplace(x),
+            fallback => danger::i32_xany_fallback_nofma_abs_inplace(x),
+        )
+    }
+}
+
+/// Computes the square root of each element of `x`, writing the result to `result`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_sqrt(x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_sqrt", result),
+            avx512 => danger::f32_xany_avx2_nofma_sqrt(x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_sqrt(x, result),
+            avx2 => danger::f32_xany_avx2_nofma_sqrt(x, result),
+            fallback => danger::generic_xany_fallback_nofma_sqrt(x, result),
+        )
+    }
+}
+
+/// Computes the square root of each element of `x` in place.
+pub fn f32_xany_sqrt_inplace(x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_sqrt_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_sqrt_inplace(x),
+            avx2_fma => danger::f32_xany_avx2_nofma_sqrt_inplace(x),
+            avx2 => danger::f32_xany_avx2_nofma_sqrt_inplace(x),
+            fallback => danger::generic_xany_fallback_nofma_sqrt_inplace(x),
+        )
+    }
+}
+
+/// Computes the square root of each element of the `[f32; DIMS]` vector `x`, writing
+/// the result to `result`.
+///
+/// The `xconst` routines are used when `DIMS` is a multiple of `32`, other
+/// dimensions use the `xany` routines.
+///
+/// # Panics
+///
+/// Panics if either vector is not `DIMS` in length.
+pub fn f32_xconst_sqrt<const DIMS: usize>(x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), DIMS, "Vectors must be `DIMS` in length");
+    assert_eq!(result.len(), DIMS, "Vectors must be `DIMS` in length");
+
+    if !DIMS.is_multiple_of(32) {
+        return f32_xany_sqrt(x, result);
+    }
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xconst_sqrt", result),
+            avx512 => danger::f32_xconst_avx2_nofma_sqrt::<DIMS>(x, result),
+            avx2_fma => danger::f32_xconst_avx2_nofma_sqrt::<DIMS>(x, result),
+            avx2 => danger::f32_xconst_avx2_nofma_sqrt::<DIMS>(x, result),
+            fallback => danger::generic_xany_fallback_nofma_sqrt(x, result),
+        )
+    }
+}
+
+/// Computes the square root of each element of the `[f32; DIMS]` vector `x` in place.
+///
+/// The `xconst` routines are used when `DIMS` is a multiple of `32`, other
+/// dimensions use the `xany` routines.
+///
+/// # Panics
+///
+/// Panics if `x` is not `DIMS` in length.
+pub fn f32_xconst_sqrt_inplace<const DIMS: usize>(x: &mut [f32]) {
+    assert_eq!(x.len(), DIMS, "Vectors must be `DIMS` in length");
+
+    if !DIMS.is_multiple_of(32) {
+        return f32_xany_sqrt_inplace(x);
+    }
+
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xconst_sqrt_inplace", x),
+            avx512 => danger::f32_xconst_avx2_nofma_sqrt_inplace::<DIMS>(x),
+            avx2_fma => danger::f32_xconst_avx2_nofma_sqrt_inplace::<DIMS>(x),
+            avx2 => danger::f32_xconst_avx2_nofma_sqrt_inplace::<DIMS>(x),
+            fallback => danger::generic_xany_fallback_nofma_sqrt_inplace(x),
+        )
+    }
+}
+
+/// Computes the square root of each element of `x`, writing the result to `result`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_sqrt(x: &[f64], result: &mut [f64]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f64_xany_sqrt", result),
+            avx512 => danger::f64_xany_avx2_nofma_sqrt(x, result),
+            avx2_fma => danger::f64_xany_avx2_nofma_sqrt(x, result),
+            avx2 => danger::f64_xany_avx2_nofma_sqrt(x, result),
+            fallback => danger::generic_xany_fallback_nofma_sqrt(x, result),
+        )
+    }
+}
+
+/// Computes the square root of each element of `x` in place.
+pub fn f64_xany_sqrt_inplace(x: &mut [f64]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f64_xany_sqrt_inplace", x),
+            avx512 => danger::f64_xany_avx2_nofma_sqrt_inplace(x),
+            avx2_fma => danger::f64_xany_avx2_nofma_sqrt_inplace(x),
+            avx2 => danger::f64_xany_avx2_nofma_sqrt_inplace(x),
+            fallback => danger::generic_xany_fallback_nofma_sqrt_inplace(x),
+        )
+    }
+}
+
+/// Computes the square root of each element of the `[f64; DIMS]` vector `x`, writing
+/// the result to `result`.
+///
+/// The `xconst` routines are used when `DIMS` is a multiple of `16`, other
+/// dimensions use the `xany` routines.
+///
+/// # Panics
+///
+/// Panics if either vector is not `DIMS` in length.
+pub fn f64_xconst_sqrt<const DIMS: usize>(x: &[f64], result: &mut [f64]) {
+    assert_eq!(x.len(), DIMS, "Vectors must be `DIMS` in length");
+    assert_eq!(result.len(), DIMS, "Vectors must be `DIMS` in length");
+
+    if !DIMS.is_multiple_of(16) {
+        return f64_xany_sqrt(x, result);
+    }
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f64_xconst_sqrt", result),
+            avx512 => danger::f64_xconst_avx2_nofma_sqrt::<DIMS>(x, result),
+            avx2_fma => danger::f64_xconst_avx2_nofma_sqrt::<DIMS>(x, result),
+            avx2 => danger::f64_xconst_avx2_nofma_sqrt::<DIMS>(x, result),
+            fallback => danger::generic_xany_fallback_nofma_sqrt(x, result),
+        )
+    }
+}
+
+/// Computes the square root of each element of the `[f64; DIMS]` vector `x` in place.
+///
+/// The `xconst` routines are used when `DIMS` is a multiple of `16`, other
+/// dimensions use the `xany` routines.
+///
+/// # Panics
+///
+/// Panics if `x` is not `DIMS` in length.
+pub fn f64_xconst_sqrt_inplace<const DIMS: usize>(x: &mut [f64]) {
+    assert_eq!(x.len(), DIMS, "Vectors must be `DIMS` in length");
+
+    if !DIMS.is_multiple_of(16) {
+        return f64_xany_sqrt_inplace(x);
+    }
+
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f64_xconst_sqrt_inplace", x),
+            avx512 => danger::f64_xconst_avx2_nofma_sqrt_inplace::<DIMS>(x),
+            avx2_fma => danger::f64_xconst_avx2_nofma_sqrt_inplace::<DIMS>(x),
+            avx2 => danger::f64_xconst_avx2_nofma_sqrt_inplace::<DIMS>(x),
+            fallback => danger::generic_xany_fallback_nofma_sqrt_inplace(x),
+        )
+    }
+}
+
+/// Approximates `1 / x` of each element of `x`, writing the result to `result`.
+///
+/// The AVX2 routines have a relative error of at most `1.5 * 2^-12`, with `refine` a
+/// Newton-Raphson step brings it to within a few ULP. The fallback routines are
+/// always exact.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_recip(refine: bool, x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_recip", result, tolerance = RECIP_TOLERANCE),
+            avx512 => danger::f32_xany_avx2_nofma_recip(refine, x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_recip(refine, x, result),
+            avx2 => danger::f32_xany_avx2_nofma_recip(refine, x, result),
+            fallback => danger::generic_xany_fallback_nofma_recip(x, result),
+        )
+    }
+}
+
+/// Approximates `1 / x` of each element of `x` in place, see [f32_xany_recip].
+pub fn f32_xany_recip_inplace(refine: bool, x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_recip_inplace", x, tolerance = RECIP_TOLERANCE),
+            avx512 => danger::f32_xany_avx2_nofma_recip_inplace(refine, x),
+            avx2_fma => danger::f32_xany_avx2_nofma_recip_inplace(refine, x),
+            avx2 => danger::f32_xany_avx2_nofma_recip_inplace(refine, x),
+            fallback => danger::generic_xany_fallback_nofma_recip_inplace(x),
+        )
+    }
+}
+
+/// Approximates `1 / sqrt(x)` of each element of `x`, writing the result to `result`.
+///
+/// The AVX2 routines have a relative error of at most `1.5 * 2^-12`, with `refine` a
+/// Newton-Raphson step brings it to within a few ULP. The fallback routines are
+/// always exact.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_rsqrt(refine: bool, x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_rsqrt", result, tolerance = RECIP_TOLERANCE),
+            avx512 => danger::f32_xany_avx2_nofma_rsqrt(refine, x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_rsqrt(refine, x, result),
+            avx2 => danger::f32_xany_avx2_nofma_rsqrt(refine, x, result),
+            fallback => danger::generic_xany_fallback_nofma_rsqrt(x, result),
+        )
+    }
+}
+
+/// Approximates `1 / sqrt(x)` of each element of `x` in place, see [f32_xany_rsqrt].
+pub fn f32_xany_rsqrt_inplace(refine: bool, x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_rsqrt_inplace", x, tolerance = RECIP_TOLERANCE),
+            avx512 => danger::f32_xany_avx2_nofma_rsqrt_inplace(refine, x),
+            avx2_fma => danger::f32_xany_avx2_nofma_rsqrt_inplace(refine, x),
+            avx2 => danger::f32_xany_avx2_nofma_rsqrt_inplace(refine, x),
+            fallback => danger::generic_xany_fallback_nofma_rsqrt_inplace(x),
+        )
+    }
+}
+
+/// Computes `e^x` of each element of `x`, writing the result to `result`.
+///
+/// The AVX2 routines use a polynomial approximation accurate to within a few ULP, inputs
+/// above `88.0` produce `inf` and results which would be subnormal are flushed to `0.0`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_exp(x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_exp", result),
+            avx512 => danger::f32_xany_avx2_nofma_exp(x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_exp(x, result),
+            avx2 => danger::f32_xany_avx2_nofma_exp(x, result),
+            fallback => danger::generic_xany_fallback_nofma_exp(x, result),
+        )
+    }
+}
+
+/// Computes `e^x` of each element of `x` in place, see [f32_xany_exp].
+pub fn f32_xany_exp_inplace(x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_exp_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_exp_inplace(x),
+            avx2_fma => danger::f32_xany_avx2_nofma_exp_inplace(x),
+            avx2 => danger::f32_xany_avx2_nofma_exp_inplace(x),
+            fallback => danger::generic_xany_fallback_nofma_exp_inplace(x),
+        )
+    }
+}
+
+/// Computes `e^x` of each element of `x`, writing the result to `result`.
+///
+/// The AVX2 routines use a polynomial approximation accurate to within a few ULP, inputs
+/// above `709.0` produce `inf` and results which would be subnormal are flushed to `0.0`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_exp(x: &[f64], result: &mut [f64]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f64_xany_exp", result),
+            avx512 => danger::f64_xany_avx2_nofma_exp(x, result),
+            avx2_fma => danger::f64_xany_avx2_nofma_exp(x, result),
+            avx2 => danger::f64_xany_avx2_nofma_exp(x, result),
+            fallback => danger::generic_xany_fallback_nofma_exp(x, result),
+        )
+    }
+}
+
+/// Computes `e^x` of each element of `x` in place, see [f64_xany_exp].
+pub fn f64_xany_exp_inplace(x: &mut [f64]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f64_xany_exp_inplace", x),
+            avx512 => danger::f64_xany_avx2_nofma_exp_inplace(x),
+            avx2_fma => danger::f64_xany_avx2_nofma_exp_inplace(x),
+            avx2 => danger::f64_xany_avx2_nofma_exp_inplace(x),
+            fallback => danger::generic_xany_fallback_nofma_exp_inplace(x),
+        )
+    }
+}
+
+/// Computes the natural logarithm of each element of `x`, writing the result to `result`.
+///
+/// With `precise` the AVX2 routines have a relative error within a few ULP, otherwise
+/// a cheaper approximation is used with a relative error of roughly `2e-5`. The
+/// fallback routines are always exact.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_ln(precise: bool, x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_ln", result),
+            avx512 => danger::f32_xany_avx2_nofma_ln(precise, x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_ln(precise, x, result),
+            avx2 => danger::f32_xany_avx2_nofma_ln(precise, x, result),
+            fallback => danger::generic_xany_fallback_nofma_ln(x, result),
+        )
+    }
+}
+
+/// Computes the natural logarithm of each element of `x` in place, see [f32_xany_ln].
+pub fn f32_xany_ln_inplace(precise: bool, x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_ln_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_ln_inplace(precise, x),
+            avx2_fma => danger::f32_xany_avx2_nofma_ln_inplace(precise, x),
+            avx2 => danger::f32_xany_avx2_nofma_ln_inplace(precise, x),
+            fallback => danger::generic_xany_fallback_nofma_ln_inplace(x),
+        )
+    }
+}
+
+/// Computes the base 2 logarithm of each element of `x`, writing the result to `result`.
+///
+/// See [f32_xany_ln] for the accuracy with and without `precise`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_log2(precise: bool, x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_log2", result),
+            avx512 => danger::f32_xany_avx2_nofma_log2(precise, x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_log2(precise, x, result),
+            avx2 => danger::f32_xany_avx2_nofma_log2(precise, x, result),
+            fallback => danger::generic_xany_fallback_nofma_log2(x, result),
+        )
+    }
+}
+
+/// Computes the base 2 logarithm of each element of `x` in place, see [f32_xany_log2].
+pub fn f32_xany_log2_inplace(precise: bool, x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_log2_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_log2_inplace(precise, x),
+            avx2_fma => danger::f32_xany_avx2_nofma_log2_inplace(precise, x),
+            avx2 => danger::f32_xany_avx2_nofma_log2_inplace(precise, x),
+            fallback => danger::generic_xany_fallback_nofma_log2_inplace(x),
+        )
+    }
+}
+
+/// Computes the base 10 logarithm of each element of `x`, writing the result to `result`.
+///
+/// See [f32_xany_ln] for the accuracy with and without `precise`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_log10(precise: bool, x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_log10", result),
+            avx512 => danger::f32_xany_avx2_nofma_log10(precise, x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_log10(precise, x, result),
+            avx2 => danger::f32_xany_avx2_nofma_log10(precise, x, result),
+            fallback => danger::generic_xany_fallback_nofma_log10(x, result),
+        )
+    }
+}
+
+/// Computes the base 10 logarithm of each element of `x` in place, see [f32_xany_log10].
+pub fn f32_xany_log10_inplace(precise: bool, x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_log10_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_log10_inplace(precise, x),
+            avx2_fma => danger::f32_xany_avx2_nofma_log10_inplace(precise, x),
+            avx2 => danger::f32_xany_avx2_nofma_log10_inplace(precise, x),
+            fallback => danger::generic_xany_fallback_nofma_log10_inplace(x),
+        )
+    }
+}
+
+/// Computes the natural logarithm of each element of `x`, writing the result to `result`.
+///
+/// With `precise` the AVX2 routines have a relative error within a few ULP, otherwise
+/// a cheaper approximation is used with a relative error of roughly `1e-7`. The
+/// fallback routines are always exact.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_ln(precise: bool, x: &[f64], result: &mut [f64]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f64_xany_ln", result),
+            avx512 => danger::f64_xany_avx2_nofma_ln(precise, x, result),
+            avx2_fma => danger::f64_xany_avx2_nofma_ln(precise, x, result),
+            avx2 => danger::f64_xany_avx2_nofma_ln(precise, x, result),
+            fallback => danger::generic_xany_fallback_nofma_ln(x, result),
+        )
+    }
+}
+
+/// Computes the natural logarithm of each element of `x` in place, see [f64_xany_ln].
+pub fn f64_xany_ln_inplace(precise: bool, x: &mut [f64]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f64_xany_ln_inplace", x),
+            avx512 => danger::f64_xany_avx2_nofma_ln_inplace(precise, x),
+            avx2_fma => danger::f64_xany_avx2_nofma_ln_inplace(precise, x),
+            avx2 => danger::f64_xany_avx2_nofma_ln_inplace(precise, x),
+            fallback => danger::generic_xany_fallback_nofma_ln_inplace(x),
+        )
+    }
+}
+
+/// Computes the base 2 logarithm of each element of `x`, writing the result to `result`.
+///
+/// See [f64_xany_ln] for the accuracy with and without `precise`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_log2(precise: bool, x: &[f64], result: &mut [f64]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f64_xany_log2", result),
+            avx512 => danger::f64_xany_avx2_nofma_log2(precise, x, result),
+            avx2_fma => danger::f64_xany_avx2_nofma_log2(precise, x, result),
+            avx2 => danger::f64_xany_avx2_nofma_log2(precise, x, result),
+            fallback => danger::generic_xany_fallback_nofma_log2(x, result),
+        )
+    }
+}
+
+/// Computes the base 2 logarithm of each element of `x` in place, see [f64_xany_log2].
+pub fn f64_xany_log2_inplace(precise: bool, x: &mut [f64]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f64_xany_log2_inplace", x),
+            avx512 => danger::f64_xany_avx2_nofma_log2_inplace(precise, x),
+            avx2_fma => danger::f64_xany_avx2_nofma_log2_inplace(precise, x),
+            avx2 => danger::f64_xany_avx2_nofma_log2_inplace(precise, x),
+            fallback => danger::generic_xany_fallback_nofma_log2_inplace(x),
+        )
+    }
+}
+
+/// Computes the base 10 logarithm of each element of `x`, writing the result to `result`.
+///
+/// See [f64_xany_ln] for the accuracy with and without `precise`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_log10(precise: bool, x: &[f64], result: &mut [f64]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f64_xany_log10", result),
+            avx512 => danger::f64_xany_avx2_nofma_log10(precise, x, result),
+            avx2_fma => danger::f64_xany_avx2_nofma_log10(precise, x, result),
+            avx2 => danger::f64_xany_avx2_nofma_log10(precise, x, result),
+            fallback => danger::generic_xany_fallback_nofma_log10(x, result),
+        )
+    }
+}
+
+/// Computes the base 10 logarithm of each element of `x` in place, see [f64_xany_log10].
+pub fn f64_xany_log10_inplace(precise: bool, x: &mut [f64]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f64_xany_log10_inplace", x),
+            avx512 => danger::f64_xany_avx2_nofma_log10_inplace(precise, x),
+            avx2_fma => danger::f64_xany_avx2_nofma_log10_inplace(precise, x),
+            avx2 => danger::f64_xany_avx2_nofma_log10_inplace(precise, x),
+            fallback => danger::generic_xany_fallback_nofma_log10_inplace(x),
+        )
+    }
+}
+
+/// Computes the hyperbolic tangent of each element of `x`, writing the result to
+/// `result`.
+///
+/// The AVX2 routines use a rational approximation accurate to within a few ULP.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_tanh(x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_tanh", result),
+            avx512 => danger::f32_xany_avx2_nofma_tanh(x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_tanh(x, result),
+            avx2 => danger::f32_xany_avx2_nofma_tanh(x, result),
+            fallback => danger::generic_xany_fallback_nofma_tanh(x, result),
+        )
+    }
+}
+
+/// Computes the hyperbolic tangent of each element of `x` in place, see
+/// [f32_xany_tanh].
+pub fn f32_xany_tanh_inplace(x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_tanh_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_tanh_inplace(x),
+            avx2_fma => danger::f32_xany_avx2_nofma_tanh_inplace(x),
+            avx2 => danger::f32_xany_avx2_nofma_tanh_inplace(x),
+            fallback => danger::generic_xany_fallback_nofma_tanh_inplace(x),
+        )
+    }
+}
+
+/// Computes the hyperbolic tangent of each element of `x`, writing the result to
+/// `result`.
+///
+/// The AVX2 routines use a rational approximation accurate to within a few ULP.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_tanh(x: &[f64], result: &mut [f64]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f64_xany_tanh", result),
+            avx512 => danger::f64_xany_avx2_nofma_tanh(x, result),
+            avx2_fma => danger::f64_xany_avx2_nofma_tanh(x, result),
+            avx2 => danger::f64_xany_avx2_nofma_tanh(x, result),
+            fallback => danger::generic_xany_fallback_nofma_tanh(x, result),
+        )
+    }
+}
+
+/// Computes the hyperbolic tangent of each element of `x` in place, see
+/// [f64_xany_tanh].
+pub fn f64_xany_tanh_inplace(x: &mut [f64]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f64_xany_tanh_inplace", x),
+            avx512 => danger::f64_xany_avx2_nofma_tanh_inplace(x),
+            avx2_fma => danger::f64_xany_avx2_nofma_tanh_inplace(x),
+            avx2 => danger::f64_xany_avx2_nofma_tanh_inplace(x),
+            fallback => danger::generic_xany_fallback_nofma_tanh_inplace(x),
+        )
+    }
+}
+
+/// Applies the ReLU activation `max(x, 0)` to each element of `x`, writing the result
+/// to `result`.
+///
+/// Negative inputs produce `0.0` and `NaN` inputs are propagated.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_relu(x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_relu", result),
+            avx512 => danger::f32_xany_avx2_nofma_relu(x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_relu(x, result),
+            avx2 => danger::f32_xany_avx2_nofma_relu(x, result),
+            fallback => danger::f32_xany_fallback_nofma_relu(x, result),
+        )
+    }
+}
+
+/// Applies the ReLU activation to each element of `x` in place, see [f32_xany_relu].
+pub fn f32_xany_relu_inplace(x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_relu_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_relu_inplace(x),
+            avx2_fma => danger::f32_xany_avx2_nofma_relu_inplace(x),
+            avx2 => danger::f32_xany_avx2_nofma_relu_inplace(x),
+            fallback => danger::f32_xany_fallback_nofma_relu_inplace(x),
+        )
+    }
+}
+
+/// Applies the leaky ReLU activation to each element of `x`, writing the result to
+/// `result`, positive values are kept as is and the rest are multiplied by `slope`.
+///
+/// `NaN` inputs are propagated.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_leaky_relu(slope: f32, x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_leaky_relu", result),
+            avx512 => danger::f32_xany_avx2_nofma_leaky_relu(slope, x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_leaky_relu(slope, x, result),
+            avx2 => danger::f32_xany_avx2_nofma_leaky_relu(slope, x, result),
+            fallback => danger::f32_xany_fallback_nofma_leaky_relu(slope, x, result),
+        )
+    }
+}
+
+/// Applies the leaky ReLU activation to each element of `x` in place, see
+/// [f32_xany_leaky_relu].
+pub fn f32_xany_leaky_relu_inplace(slope: f32, x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_leaky_relu_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_leaky_relu_inplace(slope, x),
+            avx2_fma => danger::f32_xany_avx2_nofma_leaky_relu_inplace(slope, x),
+            avx2 => danger::f32_xany_avx2_nofma_leaky_relu_inplace(slope, x),
+            fallback => danger::f32_xany_fallback_nofma_leaky_relu_inplace(slope, x),
+        )
+    }
+}
+
+/// Applies the GELU activation to each element of `x`, writing the result to `result`.
+///
+/// This is the tanh approximation of GELU, equivalent to `gelu(x, approximate="tanh")`
+/// in PyTorch, fused into a single pass over `x` rather than separate `mul`, `add` and
+/// `tanh` passes.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_gelu(x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_gelu", result),
+            avx512 => danger::f32_xany_avx2_nofma_gelu(x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_gelu(x, result),
+            avx2 => danger::f32_xany_avx2_nofma_gelu(x, result),
+            fallback => danger::generic_xany_fallback_nofma_gelu(x, result),
+        )
+    }
+}
+
+/// Applies the GELU activation to each element of `x` in place, see [f32_xany_gelu].
+pub fn f32_xany_gelu_inplace(x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_gelu_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_gelu_inplace(x),
+            avx2_fma => danger::f32_xany_avx2_nofma_gelu_inplace(x),
+            avx2 => danger::f32_xany_avx2_nofma_gelu_inplace(x),
+            fallback => danger::generic_xany_fallback_nofma_gelu_inplace(x),
+        )
+    }
+}
+
+/// Computes the softmax of `x`, writing the result to `result`.
+///
+/// The maximum is subtracted before exponentiating so large inputs cannot overflow,
+/// the AVX2 routines make three passes over the data by fusing the exponent and sum.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_softmax(x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_softmax", result),
+            avx512 => danger::f32_xany_avx2_nofma_softmax(x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_softmax(x, result),
+            avx2 => danger::f32_xany_avx2_nofma_softmax(x, result),
+            fallback => danger::generic_xany_fallback_nofma_softmax(x, result),
+        )
+    }
+}
+
+/// Computes the softmax of `x` in place, see [f32_xany_softmax].
+pub fn f32_xany_softmax_inplace(x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_softmax_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_softmax_inplace(x),
+            avx2_fma => danger::f32_xany_avx2_nofma_softmax_inplace(x),
+            avx2 => danger::f32_xany_avx2_nofma_softmax_inplace(x),
+            fallback => danger::generic_xany_fallback_nofma_softmax_inplace(x),
+        )
+    }
+}
+
+/// Clamps each element of `x` to the bounds `lo` and `hi`, writing the result to
+/// `result`.
+///
+/// If `lo` is greater than `hi` every element is set to `hi`.
+///
+/// `NaN` elements of `x` are clamped to `lo`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_clamp(lo: f32, hi: f32, x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_clamp", result),
+            avx512 => danger::f32_xany_avx2_nofma_clamp_value(lo, hi, x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_clamp_value(lo, hi, x, result),
+            avx2 => danger::f32_xany_avx2_nofma_clamp_value(lo, hi, x, result),
+            fallback => danger::generic_xany_fallback_nofma_clamp_value(lo, hi, x, result),
+        )
+    }
+}
+
+/// Clamps each element of `x` to the bounds `lo` and `hi` in place, see
+/// [f32_xany_clamp].
+pub fn f32_xany_clamp_inplace(lo: f32, hi: f32, x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_clamp_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_clamp_value_inplace(lo, hi, x),
+            avx2_fma => danger::f32_xany_avx2_nofma_clamp_value_inplace(lo, hi, x),
+            avx2 => danger::f32_xany_avx2_nofma_clamp_value_inplace(lo, hi, x),
+            fallback => danger::generic_xany_fallback_nofma_clamp_value_inplace(lo, hi, x),
+        )
+    }
+}
+
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi`, writing the
+/// result to `result`.
+///
+/// If `lo[i]` is greater than `hi[i]` the element is set to `hi[i]`.
+///
+/// `NaN` elements of `x` are clamped to `lo`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_clamp_vertical(x: &[f32], lo: &[f32], hi: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), lo.len(), "Vectors must be the same length");
+    assert_eq!(x.len(), hi.len(), "Vectors must be the same length");
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_clamp_vertical", result),
+            avx512 => danger::f32_xany_avx2_nofma_clamp_vertical(x, lo, hi, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_clamp_vertical(x, lo, hi, result),
+            avx2 => danger::f32_xany_avx2_nofma_clamp_vertical(x, lo, hi, result),
+            fallback => danger::generic_xany_fallback_nofma_clamp_vertical(x, lo, hi, result),
+        )
+    }
+}
+
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi` in place, see
+/// [f32_xany_clamp_vertical].
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_clamp_vertical_inplace(x: &mut [f32], lo: &[f32], hi: &[f32]) {
+    assert_eq!(x.len(), lo.len(), "Vectors must be the same length");
+    assert_eq!(x.len(), hi.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_clamp_vertical_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_clamp_vertical_inplace(x, lo, hi),
+            avx2_fma => danger::f32_xany_avx2_nofma_clamp_vertical_inplace(x, lo, hi),
+            avx2 => danger::f32_xany_avx2_nofma_clamp_vertical_inplace(x, lo, hi),
+            fallback => danger::generic_xany_fallback_nofma_clamp_vertical_inplace(x, lo, hi),
+        )
+    }
+}
+
+/// Clamps each element of `x` to the bounds `lo` and `hi`, writing the result to
+/// `result`.
+///
+/// If `lo` is greater than `hi` every element is set to `hi`.
+///
+/// `NaN` elements of `x` are clamped to `lo`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_clamp(lo: f64, hi: f64, x: &[f64], result: &mut [f64]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f64_xany_clamp", result),
+            avx512 => danger::f64_xany_avx2_nofma_clamp_value(lo, hi, x, result),
+            avx2_fma => danger::f64_xany_avx2_nofma_clamp_value(lo, hi, x, result),
+            avx2 => danger::f64_xany_avx2_nofma_clamp_value(lo, hi, x, result),
+            fallback => danger::generic_xany_fallback_nofma_clamp_value(lo, hi, x, result),
+        )
+    }
+}
+
+/// Clamps each element of `x` to the bounds `lo` and `hi` in place, see
+/// [f64_xany_clamp].
+pub fn f64_xany_clamp_inplace(lo: f64, hi: f64, x: &mut [f64]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f64_xany_clamp_inplace", x),
+            avx512 => danger::f64_xany_avx2_nofma_clamp_value_inplace(lo, hi, x),
+            avx2_fma => danger::f64_xany_avx2_nofma_clamp_value_inplace(lo, hi, x),
+            avx2 => danger::f64_xany_avx2_nofma_clamp_value_inplace(lo, hi, x),
+            fallback => danger::generic_xany_fallback_nofma_clamp_value_inplace(lo, hi, x),
+        )
+    }
+}
+
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi`, writing the
+/// result to `result`.
+///
+/// If `lo[i]` is greater than `hi[i]` the element is set to `hi[i]`.
+///
+/// `NaN` elements of `x` are clamped to `lo`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_clamp_vertical(x: &[f64], lo: &[f64], hi: &[f64], result: &mut [f64]) {
+    assert_eq!(x.len(), lo.len(), "Vectors must be the same length");
+    assert_eq!(x.len(), hi.len(), "Vectors must be the same length");
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f64_xany_clamp_vertical", result),
+            avx512 => danger::f64_xany_avx2_nofma_clamp_vertical(x, lo, hi, result),
+            avx2_fma => danger::f64_xany_avx2_nofma_clamp_vertical(x, lo, hi, result),
+            avx2 => danger::f64_xany_avx2_nofma_clamp_vertical(x, lo, hi, result),
+            fallback => danger::generic_xany_fallback_nofma_clamp_vertical(x, lo, hi, result),
+        )
+    }
+}
+
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi` in place, see
+/// [f64_xany_clamp_vertical].
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_clamp_vertical_inplace(x: &mut [f64], lo: &[f64], hi: &[f64]) {
+    assert_eq!(x.len(), lo.len(), "Vectors must be the same length");
+    assert_eq!(x.len(), hi.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f64_xany_clamp_vertical_inplace", x),
+            avx512 => danger::f64_xany_avx2_nofma_clamp_vertical_inplace(x, lo, hi),
+            avx2_fma => danger::f64_xany_avx2_nofma_clamp_vertical_inplace(x, lo, hi),
+            avx2 => danger::f64_xany_avx2_nofma_clamp_vertical_inplace(x, lo, hi),
+            fallback => danger::generic_xany_fallback_nofma_clamp_vertical_inplace(x, lo, hi),
+        )
+    }
+}
+
+/// Clamps each element of `x` to the bounds `lo` and `hi`, writing the result to
+/// `result`.
+///
+/// If `lo` is greater than `hi` every element is set to `hi`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn i32_xany_clamp(lo: i32, hi: i32, x: &[i32], result: &mut [i32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("i32_xany_clamp", result),
+            avx512 => danger::i32_xany_avx2_nofma_clamp_value(lo, hi, x, result),
+            avx2_fma => danger::i32_xany_avx2_nofma_clamp_value(lo, hi, x, result),
+            avx2 => danger::i32_xany_avx2_nofma_clamp_value(lo, hi, x, result),
+            fallback => danger::i32_xany_fallback_nofma_clamp_value(lo, hi, x, result),
+        )
+    }
+}
+
+/// Clamps each element of `x` to the bounds `lo` and `hi` in place, see
+/// [i32_xany_clamp].
+pub fn i32_xany_clamp_inplace(lo: i32, hi: i32, x: &mut [i32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("i32_xany_clamp_inplace", x),
+            avx512 => danger::i32_xany_avx2_nofma_clamp_value_inplace(lo, hi, x),
+            avx2_fma => danger::i32_xany_avx2_nofma_clamp_value_inplace(lo, hi, x),
+            avx2 => danger::i32_xany_avx2_nofma_clamp_value_inplace(lo, hi, x),
+            fallback => danger::i32_xany_fallback_nofma_clamp_value_inplace(lo, hi, x),
+        )
+    }
+}
+
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi`, writing the
+/// result to `result`.
+///
+/// If `lo[i]` is greater than `hi[i]` the element is set to `hi[i]`.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn i32_xany_clamp_vertical(x: &[i32], lo: &[i32], hi: &[i32], result: &mut [i32]) {
+    assert_eq!(x.len(), lo.len(), "Vectors must be the same length");
+    assert_eq!(x.len(), hi.len(), "Vectors must be the same length");
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("i32_xany_clamp_vertical", result),
+            avx512 => danger::i32_xany_avx2_nofma_clamp_vertical(x, lo, hi, result),
+            avx2_fma => danger::i32_xany_avx2_nofma_clamp_vertical(x, lo, hi, result),
+            avx2 => danger::i32_xany_avx2_nofma_clamp_vertical(x, lo, hi, result),
+            fallback => danger::i32_xany_fallback_nofma_clamp_vertical(x, lo, hi, result),
+        )
+    }
+}
+
+/// Clamps each element of `x` to the matching bounds of `lo` and `hi` in place, see
+/// [i32_xany_clamp_vertical].
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn i32_xany_clamp_vertical_inplace(x: &mut [i32], lo: &[i32], hi: &[i32]) {
+    assert_eq!(x.len(), lo.len(), "Vectors must be the same length");
+    assert_eq!(x.len(), hi.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("i32_xany_clamp_vertical_inplace", x),
+            avx512 => danger::i32_xany_avx2_nofma_clamp_vertical_inplace(x, lo, hi),
+            avx2_fma => danger::i32_xany_avx2_nofma_clamp_vertical_inplace(x, lo, hi),
+            avx2 => danger::i32_xany_avx2_nofma_clamp_vertical_inplace(x, lo, hi),
+            fallback => danger::i32_xany_fallback_nofma_clamp_vertical_inplace(x, lo, hi),
+        )
+    }
+}
+
+/// Raises each element of `x` to the power of `exp`, writing the result to `result`.
+///
+/// Integer exponents with a magnitude of at most `32` are computed by repeated squaring,
+/// otherwise the result is computed in double precision. Special values follow
+/// [f32::powf].
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_pow_value(exp: f32, x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_pow_value", result),
+            avx512 => danger::f32_xany_avx2_nofma_pow_value(exp, x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_pow_value(exp, x, result),
+            avx2 => danger::f32_xany_avx2_nofma_pow_value(exp, x, result),
+            fallback => danger::generic_xany_fallback_nofma_pow_value(exp, x, result),
+        )
+    }
+}
+
+/// Raises each element of `x` to the power of `exp` in place, see
+/// [f32_xany_pow_value].
+pub fn f32_xany_pow_value_inplace(exp: f32, x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_pow_value_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_pow_value_inplace(exp, x),
+            avx2_fma => danger::f32_xany_avx2_nofma_pow_value_inplace(exp, x),
+            avx2 => danger::f32_xany_avx2_nofma_pow_value_inplace(exp, x),
+            fallback => danger::generic_xany_fallback_nofma_pow_value_inplace(exp, x),
+        )
+    }
+}
+
+/// Raises each element of `x` to the power of the matching element of `exp`, writing
+/// the result to `result`.
+///
+/// Special values follow [f32::powf].
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_pow_vertical(x: &[f32], exp: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), exp.len(), "Vectors must be the same length");
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_pow_vertical", result),
+            avx512 => danger::f32_xany_avx2_nofma_pow_vertical(x, exp, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_pow_vertical(x, exp, result),
+            avx2 => danger::f32_xany_avx2_nofma_pow_vertical(x, exp, result),
+            fallback => danger::generic_xany_fallback_nofma_pow_vertical(x, exp, result),
+        )
+    }
+}
+
+/// Raises each element of `x` to the power of the matching element of `exp` in place,
+/// see [f32_xany_pow_vertical].
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_pow_vertical_inplace(x: &mut [f32], exp: &[f32]) {
+    assert_eq!(x.len(), exp.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_pow_vertical_inplace", x),
+            avx512 => danger::f32_xany_avx2_nofma_pow_vertical_inplace(x, exp),
+            avx2_fma => danger::f32_xany_avx2_nofma_pow_vertical_inplace(x, exp),
+            avx2 => danger::f32_xany_avx2_nofma_pow_vertical_inplace(x, exp),
+            fallback => danger::generic_xany_fallback_nofma_pow_vertical_inplace(x, exp),
+        )
+    }
+}
+
+/// Rounds each element of `x` down to the nearest integer, writing the result to `result`.
+///
+/// `NaN` and infinite values are returned unchanged.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_floor(x: &[f32], result: &mut [f32]) {
+    f32_xany_round_with(RoundingMode::TowardNegative, x, result)
+}
+
+/// Rounds each element of `x` down to the nearest integer in place, see [f32_xany_floor].
+pub fn f32_xany_floor_inplace(x: &mut [f32]) {
+    f32_xany_round_inplace_with(RoundingMode::TowardNegative, x)
+}
+
+/// Rounds each element of `x` up to the nearest integer, writing the result to `result`.
+///
+/// `NaN` and infinite values are returned unchanged.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_ceil(x: &[f32], result: &mut [f32]) {
+    f32_xany_round_with(RoundingMode::TowardPositive, x, result)
+}
+
+/// Rounds each element of `x` up to the nearest integer in place, see [f32_xany_ceil].
+pub fn f32_xany_ceil_inplace(x: &mut [f32]) {
+    f32_xany_round_inplace_with(RoundingMode::TowardPositive, x)
+}
+
+/// Rounds each element of `x` to the nearest integer, with ties rounded to the nearest
+/// even integer, writing the result to `result`.
+///
+/// This matches [f32::round_ties_even] and numpy's `round`, **not** [f32::round], which
+/// rounds values exactly half way between two integers away from zero.
+///
+/// `NaN` and infinite values are returned unchanged.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_round(x: &[f32], result: &mut [f32]) {
+    f32_xany_round_with(RoundingMode::NearestEven, x, result)
+}
+
+/// Rounds each element of `x` to the nearest integer, with ties rounded to the nearest
+/// even integer, in place, see [f32_xany_round].
+pub fn f32_xany_round_inplace(x: &mut [f32]) {
+    f32_xany_round_inplace_with(RoundingMode::NearestEven, x)
+}
+
+/// Rounds each element of `x` towards zero to an integer, writing the result to `result`.
+///
+/// `NaN` and infinite values are returned unchanged.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f32_xany_trunc(x: &[f32], result: &mut [f32]) {
+    f32_xany_round_with(RoundingMode::TowardZero, x, result)
+}
+
+/// Rounds each element of `x` towards zero to an integer in place, see [f32_xany_trunc].
+pub fn f32_xany_trunc_inplace(x: &mut [f32]) {
+    f32_xany_round_inplace_with(RoundingMode::TowardZero, x)
+}
+
+/// Rounds each element of `x` in the given [RoundingMode], writing the result to
+/// `result`.
+fn f32_xany_round_with(mode: RoundingMode, x: &[f32], result: &mut [f32]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f32_xany_round_with", result),
+            avx512 => danger::f32_xany_avx2_nofma_round(mode, x, result),
+            avx2_fma => danger::f32_xany_avx2_nofma_round(mode, x, result),
+            avx2 => danger::f32_xany_avx2_nofma_round(mode, x, result),
+            fallback => danger::f32_xany_fallback_nofma_round(mode, x, result),
+        )
+    }
+}
+
+/// Rounds each element of `x` in the given [RoundingMode] in place.
+fn f32_xany_round_inplace_with(mode: RoundingMode, x: &mut [f32]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f32_xany_round_inplace_with", x),
+            avx512 => danger::f32_xany_avx2_nofma_round_inplace(mode, x),
+            avx2_fma => danger::f32_xany_avx2_nofma_round_inplace(mode, x),
+            avx2 => danger::f32_xany_avx2_nofma_round_inplace(mode, x),
+            fallback => danger::f32_xany_fallback_nofma_round_inplace(mode, x),
+        )
+    }
+}
+
+/// Rounds each element of `x` down to the nearest integer, writing the result to `result`.
+///
+/// `NaN` and infinite values are returned unchanged.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_floor(x: &[f64], result: &mut [f64]) {
+    f64_xany_round_with(RoundingMode::TowardNegative, x, result)
+}
+
+/// Rounds each element of `x` down to the nearest integer in place, see [f64_xany_floor].
+pub fn f64_xany_floor_inplace(x: &mut [f64]) {
+    f64_xany_round_inplace_with(RoundingMode::TowardNegative, x)
+}
+
+/// Rounds each element of `x` up to the nearest integer, writing the result to `result`.
+///
+/// `NaN` and infinite values are returned unchanged.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_ceil(x: &[f64], result: &mut [f64]) {
+    f64_xany_round_with(RoundingMode::TowardPositive, x, result)
+}
+
+/// Rounds each element of `x` up to the nearest integer in place, see [f64_xany_ceil].
+pub fn f64_xany_ceil_inplace(x: &mut [f64]) {
+    f64_xany_round_inplace_with(RoundingMode::TowardPositive, x)
+}
+
+/// Rounds each element of `x` to the nearest integer, with ties rounded to the nearest
+/// even integer, writing the result to `result`.
+///
+/// This matches [f64::round_ties_even] and numpy's `round`, **not** [f64::round], which
+/// rounds values exactly half way between two integers away from zero.
+///
+/// `NaN` and infinite values are returned unchanged.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_round(x: &[f64], result: &mut [f64]) {
+    f64_xany_round_with(RoundingMode::NearestEven, x, result)
+}
+
+/// Rounds each element of `x` to the nearest integer, with ties rounded to the nearest
+/// even integer, in place, see [f64_xany_round].
+pub fn f64_xany_round_inplace(x: &mut [f64]) {
+    f64_xany_round_inplace_with(RoundingMode::NearestEven, x)
+}
+
+/// Rounds each element of `x` towards zero to an integer, writing the result to `result`.
+///
+/// `NaN` and infinite values are returned unchanged.
+///
+/// # Panics
+///
+/// Panics if the vectors are not the same length.
+pub fn f64_xany_trunc(x: &[f64], result: &mut [f64]) {
+    f64_xany_round_with(RoundingMode::TowardZero, x, result)
+}
+
+/// Rounds each element of `x` towards zero to an integer in place, see [f64_xany_trunc].
+pub fn f64_xany_trunc_inplace(x: &mut [f64]) {
+    f64_xany_round_inplace_with(RoundingMode::TowardZero, x)
+}
+
+/// Rounds each element of `x` in the given [RoundingMode], writing the result to
+/// `result`.
+fn f64_xany_round_with(mode: RoundingMode, x: &[f64], result: &mut [f64]) {
+    assert_eq!(x.len(), result.len(), "Vectors must be the same length");
+
+    unsafe {
+        dispatch_backend!(
+            verify => written("f64_xany_round_with", result),
+            avx512 => danger::f64_xany_avx2_nofma_round(mode, x, result),
+            avx2_fma => danger::f64_xany_avx2_nofma_round(mode, x, result),
+            avx2 => danger::f64_xany_avx2_nofma_round(mode, x, result),
+            fallback => danger::f64_xany_fallback_nofma_round(mode, x, result),
+        )
+    }
+}
+
+/// Rounds each element of `x` in the given [RoundingMode] in place.
+fn f64_xany_round_inplace_with(mode: RoundingMode, x: &mut [f64]) {
+    unsafe {
+        dispatch_backend!(
+            verify => inplace("f64_xany_round_inplace_with", x),
+            avx512 => danger::f64_xany_avx2_nofma_round_inplace(mode, x),
+            avx2_fma => danger::f64_xany_avx2_nofma_round_inplace(mode, x),
+            avx2 => danger::f64_xany_avx2_nofma_round_inplace(mode, x),
+            fallback => danger::f64_xany_fallback_nofma_round_inplace(mode, x),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::{assert_is_close, get_sample_vectors};
+
+    #[test]
+    fn test_abs() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let mut x = x.iter().zip(y).map(|(a, b)| a - b).collect::<Vec<_>>();
+        let expected = x.iter().map(|v| v.abs()).collect::<Vec<_>>();
+
+        let mut result = vec![0.0; x.len()];
+        f32_xany_abs(&x, &mut result);
+        assert_eq!(result, expected);
+        f32_xany_abs_inplace(&mut x);
+        assert_eq!(x, expected);
+
+        let mut x = (-40..27).map(|v| v as f64 * 0.5).collect::<Vec<_>>();
+        let expected = x.iter().map(|v| v.abs()).collect::<Vec<_>>();
+        f64_xany_abs_inplace(&mut x);
+        assert_eq!(x, expected);
+
+        let x = (-40..27).collect::<Vec<i32>>();
+        let mut result = vec![0; x.len()];
+        i32_xany_abs(&x, &mut result);
+        assert_eq!(result, x.iter().map(|v| v.abs()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let (x, _) = get_sample_vectors::<f32>(1024);
+        let expected = x.iter().map(|v| v.sqrt()).collect::<Vec<_>>();
+
+        let mut result = vec![0.0; x.len()];
+        f32_xany_sqrt(&x, &mut result);
+        assert_eq!(result, expected);
+        f32_xconst_sqrt::<1024>(&x, &mut result);
+        assert_eq!(result, expected);
+
+        let mut y = x.clone();
+        f32_xconst_sqrt_inplace::<1024>(&mut y);
+        assert_eq!(y, expected);
+
+        let (x, _) = get_sample_vectors::<f64>(100);
+        let expected = x.iter().map(|v| v.sqrt()).collect::<Vec<_>>();
+
+        let mut result = vec![0.0; x.len()];
+        f64_xconst_sqrt::<100>(&x, &mut result);
+        assert_eq!(result, expected);
+
+        let mut y = x.clone();
+        f64_xany_sqrt_inplace(&mut y);
+        assert_eq!(y, expected);
+    }
+
+    #[test]
+    fn test_recip_rsqrt() {
+        let (x, _) = get_sample_vectors::<f32>(131);
+        let x = x.iter().map(|v| v + 0.5).collect::<Vec<_>>();
+
+        let mut result = vec![0.0; x.len()];
+        f32_xany_recip(true, &x, &mut result);
+        for (r, v) in result.iter().zip(&x) {
+            assert!((r * v - 1.0).abs() < 1e-6);
+        }
+
+        let mut y = x.clone();
+        f32_xany_rsqrt_inplace(true, &mut y);
+        for (r, v) in y.iter().zip(&x) {
+            assert!((r * r * v - 1.0).abs() < 1e-5);
+        }
+    }
+
+    #[test]
+    fn test_exp() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let x = x
+            .iter()
+            .zip(y)
+            .map(|(a, b)| (a - b) * 20.0)
+            .collect::<Vec<_>>();
+
+        let mut result = vec![0.0; x.len()];
+        f32_xany_exp(&x, &mut result);
+        for (r, v) in result.iter().zip(&x) {
+            assert!((r - v.exp()).abs() <= 1e-6 * v.exp());
+        }
+
+        let mut x = x.iter().map(|v| *v as f64).collect::<Vec<_>>();
+        let expected = x.iter().map(|v| v.exp()).collect::<Vec<_>>();
+        f64_xany_exp_inplace(&mut x);
+        for (r, e) in x.iter().zip(&expected) {
+            assert!((r - e).abs() <= 1e-14 * e);
+        }
+    }
+
+    #[test]
+    fn test_log() {
+        let (x, _) = get_sample_vectors::<f32>(131);
+        let x = x.iter().map(|v| v * 100.0 + 1e-3).collect::<Vec<_>>();
+
+        let mut result = vec![0.0; x.len()];
+        for (precise, tolerance) in [(true, 1e-6), (false, 3e-5)] {
+            f32_xany_ln(precise, &x, &mut result);
+            for (r, v) in result.iter().zip(&x) {
+                assert!((r - v.ln()).abs() <= tolerance * v.ln().abs());
+            }
+
+            f32_xany_log2(precise, &x, &mut result);
+            for (r, v) in result.iter().zip(&x) {
+                assert!((r - v.log2()).abs() <= tolerance * v.log2().abs());
+            }
+        }
+
+        let mut x = x.iter().map(|v| *v as f64).collect::<Vec<_>>();
+        let expected = x.iter().map(|v| v.log10()).collect::<Vec<_>>();
+        f64_xany_log10_inplace(true, &mut x);
+        for (r, e) in x.iter().zip(&expected) {
+            assert!((r - e).abs() <= 1e-14 * e.abs());
+        }
+    }
+
+    #[test]
+    fn test_tanh() {
+        let (x, y) = get_sample_vectors::<f32>(131);
+        let x = x
+            .iter()
+            .zip(y)
+            .map(|(a, b)| (a - b) * 10.0)
+            .collect::<Vec<_>>();
+
+        let mut result = vec![0.0; x.len()];
+        f32_xany_tanh(&x, &mut result);
+        for (r, v) in result.iter().zip(&x) {
+            assert!((r - v.tanh()).abs() <= 2e-6 * v.tanh().abs());
+        }
+
+        let mut x = x.iter().map(|v| *v as f64).collect::<Vec<_>>();
+        let expected = x.iter().map(|v| v.tanh()).collect::<Vec<_>>();
+        f64_xany_tanh_inplace(&mut x);
+        for (r, e) in x.iter().zip(&expected) {
+            assert!((r - e).abs() <= 1e-14 * e.abs());
+        }
+    }
+
+    #[test]
+    fn test_relu() {
+        let x = [-2.0, -0.5, 0.0, 0.5, 2.0];
+
+        let mut result = [1.0; 5];
+        f32_xany_relu(&x, &mut result);
+        assert_eq!(result, [0.0, 0.0, 0.0, 0.5, 2.0]);
+
+        f32_xany_leaky_relu(0.1, &x, &mut result);
+        assert_eq!(result, [-0.2, -0.05, 0.0, 0.5, 2.0]);
+
+        let mut x = x;
+        f32_xany_leaky_relu_inplace(0.5, &mut x);
+        assert_eq!(x, [-1.0, -0.25, 0.0, 0.5, 2.0]);
+        f32_xany_relu_inplace(&mut x);
+        assert_eq!(x, [0.0, 0.0, 0.0, 0.5, 2.0]);
+    }
+
+    #[test]
+    fn test_gelu() {
+        // Reference values from `torch.nn.functional.gelu(x, approximate="tanh")`.
+        let x = [-3.0, -1.0, 0.0, 1.0, 3.0];
+        let expected = [-0.003_637_392, -0.158_808, 0.0, 0.841_192, 2.996_363];
+
+        let mut result = [0.0; 5];
+        f32_xany_gelu(&x, &mut result);
+        for (r, e) in result.iter().zip(expected) {
+            assert!((r - e).abs() <= 1e-5, "{r} vs {e}");
+        }
+
+        let mut x = x;
+        f32_xany_gelu_inplace(&mut x);
+        assert_eq!(x, result);
+    }
+
+    #[test]
+    fn test_softmax() {
+        let x = [1.0, 2.0, 3.0, 1000.0, 1000.0];
+        let mut result = [0.0; 5];
+        f32_xany_softmax(&x, &mut result);
+        assert_eq!(result, [0.0, 0.0, 0.0, 0.5, 0.5]);
+
+        let mut x = [0.0, 2f32.ln()];
+        f32_xany_softmax_inplace(&mut x);
+        assert_is_close(x[0], 1.0 / 3.0);
+        assert_is_close(x[1], 2.0 / 3.0);
+    }
+
+    #[test]
+    fn test_clamp() {
+        let x = [-2.0, 0.5, 3.0, f32::NAN];
+        let mut result = [0.0; 4];
+        f32_xany_clamp(0.0, 1.0, &x, &mut result);
+        assert_eq!(result, [0.0, 0.5, 1.0, 0.0]);
+
+        let lo = [-1.0, 0.0, 0.0, -5.0];
+        let hi = [1.0, 0.25, 2.5, 5.0];
+        let mut x = x.map(|v| v as f64);
+        f64_xany_clamp_vertical_inplace(&mut x, &lo, &hi);
+        assert_eq!(x, [-1.0, 0.25, 2.5, -5.0]);
+
+        let mut x = [i32::MIN, -3, 0, 7, i32::MAX];
+        i32_xany_clamp_inplace(-2, 5, &mut x);
+        assert_eq!(x, [-2, -2, 0, 5, 5]);
+
+        let mut result = [0; 5];
+        i32_xany_clamp_vertical(&x, &[0; 5], &[1, 1, 1, 10, 10], &mut result);
+        assert_eq!(result, [0, 0, 0, 5, 5]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectors must be the same length")]
+    fn test_clamp_vertical_length_mismatch() {
+        f32_xany_clamp_vertical(&[1.0, 2.0], &[0.0, 0.0], &[1.0], &mut [0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_pow() {
+        let x = [2.0, -3.0, 0.25, 0.0, -1.0];
+        let mut result = [0.0; 5];
+        f32_xany_pow_value(3.0, &x, &mut result);
+        assert_eq!(result, [8.0, -27.0, 0.015625, 0.0, -1.0]);
+
+        f32_xany_pow_vertical(&x, &[0.5, 2.0, -0.5, 0.0, f32::INFINITY], &mut result);
+        assert_is_close(result[0], 2f32.sqrt());
+        assert_eq!(&result[1..], [9.0, 2.0, 1.0, 1.0]);
+
+        let mut x = [4.0, 9.0];
+        f32_xany_pow_value_inplace(1.5, &mut x);
+        assert_eq!(x, [8.0, 27.0]);
+        f32_xany_pow_vertical_inplace(&mut x, &[-1.0, 1.0 / 3.0]);
+        assert_is_close(x[0], 0.125);
+        assert_is_close(x[1], 3.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectors must be the same length")]
+    fn test_pow_vertical_length_mismatch() {
+        f32_xany_pow_vertical(&[1.0, 2.0], &[1.0], &mut [0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_rounding() {
+        let x = [-1.5, -0.2, 0.5, 2.5, 2.7];
+        let mut result = [0.0; 5];
+        f32_xany_floor(&x, &mut result);
+        assert_eq!(result, [-2.0, -1.0, 0.0, 2.0, 2.0]);
+        f32_xany_ceil(&x, &mut result);
+        assert_eq!(result, [-1.0, -0.0, 1.0, 3.0, 3.0]);
+        f32_xany_round(&x, &mut result);
+        assert_eq!(result, [-2.0, -0.0, 0.0, 2.0, 3.0]);
+        f32_xany_trunc(&x, &mut result);
+        assert_eq!(result, [-1.0, -0.0, 0.0, 2.0, 2.0]);
+
+        let mut x = x.map(|v| v as f64);
+        f64_xany_floor_inplace(&mut x);
+        assert_eq!(x, [-2.0, -1.0, 0.0, 2.0, 2.0]);
+
+        let mut x = [0.5f64, 1.5, -2.5, 1e300, f64::NEG_INFINITY];
+        f64_xany_round_inplace(&mut x);
+        assert_eq!(x, [0.0, 2.0, -2.0, 1e300, f64::NEG_INFINITY]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectors must be the same length")]
+    fn test_abs_length_mismatch() {
+        f32_xany_abs(&[1.0, 2.0], &mut [0.0]);
+    }
+}
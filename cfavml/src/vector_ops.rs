@@ -0,0 +1,679 @@
+//! A dispatch once, call many front-end to the routines.
+//!
+//! [VectorOps] resolves the routines for the detected backend a single time when it
+//! is constructed and stores them as function pointers, so hot loops calling the
+//! same operations repeatedly pay no per-call feature detection.
+//!
+//! ```
+//! use cfavml::vector_ops::VectorOps;
+//!
+//! let ops = VectorOps::<f32>::new();
+//!
+//! let mut a = vec![1.0, 2.0, 3.0];
+//! let b = vec![4.0, 5.0, 6.0];
+//! assert_eq!(ops.dot(&a, &b), 32.0);
+//!
+//! ops.add_vector(&mut a, &b);
+//! assert_eq!(a, [5.0, 7.0, 9.0]);
+//! ```
+
+use core::fmt;
+
+use crate::backend::{backend_for, Backend};
+use crate::danger;
+#[cfg(feature = "verify")]
+use crate::verify;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+/// The axis of a matrix a reduction is performed along.
+pub enum Axis {
+    /// Reduces each row to a single value, producing one value per row.
+    Rows,
+    /// Reduces each column to a single value, producing one value per column.
+    Cols,
+}
+
+/// The routines of a single backend for vectors of `T`.
+///
+/// Construct it once, i.e. with [`VectorOps::<f32>::new`](VectorOps::new), and reuse it,
+/// each method is a single indirect call with no feature detection.
+///
+/// With the `verify` feature enabled every method also runs the fallback routines on
+/// the same inputs and panics if the results do not match within a relative tolerance
+/// of `1e-4`, which helps narrow down numerical differences to a specific backend.
+/// This more than doubles the cost of each call, copies the inputs of the in-place
+/// operations and allocates, so it is meant for debugging and testing only. The safe
+/// functions of the other modules, i.e. [dot_ops](crate::dot_ops), are checked the
+/// same way.
+pub struct VectorOps<T> {
+    backend: Backend,
+    dot: unsafe fn(&[T], &[T]) -> T,
+    cosine: unsafe fn(&[T], &[T]) -> T,
+    euclidean: unsafe fn(&[T], &[T]) -> T,
+    norm: unsafe fn(&[T]) -> T,
+    sum: unsafe fn(&[T]) -> T,
+    max: unsafe fn(&[T]) -> T,
+    min: unsafe fn(&[T]) -> T,
+    add_vector: unsafe fn(&mut [T], &[T]),
+    sub_vector: unsafe fn(&mut [T], &[T]),
+    mul_vector: unsafe fn(&mut [T], &[T]),
+    div_vector: unsafe fn(&mut [T], &[T]),
+    sum_vertical: unsafe fn(&[&[T]]) -> Vec<T>,
+    max_vertical: unsafe fn(&[&[T]]) -> Vec<T>,
+    min_vertical: unsafe fn(&[&[T]]) -> Vec<T>,
+    #[cfg(feature = "verify")]
+    verifier: Verifier<T>,
+}
+
+impl<T> Clone for VectorOps<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for VectorOps<T> {}
+
+impl<T> fmt::Debug for VectorOps<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("VectorOps")
+            .field("backend", &self.backend)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T> VectorOps<T> {
+    /// Returns the backend the routines were resolved for.
+    pub fn backend(&self) -> Backend {
+        self.backend
+    }
+
+    /// Computes the dot product of two vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors are not the same length.
+    pub fn dot(&self, x: &[T], y: &[T]) -> T {
+        assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+        let result = unsafe { (self.dot)(x, y) };
+        #[cfg(feature = "verify")]
+        self.verify("dot", core::slice::from_ref(&result), |ops| {
+            vec![ops.dot(x, y)]
+        });
+        result
+    }
+
+    /// Computes the cosine distance of two vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors are not the same length.
+    pub fn cosine(&self, x: &[T], y: &[T]) -> T {
+        assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+        let result = unsafe { (self.cosine)(x, y) };
+        #[cfg(feature = "verify")]
+        self.verify("cosine", core::slice::from_ref(&result), |ops| {
+            vec![ops.cosine(x, y)]
+        });
+        result
+    }
+
+    /// Computes the squared Euclidean distance of two vectors.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors are not the same length.
+    pub fn euclidean(&self, x: &[T], y: &[T]) -> T {
+        assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+        let result = unsafe { (self.euclidean)(x, y) };
+        #[cfg(feature = "verify")]
+        self.verify("euclidean", core::slice::from_ref(&result), |ops| {
+            vec![ops.euclidean(x, y)]
+        });
+        result
+    }
+
+    /// Computes the squared L2 norm of the vector.
+    pub fn norm(&self, x: &[T]) -> T {
+        let result = unsafe { (self.norm)(x) };
+        #[cfg(feature = "verify")]
+        self.verify("norm", core::slice::from_ref(&result), |ops| {
+            vec![ops.norm(x)]
+        });
+        result
+    }
+
+    /// Sums all elements of the vector.
+    pub fn sum(&self, x: &[T]) -> T {
+        let result = unsafe { (self.sum)(x) };
+        #[cfg(feature = "verify")]
+        self.verify(
+            "sum",
+            core::slice::from_ref(&result),
+            |ops| vec![ops.sum(x)],
+        );
+        result
+    }
+
+    /// Returns the largest element of the vector.
+    pub fn max(&self, x: &[T]) -> T {
+        let result = unsafe { (self.max)(x) };
+        #[cfg(feature = "verify")]
+        self.verify(
+            "max",
+            core::slice::from_ref(&result),
+            |ops| vec![ops.max(x)],
+        );
+        result
+    }
+
+    /// Returns the smallest element of the vector.
+    pub fn min(&self, x: &[T]) -> T {
+        let result = unsafe { (self.min)(x) };
+        #[cfg(feature = "verify")]
+        self.verify(
+            "min",
+            core::slice::from_ref(&result),
+            |ops| vec![ops.min(x)],
+        );
+        result
+    }
+
+    /// Adds `y` to `x` element wise, writing the result to `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors are not the same length.
+    pub fn add_vector(&self, x: &mut [T], y: &[T]) {
+        assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+        #[cfg(feature = "verify")]
+        let original = (self.verifier.to_vec)(x);
+        unsafe { (self.add_vector)(x, y) }
+        #[cfg(feature = "verify")]
+        self.verify("add_vector", x, |ops| {
+            let mut expected = original;
+            ops.add_vector(&mut expected, y);
+            expected
+        });
+    }
+
+    /// Subtracts `y` from `x` element wise, writing the result to `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors are not the same length.
+    pub fn sub_vector(&self, x: &mut [T], y: &[T]) {
+        assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+        #[cfg(feature = "verify")]
+        let original = (self.verifier.to_vec)(x);
+        unsafe { (self.sub_vector)(x, y) }
+        #[cfg(feature = "verify")]
+        self.verify("sub_vector", x, |ops| {
+            let mut expected = original;
+            ops.sub_vector(&mut expected, y);
+            expected
+        });
+    }
+
+    /// Multiplies `x` by `y` element wise, writing the result to `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors are not the same length.
+    pub fn mul_vector(&self, x: &mut [T], y: &[T]) {
+        assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+        #[cfg(feature = "verify")]
+        let original = (self.verifier.to_vec)(x);
+        unsafe { (self.mul_vector)(x, y) }
+        #[cfg(feature = "verify")]
+        self.verify("mul_vector", x, |ops| {
+            let mut expected = original;
+            ops.mul_vector(&mut expected, y);
+            expected
+        });
+    }
+
+    /// Divides `x` by `y` element wise, writing the result to `x`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the vectors are not the same length.
+    pub fn div_vector(&self, x: &mut [T], y: &[T]) {
+        assert_eq!(x.len(), y.len(), "Vectors must be the same length");
+        #[cfg(feature = "verify")]
+        let original = (self.verifier.to_vec)(x);
+        unsafe { (self.div_vector)(x, y) }
+        #[cfg(feature = "verify")]
+        self.verify("div_vector", x, |ops| {
+            let mut expected = original;
+            ops.div_vector(&mut expected, y);
+            expected
+        });
+    }
+
+    /// Sums each column of the matrix, returning a vector of the column totals.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is empty or its rows are not all the same length.
+    pub fn sum_vertical(&self, matrix: &[&[T]]) -> Vec<T> {
+        assert_matrix_rows(matrix);
+        let result = unsafe { (self.sum_vertical)(matrix) };
+        #[cfg(feature = "verify")]
+        self.verify("sum_vertical", &result, |ops| ops.sum_vertical(matrix));
+        result
+    }
+
+    /// Sums the matrix along the given [Axis], the equivalent of numpy's `sum(axis=)`.
+    ///
+    /// [Axis::Rows] sums every row with the horizontal routine while [Axis::Cols]
+    /// uses the vertical routine, so both layouts stay vectorized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is empty or its rows are not all the same length.
+    pub fn sum_axis(&self, matrix: &[&[T]], axis: Axis) -> Vec<T> {
+        assert_matrix_rows(matrix);
+        match axis {
+            Axis::Rows => matrix.iter().map(|row| self.sum(row)).collect(),
+            Axis::Cols => self.sum_vertical(matrix),
+        }
+    }
+
+    /// Returns the largest element of each column of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is empty or its rows are not all the same length.
+    pub fn max_vertical(&self, matrix: &[&[T]]) -> Vec<T> {
+        assert_matrix_rows(matrix);
+        let result = unsafe { (self.max_vertical)(matrix) };
+        #[cfg(feature = "verify")]
+        self.verify("max_vertical", &result, |ops| ops.max_vertical(matrix));
+        result
+    }
+
+    /// Returns the smallest element of each column of the matrix.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the matrix is empty or its rows are not all the same length.
+    pub fn min_vertical(&self, matrix: &[&[T]]) -> Vec<T> {
+        assert_matrix_rows(matrix);
+        let result = unsafe { (self.min_vertical)(matrix) };
+        #[cfg(feature = "verify")]
+        self.verify("min_vertical", &result, |ops| ops.min_vertical(matrix));
+        result
+    }
+}
+
+#[cfg(feature = "verify")]
+impl<T> VectorOps<T> {
+    /// Runs `fallback` with the fallback routines and asserts its output matches the
+    /// `result` of the resolved backend.
+    fn verify(&self, op: &str, result: &[T], fallback: impl FnOnce(&Self) -> Vec<T>) {
+        if self.backend != Backend::Fallback {
+            let expected = fallback(&(self.verifier.fallback)());
+            (self.verifier.assert_matches)(op, self.backend, result, &expected);
+        }
+    }
+}
+
+#[cfg(feature = "verify")]
+/// The type specific routines [VectorOps] needs to check its results against the
+/// fallback routines, stored as function pointers as the methods are not bound on `T`.
+struct Verifier<T> {
+    fallback: fn() -> VectorOps<T>,
+    to_vec: fn(&[T]) -> Vec<T>,
+    assert_matches: fn(&str, Backend, &[T], &[T]),
+}
+
+#[cfg(feature = "verify")]
+impl<T> Clone for Verifier<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+#[cfg(feature = "verify")]
+impl<T> Copy for Verifier<T> {}
+
+fn assert_matrix_rows<T>(matrix: &[&[T]]) {
+    assert!(!matrix.is_empty(), "Matrix must have at least one row");
+    let dims = matrix[0].len();
+    assert!(
+        matrix.iter().all(|row| row.len() == dims),
+        "Matrix rows must be the same length"
+    );
+}
+
+impl VectorOps<f32> {
+    /// Resolves the routines for the most capable backend supported by the current CPU.
+    pub fn new() -> Self {
+        Self::for_backend(backend_for::<f32>())
+    }
+
+    fn for_backend(backend: Backend) -> Self {
+        match backend {
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            Backend::Avx512 => Self {
+                backend,
+                dot: danger::f32_xany_avx512_fma_dot,
+                cosine: danger::f32_xany_avx512_fma_cosine,
+                euclidean: danger::f32_xany_avx512_fma_euclidean,
+                norm: danger::f32_xany_avx512_fma_norm,
+                sum: danger::f32_xany_avx512_nofma_sum_horizontal,
+                max: danger::f32_xany_avx512_nofma_max_horizontal,
+                min: danger::f32_xany_avx512_nofma_min_horizontal,
+                add_vector: danger::f32_xany_avx512_nofma_add_vertical,
+                sub_vector: danger::f32_xany_avx512_nofma_sub_vertical,
+                mul_vector: danger::f32_xany_avx512_nofma_mul_vertical,
+                div_vector: danger::f32_xany_avx512_nofma_div_vertical,
+                sum_vertical: danger::f32_xany_avx512_nofma_sum_vertical,
+                max_vertical: danger::f32_xany_avx512_nofma_max_vertical,
+                min_vertical: danger::f32_xany_avx512_nofma_min_vertical,
+                #[cfg(feature = "verify")]
+                verifier: Self::VERIFIER,
+            },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Backend::Avx2Fma => Self {
+                backend,
+                dot: danger::f32_xany_avx2_fma_dot,
+                cosine: danger::f32_xany_avx2_fma_cosine_fused,
+                euclidean: danger::f32_xany_avx2_fma_euclidean,
+                norm: danger::f32_xany_avx2_fma_norm,
+                sum: danger::f32_xany_avx2_nofma_sum_horizontal,
+                max: danger::f32_xany_avx2_nofma_max_horizontal,
+                min: danger::f32_xany_avx2_nofma_min_horizontal,
+                add_vector: danger::f32_xany_avx2_nofma_add_vertical,
+                sub_vector: danger::f32_xany_avx2_nofma_sub_vertical,
+                mul_vector: danger::f32_xany_avx2_nofma_mul_vertical,
+                div_vector: danger::f32_xany_avx2_nofma_div_vertical,
+                sum_vertical: danger::f32_xany_avx2_nofma_sum_vertical,
+                max_vertical: danger::f32_xany_avx2_nofma_max_vertical,
+                min_vertical: danger::f32_xany_avx2_nofma_min_vertical,
+                #[cfg(feature = "verify")]
+                verifier: Self::VERIFIER,
+            },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Backend::Avx2 => Self {
+                backend,
+                dot: danger::f32_xany_avx2_nofma_dot,
+                cosine: danger::f32_xany_avx2_nofma_cosine_fused,
+                euclidean: danger::f32_xany_avx2_nofma_euclidean,
+                norm: danger::f32_xany_avx2_nofma_norm,
+                sum: danger::f32_xany_avx2_nofma_sum_horizontal,
+                max: danger::f32_xany_avx2_nofma_max_horizontal,
+                min: danger::f32_xany_avx2_nofma_min_horizontal,
+                add_vector: danger::f32_xany_avx2_nofma_add_vertical,
+                sub_vector: danger::f32_xany_avx2_nofma_sub_vertical,
+                mul_vector: danger::f32_xany_avx2_nofma_mul_vertical,
+                div_vector: danger::f32_xany_avx2_nofma_div_vertical,
+                sum_vertical: danger::f32_xany_avx2_nofma_sum_vertical,
+                max_vertical: danger::f32_xany_avx2_nofma_max_vertical,
+                min_vertical: danger::f32_xany_avx2_nofma_min_vertical,
+                #[cfg(feature = "verify")]
+                verifier: Self::VERIFIER,
+            },
+            _ => Self {
+                backend: Backend::Fallback,
+                dot: danger::generic_xany_fallback_nofma_dot::<f32>,
+                cosine: danger::generic_xany_fallback_nofma_cosine::<f32>,
+                euclidean: danger::generic_xany_fallback_nofma_euclidean::<f32>,
+                norm: danger::generic_xany_fallback_nofma_norm::<f32>,
+                sum: danger::generic_xany_fallback_nofma_sum_horizontal::<f32>,
+                max: danger::generic_xany_fallback_nofma_max_horizontal::<f32>,
+                min: danger::f32_xany_fallback_nofma_min_horizontal::<f32>,
+                add_vector: danger::generic_xany_fallback_nofma_add_vertical::<f32>,
+                sub_vector: danger::generic_xany_fallback_nofma_sub_vertical::<f32>,
+                mul_vector: danger::generic_xany_fallback_nofma_mul_vertical::<f32>,
+                div_vector: danger::generic_xany_fallback_nofma_div_vertical::<f32>,
+                sum_vertical: danger::generic_xany_fallback_nofma_sum_vertical::<f32>,
+                max_vertical: danger::generic_xany_fallback_nofma_max_vertical::<f32>,
+                min_vertical: danger::generic_xany_fallback_nofma_min_vertical::<f32>,
+                #[cfg(feature = "verify")]
+                verifier: Self::VERIFIER,
+            },
+        }
+    }
+
+    #[cfg(feature = "verify")]
+    const VERIFIER: Verifier<f32> = Verifier {
+        fallback: || Self::for_backend(Backend::Fallback),
+        to_vec: <[f32]>::to_vec,
+        assert_matches: verify::assert_matches_fallback::<f32>,
+    };
+}
+
+impl Default for VectorOps<f32> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VectorOps<f64> {
+    /// Resolves the routines for the most capable backend supported by the current CPU.
+    pub fn new() -> Self {
+        Self::for_backend(backend_for::<f64>())
+    }
+
+    fn for_backend(backend: Backend) -> Self {
+        match backend {
+            #[cfg(all(
+                any(target_arch = "x86", target_arch = "x86_64"),
+                feature = "nightly"
+            ))]
+            Backend::Avx512 => Self {
+                backend,
+                dot: danger::f64_xany_avx512_fma_dot,
+                cosine: danger::f64_xany_avx512_fma_cosine,
+                euclidean: danger::f64_xany_avx512_fma_euclidean,
+                norm: danger::f64_xany_avx512_fma_norm,
+                sum: danger::f64_xany_avx512_nofma_sum_horizontal,
+                max: danger::f64_xany_avx512_nofma_max_horizontal,
+                min: danger::f64_xany_avx512_nofma_min_horizontal,
+                add_vector: danger::f64_xany_avx512_nofma_add_vertical,
+                sub_vector: danger::f64_xany_avx512_nofma_sub_vertical,
+                mul_vector: danger::f64_xany_avx512_nofma_mul_vertical,
+                div_vector: danger::f64_xany_avx512_nofma_div_vertical,
+                sum_vertical: danger::f64_xany_avx512_nofma_sum_vertical,
+                max_vertical: danger::f64_xany_avx512_nofma_max_vertical,
+                min_vertical: danger::f64_xany_avx512_nofma_min_vertical,
+                #[cfg(feature = "verify")]
+                verifier: Self::VERIFIER,
+            },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Backend::Avx2Fma => Self {
+                backend,
+                dot: danger::f64_xany_avx2_fma_dot,
+                cosine: danger::f64_xany_avx2_fma_cosine,
+                euclidean: danger::f64_xany_avx2_fma_euclidean,
+                norm: danger::f64_xany_avx2_fma_norm,
+                sum: danger::f64_xany_avx2_nofma_sum_horizontal,
+                max: danger::f64_xany_avx2_nofma_max_horizontal,
+                min: danger::f64_xany_avx2_nofma_min_horizontal,
+                add_vector: danger::f64_xany_avx2_nofma_add_vertical,
+                sub_vector: danger::f64_xany_avx2_nofma_sub_vertical,
+                mul_vector: danger::f64_xany_avx2_nofma_mul_vertical,
+                div_vector: danger::f64_xany_avx2_nofma_div_vertical,
+                sum_vertical: danger::f64_xany_avx2_nofma_sum_vertical,
+                max_vertical: danger::f64_xany_avx2_nofma_max_vertical,
+                min_vertical: danger::f64_xany_avx2_nofma_min_vertical,
+                #[cfg(feature = "verify")]
+                verifier: Self::VERIFIER,
+            },
+            #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
+            Backend::Avx2 => Self {
+                backend,
+                dot: danger::f64_xany_avx2_nofma_dot,
+                cosine: danger::f64_xany_avx2_nofma_cosine,
+                euclidean: danger::f64_xany_avx2_nofma_euclidean,
+                norm: danger::f64_xany_avx2_nofma_norm,
+                sum: danger::f64_xany_avx2_nofma_sum_horizontal,
+                max: danger::f64_xany_avx2_nofma_max_horizontal,
+                min: danger::f64_xany_avx2_nofma_min_horizontal,
+                add_vector: danger::f64_xany_avx2_nofma_add_vertical,
+                sub_vector: danger::f64_xany_avx2_nofma_sub_vertical,
+                mul_vector: danger::f64_xany_avx2_nofma_mul_vertical,
+                div_vector: danger::f64_xany_avx2_nofma_div_vertical,
+                sum_vertical: danger::f64_xany_avx2_nofma_sum_vertical,
+                max_vertical: danger::f64_xany_avx2_nofma_max_vertical,
+                min_vertical: danger::f64_xany_avx2_nofma_min_vertical,
+                #[cfg(feature = "verify")]
+                verifier: Self::VERIFIER,
+            },
+            _ => Self {
+                backend: Backend::Fallback,
+                dot: danger::generic_xany_fallback_nofma_dot::<f64>,
+                cosine: danger::generic_xany_fallback_nofma_cosine::<f64>,
+                euclidean: danger::generic_xany_fallback_nofma_euclidean::<f64>,
+                norm: danger::generic_xany_fallback_nofma_norm::<f64>,
+                sum: danger::generic_xany_fallback_nofma_sum_horizontal::<f64>,
+                max: danger::generic_xany_fallback_nofma_max_horizontal::<f64>,
+                min: danger::f32_xany_fallback_nofma_min_horizontal::<f64>,
+                add_vector: danger::generic_xany_fallback_nofma_add_vertical::<f64>,
+                sub_vector: danger::generic_xany_fallback_nofma_sub_vertical::<f64>,
+                mul_vector: danger::generic_xany_fallback_nofma_mul_vertical::<f64>,
+                div_vector: danger::generic_xany_fallback_nofma_div_vertical::<f64>,
+                sum_vertical: danger::generic_xany_fallback_nofma_sum_vertical::<f64>,
+                max_vertical: danger::generic_xany_fallback_nofma_max_vertical::<f64>,
+                min_vertical: danger::generic_xany_fallback_nofma_min_vertical::<f64>,
+                #[cfg(feature = "verify")]
+                verifier: Self::VERIFIER,
+            },
+        }
+    }
+
+    #[cfg(feature = "verify")]
+    const VERIFIER: Verifier<f64> = Verifier {
+        fallback: || Self::for_backend(Backend::Fallback),
+        to_vec: <[f64]>::to_vec,
+        assert_matches: verify::assert_matches_fallback::<f64>,
+    };
+}
+
+impl Default for VectorOps<f64> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::detected_backend;
+    use crate::test_utils::{assert_is_close, get_sample_vectors, simple_dot};
+
+    #[test]
+    fn test_vector_ops_backend() {
+        assert_eq!(VectorOps::<f32>::new().backend(), detected_backend());
+        assert_eq!(VectorOps::<f64>::default().backend(), detected_backend());
+    }
+
+    #[test]
+    fn test_vector_ops_reductions() {
+        let ops = VectorOps::<f32>::new();
+        let (x, y) = get_sample_vectors::<f32>(531);
+
+        assert_is_close(ops.dot(&x, &y), simple_dot(&x, &y));
+        assert_is_close(ops.norm(&x), simple_dot(&x, &x));
+        assert_is_close(ops.sum(&x), x.iter().sum());
+        assert_eq!(
+            ops.max(&x),
+            x.iter().copied().fold(f32::NEG_INFINITY, f32::max)
+        );
+        assert_eq!(ops.min(&x), x.iter().copied().fold(f32::INFINITY, f32::min));
+    }
+
+    #[test]
+    fn test_vector_ops_matches_fallback() {
+        let ops = VectorOps::<f64>::new();
+        let fallback = VectorOps::<f64>::for_backend(Backend::Fallback);
+        let (x, y) = get_sample_vectors::<f64>(531);
+
+        assert_is_close(ops.cosine(&x, &y) as f32, fallback.cosine(&x, &y) as f32);
+        assert_is_close(
+            ops.euclidean(&x, &y) as f32,
+            fallback.euclidean(&x, &y) as f32,
+        );
+
+        let mut result = x.clone();
+        let mut expected = x.clone();
+        ops.mul_vector(&mut result, &y);
+        fallback.mul_vector(&mut expected, &y);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn test_vector_ops_vertical() {
+        let ops = VectorOps::<f32>::new();
+        let fallback = VectorOps::<f32>::for_backend(Backend::Fallback);
+        let rows = (0..9)
+            .map(|_| get_sample_vectors::<f32>(67).0)
+            .collect::<Vec<_>>();
+        let matrix = rows.iter().map(|row| row.as_slice()).collect::<Vec<_>>();
+
+        let max = ops.max_vertical(&matrix);
+        let min = ops.min_vertical(&matrix);
+        for column in 0..67 {
+            let values = rows.iter().map(|row| row[column]);
+            assert_eq!(
+                max[column],
+                values.clone().fold(f32::NEG_INFINITY, f32::max)
+            );
+            assert_eq!(min[column], values.fold(f32::INFINITY, f32::min));
+        }
+
+        assert_eq!(max, fallback.max_vertical(&matrix));
+        assert_eq!(min, fallback.min_vertical(&matrix));
+        for (total, expected) in ops
+            .sum_vertical(&matrix)
+            .into_iter()
+            .zip(fallback.sum_vertical(&matrix))
+        {
+            assert_is_close(total, expected);
+        }
+    }
+
+    #[test]
+    fn test_vector_ops_sum_axis() {
+        let ops = VectorOps::<f64>::new();
+        let matrix: [&[f64]; 2] = [&[1.0, 2.0, 3.0], &[4.0, 5.0, 6.0]];
+
+        assert_eq!(ops.sum_axis(&matrix, Axis::Rows), [6.0, 15.0]);
+        assert_eq!(ops.sum_axis(&matrix, Axis::Cols), [5.0, 7.0, 9.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Matrix rows must be the same length")]
+    fn test_vector_ops_vertical_ragged() {
+        let ops = VectorOps::<f64>::new();
+        ops.max_vertical(&[&[1.0, 2.0], &[1.0]]);
+    }
+
+    #[cfg(feature = "verify")]
+    #[test]
+    #[should_panic(expected = "Avx2 `dot` does not match the fallback routines")]
+    fn test_vector_ops_verify_mismatch() {
+        unsafe fn bad_dot(x: &[f32], y: &[f32]) -> f32 {
+            danger::generic_xany_fallback_nofma_dot(x, y) + 1.0
+        }
+
+        let ops = VectorOps {
+            backend: Backend::Avx2,
+            dot: bad_dot,
+            ..VectorOps::<f32>::for_backend(Backend::Fallback)
+        };
+        let (x, y) = get_sample_vectors::<f32>(67);
+        ops.dot(&x, &y);
+    }
+
+    #[test]
+    #[should_panic(expected = "Vectors must be the same length")]
+    fn test_vector_ops_length_mismatch() {
+        let ops = VectorOps::<f32>::new();
+        ops.add_vector(&mut [1.0, 2.0], &[1.0]);
+    }
+}
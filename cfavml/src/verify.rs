@@ -0,0 +1,215 @@
+//! Checks of the results of the optimized backends against the fallback routines,
+//! enabled by the `verify` feature.
+//!
+//! [VectorOps](crate::vector_ops::VectorOps) and the safe functions of
+//! [dot_ops](crate::dot_ops), [distance_ops](crate::distance_ops),
+//! [unary_ops](crate::unary_ops) and [overflow_ops](crate::overflow_ops) run the
+//! fallback routines on the same inputs after each call and panic if the results do
+//! not match, the `danger` routines are not checked.
+
+use core::fmt;
+
+use crate::backend::{detected_backend, static_backend, Backend};
+
+/// The relative tolerance results may differ from the fallback routines by, the
+/// backends accumulate in a different order so are not expected to be bit exact.
+pub(crate) const VERIFY_TOLERANCE: f64 = 1e-4;
+
+/// The relative tolerance a `verify` arm of [dispatch_backend](crate::backend::dispatch_backend)
+/// checks the result with, the given one or [VERIFY_TOLERANCE].
+macro_rules! verify_tolerance {
+    () => {
+        $crate::verify::VERIFY_TOLERANCE
+    };
+    ($tolerance:expr) => {
+        $tolerance
+    };
+}
+pub(crate) use verify_tolerance;
+
+/// An element type the results of the backends can be compared with.
+pub(crate) trait VerifyValue: Copy + fmt::Debug {
+    /// Returns if `self` matches the `fallback` result within `tolerance`, relative to
+    /// the larger of the two magnitudes or `1.0`.
+    fn matches(self, fallback: Self, tolerance: f64) -> bool;
+}
+
+macro_rules! impl_float_verify_value {
+    ($($t:ty),+) => {
+        $(
+            impl VerifyValue for $t {
+                fn matches(self, fallback: Self, tolerance: f64) -> bool {
+                    let (a, b) = (self as f64, fallback as f64);
+                    (a.is_nan() && b.is_nan())
+                        || a == b
+                        || (a - b).abs() <= tolerance * a.abs().max(b.abs()).max(1.0)
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! impl_exact_verify_value {
+    ($($t:ty),+) => {
+        $(
+            impl VerifyValue for $t {
+                fn matches(self, fallback: Self, _tolerance: f64) -> bool {
+                    self == fallback
+                }
+            }
+        )+
+    };
+}
+
+impl_float_verify_value!(f32, f64);
+impl_exact_verify_value!(u8, i32);
+
+/// Returns the backend [dispatch_backend](crate::backend::dispatch_backend) selects,
+/// which is only [Backend::Fallback] when no optimized backend is available.
+pub(crate) fn dispatched_backend() -> Backend {
+    static_backend().unwrap_or_else(detected_backend)
+}
+
+/// Asserts every element of `result` matches the `expected` output of the fallback
+/// routines.
+pub(crate) fn assert_matches_fallback<T: VerifyValue>(
+    op: &str,
+    backend: Backend,
+    result: &[T],
+    expected: &[T],
+) {
+    assert_matches_fallback_within(op, backend, result, expected, VERIFY_TOLERANCE)
+}
+
+/// Asserts every element of `result` matches the `expected` output of the fallback
+/// routines within the given relative `tolerance`.
+pub(crate) fn assert_matches_fallback_within<T: VerifyValue>(
+    op: &str,
+    backend: Backend,
+    result: &[T],
+    expected: &[T],
+    tolerance: f64,
+) {
+    assert_eq!(result.len(), expected.len());
+
+    for (i, (&value, &fallback)) in result.iter().zip(expected).enumerate() {
+        assert!(
+            value.matches(fallback, tolerance),
+            "{backend:?} `{op}` does not match the fallback routines at [{i}]: \
+             {value:?} vs {fallback:?}"
+        );
+    }
+}
+
+/// Checks the value returned by `backend` against the value `fallback` returns.
+pub(crate) fn verify_returned<T: VerifyValue>(
+    op: &str,
+    backend: Backend,
+    tolerance: f64,
+    result: T,
+    fallback: impl FnOnce() -> T,
+) {
+    if backend != Backend::Fallback {
+        let expected = fallback();
+        assert_matches_fallback_within(op, backend, &[result], &[expected], tolerance);
+    }
+}
+
+/// Checks the `result` written by `backend` against the output `fallback` writes to
+/// a buffer of the same length.
+pub(crate) fn verify_written<T: VerifyValue>(
+    op: &str,
+    backend: Backend,
+    tolerance: f64,
+    result: &[T],
+    fallback: impl FnOnce(&mut [T]),
+) {
+    if backend != Backend::Fallback {
+        let mut expected = result.to_vec();
+        fallback(&mut expected);
+        assert_matches_fallback_within(op, backend, result, &expected, tolerance);
+    }
+}
+
+/// Checks the `result` of an in place operation by `backend` against `fallback`
+/// applied in place to a copy of the `original` input.
+pub(crate) fn verify_inplace<T: VerifyValue>(
+    op: &str,
+    backend: Backend,
+    tolerance: f64,
+    result: &[T],
+    mut original: Vec<T>,
+    fallback: impl FnOnce(&mut [T]),
+) {
+    if backend != Backend::Fallback {
+        fallback(&mut original);
+        assert_matches_fallback_within(op, backend, result, &original, tolerance);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_matches_fallback() {
+        verify_returned("returned", Backend::Avx2, VERIFY_TOLERANCE, 1.0f32, || {
+            1.00001
+        });
+        verify_written(
+            "written",
+            Backend::Avx2,
+            VERIFY_TOLERANCE,
+            &[1, 2],
+            |result| result.copy_from_slice(&[1, 2]),
+        );
+        verify_inplace(
+            "inplace",
+            Backend::Avx2,
+            VERIFY_TOLERANCE,
+            &[2.0f64, 4.0],
+            vec![1.0, 2.0],
+            |x| x.iter_mut().for_each(|v| *v *= 2.0),
+        );
+    }
+
+    #[test]
+    fn test_verify_skips_fallback_backend() {
+        verify_returned(
+            "returned",
+            Backend::Fallback,
+            VERIFY_TOLERANCE,
+            1.0f32,
+            || unreachable!(),
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Avx2 `written` does not match the fallback routines at [1]"
+    )]
+    fn test_verify_written_mismatch() {
+        verify_written(
+            "written",
+            Backend::Avx2,
+            VERIFY_TOLERANCE,
+            &[1.0f32, 2.0],
+            |result| result.copy_from_slice(&[1.0, 2.1]),
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Avx2 `inplace` does not match the fallback routines at [0]"
+    )]
+    fn test_verify_inplace_exact_mismatch() {
+        verify_inplace(
+            "inplace",
+            Backend::Avx2,
+            VERIFY_TOLERANCE,
+            &[255u8],
+            vec![0],
+            |x| x[0] = x[0].wrapping_sub(2),
+        );
+    }
+}